@@ -0,0 +1,81 @@
+use anyhow::Result;
+use modsurfer_module::Module;
+use modsurfer_validation::{Report, Validation};
+
+use crate::ModuleQuery;
+
+/// Serialize a `Validation` into the YAML checkfile bytes expected by the server.
+pub(crate) fn checkfile_bytes(validation: &Validation) -> Result<Vec<u8>> {
+    Ok(serde_yaml::to_string(validation)?.into_bytes())
+}
+
+/// The validation report for a single module included in the results of an audit.
+#[derive(Debug)]
+pub struct ModuleReport {
+    pub module_id: i64,
+    pub hash: String,
+    /// the name of the checkfile the module was validated against, when an audit selects a
+    /// checkfile for each module
+    pub checkfile: Option<String>,
+    pub report: Report,
+}
+
+/// A checkfile to audit the modules matching the `query` with, e.g. every module having the
+/// metadata entry `team=payments`. An empty query matches every module.
+#[derive(Clone, Debug)]
+pub struct CheckfileSelector {
+    /// a name for the checkfile, included in the reports of the modules it was applied to
+    pub name: String,
+    pub query: ModuleQuery,
+    pub validation: Validation,
+}
+
+impl CheckfileSelector {
+    pub fn matches(&self, module: &Module) -> bool {
+        self.query.matches(module)
+    }
+}
+
+/// The typed results of an audit, which can be looked up by either module ID or hash.
+#[derive(Debug, Default)]
+pub struct AuditReports {
+    inner: Vec<ModuleReport>,
+}
+
+impl AuditReports {
+    /// Construct the results from individual module reports, ordered by module ID.
+    pub fn new(mut inner: Vec<ModuleReport>) -> Self {
+        inner.sort_by_key(|r| r.module_id);
+        Self { inner }
+    }
+
+    /// Find the report for a module by its ID.
+    pub fn by_id(&self, module_id: i64) -> Option<&ModuleReport> {
+        self.inner.iter().find(|r| r.module_id == module_id)
+    }
+
+    /// Find the report for a module by its SHA-256 hash.
+    pub fn by_hash(&self, hash: &str) -> Option<&ModuleReport> {
+        self.inner
+            .iter()
+            .find(|r| r.hash.eq_ignore_ascii_case(hash.trim()))
+    }
+
+    /// Iterate over the module reports, ordered by module ID.
+    pub fn iter(&self) -> impl Iterator<Item = &ModuleReport> {
+        self.inner.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return the module reports, dropping the lookup helpers.
+    pub fn into_inner(self) -> Vec<ModuleReport> {
+        self.inner
+    }
+}
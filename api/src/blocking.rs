@@ -0,0 +1,328 @@
+//! A blocking API client, for use in applications which do not otherwise need an async runtime
+//! (build scripts, simple integrations, etc.). Each method mirrors the equivalent `ApiClient`
+//! operation and blocks the current thread until the operation completes.
+//!
+//! NOTE: like `reqwest::blocking`, this client must not be used from within an async runtime.
+use std::collections::HashMap;
+
+use anyhow::Result;
+use futures::StreamExt;
+use modsurfer_convert::{Audit, AuditOutcome, Pagination};
+use modsurfer_module::{Export, Import, Module, Quarantine};
+use modsurfer_validation::{Report, Validation};
+
+use crate::{
+    ApiClient, AuditReports, CheckfileSelector, EventFilter, List, ModuleEvent, ModuleQuery,
+    NamespaceUsage, Persisted, RegistryStats, ServerInfo, SortDirection, SortField,
+};
+
+/// The blocking API Client implementation, wrapping the async `Client` and driving it to
+/// completion on a private single-threaded runtime.
+pub struct Client {
+    inner: crate::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    /// Construct a blocking API Client using the `base_url`, which should be the server host
+    /// address and port needed to communicate with a Modsurfer backend.
+    pub fn new(base_url: &str) -> Result<Self> {
+        Client::from_client(crate::Client::new(base_url)?)
+    }
+
+    /// Wrap an already-configured async `Client` (e.g. from `Client::builder()`).
+    pub fn from_client(inner: crate::Client) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client { inner, runtime })
+    }
+
+    /// Find a module by its ID.
+    pub fn get_module(&self, module_id: i64) -> Result<Persisted<Module>> {
+        self.runtime.block_on(self.inner.get_module(module_id))
+    }
+
+    /// Find many modules by their IDs, fetched concurrently. See `ApiClient::get_modules`.
+    pub fn get_modules(&self, module_ids: Vec<i64>) -> Result<Vec<Persisted<Module>>> {
+        self.runtime.block_on(self.inner.get_modules(module_ids))
+    }
+
+    /// Find a module by its exact SHA-256 hash.
+    pub fn get_module_by_hash(&self, hash: &str) -> Result<Persisted<Module>> {
+        self.runtime.block_on(self.inner.get_module_by_hash(hash))
+    }
+
+    /// List all modules stored in the database.
+    pub fn list_modules(&self, offset: u32, limit: u32) -> Result<List<Persisted<Module>>> {
+        self.runtime
+            .block_on(self.inner.list_modules(offset, limit))
+    }
+
+    /// Create a new module entry in Modsurfer.
+    pub fn create_module(
+        &self,
+        wasm: impl AsRef<[u8]> + Send,
+        metadata: Option<HashMap<String, String>>,
+        location: Option<url::Url>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
+    ) -> Result<(i64, String)> {
+        self.runtime.block_on(
+            self.inner
+                .create_module(wasm, metadata, location, tags, version),
+        )
+    }
+
+    /// Search for modules based on input parameters, combined using `AND` conditions.
+    pub fn search_modules(
+        &self,
+        module_id: Option<i64>,
+        hash: Option<String>,
+        function_name: Option<String>,
+        module_name: Option<String>,
+        imports: Option<Vec<Import>>,
+        exports: Option<Vec<Export>>,
+        min_size: Option<u64>,
+        max_size: Option<u64>,
+        location: Option<url::Url>,
+        source_language: Option<String>,
+        metadata: Option<HashMap<String, String>>,
+        inserted_before: Option<chrono::DateTime<chrono::Utc>>,
+        inserted_after: Option<chrono::DateTime<chrono::Utc>>,
+        strings: Option<Vec<String>>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
+        min_complexity: Option<u32>,
+        max_complexity: Option<u32>,
+        namespaces: Option<Vec<String>>,
+        offset: u32,
+        limit: u32,
+        sort_field: Option<SortField>,
+        sort_direction: Option<SortDirection>,
+    ) -> Result<List<Persisted<Module>>> {
+        self.runtime.block_on(self.inner.search_modules(
+            module_id,
+            hash,
+            function_name,
+            module_name,
+            imports,
+            exports,
+            min_size,
+            max_size,
+            location,
+            source_language,
+            metadata,
+            inserted_before,
+            inserted_after,
+            strings,
+            tags,
+            version,
+            min_complexity,
+            max_complexity,
+            namespaces,
+            offset,
+            limit,
+            sort_field,
+            sort_direction,
+        ))
+    }
+
+    /// Replace the tags attached to a module.
+    pub fn tag_module(&self, module_id: i64, tags: Vec<String>) -> Result<Vec<String>> {
+        self.runtime
+            .block_on(self.inner.tag_module(module_id, tags))
+    }
+
+    /// Flag a module as blocked, without deleting or yanking it.
+    pub fn quarantine_module(&self, module_id: i64, reason: String) -> Result<Quarantine> {
+        self.runtime
+            .block_on(self.inner.quarantine_module(module_id, reason))
+    }
+
+    /// Lift the quarantine of a module.
+    pub fn unquarantine_module(&self, module_id: i64) -> Result<()> {
+        self.runtime
+            .block_on(self.inner.unquarantine_module(module_id))
+    }
+
+    /// Delete modules from the database. This is a non-reversable operation.
+    pub fn delete_modules(&self, module_ids: Vec<i64>) -> Result<HashMap<i64, String>> {
+        self.runtime.block_on(self.inner.delete_modules(module_ids))
+    }
+
+    /// Collect every module matching the `query`.
+    pub fn find_modules(&self, query: &ModuleQuery) -> Result<Vec<Persisted<Module>>> {
+        self.runtime.block_on(self.inner.find_modules(query))
+    }
+
+    /// Delete every module matching the `query`, or only list them if `dry_run` is true.
+    pub fn delete_modules_matching(
+        &self,
+        query: &ModuleQuery,
+        dry_run: bool,
+    ) -> Result<HashMap<i64, String>> {
+        self.runtime
+            .block_on(self.inner.delete_modules_matching(query, dry_run))
+    }
+
+    /// Audit the modules based on a provided checkfile and expected outcome.
+    pub fn audit_modules(&self, audit: Audit) -> Result<HashMap<i64, Report>> {
+        self.runtime.block_on(self.inner.audit_modules(audit))
+    }
+
+    /// Audit the modules using a structured `Validation`, returning reports with module hashes.
+    pub fn audit_modules_with(
+        &self,
+        validation: &Validation,
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        self.runtime
+            .block_on(self.inner.audit_modules_with(validation, outcome, page))
+    }
+
+    /// Audit the modules, validating them locally instead of on the server.
+    pub fn audit_modules_locally(
+        &self,
+        validation: &Validation,
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        self.runtime
+            .block_on(self.inner.audit_modules_locally(validation, outcome, page))
+    }
+
+    /// Audit the modules, validating each against the checkfile of the first matching selector.
+    pub fn audit_modules_selected(
+        &self,
+        selectors: &[CheckfileSelector],
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        self.runtime
+            .block_on(self.inner.audit_modules_selected(selectors, outcome, page))
+    }
+
+    /// Compare two modules stored in Modsurfer.
+    pub fn diff_modules(
+        &self,
+        module1: i64,
+        module2: i64,
+        color_terminal: bool,
+        with_context: bool,
+    ) -> Result<String> {
+        self.runtime.block_on(self.inner.diff_modules(
+            module1,
+            module2,
+            color_terminal,
+            with_context,
+        ))
+    }
+
+    /// Validate a module against a checkfile on the server.
+    pub fn validate_module(
+        &self,
+        wasm: impl AsRef<[u8]> + Send,
+        checkfile: impl AsRef<[u8]> + Send,
+    ) -> Result<Report> {
+        self.runtime
+            .block_on(self.inner.validate_module(wasm, checkfile))
+    }
+
+    /// Validate many named modules against the same checkfile on the server.
+    pub fn validate_many(
+        &self,
+        modules: Vec<(String, Vec<u8>)>,
+        checkfile: impl AsRef<[u8]> + Send,
+    ) -> Vec<(String, Result<Report>)> {
+        self.runtime
+            .block_on(self.inner.validate_many(modules, checkfile))
+    }
+
+    /// Validate many named modules against the same checkfile, yielding each result in order as
+    /// it becomes available.
+    pub fn validate_each(
+        &self,
+        modules: Vec<(String, Vec<u8>)>,
+        checkfile: Vec<u8>,
+    ) -> impl Iterator<Item = (String, Result<Report>)> + '_ {
+        let mut results = self.inner.validate_each(modules, checkfile);
+        std::iter::from_fn(move || self.runtime.block_on(results.next()))
+    }
+
+    /// Find a module graph by its ID.
+    pub fn get_module_graph(&self, module_id: i64) -> Result<Vec<u8>> {
+        self.runtime
+            .block_on(self.inner.get_module_graph(module_id))
+    }
+
+    /// Call a Modsurfer plugin.
+    pub fn call_plugin(
+        &self,
+        identifier: String,
+        function_name: String,
+        function_input: Vec<u8>,
+    ) -> Result<Vec<u8>> {
+        self.runtime.block_on(
+            self.inner
+                .call_plugin(identifier, function_name, function_input),
+        )
+    }
+
+    /// Install a Modsurfer plugin.
+    pub fn install_plugin(
+        &self,
+        identifier: String,
+        name: Option<String>,
+        location: String,
+        wasm: Vec<u8>,
+    ) -> Result<()> {
+        self.runtime
+            .block_on(self.inner.install_plugin(identifier, name, location, wasm))
+    }
+
+    /// Uninstall a Modsurfer plugin.
+    pub fn uninstall_plugin(&self, identifier: String) -> Result<()> {
+        self.runtime
+            .block_on(self.inner.uninstall_plugin(identifier))
+    }
+
+    /// Subscribe to module events matching the `filter`. The iterator blocks while waiting for new
+    /// events, and ends after yielding an error.
+    pub fn subscribe(&self, filter: EventFilter) -> impl Iterator<Item = Result<ModuleEvent>> + '_ {
+        let mut events = self.inner.subscribe(filter);
+        std::iter::from_fn(move || self.runtime.block_on(events.next()))
+    }
+
+    /// Check that the server is running and able to handle requests.
+    pub fn health(&self) -> Result<()> {
+        self.runtime.block_on(self.inner.health())
+    }
+
+    /// Get the server's version and the API features it supports.
+    pub fn server_info(&self) -> Result<ServerInfo> {
+        self.runtime.block_on(self.inner.server_info())
+    }
+
+    /// Find the modules in the registry similar to the `module`, most similar first.
+    pub fn similar_modules(
+        &self,
+        module: &Module,
+        threshold: f64,
+    ) -> Result<Vec<(Persisted<Module>, f64)>> {
+        self.runtime
+            .block_on(self.inner.similar_modules(module, threshold))
+    }
+
+    /// Compute statistics about every module in the registry.
+    pub fn registry_stats(&self) -> Result<RegistryStats> {
+        self.runtime.block_on(self.inner.registry_stats())
+    }
+
+    /// Count the modules importing from each namespace, and each function in it.
+    pub fn namespace_usage(&self, namespace: Option<&str>) -> Result<NamespaceUsage> {
+        self.runtime.block_on(self.inner.namespace_usage(namespace))
+    }
+}
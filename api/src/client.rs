@@ -1,18 +1,36 @@
 use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use modsurfer_convert::{
     api::{self, Sort},
-    to_api, Audit,
+    from_api, to_api, Audit, AuditOutcome, Pagination,
 };
-use modsurfer_module::{Export, Import, Module};
-use modsurfer_validation::Report;
-use protobuf::{self, EnumOrUnknown, Message, MessageField, SpecialFields};
-use reqwest;
+use modsurfer_module::{Export, Import, Module, Quarantine};
+use modsurfer_validation::{Report, Validation};
+use protobuf::{self, EnumOrUnknown, MessageField, SpecialFields};
+use reqwest::{self, header, Method};
+use tracing::Instrument;
 use url::Url;
 
-use super::{ApiClient, List, Persisted, SortDirection, SortField};
+use super::audit::{self, AuditReports, ModuleReport};
+use super::compression::{self, Compression};
+use super::error::ApiError;
+#[cfg(not(target_arch = "wasm32"))]
+use super::fixtures::{self, Recorder};
+use super::middleware::{self, RequestHook, ResponseHook};
+use super::page::ModulePage;
+use super::wire_format::WireFormat;
+use super::{
+    ApiClient, EventFilter, List, ModuleEvent, Persisted, ServerInfo, SortDirection, SortField,
+    DEFAULT_CONCURRENCY,
+};
 
 enum ModserverCommand {
     CreateModule(api::CreateModuleRequest),
@@ -20,6 +38,8 @@ enum ModserverCommand {
     ListModules(api::ListModulesRequest),
     SearchModules(api::SearchModulesRequest),
     DeleteModules(api::DeleteModulesRequest),
+    TagModule(api::TagModuleRequest),
+    QuarantineModule(api::QuarantineModuleRequest),
     AuditModules(api::AuditModulesRequest),
     DiffModules(api::DiffRequest),
     ValidateModule(api::ValidateModuleRequest),
@@ -27,13 +47,60 @@ enum ModserverCommand {
     CallPlugin(api::CallPluginRequest),
     InstallPlugin(api::InstallPluginRequest),
     UninstallPlugin(api::UninstallPluginRequest),
+    Subscribe(api::SubscribeRequest),
+    Health(api::HealthRequest),
+    ServerInfo(api::ServerInfoRequest),
+}
+
+/// The relevant parts of an HTTP response, independent of the underlying transport.
+struct RawResponse {
+    status: u16,
+    accept_encoding: Option<String>,
+    content_encoding: Option<String>,
+    content_type: Option<String>,
+    data: Vec<u8>,
 }
 
-/// The API Client implementation.
+/// A decompressed response body, with the format it is encoded in.
+struct Body {
+    url: String,
+    status: u16,
+    wire_format: WireFormat,
+    data: Vec<u8>,
+}
+
+impl Body {
+    fn decode<T: protobuf::MessageFull>(&self) -> Result<T> {
+        self.wire_format
+            .decode(&self.data)
+            .map_err(|e| self.unexpected(e))
+    }
+
+    fn unexpected(&self, e: impl std::fmt::Display) -> anyhow::Error {
+        anyhow::anyhow!(
+            "unexpected response from {} (HTTP status {}), is this a Modsurfer server? ({})",
+            self.url,
+            self.status,
+            e
+        )
+    }
+}
+
+/// The API Client implementation. Requests are sent using reqwest, or the browser's `fetch` API
+/// when built for `wasm32` with the `wasm-client` feature enabled.
 #[derive(Clone)]
 pub struct Client {
+    #[cfg(not(all(feature = "wasm-client", target_arch = "wasm32")))]
     inner: reqwest::Client,
     base_url: String,
+    compression: Compression,
+    compression_negotiated: Arc<AtomicBool>,
+    wire_format: WireFormat,
+    on_request: Vec<RequestHook>,
+    on_response: Vec<ResponseHook>,
+    concurrency: usize,
+    #[cfg(not(target_arch = "wasm32"))]
+    recorder: Option<Recorder>,
 }
 
 #[async_trait(?Send)]
@@ -41,17 +108,14 @@ impl ApiClient for Client {
     /// Construct an API Client using the `base_url`, which should be the server host address and
     /// port needed to communicate with a Modsurfer backend. Many backends default to http://localhost:1739.
     fn new(base_url: &str) -> Result<Self> {
-        let inner = reqwest::ClientBuilder::new()
-            .build()
-            .map_err(|e| anyhow::anyhow!("{}", e))?;
-
-        Ok(Client {
-            inner,
-            base_url: base_url.to_string(),
-        })
+        Client::builder().base_url(base_url).build()
     }
 
     /// Find a module by its ID.
+    fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
     async fn get_module(&self, module_id: i64) -> Result<Persisted<Module>> {
         let req = api::GetModuleRequest {
             module_id,
@@ -63,7 +127,7 @@ impl ApiClient for Client {
         }
 
         if res.module.is_some() {
-            Ok(res.module.unwrap().into())
+            res.module.unwrap().try_into()
         } else {
             Err(anyhow::anyhow!("No module found."))
         }
@@ -79,14 +143,14 @@ impl ApiClient for Client {
         let mut req = api::ListModulesRequest::new();
         req.pagination = MessageField::some(pagination);
 
-        let res: api::ListModulesResponse = self.send(ModserverCommand::ListModules(req)).await?;
+        let res = self
+            .send_page::<api::ListModulesResponse>(ModserverCommand::ListModules(req))
+            .await?;
         if res.error.is_some() {
             return Err(api_error(res.error, "list module request failed"));
         }
 
-        let modules = res.modules.into_iter().map(Into::into).collect();
-
-        Ok(List::new(modules, res.total as u32, offset, limit))
+        Ok(List::new(res.modules, res.total as u32, offset, limit))
     }
 
     /// Create a new module entry in Modsurfer. If no `location` is set, the module will be named
@@ -98,11 +162,15 @@ impl ApiClient for Client {
         wasm: impl AsRef<[u8]> + Send,
         metadata: Option<HashMap<String, String>>,
         location: std::option::Option<Url>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
     ) -> Result<(i64, String)> {
         let req = api::CreateModuleRequest {
             wasm: wasm.as_ref().to_vec(),
             metadata: metadata.unwrap_or_default(),
             location: location.map(Into::into),
+            tags: tags.unwrap_or_default(),
+            version,
             ..Default::default()
         };
 
@@ -132,6 +200,11 @@ impl ApiClient for Client {
         inserted_before: Option<chrono::DateTime<chrono::Utc>>,
         inserted_after: Option<chrono::DateTime<chrono::Utc>>,
         strings: Option<Vec<String>>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
+        min_complexity: Option<u32>,
+        max_complexity: Option<u32>,
+        namespaces: Option<Vec<String>>,
         offset: u32,
         limit: u32,
         sort_field: Option<SortField>,
@@ -199,26 +272,82 @@ impl ApiClient for Client {
             inserted_before,
             inserted_after,
             strings: strings.unwrap_or_default(),
+            tags: tags.unwrap_or_default(),
+            version,
+            min_complexity,
+            max_complexity,
+            namespaces: namespaces.unwrap_or_default(),
             pagination: MessageField::some(pagination),
             ..Default::default()
         };
 
-        let res: api::SearchModulesResponse =
-            self.send(ModserverCommand::SearchModules(req)).await?;
+        let res = self
+            .send_page::<api::SearchModulesResponse>(ModserverCommand::SearchModules(req))
+            .await?;
         if res.error.is_some() {
             return Err(api_error(res.error, "search modules request failed"));
         }
 
-        let modules = res.modules.into_iter().map(Into::into).collect();
-
         Ok(List::new(
-            modules,
+            res.modules,
             res.total as u32,
             res.pagination.offset,
             res.pagination.limit,
         ))
     }
 
+    /// Replace the tags attached to a module, returning the module's resulting tags.
+    async fn tag_module(&self, module_id: i64, tags: Vec<String>) -> Result<Vec<String>> {
+        let req = api::TagModuleRequest {
+            module_id,
+            tags,
+            ..Default::default()
+        };
+
+        let res: api::TagModuleResponse = self.send(ModserverCommand::TagModule(req)).await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "tag module request failed"));
+        }
+
+        Ok(res.tags)
+    }
+
+    async fn quarantine_module(&self, module_id: i64, reason: String) -> Result<Quarantine> {
+        let req = api::QuarantineModuleRequest {
+            module_id,
+            quarantined: true,
+            reason,
+            ..Default::default()
+        };
+
+        let res: api::QuarantineModuleResponse =
+            self.send(ModserverCommand::QuarantineModule(req)).await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "quarantine module request failed"));
+        }
+
+        res.quarantine
+            .into_option()
+            .map(from_api::quarantine)
+            .ok_or_else(|| anyhow::anyhow!("the server did not quarantine module {}", module_id))
+    }
+
+    async fn unquarantine_module(&self, module_id: i64) -> Result<()> {
+        let req = api::QuarantineModuleRequest {
+            module_id,
+            quarantined: false,
+            ..Default::default()
+        };
+
+        let res: api::QuarantineModuleResponse =
+            self.send(ModserverCommand::QuarantineModule(req)).await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "unquarantine module request failed"));
+        }
+
+        Ok(())
+    }
+
     /// Delete a module from the database. This is a non-reversable operation.
     async fn delete_modules(&self, module_ids: Vec<i64>) -> Result<HashMap<i64, String>> {
         let req = api::DeleteModulesRequest {
@@ -240,34 +369,50 @@ impl ApiClient for Client {
         &self,
         audit: Audit,
     ) -> Result<HashMap<i64, modsurfer_validation::Report>> {
-        let mut pagination: api::Pagination = Default::default();
-        pagination.limit = audit.page.limit;
-        pagination.offset = audit.page.offset;
+        let (id_reports, _) = self.audit(audit, None).await?;
 
-        let req = api::AuditModulesRequest {
-            outcome: EnumOrUnknown::new(api::AuditOutcome::from(audit.outcome)),
-            pagination: MessageField::some(pagination),
-            checkfile: audit.checkfile,
-            ..Default::default()
+        Ok(id_reports)
+    }
+
+    /// Audit the modules using a structured `Validation`. Servers which do not return the hashes of
+    /// audited modules require an additional request per module to look them up, which are sent
+    /// concurrently.
+    async fn audit_modules_with(
+        &self,
+        validation: &Validation,
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        let audit = Audit {
+            checkfile: audit::checkfile_bytes(validation)?,
+            page,
+            outcome,
         };
+        // older servers only read the YAML bytes, so both forms of the checkfile are sent
+        let (id_reports, mut id_hashes) = self
+            .audit(audit, Some(api::Checkfile::from(validation)))
+            .await?;
 
-        let res: api::AuditModulesResponse = self.send(ModserverCommand::AuditModules(req)).await?;
-        if res.error.is_some() {
-            return Err(api_error(res.error, "audit modules request failed"));
+        let missing = id_reports
+            .keys()
+            .filter(|module_id| !id_hashes.contains_key(module_id))
+            .copied()
+            .collect();
+        for module in self.get_modules(missing).await? {
+            id_hashes.insert(module.get_id(), module.into_inner().hash);
         }
 
-        let mut id_reports: HashMap<i64, modsurfer_validation::Report> = Default::default();
-        res.invalid_module_report
-            .iter()
-            .for_each(|(id, json_report)| {
-                if let Ok(report) = serde_json::from_slice(json_report) {
-                    let _ = id_reports.insert(*id, report);
-                } else {
-                    log::error!("failed to decode validation report for module {}", id);
-                }
-            });
+        let reports = id_reports
+            .into_iter()
+            .map(|(module_id, report)| ModuleReport {
+                module_id,
+                hash: id_hashes.remove(&module_id).unwrap_or_default(),
+                checkfile: None,
+                report,
+            })
+            .collect();
 
-        Ok(id_reports)
+        Ok(AuditReports::new(reports))
     }
 
     async fn diff_modules(
@@ -404,150 +549,351 @@ impl ApiClient for Client {
 
         Ok(())
     }
+
+    /// Fetch the module events matching the `filter`, long-polling for up to `wait_seconds`.
+    async fn poll_events(
+        &self,
+        filter: &EventFilter,
+        wait_seconds: u32,
+    ) -> Result<(Vec<ModuleEvent>, u64)> {
+        let req = api::SubscribeRequest {
+            after_event_id: filter.after_event_id,
+            wait_seconds,
+            kinds: filter
+                .kinds
+                .iter()
+                .map(|k| EnumOrUnknown::new(k.to_proto()))
+                .collect(),
+            tags: filter.tags.clone(),
+            hash: filter.hash.clone(),
+            ..Default::default()
+        };
+
+        let res: api::SubscribeResponse = self.send(ModserverCommand::Subscribe(req)).await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "subscribe request failed"));
+        }
+
+        let events = res
+            .events
+            .into_iter()
+            .filter_map(ModuleEvent::from_proto)
+            .collect();
+
+        Ok((events, res.last_event_id))
+    }
+
+    /// Check that the server is running and able to handle requests.
+    async fn health(&self) -> Result<()> {
+        let res: api::HealthResponse = self
+            .send(ModserverCommand::Health(api::HealthRequest::new()))
+            .await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "health request failed"));
+        }
+
+        if !res.ok {
+            anyhow::bail!("server at {} reported that it is unhealthy", self.base_url);
+        }
+
+        Ok(())
+    }
+
+    /// Get the server's version and the API features it supports.
+    async fn server_info(&self) -> Result<ServerInfo> {
+        let res: api::ServerInfoResponse = self
+            .send(ModserverCommand::ServerInfo(api::ServerInfoRequest::new()))
+            .await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "server info request failed"));
+        }
+
+        Ok(res.into())
+    }
 }
 
 impl Client {
-    async fn send<T: protobuf::Message>(&self, cmd: ModserverCommand) -> Result<T> {
-        match cmd {
-            ModserverCommand::CreateModule(req) => {
-                let resp = self
-                    .inner
-                    .put(&self.make_endpoint("/api/v1/module"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-
-                return Ok(val);
-            }
-            ModserverCommand::GetModule(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/module"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-
-                return Ok(val);
+    /// Create a `ClientBuilder` to configure a Client beyond the defaults used by `Client::new`.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    async fn send<T: protobuf::MessageFull>(&self, cmd: ModserverCommand) -> Result<T> {
+        let body = self.send_raw(cmd).await?;
+        let message: T = body.decode()?;
+        self.record(T::NAME, &body, Some(&message));
+
+        Ok(message)
+    }
+
+    /// Send a list or search request, decoding the modules of the response one at a time (see
+    /// `ModulePage`) unless the server replied using JSON.
+    async fn send_page<T>(&self, cmd: ModserverCommand) -> Result<ModulePage>
+    where
+        T: protobuf::MessageFull + TryInto<ModulePage, Error = anyhow::Error>,
+    {
+        let body = self.send_raw(cmd).await?;
+        match body.wire_format {
+            WireFormat::Protobuf => {
+                let page = ModulePage::decode(&body.data).map_err(|e| body.unexpected(e))?;
+                self.record(T::NAME, &body, None::<&T>);
+                Ok(page)
             }
-            ModserverCommand::ListModules(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/modules"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-
-                return Ok(val);
+            WireFormat::Json => {
+                let message: T = body.decode()?;
+                self.record(T::NAME, &body, Some(&message));
+                message.try_into()
             }
-            ModserverCommand::SearchModules(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/search"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-
-                return Ok(val);
+        }
+    }
+
+    /// Record a response as a fixture, if recording. Fixtures are always stored as protobuf, so a
+    /// JSON response is re-encoded from its decoded `message`.
+    #[allow(unused_variables)]
+    fn record<T: protobuf::MessageFull>(&self, name: &str, body: &Body, message: Option<&T>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(recorder) = &self.recorder {
+            let recorded = match (body.wire_format, message) {
+                (WireFormat::Json, Some(message)) => message
+                    .write_to_bytes()
+                    .map_err(Into::into)
+                    .and_then(|data| recorder.record(name, &data)),
+                _ => recorder.record(name, &body.data),
+            };
+            if let Err(e) = recorded {
+                log::warn!("failed to record {} fixture: {}", name, e);
             }
+        }
+    }
+
+    async fn send_raw(&self, cmd: ModserverCommand) -> Result<Body> {
+        let encode = |req: &dyn protobuf::MessageDyn| self.wire_format.encode(req);
+        let (method, route, mut body) = match cmd {
+            ModserverCommand::CreateModule(req) => (Method::PUT, "/api/v1/module", encode(&req)?),
+            ModserverCommand::GetModule(req) => (Method::POST, "/api/v1/module", encode(&req)?),
+            ModserverCommand::ListModules(req) => (Method::POST, "/api/v1/modules", encode(&req)?),
+            ModserverCommand::SearchModules(req) => (Method::POST, "/api/v1/search", encode(&req)?),
             ModserverCommand::DeleteModules(req) => {
-                let resp = self
-                    .inner
-                    .delete(&self.make_endpoint("/api/v1/modules"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-
-                return Ok(val);
-            }
-            ModserverCommand::AuditModules(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/audit"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-
-                return Ok(val);
+                (Method::DELETE, "/api/v1/modules", encode(&req)?)
             }
-            ModserverCommand::DiffModules(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/diff"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-                return Ok(val);
+            ModserverCommand::TagModule(req) => (Method::PUT, "/api/v1/module/tags", encode(&req)?),
+            ModserverCommand::QuarantineModule(req) => {
+                (Method::PUT, "/api/v1/module/quarantine", encode(&req)?)
             }
+            ModserverCommand::AuditModules(req) => (Method::POST, "/api/v1/audit", encode(&req)?),
+            ModserverCommand::DiffModules(req) => (Method::POST, "/api/v1/diff", encode(&req)?),
             ModserverCommand::ValidateModule(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/validate"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-                return Ok(val);
+                (Method::POST, "/api/v1/validate", encode(&req)?)
             }
             ModserverCommand::GetModuleGraph(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/module_graph"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-                return Ok(val);
-            }
-            ModserverCommand::CallPlugin(req) => {
-                let resp = self
-                    .inner
-                    .post(&self.make_endpoint("/api/v1/plugin"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-                return Ok(val);
-            }
-            ModserverCommand::InstallPlugin(req) => {
-                let resp = self
-                    .inner
-                    .put(&self.make_endpoint("/api/v1/plugin"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-                return Ok(val);
+                (Method::POST, "/api/v1/module_graph", encode(&req)?)
             }
+            ModserverCommand::CallPlugin(req) => (Method::POST, "/api/v1/plugin", encode(&req)?),
+            ModserverCommand::InstallPlugin(req) => (Method::PUT, "/api/v1/plugin", encode(&req)?),
             ModserverCommand::UninstallPlugin(req) => {
-                let resp = self
-                    .inner
-                    .delete(&self.make_endpoint("/api/v1/plugin"))
-                    .body(req.write_to_bytes()?)
-                    .send()
-                    .await?;
-                let data = resp.bytes().await?;
-                let val = protobuf::Message::parse_from_bytes(&data)?;
-                return Ok(val);
+                (Method::DELETE, "/api/v1/plugin", encode(&req)?)
+            }
+            ModserverCommand::Subscribe(req) => (Method::POST, "/api/v1/events", encode(&req)?),
+            ModserverCommand::Health(req) => (Method::GET, "/api/v1/health", encode(&req)?),
+            ModserverCommand::ServerInfo(req) => (Method::GET, "/api/v1/info", encode(&req)?),
+        };
+
+        // GET requests carry no fields, and `fetch` rejects them if a body is set
+        if method == Method::GET {
+            body.clear();
+        }
+
+        // only compress the body once the server has told us it can decode it
+        let encoded = if self.compression_negotiated.load(Ordering::Relaxed) {
+            self.compression.encode(&body)?
+        } else {
+            None
+        };
+        let (body, content_encoding) = match (encoded, self.compression.as_header_value()) {
+            (Some(encoded), Some(encoding)) => (encoded, Some(encoding)),
+            _ => (body, None),
+        };
+
+        let mut request = middleware::Request {
+            method,
+            route,
+            url: self.make_endpoint(route),
+            body_len: body.len(),
+            headers: vec![],
+        };
+        request.insert_header(
+            header::CONTENT_TYPE.as_str(),
+            self.wire_format.content_type(),
+        );
+        request.insert_header(header::ACCEPT.as_str(), self.wire_format.content_type());
+        if let Some(encoding) = content_encoding {
+            request.insert_header(header::CONTENT_ENCODING.as_str(), encoding);
+        }
+        self.on_request.iter().for_each(|hook| hook(&mut request));
+
+        let span = tracing::info_span!(
+            "modsurfer_request",
+            method = %request.method,
+            endpoint = route,
+            request_bytes = request.body_len,
+            status = tracing::field::Empty,
+            response_bytes = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+        let started = chrono::Utc::now();
+        let resp = self
+            .execute(&request, body)
+            .instrument(span.clone())
+            .await
+            .map_err(|e| {
+                tracing::warn!(parent: &span, error = %e, "request failed");
+                e
+            })?;
+
+        let response = middleware::Response {
+            method: request.method,
+            route,
+            status: resp.status,
+            latency: (chrono::Utc::now() - started).to_std().unwrap_or_default(),
+            body_len: resp.data.len(),
+        };
+        span.record("status", response.status);
+        span.record("response_bytes", response.body_len);
+        span.record("latency_ms", response.latency.as_millis() as u64);
+        tracing::debug!(parent: &span, "request completed");
+        self.on_response.iter().for_each(|hook| hook(&response));
+
+        if let Some(accept) = resp.accept_encoding {
+            self.compression_negotiated
+                .store(self.compression.accepted_by(&accept), Ordering::Relaxed);
+        }
+
+        let data = compression::decode_response(resp.content_encoding.as_deref(), resp.data)?;
+
+        // an empty body is a valid (default) protobuf message, so it must not be mistaken for a
+        // successful response when the server returned an error status
+        if data.is_empty() && !(200..300).contains(&resp.status) {
+            return Err(ApiError {
+                context: format!("request to {} failed", request.url),
+                message: format!("HTTP status {}", resp.status),
+                code: resp.status as i32,
             }
+            .into());
+        }
+
+        // servers which do not support JSON reply using protobuf, so trust the response's format
+        let wire_format = resp
+            .content_type
+            .as_deref()
+            .and_then(WireFormat::from_content_type)
+            .unwrap_or(self.wire_format);
+
+        Ok(Body {
+            url: request.url,
+            status: resp.status,
+            wire_format,
+            data,
+        })
+    }
+
+    #[cfg(not(all(feature = "wasm-client", target_arch = "wasm32")))]
+    async fn execute(&self, request: &middleware::Request, body: Vec<u8>) -> Result<RawResponse> {
+        let mut builder = self
+            .inner
+            .request(request.method.clone(), &request.url)
+            .header(header::ACCEPT_ENCODING, compression::ACCEPT_ENCODING);
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+
+        let resp = builder.body(body).send().await?;
+        let status = resp.status().as_u16();
+        let header_value = |name| {
+            resp.headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(String::from)
+        };
+        let accept_encoding = header_value(header::ACCEPT_ENCODING);
+        let content_encoding = header_value(header::CONTENT_ENCODING);
+        let content_type = header_value(header::CONTENT_TYPE);
+
+        Ok(RawResponse {
+            status,
+            accept_encoding,
+            content_encoding,
+            content_type,
+            data: resp.bytes().await?.to_vec(),
+        })
+    }
+
+    /// Send the request using the browser's `fetch` API. The browser manages `Accept-Encoding`
+    /// itself (it is a forbidden header name), and transparently decodes compressed responses.
+    #[cfg(all(feature = "wasm-client", target_arch = "wasm32"))]
+    async fn execute(&self, request: &middleware::Request, body: Vec<u8>) -> Result<RawResponse> {
+        let mut builder =
+            gloo_net::http::RequestBuilder::new(&request.url).method(request.method.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        // fetch rejects GET requests with a body, and empty messages need not be sent at all
+        let built = if body.is_empty() {
+            builder.build()
+        } else {
+            builder.body(js_sys::Uint8Array::from(body.as_slice()))
+        };
+        let resp = built
+            .map_err(|e| anyhow::anyhow!("{}", e))?
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+        Ok(RawResponse {
+            status: resp.status(),
+            accept_encoding: resp.headers().get(header::ACCEPT_ENCODING.as_str()),
+            // fetch has already decoded the body, regardless of the header it reports
+            content_encoding: None,
+            content_type: resp.headers().get(header::CONTENT_TYPE.as_str()),
+            data: resp.binary().await.map_err(|e| anyhow::anyhow!("{}", e))?,
+        })
+    }
+
+    /// Send an audit request, returning the decoded reports and module hashes, keyed by module ID.
+    async fn audit(
+        &self,
+        audit: Audit,
+        validation: Option<api::Checkfile>,
+    ) -> Result<(HashMap<i64, Report>, HashMap<i64, String>)> {
+        let mut pagination: api::Pagination = Default::default();
+        pagination.limit = audit.page.limit;
+        pagination.offset = audit.page.offset;
+
+        let req = api::AuditModulesRequest {
+            outcome: EnumOrUnknown::new(api::AuditOutcome::from(audit.outcome)),
+            pagination: MessageField::some(pagination),
+            checkfile: audit.checkfile,
+            validation: validation.into(),
+            ..Default::default()
+        };
+
+        let res: api::AuditModulesResponse = self.send(ModserverCommand::AuditModules(req)).await?;
+        if res.error.is_some() {
+            return Err(api_error(res.error, "audit modules request failed"));
         }
+
+        let mut id_reports: HashMap<i64, Report> = Default::default();
+        res.invalid_module_report
+            .iter()
+            .for_each(|(id, json_report)| {
+                if let Ok(report) = serde_json::from_slice(json_report) {
+                    let _ = id_reports.insert(*id, report);
+                } else {
+                    log::error!("failed to decode validation report for module {}", id);
+                }
+            });
+
+        Ok((id_reports, res.module_id_hash))
     }
 
     fn make_endpoint(&self, route: &str) -> String {
@@ -557,11 +903,137 @@ impl Client {
     }
 }
 
+/// A builder to configure and construct an API `Client`.
+#[derive(Default)]
+pub struct ClientBuilder {
+    base_url: Option<String>,
+    compression: Compression,
+    wire_format: WireFormat,
+    on_request: Vec<RequestHook>,
+    on_response: Vec<ResponseHook>,
+    concurrency: Option<usize>,
+    #[cfg(not(target_arch = "wasm32"))]
+    record_dir: Option<PathBuf>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy: Option<String>,
+}
+
+impl ClientBuilder {
+    /// Set the server host address and port of the Modsurfer backend, e.g. http://localhost:1739.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the preferred encoding for request bodies. Bodies are only compressed after the server
+    /// advertises support for the encoding via an `Accept-Encoding` response header. Defaults to
+    /// `Compression::Gzip`.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the encoding of request and response bodies. Defaults to `WireFormat::Protobuf`; use
+    /// `WireFormat::Json` for servers exposing JSON endpoints which are easier to debug.
+    pub fn wire_format(mut self, wire_format: WireFormat) -> Self {
+        self.wire_format = wire_format;
+        self
+    }
+
+    /// Register a hook which is called with every request before it is sent, in the order hooks
+    /// are registered. Hooks may add headers, e.g. for authentication or trace propagation.
+    pub fn on_request(
+        mut self,
+        hook: impl Fn(&mut middleware::Request) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_request.push(Arc::new(hook));
+        self
+    }
+
+    /// Register a hook which is called with every response received, in the order hooks are
+    /// registered. Responses are passed to hooks before the body is decoded.
+    pub fn on_response(
+        mut self,
+        hook: impl Fn(&middleware::Response) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_response.push(Arc::new(hook));
+        self
+    }
+
+    /// Set the maximum number of requests sent concurrently by operations which fetch many
+    /// modules, e.g. to look up the modules in an audit. Defaults to `DEFAULT_CONCURRENCY`, and is
+    /// at least 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency.max(1));
+        self
+    }
+
+    /// Record every response received by the `Client` into `dir`, to later be replayed by the mock
+    /// client (see the `fixtures` module). Defaults to the `MODSURFER_RECORD_DIR` environment
+    /// variable, if set.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn record_to(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.record_dir = Some(dir.into());
+        self
+    }
+
+    /// Send every request through the proxy at this URL, except requests to hosts listed in the
+    /// `NO_PROXY` environment variable. Without a proxy set, the standard `HTTP_PROXY`,
+    /// `HTTPS_PROXY` and `ALL_PROXY` environment variables are used.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Construct the `Client` from the builder's configuration.
+    pub fn build(self) -> Result<Client> {
+        let base_url = self
+            .base_url
+            .ok_or_else(|| anyhow::anyhow!("a base URL is required to build a Client"))?;
+
+        #[cfg(not(all(feature = "wasm-client", target_arch = "wasm32")))]
+        #[allow(unused_mut)]
+        let mut inner = reqwest::ClientBuilder::new();
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(url) = &self.proxy {
+            let proxy = reqwest::Proxy::all(url)
+                .map_err(|e| anyhow::anyhow!("invalid proxy `{}`: {}", url, e))?
+                .no_proxy(reqwest::NoProxy::from_env());
+            inner = inner.proxy(proxy);
+        }
+        #[cfg(not(all(feature = "wasm-client", target_arch = "wasm32")))]
+        let inner = inner.build().map_err(|e| anyhow::anyhow!("{}", e))?;
+
+        Ok(Client {
+            #[cfg(not(all(feature = "wasm-client", target_arch = "wasm32")))]
+            inner,
+            base_url,
+            compression: self.compression,
+            compression_negotiated: Arc::new(AtomicBool::new(false)),
+            wire_format: self.wire_format,
+            on_request: self.on_request,
+            on_response: self.on_response,
+            concurrency: self.concurrency.unwrap_or(DEFAULT_CONCURRENCY),
+            #[cfg(not(target_arch = "wasm32"))]
+            recorder: self
+                .record_dir
+                .or_else(|| std::env::var_os(fixtures::RECORD_DIR_ENV).map(PathBuf::from))
+                .map(Recorder::new),
+        })
+    }
+}
+
 fn api_error(
     error: protobuf::MessageField<modsurfer_convert::api::Error>,
     msg: &str,
 ) -> anyhow::Error {
     let e = error.get_or_default();
 
-    return anyhow::anyhow!("{}: {} [{}]", msg, e.message, e.code);
+    ApiError {
+        context: msg.to_string(),
+        message: e.message.clone(),
+        code: e.code,
+    }
+    .into()
 }
@@ -0,0 +1,83 @@
+use std::io::{Read, Write};
+
+use anyhow::Result;
+
+/// Requests smaller than this are sent uncompressed, as the encoding overhead outweighs any gain.
+const MIN_COMPRESSED_BODY_LEN: usize = 1024;
+
+/// The content-encoding used for request bodies sent to a Modsurfer backend. Responses are always
+/// accepted in any of the supported encodings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    #[default]
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// The `Content-Encoding` token for this compression, if any.
+    pub fn as_header_value(&self) -> Option<&'static str> {
+        match self {
+            Compression::None => None,
+            Compression::Gzip => Some("gzip"),
+            Compression::Zstd => Some("zstd"),
+        }
+    }
+
+    /// Determine if the server advertised support for this encoding, using the `Accept-Encoding`
+    /// header it returned on a response (see: <https://www.rfc-editor.org/rfc/rfc7694>).
+    pub fn accepted_by(&self, accept_encoding: &str) -> bool {
+        match self.as_header_value() {
+            Some(token) => accept_encoding
+                .split(',')
+                .map(|enc| enc.split(';').next().unwrap_or_default().trim())
+                .any(|enc| enc.eq_ignore_ascii_case(token)),
+            None => false,
+        }
+    }
+
+    /// Compress a request body, returning `None` when the body should be sent as-is.
+    pub fn encode(&self, body: &[u8]) -> Result<Option<Vec<u8>>> {
+        if body.len() < MIN_COMPRESSED_BODY_LEN {
+            return Ok(None);
+        }
+
+        match self {
+            Compression::None => Ok(None),
+            Compression::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(body)?;
+                Ok(Some(encoder.finish()?))
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            Compression::Zstd => Ok(Some(zstd::encode_all(body, 0)?)),
+            #[cfg(target_arch = "wasm32")]
+            Compression::Zstd => Ok(None),
+        }
+    }
+}
+
+/// The `Accept-Encoding` value sent with every request. Gzip responses are decoded transparently
+/// by reqwest, zstd responses are decoded by `decode_response`.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const ACCEPT_ENCODING: &str = "gzip, zstd";
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm-client")))]
+pub(crate) const ACCEPT_ENCODING: &str = "gzip";
+
+/// Decode a response body which the server compressed using an encoding not handled by reqwest.
+pub(crate) fn decode_response(content_encoding: Option<&str>, data: Vec<u8>) -> Result<Vec<u8>> {
+    match content_encoding.map(str::trim) {
+        #[cfg(not(target_arch = "wasm32"))]
+        Some(enc) if enc.eq_ignore_ascii_case("zstd") => Ok(zstd::decode_all(data.as_slice())?),
+        Some(enc) if enc.eq_ignore_ascii_case("gzip") => {
+            // reqwest strips the header once it has decoded gzip, so this is only reached when a
+            // server double-encodes or reqwest was built without gzip support
+            let mut out = vec![];
+            flate2::read::GzDecoder::new(data.as_slice()).read_to_end(&mut out)?;
+            Ok(out)
+        }
+        _ => Ok(data),
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+/// An error reported by the Modsurfer server, either in the body of a response or as an HTTP
+/// error status, so that callers can tell e.g. authorization failures from other errors.
+#[derive(Debug)]
+pub struct ApiError {
+    /// what the client was doing, e.g. `get module request failed`
+    pub context: String,
+    pub message: String,
+    /// the error code from the server, which follows HTTP status codes
+    pub code: i32,
+}
+
+impl ApiError {
+    /// Whether the request was rejected for missing or insufficient credentials.
+    pub fn is_auth(&self) -> bool {
+        matches!(self.code, 401 | 403)
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} [{}]", self.context, self.message, self.code)
+    }
+}
+
+impl std::error::Error for ApiError {}
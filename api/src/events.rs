@@ -0,0 +1,143 @@
+use chrono::TimeZone;
+use modsurfer_convert::api;
+use protobuf::EnumOrUnknown;
+
+/// The number of seconds a subscription asks the server to wait for new events before returning.
+pub(crate) const SUBSCRIBE_WAIT_SECONDS: u32 = 30;
+
+/// The kinds of changes to modules reported by `ApiClient::subscribe`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModuleEventKind {
+    Created,
+    Updated,
+    Deleted,
+    Yanked,
+}
+
+impl ModuleEventKind {
+    pub fn to_proto(self) -> api::ModuleEventKind {
+        match self {
+            ModuleEventKind::Created => api::ModuleEventKind::CREATED,
+            ModuleEventKind::Updated => api::ModuleEventKind::UPDATED,
+            ModuleEventKind::Deleted => api::ModuleEventKind::DELETED,
+            ModuleEventKind::Yanked => api::ModuleEventKind::YANKED,
+        }
+    }
+
+    fn from_proto(kind: EnumOrUnknown<api::ModuleEventKind>) -> Option<ModuleEventKind> {
+        match kind.enum_value() {
+            Ok(api::ModuleEventKind::CREATED) => Some(ModuleEventKind::Created),
+            Ok(api::ModuleEventKind::UPDATED) => Some(ModuleEventKind::Updated),
+            Ok(api::ModuleEventKind::DELETED) => Some(ModuleEventKind::Deleted),
+            Ok(api::ModuleEventKind::YANKED) => Some(ModuleEventKind::Yanked),
+            Ok(api::ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED) | Err(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ModuleEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ModuleEventKind::Created => "created",
+            ModuleEventKind::Updated => "updated",
+            ModuleEventKind::Deleted => "deleted",
+            ModuleEventKind::Yanked => "yanked",
+        })
+    }
+}
+
+impl std::str::FromStr for ModuleEventKind {
+    type Err = anyhow::Error;
+
+    fn from_str(k: &str) -> anyhow::Result<Self> {
+        match k.to_lowercase().as_str() {
+            "created" => Ok(ModuleEventKind::Created),
+            "updated" => Ok(ModuleEventKind::Updated),
+            "deleted" => Ok(ModuleEventKind::Deleted),
+            "yanked" => Ok(ModuleEventKind::Yanked),
+            _ => anyhow::bail!(
+                "unknown event kind `{}`, expected one of: created, updated, deleted, yanked",
+                k
+            ),
+        }
+    }
+}
+
+/// A change to a module stored in Modsurfer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleEvent {
+    /// monotonically increasing ID of the event, which can be used to resume a subscription
+    pub id: u64,
+    pub kind: ModuleEventKind,
+    pub module_id: i64,
+    pub hash: String,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ModuleEvent {
+    /// Convert an event received from the server, skipping unset kinds and kinds unknown to this
+    /// client.
+    pub(crate) fn from_proto(event: api::ModuleEvent) -> Option<ModuleEvent> {
+        let occurred_at = event
+            .occurred_at
+            .as_ref()
+            .and_then(|t| {
+                chrono::Utc
+                    .timestamp_opt(t.seconds, t.nanos as u32)
+                    .single()
+            })
+            .unwrap_or_default();
+
+        Some(ModuleEvent {
+            id: event.id,
+            kind: ModuleEventKind::from_proto(event.kind)?,
+            module_id: event.module_id,
+            hash: event.hash,
+            occurred_at,
+        })
+    }
+}
+
+/// Select which events are delivered to a subscription. All provided criteria must match.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventFilter {
+    /// resume after this event ID, or only receive new events if unset
+    pub after_event_id: Option<u64>,
+    /// only receive events of these kinds (all kinds if empty)
+    pub kinds: Vec<ModuleEventKind>,
+    /// only receive events for modules having all of these tags
+    pub tags: Vec<String>,
+    /// only receive events for the module with this hash
+    pub hash: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_round_trip() {
+        for kind in [
+            ModuleEventKind::Created,
+            ModuleEventKind::Updated,
+            ModuleEventKind::Deleted,
+            ModuleEventKind::Yanked,
+        ] {
+            let proto = EnumOrUnknown::new(kind.to_proto());
+            assert_eq!(ModuleEventKind::from_proto(proto), Some(kind));
+        }
+    }
+
+    #[test]
+    fn unset_and_unknown_kinds_are_skipped() {
+        let event = api::ModuleEvent::default();
+        assert_eq!(
+            event.kind.enum_value(),
+            Ok(api::ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED)
+        );
+        assert_eq!(ModuleEvent::from_proto(event), None);
+
+        let unknown = EnumOrUnknown::from_i32(99);
+        assert_eq!(ModuleEventKind::from_proto(unknown), None);
+    }
+}
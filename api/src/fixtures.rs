@@ -0,0 +1,95 @@
+//! Record responses from a real Modsurfer server to disk, and replay them with the mock client.
+//! This lets downstream test suites pin realistic data rather than relying on the bundled fixture.
+//!
+//! Each response is written as `<dir>/<MessageName>-<n>.pb`, containing the raw protobuf bytes of
+//! the message, e.g. `ListModulesResponse-0.pb`.
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use modsurfer_convert::api;
+use modsurfer_module::Module;
+
+use crate::{page, Persisted};
+
+/// When set, a `Client` records every response it receives into this directory.
+pub const RECORD_DIR_ENV: &str = "MODSURFER_RECORD_DIR";
+
+/// When set, the mock `Client` replays modules from responses recorded into this directory.
+pub const REPLAY_DIR_ENV: &str = "MODSURFER_REPLAY_DIR";
+
+/// Writes response messages into a fixture directory.
+#[derive(Clone, Debug)]
+pub(crate) struct Recorder {
+    dir: PathBuf,
+}
+
+impl Recorder {
+    pub(crate) fn new(dir: impl Into<PathBuf>) -> Self {
+        Recorder { dir: dir.into() }
+    }
+
+    /// Write the bytes of a message to the next unused fixture file for its type.
+    pub(crate) fn record(&self, message_name: &str, data: &[u8]) -> Result<PathBuf> {
+        std::fs::create_dir_all(&self.dir)?;
+
+        for n in 0.. {
+            let path = self.dir.join(format!("{}-{}.pb", message_name, n));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    file.write_all(data)?;
+                    return Ok(path);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+/// Load every module contained in the recorded `ListModulesResponse`, `SearchModulesResponse` and
+/// `GetModuleResponse` fixtures within `dir`. Modules recorded more than once are deduplicated by
+/// their ID, and the result is ordered by ID.
+pub fn load_modules(dir: impl AsRef<Path>) -> Result<Vec<Persisted<Module>>> {
+    let mut modules: BTreeMap<i64, Persisted<Module>> = BTreeMap::new();
+    let mut insert = |m: api::Module| -> Result<()> {
+        let m: Persisted<Module> = m.try_into()?;
+        modules.insert(m.get_id(), m);
+        Ok(())
+    };
+
+    for entry in std::fs::read_dir(dir.as_ref())? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("pb") {
+            continue;
+        }
+
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        let message_name = stem.split('-').next().unwrap_or_default();
+        let data = std::fs::read(&path)?;
+
+        let parse_err = || format!("failed to parse fixture {}", path.display());
+        match message_name {
+            "ListModulesResponse" | "SearchModulesResponse" => {
+                page::for_each_module(&data, &mut insert).with_context(parse_err)?;
+            }
+            "GetModuleResponse" => {
+                let res: api::GetModuleResponse =
+                    protobuf::Message::parse_from_bytes(&data).with_context(parse_err)?;
+                if let Some(module) = res.module.into_option() {
+                    insert(module).with_context(parse_err)?;
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(modules.into_values().collect())
+}
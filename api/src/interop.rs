@@ -69,48 +69,44 @@ impl<T> AsRef<T> for Persisted<T> {
     }
 }
 
-impl From<api::Module> for Persisted<Module> {
-    fn from(a: api::Module) -> Self {
-        let metadata = if a.metadata.is_empty() {
-            None
-        } else {
-            Some(a.metadata)
-        };
-
-        #[cfg(not(target_arch = "wasm32"))]
-        let inserted_at: std::time::SystemTime = a
-            .inserted_at
-            .unwrap_or_else(|| protobuf::well_known_types::timestamp::Timestamp::new())
-            .into();
-
-        #[cfg(target_arch = "wasm32")]
-        let inserted_at: u64 = a.inserted_at.seconds as u64;
+/// Information about a Modsurfer server, describing its version and the API features it supports.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServerInfo {
+    pub version: String,
+    pub features: Vec<String>,
+    pub plugins_enabled: bool,
+}
 
-        Persisted {
-            inner: Module {
-                hash: a.hash,
-                imports: from_api::imports(a.imports),
-                exports: from_api::exports(a.exports),
-                size: a.size as u64,
-                location: a.location,
-                source_language: from_api::source_language(
-                    a.source_language.enum_value_or_default(),
-                ),
-                metadata,
-                #[cfg(not(target_arch = "wasm32"))]
-                inserted_at: inserted_at.into(),
-                #[cfg(target_arch = "wasm32")]
-                inserted_at: inserted_at,
-                strings: a.strings,
-                complexity: a.complexity,
-                graph: a.graph,
-                function_hashes: a.function_hashes,
-            },
-            source_id: a.id,
+impl ServerInfo {
+    /// Check if the server advertised support for an API feature, e.g. "audit".
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features
+            .iter()
+            .any(|f| f.eq_ignore_ascii_case(feature))
+    }
+}
+
+impl From<api::ServerInfoResponse> for ServerInfo {
+    fn from(res: api::ServerInfoResponse) -> Self {
+        ServerInfo {
+            version: res.version,
+            features: res.features,
+            plugins_enabled: res.plugins_enabled,
         }
     }
 }
 
+impl TryFrom<api::Module> for Persisted<Module> {
+    type Error = anyhow::Error;
+
+    fn try_from(a: api::Module) -> Result<Self, Self::Error> {
+        Ok(Persisted {
+            source_id: a.id,
+            inner: from_api::module(a)?,
+        })
+    }
+}
+
 #[cfg(feature = "mock")]
 impl<T> Persisted<T> {
     /// Convert a non-persisted object into one usable by API operations which expect IDs. Only
@@ -1,12 +1,36 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+
+mod audit;
+
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
 
 #[cfg(not(feature = "mock"))]
 mod client;
 
+mod compression;
+
+mod error;
+
+mod events;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fixtures;
+
 mod interop;
 
+pub mod middleware;
+
+mod page;
+
+mod query;
+
 mod sort;
 
+mod stats;
+
+mod wire_format;
+
 #[cfg(feature = "mock")]
 mod mock_client;
 
@@ -14,16 +38,40 @@ mod mock_client;
 pub use mock_client::Client;
 
 #[cfg(not(feature = "mock"))]
-pub use client::Client;
+pub use client::{Client, ClientBuilder};
 
-pub use interop::{List, Persisted};
+pub use audit::{AuditReports, CheckfileSelector, ModuleReport};
+pub use compression::Compression;
+pub use error::ApiError;
+pub use events::{EventFilter, ModuleEvent, ModuleEventKind};
+
+pub use interop::{List, Persisted, ServerInfo};
+pub use query::ModuleQuery;
 pub use sort::{SortDirection, SortField};
+pub use stats::{
+    ComplexityDistribution, NamespaceCount, NamespaceUsage, RegistryStats, SizeBucket,
+};
+pub use wire_format::WireFormat;
 
 pub use anyhow::Result;
 use async_trait::async_trait;
-use modsurfer_convert::Audit;
-use modsurfer_module::{Export, Import, Module};
-use modsurfer_validation::Report;
+use futures::{stream::LocalBoxStream, StreamExt, TryStreamExt};
+use modsurfer_convert::{Audit, AuditOutcome, Pagination};
+use modsurfer_module::{Export, Import, Module, Quarantine};
+use modsurfer_validation::{Report, Validation};
+
+/// The maximum number of search results inspected to find an exact match for a module hash.
+const HASH_LOOKUP_LIMIT: u32 = 50;
+
+/// The maximum number of concurrent requests made by `ApiClient::validate_many`.
+const VALIDATE_CONCURRENCY: usize = 8;
+
+/// The default maximum number of concurrent requests made when fetching many modules, see
+/// `ApiClient::concurrency`.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// The page size used when collecting every module matching a `ModuleQuery`.
+const QUERY_PAGE_SIZE: u32 = 100;
 
 /// A trait to describe the functionality of Modsurfer's internal API client. This is used across
 /// the CLI and GUI application. As such, the code must compile to `wasm32-unknown-unknown` target.
@@ -33,12 +81,28 @@ pub trait ApiClient {
     where
         Self: Sized;
     async fn get_module(&self, module_id: i64) -> Result<Persisted<Module>>;
+    /// The maximum number of requests sent concurrently by operations which fetch many modules,
+    /// such as `get_modules` and audits.
+    fn concurrency(&self) -> usize {
+        DEFAULT_CONCURRENCY
+    }
+    /// Fetch many modules by their IDs, sending up to `concurrency` requests at a time. Modules are
+    /// returned in the same order as their IDs, or the first error encountered.
+    async fn get_modules(&self, module_ids: Vec<i64>) -> Result<Vec<Persisted<Module>>> {
+        futures::stream::iter(module_ids)
+            .map(|module_id| self.get_module(module_id))
+            .buffered(self.concurrency())
+            .try_collect()
+            .await
+    }
     async fn list_modules(&self, offset: u32, limit: u32) -> Result<List<Persisted<Module>>>;
     async fn create_module(
         &self,
         wasm: impl AsRef<[u8]> + Send,
         metadata: Option<HashMap<String, String>>,
         location: Option<url::Url>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
     ) -> Result<(i64, String)>;
     async fn search_modules(
         &self,
@@ -56,17 +120,261 @@ pub trait ApiClient {
         inserted_before: Option<chrono::DateTime<chrono::Utc>>,
         inserted_after: Option<chrono::DateTime<chrono::Utc>>,
         strings: Option<Vec<String>>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
+        min_complexity: Option<u32>,
+        max_complexity: Option<u32>,
+        namespaces: Option<Vec<String>>,
         offset: u32,
         limit: u32,
         sort_field: Option<SortField>,
         sort_direction: Option<SortDirection>,
     ) -> Result<List<Persisted<Module>>>;
+    /// Find a module by its SHA-256 hash. Unlike `search_modules`, which may match on partial
+    /// hashes, this only returns a module whose hash is exactly equal to `hash`. If the same wasm
+    /// has been stored more than once, the most recently inserted entry is returned.
+    async fn get_module_by_hash(&self, hash: &str) -> Result<Persisted<Module>> {
+        let hash = hash.trim().to_lowercase();
+        if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            anyhow::bail!("Invalid SHA-256 hash: {}", hash);
+        }
+
+        let list = self
+            .search_modules(
+                None,
+                Some(hash.clone()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                0,
+                HASH_LOOKUP_LIMIT,
+                Some(SortField::CreatedAt),
+                Some(SortDirection::Desc),
+            )
+            .await?;
+
+        list.vec()
+            .into_iter()
+            .find(|m| m.get_inner().hash.eq_ignore_ascii_case(&hash))
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No module found with hash {}.", hash))
+    }
+    async fn tag_module(&self, _module_id: i64, _tags: Vec<String>) -> Result<Vec<String>> {
+        anyhow::bail!("Tag operation unimplemented.")
+    }
+    /// Flag a module as blocked, e.g. during incident response, without deleting or yanking it.
+    async fn quarantine_module(&self, _module_id: i64, _reason: String) -> Result<Quarantine> {
+        anyhow::bail!("Quarantine operation unimplemented.")
+    }
+    /// Lift the quarantine of a module.
+    async fn unquarantine_module(&self, _module_id: i64) -> Result<()> {
+        anyhow::bail!("Quarantine operation unimplemented.")
+    }
     async fn delete_modules(&self, _module_ids: Vec<i64>) -> Result<HashMap<i64, String>> {
         anyhow::bail!("Delete operation unimplemented.")
     }
+    /// Collect every module matching the `query`, paging through the search results.
+    async fn find_modules(&self, query: &ModuleQuery) -> Result<Vec<Persisted<Module>>> {
+        let mut found = vec![];
+        let mut offset = 0;
+        loop {
+            let page = self
+                .search_modules(
+                    None,
+                    query.hash.clone(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    if query.metadata.is_empty() {
+                        None
+                    } else {
+                        Some(query.metadata.clone())
+                    },
+                    query.inserted_before,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    offset,
+                    QUERY_PAGE_SIZE,
+                    Some(SortField::CreatedAt),
+                    Some(SortDirection::Asc),
+                )
+                .await?;
+
+            let (modules, _, _, total) = page.split();
+            let count = modules.len() as u32;
+            found.extend(
+                modules
+                    .into_iter()
+                    .filter(|m| query.matches(m.get_inner()))
+                    .cloned(),
+            );
+
+            offset += count;
+            if count == 0 || offset >= total {
+                break;
+            }
+        }
+
+        Ok(found)
+    }
+    /// Delete every module matching the `query`, returning the IDs and hashes of the deleted
+    /// modules. When `dry_run` is true, nothing is deleted and the modules which would have been
+    /// removed are returned instead. An empty query is rejected rather than deleting everything.
+    async fn delete_modules_matching(
+        &self,
+        query: &ModuleQuery,
+        dry_run: bool,
+    ) -> Result<HashMap<i64, String>> {
+        if query.is_empty() {
+            anyhow::bail!("Refusing to delete modules using an empty query.")
+        }
+
+        let matched: HashMap<i64, String> = self
+            .find_modules(query)
+            .await?
+            .into_iter()
+            .map(|m| (m.get_id(), m.into_inner().hash))
+            .collect();
+
+        if dry_run || matched.is_empty() {
+            return Ok(matched);
+        }
+
+        self.delete_modules(matched.into_keys().collect()).await
+    }
     async fn audit_modules(&self, _audit: Audit) -> Result<HashMap<i64, Report>> {
         anyhow::bail!("Audit operation unimplemented.")
     }
+    /// Audit the modules using a structured `Validation`, which is serialized into a checkfile for
+    /// the server. Each report is returned along with the audited module's ID and hash.
+    async fn audit_modules_with(
+        &self,
+        validation: &Validation,
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        let audit = Audit {
+            checkfile: audit::checkfile_bytes(validation)?,
+            page,
+            outcome,
+        };
+
+        let (module_ids, reports): (Vec<_>, Vec<_>) =
+            self.audit_modules(audit).await?.into_iter().unzip();
+        let modules = self.get_modules(module_ids).await?;
+        let reports = modules
+            .into_iter()
+            .zip(reports)
+            .map(|(module, report)| ModuleReport {
+                module_id: module.get_id(),
+                hash: module.into_inner().hash,
+                checkfile: None,
+                report,
+            })
+            .collect();
+
+        Ok(AuditReports::new(reports))
+    }
+    /// Audit a page of modules, validating each against the checkfile of the first selector which
+    /// matches it. Modules matching no selector are skipped, so end the selectors with one having
+    /// an empty query to apply a default checkfile. Validation is performed locally, using the
+    /// module data stored in the registry, which is listed using concurrent requests.
+    async fn audit_modules_selected(
+        &self,
+        selectors: &[CheckfileSelector],
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        let end = page.offset.saturating_add(page.limit);
+        let mut pages = futures::stream::iter(
+            (page.offset..end)
+                .step_by(QUERY_PAGE_SIZE as usize)
+                .map(|offset| self.list_modules(offset, QUERY_PAGE_SIZE.min(end - offset))),
+        )
+        .buffered(self.concurrency());
+
+        let mut reports = vec![];
+        while let Some(modules) = pages.next().await {
+            let modules = modules?;
+            if modules.vec().is_empty() {
+                break;
+            }
+
+            for m in modules.vec() {
+                let module = m.get_inner();
+                let Some(selector) = selectors.iter().find(|s| s.matches(module)) else {
+                    continue;
+                };
+
+                let report =
+                    modsurfer_validation::validate(selector.validation.clone(), module.clone())?;
+                let keep = match outcome {
+                    AuditOutcome::Pass => !report.has_failures(),
+                    AuditOutcome::Fail => report.has_failures(),
+                };
+                if keep {
+                    reports.push(ModuleReport {
+                        module_id: m.get_id(),
+                        hash: module.hash.clone(),
+                        checkfile: Some(selector.name.clone()),
+                        report,
+                    });
+                }
+            }
+        }
+
+        Ok(AuditReports::new(reports))
+    }
+    /// Audit the modules like `audit_modules_with`, but validate them locally instead of on the
+    /// server. Use this with servers which don't support the audit endpoint.
+    async fn audit_modules_locally(
+        &self,
+        validation: &Validation,
+        outcome: AuditOutcome,
+        page: Pagination,
+    ) -> Result<AuditReports> {
+        let selector = CheckfileSelector {
+            name: String::new(),
+            query: ModuleQuery::default(),
+            validation: validation.clone(),
+        };
+        let reports = self
+            .audit_modules_selected(&[selector], outcome, page)
+            .await?
+            .into_inner()
+            .into_iter()
+            .map(|r| ModuleReport {
+                checkfile: None,
+                ..r
+            })
+            .collect();
+
+        Ok(AuditReports::new(reports))
+    }
     async fn diff_modules(
         &self,
         module1: i64,
@@ -81,6 +389,36 @@ pub trait ApiClient {
     ) -> Result<Report> {
         anyhow::bail!("Validate operation unimplemented.")
     }
+    /// Validate many named modules against the same checkfile on the server, sending requests
+    /// concurrently. Results are returned in the same order as the input modules, and a failure to
+    /// validate one module does not prevent the others from being validated.
+    async fn validate_many(
+        &self,
+        modules: Vec<(String, Vec<u8>)>,
+        checkfile: impl AsRef<[u8]> + Send,
+    ) -> Vec<(String, Result<Report>)> {
+        self.validate_each(modules, checkfile.as_ref().to_vec())
+            .collect()
+            .await
+    }
+    /// Like `validate_many`, but yield each result as soon as it (and every result before it) is
+    /// available, e.g. to report progress.
+    fn validate_each(
+        &self,
+        modules: Vec<(String, Vec<u8>)>,
+        checkfile: Vec<u8>,
+    ) -> LocalBoxStream<'_, (String, Result<Report>)> {
+        futures::stream::iter(modules)
+            .map(move |(name, wasm)| {
+                let checkfile = checkfile.clone();
+                async move {
+                    let report = self.validate_module(wasm, checkfile).await;
+                    (name, report)
+                }
+            })
+            .buffered(VALIDATE_CONCURRENCY)
+            .boxed_local()
+    }
     async fn get_module_graph(&self, _module_id: i64) -> Result<Vec<u8>> {
         anyhow::bail!("ModuleGraph operation unimplemented.")
     }
@@ -104,4 +442,150 @@ pub trait ApiClient {
     async fn uninstall_plugin(&self, _identifier: String) -> Result<()> {
         anyhow::bail!("UninstallPlugin operation unimplemented.")
     }
+    /// Fetch the module events matching the `filter`, waiting (long-polling) for up to
+    /// `wait_seconds` if none are available yet. Returns the events along with the ID of the latest
+    /// event known to the server, which should be used as `after_event_id` in the next poll.
+    async fn poll_events(
+        &self,
+        _filter: &EventFilter,
+        _wait_seconds: u32,
+    ) -> Result<(Vec<ModuleEvent>, u64)> {
+        anyhow::bail!("PollEvents operation unimplemented.")
+    }
+    /// Subscribe to module events matching the `filter`, as a stream which long-polls the server.
+    /// The stream ends after yielding an error; resume it by subscribing again with
+    /// `after_event_id` set to the ID of the last event received.
+    fn subscribe(&self, filter: EventFilter) -> LocalBoxStream<'_, Result<ModuleEvent>> {
+        let state = (filter, VecDeque::new(), false);
+        futures::stream::unfold(state, move |(mut filter, mut pending, done)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Some((Ok(event), (filter, pending, done)));
+                }
+                if done {
+                    return None;
+                }
+
+                match self
+                    .poll_events(&filter, events::SUBSCRIBE_WAIT_SECONDS)
+                    .await
+                {
+                    Ok((events, last_event_id)) => {
+                        filter.after_event_id = Some(last_event_id);
+                        pending.extend(events);
+                    }
+                    Err(e) => return Some((Err(e), (filter, pending, true))),
+                }
+            }
+        })
+        .boxed_local()
+    }
+    async fn health(&self) -> Result<()> {
+        anyhow::bail!("Health operation unimplemented.")
+    }
+    async fn server_info(&self) -> Result<ServerInfo> {
+        anyhow::bail!("ServerInfo operation unimplemented.")
+    }
+    /// Find the modules in the registry whose function hashes have a Jaccard similarity of at
+    /// least `threshold` with the `module`'s, most similar first. See `Module::similarity`.
+    async fn similar_modules(
+        &self,
+        module: &Module,
+        threshold: f64,
+    ) -> Result<Vec<(Persisted<Module>, f64)>> {
+        let mut similar = vec![];
+        let mut offset = 0;
+        loop {
+            let page = self.list_modules(offset, QUERY_PAGE_SIZE).await?;
+            let (modules, _, _, total) = page.split();
+            let count = modules.len() as u32;
+            similar.extend(modules.into_iter().filter_map(|m| {
+                let similarity = module.similarity(m.get_inner());
+                (similarity >= threshold).then(|| (m.clone(), similarity))
+            }));
+
+            offset += count;
+            if count == 0 || offset >= total {
+                break;
+            }
+        }
+
+        similar.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(similar)
+    }
+    /// Compute statistics about every module in the registry, paging through the module list.
+    async fn registry_stats(&self) -> Result<RegistryStats> {
+        let mut stats = RegistryStats::default();
+        let mut offset = 0;
+        loop {
+            let page = self.list_modules(offset, QUERY_PAGE_SIZE).await?;
+            let (modules, _, _, total) = page.split();
+            let count = modules.len() as u32;
+            modules.into_iter().for_each(|m| stats.add(m.get_inner()));
+
+            offset += count;
+            if count == 0 || offset >= total {
+                break;
+            }
+        }
+
+        Ok(stats)
+    }
+    /// Count the modules importing from each namespace, and each function in it, paging through
+    /// the search results. Given a `namespace`, only the modules importing from it are searched,
+    /// and only it is counted.
+    async fn namespace_usage(&self, namespace: Option<&str>) -> Result<NamespaceUsage> {
+        let mut usage = NamespaceUsage::default();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .search_modules(
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    namespace.map(|ns| vec![ns.to_string()]),
+                    offset,
+                    QUERY_PAGE_SIZE,
+                    Some(SortField::CreatedAt),
+                    Some(SortDirection::Asc),
+                )
+                .await?;
+
+            let (modules, _, _, total) = page.split();
+            let count = modules.len() as u32;
+            // the server may match namespaces loosely, so each module is checked again
+            modules
+                .into_iter()
+                .map(|m| m.get_inner())
+                .filter(|m| {
+                    namespace.is_none_or(|ns| m.imports.iter().any(|i| i.module_name == ns))
+                })
+                .for_each(|m| usage.add(m));
+
+            offset += count;
+            if count == 0 || offset >= total {
+                break;
+            }
+        }
+        if let Some(namespace) = namespace {
+            usage.namespaces.retain(|ns, _| ns == namespace);
+        }
+
+        Ok(usage)
+    }
 }
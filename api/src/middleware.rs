@@ -0,0 +1,89 @@
+//! Hooks to observe and modify the requests made by a `Client`, registered using
+//! `ClientBuilder::on_request` and `ClientBuilder::on_response`. Use these to add authentication
+//! headers, record metrics, or propagate distributed tracing context.
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use reqwest::Method;
+
+pub(crate) type RequestHook = Arc<dyn Fn(&mut Request) + Send + Sync>;
+pub(crate) type ResponseHook = Arc<dyn Fn(&Response) + Send + Sync>;
+
+/// An outgoing request, passed to each `on_request` hook before it is sent.
+#[derive(Debug)]
+pub struct Request {
+    pub(crate) method: Method,
+    pub(crate) route: &'static str,
+    pub(crate) url: String,
+    pub(crate) body_len: usize,
+    pub(crate) headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// The HTTP method used for the request.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// The API route of the request, e.g. `/api/v1/module`.
+    pub fn route(&self) -> &str {
+        self.route
+    }
+
+    /// The full URL the request will be sent to.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The size of the (possibly compressed) request body in bytes.
+    pub fn body_len(&self) -> usize {
+        self.body_len
+    }
+
+    /// The headers which will be added to the request.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Add a header to the request, e.g. `Authorization`.
+    pub fn insert_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.headers.push((name.into(), value.into()));
+    }
+}
+
+/// A completed response, passed to each `on_response` hook before it is decoded.
+#[derive(Debug)]
+pub struct Response {
+    pub(crate) method: Method,
+    pub(crate) route: &'static str,
+    pub(crate) status: u16,
+    pub(crate) latency: Duration,
+    pub(crate) body_len: usize,
+}
+
+impl Response {
+    /// The HTTP method used for the request.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// The API route of the request, e.g. `/api/v1/module`.
+    pub fn route(&self) -> &str {
+        self.route
+    }
+
+    /// The HTTP status code returned by the server.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The time between sending the request and receiving the full response body.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// The size of the response body in bytes, as received.
+    pub fn body_len(&self) -> usize {
+        self.body_len
+    }
+}
@@ -5,38 +5,68 @@ use async_trait::async_trait;
 
 use lazy_static::lazy_static;
 
-#[cfg(not(feature = "mock-empty"))]
-use modsurfer_convert::api::ListModulesResponse;
-
-use modsurfer_module::{Export, Import, Module, SourceLanguage};
+use modsurfer_module::{Export, Import, Module, Quarantine, SourceLanguage};
+use modsurfer_validation::Report;
 use url::Url;
 
-use crate::{ApiClient, List, Persisted, SortDirection, SortField};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::fixtures;
+#[cfg(not(feature = "mock-empty"))]
+use crate::page::ModulePage;
+use crate::{ApiClient, List, Persisted, ServerInfo, SortDirection, SortField};
 
 #[cfg(not(feature = "mock-empty"))]
+static MODULES: &[u8] = include_bytes!("../ListModulesResponse.pb");
+
 lazy_static! {
-    static ref MODULES: &'static [u8] = include_bytes!("../ListModulesResponse.pb");
-    static ref PB_DATA: ListModulesResponse =
-        protobuf::Message::parse_from_bytes(&MODULES).unwrap();
-    static ref MOCK_CLIENT_DATA: Mutex<Vec<Persisted<Module>>> = Mutex::new(
-        PB_DATA
-            .modules
-            .clone()
-            .into_iter()
-            .map(Into::into)
-            .collect::<Vec<Persisted<Module>>>()
-    );
+    static ref MOCK_CLIENT_DATA: Mutex<Vec<Persisted<Module>>> = Mutex::new(initial_modules());
+}
+
+#[cfg(not(feature = "mock-empty"))]
+fn bundled_modules() -> Vec<Persisted<Module>> {
+    ModulePage::decode(MODULES).unwrap().modules
 }
 
 #[cfg(feature = "mock-empty")]
-lazy_static! {
-    static ref MOCK_CLIENT_DATA: Mutex<Vec<Persisted<Module>>> = Mutex::new(vec![]);
+fn bundled_modules() -> Vec<Persisted<Module>> {
+    vec![]
+}
+
+/// Use modules replayed from recorded fixtures if `MODSURFER_REPLAY_DIR` is set, otherwise the
+/// bundled data.
+#[cfg(not(target_arch = "wasm32"))]
+fn initial_modules() -> Vec<Persisted<Module>> {
+    match std::env::var_os(fixtures::REPLAY_DIR_ENV) {
+        Some(dir) => fixtures::load_modules(&dir).unwrap_or_else(|e| {
+            panic!(
+                "failed to replay fixtures from {}: {}",
+                dir.to_string_lossy(),
+                e
+            )
+        }),
+        None => bundled_modules(),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn initial_modules() -> Vec<Persisted<Module>> {
+    bundled_modules()
 }
 
 #[derive(Clone, Default)]
 pub struct Client;
 
 impl Client {
+    /// Construct a mock client which replays the modules recorded into `dir` by a real `Client`.
+    /// NOTE: the mock data is shared, so this replaces the modules seen by every mock client.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn replay(dir: impl AsRef<std::path::Path>) -> Result<Self> {
+        let modules = fixtures::load_modules(dir)?;
+        *MOCK_CLIENT_DATA.lock().unwrap() = modules;
+
+        Ok(Self)
+    }
+
     pub fn modules(&self) -> Vec<Persisted<Module>> {
         MOCK_CLIENT_DATA.lock().unwrap().to_vec()
     }
@@ -84,10 +114,14 @@ impl ApiClient for Client {
         _wasm: impl AsRef<[u8]> + Send,
         metadata: Option<HashMap<String, String>>,
         _location: Option<Url>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
     ) -> Result<(i64, String)> {
         let mut module = Module::default();
 
         module.metadata = metadata;
+        module.tags = tags.unwrap_or_default();
+        module.version = version;
 
         let id = (MOCK_CLIENT_DATA.lock().unwrap().len() + 1) as i64;
         let hash = module.hash.clone();
@@ -115,6 +149,11 @@ impl ApiClient for Client {
         _inserted_before: Option<chrono::DateTime<chrono::Utc>>,
         _inserted_after: Option<chrono::DateTime<chrono::Utc>>,
         strings: Option<Vec<String>>,
+        tags: Option<Vec<String>>,
+        version: Option<String>,
+        min_complexity: Option<u32>,
+        max_complexity: Option<u32>,
+        namespaces: Option<Vec<String>>,
         offset: u32,
         limit: u32,
         _sort_field: Option<SortField>,
@@ -155,12 +194,7 @@ impl ApiClient for Client {
         if let Some(module_name) = module_name {
             filtered = filtered
                 .into_iter()
-                .filter(|p| {
-                    p.get_inner()
-                        .imports
-                        .iter()
-                        .any(|i| i.module_name == module_name)
-                })
+                .filter(|p| p.get_inner().imports_in(&module_name).next().is_some())
                 .collect();
         }
 
@@ -183,10 +217,80 @@ impl ApiClient for Client {
                 .collect();
         }
 
+        if let Some(tags) = tags {
+            filtered = filtered
+                .into_iter()
+                .filter(|p| tags.iter().all(|t| p.get_inner().tags.contains(t)))
+                .collect();
+        }
+
+        if let Some(version) = version {
+            filtered = filtered
+                .into_iter()
+                .filter(|p| p.get_inner().version.as_ref() == Some(&version))
+                .collect();
+        }
+
+        if min_complexity.is_some() || max_complexity.is_some() {
+            filtered = filtered
+                .into_iter()
+                .filter(|p| match p.get_inner().complexity {
+                    Some(c) => {
+                        min_complexity.map_or(true, |min| c >= min)
+                            && max_complexity.map_or(true, |max| c <= max)
+                    }
+                    None => false,
+                })
+                .collect();
+        }
+
+        if let Some(namespaces) = namespaces {
+            filtered = filtered
+                .into_iter()
+                .filter(|p| {
+                    namespaces
+                        .iter()
+                        .all(|ns| p.get_inner().imports_in(ns).next().is_some())
+                })
+                .collect();
+        }
+
         let total = filtered.len() as u32;
         Ok(List::new(filtered, total, offset, limit))
     }
 
+    async fn tag_module(&self, module_id: i64, tags: Vec<String>) -> Result<Vec<String>> {
+        let mut modules = MOCK_CLIENT_DATA.lock().unwrap();
+        match modules.iter_mut().find(|m| m.get_id() == module_id) {
+            Some(p) => {
+                *p = Persisted::from_module(
+                    module_id,
+                    Module {
+                        tags: tags.clone(),
+                        ..p.get_inner().clone()
+                    },
+                );
+                Ok(tags)
+            }
+            None => Err(anyhow::anyhow!("no module found")),
+        }
+    }
+
+    async fn quarantine_module(&self, module_id: i64, reason: String) -> Result<Quarantine> {
+        let quarantine = Quarantine {
+            reason,
+            quarantined_at: Some(
+                chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            ),
+        };
+        set_quarantine(module_id, Some(quarantine.clone()))?;
+        Ok(quarantine)
+    }
+
+    async fn unquarantine_module(&self, module_id: i64) -> Result<()> {
+        set_quarantine(module_id, None)
+    }
+
     async fn diff_modules(
         &self,
         _module1: i64,
@@ -214,15 +318,50 @@ impl ApiClient for Client {
         _identifier: String,
         _function_name: String,
         _function_input: Vec<u8>,
-    ) -> Result<()> {
+    ) -> Result<Vec<u8>> {
         anyhow::bail!("CallPlugin operation unimplemented.")
     }
 
-    async fn install_plugin(&self, _identifier: String, _wasm: Vec<u8>) -> Result<()> {
+    async fn install_plugin(
+        &self,
+        _identifier: String,
+        _name: Option<String>,
+        _location: String,
+        _wasm: Vec<u8>,
+    ) -> Result<()> {
         anyhow::bail!("InstallPlugin operation unimplemented.")
     }
 
     async fn uninstall_plugin(&self, _identifier: String) -> Result<()> {
         anyhow::bail!("UninstallPlugin operation unimplemented.")
     }
+
+    async fn health(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn server_info(&self) -> Result<ServerInfo> {
+        Ok(ServerInfo {
+            version: env!("CARGO_PKG_VERSION").into(),
+            features: vec!["tags".into(), "quarantine".into()],
+            plugins_enabled: false,
+        })
+    }
+}
+
+fn set_quarantine(module_id: i64, quarantine: Option<Quarantine>) -> Result<()> {
+    let mut modules = MOCK_CLIENT_DATA.lock().unwrap();
+    match modules.iter_mut().find(|m| m.get_id() == module_id) {
+        Some(p) => {
+            *p = Persisted::from_module(
+                module_id,
+                Module {
+                    quarantine,
+                    ..p.get_inner().clone()
+                },
+            );
+            Ok(())
+        }
+        None => Err(anyhow::anyhow!("no module found")),
+    }
 }
@@ -0,0 +1,101 @@
+//! Decode the modules of a `ListModulesResponse` or `SearchModulesResponse` one at a time,
+//! converting each into a `Module` before the next is read. Decoding the whole message first
+//! would hold every protobuf module alongside its converted copy, which for a page of thousands of
+//! modules (each with its imports, exports, strings and graph) doubles the peak memory.
+use anyhow::Result;
+use modsurfer_convert::api;
+use modsurfer_module::Module;
+use protobuf::{CodedInputStream, MessageField};
+
+use crate::Persisted;
+
+// field numbers shared by `ListModulesResponse` and `SearchModulesResponse`
+const MODULES: u32 = 1;
+const PAGINATION: u32 = 2;
+const TOTAL: u32 = 3;
+const ERROR: u32 = 5;
+
+// wire types of the fields above
+const VARINT: u32 = 0;
+const LENGTH_DELIMITED: u32 = 2;
+
+/// The fields of a list or search response needed by the client, with its modules converted.
+#[derive(Default)]
+pub(crate) struct ModulePage {
+    pub modules: Vec<Persisted<Module>>,
+    pub pagination: MessageField<api::Pagination>,
+    pub total: u64,
+    pub error: MessageField<api::Error>,
+}
+
+impl ModulePage {
+    /// Decode a page from the bytes of a `ListModulesResponse` or `SearchModulesResponse`.
+    pub(crate) fn decode(data: &[u8]) -> Result<Self> {
+        let mut modules = vec![];
+        let page = decode_with(data, |module| {
+            modules.push(module.try_into()?);
+            Ok(())
+        })?;
+        Ok(ModulePage { modules, ..page })
+    }
+}
+
+impl TryFrom<api::ListModulesResponse> for ModulePage {
+    type Error = anyhow::Error;
+
+    fn try_from(res: api::ListModulesResponse) -> Result<Self> {
+        Ok(ModulePage {
+            modules: res
+                .modules
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            pagination: res.pagination,
+            total: res.total,
+            error: res.error,
+        })
+    }
+}
+
+impl TryFrom<api::SearchModulesResponse> for ModulePage {
+    type Error = anyhow::Error;
+
+    fn try_from(res: api::SearchModulesResponse) -> Result<Self> {
+        Ok(ModulePage {
+            modules: res
+                .modules
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            pagination: res.pagination,
+            total: res.total,
+            error: res.error,
+        })
+    }
+}
+
+/// Call `f` with each module of a `ListModulesResponse` or `SearchModulesResponse`, in order, as
+/// it is decoded, stopping at the first error.
+pub(crate) fn for_each_module(data: &[u8], f: impl FnMut(api::Module) -> Result<()>) -> Result<()> {
+    decode_with(data, f).map(drop)
+}
+
+// decode the fields of a page other than its modules, which are passed to `f` instead. Unknown
+// fields (including the response's `sort`) are skipped.
+fn decode_with(data: &[u8], mut f: impl FnMut(api::Module) -> Result<()>) -> Result<ModulePage> {
+    let mut page = ModulePage::default();
+    let mut is = CodedInputStream::from_bytes(data);
+    while let Some(tag) = is.read_raw_tag_or_eof()? {
+        match (tag >> 3, tag & 7) {
+            (MODULES, LENGTH_DELIMITED) => f(is.read_message()?)?,
+            (PAGINATION, LENGTH_DELIMITED) => {
+                is.merge_message(page.pagination.mut_or_insert_default())?
+            }
+            (TOTAL, VARINT) => page.total = is.read_uint64()?,
+            (ERROR, LENGTH_DELIMITED) => is.merge_message(page.error.mut_or_insert_default())?,
+            _ => protobuf::rt::skip_field_for_tag(tag, &mut is)?,
+        }
+    }
+
+    Ok(page)
+}
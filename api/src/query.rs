@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use modsurfer_module::Module;
+
+/// Criteria used to select a set of modules for bulk operations, such as
+/// `ApiClient::delete_modules_matching`. All provided criteria must match.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModuleQuery {
+    /// match the exact SHA-256 hash of the module
+    pub hash: Option<String>,
+    /// match modules inserted before this point in time
+    pub inserted_before: Option<chrono::DateTime<chrono::Utc>>,
+    /// match modules having every one of these metadata entries
+    pub metadata: HashMap<String, String>,
+}
+
+impl ModuleQuery {
+    /// Returns true if no criteria are set, which would otherwise match every module.
+    pub fn is_empty(&self) -> bool {
+        self.hash.is_none() && self.inserted_before.is_none() && self.metadata.is_empty()
+    }
+
+    /// Check a module against the query. Servers may match search parameters loosely (e.g. by
+    /// prefix or substring), so results are always re-checked against the exact criteria.
+    pub fn matches(&self, module: &Module) -> bool {
+        if let Some(hash) = &self.hash {
+            if !module.hash.eq_ignore_ascii_case(hash.trim()) {
+                return false;
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(before) = self.inserted_before {
+            if module.inserted_at >= before {
+                return false;
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(before) = self.inserted_before {
+            if module.inserted_at as i64 >= before.timestamp() {
+                return false;
+            }
+        }
+
+        self.metadata.iter().all(|(k, v)| {
+            module
+                .metadata
+                .as_ref()
+                .and_then(|m| m.get(k))
+                .map(|actual| actual == v)
+                .unwrap_or(false)
+        })
+    }
+}
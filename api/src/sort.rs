@@ -36,6 +36,7 @@ pub enum SortField {
     ExportsCount,
     Sha256,
     Complexity,
+    RiskScore,
 }
 
 impl SortField {
@@ -49,6 +50,7 @@ impl SortField {
             "exports_count" => Some(SortField::ExportsCount),
             "sha256" => Some(SortField::Sha256),
             "complexity" => Some(SortField::Complexity),
+            "risk_score" => Some(SortField::RiskScore),
             _ => None,
         }
     }
@@ -63,6 +65,7 @@ impl SortField {
             SortField::ExportsCount => Field::ExportsCount,
             SortField::Sha256 => Field::Sha256,
             SortField::Complexity => Field::Complexity,
+            SortField::RiskScore => Field::RiskScore,
         }
     }
 }
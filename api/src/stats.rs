@@ -0,0 +1,179 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use modsurfer_module::{Module, RiskLevel};
+use serde::Serialize;
+
+/// The upper bounds, in bytes, of the size buckets used for the size distribution. Modules larger
+/// than the last bound are counted in a final, unbounded bucket.
+const SIZE_BUCKETS: [u64; 4] = [100 * 1024, 1024 * 1024, 10 * 1024 * 1024, 50 * 1024 * 1024];
+
+/// The number of modules whose size is below `max_size`, and at or above the previous bucket's
+/// `max_size`. The last bucket has no `max_size`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SizeBucket {
+    pub max_size: Option<u64>,
+    pub modules: u64,
+}
+
+/// The number of modules at each risk level, derived from their cyclomatic complexity.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ComplexityDistribution {
+    pub low: u64,
+    pub medium: u64,
+    pub high: u64,
+    /// modules for which no complexity was computed
+    pub unknown: u64,
+}
+
+/// Aggregate statistics about the modules stored in a registry.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RegistryStats {
+    pub modules: u64,
+    /// the combined size in bytes of every module
+    pub total_size: u64,
+    /// module counts keyed by source language
+    pub languages: BTreeMap<String, u64>,
+    pub sizes: Vec<SizeBucket>,
+    pub complexity: ComplexityDistribution,
+    /// the number of modules importing from each namespace
+    pub namespaces: BTreeMap<String, u64>,
+    /// the number of modules inserted each month, keyed by `YYYY-MM`
+    pub growth: BTreeMap<String, u64>,
+}
+
+impl Default for RegistryStats {
+    fn default() -> Self {
+        RegistryStats {
+            modules: 0,
+            total_size: 0,
+            languages: BTreeMap::new(),
+            sizes: SIZE_BUCKETS
+                .iter()
+                .map(|max| Some(*max))
+                .chain([None])
+                .map(|max_size| SizeBucket {
+                    max_size,
+                    modules: 0,
+                })
+                .collect(),
+            complexity: ComplexityDistribution::default(),
+            namespaces: BTreeMap::new(),
+            growth: BTreeMap::new(),
+        }
+    }
+}
+
+impl RegistryStats {
+    /// Count a module in the statistics.
+    pub fn add(&mut self, module: &Module) {
+        self.modules += 1;
+        self.total_size += module.size;
+
+        *self
+            .languages
+            .entry(module.source_language.to_string())
+            .or_default() += 1;
+
+        let bucket = SIZE_BUCKETS
+            .iter()
+            .position(|max| module.size < *max)
+            .unwrap_or(SIZE_BUCKETS.len());
+        self.sizes[bucket].modules += 1;
+
+        match module.complexity.map(RiskLevel::from) {
+            Some(RiskLevel::Low) => self.complexity.low += 1,
+            Some(RiskLevel::Medium) => self.complexity.medium += 1,
+            Some(RiskLevel::High) => self.complexity.high += 1,
+            None => self.complexity.unknown += 1,
+        }
+
+        for namespace in module.get_import_namespaces() {
+            *self.namespaces.entry(namespace.to_string()).or_default() += 1;
+        }
+
+        if let Some(month) = inserted_month(module) {
+            *self.growth.entry(month).or_default() += 1;
+        }
+    }
+
+    /// The `n` namespaces imported by the most modules, most imported first.
+    pub fn top_namespaces(&self, n: usize) -> Vec<(&str, u64)> {
+        let mut namespaces = self
+            .namespaces
+            .iter()
+            .map(|(ns, count)| (ns.as_str(), *count))
+            .collect::<Vec<_>>();
+        // the sort is stable, so namespaces with equal counts remain ordered by name
+        namespaces.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        namespaces.truncate(n);
+        namespaces
+    }
+}
+
+impl<'a> FromIterator<&'a Module> for RegistryStats {
+    fn from_iter<T: IntoIterator<Item = &'a Module>>(iter: T) -> Self {
+        let mut stats = RegistryStats::default();
+        iter.into_iter().for_each(|m| stats.add(m));
+        stats
+    }
+}
+
+/// How many modules import from each namespace, and each function in it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct NamespaceUsage {
+    /// the number of modules counted
+    pub modules: u64,
+    pub namespaces: BTreeMap<String, NamespaceCount>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct NamespaceCount {
+    /// the number of modules importing any function from the namespace
+    pub modules: u64,
+    /// the number of modules importing each function
+    pub functions: BTreeMap<String, u64>,
+}
+
+impl NamespaceUsage {
+    /// Count the imports of a module, once for each function however often it is imported.
+    pub fn add(&mut self, module: &Module) {
+        self.modules += 1;
+
+        let imports = module
+            .imports
+            .iter()
+            .map(|import| (import.module_name.as_str(), import.func.name.as_str()))
+            .collect::<BTreeSet<_>>();
+        let mut namespaces = BTreeSet::new();
+        for (namespace, function) in imports {
+            let count = self.namespaces.entry(namespace.to_string()).or_default();
+            if namespaces.insert(namespace) {
+                count.modules += 1;
+            }
+            *count.functions.entry(function.to_string()).or_default() += 1;
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a Module> for NamespaceUsage {
+    fn from_iter<T: IntoIterator<Item = &'a Module>>(iter: T) -> Self {
+        let mut usage = NamespaceUsage::default();
+        iter.into_iter().for_each(|m| usage.add(m));
+        usage
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn inserted_month(module: &Module) -> Option<String> {
+    Some(module.inserted_at.format("%Y-%m").to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn inserted_month(module: &Module) -> Option<String> {
+    use chrono::TimeZone;
+
+    chrono::Utc
+        .timestamp_opt(module.inserted_at as i64, 0)
+        .single()
+        .map(|t| t.format("%Y-%m").to_string())
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use protobuf::{MessageDyn, MessageFull};
+
+/// The encoding of request and response bodies exchanged with a Modsurfer backend, advertised using
+/// the `Content-Type` and `Accept` headers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WireFormat {
+    /// Binary protobuf messages.
+    #[default]
+    Protobuf,
+    /// The canonical proto3 JSON mapping of the same messages, which is easier to inspect and to
+    /// reproduce with tools like `curl`.
+    Json,
+}
+
+impl WireFormat {
+    /// The media type used in the `Content-Type` and `Accept` headers for this format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            WireFormat::Protobuf => "application/x-protobuf",
+            WireFormat::Json => "application/json",
+        }
+    }
+
+    /// Determine the format of a response from its `Content-Type` header, if recognized.
+    pub(crate) fn from_content_type(content_type: &str) -> Option<WireFormat> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        match media_type.to_ascii_lowercase().as_str() {
+            "application/json" => Some(WireFormat::Json),
+            "application/x-protobuf" | "application/protobuf" | "application/octet-stream" => {
+                Some(WireFormat::Protobuf)
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn encode(&self, message: &dyn MessageDyn) -> Result<Vec<u8>> {
+        match self {
+            WireFormat::Protobuf => Ok(message.write_to_bytes_dyn()?),
+            WireFormat::Json => Ok(protobuf_json_mapping::print_to_string(message)?.into_bytes()),
+        }
+    }
+
+    pub(crate) fn decode<M: MessageFull>(&self, data: &[u8]) -> Result<M> {
+        match self {
+            WireFormat::Protobuf => Ok(M::parse_from_bytes(data)?),
+            WireFormat::Json => Ok(protobuf_json_mapping::parse_from_str(std::str::from_utf8(
+                data,
+            )?)?),
+        }
+    }
+}
@@ -1,23 +1,20 @@
 use std::fmt::Display;
 
-use comfy_table::{modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, Row, Table};
-use modsurfer_module::SourceLanguage;
+use comfy_table::Row;
+use human_bytes::human_bytes;
+use modsurfer_api::{NamespaceUsage, RegistryStats};
+use modsurfer_module::{Bloat, Capability, ModuleSummary};
+use modsurfer_validation::new_table;
 use serde::{ser::SerializeStruct, Serialize};
 
 #[derive(Serialize)]
-pub struct ApiResults<'a> {
-    pub results: Vec<ApiResult<'a>>,
+pub struct ApiResults {
+    pub results: Vec<ApiResult>,
 }
 
-pub struct ApiResult<'a> {
+pub struct ApiResult {
     pub module_id: i64,
-    pub hash: String,
-    pub file_name: String,
-    pub exports: usize,
-    pub imports: usize,
-    pub namespaces: Vec<&'a str>,
-    pub source_language: SourceLanguage,
-    pub size: String,
+    pub summary: ModuleSummary,
 }
 
 #[derive(Serialize)]
@@ -30,20 +27,201 @@ pub struct SimpleApiResult {
     pub hash: String,
 }
 
-impl<'a> Serialize for ApiResult<'a> {
+#[derive(Serialize)]
+pub struct PingResult {
+    pub host: String,
+    pub latency_ms: u64,
+    pub version: Option<String>,
+    pub features: Vec<String>,
+    pub plugins_enabled: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct SimilarResults {
+    pub results: Vec<SimilarResult>,
+}
+
+/// A module similar to the one being compared. Modules found in a local directory have no ID.
+pub struct SimilarResult {
+    pub module_id: Option<i64>,
+    pub hash: String,
+    pub file_name: String,
+    pub similarity: f64,
+}
+
+impl Serialize for SimilarResult {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("ApiResult", 8)?;
-        state.serialize_field("module_id", &self.module_id.to_string())?;
+        let mut state = serializer.serialize_struct("SimilarResult", 4)?;
+        state.serialize_field("module_id", &self.module_id.map(|id| id.to_string()))?;
         state.serialize_field("hash", &self.hash)?;
         state.serialize_field("file_name", &self.file_name)?;
-        state.serialize_field("exports", &self.exports)?;
-        state.serialize_field("imports", &self.imports)?;
-        state.serialize_field("namespaces", &self.namespaces)?;
-        state.serialize_field("source_language", &self.source_language)?;
-        state.serialize_field("size", &self.size)?;
+        state.serialize_field("similarity", &self.similarity)?;
+        state.end()
+    }
+}
+
+impl Display for SimilarResults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec!["ID", "Hash", "Filename", "Similarity"]);
+
+        self.results.iter().for_each(|m| {
+            table.add_row(Row::from(vec![
+                m.module_id.map(|id| id.to_string()).unwrap_or_default(),
+                m.hash[0..6].to_string(),
+                m.file_name.clone(),
+                format!("{:.0}%", m.similarity * 100.0),
+            ]));
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
+
+#[derive(Serialize)]
+pub struct CapabilitiesResult {
+    pub capabilities: Vec<CapabilityResult>,
+}
+
+/// Whether a module is granted a capability, and the imports (as `namespace::name`) which grant it.
+#[derive(Serialize)]
+pub struct CapabilityResult {
+    pub capability: Capability,
+    pub granted: bool,
+    pub imports: Vec<String>,
+}
+
+impl Display for CapabilitiesResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec!["Capability", "Granted", "Imports"]);
+
+        self.capabilities.iter().for_each(|c| {
+            table.add_row(Row::from(vec![
+                c.capability.to_string(),
+                if c.granted { "yes" } else { "no" }.to_string(),
+                c.imports.join("\n"),
+            ]));
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
+
+/// Where a module's bytes go, showing only the `top` largest items in table output, followed by
+/// the unreachable functions. JSON output includes every item.
+pub struct BloatResult {
+    pub bloat: Bloat,
+    pub top: usize,
+}
+
+impl Serialize for BloatResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.bloat.serialize(serializer)
+    }
+}
+
+impl Display for BloatResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let percent = |size: u64| format!("{:.1}%", size as f64 * 100.0 / self.bloat.size as f64);
+        let mut table = new_table(vec!["Kind", "Name", "Size", "Share", "Reachable"]);
+
+        self.bloat.items.iter().take(self.top).for_each(|item| {
+            table.add_row(Row::from(vec![
+                item.kind.to_string(),
+                item.name.clone(),
+                human_bytes(item.size as f64),
+                percent(item.size),
+                if item.reachable { "yes" } else { "no" }.to_string(),
+            ]));
+        });
+        writeln!(f, "{table}")?;
+
+        let unreachable = self.bloat.unreachable().count();
+        if unreachable > 0 {
+            write!(
+                f,
+                "{} unreachable function(s) take {} ({}) of {}",
+                unreachable,
+                human_bytes(self.bloat.unreachable_size as f64),
+                percent(self.bloat.unreachable_size),
+                human_bytes(self.bloat.size as f64),
+            )
+        } else {
+            write!(
+                f,
+                "No unreachable functions in {}",
+                human_bytes(self.bloat.size as f64)
+            )
+        }
+    }
+}
+
+/// Registry statistics, showing only the `top` most imported namespaces in table output. JSON
+/// output includes every namespace, for use in dashboards.
+pub struct StatsResult {
+    pub stats: RegistryStats,
+    pub top: usize,
+}
+
+/// The modules importing each namespace and function. With `max_modules`, only those imported by
+/// at most that many modules are kept, e.g. to find host functions which could be deprecated.
+pub struct NamespaceUsageResult {
+    pub usage: NamespaceUsage,
+}
+
+impl NamespaceUsageResult {
+    pub fn new(mut usage: NamespaceUsage, max_modules: Option<u64>) -> Self {
+        if let Some(max) = max_modules {
+            usage.namespaces.retain(|_, namespace| {
+                namespace.functions.retain(|_, count| *count <= max);
+                !namespace.functions.is_empty() || namespace.modules <= max
+            });
+        }
+        NamespaceUsageResult { usage }
+    }
+}
+
+impl Serialize for NamespaceUsageResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.usage.serialize(serializer)
+    }
+}
+
+impl Serialize for StatsResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.stats.serialize(serializer)
+    }
+}
+
+impl Serialize for ApiResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ApiResult", 12)?;
+        state.serialize_field("module_id", &self.module_id.to_string())?;
+        state.serialize_field("hash", &self.summary.hash)?;
+        state.serialize_field("file_name", &self.summary.file_name)?;
+        state.serialize_field("version", &self.summary.version)?;
+        state.serialize_field("exports", &self.summary.exports)?;
+        state.serialize_field("imports", &self.summary.imports)?;
+        state.serialize_field("namespaces", &self.summary.namespaces)?;
+        state.serialize_field("source_language", &self.summary.source_language)?;
+        state.serialize_field("size", &self.summary.human_size)?;
+        state.serialize_field("risk_score", &self.summary.risk_score)?;
+        state.serialize_field("tags", &self.summary.tags)?;
+        state.serialize_field("quarantine", &self.summary.quarantine)?;
         state.end()
     }
 }
@@ -62,11 +240,7 @@ impl Serialize for SimpleApiResult {
 
 impl Display for SimpleApiResults {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut table = Table::new();
-
-        table.load_preset(UTF8_FULL);
-        table.apply_modifier(UTF8_SOLID_INNER_BORDERS);
-        table.set_header(vec!["ID", "Hash"]);
+        let mut table = new_table(vec!["ID", "Hash"]);
 
         self.results.iter().for_each(|m| {
             table.add_row(Row::from(vec![m.module_id.to_string(), m.hash.clone()]));
@@ -76,13 +250,9 @@ impl Display for SimpleApiResults {
     }
 }
 
-impl Display for ApiResults<'_> {
+impl Display for ApiResults {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut table = Table::new();
-
-        table.load_preset(UTF8_FULL);
-        table.apply_modifier(UTF8_SOLID_INNER_BORDERS);
-        table.set_header(vec![
+        let mut table = new_table(vec![
             "ID",
             "Hash",
             "Filename",
@@ -91,6 +261,9 @@ impl Display for ApiResults<'_> {
             "Namespaces",
             "Source",
             "Size",
+            "Risk",
+            "Tags",
+            "Quarantine",
         ]);
 
         if self.results.is_empty() {
@@ -98,18 +271,152 @@ impl Display for ApiResults<'_> {
         }
 
         self.results.iter().for_each(|m| {
+            let summary = &m.summary;
             table.add_row(Row::from(vec![
                 m.module_id.to_string(),
-                m.hash[0..6].to_string(),
-                m.file_name.clone(),
-                m.exports.to_string(),
-                m.imports.to_string(),
-                m.namespaces.join(", "),
-                m.source_language.to_string(),
-                m.size.clone(),
+                summary.hash[0..6].to_string(),
+                summary.file_name.clone(),
+                summary.exports.to_string(),
+                summary.imports.to_string(),
+                summary.namespaces.join(", "),
+                summary.source_language.to_string(),
+                summary.human_size.clone(),
+                summary.risk_score.to_string(),
+                summary.tags.join(", "),
+                summary.quarantine.clone().unwrap_or_default(),
             ]));
         });
 
         f.write_str(table.to_string().as_str())
     }
 }
+
+impl Display for PingResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unknown = || "unknown".to_string();
+        let mut table = new_table(vec!["Host", "Latency", "Version", "Features", "Plugins"]);
+        table.add_row(Row::from(vec![
+            self.host.clone(),
+            format!("{}ms", self.latency_ms),
+            self.version.clone().unwrap_or_else(unknown),
+            if self.features.is_empty() {
+                unknown()
+            } else {
+                self.features.join(", ")
+            },
+            self.plugins_enabled
+                .map(|enabled| if enabled { "enabled" } else { "disabled" }.to_string())
+                .unwrap_or_else(unknown),
+        ]));
+
+        f.write_str(table.to_string().as_str())
+    }
+}
+
+impl Display for NamespaceUsageResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let share = |count: u64| {
+            if self.usage.modules == 0 {
+                "-".to_string()
+            } else {
+                format!("{:.1}%", count as f64 * 100.0 / self.usage.modules as f64)
+            }
+        };
+
+        // the most imported namespaces first, each followed by its functions, most imported first
+        let mut namespaces = self.usage.namespaces.iter().collect::<Vec<_>>();
+        namespaces.sort_by_key(|(_, namespace)| std::cmp::Reverse(namespace.modules));
+
+        let mut table = new_table(vec!["Namespace", "Function", "Modules", "Share"]);
+        namespaces.into_iter().for_each(|(name, namespace)| {
+            table.add_row(Row::from(vec![
+                name.clone(),
+                "*".to_string(),
+                namespace.modules.to_string(),
+                share(namespace.modules),
+            ]));
+
+            let mut functions = namespace.functions.iter().collect::<Vec<_>>();
+            functions.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
+            functions.into_iter().for_each(|(function, count)| {
+                table.add_row(Row::from(vec![
+                    name.clone(),
+                    function.clone(),
+                    count.to_string(),
+                    share(*count),
+                ]));
+            });
+        });
+
+        writeln!(f, "{}", table)?;
+        write!(
+            f,
+            "{} namespace(s) imported across {} module(s).",
+            self.usage.namespaces.len(),
+            self.usage.modules
+        )
+    }
+}
+
+impl Display for StatsResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stats = &self.stats;
+
+        let mut totals = new_table(vec!["Modules", "Total Size"]);
+        totals.add_row(Row::from(vec![
+            stats.modules.to_string(),
+            human_bytes(stats.total_size as f64),
+        ]));
+
+        let mut languages = new_table(vec!["Source", "Modules"]);
+        stats.languages.iter().for_each(|(lang, count)| {
+            languages.add_row(Row::from(vec![lang.clone(), count.to_string()]));
+        });
+
+        let mut sizes = new_table(vec!["Size", "Modules"]);
+        let mut min_size = 0;
+        stats.sizes.iter().for_each(|bucket| {
+            let range = match bucket.max_size {
+                Some(max) => format!(
+                    "{} - {}",
+                    human_bytes(min_size as f64),
+                    human_bytes(max as f64)
+                ),
+                None => format!(">= {}", human_bytes(min_size as f64)),
+            };
+            min_size = bucket.max_size.unwrap_or_default();
+            sizes.add_row(Row::from(vec![range, bucket.modules.to_string()]));
+        });
+
+        let mut complexity = new_table(vec!["Risk", "Modules"]);
+        [
+            ("low", stats.complexity.low),
+            ("medium", stats.complexity.medium),
+            ("high", stats.complexity.high),
+            ("unknown", stats.complexity.unknown),
+        ]
+        .into_iter()
+        .for_each(|(risk, count)| {
+            complexity.add_row(Row::from(vec![risk.to_string(), count.to_string()]));
+        });
+
+        let mut namespaces = new_table(vec!["Namespace", "Modules"]);
+        stats
+            .top_namespaces(self.top)
+            .into_iter()
+            .for_each(|(ns, count)| {
+                namespaces.add_row(Row::from(vec![ns.to_string(), count.to_string()]));
+            });
+
+        let mut growth = new_table(vec!["Month", "Modules Added"]);
+        stats.growth.iter().for_each(|(month, count)| {
+            growth.add_row(Row::from(vec![month.clone(), count.to_string()]));
+        });
+
+        let tables = [totals, languages, sizes, complexity, namespaces, growth]
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>();
+        f.write_str(tables.join("\n").as_str())
+    }
+}
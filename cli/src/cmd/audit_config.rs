@@ -0,0 +1,125 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use comfy_table::Row;
+use modsurfer_api::{AuditReports, CheckfileSelector, ModuleQuery};
+use modsurfer_validation::{new_table, Validation};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Selects the checkfile used to audit each module, e.g.
+///
+/// ```yaml
+/// rules:
+///   - metadata:
+///       team: payments
+///     checkfile: payments.yaml
+/// default: org.yaml
+/// ```
+///
+/// Rules are tried in order, and modules matching no rule are audited with the `default`
+/// checkfile, if any. Checkfile paths are relative to the config file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditConfig {
+    #[serde(default)]
+    pub rules: Vec<AuditRule>,
+    pub default: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditRule {
+    /// metadata entries which a module must all have for the rule to apply
+    pub metadata: HashMap<String, String>,
+    pub checkfile: PathBuf,
+}
+
+impl AuditConfig {
+    /// Read the config and each checkfile it refers to, in the order they should be applied, with
+    /// the hex-encoded SHA-256 of each checkfile by selector name.
+    pub async fn load(path: &Path) -> Result<(Vec<CheckfileSelector>, BTreeMap<String, String>)> {
+        let data = tokio::fs::read(path).await?;
+        let config: AuditConfig = serde_yaml::from_slice(&data)
+            .map_err(|e| anyhow!("invalid audit config {}: {}", path.display(), e))?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut selectors = vec![];
+        let mut hashes = BTreeMap::new();
+        for rule in config.rules {
+            let (selector, hash) = selector(
+                base,
+                &rule.checkfile,
+                ModuleQuery {
+                    metadata: rule.metadata,
+                    ..Default::default()
+                },
+            )
+            .await?;
+            hashes.insert(selector.name.clone(), hash);
+            selectors.push(selector);
+        }
+        if let Some(default) = config.default {
+            let (selector, hash) = selector(base, &default, ModuleQuery::default()).await?;
+            hashes.insert(selector.name.clone(), hash);
+            selectors.push(selector);
+        }
+
+        Ok((selectors, hashes))
+    }
+}
+
+// read the checkfile of a selector, returning it with the checkfile's SHA-256
+async fn selector(
+    base: &Path,
+    checkfile: &Path,
+    query: ModuleQuery,
+) -> Result<(CheckfileSelector, String)> {
+    let data = tokio::fs::read(base.join(checkfile)).await?;
+    let validation: Validation = serde_yaml::from_slice(&data)
+        .map_err(|e| anyhow!("invalid checkfile {}: {}", checkfile.display(), e))?;
+
+    Ok((
+        CheckfileSelector {
+            name: checkfile.display().to_string(),
+            query,
+            validation,
+        },
+        format!("{:x}", Sha256::digest(&data)),
+    ))
+}
+
+/// The number of reported modules for each checkfile in an audit.
+#[derive(Serialize)]
+pub struct AuditSummary {
+    pub checkfiles: BTreeMap<String, usize>,
+}
+
+impl AuditSummary {
+    pub fn new(selectors: &[CheckfileSelector], reports: &AuditReports) -> Self {
+        let mut checkfiles = selectors
+            .iter()
+            .map(|s| (s.name.clone(), 0))
+            .collect::<BTreeMap<_, _>>();
+        reports.iter().for_each(|r| {
+            if let Some(checkfile) = &r.checkfile {
+                *checkfiles.entry(checkfile.clone()).or_default() += 1;
+            }
+        });
+
+        AuditSummary { checkfiles }
+    }
+}
+
+impl std::fmt::Display for AuditSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec!["Checkfile", "Modules"]);
+
+        self.checkfiles.iter().for_each(|(checkfile, count)| {
+            table.add_row(Row::from(vec![checkfile.clone(), count.to_string()]));
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{SecondsFormat, Utc};
+use comfy_table::Row;
+use modsurfer_api::AuditReports;
+use modsurfer_convert::Pagination;
+use modsurfer_validation::new_table;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The version of the state file format, increased when it changes incompatibly.
+const STATE_VERSION: u32 = 1;
+
+/// The modules failing an audit, saved with `audit --save-state` so that the next run can report
+/// what changed since.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuditState {
+    pub version: u32,
+    /// when the audit was run, in RFC 3339 format
+    pub saved_at: String,
+    /// the checkfile or audit config the modules were audited with
+    pub checkfile: String,
+    pub offset: u32,
+    pub limit: u32,
+    /// the failing modules, by ID
+    pub failing: BTreeMap<i64, FailingModule>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FailingModule {
+    pub hash: String,
+    /// the properties of the checks which failed, e.g. `allow_wasi`
+    pub fails: Vec<String>,
+}
+
+impl AuditState {
+    /// The state of an audit of failing modules, run now.
+    pub fn new(checkfile: &Path, page: &Pagination, reports: &AuditReports) -> Self {
+        AuditState {
+            version: STATE_VERSION,
+            saved_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            checkfile: checkfile.display().to_string(),
+            offset: page.offset,
+            limit: page.limit,
+            failing: reports
+                .iter()
+                .map(|r| {
+                    (
+                        r.module_id,
+                        FailingModule {
+                            hash: r.hash.clone(),
+                            fails: r.report.fails.keys().cloned().collect(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Read the state saved by a previous run, if there is one.
+    pub async fn load(path: &Path) -> Result<Option<AuditState>> {
+        let data = match tokio::fs::read(path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+        let state: AuditState = serde_json::from_slice(&data)
+            .map_err(|e| anyhow!("invalid audit state {}: {}", path.display(), e))?;
+        if state.version != STATE_VERSION {
+            anyhow::bail!(
+                "audit state {} has version {}, expected {}; remove it to start again",
+                path.display(),
+                state.version,
+                STATE_VERSION
+            );
+        }
+
+        Ok(Some(state))
+    }
+
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        tokio::fs::write(path, serde_json::to_string_pretty(self)?)
+            .await
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// What changed since a previous run. Without one, every failing module is newly failing.
+    pub fn delta(&self, previous: Option<&AuditState>) -> AuditDelta {
+        let empty = BTreeMap::new();
+        let before = previous.map(|p| &p.failing).unwrap_or(&empty);
+
+        let mut delta = AuditDelta {
+            since: previous.map(|p| p.saved_at.clone()),
+            saved_at: self.saved_at.clone(),
+            newly_failing: vec![],
+            newly_fixed: vec![],
+            unchanged: vec![],
+        };
+        for (id, module) in &self.failing {
+            match before.get(id) {
+                None => delta.newly_failing.push(ModuleDelta::new(*id, module)),
+                Some(was) => {
+                    let now = module.fails.iter().collect::<BTreeSet<_>>();
+                    let then = was.fails.iter().collect::<BTreeSet<_>>();
+                    let mut entry = ModuleDelta::new(*id, module);
+                    entry.new_fails = now.difference(&then).map(|p| p.to_string()).collect();
+                    entry.fixed_fails = then.difference(&now).map(|p| p.to_string()).collect();
+                    delta.unchanged.push(entry);
+                }
+            }
+        }
+        for (id, module) in before {
+            if !self.failing.contains_key(id) {
+                delta.newly_fixed.push(ModuleDelta::new(*id, module));
+            }
+        }
+
+        delta
+    }
+}
+
+/// The modules which started or stopped failing an audit since the previous run, and those still
+/// failing.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct AuditDelta {
+    /// when the previous run was saved, if there was one
+    pub since: Option<String>,
+    pub saved_at: String,
+    pub newly_failing: Vec<ModuleDelta>,
+    /// modules which no longer fail, with the checks which failed before
+    pub newly_fixed: Vec<ModuleDelta>,
+    /// modules failing in both runs
+    pub unchanged: Vec<ModuleDelta>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleDelta {
+    pub module_id: i64,
+    pub hash: String,
+    pub fails: Vec<String>,
+    /// checks which fail now but didn't in the previous run
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub new_fails: Vec<String>,
+    /// checks which failed in the previous run but don't now
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixed_fails: Vec<String>,
+}
+
+impl ModuleDelta {
+    fn new(module_id: i64, module: &FailingModule) -> Self {
+        ModuleDelta {
+            module_id,
+            hash: module.hash.clone(),
+            fails: module.fails.clone(),
+            new_fails: vec![],
+            fixed_fails: vec![],
+        }
+    }
+}
+
+impl Display for AuditDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec!["Change", "ID", "Hash", "Failing Checks"]);
+        let rows = [
+            ("newly failing", &self.newly_failing),
+            ("newly fixed", &self.newly_fixed),
+            ("unchanged", &self.unchanged),
+        ];
+        for (change, modules) in rows {
+            modules.iter().for_each(|m| {
+                let mut fails = m.fails.join(", ");
+                if !m.new_fails.is_empty() {
+                    fails.push_str(&format!(" (new: {})", m.new_fails.join(", ")));
+                }
+                if !m.fixed_fails.is_empty() {
+                    fails.push_str(&format!(" (fixed: {})", m.fixed_fails.join(", ")));
+                }
+                table.add_row(Row::from(vec![
+                    change.to_string(),
+                    m.module_id.to_string(),
+                    m.hash.chars().take(6).collect(),
+                    fails,
+                ]));
+            });
+        }
+
+        writeln!(f, "{}", table)?;
+        write!(
+            f,
+            "{} newly failing, {} newly fixed, {} unchanged",
+            self.newly_failing.len(),
+            self.newly_fixed.len(),
+            self.unchanged.len()
+        )?;
+        match &self.since {
+            Some(since) => write!(f, " since {}.", since),
+            None => write!(f, " (no previous state)."),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{SecondsFormat, Utc};
+use comfy_table::Row;
+use modsurfer_api::AuditReports;
+use modsurfer_validation::new_table;
+use serde::Serialize;
+
+use super::output::{csv_record, to_csv};
+
+// the columns of an export, the fields of `CompliantModule`
+const COLUMNS: &[&str] = &[
+    "module_id",
+    "hash",
+    "checkfile",
+    "checkfile_sha256",
+    "audited_at",
+];
+
+/// The modules passing an audit (`audit --outcome pass`), each with the checkfile it complies with
+/// and when it was audited, as evidence for auditors that the modules comply with a policy. Exported
+/// as CSV with `audit --export`.
+#[derive(Serialize)]
+pub struct ComplianceResult {
+    pub modules: Vec<CompliantModule>,
+}
+
+#[derive(Serialize)]
+pub struct CompliantModule {
+    pub module_id: i64,
+    pub hash: String,
+    pub checkfile: String,
+    /// the hex-encoded SHA-256 hash of the checkfile, identifying the version of the policy
+    pub checkfile_sha256: String,
+    /// when the module was audited, in RFC 3339 format
+    pub audited_at: String,
+}
+
+impl ComplianceResult {
+    /// The modules passing an audit run now. `hashes` holds the SHA-256 of each checkfile by name,
+    /// and modules whose reports name no checkfile were audited with `checkfile`.
+    pub fn new(
+        reports: &AuditReports,
+        checkfile: &Path,
+        hashes: &BTreeMap<String, String>,
+    ) -> Self {
+        let audited_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        let checkfile = checkfile.display().to_string();
+
+        ComplianceResult {
+            modules: reports
+                .iter()
+                .map(|r| {
+                    let checkfile = r.checkfile.clone().unwrap_or_else(|| checkfile.clone());
+                    CompliantModule {
+                        module_id: r.module_id,
+                        hash: r.hash.clone(),
+                        checkfile_sha256: hashes.get(&checkfile).cloned().unwrap_or_default(),
+                        checkfile,
+                        audited_at: audited_at.clone(),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the compliant modules to `path` as CSV, with a header even if there are none.
+    pub async fn export(&self, path: &Path) -> Result<()> {
+        // `to_csv` takes its header from the rows, so there is none without modules
+        let csv = if self.modules.is_empty() {
+            csv_record(COLUMNS)
+        } else {
+            to_csv(self)?
+        };
+        tokio::fs::write(path, csv)
+            .await
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+impl Display for ComplianceResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec![
+            "Module ID",
+            "Hash",
+            "Checkfile",
+            "Checkfile SHA-256",
+            "Audited At",
+        ]);
+
+        self.modules.iter().for_each(|m| {
+            table.add_row(Row::from(vec![
+                m.module_id.to_string(),
+                m.hash.clone(),
+                m.checkfile.clone(),
+                m.checkfile_sha256.clone(),
+                m.audited_at.clone(),
+            ]));
+        });
+        writeln!(f, "{}", table)?;
+
+        let checkfiles = self
+            .modules
+            .iter()
+            .map(|m| m.checkfile.as_str())
+            .collect::<BTreeSet<_>>();
+        match Vec::from_iter(checkfiles).as_slice() {
+            [] => f.write_str("No modules passed the audit"),
+            [checkfile] => write!(
+                f,
+                "{} module(s) comply with {}",
+                self.modules.len(),
+                checkfile
+            ),
+            _ => write!(
+                f,
+                "{} module(s) comply with the checkfile selected for each",
+                self.modules.len()
+            ),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+use std::process::ExitCode;
+
+use modsurfer_api::ApiError;
+use modsurfer_validation::{InvalidModule, ParseTimeout};
+
+/// The command was invoked incorrectly, e.g. with a missing file or an unknown rule.
+pub const EXIT_USAGE: u8 = 2;
+/// A module, checkfile or other input could not be parsed.
+pub const EXIT_PARSE: u8 = 3;
+/// The Modsurfer server (or a remote checkfile or plugin) could not be reached, or failed.
+pub const EXIT_NETWORK: u8 = 4;
+/// The Modsurfer server rejected the request's credentials.
+pub const EXIT_AUTH: u8 = 5;
+
+/// Why a command failed, which decides its exit code so that scripts can branch on the cause:
+///
+/// | code | cause          |
+/// |------|----------------|
+/// | 0    | success        |
+/// | 1    | policy failure |
+/// | 2    | usage error    |
+/// | 3    | parse error    |
+/// | 4    | network error  |
+/// | 5    | auth error     |
+///
+/// Policy failures are not errors, and are returned by the commands themselves from
+/// `Verdict::exit_code`.
+#[derive(Debug)]
+pub enum CliError {
+    Usage(anyhow::Error),
+    Parse(anyhow::Error),
+    Network(anyhow::Error),
+    Auth(anyhow::Error),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from(match self {
+            CliError::Usage(_) => EXIT_USAGE,
+            CliError::Parse(_) => EXIT_PARSE,
+            CliError::Network(_) => EXIT_NETWORK,
+            CliError::Auth(_) => EXIT_AUTH,
+        })
+    }
+}
+
+impl From<anyhow::Error> for CliError {
+    /// Classify an error by the first cause in its chain with a known type. Errors of any other
+    /// type (e.g. a file which doesn't exist) are usage errors.
+    fn from(e: anyhow::Error) -> Self {
+        if e.is::<InvalidModule>() || e.is::<ParseTimeout>() {
+            return CliError::Parse(e);
+        }
+
+        for cause in e.chain() {
+            if let Some(api) = cause.downcast_ref::<ApiError>() {
+                return if api.is_auth() {
+                    CliError::Auth(e)
+                } else {
+                    CliError::Network(e)
+                };
+            }
+            if let Some(req) = cause.downcast_ref::<reqwest::Error>() {
+                return match req.status() {
+                    Some(status) if status == 401 || status == 403 => CliError::Auth(e),
+                    _ => CliError::Network(e),
+                };
+            }
+            if cause.is::<serde_yaml::Error>()
+                || cause.is::<serde_json::Error>()
+                || cause.is::<protobuf::Error>()
+            {
+                return CliError::Parse(e);
+            }
+        }
+
+        CliError::Usage(e)
+    }
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Usage(e) | CliError::Parse(e) | CliError::Network(e) | CliError::Auth(e) => {
+                write!(f, "{:?}", e)
+            }
+        }
+    }
+}
@@ -1,21 +1,53 @@
 #![allow(unused)]
+use std::fmt::Display;
 use std::io::Write;
 use std::ops::Sub;
 use std::path::Path;
 use std::process::ExitCode;
-use std::{collections::HashMap, ffi::OsString, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::{OsStr, OsString},
+    path::PathBuf,
+};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
+use clap_complete::Shell;
+use futures::StreamExt;
 use human_bytes::human_bytes;
-use modsurfer_api::{ApiClient, Client, Persisted};
+use modsurfer_api::{
+    ApiClient, Client, EventFilter, ModuleEventKind, ModuleQuery, Persisted, SortDirection,
+    SortField,
+};
 use modsurfer_convert::{Audit, AuditOutcome, Pagination};
-use modsurfer_module::{Module, SourceLanguage};
-use modsurfer_validation::validate_module;
+use modsurfer_module::{Bloat, Module, SourceLanguage, CAPABILITIES, VERSION_METADATA_KEY};
+use modsurfer_validation::{
+    find_vulnerabilities, link_check, plan_checkfile_with, resolve_checkfile, run_report_hooks,
+    test_policy, upgrade_check, validate_module, validate_module_against, validate_module_at,
+    validate_module_timed, validate_module_with, validate_module_within, validate_world, Advice,
+    Baseline, CheckfileIdentity, Comparison, Host, InvalidModule, LocalPlugin, MemoryBudget,
+    NamespaceGraph, ParseOptions, PluginLimits, ReportEnvelope, ReportHook, Validation, Verdict,
+    World,
+};
 use serde::Serialize;
 use url::Url;
 
-use super::api_result::{ApiResult, ApiResults, SimpleApiResult, SimpleApiResults};
-use super::generate::checkfile_from_module;
+use super::api_result::{
+    ApiResult, ApiResults, BloatResult, CapabilitiesResult, CapabilityResult, NamespaceUsageResult,
+    PingResult, SimilarResult, SimilarResults, SimpleApiResult, SimpleApiResults, StatsResult,
+};
+use super::audit_config::{AuditConfig, AuditSummary};
+use super::audit_state::AuditState;
+use super::compliance::ComplianceResult;
+use super::error::{CliError, EXIT_PARSE, EXIT_USAGE};
+use super::generate::{checkfile_from_module, checkfiles_from_modules, snapshot_module};
+use super::location::{fetch_module, LocationCredentials};
+use super::metadata::{expand_metadata, read_metadata_file};
+use super::output::{csv_record, to_csv};
+use super::plugin_index::PluginIndex;
+use super::plugin_manifest::{PluginManifest, MANIFEST_FILE};
+use super::profile::{Config, ProfilesResult};
+use super::progress;
+use super::report_dir::{write_reports, ReportFormat};
 
 pub type Id = i64;
 pub type Hash = String;
@@ -27,11 +59,24 @@ pub type Limit = u32;
 pub type Version = String;
 pub type ModuleFile = PathBuf;
 pub type CheckFile = PathBuf;
-pub type MetadataEntry = String;
+pub type MetadataEntry = (String, String);
+/// A checkfile field override in the `path=value` form, e.g. `size.max=6MB`.
+pub type Override = (String, String);
 pub type WithContext = bool;
 pub type Identifier = String;
 pub type PluginName = String;
 pub type OutputFile = PathBuf;
+pub type Tag = String;
+
+const NO_COLOR_ENV: &str = "NO_COLOR";
+const ASCII_ENV: &str = "MODSURFER_ASCII";
+const MAX_WIDTH_ENV: &str = "MODSURFER_MAX_WIDTH";
+const MAX_COLUMN_WIDTH_ENV: &str = "MODSURFER_MAX_COLUMN_WIDTH";
+/// The maximum number of requests sent to the server at once when fetching many modules.
+const CONCURRENCY_ENV: &str = "MODSURFER_CONCURRENCY";
+
+/// How long `watch-registry` waits before resubscribing after an error.
+const WATCH_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
 
 #[derive(Clone, Debug)]
 pub enum BytesOrPath {
@@ -76,8 +121,100 @@ impl PathOrUrl {
     async fn resolve(&self) -> Result<Vec<u8>, anyhow::Error> {
         match self {
             PathOrUrl::Path(v) => Ok(tokio::fs::read(v).await?),
-            PathOrUrl::Url(v) => Ok(reqwest::get(v.as_str()).await?.bytes().await?.to_vec()),
+            PathOrUrl::Url(v) => Ok(modsurfer_validation::http_client()?
+                .get(v.as_str())
+                .send()
+                .await?
+                .bytes()
+                .await?
+                .to_vec()),
+        }
+    }
+}
+
+/// Parse a relative age such as `30s`, `15m`, `12h`, `90d` or `2w` into a duration.
+pub fn parse_age(s: &str) -> Result<chrono::Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in `{s}`, expected one of: s, m, h, d, w"))?;
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<i64>()
+        .map_err(|_| format!("invalid number in `{s}`"))?;
+
+    let age = match unit {
+        "s" => chrono::Duration::try_seconds(value),
+        "m" => chrono::Duration::try_minutes(value),
+        "h" => chrono::Duration::try_hours(value),
+        "d" => chrono::Duration::try_days(value),
+        "w" => chrono::Duration::try_weeks(value),
+        _ => {
+            return Err(format!(
+                "unknown unit `{unit}` in `{s}`, expected one of: s, m, h, d, w"
+            ))
         }
+    };
+    age.ok_or_else(|| format!("age `{s}` is too large"))
+}
+
+/// Parse the kind of module event to watch for, e.g. `created`.
+pub fn parse_event_kind(s: &str) -> Result<ModuleEventKind, String> {
+    s.parse::<ModuleEventKind>().map_err(|e| e.to_string())
+}
+
+/// Parse the field to sort search results by, e.g. `risk_score`.
+pub fn parse_sort_field(s: &str) -> Result<SortField, String> {
+    SortField::from_str(s).ok_or_else(|| {
+        format!("unknown sort field `{s}`, expected one of: size, name, created_at, language, imports_count, exports_count, sha256, complexity, risk_score")
+    })
+}
+
+/// Parse the direction to sort search results in, `asc` or `desc`.
+pub fn parse_sort_direction(s: &str) -> Result<SortDirection, String> {
+    SortDirection::from_str(s)
+        .ok_or_else(|| format!("unknown sort direction `{s}`, expected asc or desc"))
+}
+
+/// Parse a metadata entry in the `key=value` form.
+pub fn parse_metadata_entry(s: &str) -> Result<MetadataEntry, String> {
+    match s.split_once('=') {
+        Some((k, v)) if !k.is_empty() => Ok((k.to_string(), v.to_string())),
+        _ => Err(format!("invalid metadata entry `{s}`, expected key=value")),
+    }
+}
+
+/// Parse the longest a module may take to parse, in the same form as `parse_age`, e.g. `30s`.
+pub fn parse_timeout(s: &str) -> Result<std::time::Duration, String> {
+    match parse_age(s)?.to_std() {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!(
+            "invalid timeout `{s}`, expected a positive duration"
+        )),
+    }
+}
+
+// the `--plugin-memory-max` global arg, in bytes
+fn plugin_memory_max(args: &clap::ArgMatches) -> Option<u64> {
+    args.get_one::<MemoryBudget>("plugin-memory-max")
+        .map(MemoryBudget::bytes)
+}
+
+// the `--post-hook` args of `generate` and `validate`
+fn post_hooks(args: &clap::ArgMatches) -> Vec<PathBuf> {
+    args.get_many::<PathBuf>("post-hook")
+        .unwrap_or_default()
+        .cloned()
+        .collect()
+}
+
+/// Parse a checkfile field override in the `path=value` form, e.g. `size.max=6MB`.
+pub fn parse_override(s: &str) -> Result<Override, String> {
+    match s.split_once('=') {
+        Some((path, value)) if !path.is_empty() => Ok((path.to_string(), value.to_string())),
+        _ => Err(format!(
+            "invalid override `{s}`, expected path=value (e.g. size.max=6MB)"
+        )),
     }
 }
 
@@ -86,12 +223,36 @@ pub struct Cli {
     cmd: clap::Command,
     help: String,
     host: Url,
+    token: Option<String>,
+    metadata: BTreeMap<String, String>,
+    locations: LocationCredentials,
+}
+
+/// The format `graph` renders a graph in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphFormat {
+    #[default]
+    Dot,
+    Json,
+    Svg,
+}
+
+impl From<String> for GraphFormat {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "json" => Self::Json,
+            "svg" => Self::Svg,
+            _ => Self::Dot,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum OutputFormat {
     Json,
     Table,
+    Csv,
+    Yaml,
 }
 
 impl Default for OutputFormat {
@@ -100,19 +261,45 @@ impl Default for OutputFormat {
     }
 }
 
-impl From<String> for OutputFormat {
-    fn from(value: String) -> Self {
-        match value.as_str() {
-            "json" => Self::Json,
-            _ => Self::Table,
-        }
+impl clap::ValueEnum for OutputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            OutputFormat::Table,
+            OutputFormat::Json,
+            OutputFormat::Csv,
+            OutputFormat::Yaml,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(match self {
+            OutputFormat::Table => clap::builder::PossibleValue::new("table"),
+            OutputFormat::Json => clap::builder::PossibleValue::new("json"),
+            OutputFormat::Csv => clap::builder::PossibleValue::new("csv"),
+            OutputFormat::Yaml => clap::builder::PossibleValue::new("yaml").alias("yml"),
+        })
     }
 }
 
-impl From<OsString> for OutputFormat {
-    fn from(value: OsString) -> Self {
-        let s = value.into_string().unwrap_or_default();
-        s.into()
+impl OutputFormat {
+    /// Render the output of a command, using its `Display` impl for tables.
+    pub fn render<T: Serialize + Display + ?Sized>(&self, value: &T) -> Result<String> {
+        match self {
+            OutputFormat::Table => Ok(value.to_string()),
+            _ => self.serialize(value),
+        }
+    }
+
+    /// Render the output of a command which has no table view, where tables fall back to JSON.
+    pub fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<String> {
+        let output = match self {
+            OutputFormat::Json | OutputFormat::Table => serde_json::to_string_pretty(value)?,
+            OutputFormat::Csv => to_csv(value)?,
+            OutputFormat::Yaml => serde_yaml::to_string(value)?,
+        };
+
+        // output is printed with a trailing newline, like JSON and tables
+        Ok(output.trim_end_matches('\n').to_string())
     }
 }
 
@@ -135,10 +322,25 @@ impl IdOrFilename {
     async fn fetch(&self, client: &Client) -> Result<Module, anyhow::Error> {
         match self {
             IdOrFilename::Id(id) => client.get_module(*id).await.map(|x| x.into_inner()),
-            IdOrFilename::Filename(filename) => {
-                let data = std::fs::read(filename)?;
-                modsurfer_validation::Module::parse(data)
+            IdOrFilename::Filename(filename) => modsurfer_validation::Module::parse_file(filename),
+        }
+    }
+
+    /// Like `fetch`, but a module given by ID is fetched from its location and parsed locally.
+    async fn fetch_bytes(
+        &self,
+        client: &Client,
+        credentials: &LocationCredentials,
+    ) -> Result<Module, anyhow::Error> {
+        match self {
+            IdOrFilename::Id(id) => {
+                let stored = client.get_module(*id).await?.into_inner();
+                let wasm = fetch_module(&stored, credentials).await?;
+                let mut module = modsurfer_validation::Module::parse(wasm)?;
+                module.location = stored.location;
+                Ok(module)
             }
+            IdOrFilename::Filename(_) => self.fetch(client).await,
         }
     }
 }
@@ -151,11 +353,23 @@ pub enum Subcommand<'a> {
         &'a ModuleFile,
         Option<&'a CheckFile>,
         HashMap<String, String>,
+        Option<&'a PathBuf>,
         Option<Url>,
+        Vec<Tag>,
+        Option<&'a Version>,
         &'a OutputFormat,
     ),
     Delete(Vec<Id>, &'a OutputFormat),
-    Get(Id, &'a OutputFormat),
+    DeleteMatching(ModuleQuery, bool, &'a OutputFormat),
+    Get(
+        Option<Id>,
+        Option<&'a Hash>,
+        Option<&'a PathBuf>,
+        &'a OutputFormat,
+    ),
+    Tag(Id, Vec<Tag>, &'a OutputFormat),
+    Quarantine(Id, &'a String, &'a OutputFormat),
+    Unquarantine(Id, &'a OutputFormat),
     List(Offset, Limit, &'a OutputFormat),
     Search(
         Option<&'a Hash>,
@@ -163,34 +377,146 @@ pub enum Subcommand<'a> {
         Option<&'a FunctionName>,
         Option<SourceLanguage>,
         Option<&'a TextSearch>,
+        Vec<Tag>,
+        Option<&'a Version>,
+        Option<u32>,
+        Option<u32>,
+        Vec<String>,
+        Option<SortField>,
+        Option<SortDirection>,
         Offset,
         Limit,
         &'a OutputFormat,
     ),
-    Generate(ModuleFile, CheckFile),
-    Validate(ModuleFile, CheckFile, &'a OutputFormat),
+    Generate(ModuleFile, CheckFile, bool, Vec<PathBuf>),
+    GenerateDir(String, PathBuf, bool, Vec<PathBuf>),
+    Snapshot(ModuleFile, Option<PathBuf>),
+    Validate(
+        ModuleFile,
+        CheckFile,
+        Option<PathBuf>,
+        Vec<Override>,
+        Option<PathBuf>,
+        Option<PathBuf>,
+        bool,
+        Option<MemoryBudget>,
+        bool,
+        Vec<MetadataEntry>,
+        Vec<ReportHook>,
+        &'a OutputFormat,
+    ),
+    ValidateRemote(&'a ModuleFile, CheckFile, Vec<Override>, &'a OutputFormat),
+    ValidatePlan(CheckFile, Vec<Override>, &'a OutputFormat),
+    ValidateWit(ModuleFile, PathBuf, Option<&'a String>, &'a OutputFormat),
     Yank(Id, Version, &'a OutputFormat),
-    Audit(CheckFile, AuditOutcome, Offset, Limit, &'a OutputFormat),
-    Diff(IdOrFilename, IdOrFilename, WithContext),
+    Audit(
+        CheckFile,
+        Option<PathBuf>,
+        AuditOutcome,
+        Offset,
+        Limit,
+        Option<PathBuf>,
+        ReportFormat,
+        Option<PathBuf>,
+        Option<PathBuf>,
+        &'a OutputFormat,
+    ),
+    Diff(IdOrFilename, IdOrFilename, WithContext, bool),
+    Graph(Vec<IdOrFilename>, bool, GraphFormat, Option<&'a OutputFile>),
     CallPlugin(
         Identifier,
         FunctionName,
         BytesOrPath,
         Option<&'a OutputFile>,
     ),
+    CallLocalPlugin(
+        PathBuf,
+        FunctionName,
+        BytesOrPath,
+        Option<&'a OutputFile>,
+        PluginLimits,
+        Option<&'a PathBuf>,
+        BTreeMap<String, String>,
+    ),
     InstallPlugin(Identifier, Option<&'a PluginName>, PathOrUrl),
+    InstallPluginManifest(&'a PathBuf, Option<&'a PluginName>),
+    InstallIndexedPlugin(
+        &'a String,
+        Option<&'a Identifier>,
+        Option<&'a PluginName>,
+        Option<&'a String>,
+    ),
+    SearchPlugins(&'a String, Option<&'a String>, &'a OutputFormat),
+    ProfileList(&'a OutputFormat),
+    ProfileUse(&'a String),
     UninstallPlugin(Identifier),
+    Ping(&'a OutputFormat),
+    Similar(IdOrFilename, Option<PathBuf>, f64, &'a OutputFormat),
+    Stats(usize, &'a OutputFormat),
+    ReportNamespaces(Option<&'a String>, Option<u64>, &'a OutputFormat),
+    Explain(&'a String, &'a OutputFormat),
+    CompareCheckfiles(CheckFile, CheckFile, &'a OutputFormat),
+    TestPolicy(PathBuf, Option<CheckFile>, &'a OutputFormat),
+    LinkCheck(PathBuf, ModuleFile, &'a String, &'a OutputFormat),
+    Capabilities(ModuleFile, &'a OutputFormat),
+    Vuln(ModuleFile, &'a String, &'a OutputFormat),
+    Bloat(ModuleFile, usize, &'a OutputFormat),
+    Completions(Shell),
+    Man(Option<&'a PathBuf>),
+    WatchRegistry(EventFilter, &'a OutputFormat),
 }
 
 impl Cli {
     pub fn new(mut cmd: clap::Command, host: Url) -> Self {
         let help = cmd.render_long_help().to_string();
 
-        Self { cmd, help, host }
+        Self {
+            cmd,
+            help,
+            host,
+            token: None,
+            metadata: BTreeMap::new(),
+            locations: LocationCredentials::default(),
+        }
+    }
+
+    /// Send a bearer token in the `Authorization` header of every request to the server.
+    pub fn token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Add metadata to every module created, from templates of environment variables. See
+    /// `expand_metadata`.
+    pub fn metadata(mut self, templates: BTreeMap<String, String>) -> Self {
+        self.metadata = templates;
+        self
+    }
+
+    /// Fetch modules from their locations with these credentials.
+    pub fn locations(mut self, credentials: LocationCredentials) -> Self {
+        self.locations = credentials;
+        self
     }
 
     pub async fn execute(&self) -> Result<ExitCode> {
-        match self.cmd.clone().get_matches().subcommand() {
+        let matches = self.cmd.clone().get_matches();
+        apply_display_args(&matches)?;
+        modsurfer_validation::set_proxy(matches.get_one::<String>("proxy").cloned());
+        modsurfer_validation::set_strict_checkfile(matches.get_flag("strict-checkfile"));
+        modsurfer_validation::Parser::shared().set_options(ParseOptions {
+            extract_strings: !matches.get_flag("no-strings"),
+            build_graph: !matches.get_flag("no-graph"),
+            compute_hashes: !matches.get_flag("no-hashes"),
+        });
+        modsurfer_validation::Parser::shared().set_limits(PluginLimits {
+            memory_max: plugin_memory_max(&matches),
+            timeout: matches
+                .get_one::<std::time::Duration>("parse-timeout")
+                .copied(),
+        });
+
+        match matches.subcommand() {
             Some(x) => self.run(x).await,
             _ => {
                 println!("{}", self.help);
@@ -199,28 +525,84 @@ impl Cli {
         }
     }
 
-    async fn run(&self, sub: impl Into<Subcommand<'_>>) -> Result<ExitCode> {
-        match sub.into() {
-            Subcommand::Unknown => unimplemented!("Unknown subcommand.\n\n{}", self.help),
-            Subcommand::Create(module_path, checkfile_path, metadata, location, output_format) => {
+    /// A client for the Modsurfer server, sending requests through the proxy set by `--proxy`, if
+    /// any, and at most `MODSURFER_CONCURRENCY` requests at once.
+    fn client(&self) -> Result<Client> {
+        let mut builder = Client::builder().base_url(self.host.as_str());
+        if let Some(token) = &self.token {
+            let authorization = format!("Bearer {}", token);
+            builder = builder
+                .on_request(move |req| req.insert_header("Authorization", authorization.clone()));
+        }
+        if let Some(concurrency) = std::env::var(CONCURRENCY_ENV)
+            .ok()
+            .filter(|v| !v.is_empty())
+        {
+            let concurrency = concurrency
+                .parse()
+                .map_err(|e| anyhow!("invalid {}: {}", CONCURRENCY_ENV, e))?;
+            builder = builder.concurrency(concurrency);
+        }
+        match modsurfer_validation::proxy() {
+            Some(proxy) => builder.proxy(proxy).build(),
+            None => builder.build(),
+        }
+    }
+
+    async fn run(&self, sub: (&str, &clap::ArgMatches)) -> Result<ExitCode> {
+        match Subcommand::try_from(sub)? {
+            Subcommand::Unknown => Err(anyhow!("Unknown subcommand.\n\n{}", self.help)),
+            Subcommand::Create(
+                module_path,
+                checkfile_path,
+                entries,
+                metadata_file,
+                location,
+                tags,
+                version,
+                output_format,
+            ) => {
                 if let Some(check) = checkfile_path {
-                    let report = validate_module(&module_path, check).await?;
-                    if report.has_failures() {
-                        println!(
-                            "{}",
-                            match output_format {
-                                OutputFormat::Json => serde_json::to_string_pretty(&report)?,
-                                OutputFormat::Table => report.to_string(),
-                            }
-                        );
+                    // validate the module where it will be stored, so the checkfile can govern its
+                    // name and location
+                    let stored_at = location
+                        .as_ref()
+                        .map(|url| url.to_string())
+                        .unwrap_or_else(|| module_path.display().to_string());
+                    let report = validate_module_at(&module_path, check, &stored_at).await?;
+                    let verdict = Verdict::new(&report);
+                    if !verdict.passed() {
+                        println!("{}", output_format.render(&report)?);
 
-                        return Ok(report.as_exit_code());
+                        return Ok(verdict.exit_code());
                     }
                 }
 
+                // entries from the command line override those from the file, which override
+                // the profile's
+                let mut metadata = expand_metadata(&self.metadata)?;
+                if let Some(path) = metadata_file {
+                    metadata.extend(read_metadata_file(path).await?);
+                }
+                metadata.extend(entries);
+
                 let wasm = tokio::fs::read(module_path).await?;
-                let client = Client::new(self.host.as_str())?;
-                let (id, hash) = client.create_module(wasm, Some(metadata), location).await?;
+                let version = version
+                    .cloned()
+                    .or_else(|| metadata.get(VERSION_METADATA_KEY).cloned());
+                let client = self.client()?;
+                let progress = progress::spinner(
+                    format!(
+                        "Uploading {} ({})",
+                        module_path.display(),
+                        human_bytes(wasm.len() as f64)
+                    ),
+                    output_format,
+                );
+                let (id, hash) = client
+                    .create_module(wasm, Some(metadata), location, Some(tags), version)
+                    .await?;
+                progress.finish_and_clear();
 
                 let output = SimpleApiResults {
                     results: vec![SimpleApiResult {
@@ -229,18 +611,12 @@ impl Cli {
                     }],
                 };
 
-                println!(
-                    "{}",
-                    match output_format {
-                        OutputFormat::Json => serde_json::to_string_pretty(&output)?,
-                        OutputFormat::Table => output.to_string(),
-                    }
-                );
+                println!("{}", output_format.render(&output)?);
 
                 Ok(ExitCode::SUCCESS)
             }
             Subcommand::Delete(ids, output_format) => {
-                let client = Client::new(self.host.as_str())?;
+                let client = self.client()?;
                 let deleted_modules = client.delete_modules(ids).await?;
 
                 let results = deleted_modules
@@ -253,46 +629,341 @@ impl Cli {
 
                 let output = SimpleApiResults { results };
 
-                println!(
-                    "{}",
-                    match output_format {
-                        OutputFormat::Json => serde_json::to_string_pretty(&output)?,
-                        OutputFormat::Table => output.to_string(),
-                    }
-                );
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::DeleteMatching(query, dry_run, output_format) => {
+                let client = self.client()?;
+                let matched = client.delete_modules_matching(&query, dry_run).await?;
+
+                let mut results: Vec<_> = matched
+                    .iter()
+                    .map(|(id, hash)| SimpleApiResult {
+                        module_id: *id,
+                        hash: hash.clone(),
+                    })
+                    .collect();
+                results.sort_by_key(|r| r.module_id);
+
+                let output = SimpleApiResults { results };
+
+                println!("{}", output_format.render(&output)?);
+
+                if dry_run {
+                    eprintln!(
+                        "Dry run: {} module(s) would be deleted. Re-run with `--confirm` to delete them.",
+                        output.results.len()
+                    );
+                }
 
                 Ok(ExitCode::SUCCESS)
             }
-            Subcommand::Get(id, output_format) => {
-                let client = Client::new(self.host.as_str())?;
+            Subcommand::Get(id, hash, save, output_format) => {
+                let client = self.client()?;
+                let m = match (id, hash) {
+                    (_, Some(hash)) => client.get_module_by_hash(hash).await?,
+                    (Some(id), None) => client.get_module(id).await?,
+                    (None, None) => anyhow::bail!("either --id or --hash is required"),
+                };
+                if let Some(path) = save {
+                    let progress = progress::spinner(
+                        format!("Fetching {}", m.get_inner().location),
+                        output_format,
+                    );
+                    let wasm = fetch_module(m.get_inner(), &self.locations).await?;
+                    progress.finish_and_clear();
+                    tokio::fs::write(path, wasm)
+                        .await
+                        .with_context(|| format!("failed to write {}", path.display()))?;
+                }
+                let results = vec![to_api_result(&m)];
+                let output = ApiResults { results };
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Tag(id, tags, output_format) => {
+                let client = self.client()?;
+                client.tag_module(id, tags).await?;
                 let m = client.get_module(id).await?;
                 let results = vec![to_api_result(&m)];
                 let output = ApiResults { results };
 
-                println!(
-                    "{}",
-                    match output_format {
-                        OutputFormat::Json => serde_json::to_string_pretty(&output)?,
-                        OutputFormat::Table => output.to_string(),
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Quarantine(id, reason, output_format) => {
+                let client = self.client()?;
+                client.quarantine_module(id, reason.clone()).await?;
+                let m = client.get_module(id).await?;
+                let results = vec![to_api_result(&m)];
+                let output = ApiResults { results };
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Unquarantine(id, output_format) => {
+                let client = self.client()?;
+                client.unquarantine_module(id).await?;
+                let m = client.get_module(id).await?;
+                let results = vec![to_api_result(&m)];
+                let output = ApiResults { results };
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Ping(output_format) => {
+                let client = self.client()?;
+                let started = std::time::Instant::now();
+                if let Err(e) = client.health().await {
+                    eprintln!(
+                        "No healthy Modsurfer server found at {} (set MODSURFER_BASE_URL to change it): {}",
+                        self.host, e
+                    );
+                    return Ok(CliError::from(e).exit_code());
+                }
+                let latency_ms = started.elapsed().as_millis() as u64;
+
+                // older servers may not implement the info endpoint, but are still reachable
+                let info = client.server_info().await.ok();
+                let output = PingResult {
+                    host: self.host.to_string(),
+                    latency_ms,
+                    version: info.as_ref().map(|i| i.version.clone()),
+                    features: info
+                        .as_ref()
+                        .map(|i| i.features.clone())
+                        .unwrap_or_default(),
+                    plugins_enabled: info.as_ref().map(|i| i.plugins_enabled),
+                };
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Similar(module, dir, threshold, output_format) => {
+                let client = self.client()?;
+                let source = module.fetch(&client).await?;
+
+                let progress = progress::spinner("Comparing modules", output_format);
+                let mut results = match dir {
+                    Some(dir) => similar_in_dir(&source, &dir, threshold)?,
+                    None => client
+                        .similar_modules(&source, threshold)
+                        .await?
+                        .into_iter()
+                        .filter(
+                            |(m, _)| !matches!(module, IdOrFilename::Id(id) if m.get_id() == id),
+                        )
+                        .map(|(m, similarity)| SimilarResult {
+                            module_id: Some(m.get_id()),
+                            hash: m.get_inner().hash.clone(),
+                            file_name: m.get_inner().file_name(),
+                            similarity,
+                        })
+                        .collect(),
+                };
+                progress.finish_and_clear();
+                results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+                let output = SimilarResults { results };
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Stats(top, output_format) => {
+                let client = self.client()?;
+                let progress = progress::spinner("Collecting module statistics", output_format);
+                let stats = client.registry_stats().await?;
+                progress.finish_and_clear();
+                let output = StatsResult { stats, top };
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::ReportNamespaces(namespace, max_modules, output_format) => {
+                let client = self.client()?;
+                let progress = progress::spinner("Counting imported namespaces", output_format);
+                let usage = client
+                    .namespace_usage(namespace.map(String::as_str))
+                    .await?;
+                progress.finish_and_clear();
+                let output = NamespaceUsageResult::new(usage, max_modules);
+
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Explain(rule, output_format) => {
+                let Some(rule) = modsurfer_validation::find_rule(rule) else {
+                    let codes = modsurfer_validation::RULES
+                        .iter()
+                        .map(|r| format!("{} ({})", r.code, r.property))
+                        .collect::<Vec<_>>();
+                    eprintln!(
+                        "No rule found for `{}`. Known rules:\n  {}",
+                        rule,
+                        codes.join("\n  ")
+                    );
+                    return Ok(ExitCode::from(EXIT_USAGE));
+                };
+
+                println!("{}", output_format.render(rule)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::CompareCheckfiles(before, after, output_format) => {
+                let read = |path: PathBuf| async move {
+                    let buf = tokio::fs::read(&path).await?;
+                    serde_yaml::from_slice::<Validation>(&buf)
+                        .with_context(|| format!("invalid checkfile {}", path.display()))
+                };
+                let comparison = Comparison::new(&read(before).await?, &read(after).await?)?;
+
+                println!("{}", output_format.render(&comparison)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::TestPolicy(manifest, checkfile, output_format) => {
+                let report = test_policy(&manifest, checkfile.as_deref()).await?;
+
+                println!("{}", output_format.render(&report)?);
+
+                if report.passed() {
+                    Ok(ExitCode::SUCCESS)
+                } else {
+                    Ok(ExitCode::FAILURE)
+                }
+            }
+            Subcommand::LinkCheck(host, file, namespace, output_format) => {
+                let host = if host.extension().is_some_and(|ext| ext == "wasm") {
+                    let provider = modsurfer_validation::Module::parse_file(&host)?;
+                    Host::from_module(&provider, namespace)
+                } else {
+                    serde_yaml::from_slice(&tokio::fs::read(&host).await?)
+                        .with_context(|| format!("invalid host description {}", host.display()))?
+                };
+                let module = modsurfer_validation::Module::parse_file(&file)?;
+
+                let report = link_check(&host, &module);
+                let verdict = Verdict::new(&report);
+                match output_format {
+                    OutputFormat::Table => {
+                        if report.has_failures() {
+                            println!("{report}")
+                        }
                     }
-                );
+                    _ => println!("{}", output_format.serialize(&report)?),
+                }
+
+                Ok(verdict.exit_code())
+            }
+            Subcommand::Capabilities(file, output_format) => {
+                let module = modsurfer_validation::Module::parse_file(&file)?;
+                let mut granted = module.capabilities();
+                let result = CapabilitiesResult {
+                    capabilities: CAPABILITIES
+                        .iter()
+                        .map(|capability| {
+                            let imports = granted.remove(capability).unwrap_or_default();
+                            CapabilityResult {
+                                capability: *capability,
+                                granted: !imports.is_empty(),
+                                imports,
+                            }
+                        })
+                        .collect(),
+                };
+
+                println!("{}", output_format.render(&result)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Vuln(file, db, output_format) => {
+                let module = modsurfer_validation::Module::parse_file(&file)?;
+                let findings = find_vulnerabilities(&module.provenance.libraries, db).await?;
+
+                println!("{}", output_format.render(&findings)?);
 
                 Ok(ExitCode::SUCCESS)
             }
+            Subcommand::Bloat(file, top, output_format) => {
+                let bloat = Bloat::new(&tokio::fs::read(&file).await?).context(InvalidModule)?;
+
+                println!("{}", output_format.render(&BloatResult { bloat, top })?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::WatchRegistry(mut filter, output_format) => {
+                let client = self.client()?;
+                if let OutputFormat::Csv = output_format {
+                    print!(
+                        "{}",
+                        csv_record(&["id", "kind", "module_id", "hash", "occurred_at"])
+                    );
+                }
+                loop {
+                    let mut events = client.subscribe(filter.clone());
+                    while let Some(event) = events.next().await {
+                        match event {
+                            Ok(event) => {
+                                // resume from the last event seen if the subscription is interrupted
+                                filter.after_event_id = Some(event.id);
+                                let fields = [
+                                    event.id.to_string(),
+                                    event.kind.to_string(),
+                                    event.module_id.to_string(),
+                                    event.hash.clone(),
+                                    event.occurred_at.to_rfc3339(),
+                                ];
+                                match output_format {
+                                    OutputFormat::Json | OutputFormat::Yaml => {
+                                        let event = serde_json::json!({
+                                            "id": event.id,
+                                            "kind": fields[1],
+                                            "module_id": fields[2],
+                                            "hash": fields[3],
+                                            "occurred_at": fields[4],
+                                        });
+                                        if let OutputFormat::Json = output_format {
+                                            println!("{}", event)
+                                        } else {
+                                            // one YAML document per event
+                                            print!("---\n{}", serde_yaml::to_string(&event)?)
+                                        }
+                                    }
+                                    OutputFormat::Csv => print!("{}", csv_record(&fields)),
+                                    OutputFormat::Table => println!(
+                                        "{} {:<8} module {} ({})",
+                                        fields[4], fields[1], fields[2], fields[3]
+                                    ),
+                                }
+                            }
+                            Err(e) => eprintln!(
+                                "Subscription interrupted, retrying in {}s: {}",
+                                WATCH_RETRY_DELAY.as_secs(),
+                                e
+                            ),
+                        }
+                    }
+
+                    tokio::time::sleep(WATCH_RETRY_DELAY).await;
+                }
+            }
             Subcommand::List(offset, limit, output_format) => {
-                let client = Client::new(self.host.as_str())?;
+                let client = self.client()?;
                 let list = client.list_modules(offset, limit).await?;
 
                 let results = list.vec().into_iter().map(to_api_result).collect();
                 let output = ApiResults { results };
 
-                println!(
-                    "{}",
-                    match output_format {
-                        OutputFormat::Json => serde_json::to_string_pretty(&output)?,
-                        OutputFormat::Table => output.to_string(),
-                    }
-                );
+                println!("{}", output_format.render(&output)?);
 
                 Ok(ExitCode::SUCCESS)
             }
@@ -302,11 +973,18 @@ impl Cli {
                 func_name,
                 src_lang,
                 text_search,
+                tags,
+                version,
+                min_complexity,
+                max_complexity,
+                namespaces,
+                sort_field,
+                sort_direction,
                 offset,
                 limit,
                 output_format,
             ) => {
-                let client = Client::new(self.host.as_str())?;
+                let client = self.client()?;
                 let modules = client
                     .search_modules(
                         None,
@@ -323,84 +1001,519 @@ impl Cli {
                         None,
                         None,
                         text_search.map(|s| vec![s.clone()]),
+                        if tags.is_empty() { None } else { Some(tags) },
+                        version.cloned(),
+                        min_complexity,
+                        max_complexity,
+                        if namespaces.is_empty() {
+                            None
+                        } else {
+                            Some(namespaces)
+                        },
                         offset,
                         limit,
-                        None,
-                        None,
+                        sort_field,
+                        sort_direction,
                     )
                     .await?;
 
                 let results = modules.vec().into_iter().map(to_api_result).collect();
                 let output = ApiResults { results };
 
-                println!(
-                    "{}",
-                    match output_format {
-                        OutputFormat::Json => serde_json::to_string_pretty(&output)?,
-                        OutputFormat::Table => output.to_string(),
+                println!("{}", output_format.render(&output)?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Generate(file, check, minimal, hooks) => {
+                match checkfile_from_module(&file, &check, minimal, &hooks).await {
+                    Ok(_) => Ok(ExitCode::SUCCESS),
+                    Err(e) => {
+                        println!("{:?}", e);
+                        Ok(CliError::from(e).exit_code())
                     }
+                }
+            }
+            Subcommand::GenerateDir(pattern, out_dir, minimal, hooks) => {
+                let generated =
+                    checkfiles_from_modules(&pattern, &out_dir, minimal, &hooks).await?;
+                let failed = generated.iter().filter(|g| g.error.is_some()).count();
+                for g in &generated {
+                    if let Some(e) = &g.error {
+                        eprintln!(
+                            "Failed to generate a checkfile for module {}: {}",
+                            g.module, e
+                        );
+                    }
+                }
+                println!(
+                    "Generated {} checkfiles in {}",
+                    generated.len() - failed,
+                    out_dir.display()
                 );
 
+                if failed > 0 {
+                    Ok(ExitCode::from(EXIT_PARSE))
+                } else {
+                    Ok(ExitCode::SUCCESS)
+                }
+            }
+            Subcommand::Snapshot(file, output) => {
+                snapshot_module(&file, output.as_ref()).await?;
                 Ok(ExitCode::SUCCESS)
             }
-            Subcommand::Generate(file, check) => match checkfile_from_module(&file, &check).await {
-                Ok(_) => Ok(ExitCode::SUCCESS),
-                Err(e) => {
-                    println!("{:?}", e);
-                    Ok(ExitCode::FAILURE)
+            Subcommand::Validate(
+                file,
+                check,
+                against,
+                overrides,
+                baseline,
+                write_baseline,
+                timings,
+                max_memory,
+                advise,
+                compare_registry,
+                post_hooks,
+                output_format,
+            ) => {
+                let (mut report, timings) = if let Some(snapshot) = &against {
+                    (validate_module_against(&file, snapshot).await?, None)
+                } else if let Some(budget) = max_memory {
+                    let (report, skipped) =
+                        validate_module_within(&file, &check, &overrides, budget).await?;
+                    skipped.iter().for_each(|skipped| eprintln!("{}", skipped));
+                    (report, None)
+                } else if timings {
+                    let (report, timings) =
+                        validate_module_timed(&file, &check, &overrides).await?;
+                    (report, Some(timings))
+                } else {
+                    (validate_module_with(&file, &check, &overrides).await?, None)
+                };
+
+                let previous = if compare_registry.is_empty() {
+                    None
+                } else {
+                    let module = modsurfer_validation::Module::parse_file(&file)?;
+                    let query = ModuleQuery {
+                        metadata: compare_registry.iter().cloned().collect(),
+                        ..Default::default()
+                    };
+                    // the latest version other than this module, which may have been stored already
+                    let previous = self
+                        .client()?
+                        .find_modules(&query)
+                        .await?
+                        .into_iter()
+                        .filter(|m| m.get_inner().hash != module.hash)
+                        .max_by_key(|m| (m.get_inner().inserted_at, m.get_id()));
+                    match &previous {
+                        Some(previous) => {
+                            eprintln!(
+                                "Compared with module #{}{}",
+                                previous.get_id(),
+                                previous
+                                    .get_inner()
+                                    .version
+                                    .as_ref()
+                                    .map(|v| format!(" (version {})", v))
+                                    .unwrap_or_default()
+                            );
+                            let upgrade = upgrade_check(previous.get_inner(), &module);
+                            report.fails.extend(upgrade.fails);
+                        }
+                        None => eprintln!(
+                            "No earlier version of the module found in Modsurfer, skipping the comparison"
+                        ),
+                    }
+                    previous
+                };
+                report = run_report_hooks(&post_hooks, report)?;
+
+                if let Some(path) = write_baseline {
+                    let baseline = Baseline::new(&report);
+                    tokio::fs::write(&path, serde_json::to_vec_pretty(&baseline)?).await?;
+                    eprintln!(
+                        "Wrote baseline of {} known failure(s) to {}",
+                        baseline.fails.len(),
+                        path.display()
+                    );
+                    return Ok(ExitCode::SUCCESS);
                 }
-            },
-            Subcommand::Validate(file, check, output_format) => {
-                let report = validate_module(&file, &check).await?;
+
+                if let Some(path) = baseline {
+                    let baseline: Baseline = serde_json::from_slice(&tokio::fs::read(&path).await?)
+                        .with_context(|| format!("invalid baseline {}", path.display()))?;
+                    let suppressed = baseline.suppress(&mut report);
+                    if suppressed > 0 {
+                        eprintln!(
+                            "Ignored {} known failure(s) recorded in {}",
+                            suppressed,
+                            path.display()
+                        );
+                    }
+                }
+
+                let advice = if advise && Advice::applies(&report) {
+                    let wasm = tokio::fs::read(&file).await?;
+                    let bloat = Bloat::new(&wasm).context(InvalidModule)?;
+                    Some(Advice::new(
+                        &modsurfer_validation::Module::parse(&wasm)?,
+                        &bloat,
+                    ))
+                } else {
+                    None
+                };
+
+                let verdict = Verdict::new(&report);
+                match (output_format, timings, advice) {
+                    (OutputFormat::Table, timings, advice) => {
+                        if report.has_failures() {
+                            println!("{report}")
+                        }
+                        if let Some(advice) = advice {
+                            println!("{advice}")
+                        }
+                        if let Some(timings) = timings {
+                            println!("{timings}")
+                        }
+                    }
+                    (OutputFormat::Csv, None, None) => {
+                        println!("{}", output_format.serialize(&report)?)
+                    }
+                    (_, timings, advice) => {
+                        // the snapshot stands in for the checkfile when validating against one
+                        let checkfile = against.as_ref().unwrap_or(&check);
+                        let envelope = ReportEnvelope::new(&report)
+                            .module_file(&file)
+                            .checkfile(CheckfileIdentity::new(
+                                checkfile,
+                                &tokio::fs::read(checkfile).await?,
+                            ))
+                            .tool("modsurfer", env!("CARGO_PKG_VERSION"));
+                        let mut output = serde_json::to_value(envelope)?;
+                        if let Some(timings) = timings {
+                            output["timings"] = serde_json::to_value(timings)?;
+                        }
+                        if let Some(advice) = advice {
+                            output["advice"] = serde_json::to_value(advice)?;
+                        }
+                        if let Some(previous) = &previous {
+                            output["previous"] = serde_json::json!({
+                                "module_id": previous.get_id(),
+                                "hash": previous.get_inner().hash,
+                                "version": previous.get_inner().version,
+                            });
+                        }
+                        println!("{}", output_format.serialize(&output)?)
+                    }
+                };
+                Ok(verdict.exit_code())
+            }
+            Subcommand::ValidatePlan(check, overrides, output_format) => {
+                let plan = plan_checkfile_with(&check, &overrides).await?;
+                let output = match output_format {
+                    // the checks are the only part of a plan which fits in rows
+                    OutputFormat::Csv => output_format.serialize(&plan.checks)?,
+                    _ => output_format.serialize(&plan)?,
+                };
+                println!("{}", output);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::ValidateWit(file, wit, world, output_format) => {
+                let world = World::load(&wit, world.map(String::as_str))?;
+                let wasm = tokio::fs::read(&file).await?;
+                let component = World::of_component(&wasm)
+                    .with_context(|| format!("failed to read the world of {}", file.display()))?;
+
+                let report = validate_world(&world, &component);
+                let verdict = Verdict::new(&report);
                 match output_format {
-                    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
                     OutputFormat::Table => {
                         if report.has_failures() {
                             println!("{report}")
                         }
                     }
+                    _ => println!("{}", output_format.serialize(&report)?),
+                }
+
+                Ok(verdict.exit_code())
+            }
+            Subcommand::ValidateRemote(pattern, check, overrides, output_format) => {
+                let pattern = pattern.to_string_lossy();
+                let mut modules = vec![];
+                for path in glob::glob(&pattern)? {
+                    let path = path?;
+                    let wasm = tokio::fs::read(&path).await?;
+                    modules.push((path.display().to_string(), wasm));
+                }
+                if modules.is_empty() {
+                    return Err(anyhow!("no modules found matching {}", pattern));
+                }
+
+                let checkfile = if overrides.is_empty() {
+                    tokio::fs::read(&check).await?
+                } else {
+                    // resolve any remote checkfile here, so the server can't replace the
+                    // overridden fields by fetching it again
+                    let (mut validation, _) = resolve_checkfile(&check, &overrides).await?;
+                    validation.validate.url = None;
+                    serde_yaml::to_string(&validation)?.into_bytes()
                 };
-                Ok(report.as_exit_code())
+                let client = self.client()?;
+                let progress = progress::bar(modules.len() as u64, "Validating", output_format);
+                let results = client
+                    .validate_each(modules, checkfile)
+                    .inspect(|_| progress.inc(1))
+                    .collect::<Vec<_>>()
+                    .await;
+                progress.finish_and_clear();
+
+                let mut exit_code = ExitCode::SUCCESS;
+                let mut output = serde_json::Map::new();
+                for (name, result) in results {
+                    match result {
+                        Ok(report) => {
+                            let verdict = Verdict::new(&report);
+                            if !verdict.passed() {
+                                exit_code = verdict.exit_code();
+                                if let OutputFormat::Table = output_format {
+                                    println!("Report for module: {name}\n{report}");
+                                }
+                            }
+                            output.insert(name, serde_json::to_value(report)?);
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to validate module {name}: {e}");
+                            output.insert(name, serde_json::json!({ "error": e.to_string() }));
+                            exit_code = CliError::from(e).exit_code();
+                        }
+                    }
+                }
+
+                if !matches!(output_format, OutputFormat::Table) {
+                    println!("{}", output_format.serialize(&output)?);
+                }
+
+                Ok(exit_code)
             }
             Subcommand::Yank(_id, _version, _output_format) => {
                 println!("`yank` is not yet supported. Reach out to support@dylib.so for more information!");
 
-                Ok(ExitCode::FAILURE)
+                Ok(ExitCode::from(EXIT_USAGE))
             }
-            Subcommand::Audit(check, outcome, offset, limit, output_format) => {
-                let checkfile = tokio::fs::read(&check).await?;
+            Subcommand::Audit(
+                check,
+                config,
+                outcome,
+                offset,
+                limit,
+                report_dir,
+                report_format,
+                save_state,
+                export,
+                output_format,
+            ) => {
+                if save_state.is_some() && !matches!(outcome, AuditOutcome::Fail) {
+                    anyhow::bail!(
+                        "`--save-state` tracks failing modules, so requires `--outcome fail`"
+                    );
+                }
+                if export.is_some() && !matches!(outcome, AuditOutcome::Pass) {
+                    anyhow::bail!(
+                        "`--export` lists the modules which comply, so requires `--outcome pass`"
+                    );
+                }
                 let page = Pagination { offset, limit };
-                let audit = Audit {
-                    checkfile,
-                    page,
-                    outcome,
+                let client = self.client()?;
+
+                let progress = progress::spinner("Auditing modules", output_format);
+                let (reports, summary, hashes) = match &config {
+                    Some(config) => {
+                        let (selectors, hashes) = AuditConfig::load(config).await?;
+                        let reports = client
+                            .audit_modules_selected(&selectors, outcome.clone(), page)
+                            .await?;
+                        let summary = AuditSummary::new(&selectors, &reports);
+                        (reports, Some(summary), hashes)
+                    }
+                    None => {
+                        // parse the checkfile locally so that errors are reported before calling the server
+                        let checkfile = tokio::fs::read(&check).await?;
+                        let validation: Validation = serde_yaml::from_slice(&checkfile)
+                            .map_err(|e| anyhow!("invalid checkfile {}: {}", check.display(), e))?;
+                        let hashes = BTreeMap::from([(
+                            check.display().to_string(),
+                            CheckfileIdentity::new(&check, &checkfile).sha256,
+                        )]);
+                        let reports = match client
+                            .audit_modules_with(&validation, outcome.clone(), page)
+                            .await
+                        {
+                            Ok(reports) => reports,
+                            Err(e) if !supports_audit(&client).await => {
+                                eprintln!(
+                                    "The server does not support audits ({}), validating modules locally instead.",
+                                    e
+                                );
+                                client
+                                    .audit_modules_locally(&validation, outcome.clone(), page)
+                                    .await?
+                            }
+                            Err(e) => return Err(e),
+                        };
+                        (reports, None, hashes)
+                    }
                 };
+                progress.finish_and_clear();
 
-                let client = Client::new(self.host.as_str())?;
-                let reports = client.audit_modules(audit).await?;
+                let check = config.as_ref().unwrap_or(&check);
+                let delta = match &save_state {
+                    Some(path) => {
+                        let state = AuditState::new(check, &page, &reports);
+                        let previous = AuditState::load(path).await?;
+                        if let Some(previous) = &previous {
+                            if previous.checkfile != state.checkfile
+                                || (previous.offset, previous.limit) != (offset, limit)
+                            {
+                                eprintln!(
+                                    "The previous audit in {} used {} (offset {}, limit {}), so some changes may be due to auditing different modules or checks.",
+                                    path.display(),
+                                    previous.checkfile,
+                                    previous.offset,
+                                    previous.limit
+                                );
+                            }
+                        }
+                        state.save(path).await?;
+                        Some(state.delta(previous.as_ref()))
+                    }
+                    None => None,
+                };
 
-                match output_format {
-                    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
-                    OutputFormat::Table => {
+                // passing modules have no failures to report, so they are listed with the
+                // checkfile they comply with instead, and exported whatever else is written
+                let compliance = matches!(outcome, AuditOutcome::Pass)
+                    .then(|| ComplianceResult::new(&reports, check, &hashes));
+                if let (Some(compliance), Some(path)) = (&compliance, &export) {
+                    compliance.export(path).await?;
+                    eprintln!(
+                        "Exported {} compliant module(s) to {}",
+                        compliance.modules.len(),
+                        path.display()
+                    );
+                }
+
+                if let Some(dir) = report_dir {
+                    let written = write_reports(&dir, check, &reports, report_format)?;
+                    println!(
+                        "Wrote {} module report(s) and an index to {}",
+                        written,
+                        dir.display()
+                    );
+                    if delta.is_none() {
+                        return Ok(ExitCode::SUCCESS);
+                    }
+                }
+
+                // with saved state, the changes since the previous run are shown instead of
+                // every report
+                if let Some(delta) = delta {
+                    println!("{}", output_format.render(&delta)?);
+                    return Ok(ExitCode::SUCCESS);
+                }
+
+                if let Some(compliance) = compliance {
+                    println!("{}", output_format.render(&compliance)?);
+                    return Ok(ExitCode::SUCCESS);
+                }
+
+                match (output_format, summary) {
+                    (OutputFormat::Table, summary) => {
                         let mut buf = vec![];
-                        reports.iter().enumerate().for_each(|(i, (id, report))| {
+                        reports.iter().enumerate().for_each(|(i, r)| {
                             if i != 0 {
                                 writeln!(buf, "");
                             }
-                            writeln!(buf, "Report for module: {id}");
-                            writeln!(buf, "{}", report);
+                            match &r.checkfile {
+                                Some(checkfile) => writeln!(
+                                    buf,
+                                    "Report for module: {} ({}) using {}",
+                                    r.module_id, r.hash, checkfile
+                                ),
+                                None => {
+                                    writeln!(buf, "Report for module: {} ({})", r.module_id, r.hash)
+                                }
+                            };
+                            writeln!(buf, "{}", r.report);
                         });
+                        if let Some(summary) = summary {
+                            writeln!(buf, "{}", summary);
+                        }
 
                         print!("{}", String::from_utf8(buf)?);
                     }
+                    (_, None) => {
+                        let reports = reports
+                            .iter()
+                            .map(|r| (r.module_id, &r.report))
+                            .collect::<std::collections::BTreeMap<_, _>>();
+                        println!("{}", output_format.serialize(&reports)?)
+                    }
+                    (_, Some(summary)) => {
+                        let reports = reports
+                            .iter()
+                            .map(|r| {
+                                (
+                                    r.module_id,
+                                    serde_json::json!({
+                                        "checkfile": r.checkfile,
+                                        "report": r.report,
+                                    }),
+                                )
+                            })
+                            .collect::<std::collections::BTreeMap<_, _>>();
+                        let output = serde_json::json!({
+                            "summary": summary,
+                            "reports": reports,
+                        });
+                        println!("{}", output_format.serialize(&output)?)
+                    }
                 };
 
                 Ok(ExitCode::SUCCESS)
             }
-            Subcommand::Diff(module1, module2, with_context) => {
-                let client = Client::new(self.host.as_str())?;
-                let module1 = module1.fetch(&client).await?;
-                let module2 = module2.fetch(&client).await?;
+            Subcommand::Completions(shell) => {
+                let mut cmd = self.cmd.clone();
+                let name = cmd.get_name().to_string();
+                clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Man(dir) => {
+                match dir {
+                    Some(dir) => {
+                        std::fs::create_dir_all(dir)?;
+                        let written = write_man_pages(&self.cmd, None, dir)?;
+                        println!("Wrote {} man page(s) to {}", written, dir.display());
+                    }
+                    None => {
+                        clap_mangen::Man::new(self.cmd.clone()).render(&mut std::io::stdout())?
+                    }
+                }
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::Diff(module1, module2, with_context, fetch) => {
+                let client = self.client()?;
+                let (module1, module2) = if fetch {
+                    futures::try_join!(
+                        module1.fetch_bytes(&client, &self.locations),
+                        module2.fetch_bytes(&client, &self.locations)
+                    )?
+                } else {
+                    futures::try_join!(module1.fetch(&client), module2.fetch(&client))?
+                };
                 let diff = modsurfer_validation::Diff::new(
                     &module1,
                     &module2,
@@ -411,10 +1524,58 @@ impl Cli {
                 print!("{}", diff);
                 Ok(ExitCode::SUCCESS)
             }
+            Subcommand::Graph(modules, namespaces, format, output) => {
+                let client = self.client()?;
+                let fetched =
+                    futures::future::try_join_all(modules.iter().map(|m| m.fetch(&client))).await?;
+
+                let graph = if namespaces {
+                    let graph =
+                        NamespaceGraph::new(modules.iter().zip(&fetched).map(|(given, module)| {
+                            let name = match given {
+                                IdOrFilename::Id(id) if !module.file_name().is_empty() => {
+                                    format!("#{} {}", id, module.file_name())
+                                }
+                                IdOrFilename::Id(id) => format!("#{}", id),
+                                IdOrFilename::Filename(path) => path.clone(),
+                            };
+                            (name, module)
+                        }));
+                    match format {
+                        GraphFormat::Dot => graph.to_dot(),
+                        GraphFormat::Json => serde_json::to_string_pretty(&graph)?,
+                        GraphFormat::Svg => graph.to_svg(),
+                    }
+                } else {
+                    // the call graph collected by the parser, which is only available as Dot
+                    let [module] = fetched.as_slice() else {
+                        anyhow::bail!("the call graph is drawn for one module at a time, use `--namespaces` to graph several");
+                    };
+                    if format != GraphFormat::Dot {
+                        anyhow::bail!("the call graph is only available in Dot format, use `--namespaces` for JSON or SVG");
+                    }
+                    let graph = module
+                        .graph
+                        .as_ref()
+                        .ok_or_else(|| anyhow!("no call graph was collected for the module"))?;
+                    String::from_utf8_lossy(graph).into_owned()
+                };
+
+                match output {
+                    Some(path) => tokio::fs::write(path, graph)
+                        .await
+                        .with_context(|| format!("failed to write {}", path.display()))?,
+                    None => print!("{}", graph),
+                }
+                Ok(ExitCode::SUCCESS)
+            }
             Subcommand::CallPlugin(identifier, function, input_arg, output) => {
+                if let Some(manifest) = PluginManifest::load_installed(&identifier).await? {
+                    manifest.check_call(&function, None)?;
+                }
                 let input = input_arg.resolve().await?;
 
-                let client = Client::new(self.host.as_str())?;
+                let client = self.client()?;
                 let res = client.call_plugin(identifier, function, input).await?;
 
                 if let Some(output) = output {
@@ -425,6 +1586,55 @@ impl Cli {
 
                 Ok(ExitCode::SUCCESS)
             }
+            Subcommand::CallLocalPlugin(
+                path,
+                function,
+                input_arg,
+                output,
+                limits,
+                manifest,
+                config,
+            ) => {
+                let manifest = match manifest {
+                    Some(manifest) => Some(PluginManifest::read(manifest).await?),
+                    None => {
+                        let default = path.with_file_name(MANIFEST_FILE);
+                        match tokio::fs::try_exists(&default).await {
+                            Ok(true) => Some(PluginManifest::read(&default).await?),
+                            _ => None,
+                        }
+                    }
+                };
+                if let Some(manifest) = &manifest {
+                    manifest.check_call(&function, Some(&config))?;
+                    if let Some(needs) = manifest.permissions_summary() {
+                        anyhow::bail!(
+                            "plugin {} needs {}, which plugins called with `--wasm` are not given",
+                            manifest.identifier,
+                            needs
+                        );
+                    }
+                }
+
+                let input = input_arg.resolve().await?;
+                let wasm = tokio::fs::read(&path)
+                    .await
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+
+                let name = path.display().to_string();
+                let res = tokio::task::spawn_blocking(move || {
+                    LocalPlugin::with_config(name, &wasm, limits, &config)?.call(&function, &input)
+                })
+                .await??;
+
+                if let Some(output) = output {
+                    tokio::fs::write(output, res).await?;
+                } else {
+                    std::io::stdout().write_all(&res);
+                }
+
+                Ok(ExitCode::SUCCESS)
+            }
             Subcommand::InstallPlugin(identifier, name, wasm) => {
                 let location = match &wasm {
                     PathOrUrl::Path(v) => v.to_str().unwrap_or_else(|| ""),
@@ -432,34 +1642,166 @@ impl Cli {
                 }
                 .to_string();
                 let name = name.cloned();
+                let progress = progress::spinner(
+                    format!("Installing plugin from {}", location),
+                    &OutputFormat::Table,
+                );
                 let wasm = wasm.resolve().await?;
 
-                let client = Client::new(self.host.as_str())?;
+                let client = self.client()?;
                 let res = client
                     .install_plugin(identifier, name, location, wasm)
                     .await?;
+                progress.finish_and_clear();
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::InstallPluginManifest(path, name) => {
+                let mut manifest = PluginManifest::read(path).await?;
+                let location = manifest.wasm_location(path);
+                let progress = progress::spinner(
+                    format!("Installing plugin from {}", location),
+                    &OutputFormat::Table,
+                );
+                let wasm = PathOrUrl::from(&location).resolve().await?;
+                manifest.check_exports(&modsurfer_validation::Module::parse(&wasm)?)?;
+
+                let client = self.client()?;
+                client
+                    .install_plugin(
+                        manifest.identifier.clone(),
+                        name.cloned().or_else(|| manifest.name.clone()),
+                        location.clone(),
+                        wasm,
+                    )
+                    .await?;
+                progress.finish_and_clear();
+
+                // the wasm is kept relative to the current directory, not the installed manifest
+                manifest.wasm = location;
+                manifest.save_installed().await?;
+                if let Some(needs) = manifest.permissions_summary() {
+                    eprintln!("Plugin {} needs {}", manifest.identifier, needs);
+                }
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::InstallIndexedPlugin(spec, identifier, name, index) => {
+                let index = PluginIndex::location(index)?;
+                let progress = progress::spinner(
+                    format!("Installing plugin {} from {}", spec, index),
+                    &OutputFormat::Table,
+                );
+                let index = PluginIndex::fetch(&index, &self.locations).await?;
+                let plugin = index.resolve(spec)?;
+                let wasm = plugin.fetch_wasm(&self.locations).await?;
+                let manifest = plugin.fetch_manifest(&self.locations).await?;
+                if let Some(manifest) = &manifest {
+                    manifest.check_exports(&modsurfer_validation::Module::parse(&wasm)?)?;
+                }
+
+                let identifier = identifier
+                    .cloned()
+                    .or_else(|| manifest.as_ref().map(|m| m.identifier.clone()))
+                    .unwrap_or_else(|| plugin.name.clone());
+                let name = name
+                    .cloned()
+                    .or_else(|| manifest.as_ref().and_then(|m| m.name.clone()))
+                    .unwrap_or_else(|| plugin.name.clone());
+                let client = self.client()?;
+                client
+                    .install_plugin(identifier.clone(), Some(name), plugin.wasm.clone(), wasm)
+                    .await?;
+                progress.finish_and_clear();
+
+                if let Some(mut manifest) = manifest {
+                    manifest.identifier = identifier;
+                    manifest.wasm = plugin.wasm.clone();
+                    manifest.save_installed().await?;
+                    if let Some(needs) = manifest.permissions_summary() {
+                        eprintln!("Plugin {} needs {}", manifest.identifier, needs);
+                    }
+                }
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::SearchPlugins(term, index, output_format) => {
+                let index = PluginIndex::location(index)?;
+                let index = PluginIndex::fetch(&index, &self.locations).await?;
+                println!("{}", output_format.render(&index.search(term))?);
 
                 Ok(ExitCode::SUCCESS)
             }
             Subcommand::UninstallPlugin(identifier) => {
-                let client = Client::new(self.host.as_str())?;
-                let res = client.uninstall_plugin(identifier).await?;
+                let client = self.client()?;
+                let res = client.uninstall_plugin(identifier.clone()).await?;
+                PluginManifest::remove_installed(&identifier).await?;
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::ProfileList(output_format) => {
+                let config = Config::load().await?;
+                println!("{}", output_format.render(&ProfilesResult::from(&config))?);
+
+                Ok(ExitCode::SUCCESS)
+            }
+            Subcommand::ProfileUse(name) => {
+                let mut config = Config::load().await?;
+                config.profile(name)?;
+                config.current = Some(name.clone());
+                let path = config.save().await?;
+                eprintln!("Using profile `{}` (saved to {})", name, path.display());
+
                 Ok(ExitCode::SUCCESS)
             }
         }
     }
 }
 
+/// Check whether the server can audit modules. Servers too old to describe their features are
+/// assumed not to.
+async fn supports_audit(client: &Client) -> bool {
+    client
+        .server_info()
+        .await
+        .map(|info| info.supports("audit"))
+        .unwrap_or(false)
+}
+
+/// Compare the `source` module to every .wasm module in `dir`, returning those at least
+/// `threshold` similar. Modules in the directory which fail to parse are skipped with a warning.
+fn similar_in_dir(source: &Module, dir: &Path, threshold: f64) -> Result<Vec<SimilarResult>> {
+    let mut results = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension() != Some(OsStr::new("wasm")) {
+            continue;
+        }
+
+        let module = match modsurfer_validation::Module::parse_file(&path) {
+            Ok(module) => module,
+            Err(e) => {
+                eprintln!("Skipping {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        let similarity = source.similarity(&module);
+        if similarity >= threshold {
+            results.push(SimilarResult {
+                module_id: None,
+                hash: module.hash,
+                file_name: path.display().to_string(),
+                similarity,
+            });
+        }
+    }
+
+    Ok(results)
+}
+
 fn to_api_result(m: &Persisted<Module>) -> ApiResult {
     ApiResult {
         module_id: m.get_id(),
-        hash: m.get_inner().hash.clone(),
-        file_name: m.get_inner().file_name(),
-        exports: m.get_inner().exports.len(),
-        imports: m.get_inner().imports.len(),
-        namespaces: m.get_inner().get_import_namespaces(),
-        source_language: m.get_inner().source_language.clone(),
-        size: human_bytes(m.get_inner().size as f64),
+        summary: m.get_inner().summary(),
     }
 }
 
@@ -468,47 +1810,143 @@ fn output_format(args: &clap::ArgMatches) -> &OutputFormat {
         .unwrap_or_else(|| &OutputFormat::Table)
 }
 
-impl<'a> From<(&'a str, &'a clap::ArgMatches)> for Subcommand<'a> {
-    fn from(input: (&'a str, &'a clap::ArgMatches)) -> Self {
-        match input {
+impl<'a> TryFrom<(&'a str, &'a clap::ArgMatches)> for Subcommand<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(input: (&'a str, &'a clap::ArgMatches)) -> Result<Self> {
+        let sub = match input {
             ("create", args) => {
-                let module_path = args
-                    .get_one::<PathBuf>("path")
-                    .expect("must provide a --path to the module on disk");
+                let module_path = required::<PathBuf>(args, "path")?;
                 let checkfile_path: Option<&PathBuf> = args.get_one("check");
-                let raw_metadata = args
-                    .get_many("metadata")
+                let metadata: HashMap<String, String> = args
+                    .get_many::<MetadataEntry>("metadata")
                     .unwrap_or_default()
                     .cloned()
-                    .collect::<Vec<String>>();
-
-                let metadata: HashMap<String, String> = raw_metadata
-                    .into_iter()
-                    .map(|raw| {
-                        let parts = raw.split("=").collect::<Vec<_>>();
-                        (parts[0].to_string(), parts[1].to_string())
-                    })
                     .collect();
 
                 let location: Option<&Url> = args.get_one("location");
+                let tags = args.get_many("tag").unwrap_or_default().cloned().collect();
 
                 Subcommand::Create(
                     module_path,
                     checkfile_path,
                     metadata,
+                    args.get_one("metadata-file"),
                     location.cloned(),
+                    tags,
+                    args.get_one("version"),
                     output_format(args),
                 )
             }
-            ("delete", args) => Subcommand::Delete(
-                args.get_many("id")
-                    .expect("module id(s) to delete")
-                    .cloned()
-                    .collect::<Vec<Id>>(),
+            ("delete", args) => match args.get_many::<Id>("id") {
+                Some(ids) => Subcommand::Delete(ids.cloned().collect(), output_format(args)),
+                None => {
+                    let metadata = args
+                        .get_many::<MetadataEntry>("metadata")
+                        .unwrap_or_default()
+                        .cloned()
+                        .collect();
+                    let inserted_before = args
+                        .get_one::<chrono::Duration>("older-than")
+                        .map(|age| {
+                            chrono::Utc::now()
+                                .checked_sub_signed(*age)
+                                .ok_or_else(|| anyhow!("`--older-than` age is too large"))
+                        })
+                        .transpose()?;
+                    let query = ModuleQuery {
+                        hash: args.get_one::<Hash>("hash").cloned(),
+                        inserted_before,
+                        metadata,
+                    };
+
+                    Subcommand::DeleteMatching(
+                        query,
+                        !args.get_flag("confirm"),
+                        output_format(args),
+                    )
+                }
+            },
+            ("get", args) => Subcommand::Get(
+                args.get_one("id").copied(),
+                args.get_one("hash"),
+                args.get_one("save"),
                 output_format(args),
             ),
-            ("get", args) => Subcommand::Get(
-                *args.get_one("id").expect("valid module ID"),
+            ("tag", args) => Subcommand::Tag(
+                *required(args, "id")?,
+                args.get_many("tag").unwrap_or_default().cloned().collect(),
+                output_format(args),
+            ),
+            ("quarantine", args) => Subcommand::Quarantine(
+                *required(args, "id")?,
+                required(args, "reason")?,
+                output_format(args),
+            ),
+            ("unquarantine", args) => {
+                Subcommand::Unquarantine(*required(args, "id")?, output_format(args))
+            }
+            ("ping", args) => Subcommand::Ping(output_format(args)),
+            ("similar", args) => Subcommand::Similar(
+                match args.get_one::<Id>("id") {
+                    Some(id) => IdOrFilename::Id(*id),
+                    None => IdOrFilename::Filename(
+                        required::<PathBuf>(args, "path")?
+                            .to_string_lossy()
+                            .to_string(),
+                    ),
+                },
+                args.get_one::<PathBuf>("dir").cloned(),
+                *required(args, "threshold")?,
+                output_format(args),
+            ),
+            ("stats", args) => Subcommand::Stats(*required(args, "top")?, output_format(args)),
+            ("report", args) => match args.subcommand() {
+                Some(("namespaces", args)) => Subcommand::ReportNamespaces(
+                    args.get_one("namespace"),
+                    args.get_one("max-modules").copied(),
+                    output_format(args),
+                ),
+                _ => Subcommand::Unknown,
+            },
+            ("explain", args) => Subcommand::Explain(required(args, "rule")?, output_format(args)),
+            ("link-check", args) => Subcommand::LinkCheck(
+                required::<PathBuf>(args, "host")?.clone(),
+                required::<PathBuf>(args, "module")?.clone(),
+                required(args, "namespace")?,
+                output_format(args),
+            ),
+            ("capabilities", args) => Subcommand::Capabilities(
+                required::<PathBuf>(args, "path")?.clone(),
+                output_format(args),
+            ),
+            ("vuln", args) => Subcommand::Vuln(
+                required::<PathBuf>(args, "path")?.clone(),
+                required(args, "db")?,
+                output_format(args),
+            ),
+            ("bloat", args) => Subcommand::Bloat(
+                required::<PathBuf>(args, "path")?.clone(),
+                *required(args, "top")?,
+                output_format(args),
+            ),
+            ("compare-checkfiles", args) => Subcommand::CompareCheckfiles(
+                required::<PathBuf>(args, "before")?.clone(),
+                required::<PathBuf>(args, "after")?.clone(),
+                output_format(args),
+            ),
+            ("test-policy", args) => Subcommand::TestPolicy(
+                required::<PathBuf>(args, "manifest")?.clone(),
+                args.get_one::<PathBuf>("check").cloned(),
+                output_format(args),
+            ),
+            ("watch-registry", args) => Subcommand::WatchRegistry(
+                EventFilter {
+                    after_event_id: args.get_one("since").copied(),
+                    kinds: args.get_many("kind").unwrap_or_default().copied().collect(),
+                    tags: args.get_many("tag").unwrap_or_default().cloned().collect(),
+                    hash: args.get_one("hash").cloned(),
+                },
                 output_format(args),
             ),
             ("list", args) => Subcommand::List(
@@ -524,12 +1962,9 @@ impl<'a> From<(&'a str, &'a clap::ArgMatches)> for Subcommand<'a> {
                     .get_one("source-language")
                     .map(|s: &String| s.clone().into());
                 let text_search: Option<&TextSearch> = args.get_one("text");
-                let offset: Offset = *args
-                    .get_one("offset")
-                    .expect("offset should have default value");
-                let limit: Limit = *args
-                    .get_one("limit")
-                    .expect("limit should have default value");
+                let tags = args.get_many("tag").unwrap_or_default().cloned().collect();
+                let offset: Offset = *required(args, "offset")?;
+                let limit: Limit = *required(args, "limit")?;
 
                 Subcommand::Search(
                     hash,
@@ -537,57 +1972,118 @@ impl<'a> From<(&'a str, &'a clap::ArgMatches)> for Subcommand<'a> {
                     func_name,
                     src_lang,
                     text_search,
+                    tags,
+                    args.get_one("version"),
+                    args.get_one("min-complexity").copied(),
+                    args.get_one("max-complexity").copied(),
+                    args.get_many("namespace")
+                        .unwrap_or_default()
+                        .cloned()
+                        .collect(),
+                    args.get_one("sort").cloned(),
+                    args.get_one("direction").cloned(),
                     offset,
                     limit,
                     output_format(args),
                 )
             }
-            ("generate", args) => Subcommand::Generate(
-                args.get_one::<PathBuf>("path")
-                    .expect("valid module path")
-                    .clone(),
-                args.get_one::<PathBuf>("output")
-                    .expect("valid checkfile output path")
-                    .clone(),
-            ),
-            ("validate", args) => Subcommand::Validate(
-                args.get_one::<PathBuf>("path")
-                    .expect("valid module path")
-                    .clone(),
-                args.get_one::<PathBuf>("check")
-                    .expect("valid checkfile path")
-                    .clone(),
-                output_format(args),
+            ("generate", args) => match args.get_one::<PathBuf>("out-dir") {
+                Some(out_dir) => Subcommand::GenerateDir(
+                    required::<PathBuf>(args, "path")?
+                        .to_string_lossy()
+                        .into_owned(),
+                    out_dir.clone(),
+                    args.get_flag("minimal"),
+                    post_hooks(args),
+                ),
+                None => Subcommand::Generate(
+                    required::<PathBuf>(args, "path")?.clone(),
+                    required::<PathBuf>(args, "output")?.clone(),
+                    args.get_flag("minimal"),
+                    post_hooks(args),
+                ),
+            },
+            ("snapshot", args) => Subcommand::Snapshot(
+                required::<PathBuf>(args, "path")?.clone(),
+                args.get_one::<PathBuf>("output").cloned(),
             ),
+            ("validate", args) => {
+                let check = required::<PathBuf>(args, "check")?.clone();
+                let overrides = args
+                    .get_many::<Override>("set")
+                    .map(|overrides| overrides.cloned().collect())
+                    .unwrap_or_default();
+                if args.get_flag("plan") {
+                    return Ok(Subcommand::ValidatePlan(
+                        check,
+                        overrides,
+                        output_format(args),
+                    ));
+                }
+                let path = required::<PathBuf>(args, "path")?;
+                if let Some(wit) = args.get_one::<PathBuf>("wit") {
+                    return Ok(Subcommand::ValidateWit(
+                        path.clone(),
+                        wit.clone(),
+                        args.get_one("world"),
+                        output_format(args),
+                    ));
+                }
+
+                if args.get_flag("remote") {
+                    Subcommand::ValidateRemote(path, check, overrides, output_format(args))
+                } else {
+                    Subcommand::Validate(
+                        path.clone(),
+                        check,
+                        args.get_one::<PathBuf>("against").cloned(),
+                        overrides,
+                        args.get_one::<PathBuf>("baseline").cloned(),
+                        args.get_one::<PathBuf>("write-baseline").cloned(),
+                        args.get_flag("timings"),
+                        args.get_one::<MemoryBudget>("max-memory").copied(),
+                        args.get_flag("advise"),
+                        args.get_many::<MetadataEntry>("compare-registry")
+                            .unwrap_or_default()
+                            .cloned()
+                            .collect(),
+                        post_hooks(args)
+                            .iter()
+                            .map(|path| ReportHook {
+                                path: path.display().to_string(),
+                                ..Default::default()
+                            })
+                            .collect(),
+                        output_format(args),
+                    )
+                }
+            }
             ("yank", args) => Subcommand::Yank(
-                *args.get_one::<Id>("id").expect("id is required"),
-                args.get_one::<Version>("version")
-                    .expect("version is required")
-                    .clone(),
+                *required::<Id>(args, "id")?,
+                required::<Version>(args, "version")?.clone(),
                 output_format(args),
             ),
             ("audit", args) => {
-                let offset: Offset = *args
-                    .get_one("offset")
-                    .expect("offset should have default value");
-                let limit: Limit = *args
-                    .get_one("limit")
-                    .expect("limit should have default value");
+                let offset: Offset = *required(args, "offset")?;
+                let limit: Limit = *required(args, "limit")?;
                 Subcommand::Audit(
-                    args.get_one::<PathBuf>("check")
-                        .expect("valid checkfile path")
-                        .clone(),
-                    args.get_one::<AuditOutcome>("outcome")
-                        .expect("requires valid outcome ('pass' or 'fail')")
-                        .clone(),
+                    required::<PathBuf>(args, "check")?.clone(),
+                    args.get_one::<PathBuf>("config").cloned(),
+                    required::<AuditOutcome>(args, "outcome")?.clone(),
                     offset,
                     limit,
+                    args.get_one::<PathBuf>("report-dir").cloned(),
+                    args.get_one::<ReportFormat>("report-format")
+                        .copied()
+                        .unwrap_or_default(),
+                    args.get_one::<PathBuf>("save-state").cloned(),
+                    args.get_one::<PathBuf>("export").cloned(),
                     output_format(args),
                 )
             }
             ("diff", args) => {
-                let module1 = args.get_one::<String>("module1").expect("id is required");
-                let module2 = args.get_one::<String>("module2").expect("id is required");
+                let module1 = required::<String>(args, "module1")?;
+                let module2 = required::<String>(args, "module2")?;
                 let with_context = *args
                     .get_one::<WithContext>("with-context")
                     .unwrap_or_else(|| &false);
@@ -595,43 +2091,157 @@ impl<'a> From<(&'a str, &'a clap::ArgMatches)> for Subcommand<'a> {
                     IdOrFilename::parse(module1),
                     IdOrFilename::parse(module2),
                     with_context,
+                    args.get_flag("fetch"),
                 )
             }
+            ("graph", args) => Subcommand::Graph(
+                args.get_many::<String>("module")
+                    .unwrap_or_default()
+                    .map(IdOrFilename::parse)
+                    .collect(),
+                args.get_flag("namespaces"),
+                args.get_one::<GraphFormat>("format")
+                    .copied()
+                    .unwrap_or_default(),
+                args.get_one("output"),
+            ),
+            ("completions", args) => Subcommand::Completions(*required(args, "shell")?),
+            ("man", args) => Subcommand::Man(args.get_one("dir")),
             ("plugin", args) => match args.subcommand() {
                 Some(("call", args)) => {
-                    let identifier = args
-                        .get_one::<Identifier>("identifier")
-                        .expect("identifier is required");
-                    let function_name = args
-                        .get_one::<FunctionName>("function")
-                        .expect("function is required");
-                    let input = args.get_one::<String>("input").expect("input is required");
+                    let function_name = required::<FunctionName>(args, "function")?;
+                    let input = required::<String>(args, "input")?;
                     let output = args.get_one::<OutputFile>("output");
 
-                    Subcommand::CallPlugin(
-                        identifier.to_string(),
-                        function_name.to_string(),
-                        BytesOrPath::from(input),
-                        output,
+                    match args.get_one::<PathBuf>("wasm") {
+                        Some(wasm) => Subcommand::CallLocalPlugin(
+                            wasm.clone(),
+                            function_name.to_string(),
+                            BytesOrPath::from(input),
+                            output,
+                            PluginLimits {
+                                memory_max: plugin_memory_max(args),
+                                timeout: args.get_one::<std::time::Duration>("timeout").copied(),
+                            },
+                            args.get_one::<PathBuf>("manifest"),
+                            args.get_many::<MetadataEntry>("config")
+                                .unwrap_or_default()
+                                .cloned()
+                                .collect(),
+                        ),
+                        None => Subcommand::CallPlugin(
+                            required::<Identifier>(args, "identifier")?.to_string(),
+                            function_name.to_string(),
+                            BytesOrPath::from(input),
+                            output,
+                        ),
+                    }
+                }
+                Some(("install", args)) if args.contains_id("manifest") => {
+                    Subcommand::InstallPluginManifest(
+                        required::<PathBuf>(args, "manifest")?,
+                        args.get_one::<PluginName>("name"),
+                    )
+                }
+                Some(("install", args)) if args.contains_id("plugin") => {
+                    Subcommand::InstallIndexedPlugin(
+                        required::<String>(args, "plugin")?,
+                        args.get_one::<Identifier>("identifier"),
+                        args.get_one::<PluginName>("name"),
+                        args.get_one::<String>("index"),
                     )
                 }
+                Some(("search", args)) => Subcommand::SearchPlugins(
+                    required::<String>(args, "term")?,
+                    args.get_one::<String>("index"),
+                    output_format(args),
+                ),
                 Some(("install", args)) => {
-                    let identifier = args
-                        .get_one::<Identifier>("identifier")
-                        .expect("identifier is required");
+                    let identifier = required::<Identifier>(args, "identifier")?;
                     let name = args.get_one::<PluginName>("name");
-                    let wasm = args.get_one::<String>("wasm").expect("wasm is required");
+                    let wasm = required::<String>(args, "wasm")?;
                     Subcommand::InstallPlugin(identifier.to_string(), name, PathOrUrl::from(wasm))
                 }
                 Some(("uninstall", args)) => {
-                    let identifier = args
-                        .get_one::<Identifier>("identifier")
-                        .expect("identifier is required");
+                    let identifier = required::<Identifier>(args, "identifier")?;
                     Subcommand::UninstallPlugin(identifier.to_string())
                 }
                 _ => Subcommand::Unknown,
             },
+            ("profile", args) => match args.subcommand() {
+                Some(("list", args)) => Subcommand::ProfileList(output_format(args)),
+                Some(("use", args)) => Subcommand::ProfileUse(required::<String>(args, "name")?),
+                _ => Subcommand::Unknown,
+            },
             _ => Subcommand::Unknown,
+        };
+
+        Ok(sub)
+    }
+}
+
+/// Get the value of an argument which clap guarantees is present, as it is required or has a
+/// default, returning an error rather than panicking if that doesn't hold.
+fn required<'a, T: Clone + Send + Sync + 'static>(
+    args: &'a clap::ArgMatches,
+    id: &str,
+) -> Result<&'a T> {
+    args.get_one::<T>(id)
+        .ok_or_else(|| anyhow!("missing required argument `{}`", id))
+}
+
+/// Configure colored output and table rendering from the global display flags, falling back to
+/// their environment variables. Following https://no-color.org, any non-empty `NO_COLOR` disables
+/// color.
+fn apply_display_args(matches: &clap::ArgMatches) -> Result<()> {
+    let env_set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+    let env_width = |name: &str| -> Result<Option<u16>> {
+        match std::env::var(name) {
+            Ok(v) if !v.is_empty() => v
+                .parse()
+                .map(Some)
+                .map_err(|e| anyhow!("invalid {}: {}", name, e)),
+            _ => Ok(None),
         }
+    };
+
+    if matches.get_flag("no-color") || env_set(NO_COLOR_ENV) {
+        colored::control::set_override(false);
+    }
+
+    let max_width = match matches.get_one::<u16>("max-width") {
+        Some(width) => Some(*width),
+        None => env_width(MAX_WIDTH_ENV)?,
+    };
+    let max_column_width = match matches.get_one::<u16>("max-column-width") {
+        Some(width) => Some(*width),
+        None => env_width(MAX_COLUMN_WIDTH_ENV)?,
+    };
+    modsurfer_validation::set_table_style(modsurfer_validation::TableStyle {
+        ascii: matches.get_flag("ascii") || env_set(ASCII_ENV),
+        max_width,
+        max_column_width,
+    });
+
+    Ok(())
+}
+
+/// Write a man page for `cmd` and each of its subcommands (recursively) into `dir`, named like
+/// `modsurfer-plugin-install.1`. Returns the number of pages written.
+fn write_man_pages(cmd: &clap::Command, parent: Option<&str>, dir: &Path) -> Result<usize> {
+    let name = match parent {
+        Some(parent) => format!("{}-{}", parent, cmd.get_name()),
+        None => cmd.get_name().to_string(),
+    };
+
+    let mut page = vec![];
+    clap_mangen::Man::new(cmd.clone().display_name(name.clone())).render(&mut page)?;
+    std::fs::write(dir.join(format!("{}.1", name)), page)?;
+
+    let mut written = 1;
+    for sub in cmd.get_subcommands().filter(|sub| sub.get_name() != "help") {
+        written += write_man_pages(sub, Some(&name), dir)?;
     }
+
+    Ok(written)
 }
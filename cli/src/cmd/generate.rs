@@ -1,22 +1,176 @@
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
-use serde_yaml;
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use serde::Serialize;
 
-use modsurfer_validation::{generate_checkfile, Module as ModuleParser};
+use modsurfer_validation::{
+    generate_checkfile, generate_minimal_checkfile, run_generate_hooks, Module as ModuleParser,
+};
 
-pub async fn checkfile_from_module(wasm: &PathBuf, output: &PathBuf) -> Result<()> {
-    let module_data = tokio::fs::read(wasm).await?;
-    let module = ModuleParser::parse(&module_data)?;
-    let validation = generate_checkfile(&module)?;
+use super::exec::OutputFormat;
+use super::progress;
+
+/// The file written alongside the checkfiles by `checkfiles_from_modules`.
+pub const INDEX_FILE: &str = "index.yaml";
+
+/// Generate a checkfile from the module at `wasm`, post-processed by each of the generate `hooks`
+/// (see `run_generate_hooks`), and write it to `output`.
+pub async fn checkfile_from_module(
+    wasm: &Path,
+    output: &Path,
+    minimal: bool,
+    hooks: &[PathBuf],
+) -> Result<()> {
+    write_checkfile(wasm, output, minimal, hooks)?;
+
+    Ok(())
+}
+
+/// A module given to `checkfiles_from_modules`, and the checkfile generated from it. Written to the
+/// index file, in the order the modules were matched.
+#[derive(Debug, Serialize)]
+pub struct GeneratedCheckfile {
+    pub module: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkfile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Index<'a> {
+    checkfiles: &'a [GeneratedCheckfile],
+}
+
+/// Generate a checkfile for each module matching the glob `pattern`, named after the module's file
+/// stem, in `out_dir`. Modules are parsed in parallel, and an index of every module and its
+/// checkfile is written to `out_dir/index.yaml`. A module which fails to parse is recorded in the
+/// index with its error, and doesn't stop the others. Each checkfile is post-processed by the
+/// generate `hooks`.
+pub async fn checkfiles_from_modules(
+    pattern: &str,
+    out_dir: &Path,
+    minimal: bool,
+    hooks: &[PathBuf],
+) -> Result<Vec<GeneratedCheckfile>> {
+    let mut modules = vec![];
+    for path in glob::glob(pattern)? {
+        modules.push(path?);
+    }
+    if modules.is_empty() {
+        return Err(anyhow!("no modules found matching {}", pattern));
+    }
+
+    // fail before writing anything if two modules would share a checkfile, e.g. `a/auth.wasm` and
+    // `b/auth.wasm` matched by `**/*.wasm`
+    let index_stem = Path::new(INDEX_FILE).file_stem();
+    let mut stems: HashMap<_, &PathBuf> = HashMap::new();
+    for path in &modules {
+        let stem = path
+            .file_stem()
+            .ok_or_else(|| anyhow!("module path {} has no file name", path.display()))?;
+        if Some(stem) == index_stem {
+            return Err(anyhow!(
+                "module {} would be written over the index file {}",
+                path.display(),
+                INDEX_FILE
+            ));
+        }
+        if let Some(other) = stems.insert(stem, path) {
+            return Err(anyhow!(
+                "modules {} and {} would both be written to {}.yaml",
+                other.display(),
+                path.display(),
+                stem.to_string_lossy()
+            ));
+        }
+    }
+    tokio::fs::create_dir_all(out_dir).await?;
+
+    let jobs = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let progress = progress::bar(modules.len() as u64, "Generating", &OutputFormat::Table);
+    let generated = futures::stream::iter(modules)
+        .map(|path| {
+            let name = format!(
+                "{}.yaml",
+                path.file_stem().unwrap_or_default().to_string_lossy()
+            );
+            let output = out_dir.join(&name);
+            let hooks = hooks.to_vec();
+            async move {
+                let module = path.display().to_string();
+                let result = tokio::task::spawn_blocking(move || -> Result<String> {
+                    write_checkfile(&path, &output, minimal, &hooks)
+                })
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|result| result);
+
+                match result {
+                    Ok(hash) => GeneratedCheckfile {
+                        module,
+                        hash: Some(hash),
+                        checkfile: Some(name),
+                        error: None,
+                    },
+                    Err(e) => GeneratedCheckfile {
+                        module,
+                        hash: None,
+                        checkfile: None,
+                        error: Some(e.to_string()),
+                    },
+                }
+            }
+        })
+        .buffered(jobs)
+        .inspect(|_| progress.inc(1))
+        .collect::<Vec<_>>()
+        .await;
+    progress.finish_and_clear();
+
+    let index = serde_yaml::to_string(&Index {
+        checkfiles: &generated,
+    })?;
+    tokio::fs::write(out_dir.join(INDEX_FILE), index).await?;
+
+    Ok(generated)
+}
+
+// parse the module at `wasm` and write a checkfile generated from it to `output`, returning the
+// module's hash
+fn write_checkfile(wasm: &Path, output: &Path, minimal: bool, hooks: &[PathBuf]) -> Result<String> {
+    let module = ModuleParser::parse_file(wasm)?;
+    let validation = if minimal {
+        generate_minimal_checkfile(&module)?
+    } else {
+        generate_checkfile(&module)?
+    };
+    let validation = run_generate_hooks(hooks, &module, validation)?;
     let mut file = File::create(output)?;
     writeln!(
         &mut file,
         "# For more information about other checkfile options, see the documentation at https://dev.dylib.so/docs/modsurfer/cli#checkfile"
     )?;
-    serde_yaml::to_writer(&file, &validation)?;
+    file.write_all(validation.to_yaml()?.as_bytes())?;
+
+    Ok(module.hash)
+}
+
+/// Write every fact parsed from a module as JSON, to `output` or stdout, so that later builds can
+/// be validated against it without the module itself.
+pub async fn snapshot_module(wasm: &PathBuf, output: Option<&PathBuf>) -> Result<()> {
+    let module = ModuleParser::parse_file(wasm)?;
+    let json = serde_json::to_string_pretty(&module)?;
+    match output {
+        Some(path) => tokio::fs::write(path, json).await?,
+        None => println!("{json}"),
+    }
 
     Ok(())
 }
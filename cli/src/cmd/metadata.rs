@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+
+/// Read metadata entries from a JSON (or YAML) object of keys to strings, numbers or booleans,
+/// e.g. `{"team": "payments", "build": 42}`.
+pub async fn read_metadata_file(path: &Path) -> Result<HashMap<String, String>> {
+    let data = tokio::fs::read(path)
+        .await
+        .with_context(|| format!("failed to read metadata file {}", path.display()))?;
+    let entries: BTreeMap<String, serde_yaml::Value> = serde_yaml::from_slice(&data)
+        .map_err(|e| anyhow!("invalid metadata file {}: {}", path.display(), e))?;
+
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_yaml::Value::String(s) => s,
+                serde_yaml::Value::Number(n) => n.to_string(),
+                serde_yaml::Value::Bool(b) => b.to_string(),
+                _ => anyhow::bail!(
+                    "invalid metadata file {}: the value of `{}` must be a string, number or boolean",
+                    path.display(),
+                    key
+                ),
+            };
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Expand metadata templates, replacing each `${NAME}` with the value of the environment variable
+/// `NAME`, e.g. `git_sha: ${GITHUB_SHA}`. An entry referring to a variable which isn't set (or is
+/// empty) is left out, so that the same templates can be used in every CI system and locally.
+/// `$$` is a literal `$`.
+pub fn expand_metadata(templates: &BTreeMap<String, String>) -> Result<HashMap<String, String>> {
+    let mut metadata = HashMap::with_capacity(templates.len());
+    for (key, template) in templates {
+        let expanded = expand(template, |name| std::env::var(name).ok())
+            .with_context(|| format!("invalid metadata template for `{}`", key))?;
+        if let Some(value) = expanded {
+            metadata.insert(key.clone(), value);
+        }
+    }
+
+    Ok(metadata)
+}
+
+/// Check that a template is well-formed, without expanding it.
+pub fn check_template(template: &str) -> Result<()> {
+    expand(template, |_| Some(String::new())).map(|_| ())
+}
+
+// expand a template with the variables found by `lookup`, or `None` if any is unset or empty
+fn expand(template: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<Option<String>> {
+    let mut out = String::with_capacity(template.len());
+    let mut missing = false;
+    let mut rest = template;
+    while let Some(start) = rest.find('$') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        if let Some(after) = rest.strip_prefix('$') {
+            out.push('$');
+            rest = after;
+            continue;
+        }
+
+        let Some(inner) = rest.strip_prefix('{') else {
+            anyhow::bail!("`$` must be followed by `{{NAME}}` or another `$`");
+        };
+        let end = inner
+            .find('}')
+            .ok_or_else(|| anyhow!("unterminated `${{` in `{}`", template))?;
+        let name = &inner[..end];
+        if name.is_empty() {
+            anyhow::bail!("empty variable name in `{}`", template);
+        }
+        match lookup(name).filter(|value| !value.is_empty()) {
+            Some(value) => out.push_str(&value),
+            None => missing = true,
+        }
+        rest = &inner[end + 1..];
+    }
+    out.push_str(rest);
+
+    Ok((!missing).then_some(out))
+}
@@ -1,6 +1,18 @@
 pub mod api_result;
+pub mod audit_config;
+pub mod audit_state;
+pub mod compliance;
+pub mod error;
 pub mod exec;
 pub mod generate;
+pub mod location;
+pub mod metadata;
+pub mod output;
+pub mod plugin_index;
+pub mod plugin_manifest;
+pub mod profile;
+pub mod progress;
+pub mod report_dir;
 
 #[allow(unused_imports)]
 pub use exec::*;
@@ -0,0 +1,137 @@
+//! Rendering of command output as CSV. Any serializable output is turned into rows: a list of
+//! objects (or an object with a single list, such as `{"results": [...]}`) becomes one row per
+//! item, a map of objects becomes one row per entry with its key in a `key` column, and anything
+//! else becomes a single row. Nested objects are flattened into dotted column names.
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// The column holding the key of each entry when rows are made from a map of objects.
+const KEY_COLUMN: &str = "key";
+
+pub fn to_csv<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let rows = records(serde_json::to_value(value)?);
+
+    // the header is the union of every row's columns, in the order they are first seen
+    let mut header: Vec<String> = vec![];
+    rows.iter().for_each(|row| {
+        row.keys().for_each(|column| {
+            if !header.contains(column) {
+                header.push(column.clone());
+            }
+        })
+    });
+
+    let mut out = csv_record(&header);
+    rows.iter().for_each(|row| {
+        let fields = header
+            .iter()
+            .map(|column| row.get(column).map(cell).unwrap_or_default())
+            .collect::<Vec<_>>();
+        out.push_str(&csv_record(&fields));
+    });
+
+    Ok(out)
+}
+
+/// A single CSV line, quoting any field which contains a delimiter, quote or line break.
+pub fn csv_record<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+fn records(value: Value) -> Vec<Map<String, Value>> {
+    match value {
+        Value::Array(items) => items.into_iter().map(row).collect(),
+        Value::Object(map) if is_object_map(&map) => map
+            .into_iter()
+            .map(|(key, value)| {
+                let mut entry = Map::new();
+                entry.insert(KEY_COLUMN.into(), Value::String(key));
+                entry.extend(row(value));
+                entry
+            })
+            .collect(),
+        Value::Object(map) => {
+            // unwrap an envelope around a single table, e.g. `{"results": [...]}`
+            let tables = map.values().filter(|v| is_table(v)).count();
+            let scalars = map.values().filter(|v| is_scalar(v)).count();
+            if tables == 1 && tables + scalars == map.len() {
+                let table = map
+                    .into_iter()
+                    .find_map(|(_, v)| is_table(&v).then_some(v))
+                    .unwrap_or_default();
+                records(table)
+            } else {
+                vec![row(Value::Object(map))]
+            }
+        }
+        value => vec![row(value)],
+    }
+}
+
+fn row(value: Value) -> Map<String, Value> {
+    let mut row = Map::new();
+    match value {
+        Value::Object(map) => flatten("", map, &mut row),
+        value => {
+            row.insert("value".into(), value);
+        }
+    }
+    row
+}
+
+fn flatten(prefix: &str, map: Map<String, Value>, row: &mut Map<String, Value>) {
+    map.into_iter().for_each(|(key, value)| {
+        let column = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            Value::Object(nested) => flatten(&column, nested, row),
+            value => {
+                row.insert(column, value);
+            }
+        }
+    });
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.iter().all(is_scalar) => {
+            items.iter().map(cell).collect::<Vec<_>>().join(";")
+        }
+        value => value.to_string(),
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+fn is_object_map(map: &Map<String, Value>) -> bool {
+    !map.is_empty() && map.values().all(Value::is_object)
+}
+
+fn is_table(value: &Value) -> bool {
+    match value {
+        Value::Array(items) => items.iter().all(Value::is_object),
+        Value::Object(map) => is_object_map(map),
+        _ => false,
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use comfy_table::Row;
+use modsurfer_validation::new_table;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use super::location::{fetch_location, LocationCredentials};
+use super::plugin_manifest::PluginManifest;
+
+/// Sets the plugin index, unless one is given with `--index` or by the profile's `plugin_index`.
+pub const PLUGIN_INDEX_ENV: &str = "MODSURFER_PLUGIN_INDEX";
+
+/// An index of published plugins, searched by `plugin search` and resolving the plugins installed
+/// by `plugin install <name>@<version>`. The index is a JSON document at an `https://`, `s3://` or
+/// `oci://` location (or a path on disk), e.g.
+///
+/// ```json
+/// {
+///   "plugins": [
+///     {
+///       "name": "license-report",
+///       "version": "1.2.0",
+///       "description": "List the licenses found in a module",
+///       "wasm": "oci://ghcr.io/acme/license-report:1.2.0",
+///       "sha256": "<hex SHA-256 of the wasm>",
+///       "manifest": "license-report/1.2.0/plugin.yaml"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// The versions of a plugin are listed in the order they were published, so the last is its latest.
+/// Relative `wasm` and `manifest` locations are resolved against the location of the index.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PluginIndex {
+    #[serde(default)]
+    pub plugins: Vec<IndexedPlugin>,
+}
+
+/// A version of a plugin published to an index.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IndexedPlugin {
+    pub name: String,
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// the location of the plugin's wasm
+    pub wasm: String,
+    /// the SHA-256 of the wasm, checked when it is fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// the location of the plugin's `plugin.yaml`, see `PluginManifest`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+}
+
+impl PluginIndex {
+    /// The index given by `--index` (or the profile), otherwise by `MODSURFER_PLUGIN_INDEX`.
+    pub fn location(arg: Option<&String>) -> Result<String> {
+        arg.cloned()
+            .or_else(|| std::env::var(PLUGIN_INDEX_ENV).ok())
+            .filter(|location| !location.is_empty())
+            .ok_or_else(|| {
+                anyhow!(
+                    "no plugin index is set, use `--index`, the profile's `plugin_index` or {}",
+                    PLUGIN_INDEX_ENV
+                )
+            })
+    }
+
+    /// Fetch the index at `location`, resolving the locations of its plugins against it.
+    pub async fn fetch(location: &str, credentials: &LocationCredentials) -> Result<PluginIndex> {
+        let data = fetch(location, credentials)
+            .await
+            .with_context(|| format!("failed to fetch plugin index {}", location))?;
+        let mut index: PluginIndex = serde_json::from_slice(&data)
+            .with_context(|| format!("invalid plugin index {}", location))?;
+        for plugin in &mut index.plugins {
+            plugin.wasm = resolve(location, &plugin.wasm)?;
+            if let Some(manifest) = &plugin.manifest {
+                plugin.manifest = Some(resolve(location, manifest)?);
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// The plugins whose name or description contains `term`, ignoring case, with their versions.
+    pub fn search(&self, term: &str) -> PluginSearchResults {
+        let term = term.to_lowercase();
+        let mut found: BTreeMap<&str, PluginSearchResult> = BTreeMap::new();
+        for plugin in &self.plugins {
+            let matches = plugin.name.to_lowercase().contains(&term)
+                || plugin
+                    .description
+                    .as_ref()
+                    .is_some_and(|d| d.to_lowercase().contains(&term));
+            if !matches {
+                continue;
+            }
+            let result = found
+                .entry(plugin.name.as_str())
+                .or_insert_with(|| PluginSearchResult {
+                    name: plugin.name.clone(),
+                    latest: String::new(),
+                    versions: vec![],
+                    description: None,
+                });
+            result.latest = plugin.version.clone();
+            result.versions.push(plugin.version.clone());
+            if plugin.description.is_some() {
+                result.description = plugin.description.clone();
+            }
+        }
+
+        PluginSearchResults {
+            plugins: found.into_values().collect(),
+        }
+    }
+
+    /// The plugin named by `spec`, as `<name>@<version>`, or `<name>` for its latest version.
+    pub fn resolve(&self, spec: &str) -> Result<&IndexedPlugin> {
+        let (name, version) = match spec.split_once('@') {
+            Some((name, version)) => (name, Some(version)),
+            None => (spec, None),
+        };
+        let versions = self
+            .plugins
+            .iter()
+            .filter(|p| p.name == name)
+            .collect::<Vec<_>>();
+        if versions.is_empty() {
+            anyhow::bail!(
+                "no plugin `{}` in the plugin index, see `modsurfer plugin search`",
+                name
+            );
+        }
+
+        match version {
+            None => Ok(versions[versions.len() - 1]),
+            Some(version) => versions
+                .iter()
+                .find(|p| p.version == version)
+                .copied()
+                .ok_or_else(|| {
+                    anyhow!(
+                        "no version {} of plugin `{}` in the plugin index, it has: {}",
+                        version,
+                        name,
+                        versions
+                            .iter()
+                            .map(|p| p.version.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                }),
+        }
+    }
+}
+
+impl IndexedPlugin {
+    /// Fetch the plugin's wasm, checking its hash if the index gives one.
+    pub async fn fetch_wasm(&self, credentials: &LocationCredentials) -> Result<Vec<u8>> {
+        let wasm = fetch(&self.wasm, credentials)
+            .await
+            .with_context(|| format!("failed to fetch plugin from {}", self.wasm))?;
+        if let Some(expected) = &self.sha256 {
+            let actual = format!("{:x}", Sha256::digest(&wasm));
+            if !actual.eq_ignore_ascii_case(expected.trim()) {
+                anyhow::bail!(
+                    "the plugin at {} has SHA-256 {}, but the plugin index expects {}",
+                    self.wasm,
+                    actual,
+                    expected
+                );
+            }
+        }
+
+        Ok(wasm)
+    }
+
+    /// Fetch and check the plugin's manifest, if the index gives one.
+    pub async fn fetch_manifest(
+        &self,
+        credentials: &LocationCredentials,
+    ) -> Result<Option<PluginManifest>> {
+        let Some(location) = &self.manifest else {
+            return Ok(None);
+        };
+        let data = fetch(location, credentials)
+            .await
+            .with_context(|| format!("failed to fetch plugin manifest {}", location))?;
+
+        PluginManifest::parse(&data, location).map(Some)
+    }
+}
+
+// fetch a location of the index, which may be a path on disk
+async fn fetch(location: &str, credentials: &LocationCredentials) -> Result<Vec<u8>> {
+    if Url::parse(location).is_ok() {
+        return fetch_location(location, credentials).await;
+    }
+
+    Ok(tokio::fs::read(location).await?)
+}
+
+// a location given by the index at `index`, resolved against it if it is relative
+fn resolve(index: &str, location: &str) -> Result<String> {
+    if Url::parse(location).is_ok() {
+        return Ok(location.to_string());
+    }
+    match Url::parse(index) {
+        Ok(base) => Ok(base
+            .join(location)
+            .with_context(|| format!("invalid location `{}` in plugin index", location))?
+            .to_string()),
+        Err(_) => {
+            let dir = Path::new(index).parent().unwrap_or_else(|| Path::new(""));
+            Ok(dir.join(location).display().to_string())
+        }
+    }
+}
+
+/// The plugins found in an index by `plugin search`.
+#[derive(Serialize)]
+pub struct PluginSearchResults {
+    pub plugins: Vec<PluginSearchResult>,
+}
+
+#[derive(Serialize)]
+pub struct PluginSearchResult {
+    pub name: String,
+    pub latest: String,
+    /// every version published, oldest first
+    pub versions: Vec<String>,
+    pub description: Option<String>,
+}
+
+impl Display for PluginSearchResults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec!["Name", "Latest", "Versions", "Description"]);
+
+        self.plugins.iter().for_each(|p| {
+            table.add_row(Row::from(vec![
+                p.name.clone(),
+                p.latest.clone(),
+                p.versions.join(", "),
+                p.description.clone().unwrap_or_default(),
+            ]));
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use modsurfer_module::Module;
+use serde::{Deserialize, Serialize};
+
+use super::profile::Config;
+
+/// The manifest looked for next to a plugin's wasm by `plugin call --wasm`.
+pub const MANIFEST_FILE: &str = "plugin.yaml";
+
+/// A description of a plugin, read by `plugin install --manifest` (or from a plugin index, see
+/// `PluginIndex`) and checked by `plugin call`, e.g.
+///
+/// ```yaml
+/// identifier: acme/license-report
+/// name: License report
+/// wasm: license_report.wasm
+/// functions:
+///   - name: report
+///     description: list the licenses found in a module, as JSON
+/// config:
+///   - key: format
+///     description: the format of the report, json or csv
+///     required: true
+/// permissions:
+///   wasi: false
+///   allowed_hosts: []
+/// ```
+///
+/// The manifest of each plugin installed is kept in the `plugins` directory next to the config
+/// file, so that calls to the plugin by its identifier are checked too.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginManifest {
+    pub identifier: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// a URL, or a path relative to the manifest, of the plugin's wasm
+    pub wasm: String,
+    /// the functions the plugin exports to be called
+    pub functions: Vec<PluginFunction>,
+    /// the config keys the plugin reads
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub config: Vec<PluginConfigKey>,
+    #[serde(default)]
+    pub permissions: PluginPermissions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginFunction {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginConfigKey {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// whether every call must set the key
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// The access to the host a plugin needs. Plugins called on this machine get neither.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginPermissions {
+    #[serde(default)]
+    pub wasi: bool,
+    /// the hosts the plugin makes HTTP requests to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_hosts: Vec<String>,
+}
+
+impl PluginManifest {
+    /// Read and check the manifest at `path`.
+    pub async fn read(path: &Path) -> Result<PluginManifest> {
+        let data = tokio::fs::read(path)
+            .await
+            .with_context(|| format!("failed to read plugin manifest {}", path.display()))?;
+
+        PluginManifest::parse(&data, &path.display().to_string())
+    }
+
+    /// Parse and check a manifest read from `source`, e.g. by `plugin install <name>@<version>`.
+    pub fn parse(data: &[u8], source: &str) -> Result<PluginManifest> {
+        let manifest: PluginManifest = serde_yaml::from_slice(data)
+            .map_err(|e| anyhow!("invalid plugin manifest {}: {}", source, e))?;
+        manifest
+            .check()
+            .with_context(|| format!("invalid plugin manifest {}", source))?;
+
+        Ok(manifest)
+    }
+
+    /// The location of the plugin's wasm, resolving a relative path against the directory of the
+    /// manifest at `path`.
+    pub fn wasm_location(&self, path: &Path) -> String {
+        if url::Url::parse(&self.wasm).is_ok() {
+            return self.wasm.clone();
+        }
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        dir.join(&self.wasm).display().to_string()
+    }
+
+    /// Check that the module parsed from the plugin's wasm exports every function declared.
+    pub fn check_exports(&self, module: &Module) -> Result<()> {
+        let exported = module
+            .exports
+            .iter()
+            .map(|e| e.func.name.as_str())
+            .collect::<BTreeSet<_>>();
+        let missing = self
+            .functions
+            .iter()
+            .filter(|f| !exported.contains(f.name.as_str()))
+            .map(|f| format!("`{}`", f.name))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            anyhow::bail!(
+                "plugin {} does not export the functions its manifest declares: {}",
+                self.identifier,
+                missing.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Check a call to `function` against the manifest, with `config` if the call sets any
+    /// (calls to a Modsurfer server use the config the plugin was installed with).
+    pub fn check_call(
+        &self,
+        function: &str,
+        config: Option<&BTreeMap<String, String>>,
+    ) -> Result<()> {
+        if !self.functions.iter().any(|f| f.name == function) {
+            anyhow::bail!(
+                "plugin {} has no function `{}`, its manifest declares: {}",
+                self.identifier,
+                function,
+                self.function_names()
+            );
+        }
+
+        if let Some(config) = config {
+            for key in config.keys() {
+                if !self.config.iter().any(|c| &c.key == key) {
+                    anyhow::bail!(
+                        "plugin {} has no config key `{}`, its manifest declares: {}",
+                        self.identifier,
+                        key,
+                        self.config_keys()
+                    );
+                }
+            }
+            let missing = self
+                .config
+                .iter()
+                .filter(|c| c.required && !config.contains_key(&c.key))
+                .map(|c| format!("`{}`", c.key))
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                anyhow::bail!(
+                    "plugin {} requires config {}, set with `--config KEY=VALUE`",
+                    self.identifier,
+                    missing.join(", ")
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A summary of the access to the host the plugin needs, if any.
+    pub fn permissions_summary(&self) -> Option<String> {
+        let mut needs = vec![];
+        if self.permissions.wasi {
+            needs.push("WASI".to_string());
+        }
+        if !self.permissions.allowed_hosts.is_empty() {
+            needs.push(format!(
+                "HTTP requests to {}",
+                self.permissions.allowed_hosts.join(", ")
+            ));
+        }
+
+        (!needs.is_empty()).then(|| needs.join(" and "))
+    }
+
+    /// Keep the manifest of an installed plugin, returning where it was written.
+    pub async fn save_installed(&self) -> Result<PathBuf> {
+        let path = installed_path(&self.identifier)?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        tokio::fs::write(&path, serde_yaml::to_string(self)?)
+            .await
+            .with_context(|| format!("failed to write {}", path.display()))?;
+
+        Ok(path)
+    }
+
+    /// The manifest kept for an installed plugin, if it was installed with one.
+    pub async fn load_installed(identifier: &str) -> Result<Option<PluginManifest>> {
+        let Ok(path) = installed_path(identifier) else {
+            return Ok(None);
+        };
+        match tokio::fs::metadata(&path).await {
+            Ok(_) => Ok(Some(PluginManifest::read(&path).await?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    /// Remove the manifest kept for an uninstalled plugin, if there is one.
+    pub async fn remove_installed(identifier: &str) -> Result<()> {
+        let Ok(path) = installed_path(identifier) else {
+            return Ok(());
+        };
+        match tokio::fs::remove_file(&path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(e).with_context(|| format!("failed to remove {}", path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.identifier.trim().is_empty() {
+            anyhow::bail!("`identifier` is empty");
+        }
+        if self.functions.is_empty() {
+            anyhow::bail!("no `functions` are declared");
+        }
+        let mut names = BTreeSet::new();
+        for function in &self.functions {
+            if !names.insert(&function.name) {
+                anyhow::bail!("function `{}` is declared more than once", function.name);
+            }
+        }
+        let mut keys = BTreeSet::new();
+        for key in &self.config {
+            if !keys.insert(&key.key) {
+                anyhow::bail!("config key `{}` is declared more than once", key.key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn function_names(&self) -> String {
+        self.functions
+            .iter()
+            .map(|f| format!("`{}`", f.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn config_keys(&self) -> String {
+        if self.config.is_empty() {
+            return "none".into();
+        }
+        self.config
+            .iter()
+            .map(|c| format!("`{}`", c.key))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// the manifest kept for an installed plugin, named by its identifier with any `/` replaced, e.g.
+// `~/.config/modsurfer/plugins/acme_license-report.yaml`
+fn installed_path(identifier: &str) -> Result<PathBuf> {
+    let config = Config::path().ok_or_else(|| anyhow!("no config directory found"))?;
+    let dir = config
+        .parent()
+        .map(|dir| dir.join("plugins"))
+        .unwrap_or_else(|| PathBuf::from("plugins"));
+    let file = identifier
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+
+    Ok(dir.join(format!("{}.yaml", file)))
+}
@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use comfy_table::Row;
+use modsurfer_validation::new_table;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::location::LocationCredentials;
+use super::metadata::check_template;
+use super::OutputFormat;
+
+/// Overrides the path of the config file.
+pub const CONFIG_ENV: &str = "MODSURFER_CONFIG";
+/// Selects a profile, unless one is given with `--profile`.
+pub const PROFILE_ENV: &str = "MODSURFER_PROFILE";
+
+/// Named profiles for each Modsurfer server the CLI is used with, e.g.
+///
+/// ```yaml
+/// current: staging
+/// profiles:
+///   staging:
+///     base_url: https://modsurfer.staging.example.com
+///     token: <api token>
+///     checkfile: checks/staging.yaml
+///     output_format: json
+///     plugin_index: https://plugins.example.com/index.json
+///     generate_hooks:
+///       - hooks/org-defaults.wasm
+///     metadata:
+///       git_sha: ${GITHUB_SHA}
+///       ci_job: ${GITHUB_SERVER_URL}/${GITHUB_REPOSITORY}/actions/runs/${GITHUB_RUN_ID}
+///     locations:
+///       hosts:
+///         ghcr.io:
+///           username: ci-bot
+///           password: <personal access token>
+/// ```
+///
+/// The profile named by `--profile` or `MODSURFER_PROFILE` is used, otherwise the `current`
+/// profile, set by `modsurfer profile use`. Every field of a profile is optional.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// the URL of the Modsurfer server, used instead of `MODSURFER_BASE_URL`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// sent as a bearer token in the `Authorization` header of every request to the server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// the checkfile used by `validate` and `audit` when `-c` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkfile: Option<PathBuf>,
+    /// the output format used when `--output-format` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<String>,
+    /// the index of published plugins used by `plugin search` and `plugin install <name>`, see
+    /// `PluginIndex`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_index: Option<String>,
+    /// the generate hooks run by `generate` when `--post-hook` is not given, see
+    /// `run_generate_hooks`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generate_hooks: Option<Vec<PathBuf>>,
+    /// metadata added to every module created, as templates of environment variables, see
+    /// `expand_metadata`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<BTreeMap<String, String>>,
+    /// credentials for fetching modules from their locations, see `LocationCredentials`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locations: Option<LocationCredentials>,
+}
+
+impl Profile {
+    fn check(&self) -> Result<()> {
+        if let Some(url) = &self.base_url {
+            Url::parse(url).with_context(|| format!("invalid base_url `{}`", url))?;
+        }
+        if let Some(format) = &self.output_format {
+            OutputFormat::from_str(format, true)
+                .map_err(|_| anyhow!("invalid output_format `{}`", format))?;
+        }
+        for (key, template) in self.metadata.iter().flatten() {
+            check_template(template)
+                .with_context(|| format!("invalid metadata template for `{}`", key))?;
+        }
+        if let Some(locations) = &self.locations {
+            locations.check().context("invalid locations")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Config {
+    /// The config file: `$MODSURFER_CONFIG`, otherwise `modsurfer/config.yaml` in the user's config
+    /// directory (`$XDG_CONFIG_HOME`, `~/.config` or `%APPDATA%`).
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_ENV) {
+            return Some(path.into());
+        }
+        let dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+
+        Some(dir.join("modsurfer").join("config.yaml"))
+    }
+
+    /// Read the config file, or an empty config if there is none.
+    pub async fn load() -> Result<Config> {
+        let Some(path) = Config::path() else {
+            return Ok(Config::default());
+        };
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let config: Config = serde_yaml::from_slice(&data)
+            .map_err(|e| anyhow!("invalid config {}: {}", path.display(), e))?;
+        for (name, profile) in &config.profiles {
+            profile
+                .check()
+                .with_context(|| format!("invalid profile `{}` in {}", name, path.display()))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Write the config file, returning its path.
+    pub async fn save(&self) -> Result<PathBuf> {
+        let path = Config::path()
+            .ok_or_else(|| anyhow!("no config directory found, set {} instead", CONFIG_ENV))?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        tokio::fs::write(&path, serde_yaml::to_string(self)?).await?;
+
+        Ok(path)
+    }
+
+    pub fn profile(&self, name: &str) -> Result<&Profile> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| anyhow!("unknown profile `{}`, see `modsurfer profile list`", name))
+    }
+
+    /// The profile named by `name` (from `--profile`), by `MODSURFER_PROFILE`, or the current
+    /// profile, if any is selected.
+    pub fn select(&self, name: Option<String>) -> Result<Option<&Profile>> {
+        match name
+            .or_else(|| env::var(PROFILE_ENV).ok())
+            .or_else(|| self.current.clone())
+        {
+            Some(name) => self.profile(&name).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The profiles in the config file, without their tokens.
+#[derive(Serialize)]
+pub struct ProfilesResult {
+    pub profiles: Vec<ProfileResult>,
+}
+
+#[derive(Serialize)]
+pub struct ProfileResult {
+    pub name: String,
+    pub current: bool,
+    pub base_url: Option<String>,
+    pub token: bool,
+    pub checkfile: Option<PathBuf>,
+    pub output_format: Option<String>,
+    /// the keys of the metadata added to modules created with the profile
+    pub metadata: Vec<String>,
+}
+
+impl From<&Config> for ProfilesResult {
+    fn from(config: &Config) -> Self {
+        ProfilesResult {
+            profiles: config
+                .profiles
+                .iter()
+                .map(|(name, profile)| ProfileResult {
+                    name: name.clone(),
+                    current: config.current.as_ref() == Some(name),
+                    base_url: profile.base_url.clone(),
+                    token: profile.token.is_some(),
+                    checkfile: profile.checkfile.clone(),
+                    output_format: profile.output_format.clone(),
+                    metadata: profile
+                        .metadata
+                        .iter()
+                        .flatten()
+                        .map(|(k, _)| k.clone())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Display for ProfilesResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec![
+            "Current",
+            "Profile",
+            "Base URL",
+            "Token",
+            "Checkfile",
+            "Output Format",
+            "Metadata",
+        ]);
+
+        self.profiles.iter().for_each(|p| {
+            table.add_row(Row::from(vec![
+                if p.current { "*" } else { "" }.to_string(),
+                p.name.clone(),
+                p.base_url.clone().unwrap_or_default(),
+                if p.token { "set" } else { "" }.to_string(),
+                p.checkfile
+                    .as_ref()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_default(),
+                p.output_format.clone().unwrap_or_default(),
+                p.metadata.join(", "),
+            ]));
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+use std::io::IsTerminal;
+use std::time::Duration;
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use super::exec::OutputFormat;
+
+/// How often a spinner is redrawn while waiting on a single long-running operation.
+const SPINNER_TICK: Duration = Duration::from_millis(100);
+
+/// Progress is only drawn for people watching a terminal. It's hidden when stdout is redirected,
+/// or the output is JSON, CSV or YAML, which is likely to be consumed by another program.
+fn enabled(output_format: &OutputFormat) -> bool {
+    matches!(output_format, OutputFormat::Table) && std::io::stdout().is_terminal()
+}
+
+/// A progress bar counting up to `len` items, with an ETA. Progress is drawn to stderr, so that it
+/// doesn't interleave with the command's output.
+pub fn bar(
+    len: u64,
+    message: impl Into<Cow<'static, str>>,
+    output_format: &OutputFormat,
+) -> ProgressBar {
+    if !enabled(output_format) {
+        return ProgressBar::hidden();
+    }
+
+    let bar = ProgressBar::new(len).with_message(message);
+    bar.set_style(
+        ProgressStyle::with_template("{msg} [{bar:40}] {pos}/{len} (eta {eta})")
+            .expect("valid progress template")
+            .progress_chars("=> "),
+    );
+    bar
+}
+
+/// A spinner for an operation whose progress can't be measured, such as a single upload.
+pub fn spinner(message: impl Into<Cow<'static, str>>, output_format: &OutputFormat) -> ProgressBar {
+    if !enabled(output_format) {
+        return ProgressBar::hidden();
+    }
+
+    let spinner = ProgressBar::new_spinner().with_message(message);
+    spinner.set_style(
+        ProgressStyle::with_template("{spinner} {msg} ({elapsed})")
+            .expect("valid progress template"),
+    );
+    spinner.enable_steady_tick(SPINNER_TICK);
+    spinner
+}
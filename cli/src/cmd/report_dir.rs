@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use modsurfer_api::{AuditReports, ModuleReport};
+use modsurfer_validation::Verdict;
+use serde::Serialize;
+
+/// The file name of the summary written alongside the per-module reports.
+const INDEX_FILE: &str = "index.json";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Sarif,
+}
+
+impl ReportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Sarif => "sarif",
+        }
+    }
+}
+
+impl From<String> for ReportFormat {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "sarif" => Self::Sarif,
+            _ => Self::Json,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Index<'a> {
+    checkfile: String,
+    modules: Vec<IndexEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct IndexEntry<'a> {
+    module_id: String,
+    hash: &'a str,
+    passed: bool,
+    /// the checkfile the module was validated against, if selected by an audit config
+    checkfile: Option<&'a str>,
+    failures: usize,
+    /// the report file for this module, relative to the index, if the module failed validation
+    report: Option<String>,
+}
+
+/// Write one report file per failing module into `dir`, named by module ID and hash, along with an
+/// `index.json` summarizing every audited module. The `checkfile` is the checkfile or audit config
+/// used for the audit. Returns the number of report files written.
+pub fn write_reports(
+    dir: &Path,
+    checkfile: &Path,
+    reports: &AuditReports,
+    format: ReportFormat,
+) -> Result<usize> {
+    fs::create_dir_all(dir)?;
+
+    let mut written = 0;
+    let mut modules = vec![];
+    for r in reports.iter() {
+        let verdict = Verdict::new(&r.report);
+        let report = if verdict.passed() {
+            None
+        } else {
+            let file_name = format!("{}-{}.{}", r.module_id, r.hash, format.extension());
+            fs::write(dir.join(&file_name), render(r, format)?)?;
+            written += 1;
+            Some(file_name)
+        };
+
+        modules.push(IndexEntry {
+            module_id: r.module_id.to_string(),
+            hash: &r.hash,
+            passed: verdict.passed(),
+            checkfile: r.checkfile.as_deref(),
+            failures: r.report.fails.len(),
+            report,
+        });
+    }
+
+    let index = Index {
+        checkfile: checkfile.display().to_string(),
+        modules,
+    };
+    fs::write(dir.join(INDEX_FILE), serde_json::to_string_pretty(&index)?)?;
+
+    Ok(written)
+}
+
+fn render(r: &ModuleReport, format: ReportFormat) -> Result<String> {
+    let output = match format {
+        ReportFormat::Json => serde_json::to_string_pretty(&r.report)?,
+        ReportFormat::Sarif => {
+            serde_json::to_string_pretty(&r.report.to_sarif(&format!("modsurfer:{}", r.hash)))?
+        }
+    };
+
+    Ok(output)
+}
@@ -1,3 +1,4 @@
 mod cmd;
 
-pub use cmd::exec::{Cli, Hash, Id, Limit, MetadataEntry, Offset, OutputFormat, Version};
+pub use cmd::exec::{Cli, Hash, Id, Limit, MetadataEntry, Offset, OutputFormat, Tag, Version};
+pub use cmd::profile::{Config, Profile};
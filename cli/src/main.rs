@@ -1,46 +1,168 @@
 use std::{env, path::PathBuf, process::ExitCode};
 
 use anyhow::Result;
+use clap::builder::{PossibleValuesParser, TypedValueParser};
 use clap::{Arg, ArgAction, Command};
+use clap_complete::Shell;
 use modsurfer_convert::AuditOutcome;
+use modsurfer_validation::MemoryBudget;
 use url::Url;
 
 mod cmd;
 
-use cmd::{Cli, Hash, Id, Limit, MetadataEntry, Offset, OutputFormat, Version};
+use cmd::profile::{Config, Profile};
+use cmd::{
+    error::CliError, parse_age, parse_event_kind, parse_metadata_entry, parse_override,
+    parse_sort_direction, parse_sort_field, parse_timeout, report_dir::ReportFormat, Cli,
+    GraphFormat, Hash, Id, Limit, Offset, OutputFormat, Tag, Version,
+};
 
 const BASE_URL_ENV: &'static str = "MODSURFER_BASE_URL";
 const DEFAULT_BASE_URL: &'static str = "http://localhost:1739";
+const DEFAULT_CHECKFILE: &'static str = "mod.yaml";
 
 #[tokio::main]
-async fn main() -> Result<ExitCode> {
-    // get MODSURFER_BASE_URL environment variable if set
-    let base_url = Url::parse(
-        env::var_os(BASE_URL_ENV)
-            .unwrap_or_else(|| DEFAULT_BASE_URL.into())
-            .to_str()
-            .unwrap_or(DEFAULT_BASE_URL),
-    )?;
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(code) => code,
+        Err(e) => {
+            let e = CliError::from(e);
+            eprintln!("Error: {}", e);
+            e.exit_code()
+        }
+    }
+}
+
+async fn run() -> Result<ExitCode> {
+    // the selected profile sets the defaults of arguments, so it is found before they are parsed
+    let config = Config::load().await?;
+    let profile = config.select(profile_arg())?.cloned().unwrap_or_default();
+
+    // use the profile's server, otherwise get MODSURFER_BASE_URL environment variable if set
+    let base_url = match &profile.base_url {
+        Some(url) => Url::parse(url)?,
+        None => Url::parse(
+            env::var_os(BASE_URL_ENV)
+                .unwrap_or_else(|| DEFAULT_BASE_URL.into())
+                .to_str()
+                .unwrap_or(DEFAULT_BASE_URL),
+        )?,
+    };
     let cmd = Command::new("modsurfer")
         .about("Modsurfer CLI is used to interact with the HTTP API or validate modules offline.")
         .version(env!("CARGO_PKG_VERSION"))
         .before_help("Copyright Dylibso, Inc. <support@dylib.so>")
-        .subcommands(make_subcommands());
+        .args(make_global_args())
+        .subcommands(make_subcommands(&profile));
+
+    Cli::new(cmd, base_url)
+        .token(profile.token)
+        .metadata(profile.metadata.unwrap_or_default())
+        .locations(profile.locations.unwrap_or_default())
+        .execute()
+        .await
+}
 
-    Cli::new(cmd, base_url).execute().await
+// the value of `--profile`, read ahead of parsing the rest of the arguments
+fn profile_arg() -> Option<String> {
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--profile" {
+            return args.next();
+        }
+        if let Some(name) = arg.strip_prefix("--profile=") {
+            return Some(name.to_string());
+        }
+    }
+    None
 }
 
-fn add_output_arg(cmd: Command) -> Command {
-    cmd.arg(
-        Arg::new("output-format")
-            .value_parser(clap::value_parser!(OutputFormat))
-            .long("output-format")
-            .required(false)
-            .help("set the output format of any command, supports `json` or `table` (default)"),
-    )
+fn make_global_args() -> Vec<Arg> {
+    vec![
+        Arg::new("profile")
+            .long("profile")
+            .global(true)
+            .help("use the server, token and defaults of a profile from the config file, instead of the one set by the `MODSURFER_PROFILE` environment variable or `modsurfer profile use`"),
+        Arg::new("proxy")
+            .long("proxy")
+            .global(true)
+            .help("send requests to the Modsurfer server and for remote checkfiles through this proxy URL, instead of the one set by the `HTTPS_PROXY`, `HTTP_PROXY` or `ALL_PROXY` environment variables (hosts in `NO_PROXY` are still reached directly)"),
+        Arg::new("strict-checkfile")
+            .long("strict-checkfile")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("check checkfiles against the checkfile schema before reading them, reporting every error found with its line"),
+        Arg::new("no-strings")
+            .long("no-strings")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("drop the strings of parsed modules, skipping the scan for the licenses and libraries in them"),
+        Arg::new("no-graph")
+            .long("no-graph")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("drop the call graph of parsed modules"),
+        Arg::new("no-hashes")
+            .long("no-hashes")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("drop the function hashes of parsed modules, skipping computing them if the parser gave none"),
+        Arg::new("parse-timeout")
+            .long("parse-timeout")
+            .value_parser(parse_timeout)
+            .global(true)
+            .help("fail parsing a module which takes longer than this, e.g. `30s` or `2m`, rather than wait on it"),
+        Arg::new("plugin-memory-max")
+            .long("plugin-memory-max")
+            .value_parser(MemoryBudget::parse)
+            .global(true)
+            .help("the most memory the parser plugin, or a plugin called with `plugin call --wasm`, may grow to, e.g. `256MiB`"),
+        Arg::new("no-color")
+            .long("no-color")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("disable colored output, also set by the `NO_COLOR` environment variable"),
+        Arg::new("ascii")
+            .long("ascii")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("draw table borders with ASCII characters only, also set by the `MODSURFER_ASCII` environment variable"),
+        Arg::new("max-width")
+            .long("max-width")
+            .value_parser(clap::value_parser!(u16))
+            .global(true)
+            .help("wrap tables to fit within a total width, also set by the `MODSURFER_MAX_WIDTH` environment variable"),
+        Arg::new("max-column-width")
+            .long("max-column-width")
+            .value_parser(clap::value_parser!(u16))
+            .global(true)
+            .help("wrap table columns wider than the given width, also set by the `MODSURFER_MAX_COLUMN_WIDTH` environment variable"),
+    ]
 }
 
-fn make_subcommands() -> Vec<Command> {
+fn add_output_arg(cmd: Command, profile: &Profile) -> Command {
+    let arg = Arg::new("output-format")
+        .value_parser(clap::value_parser!(OutputFormat))
+        .long("output-format")
+        .required(false)
+        .help("set the output format of any command, supports `json`, `csv`, `yaml` or `table` (default)");
+
+    match &profile.output_format {
+        Some(format) => cmd.arg(arg.default_value(format.clone())),
+        None => cmd.arg(arg),
+    }
+}
+
+fn make_subcommands(profile: &Profile) -> Vec<Command> {
+    let checkfile = profile
+        .checkfile
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| DEFAULT_CHECKFILE.into());
+
     let create = clap::Command::new("create")
         .about("Create a new entry for a module.")
         .arg(
@@ -52,7 +174,7 @@ fn make_subcommands() -> Vec<Command> {
         )
         .arg(
             Arg::new("metadata")
-                .value_parser(clap::value_parser!(MetadataEntry))
+                .value_parser(parse_metadata_entry)
                 .long("metadata")
                 .short('m')
                 .action(ArgAction::Append)
@@ -61,6 +183,13 @@ fn make_subcommands() -> Vec<Command> {
                     "a repeatable key=value metadata entry, to add arbitrary context to a module",
                 ),
         )
+        .arg(
+            Arg::new("metadata-file")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("metadata-file")
+                .required(false)
+                .help("a path on disk to a JSON object of metadata entries, added after the profile's metadata and before `--metadata` entries"),
+        )
         .arg(
             Arg::new("location")
                 .value_parser(clap::value_parser!(url::Url))
@@ -69,6 +198,22 @@ fn make_subcommands() -> Vec<Command> {
                 .required(false)
                 .help("a valid URL to where this module should be located"),
         )
+        .arg(
+            Arg::new("tag")
+                .value_parser(clap::value_parser!(Tag))
+                .long("tag")
+                .short('t')
+                .action(ArgAction::Append)
+                .required(false)
+                .help("a repeatable tag to group this module with others, e.g. `edge`"),
+        )
+        .arg(
+            Arg::new("version")
+                .value_parser(clap::value_parser!(Version))
+                .long("version")
+                .required(false)
+                .help("the version of the module, e.g. `1.2.0` (defaults to the `version` metadata entry, if set)"),
+        )
         .arg(
             Arg::new("check")
                 .value_parser(clap::value_parser!(PathBuf))
@@ -79,24 +224,214 @@ fn make_subcommands() -> Vec<Command> {
         );
 
     let delete = clap::Command::new("delete")
-        .about("Delete a module and its versions.")
+        .about("Delete a module and its versions, either by ID or by matching search criteria.")
         .arg(
             Arg::new("id")
                 .value_parser(clap::value_parser!(i64))
                 .long("id")
                 .action(ArgAction::Append)
+                .required_unless_present_any(["hash", "older-than", "metadata"])
+                .conflicts_with_all(["hash", "older-than", "metadata"])
                 .help("the numeric ID of a module entry in Modsurfer"),
+        )
+        .arg(
+            Arg::new("hash")
+                .value_parser(clap::value_parser!(Hash))
+                .long("hash")
+                .help("delete modules matching this exact SHA-256 hash"),
+        )
+        .arg(
+            Arg::new("older-than")
+                .value_parser(parse_age)
+                .long("older-than")
+                .help("delete modules inserted longer ago than this age, e.g. `90d`, `12h` or `2w`"),
+        )
+        .arg(
+            Arg::new("metadata")
+                .value_parser(parse_metadata_entry)
+                .long("metadata")
+                .short('m')
+                .action(ArgAction::Append)
+                .help("a repeatable key=value metadata entry which modules must have to be deleted"),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("id")
+                .help("list the modules matching the search criteria, without deleting them (default)"),
+        )
+        .arg(
+            Arg::new("confirm")
+                .long("confirm")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["id", "dry-run"])
+                .help("delete the modules matching the search criteria (otherwise only a dry run is performed)"),
         );
 
     let get = clap::Command::new("get")
-        .about("Get a module by its ID.")
+        .about("Get a module by its ID or SHA-256 hash.")
         .arg(
             Arg::new("id")
                 .value_parser(clap::value_parser!(Id))
                 .long("id")
+                .required_unless_present("hash")
+                .help("the numeric ID of a module entry in Modsurfer"),
+        )
+        .arg(
+            Arg::new("hash")
+                .value_parser(clap::value_parser!(Hash))
+                .long("hash")
+                .conflicts_with("id")
+                .help("the full SHA-256 hash of a module entry in Modsurfer"),
+        )
+        .arg(
+            Arg::new("save")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("save")
+                .help("fetch the module's bytes from its location (an `https://`, `s3://` or `oci://` URL), check them against its hash, and write them to this path"),
+        );
+
+    let tag = clap::Command::new("tag")
+        .about("Replace the tags attached to a module.")
+        .arg(
+            Arg::new("id")
+                .value_parser(clap::value_parser!(Id))
+                .long("id")
+                .required(true)
+                .help("the numeric ID of a module entry in Modsurfer"),
+        )
+        .arg(
+            Arg::new("tag")
+                .value_parser(clap::value_parser!(Tag))
+                .long("tag")
+                .short('t')
+                .action(ArgAction::Append)
+                .required(false)
+                .help("a repeatable tag to set on the module (omit to clear all tags)"),
+        );
+
+    let quarantine = clap::Command::new("quarantine")
+        .about("Flag a module as blocked, e.g. during incident response, without deleting or yanking it.")
+        .arg(
+            Arg::new("id")
+                .value_parser(clap::value_parser!(Id))
+                .long("id")
+                .required(true)
+                .help("the numeric ID of a module entry in Modsurfer"),
+        )
+        .arg(
+            Arg::new("reason")
+                .long("reason")
+                .short('r')
+                .required(true)
+                .help("why the module is quarantined, e.g. a CVE or incident ID"),
+        );
+
+    let unquarantine = clap::Command::new("unquarantine")
+        .about("Lift the quarantine of a module.")
+        .arg(
+            Arg::new("id")
+                .value_parser(clap::value_parser!(Id))
+                .long("id")
+                .required(true)
                 .help("the numeric ID of a module entry in Modsurfer"),
         );
 
+    let ping = clap::Command::new("ping")
+        .about("Check that the Modsurfer server is reachable, and show its version and features.");
+
+    let similar = clap::Command::new("similar")
+        .about("Find modules which share most of their functions with a module, such as renamed copies and forks, by the Jaccard similarity of their function hashes.")
+        .arg(
+            Arg::new("id")
+                .value_parser(clap::value_parser!(Id))
+                .long("id")
+                .required_unless_present("path")
+                .help("the numeric ID of a module entry in Modsurfer to compare"),
+        )
+        .arg(
+            Arg::new("path")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("path")
+                .short('p')
+                .conflicts_with("id")
+                .help("a path on disk to a .wasm module to compare"),
+        )
+        .arg(
+            Arg::new("dir")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("dir")
+                .help("compare against the .wasm modules in this directory, instead of the registry"),
+        )
+        .arg(
+            Arg::new("threshold")
+                .value_parser(clap::value_parser!(f64))
+                .long("threshold")
+                .default_value("0.8")
+                .help("the minimum similarity, from 0.0 to 1.0, of the modules to show"),
+        );
+
+    let stats = clap::Command::new("stats")
+        .about("Show statistics about every module in the registry: counts by language, size and complexity distributions, the most imported namespaces, and growth over time.")
+        .arg(
+            Arg::new("top")
+                .value_parser(clap::value_parser!(usize))
+                .long("top")
+                .default_value("10")
+                .help("the number of most imported namespaces to show in table output"),
+        );
+
+    let report_namespaces = clap::Command::new("namespaces")
+        .about("Count the modules in the registry importing from each namespace, and each function in it, to see which host APIs are in use.")
+        .arg(
+            Arg::new("namespace")
+                .long("namespace")
+                .short('n')
+                .help("only count the functions of this namespace, searching only the modules which import from it"),
+        )
+        .arg(
+            Arg::new("max-modules")
+                .value_parser(clap::value_parser!(u64))
+                .long("max-modules")
+                .help("only show the namespaces and functions imported by at most this many modules, e.g. `1` to find candidates for deprecation"),
+        );
+
+    let report = clap::Command::new("report")
+        .about("Report on the modules in the registry.")
+        .subcommand(add_output_arg(report_namespaces, profile));
+
+    let watch_registry = clap::Command::new("watch-registry")
+        .about("Print module events (e.g. uploads and yanks) as they occur, until interrupted.")
+        .arg(
+            Arg::new("kind")
+                .value_parser(parse_event_kind)
+                .long("kind")
+                .short('k')
+                .action(ArgAction::Append)
+                .help("a repeatable kind of event to watch for: created, updated, deleted or yanked (default: all)"),
+        )
+        .arg(
+            Arg::new("tag")
+                .value_parser(clap::value_parser!(Tag))
+                .long("tag")
+                .short('t')
+                .action(ArgAction::Append)
+                .help("a repeatable tag which modules must have for their events to be shown"),
+        )
+        .arg(
+            Arg::new("hash")
+                .value_parser(clap::value_parser!(Hash))
+                .long("hash")
+                .help("only show events for the module with this SHA-256 hash"),
+        )
+        .arg(
+            Arg::new("since")
+                .value_parser(clap::value_parser!(u64))
+                .long("since")
+                .help("replay events after this event ID, rather than only showing new events"),
+        );
+
     let list = clap::Command::new("list")
         .about(
             "List all modules, paginated by the `offset` and `limit` parameters or their defaults.",
@@ -149,6 +484,56 @@ fn make_subcommands() -> Vec<Command> {
                 .required(false)
                 .help("adds a search parameter to match on `strings` extracted from a module"),
         )
+        .arg(
+            Arg::new("tag")
+                .value_parser(clap::value_parser!(Tag))
+                .long("tag")
+                .short('t')
+                .action(ArgAction::Append)
+                .required(false)
+                .help("adds a repeatable search parameter to match modules with all given tags"),
+        )
+        .arg(
+            Arg::new("version")
+                .value_parser(clap::value_parser!(Version))
+                .long("version")
+                .required(false)
+                .help("adds a search parameter to match on the exact `version`"),
+        )
+        .arg(
+            Arg::new("min-complexity")
+                .value_parser(clap::value_parser!(u32))
+                .long("min-complexity")
+                .required(false)
+                .help("adds a search parameter to match modules with at least this cyclomatic complexity"),
+        )
+        .arg(
+            Arg::new("max-complexity")
+                .value_parser(clap::value_parser!(u32))
+                .long("max-complexity")
+                .required(false)
+                .help("adds a search parameter to match modules with at most this cyclomatic complexity"),
+        )
+        .arg(
+            Arg::new("namespace")
+                .long("namespace")
+                .action(ArgAction::Append)
+                .required(false)
+                .help("adds a repeatable search parameter to match modules importing from all given namespaces, e.g. `wasi_snapshot_preview1`"),
+        )
+        .arg(
+            Arg::new("sort")
+                .value_parser(parse_sort_field)
+                .long("sort")
+                .help("the field to sort results by, e.g. `risk_score` to triage the riskiest modules first"),
+        )
+        .arg(
+            Arg::new("direction")
+                .value_parser(parse_sort_direction)
+                .long("direction")
+                .requires("sort")
+                .help("the direction to sort results in, `asc` or `desc` (the default)"),
+        )
         .arg(
             Arg::new("offset")
                 .value_parser(clap::value_parser!(Offset))
@@ -164,6 +549,113 @@ fn make_subcommands() -> Vec<Command> {
                 .help("the maximum number of modules in a list of results"),
         );
 
+    let explain = clap::Command::new("explain")
+        .about("Explain a report property or rule code (e.g. `imports.include` or `MS-SIZE-001`): what it checks, why it matters, and how to fix or suppress it.")
+        .arg(
+            Arg::new("rule")
+                .required(true)
+                .help("a report property path, such as `imports.include.env::log`, or a rule code"),
+        );
+
+    let compare_checkfiles = clap::Command::new("compare-checkfiles")
+        .about("Compare the checks made by two checkfiles, showing which were added or removed and whether each change loosens or tightens the policy.")
+        .arg(
+            Arg::new("before")
+                .value_parser(clap::value_parser!(PathBuf))
+                .required(true)
+                .help("a path on disk to the old YAML checkfile"),
+        )
+        .arg(
+            Arg::new("after")
+                .value_parser(clap::value_parser!(PathBuf))
+                .required(true)
+                .help("a path on disk to the new YAML checkfile"),
+        );
+
+    let test_policy = clap::Command::new("test-policy")
+        .about("Validate fixture modules which are known to pass or fail a checkfile, listed in a manifest, and report whether each met its expectation.")
+        .arg(
+            Arg::new("manifest")
+                .value_parser(clap::value_parser!(PathBuf))
+                .required(true)
+                .help("a path on disk to a YAML manifest naming the checkfile, and each fixture module with whether it should `pass` or `fail`"),
+        )
+        .arg(
+            Arg::new("check")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("check")
+                .short('c')
+                .help("a path on disk to a YAML checkfile to test, instead of the one named by the manifest"),
+        );
+
+    let link_check = clap::Command::new("link-check")
+        .about("Check that every function a module imports is provided by a host description or exported by another module, with a matching signature.")
+        .arg(
+            Arg::new("host")
+                .value_parser(clap::value_parser!(PathBuf))
+                .required(true)
+                .help("a path on disk to a YAML host description listing the functions it `provides`, or to a .wasm module whose exports are provided"),
+        )
+        .arg(
+            Arg::new("module")
+                .value_parser(clap::value_parser!(PathBuf))
+                .required(true)
+                .help("a path on disk to the WebAssembly module which imports from the host"),
+        )
+        .arg(
+            Arg::new("namespace")
+                .long("namespace")
+                .short('n')
+                .default_value("env")
+                .help("the namespace the module imports a host module's exports from"),
+        );
+
+    let capabilities = clap::Command::new("capabilities")
+        .about("List what a module can do on the host (filesystem, network, clock, randomness, environment and process exit), and the imports which grant each capability.")
+        .arg(
+            Arg::new("path")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("path")
+                .short('p')
+                .required(true)
+                .help("a path on disk to a valid WebAssembly module"),
+        );
+
+    let vuln = clap::Command::new("vuln")
+        .about("Look up known vulnerabilities of the libraries found in a module (from their version strings) in OSV or a local advisory database.")
+        .arg(
+            Arg::new("path")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("path")
+                .short('p')
+                .required(true)
+                .help("a path on disk to a valid WebAssembly module"),
+        )
+        .arg(
+            Arg::new("db")
+                .long("db")
+                .default_value(modsurfer_validation::OSV_DB)
+                .help("the advisory database to query: `osv`, or a path to a local OSV database (a JSON file or directory of advisories)"),
+        );
+
+    let bloat = clap::Command::new("bloat")
+        .about("Show where a module's size goes: each function, data segment and custom section, largest first, and the functions which can never be called.")
+        .arg(
+            Arg::new("path")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("path")
+                .short('p')
+                .required(true)
+                .help("a path on disk to a valid WebAssembly module"),
+        )
+        .arg(
+            Arg::new("top")
+                .value_parser(clap::value_parser!(usize))
+                .long("top")
+                .default_value("20")
+                .help("the number of largest items to show in table output"),
+        );
+
     let generate = clap::Command::new("generate")
         .about("Generate a starter checkfile from the given module.")
         .arg(
@@ -171,7 +663,7 @@ fn make_subcommands() -> Vec<Command> {
                 .value_parser(clap::value_parser!(PathBuf))
                 .long("path")
                 .short('p')
-                .help("a path on disk to a valid WebAssembly module"),
+                .help("a path on disk to a valid WebAssembly module, or a glob pattern matching modules with `--out-dir`"),
         )
         .arg(
             Arg::new("output")
@@ -180,6 +672,47 @@ fn make_subcommands() -> Vec<Command> {
                 .short('o')
                 .default_value("mod.yaml")
                 .help("a path on disk to write a generated YAML checkfile"),
+        )
+        .arg(
+            Arg::new("out-dir")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("out-dir")
+                .conflicts_with("output")
+                .help("a directory to write a checkfile for each module matching `--path`, named after the module, along with an index.yaml listing them"),
+        )
+        .arg(
+            Arg::new("minimal")
+                .long("minimal")
+                .action(ArgAction::SetTrue)
+                .help("only check the namespaces the module imports from, its size and its complexity, rather than each import and export"),
+        )
+        .arg({
+            let arg = Arg::new("post-hook")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("post-hook")
+                .action(ArgAction::Append)
+                .help("a repeatable path on disk to a generate hook plugin, whose `post_generate` function is called with each checkfile generated as JSON and may return a checkfile to replace it, e.g. to add an organization's defaults. Replaces the profile's `generate_hooks`");
+            match &profile.generate_hooks {
+                Some(hooks) => arg.default_values(hooks.iter().map(|path| path.display().to_string())),
+                None => arg,
+            }
+        });
+    let snapshot = clap::Command::new("snapshot")
+        .about("Write every fact parsed from a module to a JSON file, to validate later builds against with `validate --against` without the module itself.")
+        .arg(
+            Arg::new("path")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("path")
+                .short('p')
+                .required(true)
+                .help("a path on disk to a valid WebAssembly module"),
+        )
+        .arg(
+            Arg::new("output")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("output")
+                .short('o')
+                .help("a path on disk to write the snapshot to, instead of stdout"),
         );
     let validate = clap::Command::new("validate")
         .about("Validate a module using a module checkfile.")
@@ -188,15 +721,106 @@ fn make_subcommands() -> Vec<Command> {
                 .value_parser(clap::value_parser!(PathBuf))
                 .long("path")
                 .short('p')
-                .help("a path on disk to a valid WebAssembly module"),
+                .help("a path on disk to a valid WebAssembly module, or a glob pattern (e.g. 'dist/*.wasm') when used with --remote"),
         )
         .arg(
             Arg::new("check")
                 .value_parser(clap::value_parser!(PathBuf))
                 .long("check")
                 .short('c')
-                .default_value("mod.yaml")
+                .default_value(checkfile.clone())
                 .help("a path on disk to a YAML file which declares validation requirements"),
+        )
+        .arg(
+            Arg::new("set")
+                .value_parser(parse_override)
+                .long("set")
+                .action(ArgAction::Append)
+                .help("a repeatable path=value override of a checkfile field for this run, e.g. `size.max=6MB` or `complexity.max_risk=high`"),
+        )
+        .arg(
+            Arg::new("remote")
+                .long("remote")
+                .action(ArgAction::SetTrue)
+                .help("validate the module(s) on the Modsurfer server, rather than locally"),
+        )
+        .arg(
+            Arg::new("plan")
+                .long("plan")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["path", "remote"])
+                .help("print the checks the checkfile makes (as JSON, unless another output format is set), after fetching any remote checkfile, instead of validating a module"),
+        )
+        .arg(
+            Arg::new("timings")
+                .long("timings")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["remote", "plan"])
+                .help("report how long parsing, reading the checkfile and each category of checks took, and the peak memory used"),
+        )
+        .arg(
+            Arg::new("advise")
+                .long("advise")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["remote", "plan"])
+                .help("when a size check fails, suggest how to shrink the module (e.g. stripping sections or removing unreachable functions) and how much each would save"),
+        )
+        .arg(
+            Arg::new("wit")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("wit")
+                .conflicts_with_all(["remote", "plan", "timings", "advise", "set", "baseline", "write-baseline"])
+                .help("a path on disk to a WIT file, or to a directory of a WIT package with the packages it uses in `deps`, to check the component's imports and exports against one of its worlds instead of a checkfile"),
+        )
+        .arg(
+            Arg::new("against")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("against")
+                .conflicts_with_all(["remote", "plan", "timings", "set", "wit"])
+                .help("a path on disk to a snapshot of an approved build (see `modsurfer snapshot`), to require the same imports, exports and exported function bodies, within its size and complexity, instead of a checkfile"),
+        )
+        .arg(
+            Arg::new("compare-registry")
+                .value_parser(parse_metadata_entry)
+                .long("compare-registry")
+                .action(ArgAction::Append)
+                .conflicts_with_all(["remote", "plan", "wit"])
+                .help("a repeatable key=value metadata entry identifying earlier versions of the module in Modsurfer, e.g. `name=auth`, to also fail on breaking changes since the latest of them: exports removed or changed, and imports added or changed"),
+        )
+        .arg(
+            Arg::new("post-hook")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("post-hook")
+                .action(ArgAction::Append)
+                .conflicts_with_all(["remote", "plan", "wit"])
+                .help("a repeatable path on disk to a report hook plugin, whose `post_report` function is called with the report as JSON and may return a report to replace it, run after the checkfile's `hooks.post_report`, without WASI or network access"),
+        )
+        .arg(
+            Arg::new("world")
+                .long("world")
+                .requires("wit")
+                .help("the world in the WIT package to check against, required if it defines more than one"),
+        )
+        .arg(
+            Arg::new("max-memory")
+                .value_parser(MemoryBudget::parse)
+                .long("max-memory")
+                .conflicts_with_all(["remote", "plan", "timings", "wit", "against"])
+                .help("the most memory to use parsing and validating the module, e.g. `512MB`; strings are not extracted if they would exceed it, and the checks needing them are reported as skipped. The module is held in memory whole, so one too large for the budget fails"),
+        )
+        .arg(
+            Arg::new("baseline")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("baseline")
+                .conflicts_with_all(["remote", "plan"])
+                .help("a path on disk to a baseline of known failures to ignore, so that only new failures are reported"),
+        )
+        .arg(
+            Arg::new("write-baseline")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("write-baseline")
+                .conflicts_with_all(["remote", "plan", "baseline"])
+                .help("write every failure found to a baseline at this path, to be ignored by later runs using --baseline"),
         );
 
     let yank = clap::Command::new("yank")
@@ -215,22 +839,56 @@ fn make_subcommands() -> Vec<Command> {
         ));
 
     let audit = clap::Command::new("audit")
-        .about("Return a list of modules which violate requirements in the provided checkfile.")
+        .about("Return a list of modules which violate requirements in the provided checkfile, or with `--outcome pass`, the modules which comply with it.")
         .arg(
             Arg::new("outcome")
-                .value_parser(clap::value_parser!(AuditOutcome))
+                .value_parser(PossibleValuesParser::new(["pass", "fail"]).map(AuditOutcome::from))
                 .long("outcome")
                 .default_value("fail")
                 .help("which type of expected outcome the audit should verify ('pass' or 'fail')"),
         )
+        .arg(
+            Arg::new("report-dir")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("report-dir")
+                .help("write a report for each failing module into this directory, named by module ID and hash, along with an `index.json` summary"),
+        )
+        .arg(
+            Arg::new("report-format")
+                .value_parser(PossibleValuesParser::new(["json", "sarif"]).map(ReportFormat::from))
+                .long("report-format")
+                .default_value("json")
+                .requires("report-dir")
+                .help("the format of the reports written to `--report-dir`"),
+        )
+        .arg(
+            Arg::new("save-state")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("save-state")
+                .help("a path on disk to save the failing modules to, and to compare them with the previous run saved there; the modules newly failing, newly fixed and unchanged since are shown instead of every report"),
+        )
+        .arg(
+            Arg::new("export")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("export")
+                .conflicts_with("save-state")
+                .help("with `--outcome pass`, a path on disk to write the modules which comply to as CSV (e.g. `compliance.csv`), each with its checkfile, the checkfile's SHA-256 hash and when it was audited"),
+        )
         .arg(
             Arg::new("check")
                 .value_parser(clap::value_parser!(PathBuf))
                 .long("check")
                 .short('c')
-                .default_value("mod.yaml")
+                .default_value(checkfile.clone())
                 .help("a path on disk to a YAML file which declares validation requirements"),
         )
+        .arg(
+            Arg::new("config")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("config")
+                .conflicts_with("check")
+                .help("a path on disk to a YAML audit config, which selects the checkfile for each module by its metadata"),
+        )
         .arg(
             Arg::new("offset")
                 .value_parser(clap::value_parser!(Offset))
@@ -256,16 +914,104 @@ fn make_subcommands() -> Vec<Command> {
                 .action(clap::ArgAction::SetTrue)
                 .help("retain the surrounding unchnaged lines in the diff as context"),
         )
+        .arg(
+            Arg::new("fetch")
+                .long("fetch")
+                .action(clap::ArgAction::SetTrue)
+                .help("fetch modules given by ID from their locations and parse them locally, rather than comparing the facts stored by the server"),
+        )
         .arg(Arg::new("module1").help("first module ID or path to .wasm"))
         .arg(Arg::new("module2").help("second module ID or path to .wasm"));
 
+    let graph = clap::Command::new("graph")
+        .about("Draw the call graph of a module, or with `--namespaces`, the functions several modules import from each namespace.")
+        .arg(
+            Arg::new("namespaces")
+                .long("namespaces")
+                .action(ArgAction::SetTrue)
+                .help("draw each module, with its exports, linked to the functions it imports from each namespace, to see the host API a fleet of modules depends on"),
+        )
+        .arg(
+            Arg::new("format")
+                .value_parser(PossibleValuesParser::new(["dot", "json", "svg"]).map(GraphFormat::from))
+                .long("format")
+                .short('f')
+                .default_value("dot")
+                .help("the format of the graph; the call graph is only available as Dot"),
+        )
+        .arg(
+            Arg::new("output")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("output")
+                .short('o')
+                .help("a path on disk to write the graph to, instead of stdout"),
+        )
+        .arg(
+            Arg::new("module")
+                .num_args(1..)
+                .required(true)
+                .help("module IDs or paths to .wasm"),
+        );
+
+    let completions = clap::Command::new("completions")
+        .about("Print a shell completion script for every command, e.g. `modsurfer completions bash > /etc/bash_completion.d/modsurfer`.")
+        .arg(
+            Arg::new("shell")
+                .value_parser(clap::value_parser!(Shell))
+                .required(true)
+                .help("the shell to generate completions for"),
+        );
+
+    let man = clap::Command::new("man")
+        .about("Print the man page for Modsurfer CLI, or write a man page for every command to a directory.")
+        .arg(
+            Arg::new("dir")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("dir")
+                .short('d')
+                .help("a directory to write `modsurfer.1` and a page per subcommand (e.g. `modsurfer-plugin-install.1`) into"),
+        );
+
     let call_plugin = clap::Command::new("call")
         .about("Call a Modsurfer plugin.")
         .arg(
             Arg::new("identifier")
                 .long("id")
+                .required_unless_present("wasm")
                 .help("the identifier of the registered plugin"),
         )
+        .arg(
+            Arg::new("wasm")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("wasm")
+                .conflicts_with("identifier")
+                .help("a plugin on disk to call on this machine instead, without WASI and within `--plugin-memory-max` and `--timeout`"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_parser(parse_timeout)
+                .requires("wasm")
+                .conflicts_with("identifier")
+                .help("cancel a plugin called with `--wasm` which runs longer than this, e.g. `10s`"),
+        )
+        .arg(
+            Arg::new("manifest")
+                .value_parser(clap::value_parser!(PathBuf))
+                .long("manifest")
+                .requires("wasm")
+                .conflicts_with("identifier")
+                .help("the plugin manifest to check the call against, defaulting to `plugin.yaml` next to the wasm if there is one. Calls by `--id` are checked against the manifest the plugin was installed with"),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_parser(parse_metadata_entry)
+                .action(ArgAction::Append)
+                .requires("wasm")
+                .conflicts_with("identifier")
+                .help("a config key for a plugin called with `--wasm` to read, as KEY=VALUE (repeatable)"),
+        )
         .arg(
             Arg::new("function")
                 .long("function")
@@ -286,14 +1032,39 @@ fn make_subcommands() -> Vec<Command> {
                 .help("a location on disk to write the output.  The output of the call will be written to stdout if not specified"),
         );
 
+    let index_arg = || {
+        let arg = Arg::new("index")
+            .long("index")
+            .help("the location (`https://`, `s3://`, `oci://` or a path) of a JSON index of published plugins, otherwise set by the `MODSURFER_PLUGIN_INDEX` environment variable");
+        match &profile.plugin_index {
+            Some(index) => arg.default_value(index.clone()),
+            None => arg,
+        }
+    };
+
     // TODO: allow specification of plugin "config"
     let install_plugin =
         clap::Command::new("install")
             .about("Install a Modsurfer plugin to a given `identifier`. Any subsequent installs for a given `identifier` will overwrite the plugin at that `identifier` with the data provided on the command.")
+            .arg(
+                Arg::new("plugin")
+                    .conflicts_with_all(["manifest", "wasm"])
+                    .help("a plugin published to the plugin index, as `<name>@<version>`, or `<name>` for its latest version. Its wasm is checked against the hash, and its functions against the manifest, the index gives"),
+            )
+            .arg(index_arg())
+            .arg(
+                Arg::new("manifest")
+                    .value_parser(clap::value_parser!(PathBuf))
+                    .long("manifest")
+                    .short('m')
+                    .conflicts_with_all(["identifier", "wasm"])
+                    .help("a `plugin.yaml` manifest declaring the plugin's identifier, wasm, functions, config keys and permissions. The functions declared are checked against the wasm's exports, and calls to the plugin are checked against the manifest"),
+            )
             .arg(
                 Arg::new("identifier")
                     .long("id")
-                    .help("the identifier of the plugin to be installed"),
+                    .required_unless_present_any(["manifest", "plugin"])
+                    .help("the identifier of the plugin to be installed, defaulting to the name of a plugin from the index"),
             )
             .arg(
                 Arg::new("name")
@@ -302,10 +1073,19 @@ fn make_subcommands() -> Vec<Command> {
                     .short('n')
                     .help("the human readable name of the plugin"),
             )
-            .arg(Arg::new("wasm").long("wasm").short('w').help(
+            .arg(Arg::new("wasm").long("wasm").short('w').required_unless_present_any(["manifest", "plugin"]).help(
                 "a path on disk or a remote URL to the wasm you'd like to install as a plugin",
             ));
 
+    let search_plugins = clap::Command::new("search")
+        .about("Search the plugin index for published plugins whose name or description contains a term.")
+        .arg(
+            Arg::new("term")
+                .required(true)
+                .help("the text to search for, ignoring case"),
+        )
+        .arg(index_arg());
+
     let uninstall_plugin = clap::Command::new("uninstall")
         .about("Uninstall a Modsurfer plugin.")
         .arg(
@@ -314,17 +1094,67 @@ fn make_subcommands() -> Vec<Command> {
                 .help("the identifier of the plugin to uninstall"),
         );
 
+    let profile_list = clap::Command::new("list")
+        .about("List the profiles in the config file, marking the current profile.");
+
+    let profile_use = clap::Command::new("use")
+        .about("Set the current profile, used unless another is set by `--profile` or the `MODSURFER_PROFILE` environment variable.")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .help("the name of a profile in the config file"),
+        );
+
+    let profile_cmd = clap::Command::new("profile")
+        .about("Manage the named profiles of Modsurfer servers, tokens and defaults in the config file (`~/.config/modsurfer/config.yaml`, or set by the `MODSURFER_CONFIG` environment variable).")
+        .subcommand(add_output_arg(profile_list, profile))
+        .subcommand(profile_use);
+
     let plugin = clap::Command::new("plugin")
         .about("Manage and invoke your Modsurfer plugins")
         .subcommand(call_plugin)
         .subcommand(install_plugin)
+        .subcommand(add_output_arg(search_plugins, profile))
         .subcommand(uninstall_plugin);
 
     // This collection of commands should be exclusive to ones whose output can be formatted based on the --output-format arg, either `table` (default) or `json`.
     // If the command does not reliably support this kind of formatting, put the command within the "chained" vec below.
-    [create, delete, get, list, search, validate, yank, audit]
-        .into_iter()
-        .map(add_output_arg)
-        .chain(vec![generate, diff, plugin])
-        .collect()
+    [
+        create,
+        delete,
+        get,
+        tag,
+        quarantine,
+        unquarantine,
+        ping,
+        similar,
+        stats,
+        watch_registry,
+        list,
+        search,
+        validate,
+        yank,
+        audit,
+        explain,
+        compare_checkfiles,
+        test_policy,
+        link_check,
+        capabilities,
+        vuln,
+        bloat,
+    ]
+    .into_iter()
+    .map(|cmd| add_output_arg(cmd, profile))
+    .chain(vec![
+        generate,
+        snapshot,
+        diff,
+        graph,
+        report,
+        profile_cmd,
+        plugin,
+        completions,
+        man,
+    ])
+    .collect()
 }
@@ -1,12 +1,15 @@
 use crate::*;
 
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-use crate::types::Audit;
-
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use anyhow::{anyhow, Result};
 use chrono::offset::TimeZone;
 
-use modsurfer_module::{Export, Function, FunctionType, Import, ValType};
+use std::collections::HashMap;
+
+use modsurfer_module::{
+    CustomSection, Export, Function, FunctionMetrics, FunctionType, Global, Import, IntegrityIssue,
+    IntegrityIssueKind, Library, Memory, Provenance, Quarantine, RiskLevel, Table, Tool, ValType,
+    VERSION_METADATA_KEY,
+};
 
 pub fn source_language(src: api::SourceLanguage) -> SourceLanguage {
     match src {
@@ -23,9 +26,15 @@ pub fn source_language(src: api::SourceLanguage) -> SourceLanguage {
     }
 }
 
-pub fn val_types(v: Vec<protobuf::EnumOrUnknown<api::ValType>>) -> Vec<ValType> {
+/// Convert the value types of a function, failing on a value type unknown to this version of
+/// the API rather than mistaking it for another.
+pub fn val_types(v: Vec<protobuf::EnumOrUnknown<api::ValType>>) -> Result<Vec<ValType>> {
     v.into_iter()
-        .map(|x| val_type(x.enum_value_or_default()))
+        .map(|x| {
+            x.enum_value()
+                .map(val_type)
+                .map_err(|n| anyhow!("unknown value type `{}`", n))
+        })
         .collect()
 }
 
@@ -38,6 +47,27 @@ pub fn val_type(v: api::ValType) -> ValType {
         api::ValType::V128 => ValType::V128,
         api::ValType::FuncRef => ValType::FuncRef,
         api::ValType::ExternRef => ValType::ExternRef,
+        api::ValType::Bool => ValType::Bool,
+        api::ValType::S8 => ValType::S8,
+        api::ValType::U8 => ValType::U8,
+        api::ValType::S16 => ValType::S16,
+        api::ValType::U16 => ValType::U16,
+        api::ValType::S32 => ValType::S32,
+        api::ValType::U32 => ValType::U32,
+        api::ValType::S64 => ValType::S64,
+        api::ValType::U64 => ValType::U64,
+        api::ValType::Float32 => ValType::Float32,
+        api::ValType::Float64 => ValType::Float64,
+        api::ValType::Char => ValType::Char,
+        api::ValType::String => ValType::String,
+    }
+}
+
+pub fn risk_level(risk: api::RiskLevel) -> RiskLevel {
+    match risk {
+        api::RiskLevel::LOW => RiskLevel::Low,
+        api::RiskLevel::MEDIUM => RiskLevel::Medium,
+        api::RiskLevel::HIGH => RiskLevel::High,
     }
 }
 
@@ -56,74 +86,219 @@ pub fn sort(sort: api::Sort) -> Sort {
             api::Field::Sha256 => SortField::Sha256,
             api::Field::Size => SortField::Size,
             api::Field::Complexity => SortField::Complexity,
+            api::Field::RiskScore => SortField::RiskScore,
         },
     }
 }
 
-pub fn import(import: api::Import) -> Import {
+pub fn import(import: api::Import) -> Result<Import> {
     let name = import.func.name.to_string();
     let f = import.func.into_option().unwrap_or_default();
-    Import {
+    Ok(Import {
         module_name: import.module_name,
         func: Function {
             name,
             ty: FunctionType {
-                params: val_types(f.params),
-                results: val_types(f.results),
+                params: val_types(f.params)?,
+                results: val_types(f.results)?,
             },
         },
-    }
+    })
 }
 
-pub fn imports(imports: Vec<api::Import>) -> Vec<Import> {
+pub fn imports(imports: Vec<api::Import>) -> Result<Vec<Import>> {
     imports.into_iter().map(import).collect()
 }
 
-pub fn export(export: api::Export) -> Export {
+pub fn export(export: api::Export) -> Result<Export> {
     let name = export.func.name.to_string();
     let f = export.func.into_option().unwrap_or_default();
-    Export {
+    Ok(Export {
         func: Function {
             name,
             ty: FunctionType {
-                params: val_types(f.params),
-                results: val_types(f.results),
+                params: val_types(f.params)?,
+                results: val_types(f.results)?,
             },
         },
-    }
+    })
 }
 
-pub fn exports(exports: Vec<api::Export>) -> Vec<Export> {
+pub fn exports(exports: Vec<api::Export>) -> Result<Vec<Export>> {
     exports.into_iter().map(export).collect()
 }
 
-pub fn module(module: &modsurfer_proto_v1::api::Module) -> modsurfer_module::Module {
-    let modsurfer_module = &mut modsurfer_module::Module {
-        hash: module.hash.clone(),
-        imports: imports(module.imports.clone()),
-        exports: exports(module.exports.clone()),
+pub fn memories(memories: Vec<api::Memory>) -> Vec<Memory> {
+    memories
+        .into_iter()
+        .map(|m| Memory {
+            initial: m.initial,
+            maximum: m.maximum,
+            shared: m.shared,
+            memory64: m.memory64,
+            imported: m.imported,
+        })
+        .collect()
+}
+
+pub fn tables(tables: Vec<api::Table>) -> Vec<Table> {
+    tables
+        .into_iter()
+        .map(|t| Table {
+            element_type: val_type(t.element_type.enum_value_or_default()),
+            initial: t.initial,
+            maximum: t.maximum,
+            imported: t.imported,
+        })
+        .collect()
+}
+
+pub fn globals(globals: Vec<api::Global>) -> Vec<Global> {
+    globals
+        .into_iter()
+        .map(|g| Global {
+            ty: val_type(g.ty.enum_value_or_default()),
+            mutable: g.mutable,
+            imported: g.imported,
+        })
+        .collect()
+}
+
+pub fn custom_sections(sections: Vec<api::CustomSection>) -> Vec<CustomSection> {
+    sections
+        .into_iter()
+        .map(|s| CustomSection {
+            name: s.name,
+            size: s.size,
+        })
+        .collect()
+}
+
+pub fn function_metrics(
+    metrics: HashMap<String, api::FunctionMetrics>,
+) -> HashMap<String, FunctionMetrics> {
+    metrics
+        .into_iter()
+        .map(|(name, m)| {
+            (
+                name,
+                FunctionMetrics {
+                    complexity: m.complexity,
+                    instructions: m.instructions,
+                    size: m.size,
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn toolchain(toolchain: Vec<api::Tool>) -> Vec<Tool> {
+    toolchain
+        .into_iter()
+        .map(|t| Tool {
+            name: t.name,
+            version: t.version,
+        })
+        .collect()
+}
+
+pub fn provenance(provenance: api::Provenance) -> Provenance {
+    Provenance {
+        licenses: provenance.licenses,
+        libraries: provenance
+            .libraries
+            .into_iter()
+            .map(|l| Library {
+                name: l.name,
+                version: l.version,
+            })
+            .collect(),
+    }
+}
+
+/// Issues of kinds unknown to this version are left out.
+pub fn integrity(issues: Vec<api::IntegrityIssue>) -> Vec<IntegrityIssue> {
+    issues
+        .into_iter()
+        .filter_map(|issue| {
+            Some(IntegrityIssue {
+                kind: IntegrityIssueKind::parse(&issue.kind)?,
+                offset: issue.offset,
+                message: issue.message,
+            })
+        })
+        .collect()
+}
+
+pub fn quarantine(quarantine: api::Quarantine) -> Quarantine {
+    Quarantine {
+        reason: quarantine.reason,
+        quarantined_at: quarantine.quarantined_at.into_option().and_then(|t| {
+            chrono::Utc
+                .timestamp_opt(t.seconds, t.nanos as u32)
+                .single()
+                .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        }),
+    }
+}
+
+/// Convert a module received from the API, taking ownership to avoid copying its (potentially
+/// large) strings, graph and function hashes. Empty metadata is converted to `None`.
+///
+/// Servers which predate the memory, table, global, custom section, function metrics and toolchain
+/// fields do not send them, so they are left empty rather than treated as an error. Provenance is
+/// scanned from the module's strings when the server doesn't send it. Fails if an import or
+/// export uses a value type unknown to this version of the API.
+pub fn module(module: api::Module) -> Result<modsurfer_module::Module> {
+    let inserted_at = module.inserted_at.into_option().unwrap_or_default();
+    // servers which predate the version field may still have it in the module's metadata
+    let version = module
+        .version
+        .or_else(|| module.metadata.get(VERSION_METADATA_KEY).cloned());
+
+    Ok(modsurfer_module::Module {
+        hash: module.hash,
+        imports: imports(module.imports)?,
+        exports: exports(module.exports)?,
         size: module.size,
-        location: module.location.clone(),
+        location: module.location,
+        version,
         source_language: source_language(module.source_language.enum_value_or_default()),
-        metadata: Some(module.metadata.clone()),
-        strings: module.strings.clone(),
+        metadata: if module.metadata.is_empty() {
+            None
+        } else {
+            Some(module.metadata)
+        },
+        provenance: module
+            .provenance
+            .into_option()
+            .map(provenance)
+            .unwrap_or_else(|| Provenance::scan(module.strings.iter().map(String::as_str))),
+        strings: module.strings,
         complexity: module.complexity,
-        graph: module.graph.clone(),
-        function_hashes: module.function_hashes.clone(),
+        graph: module.graph,
+        function_hashes: module.function_hashes,
+        tags: module.tags,
+        memories: memories(module.memories),
+        tables: tables(module.tables),
+        globals: globals(module.globals),
+        custom_sections: custom_sections(module.custom_sections),
+        function_metrics: function_metrics(module.function_metrics),
+        toolchain: toolchain(module.toolchain),
+        quarantine: module.quarantine.into_option().map(quarantine),
+        integrity: integrity(module.integrity),
         #[cfg(not(target_arch = "wasm32"))]
-        inserted_at: chrono::DateTime::from(
-            chrono::Utc.timestamp_nanos(module.inserted_at.nanos as i64),
-        ),
+        inserted_at: chrono::Utc
+            .timestamp_opt(inserted_at.seconds, inserted_at.nanos as u32)
+            .single()
+            .unwrap_or_default(),
         #[cfg(target_arch = "wasm32")]
-        inserted_at: module.inserted_at.nanos as u64,
-    };
-
-    return modsurfer_module.clone();
+        inserted_at: inserted_at.seconds as u64,
+    })
 }
 
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-pub fn search(mut req: api::SearchModulesRequest) -> Search {
-    Search {
+pub fn search(mut req: api::SearchModulesRequest) -> Result<Search> {
+    Ok(Search {
         page: req
             .pagination
             .as_ref()
@@ -134,16 +309,14 @@ pub fn search(mut req: api::SearchModulesRequest) -> Search {
         function_name: req.function_name,
         module_name: req.module_name,
         inserted_after: req.inserted_after.as_ref().and_then(|x| {
-            Some(chrono::DateTime::<chrono::Utc>::from_utc(
-                chrono::NaiveDateTime::from_timestamp_opt(x.seconds, x.nanos as u32)?,
-                chrono::Utc,
-            ))
+            chrono::Utc
+                .timestamp_opt(x.seconds, x.nanos as u32)
+                .single()
         }),
         inserted_before: req.inserted_before.as_ref().and_then(|x| {
-            Some(chrono::DateTime::<chrono::Utc>::from_utc(
-                chrono::NaiveDateTime::from_timestamp_opt(x.seconds, x.nanos as u32)?,
-                chrono::Utc,
-            ))
+            chrono::Utc
+                .timestamp_opt(x.seconds, x.nanos as u32)
+                .single()
         }),
         strings: if req.strings.is_empty() {
             None
@@ -154,12 +327,16 @@ pub fn search(mut req: api::SearchModulesRequest) -> Search {
         source_language: req
             .source_language
             .map(|x| source_language(x.enum_value_or_default())),
-        imports: imports(req.imports),
-        exports: exports(req.exports),
-    }
+        imports: imports(req.imports)?,
+        exports: exports(req.exports)?,
+        tags: req.tags,
+        version: req.version,
+        min_complexity: req.min_complexity,
+        max_complexity: req.max_complexity,
+        namespaces: req.namespaces,
+    })
 }
 
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 pub fn audit(req: api::AuditModulesRequest) -> Audit {
     Audit {
         page: req
@@ -171,3 +348,42 @@ pub fn audit(req: api::AuditModulesRequest) -> Audit {
         checkfile: req.checkfile,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::{Enum, EnumOrUnknown};
+
+    #[test]
+    fn val_types_round_trip() {
+        // `from_api::val_type` and `to_api::val_type` are exhaustive, so this covers every
+        // variant on both sides
+        for ty in api::ValType::VALUES {
+            let converted = val_type(*ty);
+            assert_eq!(
+                to_api::val_type(converted),
+                *ty,
+                "{ty:?} maps to {converted}"
+            );
+        }
+
+        let wire = api::ValType::VALUES
+            .iter()
+            .copied()
+            .map(EnumOrUnknown::new)
+            .collect::<Vec<_>>();
+        let types = val_types(wire.clone()).unwrap();
+        assert_eq!(to_api::val_types(types), wire);
+    }
+
+    #[test]
+    fn unknown_val_type_is_an_error() {
+        let unknown = api::ValType::VALUES.len() as i32;
+        let wire = vec![
+            EnumOrUnknown::new(api::ValType::I64),
+            EnumOrUnknown::from_i32(unknown),
+        ];
+        let err = val_types(wire).unwrap_err();
+        assert_eq!(err.to_string(), format!("unknown value type `{unknown}`"));
+    }
+}
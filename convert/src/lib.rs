@@ -1,9 +1,12 @@
+mod profile;
 mod types;
 
 pub use types::{Order, Pagination, Sort, SortField};
 
 pub use types::{Audit, AuditOutcome, Search};
 
+pub use profile::{profile, to_checkfile, to_minimal_checkfile, ModuleProfile};
+
 pub(crate) use modsurfer_module::SourceLanguage;
 pub use modsurfer_proto_v1::api;
 
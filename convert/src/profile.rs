@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use modsurfer_module::{Export, Import, Module, RiskLevel};
+use protobuf::{EnumOrUnknown, MessageField};
+
+use crate::{api, to_api};
+
+/// A summary of the policy-relevant parts of a module: what it needs from a host, what it
+/// provides, and the resources it is expected to use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleProfile {
+    /// whether the module imports from the WASI namespace
+    pub wasi: bool,
+    pub imports: Vec<Import>,
+    /// the namespaces from which the module imports functions
+    pub import_namespaces: Vec<String>,
+    pub exports: Vec<Export>,
+    /// hashes of the exported functions, keyed by function name
+    pub export_hashes: HashMap<String, String>,
+    /// size in bytes of the module
+    pub size: u64,
+    /// the largest size expected of new versions of the module, padded by 10%
+    /// (ref: https://github.com/dylibso/modsurfer/issues/71)
+    pub size_limit: u64,
+    pub complexity: u32,
+    pub risk: RiskLevel,
+}
+
+/// Summarize a module's imports, exports, size and complexity. Imports are sorted by namespace and
+/// name, and exports by name.
+pub fn profile(module: &Module) -> ModuleProfile {
+    let import_namespaces = module
+        .get_import_namespaces()
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    let export_hashes = module
+        .exports
+        .iter()
+        .filter_map(|exp| {
+            module
+                .function_hashes
+                .get(&exp.func.name)
+                .map(|hash| (exp.func.name.clone(), hash.clone()))
+        })
+        .collect();
+    let complexity = module.complexity.unwrap_or_default();
+
+    let mut imports = module.imports.clone();
+    imports.sort_by(|a, b| (&a.module_name, &a.func.name).cmp(&(&b.module_name, &b.func.name)));
+    let mut exports = module.exports.clone();
+    exports.sort_by(|a, b| a.func.name.cmp(&b.func.name));
+
+    ModuleProfile {
+        wasi: import_namespaces
+            .iter()
+            .any(|ns| ns == "wasi_snapshot_preview1"),
+        imports,
+        import_namespaces,
+        exports,
+        export_hashes,
+        size: module.size,
+        size_limit: (module.size as f64 * 1.1).ceil() as u64,
+        complexity,
+        risk: RiskLevel::from(complexity),
+    }
+}
+
+/// Generate a checkfile which the module passes, requiring its exact imports and exports and
+/// limiting its size and complexity. Convert the result into a `modsurfer_validation::Validation`
+/// to write or evaluate it.
+///
+/// Imports and exports are sorted, so that the checkfiles generated for two builds of a module
+/// differ only where the modules do, and sections the module has nothing for are left out.
+pub fn to_checkfile(module: &Module) -> api::Checkfile {
+    let profile = profile(module);
+
+    let import_items = profile
+        .imports
+        .iter()
+        .map(|imp| api::CheckImportItem {
+            namespace: Some(imp.module_name.clone()),
+            name: imp.func.name.clone(),
+            params: val_types(&imp.func.ty.params),
+            results: val_types(&imp.func.ty.results),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let export_items = profile
+        .exports
+        .iter()
+        .map(|exp| api::CheckFunctionItem {
+            name: exp.func.name.clone(),
+            params: val_types(&exp.func.ty.params),
+            results: val_types(&exp.func.ty.results),
+            hash: profile.export_hashes.get(&exp.func.name).cloned(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let check = api::Check {
+        imports: if import_items.is_empty() {
+            MessageField::none()
+        } else {
+            MessageField::some(api::CheckImports {
+                include: MessageField::some(api::CheckImportItems {
+                    items: import_items,
+                    ..Default::default()
+                }),
+                namespace: namespaces(&profile),
+                ..Default::default()
+            })
+        },
+        exports: MessageField::some(api::CheckExports {
+            max: Some(export_items.len() as u32),
+            include: if export_items.is_empty() {
+                MessageField::none()
+            } else {
+                MessageField::some(api::CheckFunctionItems {
+                    items: export_items,
+                    ..Default::default()
+                })
+            },
+            ..Default::default()
+        }),
+        ..limits(module, &profile)
+    };
+
+    api::Checkfile {
+        validate: MessageField::some(check),
+        ..Default::default()
+    }
+}
+
+/// Generate a checkfile which the module passes, allowing the namespaces it imports from rather
+/// than each of its imports, and limiting its size and complexity. This is much shorter than the
+/// checkfile from `to_checkfile`, and only changes when the module starts importing from a new
+/// namespace or outgrows its limits.
+pub fn to_minimal_checkfile(module: &Module) -> api::Checkfile {
+    let profile = profile(module);
+
+    let namespace = namespaces(&profile);
+    let check = api::Check {
+        imports: if namespace.is_none() {
+            MessageField::none()
+        } else {
+            MessageField::some(api::CheckImports {
+                namespace,
+                ..Default::default()
+            })
+        },
+        ..limits(module, &profile)
+    };
+
+    api::Checkfile {
+        validate: MessageField::some(check),
+        ..Default::default()
+    }
+}
+
+// the namespaces the module imports from, if any
+fn namespaces(profile: &ModuleProfile) -> MessageField<api::CheckNamespaces> {
+    if profile.import_namespaces.is_empty() {
+        return MessageField::none();
+    }
+
+    MessageField::some(api::CheckNamespaces {
+        include: MessageField::some(api::CheckNamespaceItems {
+            items: profile
+                .import_namespaces
+                .iter()
+                .map(|name| api::CheckNamespaceItem {
+                    name: name.clone(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+// the checks shared by full and minimal checkfiles: WASI, size and complexity. Complexity is left
+// out if the parser couldn't determine it, as validation would fail without it.
+fn limits(module: &Module, profile: &ModuleProfile) -> api::Check {
+    api::Check {
+        allow_wasi: if profile.wasi { Some(true) } else { None },
+        // an exact number of bytes, since a human-readable size is rounded and may be smaller
+        size: MessageField::some(api::CheckSize {
+            max: Some(profile.size_limit.to_string()),
+            ..Default::default()
+        }),
+        complexity: if module.complexity.is_some() {
+            MessageField::some(api::CheckComplexity {
+                max_risk: Some(EnumOrUnknown::new(to_api::risk_level(profile.risk))),
+                ..Default::default()
+            })
+        } else {
+            MessageField::none()
+        },
+        ..Default::default()
+    }
+}
+
+fn val_types(types: &[modsurfer_module::ValType]) -> MessageField<api::ValTypes> {
+    MessageField::some(api::ValTypes {
+        types: to_api::val_types(types.to_vec()),
+        ..Default::default()
+    })
+}
@@ -1,6 +1,11 @@
 use crate::*;
 
-use modsurfer_module::{Export, Import, Module, ValType};
+use std::collections::HashMap;
+
+use modsurfer_module::{
+    CustomSection, Export, FunctionMetrics, Global, Import, IntegrityIssue, Memory, Module,
+    Provenance, Quarantine, RiskLevel, Table, Tool, ValType,
+};
 
 pub fn source_language(src: SourceLanguage) -> api::SourceLanguage {
     match src {
@@ -33,6 +38,27 @@ pub fn val_type(v: ValType) -> api::ValType {
         ValType::V128 => api::ValType::V128,
         ValType::FuncRef => api::ValType::FuncRef,
         ValType::ExternRef => api::ValType::ExternRef,
+        ValType::Bool => api::ValType::Bool,
+        ValType::S8 => api::ValType::S8,
+        ValType::U8 => api::ValType::U8,
+        ValType::S16 => api::ValType::S16,
+        ValType::U16 => api::ValType::U16,
+        ValType::S32 => api::ValType::S32,
+        ValType::U32 => api::ValType::U32,
+        ValType::S64 => api::ValType::S64,
+        ValType::U64 => api::ValType::U64,
+        ValType::Float32 => api::ValType::Float32,
+        ValType::Float64 => api::ValType::Float64,
+        ValType::Char => api::ValType::Char,
+        ValType::String => api::ValType::String,
+    }
+}
+
+pub fn risk_level(risk: RiskLevel) -> api::RiskLevel {
+    match risk {
+        RiskLevel::Low => api::RiskLevel::LOW,
+        RiskLevel::Medium => api::RiskLevel::MEDIUM,
+        RiskLevel::High => api::RiskLevel::HIGH,
     }
 }
 
@@ -41,6 +67,7 @@ pub fn module(module: Module, id: i64) -> api::Module {
     let mut dest = api::Module::new();
     dest.id = id;
     dest.location = module.location;
+    dest.version = module.version;
     dest.hash = module.hash;
     dest.metadata = module.metadata.unwrap_or_default();
     dest.size = module.size as u64;
@@ -57,6 +84,16 @@ pub fn module(module: Module, id: i64) -> api::Module {
     dest.strings = module.strings;
     dest.complexity = module.complexity;
     dest.function_hashes = module.function_hashes;
+    dest.tags = module.tags;
+    dest.memories = memories(module.memories);
+    dest.tables = tables(module.tables);
+    dest.globals = globals(module.globals);
+    dest.custom_sections = custom_sections(module.custom_sections);
+    dest.function_metrics = function_metrics(module.function_metrics);
+    dest.toolchain = toolchain(module.toolchain);
+    dest.provenance = protobuf::MessageField::some(provenance(module.provenance));
+    dest.quarantine = module.quarantine.map(quarantine).into();
+    dest.integrity = integrity(module.integrity);
 
     dest
 }
@@ -66,6 +103,7 @@ pub fn module(module: Module, id: i64) -> api::Module {
     let mut dest = api::Module::new();
     dest.id = id;
     dest.location = module.location;
+    dest.version = module.version;
     dest.hash = module.hash;
     dest.metadata = module.metadata.unwrap_or_default();
     dest.size = module.size as u64;
@@ -75,6 +113,16 @@ pub fn module(module: Module, id: i64) -> api::Module {
     dest.strings = module.strings;
     dest.complexity = module.complexity;
     dest.function_hashes = module.function_hashes;
+    dest.tags = module.tags;
+    dest.memories = memories(module.memories);
+    dest.tables = tables(module.tables);
+    dest.globals = globals(module.globals);
+    dest.custom_sections = custom_sections(module.custom_sections);
+    dest.function_metrics = function_metrics(module.function_metrics);
+    dest.toolchain = toolchain(module.toolchain);
+    dest.provenance = protobuf::MessageField::some(provenance(module.provenance));
+    dest.quarantine = module.quarantine.map(quarantine).into();
+    dest.integrity = integrity(module.integrity);
     dest
 }
 
@@ -116,3 +164,127 @@ pub fn imports(imports: Vec<Import>) -> Vec<api::Import> {
         })
         .collect()
 }
+
+pub fn memories(memories: Vec<Memory>) -> Vec<api::Memory> {
+    memories
+        .into_iter()
+        .map(|m| api::Memory {
+            initial: m.initial,
+            maximum: m.maximum,
+            shared: m.shared,
+            memory64: m.memory64,
+            imported: m.imported,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn tables(tables: Vec<Table>) -> Vec<api::Table> {
+    tables
+        .into_iter()
+        .map(|t| api::Table {
+            element_type: protobuf::EnumOrUnknown::new(val_type(t.element_type)),
+            initial: t.initial,
+            maximum: t.maximum,
+            imported: t.imported,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn globals(globals: Vec<Global>) -> Vec<api::Global> {
+    globals
+        .into_iter()
+        .map(|g| api::Global {
+            ty: protobuf::EnumOrUnknown::new(val_type(g.ty)),
+            mutable: g.mutable,
+            imported: g.imported,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn custom_sections(sections: Vec<CustomSection>) -> Vec<api::CustomSection> {
+    sections
+        .into_iter()
+        .map(|s| api::CustomSection {
+            name: s.name,
+            size: s.size,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn function_metrics(
+    metrics: HashMap<String, FunctionMetrics>,
+) -> HashMap<String, api::FunctionMetrics> {
+    metrics
+        .into_iter()
+        .map(|(name, m)| {
+            (
+                name,
+                api::FunctionMetrics {
+                    complexity: m.complexity,
+                    instructions: m.instructions,
+                    size: m.size,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn provenance(provenance: Provenance) -> api::Provenance {
+    api::Provenance {
+        licenses: provenance.licenses,
+        libraries: provenance
+            .libraries
+            .into_iter()
+            .map(|l| api::Library {
+                name: l.name,
+                version: l.version,
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+pub fn integrity(issues: Vec<IntegrityIssue>) -> Vec<api::IntegrityIssue> {
+    issues
+        .into_iter()
+        .map(|issue| api::IntegrityIssue {
+            kind: issue.kind.as_str().to_string(),
+            offset: issue.offset,
+            message: issue.message,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn quarantine(quarantine: Quarantine) -> api::Quarantine {
+    api::Quarantine {
+        reason: quarantine.reason,
+        quarantined_at: quarantine
+            .quarantined_at
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(&t).ok())
+            .map(|t| protobuf::well_known_types::timestamp::Timestamp {
+                seconds: t.timestamp(),
+                nanos: t.timestamp_subsec_nanos() as i32,
+                special_fields: protobuf::SpecialFields::new(),
+            })
+            .into(),
+        ..Default::default()
+    }
+}
+
+pub fn toolchain(toolchain: Vec<Tool>) -> Vec<api::Tool> {
+    toolchain
+        .into_iter()
+        .map(|t| api::Tool {
+            name: t.name,
+            version: t.version,
+            ..Default::default()
+        })
+        .collect()
+}
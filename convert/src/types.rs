@@ -1,4 +1,3 @@
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 use chrono::Utc;
 
 use modsurfer_module::{Export, Import, SourceLanguage};
@@ -43,6 +42,7 @@ pub enum SortField {
     ExportsCount,
     Sha256,
     Complexity,
+    RiskScore,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -61,16 +61,15 @@ pub struct Search {
     pub function_name: Option<String>,
     pub module_name: Option<String>,
     pub source_language: Option<SourceLanguage>,
-    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     pub inserted_after: Option<chrono::DateTime<Utc>>,
-    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
-    pub inserted_after: Option<u64>,
-    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     pub inserted_before: Option<chrono::DateTime<Utc>>,
-    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
-    pub inserted_before: Option<u64>,
     pub strings: Option<Vec<String>>,
     pub sort: Option<Sort>,
+    pub tags: Vec<String>,
+    pub version: Option<String>,
+    pub min_complexity: Option<u32>,
+    pub max_complexity: Option<u32>,
+    pub namespaces: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
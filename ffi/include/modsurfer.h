@@ -0,0 +1,82 @@
+#ifndef MODSURFER_H
+#define MODSURFER_H
+
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The outcome of a call. The codes match the exit codes of the `modsurfer` CLI.
+typedef enum ModsurferStatus {
+  // the call succeeded, and for `modsurfer_validate`, the module passed
+  MODSURFER_STATUS_OK = 0,
+  // the module failed validation; the result holds the report
+  MODSURFER_STATUS_POLICY_FAILURE = 1,
+  // an argument was missing or invalid, e.g. a checkfile which isn't valid YAML
+  MODSURFER_STATUS_INVALID_ARGUMENT = 2,
+  // a module could not be parsed
+  MODSURFER_STATUS_PARSE_ERROR = 3,
+  // any other failure, e.g. fetching a remote checkfile
+  MODSURFER_STATUS_ERROR = 4,
+} ModsurferStatus;
+
+// The result of a call: a status, and a NUL-terminated UTF-8 string which is the call's output
+// (e.g. JSON) when the status is `MODSURFER_STATUS_OK` or `MODSURFER_STATUS_POLICY_FAILURE`, or an
+// error message otherwise.
+typedef struct ModsurferResult {
+  enum ModsurferStatus status;
+  // owned by the library, release it with `modsurfer_result_free`
+  char *data;
+} ModsurferResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parse a module, returning the facts Modsurfer collects about it as JSON (the format written by
+// `modsurfer snapshot`).
+//
+// # Safety
+//
+// `wasm` must point to `wasm_len` readable bytes.
+struct ModsurferResult modsurfer_parse_module(const uint8_t *wasm, size_t wasm_len);
+
+// Validate a module against a checkfile, given as the NUL-terminated YAML contents of the file.
+// Returns the report as JSON (the format of `modsurfer validate --output-format json`), with
+// `MODSURFER_STATUS_POLICY_FAILURE` if the module failed any check.
+//
+// # Safety
+//
+// `wasm` must point to `wasm_len` readable bytes, and `checkfile` to a NUL-terminated string.
+struct ModsurferResult modsurfer_validate(const uint8_t *wasm,
+                                          size_t wasm_len,
+                                          const char *checkfile);
+
+// Compare two modules, returning the differences between them as text (the output of
+// `modsurfer diff`, without colors). With `with_context`, unchanged lines around each difference
+// are included.
+//
+// # Safety
+//
+// `wasm1` and `wasm2` must point to `wasm1_len` and `wasm2_len` readable bytes.
+struct ModsurferResult modsurfer_diff(const uint8_t *wasm1,
+                                      size_t wasm1_len,
+                                      const uint8_t *wasm2,
+                                      size_t wasm2_len,
+                                      bool with_context);
+
+// Release the string held by a result. Each result must be freed exactly once.
+//
+// # Safety
+//
+// `result` must have been returned by this library, and not freed already.
+void modsurfer_result_free(struct ModsurferResult result);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* MODSURFER_H */
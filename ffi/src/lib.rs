@@ -0,0 +1,187 @@
+//! A C ABI for parsing, validating and diffing modules, so that build systems in other languages
+//! (e.g. Go, Python or Node) can embed Modsurfer rather than running the CLI. See
+//! `include/modsurfer.h`, which is generated from this file.
+//!
+//! Every function returns a `ModsurferResult`, holding a status and a string owned by the library,
+//! which must be released with `modsurfer_result_free`.
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use anyhow::{Context, Result};
+use modsurfer_validation::{InvalidModule, Module, Verdict};
+
+/// The outcome of a call. The codes match the exit codes of the `modsurfer` CLI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModsurferStatus {
+    /// the call succeeded, and for `modsurfer_validate`, the module passed
+    Ok = 0,
+    /// the module failed validation; the result holds the report
+    PolicyFailure = 1,
+    /// an argument was missing or invalid, e.g. a checkfile which isn't valid YAML
+    InvalidArgument = 2,
+    /// a module could not be parsed
+    ParseError = 3,
+    /// any other failure, e.g. fetching a remote checkfile
+    Error = 4,
+}
+
+/// The result of a call: a status, and a NUL-terminated UTF-8 string which is the call's output
+/// (e.g. JSON) when the status is `MODSURFER_STATUS_OK` or `MODSURFER_STATUS_POLICY_FAILURE`, or an
+/// error message otherwise.
+#[repr(C)]
+pub struct ModsurferResult {
+    pub status: ModsurferStatus,
+    /// owned by the library, release it with `modsurfer_result_free`
+    pub data: *mut c_char,
+}
+
+/// Parse a module, returning the facts Modsurfer collects about it as JSON (the format written by
+/// `modsurfer snapshot`).
+///
+/// # Safety
+///
+/// `wasm` must point to `wasm_len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn modsurfer_parse_module(
+    wasm: *const u8,
+    wasm_len: usize,
+) -> ModsurferResult {
+    call(|| {
+        let module = Module::parse(bytes(wasm, wasm_len)?)?;
+        Ok((ModsurferStatus::Ok, serde_json::to_string(&module)?))
+    })
+}
+
+/// Validate a module against a checkfile, given as the NUL-terminated YAML contents of the file.
+/// Returns the report as JSON (the format of `modsurfer validate --output-format json`), with
+/// `MODSURFER_STATUS_POLICY_FAILURE` if the module failed any check.
+///
+/// # Safety
+///
+/// `wasm` must point to `wasm_len` readable bytes, and `checkfile` to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn modsurfer_validate(
+    wasm: *const u8,
+    wasm_len: usize,
+    checkfile: *const c_char,
+) -> ModsurferResult {
+    call(|| {
+        let wasm = bytes(wasm, wasm_len)?.to_vec();
+        let checkfile = string(checkfile, "checkfile")?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let report = runtime
+            .block_on(modsurfer_validation::validate_module_bytes(
+                wasm,
+                checkfile.as_bytes(),
+            ))
+            .map_err(|e| match e.downcast::<serde_yaml::Error>() {
+                Ok(e) => anyhow::Error::new(e).context(InvalidArgument),
+                Err(e) => e,
+            })?;
+
+        let status = if Verdict::new(&report).passed() {
+            ModsurferStatus::Ok
+        } else {
+            ModsurferStatus::PolicyFailure
+        };
+        Ok((status, serde_json::to_string(&report)?))
+    })
+}
+
+/// Compare two modules, returning the differences between them as text (the output of
+/// `modsurfer diff`, without colors). With `with_context`, unchanged lines around each difference
+/// are included.
+///
+/// # Safety
+///
+/// `wasm1` and `wasm2` must point to `wasm1_len` and `wasm2_len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn modsurfer_diff(
+    wasm1: *const u8,
+    wasm1_len: usize,
+    wasm2: *const u8,
+    wasm2_len: usize,
+    with_context: bool,
+) -> ModsurferResult {
+    call(|| {
+        let module1 = Module::parse(bytes(wasm1, wasm1_len)?)?;
+        let module2 = Module::parse(bytes(wasm2, wasm2_len)?)?;
+        let diff = modsurfer_validation::Diff::new(&module1, &module2, false, with_context)?;
+        Ok((ModsurferStatus::Ok, diff.to_string()))
+    })
+}
+
+/// Release the string held by a result. Each result must be freed exactly once.
+///
+/// # Safety
+///
+/// `result` must have been returned by this library, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn modsurfer_result_free(result: ModsurferResult) {
+    if !result.data.is_null() {
+        drop(CString::from_raw(result.data));
+    }
+}
+
+/// The context of an error caused by an argument rather than a failure to parse or validate.
+#[derive(Debug)]
+struct InvalidArgument;
+
+impl std::fmt::Display for InvalidArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid argument")
+    }
+}
+
+// run `f`, converting its output or error into a result, without unwinding into the caller
+fn call(f: impl FnOnce() -> Result<(ModsurferStatus, String)>) -> ModsurferResult {
+    let (status, data) = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => (status(&e), format!("{:#}", e)),
+        Err(_) => (ModsurferStatus::Error, "modsurfer panicked".to_string()),
+    };
+
+    // the output can't contain a NUL byte unless a module's strings do, so drop any
+    let data = CString::new(data).unwrap_or_else(|e| {
+        let mut data = e.into_vec();
+        data.retain(|b| *b != 0);
+        CString::new(data).unwrap_or_default()
+    });
+
+    ModsurferResult {
+        status,
+        data: data.into_raw(),
+    }
+}
+
+fn status(e: &anyhow::Error) -> ModsurferStatus {
+    if e.is::<InvalidArgument>() {
+        ModsurferStatus::InvalidArgument
+    } else if e.is::<InvalidModule>() {
+        ModsurferStatus::ParseError
+    } else {
+        ModsurferStatus::Error
+    }
+}
+
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> Result<&'a [u8]> {
+    if data.is_null() {
+        return Err(anyhow::anyhow!("module is null")).context(InvalidArgument);
+    }
+
+    Ok(std::slice::from_raw_parts(data, len))
+}
+
+unsafe fn string<'a>(s: *const c_char, name: &str) -> Result<&'a str> {
+    if s.is_null() {
+        return Err(anyhow::anyhow!("{} is null", name)).context(InvalidArgument);
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .with_context(|| format!("{} is not valid UTF-8", name))
+        .context(InvalidArgument)
+}
@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use wasmparser::{
+    ConstExpr, ElementItems, Encoding, ExternalKind, Name, NameSectionReader, Operator, Parser,
+    Payload, TypeRef,
+};
+
+/// Where the bytes of a core WebAssembly module go: the size of each function body, data segment
+/// and custom section, and which functions can never be called.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize)]
+pub struct Bloat {
+    /// size in bytes of the module
+    pub size: u64,
+    /// every function, data segment and custom section, largest first
+    pub items: Vec<BloatItem>,
+    /// size in bytes of the unreachable functions
+    pub unreachable_size: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BloatKind {
+    Function,
+    Data,
+    CustomSection,
+}
+
+impl std::fmt::Display for BloatKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BloatKind::Function => "function",
+            BloatKind::Data => "data",
+            BloatKind::CustomSection => "custom section",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct BloatItem {
+    pub kind: BloatKind,
+    /// the function's export or debug name (or `func[<index>]`), the data segment's name (or
+    /// `data[<index>]`), or the custom section's name
+    pub name: String,
+    /// size in bytes of the function body, segment data or section data
+    pub size: u64,
+    /// whether a function can be called from an export, the start function or a table. Always
+    /// `true` for data and custom sections.
+    pub reachable: bool,
+}
+
+impl Bloat {
+    /// Attribute the size of a module to its contents. Functions are reachable if they are
+    /// exported, are the start function, are stored in a table or referenced by `ref.func`, or
+    /// are called by a reachable function.
+    pub fn new(wasm: &[u8]) -> Result<Bloat> {
+        let mut imported_funcs = 0;
+        let mut roots = vec![];
+        let mut export_names = HashMap::new();
+        let mut debug_names = HashMap::new();
+        let mut data_names = HashMap::new();
+        let mut bodies = vec![];
+        let mut data = vec![];
+        let mut custom = vec![];
+
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload? {
+                Payload::Version {
+                    encoding: Encoding::Component,
+                    ..
+                } => bail!("components are not supported yet"),
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        if let TypeRef::Func(_) = import?.ty {
+                            imported_funcs += 1;
+                        }
+                    }
+                }
+                Payload::GlobalSection(reader) => {
+                    for global in reader {
+                        roots.extend(ref_funcs(&global?.init_expr)?);
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export?;
+                        if export.kind == ExternalKind::Func {
+                            roots.push(export.index);
+                            export_names.insert(export.index, export.name.to_string());
+                        }
+                    }
+                }
+                Payload::StartSection { func, .. } => roots.push(func),
+                Payload::ElementSection(reader) => {
+                    for element in reader {
+                        match element?.items {
+                            ElementItems::Functions(funcs) => {
+                                for func in funcs {
+                                    roots.push(func?);
+                                }
+                            }
+                            ElementItems::Expressions(exprs) => {
+                                for expr in exprs {
+                                    roots.extend(ref_funcs(&expr?)?);
+                                }
+                            }
+                        }
+                    }
+                }
+                Payload::DataSection(reader) => {
+                    for segment in reader {
+                        data.push(segment?.data.len() as u64);
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    let mut calls = vec![];
+                    for op in body.get_operators_reader()? {
+                        match op? {
+                            Operator::Call { function_index }
+                            | Operator::ReturnCall { function_index }
+                            | Operator::RefFunc { function_index } => calls.push(function_index),
+                            _ => {}
+                        }
+                    }
+                    bodies.push((body.range().len() as u64, calls));
+                }
+                Payload::CustomSection(section) => {
+                    if section.name() == "name" {
+                        for name in NameSectionReader::new(section.data(), section.data_offset()) {
+                            // a malformed name section only costs us names
+                            match name {
+                                Ok(Name::Function(map)) => {
+                                    for naming in map.into_iter().flatten() {
+                                        debug_names.insert(naming.index, naming.name.to_string());
+                                    }
+                                }
+                                Ok(Name::Data(map)) => {
+                                    for naming in map.into_iter().flatten() {
+                                        data_names.insert(naming.index, naming.name.to_string());
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    custom.push((section.name().to_string(), section.data().len() as u64));
+                }
+                _ => {}
+            }
+        }
+
+        // walk the call graph from the roots, over defined functions only
+        let mut reachable = HashSet::new();
+        while let Some(func) = roots.pop() {
+            if func < imported_funcs || !reachable.insert(func) {
+                continue;
+            }
+            if let Some((_, calls)) = bodies.get((func - imported_funcs) as usize) {
+                roots.extend(calls.iter().copied());
+            }
+        }
+
+        let mut items = vec![];
+        let mut unreachable_size = 0;
+        for (i, (size, _)) in bodies.into_iter().enumerate() {
+            let index = imported_funcs + i as u32;
+            let reachable = reachable.contains(&index);
+            if !reachable {
+                unreachable_size += size;
+            }
+            items.push(BloatItem {
+                kind: BloatKind::Function,
+                name: export_names
+                    .remove(&index)
+                    .or_else(|| debug_names.remove(&index))
+                    .unwrap_or_else(|| format!("func[{}]", index)),
+                size,
+                reachable,
+            });
+        }
+        items.extend(data.into_iter().enumerate().map(|(i, size)| {
+            BloatItem {
+                kind: BloatKind::Data,
+                name: data_names
+                    .remove(&(i as u32))
+                    .unwrap_or_else(|| format!("data[{}]", i)),
+                size,
+                reachable: true,
+            }
+        }));
+        items.extend(custom.into_iter().map(|(name, size)| BloatItem {
+            kind: BloatKind::CustomSection,
+            name,
+            size,
+            reachable: true,
+        }));
+        items.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(Bloat {
+            size: wasm.len() as u64,
+            items,
+            unreachable_size,
+        })
+    }
+
+    /// The functions which can never be called, largest first.
+    pub fn unreachable(&self) -> impl Iterator<Item = &BloatItem> {
+        self.items.iter().filter(|item| !item.reachable)
+    }
+}
+
+// the functions referenced by `ref.func` in a constant expression
+fn ref_funcs(expr: &ConstExpr) -> Result<Vec<u32>> {
+    let mut funcs = vec![];
+    for op in expr.get_operators_reader() {
+        if let Operator::RefFunc { function_index } = op? {
+            funcs.push(function_index);
+        }
+    }
+    Ok(funcs)
+}
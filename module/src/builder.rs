@@ -0,0 +1,144 @@
+use crate::{
+    CustomSection, Export, FunctionMetrics, Global, Import, IntegrityIssue, Memory, Module,
+    Provenance, SourceLanguage, Table, Tool,
+};
+
+/// Construct a `Module` programmatically, e.g. in tests or from an alternative parser. Any value
+/// which is not set keeps its default from `Module::default()`.
+#[derive(Debug, Default, Clone)]
+pub struct ModuleBuilder {
+    module: Module,
+}
+
+impl Module {
+    pub fn builder() -> ModuleBuilder {
+        ModuleBuilder::default()
+    }
+}
+
+impl ModuleBuilder {
+    pub fn hash(mut self, hash: impl Into<String>) -> Self {
+        self.module.hash = hash.into();
+        self
+    }
+
+    pub fn import(mut self, import: Import) -> Self {
+        self.module.imports.push(import);
+        self
+    }
+
+    pub fn export(mut self, export: Export) -> Self {
+        self.module.exports.push(export);
+        self
+    }
+
+    pub fn size(mut self, size: u64) -> Self {
+        self.module.size = size;
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.module.location = location.into();
+        self
+    }
+
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.module.version = Some(version.into());
+        self
+    }
+
+    pub fn source_language(mut self, source_language: SourceLanguage) -> Self {
+        self.module.source_language = source_language;
+        self
+    }
+
+    /// Add a metadata entry, replacing any existing value for the key.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.module
+            .metadata
+            .get_or_insert_with(Default::default)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn inserted_at(mut self, inserted_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.module.inserted_at = inserted_at;
+        self
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn inserted_at(mut self, inserted_at: u64) -> Self {
+        self.module.inserted_at = inserted_at;
+        self
+    }
+
+    pub fn string(mut self, string: impl Into<String>) -> Self {
+        self.module.strings.push(string.into());
+        self
+    }
+
+    pub fn complexity(mut self, complexity: u32) -> Self {
+        self.module.complexity = Some(complexity);
+        self
+    }
+
+    pub fn graph(mut self, graph: Vec<u8>) -> Self {
+        self.module.graph = Some(graph);
+        self
+    }
+
+    pub fn function_hash(mut self, name: impl Into<String>, hash: impl Into<String>) -> Self {
+        self.module.function_hashes.insert(name.into(), hash.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.module.tags.push(tag.into());
+        self
+    }
+
+    pub fn memory(mut self, memory: Memory) -> Self {
+        self.module.memories.push(memory);
+        self
+    }
+
+    pub fn table(mut self, table: Table) -> Self {
+        self.module.tables.push(table);
+        self
+    }
+
+    pub fn global(mut self, global: Global) -> Self {
+        self.module.globals.push(global);
+        self
+    }
+
+    pub fn custom_section(mut self, section: CustomSection) -> Self {
+        self.module.custom_sections.push(section);
+        self
+    }
+
+    pub fn function_metrics(mut self, name: impl Into<String>, metrics: FunctionMetrics) -> Self {
+        self.module.function_metrics.insert(name.into(), metrics);
+        self
+    }
+
+    pub fn tool(mut self, tool: Tool) -> Self {
+        self.module.toolchain.push(tool);
+        self
+    }
+
+    pub fn provenance(mut self, provenance: Provenance) -> Self {
+        self.module.provenance = provenance;
+        self
+    }
+
+    pub fn integrity_issue(mut self, issue: IntegrityIssue) -> Self {
+        self.module.integrity.push(issue);
+        self
+    }
+
+    pub fn build(self) -> Module {
+        self.module
+    }
+}
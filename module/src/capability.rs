@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Module;
+
+/// What a module can do on its host, granted by the functions it imports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    FilesystemRead,
+    /// writing files, which includes writing to stdout and stderr
+    FilesystemWrite,
+    Network,
+    Clock,
+    Randomness,
+    /// reading environment variables and command-line arguments
+    Environment,
+    ProcessExit,
+}
+
+pub const CAPABILITIES: &[Capability] = &[
+    Capability::FilesystemRead,
+    Capability::FilesystemWrite,
+    Capability::Network,
+    Capability::Clock,
+    Capability::Randomness,
+    Capability::Environment,
+    Capability::ProcessExit,
+];
+
+const WASI_PREVIEW1: &str = "wasi_snapshot_preview1";
+
+// WASI preview 1 filesystem functions which modify files, rather than read them
+const FILESYSTEM_WRITES: &[&str] = &[
+    "fd_write",
+    "fd_pwrite",
+    "fd_allocate",
+    "fd_datasync",
+    "fd_sync",
+    "fd_filestat_set_size",
+    "fd_filestat_set_times",
+    "fd_fdstat_set_flags",
+    "path_create_directory",
+    "path_remove_directory",
+    "path_unlink_file",
+    "path_rename",
+    "path_link",
+    "path_symlink",
+    "path_filestat_set_times",
+];
+
+// words in the names of WASI preview 2 filesystem functions which modify files. Functions which
+// read, e.g. `read-link-at`, are checked for first.
+const FILESYSTEM_WRITE_WORDS: &[&str] = &[
+    "write", "create", "remove", "unlink", "rename", "link-at", "set-", "sync", "append",
+];
+
+impl Capability {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Capability::FilesystemRead => "filesystem_read",
+            Capability::FilesystemWrite => "filesystem_write",
+            Capability::Network => "network",
+            Capability::Clock => "clock",
+            Capability::Randomness => "randomness",
+            Capability::Environment => "environment",
+            Capability::ProcessExit => "process_exit",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Capability> {
+        CAPABILITIES.iter().copied().find(|c| c.as_str() == s)
+    }
+
+    /// The capability granted by importing `name` from `namespace`, for WASI preview 1 functions
+    /// and WASI preview 2 interfaces.
+    pub fn of_import(namespace: &str, name: &str) -> Option<Capability> {
+        if namespace == WASI_PREVIEW1 {
+            return match name {
+                n if FILESYSTEM_WRITES.contains(&n) => Some(Capability::FilesystemWrite),
+                n if n.starts_with("fd_") || n.starts_with("path_") => {
+                    Some(Capability::FilesystemRead)
+                }
+                n if n.starts_with("sock_") => Some(Capability::Network),
+                "clock_time_get" | "clock_res_get" | "poll_oneoff" => Some(Capability::Clock),
+                "random_get" => Some(Capability::Randomness),
+                "environ_get" | "environ_sizes_get" | "args_get" | "args_sizes_get" => {
+                    Some(Capability::Environment)
+                }
+                "proc_exit" | "proc_raise" => Some(Capability::ProcessExit),
+                _ => None,
+            };
+        }
+
+        // preview 2 interfaces are versioned, e.g. `wasi:clocks/monotonic-clock@0.2.0`
+        let interface = namespace.split('@').next().unwrap_or(namespace);
+        match interface.split_once('/') {
+            Some(("wasi:filesystem", _)) => {
+                if !name.contains("read") && FILESYSTEM_WRITE_WORDS.iter().any(|w| name.contains(w))
+                {
+                    Some(Capability::FilesystemWrite)
+                } else {
+                    Some(Capability::FilesystemRead)
+                }
+            }
+            Some(("wasi:sockets" | "wasi:http", _)) => Some(Capability::Network),
+            Some(("wasi:clocks", _)) => Some(Capability::Clock),
+            Some(("wasi:random", _)) => Some(Capability::Randomness),
+            Some(("wasi:cli", "environment")) => Some(Capability::Environment),
+            Some(("wasi:cli", "exit")) => Some(Capability::ProcessExit),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Module {
+    /// The capabilities the module's imports grant it, with the imports (as `namespace::name`)
+    /// which grant each.
+    pub fn capabilities(&self) -> BTreeMap<Capability, Vec<String>> {
+        self.imports
+            .iter()
+            .fold(BTreeMap::new(), |mut acc, import| {
+                if let Some(capability) =
+                    Capability::of_import(&import.module_name, &import.func.name)
+                {
+                    acc.entry(capability)
+                        .or_insert_with(Vec::new)
+                        .push(format!("{}::{}", import.module_name, import.func.name));
+                }
+                acc
+            })
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use wasmparser::{
+    Encoding, ExternalKind, GlobalType, MemoryType, Name, NameSectionReader, Operator, Parser,
+    Payload, ProducersSectionReader, TableType, TypeRef,
+};
+
+use crate::{Module, ValType};
+
+/// A linear memory defined or imported by the module.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Memory {
+    /// initial size of the memory, in 64KiB pages
+    pub initial: u64,
+    /// maximum size of the memory, in 64KiB pages, if limited
+    pub maximum: Option<u64>,
+    /// whether the memory can be shared between threads
+    pub shared: bool,
+    /// whether the memory is indexed with 64-bit addresses
+    pub memory64: bool,
+    /// whether the memory is imported rather than defined by the module
+    pub imported: bool,
+}
+
+/// A table of references defined or imported by the module.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Table {
+    /// the type of the references stored in the table, `FuncRef` or `ExternRef`
+    pub element_type: ValType,
+    /// initial number of elements in the table
+    pub initial: u64,
+    /// maximum number of elements in the table, if limited
+    pub maximum: Option<u64>,
+    /// whether the table is imported rather than defined by the module
+    pub imported: bool,
+}
+
+/// A global variable defined or imported by the module.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Global {
+    pub ty: ValType,
+    pub mutable: bool,
+    /// whether the global is imported rather than defined by the module
+    pub imported: bool,
+}
+
+/// A custom section contained in the module, e.g. `name` or `producers`.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CustomSection {
+    pub name: String,
+    /// size in bytes of the section's data
+    pub size: u64,
+}
+
+/// Measurements of a single function defined by the module.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FunctionMetrics {
+    /// cyclomatic complexity of the function
+    pub complexity: u32,
+    /// number of instructions in the function body
+    pub instructions: u64,
+    /// size in bytes of the function body
+    pub size: u64,
+}
+
+/// A tool which produced or processed the module, read from the `producers` section.
+/// See more: <https://github.com/WebAssembly/tool-conventions/blob/main/ProducersSection.md>
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Tool {
+    /// name of the tool, e.g. `rustc` or `wasm-opt`
+    pub name: String,
+    pub version: String,
+}
+
+/// The memories, tables, globals, custom sections, function metrics and toolchain of a core
+/// WebAssembly module, read directly from its binary for parsers which don't provide them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ModuleFacts {
+    pub memories: Vec<Memory>,
+    pub tables: Vec<Table>,
+    pub globals: Vec<Global>,
+    pub custom_sections: Vec<CustomSection>,
+    pub function_metrics: HashMap<String, FunctionMetrics>,
+    pub toolchain: Vec<Tool>,
+}
+
+impl ModuleFacts {
+    /// Read the facts of a module. Components are not supported yet, and have no facts.
+    pub fn read(wasm: &[u8]) -> Result<ModuleFacts> {
+        let mut facts = ModuleFacts::default();
+        let mut imported_funcs = 0;
+        let mut export_names = HashMap::new();
+        let mut debug_names = HashMap::new();
+        let mut metrics = vec![];
+
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload? {
+                Payload::Version {
+                    encoding: Encoding::Component,
+                    ..
+                } => return Ok(ModuleFacts::default()),
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        match import?.ty {
+                            TypeRef::Func(_) => imported_funcs += 1,
+                            TypeRef::Memory(ty) => facts.memories.push(memory(ty, true)),
+                            TypeRef::Table(ty) => facts.tables.push(table(ty, true)),
+                            TypeRef::Global(ty) => facts.globals.push(global(ty, true)),
+                            TypeRef::Tag(_) => {}
+                        }
+                    }
+                }
+                Payload::MemorySection(reader) => {
+                    for ty in reader {
+                        facts.memories.push(memory(ty?, false));
+                    }
+                }
+                Payload::TableSection(reader) => {
+                    for entry in reader {
+                        facts.tables.push(table(entry?.ty, false));
+                    }
+                }
+                Payload::GlobalSection(reader) => {
+                    for entry in reader {
+                        facts.globals.push(global(entry?.ty, false));
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export?;
+                        if export.kind == ExternalKind::Func {
+                            export_names.insert(export.index, export.name.to_string());
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    let mut function = FunctionMetrics {
+                        complexity: 1,
+                        instructions: 0,
+                        size: body.range().len() as u64,
+                    };
+                    for op in body.get_operators_reader()? {
+                        function.instructions += 1;
+                        // each branch adds a path through the function
+                        match op? {
+                            Operator::If { .. } | Operator::BrIf { .. } => function.complexity += 1,
+                            Operator::BrTable { targets } => function.complexity += targets.len(),
+                            _ => {}
+                        }
+                    }
+                    metrics.push(function);
+                }
+                Payload::CustomSection(section) => {
+                    match section.name() {
+                        "name" => {
+                            for name in
+                                NameSectionReader::new(section.data(), section.data_offset())
+                            {
+                                // a malformed name section only costs us names
+                                if let Ok(Name::Function(map)) = name {
+                                    for naming in map.into_iter().flatten() {
+                                        debug_names.insert(naming.index, naming.name.to_string());
+                                    }
+                                }
+                            }
+                        }
+                        // a malformed producers section only costs us the toolchain
+                        "producers" => {
+                            if let Ok(toolchain) =
+                                read_toolchain(section.data(), section.data_offset())
+                            {
+                                facts.toolchain = toolchain;
+                            }
+                        }
+                        _ => {}
+                    }
+                    facts.custom_sections.push(CustomSection {
+                        name: section.name().to_string(),
+                        size: section.data().len() as u64,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        for (i, function) in metrics.into_iter().enumerate() {
+            let index = imported_funcs + i as u32;
+            let name = export_names
+                .remove(&index)
+                .or_else(|| debug_names.remove(&index))
+                .unwrap_or_else(|| format!("func[{}]", index));
+            // names aren't unique: functions may share a debug name, or one may be exported under
+            // another's debug name, so a name already taken is suffixed with the function's index
+            let name = if facts.function_metrics.contains_key(&name) {
+                format!("{}[{}]", name, index)
+            } else {
+                name
+            };
+            facts.function_metrics.insert(name, function);
+        }
+
+        Ok(facts)
+    }
+
+    /// Fill in each of these facts which `module` is missing.
+    pub fn fill(self, module: &mut Module) {
+        if module.memories.is_empty() {
+            module.memories = self.memories;
+        }
+        if module.tables.is_empty() {
+            module.tables = self.tables;
+        }
+        if module.globals.is_empty() {
+            module.globals = self.globals;
+        }
+        if module.custom_sections.is_empty() {
+            module.custom_sections = self.custom_sections;
+        }
+        if module.function_metrics.is_empty() {
+            module.function_metrics = self.function_metrics;
+        }
+        if module.toolchain.is_empty() {
+            module.toolchain = self.toolchain;
+        }
+    }
+}
+
+fn memory(ty: MemoryType, imported: bool) -> Memory {
+    Memory {
+        initial: ty.initial,
+        maximum: ty.maximum,
+        shared: ty.shared,
+        memory64: ty.memory64,
+        imported,
+    }
+}
+
+fn table(ty: TableType, imported: bool) -> Table {
+    Table {
+        element_type: ValType::from(wasmparser::ValType::Ref(ty.element_type)),
+        initial: ty.initial as u64,
+        maximum: ty.maximum.map(u64::from),
+        imported,
+    }
+}
+
+fn global(ty: GlobalType, imported: bool) -> Global {
+    Global {
+        ty: ty.content_type.into(),
+        mutable: ty.mutable,
+        imported,
+    }
+}
+
+// the tools listed under `processed-by` in a producers section
+fn read_toolchain(data: &[u8], offset: usize) -> Result<Vec<Tool>> {
+    let mut toolchain = vec![];
+    for field in ProducersSectionReader::new(data, offset)? {
+        let field = field?;
+        if field.name != "processed-by" {
+            continue;
+        }
+        for value in field.values {
+            let value = value?;
+            toolchain.push(Tool {
+                name: value.name.to_string(),
+                version: value.version.to_string(),
+            });
+        }
+    }
+
+    Ok(toolchain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_names_are_unique() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (func $a (export "run"))
+                (func (@name "run") (drop (i32.const 1)))
+                (func (@name "run") (if (i32.const 1) (then nop)))
+                (func)
+            )"#,
+        )
+        .unwrap();
+
+        let metrics = ModuleFacts::read(&wasm).unwrap().function_metrics;
+        let mut names = metrics.keys().map(String::as_str).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["func[3]", "run", "run[1]", "run[2]"]);
+        assert_eq!(metrics["run[2]"].complexity, 2);
+    }
+}
@@ -12,6 +12,7 @@ pub struct FunctionType {
 
 /// Represents the types of values in a WebAssembly module.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ValType {
     /// The value type is i32.
     I32,
@@ -27,6 +28,32 @@ pub enum ValType {
     FuncRef,
     /// The value type is an extern reference.
     ExternRef,
+    /// The value type is a component-model `bool`.
+    Bool,
+    /// The value type is a component-model `s8`.
+    S8,
+    /// The value type is a component-model `u8`.
+    U8,
+    /// The value type is a component-model `s16`.
+    S16,
+    /// The value type is a component-model `u16`.
+    U16,
+    /// The value type is a component-model `s32`.
+    S32,
+    /// The value type is a component-model `u32`.
+    U32,
+    /// The value type is a component-model `s64`.
+    S64,
+    /// The value type is a component-model `u64`.
+    U64,
+    /// The value type is a component-model `float32`.
+    Float32,
+    /// The value type is a component-model `float64`.
+    Float64,
+    /// The value type is a component-model `char`.
+    Char,
+    /// The value type is a component-model `string`.
+    String,
 }
 
 impl ValType {
@@ -37,6 +64,71 @@ impl ValType {
     pub fn is_reference_type(&self) -> bool {
         matches!(self, ValType::FuncRef | ValType::ExternRef)
     }
+
+    /// Returns whether this value type is only found in component-model signatures, and has no
+    /// core WebAssembly equivalent.
+    pub fn is_component_type(&self) -> bool {
+        !matches!(
+            self,
+            ValType::I32
+                | ValType::I64
+                | ValType::F32
+                | ValType::F64
+                | ValType::V128
+                | ValType::FuncRef
+                | ValType::ExternRef
+        )
+    }
+}
+
+impl std::fmt::Display for ValType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ValType::I32 => "i32",
+            ValType::I64 => "i64",
+            ValType::F32 => "f32",
+            ValType::F64 => "f64",
+            ValType::V128 => "v128",
+            ValType::FuncRef => "funcref",
+            ValType::ExternRef => "externref",
+            ValType::Bool => "bool",
+            ValType::S8 => "s8",
+            ValType::U8 => "u8",
+            ValType::S16 => "s16",
+            ValType::U16 => "u16",
+            ValType::S32 => "s32",
+            ValType::U32 => "u32",
+            ValType::S64 => "s64",
+            ValType::U64 => "u64",
+            ValType::Float32 => "float32",
+            ValType::Float64 => "float64",
+            ValType::Char => "char",
+            ValType::String => "string",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Render a list of value types as a parenthesized, comma-separated list, e.g. `(i32, i64)`.
+pub fn display_val_types(types: &[ValType]) -> String {
+    let types = types
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("({types})")
+}
+
+/// Renders as `(i32, i32) -> i64`. A single result is written bare, while no results or multiple
+/// results are written as a list, e.g. `(i32) -> ()` or `() -> (i32, i64)`.
+impl std::fmt::Display for FunctionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> ", display_val_types(&self.params))?;
+        match self.results.as_slice() {
+            [result] => write!(f, "{result}"),
+            results => f.write_str(&display_val_types(results)),
+        }
+    }
 }
 
 impl From<wasmparser::ValType> for ValType {
@@ -49,23 +141,28 @@ impl From<wasmparser::ValType> for ValType {
             V::F64 => ValType::F64,
             V::V128 => ValType::V128,
             V::Ref(wasmparser::RefType::FUNCREF) => ValType::FuncRef,
-            V::Ref(wasmparser::RefType::EXTERNREF) => ValType::FuncRef,
+            V::Ref(wasmparser::RefType::EXTERNREF) => ValType::ExternRef,
             V::Ref(r) => panic!("Unknown ref type: {:?}", r),
         }
     }
 }
 
-impl From<ValType> for wasmparser::ValType {
-    fn from(v: ValType) -> Self {
+/// Fails for component-model types, which have no core WebAssembly equivalent (see
+/// `ValType::is_component_type`).
+impl TryFrom<ValType> for wasmparser::ValType {
+    type Error = anyhow::Error;
+
+    fn try_from(v: ValType) -> Result<Self, Self::Error> {
         use wasmparser::ValType as V;
         match v {
-            ValType::I32 => V::I32,
-            ValType::I64 => V::I64,
-            ValType::F32 => V::F32,
-            ValType::F64 => V::F64,
-            ValType::V128 => V::V128,
-            ValType::FuncRef => V::FUNCREF,
-            ValType::ExternRef => V::EXTERNREF,
+            ValType::I32 => Ok(V::I32),
+            ValType::I64 => Ok(V::I64),
+            ValType::F32 => Ok(V::F32),
+            ValType::F64 => Ok(V::F64),
+            ValType::V128 => Ok(V::V128),
+            ValType::FuncRef => Ok(V::FUNCREF),
+            ValType::ExternRef => Ok(V::EXTERNREF),
+            other => anyhow::bail!("{:?} is not a core WebAssembly value type", other),
         }
     }
 }
@@ -78,3 +175,52 @@ impl<'a> From<&'a wasmparser::FuncType> for FunctionType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORE: &[ValType] = &[
+        ValType::I32,
+        ValType::I64,
+        ValType::F32,
+        ValType::F64,
+        ValType::V128,
+        ValType::FuncRef,
+        ValType::ExternRef,
+    ];
+
+    const COMPONENT: &[ValType] = &[
+        ValType::Bool,
+        ValType::S8,
+        ValType::U8,
+        ValType::S16,
+        ValType::U16,
+        ValType::S32,
+        ValType::U32,
+        ValType::S64,
+        ValType::U64,
+        ValType::Float32,
+        ValType::Float64,
+        ValType::Char,
+        ValType::String,
+    ];
+
+    #[test]
+    fn core_types_round_trip() {
+        for ty in CORE {
+            let converted = wasmparser::ValType::try_from(*ty).unwrap();
+            assert_eq!(ValType::from(converted), *ty);
+            assert!(!ty.is_component_type(), "{ty} is a core type");
+        }
+    }
+
+    #[test]
+    fn component_types_do_not_convert() {
+        for ty in COMPONENT {
+            assert!(wasmparser::ValType::try_from(*ty).is_err());
+            assert!(ty.is_component_type(), "{ty} is a component type");
+            assert!(!ty.is_reference_type());
+        }
+    }
+}
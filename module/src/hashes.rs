@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use wasmparser::{Encoding, ExternalKind, Name, NameSectionReader, Parser, Payload, TypeRef};
+
+/// Which functions to hash when computing a module's `function_hashes`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionHashes {
+    /// don't compute any function hashes
+    None,
+    /// hash only the exported functions
+    Exports,
+    /// hash every defined function which has a name, from its export or the "name" section
+    #[default]
+    All,
+}
+
+/// Compute the SHA-256 hash of each function body in a core WebAssembly module, keyed by function
+/// name. Functions without a name are skipped, as are components, which are not supported yet.
+pub fn function_hashes(wasm: &[u8], scope: FunctionHashes) -> Result<HashMap<String, String>> {
+    let mut hashes = HashMap::new();
+    if scope == FunctionHashes::None {
+        return Ok(hashes);
+    }
+
+    let mut imported_funcs = 0;
+    let mut export_names = HashMap::new();
+    let mut debug_names = HashMap::new();
+    let mut bodies = vec![];
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        match payload? {
+            Payload::Version {
+                encoding: Encoding::Component,
+                ..
+            } => return Ok(hashes),
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if let TypeRef::Func(_) = import?.ty {
+                        imported_funcs += 1;
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export?;
+                    if export.kind == ExternalKind::Func {
+                        export_names.insert(export.index, export.name);
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => bodies.push(&wasm[body.range()]),
+            Payload::CustomSection(section) if section.name() == "name" => {
+                for name in NameSectionReader::new(section.data(), section.data_offset()) {
+                    // a malformed name section only costs us names, so don't fail the parse
+                    if let Ok(Name::Function(map)) = name {
+                        for naming in map.into_iter().flatten() {
+                            debug_names.insert(naming.index, naming.name);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (i, body) in bodies.into_iter().enumerate() {
+        let index = imported_funcs + i as u32;
+        let name = match (export_names.get(&index), scope) {
+            (Some(name), _) => name,
+            (None, FunctionHashes::All) => match debug_names.get(&index) {
+                Some(name) => name,
+                None => continue,
+            },
+            _ => continue,
+        };
+        hashes.insert(name.to_string(), format!("{:x}", Sha256::digest(body)));
+    }
+
+    Ok(hashes)
+}
@@ -0,0 +1,499 @@
+//! Structural checks of a core WebAssembly binary, for problems which a parser can read past but
+//! which make the module invalid or suggest it was hand-crafted or fuzzed: out of bounds function
+//! and type indices, sections out of order, truncated sections and over-long LEB128 encodings.
+//!
+//! The binary is walked directly rather than with `wasmparser`, which stops at the first problem.
+//! Index checks are skipped for sections using encodings this doesn't understand (e.g. from newer
+//! proposals), so that a valid module is never reported.
+
+/// A structural problem found in a module's binary.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IntegrityIssue {
+    pub kind: IntegrityIssueKind,
+    /// the offset of the problem in the binary, in bytes
+    pub offset: u64,
+    pub message: String,
+}
+
+/// The kind of a structural problem, written in reports as e.g. `section_order`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntegrityIssueKind {
+    /// a function or type index refers to a function or type which doesn't exist
+    IndexOutOfBounds,
+    /// the function and code sections list a different number of functions
+    CountMismatch,
+    /// a section appears out of the order required by the spec, or more than once
+    SectionOrder,
+    /// a section with an id the spec doesn't define
+    UnknownSection,
+    /// a section extends past the end of the binary
+    TruncatedSection,
+    /// the name of a custom section extends past the end of the section
+    TruncatedCustomSection,
+    /// an integer is encoded with more LEB128 bytes than its type allows
+    OversizedLeb,
+}
+
+impl IntegrityIssueKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegrityIssueKind::IndexOutOfBounds => "index_out_of_bounds",
+            IntegrityIssueKind::CountMismatch => "count_mismatch",
+            IntegrityIssueKind::SectionOrder => "section_order",
+            IntegrityIssueKind::UnknownSection => "unknown_section",
+            IntegrityIssueKind::TruncatedSection => "truncated_section",
+            IntegrityIssueKind::TruncatedCustomSection => "truncated_custom_section",
+            IntegrityIssueKind::OversizedLeb => "oversized_leb",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "index_out_of_bounds" => IntegrityIssueKind::IndexOutOfBounds,
+            "count_mismatch" => IntegrityIssueKind::CountMismatch,
+            "section_order" => IntegrityIssueKind::SectionOrder,
+            "unknown_section" => IntegrityIssueKind::UnknownSection,
+            "truncated_section" => IntegrityIssueKind::TruncatedSection,
+            "truncated_custom_section" => IntegrityIssueKind::TruncatedCustomSection,
+            "oversized_leb" => IntegrityIssueKind::OversizedLeb,
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for IntegrityIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for IntegrityIssueKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IntegrityIssueKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        IntegrityIssueKind::parse(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown integrity issue `{}`", s)))
+    }
+}
+
+const HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+// the non-custom sections, by id, in the order they must appear
+const SECTION_ORDER: &[(u8, &str)] = &[
+    (1, "type"),
+    (2, "import"),
+    (3, "function"),
+    (4, "table"),
+    (5, "memory"),
+    (13, "tag"),
+    (6, "global"),
+    (7, "export"),
+    (8, "start"),
+    (9, "element"),
+    (12, "data count"),
+    (10, "code"),
+    (11, "data"),
+];
+
+/// Find the structural problems in a core WebAssembly module. Components, and binaries which are
+/// not WebAssembly at all, have none.
+pub fn integrity_issues(wasm: &[u8]) -> Vec<IntegrityIssue> {
+    let mut issues = vec![];
+    if wasm.len() < HEADER.len() || wasm[..HEADER.len()] != HEADER {
+        return issues;
+    }
+
+    let mut sections = Sections::default();
+    let mut last: Option<usize> = None;
+    let mut reader = Reader::new(wasm, HEADER.len());
+    while !reader.is_empty() {
+        let start = reader.pos;
+        let id = wasm[start];
+        reader.pos += 1;
+        let Some(size) = reader.u32(&mut issues) else {
+            issues.push(IntegrityIssue::new(
+                IntegrityIssueKind::TruncatedSection,
+                start,
+                "the size of the last section is cut off".into(),
+            ));
+            break;
+        };
+        let size = size as usize;
+        let payload = reader.pos;
+        if size > wasm.len() - payload {
+            issues.push(IntegrityIssue::new(
+                IntegrityIssueKind::TruncatedSection,
+                start,
+                format!(
+                    "section {} has {} bytes but only {} remain",
+                    id,
+                    size,
+                    wasm.len() - payload
+                ),
+            ));
+            break;
+        }
+        let section = Reader::new(&wasm[..payload + size], payload);
+        reader.pos = payload + size;
+
+        if id == 0 {
+            check_custom_section(section, start, &mut issues);
+            continue;
+        }
+
+        let Some(rank) = SECTION_ORDER.iter().position(|(i, _)| *i == id) else {
+            issues.push(IntegrityIssue::new(
+                IntegrityIssueKind::UnknownSection,
+                start,
+                format!("unknown section id {}", id),
+            ));
+            continue;
+        };
+        if let Some(last) = last.filter(|last| rank <= *last) {
+            let message = if rank == last {
+                format!("{} section appears more than once", SECTION_ORDER[rank].1)
+            } else {
+                format!(
+                    "{} section after {} section",
+                    SECTION_ORDER[rank].1, SECTION_ORDER[last].1
+                )
+            };
+            issues.push(IntegrityIssue::new(
+                IntegrityIssueKind::SectionOrder,
+                start,
+                message,
+            ));
+        }
+        last = Some(last.map_or(rank, |last| last.max(rank)));
+
+        match id {
+            1 => sections.types = Some(section),
+            2 => sections.imports = Some(section),
+            3 => sections.functions = Some(section),
+            7 => sections.exports = Some(section),
+            8 => sections.start = Some(section),
+            10 => sections.code = Some(section),
+            _ => {}
+        }
+    }
+
+    sections.check_indices(&mut issues);
+    issues.sort_by_key(|issue| issue.offset);
+    issues
+}
+
+impl IntegrityIssue {
+    fn new(kind: IntegrityIssueKind, offset: usize, message: String) -> Self {
+        IntegrityIssue {
+            kind,
+            offset: offset as u64,
+            message,
+        }
+    }
+}
+
+fn check_custom_section(mut section: Reader, start: usize, issues: &mut Vec<IntegrityIssue>) {
+    let truncated = |message: &str| {
+        IntegrityIssue::new(
+            IntegrityIssueKind::TruncatedCustomSection,
+            start,
+            message.to_string(),
+        )
+    };
+    match section.u32(issues) {
+        None => issues.push(truncated("custom section has no name")),
+        Some(len) if len as usize > section.remaining() => issues.push(truncated(&format!(
+            "custom section name has {} bytes but the section only has {} left",
+            len,
+            section.remaining()
+        ))),
+        Some(_) => {}
+    }
+}
+
+// the sections needed to check function and type indices
+#[derive(Default)]
+struct Sections<'a> {
+    types: Option<Reader<'a>>,
+    imports: Option<Reader<'a>>,
+    functions: Option<Reader<'a>>,
+    exports: Option<Reader<'a>>,
+    start: Option<Reader<'a>>,
+    code: Option<Reader<'a>>,
+}
+
+impl Sections<'_> {
+    fn check_indices(self, issues: &mut Vec<IntegrityIssue>) {
+        let mut found = vec![];
+        let mut lebs = vec![];
+        self.read_indices(&mut found, &mut lebs);
+        issues.extend(lebs);
+        issues.extend(found);
+    }
+
+    // reading stops at the first value which can't be read, e.g. in an encoding from a newer
+    // proposal, leaving the rest of the indices unchecked
+    fn read_indices(
+        self,
+        issues: &mut Vec<IntegrityIssue>,
+        lebs: &mut Vec<IntegrityIssue>,
+    ) -> Option<()> {
+        let types = match self.types {
+            Some(reader) => count_func_types(reader, lebs)?,
+            None => 0,
+        };
+        let out_of_bounds = |offset: usize, message: String| {
+            IntegrityIssue::new(IntegrityIssueKind::IndexOutOfBounds, offset, message)
+        };
+
+        let mut imported = 0;
+        if let Some(mut reader) = self.imports {
+            for _ in 0..reader.u32(lebs)? {
+                if let Some((offset, ty)) = read_import(&mut reader, lebs)? {
+                    imported += 1;
+                    if ty >= types {
+                        issues.push(out_of_bounds(
+                            offset,
+                            format!("imported function has type {} of {}", ty, types),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut defined = 0;
+        let mut functions_end = 0;
+        if let Some(mut reader) = self.functions {
+            functions_end = reader.data.len();
+            defined = reader.u32(lebs)?;
+            for i in 0..defined {
+                let offset = reader.pos;
+                let ty = reader.u32(lebs)?;
+                if ty >= types {
+                    issues.push(out_of_bounds(
+                        offset,
+                        format!("function {} has type {} of {}", imported + i, ty, types),
+                    ));
+                }
+            }
+        }
+
+        if let Some(mut reader) = self.code {
+            let offset = reader.pos;
+            let bodies = reader.u32(lebs)?;
+            if bodies != defined {
+                issues.push(IntegrityIssue::new(
+                    IntegrityIssueKind::CountMismatch,
+                    offset,
+                    format!(
+                        "{} functions are declared but {} function bodies are defined",
+                        defined, bodies
+                    ),
+                ));
+            }
+        } else if defined > 0 {
+            issues.push(IntegrityIssue::new(
+                IntegrityIssueKind::CountMismatch,
+                functions_end,
+                format!(
+                    "{} functions are declared but there is no code section",
+                    defined
+                ),
+            ));
+        }
+
+        let functions = imported + defined;
+        if let Some(mut reader) = self.exports {
+            for _ in 0..reader.u32(lebs)? {
+                let offset = reader.pos;
+                let name = reader.name(lebs)?;
+                let kind = reader.byte()?;
+                let index = reader.u32(lebs)?;
+                if kind == 0 && index >= functions {
+                    issues.push(out_of_bounds(
+                        offset,
+                        format!("export `{}` is function {} of {}", name, index, functions),
+                    ));
+                }
+            }
+        }
+
+        if let Some(mut reader) = self.start {
+            let offset = reader.pos;
+            let index = reader.u32(lebs)?;
+            if index >= functions {
+                issues.push(out_of_bounds(
+                    offset,
+                    format!("start function is function {} of {}", index, functions),
+                ));
+            }
+        }
+
+        Some(())
+    }
+}
+
+// count the function types of the type section, or `None` if it uses other encodings
+fn count_func_types(mut reader: Reader, issues: &mut Vec<IntegrityIssue>) -> Option<u32> {
+    let count = reader.u32(issues)?;
+    for _ in 0..count {
+        if reader.byte()? != 0x60 {
+            return None;
+        }
+        for _ in 0..2 {
+            let len = reader.u32(issues)?;
+            for _ in 0..len {
+                if !is_val_type(reader.byte()?) {
+                    return None;
+                }
+            }
+        }
+    }
+
+    Some(count)
+}
+
+// read an import, returning the offset and type index of a function import, `None` for other
+// imports, or `None` overall if it can't be read
+fn read_import(
+    reader: &mut Reader,
+    issues: &mut Vec<IntegrityIssue>,
+) -> Option<Option<(usize, u32)>> {
+    reader.name(issues)?;
+    reader.name(issues)?;
+    let offset = reader.pos;
+    match reader.byte()? {
+        // function
+        0x00 => return Some(Some((offset, reader.u32(issues)?))),
+        // table
+        0x01 => {
+            if !is_val_type(reader.byte()?) {
+                return None;
+            }
+            read_limits(reader, issues)?;
+        }
+        // memory
+        0x02 => read_limits(reader, issues)?,
+        // global
+        0x03 => {
+            if !is_val_type(reader.byte()?) {
+                return None;
+            }
+            reader.byte()?;
+        }
+        // tag
+        0x04 => {
+            reader.byte()?;
+            reader.u32(issues)?;
+        }
+        _ => return None,
+    }
+
+    Some(None)
+}
+
+fn read_limits(reader: &mut Reader, issues: &mut Vec<IntegrityIssue>) -> Option<()> {
+    let flags = reader.byte()?;
+    if flags & !0x07 != 0 {
+        return None;
+    }
+    let memory64 = flags & 0x04 != 0;
+    let fields = if flags & 0x01 != 0 { 2 } else { 1 };
+    for _ in 0..fields {
+        if memory64 {
+            reader.u64(issues)?;
+        } else {
+            reader.u32(issues)?;
+        }
+    }
+
+    Some(())
+}
+
+// number types, v128, funcref and externref
+fn is_val_type(b: u8) -> bool {
+    matches!(b, 0x7b..=0x7f | 0x70 | 0x6f)
+}
+
+// reads the values of a section, reporting integers encoded with too many bytes
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        Reader { data, pos }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.pos)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        let b = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn u32(&mut self, issues: &mut Vec<IntegrityIssue>) -> Option<u32> {
+        self.leb(32, issues).map(|v| v as u32)
+    }
+
+    fn u64(&mut self, issues: &mut Vec<IntegrityIssue>) -> Option<u64> {
+        self.leb(64, issues)
+    }
+
+    // read an unsigned LEB128 integer of `bits` bits, which may be padded with zeroes up to the
+    // most bytes the type allows, as linkers do to patch values in place
+    fn leb(&mut self, bits: u32, issues: &mut Vec<IntegrityIssue>) -> Option<u64> {
+        let start = self.pos;
+        let max_bytes = bits.div_ceil(7);
+        let mut value = 0u64;
+        for i in 0..max_bytes {
+            let b = self.byte()?;
+            let shift = i * 7;
+            // the unused bits of the last byte must be zero
+            let unused = if i == max_bytes - 1 {
+                (b & 0x7f) as u64 >> (bits - shift)
+            } else {
+                0
+            };
+            if unused != 0 || (i == max_bytes - 1 && b & 0x80 != 0) {
+                issues.push(IntegrityIssue::new(
+                    IntegrityIssueKind::OversizedLeb,
+                    start,
+                    format!("integer encoded with more than {} bits", bits),
+                ));
+                // skip the rest of the encoding to carry on after it
+                while self.byte()? & 0x80 != 0 {}
+                return Some(value);
+            }
+            value |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+
+        Some(value)
+    }
+
+    fn name(&mut self, issues: &mut Vec<IntegrityIssue>) -> Option<String> {
+        let len = self.u32(issues)? as usize;
+        if len > self.remaining() {
+            return None;
+        }
+        let name = String::from_utf8_lossy(&self.data[self.pos..self.pos + len]).into_owned();
+        self.pos += len;
+        Some(name)
+    }
+}
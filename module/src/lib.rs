@@ -1,9 +1,31 @@
 pub use anyhow::Error;
 
+mod bloat;
+mod builder;
+mod capability;
+mod facts;
 mod function;
+mod hashes;
+mod integrity;
 mod module;
+mod parser;
+mod provenance;
+mod risk;
+mod score;
 mod source_language;
+mod summary;
 
-pub use function::{Function, FunctionType, ValType};
-pub use module::{Export, Import, Module};
+pub use bloat::{Bloat, BloatItem, BloatKind};
+pub use builder::ModuleBuilder;
+pub use capability::{Capability, CAPABILITIES};
+pub use facts::{CustomSection, FunctionMetrics, Global, Memory, ModuleFacts, Table, Tool};
+pub use function::{display_val_types, Function, FunctionType, ValType};
+pub use hashes::{function_hashes, FunctionHashes};
+pub use integrity::{integrity_issues, IntegrityIssue, IntegrityIssueKind};
+pub use module::{Export, Import, Module, Quarantine, VERSION_METADATA_KEY};
+pub use parser::ModuleParser;
+pub use provenance::{custom_section_strings, Library, Provenance};
+pub use risk::RiskLevel;
+pub use score::{RiskScore, RiskSignal, MAX_RISK_SCORE};
 pub use source_language::SourceLanguage;
+pub use summary::ModuleSummary;
@@ -1,6 +1,9 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use crate::{Function, SourceLanguage};
+use crate::{
+    CustomSection, Function, FunctionMetrics, Global, IntegrityIssue, Memory, Provenance,
+    SourceLanguage, Table, Tool,
+};
 
 use serde;
 use url;
@@ -16,6 +19,19 @@ pub struct Export {
     pub func: Function,
 }
 
+/// A module flagged as blocked, e.g. during incident response, without deleting or yanking it.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Quarantine {
+    /// why the module was quarantined, e.g. `CVE-2024-1234`
+    pub reason: String,
+    /// when the module was quarantined, in RFC 3339 format
+    #[serde(default)]
+    pub quarantined_at: Option<String>,
+}
+
+/// The metadata key from which a module's version is read, when it is not otherwise provided.
+pub const VERSION_METADATA_KEY: &str = "version";
+
 /// A description of a wasm module extracted from the binary, encapsulating
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Module {
@@ -29,6 +45,9 @@ pub struct Module {
     pub size: u64,
     /// path or locator to the module
     pub location: String,
+    /// version of the module, e.g. `1.2.0`, if one was provided or found in the module
+    #[serde(default)]
+    pub version: Option<String>,
     /// programming language used to produce this module
     pub source_language: SourceLanguage,
     /// arbitrary metadata provided by the operator of this module
@@ -46,20 +65,86 @@ pub struct Module {
     pub graph: Option<Vec<u8>>,
     /// function hashes
     pub function_hashes: HashMap<String, String>,
+    /// free-form labels used to group modules for fleet queries, e.g. `edge`
+    pub tags: Vec<String>,
+    /// linear memories defined or imported by the module
+    #[serde(default)]
+    pub memories: Vec<Memory>,
+    /// tables defined or imported by the module
+    #[serde(default)]
+    pub tables: Vec<Table>,
+    /// globals defined or imported by the module
+    #[serde(default)]
+    pub globals: Vec<Global>,
+    /// custom sections contained in the module
+    #[serde(default)]
+    pub custom_sections: Vec<CustomSection>,
+    /// metrics for each function defined by the module, keyed by function name
+    #[serde(default)]
+    pub function_metrics: HashMap<String, FunctionMetrics>,
+    /// tools which produced the module, from the `producers` section
+    #[serde(default)]
+    pub toolchain: Vec<Tool>,
+    /// licenses and libraries found in the module's strings and custom sections
+    #[serde(default)]
+    pub provenance: Provenance,
+    /// set while the module is quarantined
+    #[serde(default)]
+    pub quarantine: Option<Quarantine>,
+    /// structural problems found in the module's binary, see `integrity_issues`
+    #[serde(default)]
+    pub integrity: Vec<IntegrityIssue>,
 }
 
 impl Module {
     // TODO: also add memory imports and other items of interest
-    /// return the namespaces from which this module imports functions
+    /// return the namespaces from which this module imports functions, sorted by name
     pub fn get_import_namespaces(&self) -> Vec<&str> {
+        self.import_namespace_counts().into_keys().collect()
+    }
+
+    /// return the number of functions this module imports from each namespace, ordered by
+    /// namespace
+    pub fn import_namespace_counts(&self) -> BTreeMap<&str, usize> {
         self.imports
             .iter()
-            .fold(HashSet::new(), |mut acc, import| {
-                acc.insert(import.module_name.as_str());
+            .fold(BTreeMap::new(), |mut acc, import| {
+                *acc.entry(import.module_name.as_str()).or_default() += 1;
                 acc
             })
-            .into_iter()
-            .collect()
+    }
+
+    /// The Jaccard similarity of the sets of function hashes in this and the `other` module, from
+    /// 0.0 (no functions in common) to 1.0 (identical functions). Renaming functions doesn't
+    /// affect the similarity, so renamed copies and forks of a module score highly.
+    pub fn similarity(&self, other: &Module) -> f64 {
+        let ours = self.function_hashes.values().collect::<HashSet<_>>();
+        let theirs = other.function_hashes.values().collect::<HashSet<_>>();
+        let union = ours.union(&theirs).count();
+        if union == 0 {
+            return 0.0;
+        }
+
+        ours.intersection(&theirs).count() as f64 / union as f64
+    }
+
+    /// return the exported function with the given name, if any
+    pub fn find_export(&self, name: &str) -> Option<&Export> {
+        self.exports.iter().find(|exp| exp.func.name == name)
+    }
+
+    /// return the function imported from `namespace` with the given name, if any
+    pub fn find_import(&self, namespace: &str, name: &str) -> Option<&Import> {
+        self.imports
+            .iter()
+            .find(|imp| imp.module_name == namespace && imp.func.name == name)
+    }
+
+    /// return the functions this module imports from `namespace`
+    pub fn imports_in<'a>(&'a self, namespace: &'a str) -> impl Iterator<Item = &'a Import> {
+        self.imports
+            .iter()
+            .filter(move |imp| imp.module_name == namespace)
     }
 }
 
@@ -71,6 +156,7 @@ impl Default for Module {
             exports: vec![],
             size: 0,
             location: String::new(),
+            version: None,
             source_language: SourceLanguage::Unknown,
             metadata: None,
             #[cfg(not(target_arch = "wasm32"))]
@@ -81,21 +167,44 @@ impl Default for Module {
             complexity: None,
             graph: None,
             function_hashes: HashMap::new(),
+            tags: vec![],
+            memories: vec![],
+            tables: vec![],
+            globals: vec![],
+            custom_sections: vec![],
+            function_metrics: HashMap::new(),
+            toolchain: vec![],
+            provenance: Provenance::default(),
+            quarantine: None,
+            integrity: vec![],
         }
     }
 }
 
 impl Module {
+    /// The file name of the module's location, which may be a URL or a plain filesystem path.
+    /// Returns an empty string if the location has no file name.
     pub fn file_name(&self) -> String {
-        std::path::Path::new(self.location_url().path())
+        let path = match self.try_location_url() {
+            Ok(url) => url.path().to_owned(),
+            Err(_) => self.location.clone(),
+        };
+
+        std::path::Path::new(&path)
             .file_name()
             .unwrap_or_default()
-            .to_str()
-            .unwrap_or_default()
-            .to_owned()
+            .to_string_lossy()
+            .into_owned()
     }
 
+    /// Parse the module's location as a URL. Prefer `try_location_url`, as this panics if the
+    /// location is not a valid URL (e.g. a plain filesystem path, or empty).
     pub fn location_url(&self) -> url::Url {
-        url::Url::parse(self.location.as_str()).expect("Invalid location")
+        self.try_location_url().expect("Invalid location")
+    }
+
+    /// Parse the module's location as a URL, returning an error if it is not a valid URL.
+    pub fn try_location_url(&self) -> Result<url::Url, url::ParseError> {
+        url::Url::parse(self.location.as_str())
     }
 }
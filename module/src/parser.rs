@@ -0,0 +1,27 @@
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::Module;
+
+/// Parses WebAssembly binaries into a `Module`. Embedders which need a different parser than the
+/// one provided by `modsurfer-validation` can implement this trait and pass it wherever a parser
+/// is accepted.
+pub trait ModuleParser {
+    /// Parse a module from the bytes of a WebAssembly binary.
+    fn parse_bytes(&self, wasm: &[u8]) -> Result<Module>;
+
+    /// Parse a module from a stream of WebAssembly bytes, read until EOF.
+    fn parse_reader(&self, reader: &mut dyn Read) -> Result<Module> {
+        let mut wasm = vec![];
+        reader.read_to_end(&mut wasm)?;
+        self.parse_bytes(&wasm)
+    }
+
+    /// Parse a module from a WebAssembly file.
+    fn parse_file(&self, path: &Path) -> Result<Module> {
+        let wasm = std::fs::read(path)?;
+        self.parse_bytes(&wasm)
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use wasmparser::{Parser, Payload};
+
+/// Licenses and third-party libraries found embedded in a module, from its strings and custom
+/// sections. These are best-effort hints for compliance review: a library statically linked
+/// without its version string or license text is not found.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Provenance {
+    /// SPDX identifiers of the licenses found, e.g. `GPL-3.0-only` or `MIT`, sorted
+    pub licenses: Vec<String>,
+    /// libraries found from their version strings, sorted by name
+    pub libraries: Vec<Library>,
+}
+
+/// A library identified by a version string, e.g. `zlib 1.2.11`.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct Library {
+    pub name: String,
+    pub version: String,
+}
+
+impl std::fmt::Display for Library {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.version)
+    }
+}
+
+const SPDX_TAG: &str = "SPDX-License-Identifier:";
+
+/// Phrases from the text of common licenses, each with phrases which rule the license out (to tell
+/// e.g. the 2- and 3-clause BSD licenses apart). All phrases must appear in the same string.
+const LICENSE_TEXTS: &[(&str, &[&str], &[&str])] = &[
+    (
+        "AGPL-3.0",
+        &["GNU AFFERO GENERAL PUBLIC LICENSE", "Version 3"],
+        &[],
+    ),
+    (
+        "LGPL-3.0",
+        &["GNU LESSER GENERAL PUBLIC LICENSE", "Version 3"],
+        &[],
+    ),
+    (
+        "LGPL-2.1",
+        &["GNU LESSER GENERAL PUBLIC LICENSE", "Version 2.1"],
+        &[],
+    ),
+    ("GPL-3.0", &["GNU GENERAL PUBLIC LICENSE", "Version 3"], &[]),
+    ("GPL-2.0", &["GNU GENERAL PUBLIC LICENSE", "Version 2"], &[]),
+    ("Apache-2.0", &["Apache License", "Version 2.0"], &[]),
+    ("MPL-2.0", &["Mozilla Public License", "2.0"], &[]),
+    (
+        "MIT",
+        &["Permission is hereby granted, free of charge"],
+        &[],
+    ),
+    (
+        "BSD-3-Clause",
+        &[
+            "Redistribution and use in source and binary forms",
+            "Neither the name",
+        ],
+        &[],
+    ),
+    (
+        "BSD-2-Clause",
+        &["Redistribution and use in source and binary forms"],
+        &["Neither the name"],
+    ),
+    (
+        "ISC",
+        &["Permission to use, copy, modify, and/or distribute this software for any purpose"],
+        &[],
+    ),
+    (
+        "Zlib",
+        &["This software is provided 'as-is', without any express or implied"],
+        &[],
+    ),
+    (
+        "Unlicense",
+        &["This is free and unencumbered software released into the public domain"],
+        &[],
+    ),
+];
+
+/// Libraries which embed their version in a string, with the text preceding the version.
+const LIBRARY_VERSIONS: &[(&str, &[&str])] = &[
+    ("zlib", &["zlib ", " deflate ", " inflate "]),
+    ("libpng", &["libpng version "]),
+    ("libjpeg-turbo", &["libjpeg-turbo version "]),
+    ("OpenSSL", &["OpenSSL "]),
+    ("Mbed TLS", &["Mbed TLS ", "mbed TLS "]),
+    ("SQLite", &["SQLite version "]),
+    ("libcurl", &["libcurl/"]),
+    ("expat", &["expat_"]),
+    ("Lua", &["Lua "]),
+];
+
+// shorter runs of printable bytes in a custom section are unlikely to be text
+const MIN_CUSTOM_STRING_LEN: usize = 8;
+
+impl Provenance {
+    /// Find licenses and library versions in a module's strings.
+    pub fn scan<'a>(strings: impl IntoIterator<Item = &'a str>) -> Provenance {
+        let mut licenses = BTreeSet::new();
+        let mut libraries = BTreeSet::new();
+
+        for s in strings {
+            licenses.extend(spdx_identifiers(s));
+            LICENSE_TEXTS
+                .iter()
+                .filter(|(_, all, none)| {
+                    all.iter().all(|p| s.contains(p)) && !none.iter().any(|p| s.contains(p))
+                })
+                .for_each(|(id, _, _)| {
+                    licenses.insert(id.to_string());
+                });
+
+            for (name, prefixes) in LIBRARY_VERSIONS {
+                for prefix in prefixes.iter() {
+                    s.match_indices(prefix)
+                        .filter_map(|(i, _)| version_at(&s[i + prefix.len()..]))
+                        .for_each(|version| {
+                            libraries.insert(Library {
+                                name: name.to_string(),
+                                version,
+                            });
+                        });
+                }
+            }
+        }
+
+        Provenance {
+            licenses: licenses.into_iter().collect(),
+            libraries: libraries.into_iter().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.licenses.is_empty() && self.libraries.is_empty()
+    }
+
+    /// The licenses found which are `id` or a variant of it, compared case-insensitively, e.g.
+    /// `GPL-3.0` matches `GPL-3.0-only`, `GPL-3.0-or-later` and `GPL-3.0+`.
+    pub fn find_license(&self, id: &str) -> Vec<&str> {
+        let id = id.to_ascii_lowercase();
+        self.licenses
+            .iter()
+            .filter(|license| {
+                let license = license.to_ascii_lowercase();
+                license == id
+                    || license
+                        .strip_prefix(&id)
+                        .is_some_and(|rest| rest.starts_with('-') || rest == "+")
+            })
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+// the license identifiers of every `SPDX-License-Identifier: <expression>` tag in `s`, ignoring
+// operators and exceptions, e.g. `(MIT OR Apache-2.0)` is `MIT` and `Apache-2.0`
+fn spdx_identifiers(s: &str) -> Vec<String> {
+    let mut ids = vec![];
+    for (i, _) in s.match_indices(SPDX_TAG) {
+        let expression = s[i + SPDX_TAG.len()..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .split("*/")
+            .next()
+            .unwrap_or_default();
+        let mut tokens = expression
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .filter(|t| !t.is_empty());
+        while let Some(token) = tokens.next() {
+            match token {
+                "AND" | "OR" | "and" | "or" => {}
+                "WITH" | "with" => {
+                    tokens.next();
+                }
+                _ => {
+                    // a quote or other delimiter ends the expression, e.g. closing a string literal
+                    let id = token.trim_end_matches(|c: char| !is_spdx_char(c));
+                    let valid = id.chars().all(is_spdx_char);
+                    if valid && !id.is_empty() {
+                        ids.push(id.to_string());
+                    }
+                    if !valid || id.len() != token.len() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    ids
+}
+
+fn is_spdx_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+'
+}
+
+// a version at the start of `s`, e.g. `1.2.11` or `1.1.1k`, which starts with a digit and
+// contains a dot
+fn version_at(s: &str) -> Option<String> {
+    if !s.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let version = s
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+        .next()?
+        .trim_end_matches('.');
+
+    version.contains('.').then(|| version.to_string())
+}
+
+/// Collect the runs of printable text in a module's custom sections, other than the `name` section
+/// (which only holds identifiers), for scanning with `Provenance::scan`.
+pub fn custom_section_strings(wasm: &[u8]) -> Result<Vec<String>> {
+    let mut strings = vec![];
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        if let Payload::CustomSection(section) = payload? {
+            if section.name() == "name" {
+                continue;
+            }
+            strings.extend(
+                section
+                    .data()
+                    .split(|b| !(b.is_ascii_graphic() || b.is_ascii_whitespace()))
+                    .filter(|run| run.len() >= MIN_CUSTOM_STRING_LEN)
+                    .map(|run| String::from_utf8_lossy(run).into_owned()),
+            );
+        }
+    }
+
+    Ok(strings)
+}
@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// The output of the "Cyclomatic Complexity" algorithm run on a graph analysis of the WebAssembly
+/// code inside the provided module. The risk is purely related to computational resource usage,
+/// not code security or any other interpretation of risk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RiskLevel {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "medium")]
+    Medium,
+    #[serde(rename = "high")]
+    High,
+}
+
+impl RiskLevel {
+    /// The largest cyclomatic complexity within this risk level, configurable using the
+    /// `MODSURFER_RISK_LOW`, `MODSURFER_RISK_MEDIUM` and `MODSURFER_RISK_HIGH` environment
+    /// variables.
+    pub fn max(&self) -> u32 {
+        match self {
+            RiskLevel::Low => std::env::var("MODSURFER_RISK_LOW")
+                .unwrap_or(2500.to_string())
+                .parse::<u32>()
+                .expect("valid low risk level setting"),
+            RiskLevel::Medium => std::env::var("MODSURFER_RISK_MEDIUM")
+                .unwrap_or(50000.to_string())
+                .parse::<u32>()
+                .expect("valid medium risk level setting"),
+            RiskLevel::High => std::env::var("MODSURFER_RISK_HIGH")
+                .unwrap_or(u32::MAX.to_string())
+                .parse::<u32>()
+                .expect("valid high risk level setting"),
+        }
+    }
+}
+
+impl From<u32> for RiskLevel {
+    fn from(value: u32) -> Self {
+        if value <= RiskLevel::Low.max() {
+            RiskLevel::Low
+        } else if value <= RiskLevel::Medium.max() {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::High
+        }
+    }
+}
+
+impl Display for RiskLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use serde::Serialize;
+
+use crate::{Capability, Module, RiskLevel};
+
+/// The largest risk score a module can have.
+pub const MAX_RISK_SCORE: u32 = 100;
+
+/// A composite score from 0 (lowest risk) to 100 for triaging modules, combining the complexity of
+/// the module with what it can reach on the host (files, network and clocks), its size, the strings
+/// it contains and whether it is signed. Each signal contributes a fixed number of points.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RiskScore {
+    pub score: u32,
+    /// the signals which contributed to the score
+    pub signals: Vec<RiskSignal>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RiskSignal {
+    /// e.g. `complexity` or `network`
+    pub signal: &'static str,
+    pub points: u32,
+    pub detail: String,
+}
+
+/// Renders as the score followed by the signals contributing to it, e.g.
+/// `45 (network: 1 network import(s), signature: not signed)`.
+impl std::fmt::Display for RiskScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.score)?;
+        if !self.signals.is_empty() {
+            let signals = self
+                .signals
+                .iter()
+                .map(|s| format!("{}: {}", s.signal, s.detail))
+                .collect::<Vec<_>>();
+            write!(f, " ({})", signals.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+const MIB: u64 = 1024 * 1024;
+
+// the custom section in which a signature is embedded, see https://github.com/wasm-signatures
+const SIGNATURE_SECTION: &str = "signature";
+
+// strings which suggest a module embeds endpoints or credentials
+const SUSPICIOUS_STRINGS: &[&str] = &[
+    "http://",
+    "https://",
+    "password",
+    "secret",
+    "api_key",
+    "apikey",
+    "private key",
+];
+
+impl Module {
+    /// Compute the module's composite risk score. Whether the module is signed is only scored if
+    /// its custom sections are known.
+    pub fn risk_score(&self) -> RiskScore {
+        let mut signals = vec![];
+        let mut add = |signal: &'static str, points: u32, detail: String| {
+            signals.push(RiskSignal {
+                signal,
+                points,
+                detail,
+            })
+        };
+
+        if let Some(complexity) = self.complexity {
+            match RiskLevel::from(complexity) {
+                RiskLevel::Low => {}
+                RiskLevel::Medium => add(
+                    "complexity",
+                    15,
+                    format!("medium cyclomatic complexity ({complexity})"),
+                ),
+                RiskLevel::High => add(
+                    "complexity",
+                    30,
+                    format!("high cyclomatic complexity ({complexity})"),
+                ),
+            }
+        }
+
+        let capabilities = self.capabilities();
+        for (signal, points, access) in [
+            (
+                "filesystem",
+                15,
+                &[Capability::FilesystemRead, Capability::FilesystemWrite][..],
+            ),
+            ("network", 20, &[Capability::Network]),
+            ("clock", 5, &[Capability::Clock]),
+        ] {
+            let found = access
+                .iter()
+                .filter_map(|c| capabilities.get(c))
+                .map(Vec::len)
+                .sum::<usize>();
+            if found > 0 {
+                add(signal, points, format!("{found} {signal} import(s)"));
+            }
+        }
+
+        if self.size > 10 * MIB {
+            add("size", 10, "larger than 10 MiB".into());
+        } else if self.size > MIB {
+            add("size", 5, "larger than 1 MiB".into());
+        }
+
+        let suspicious = self
+            .strings
+            .iter()
+            .filter(|s| {
+                let s = s.to_lowercase();
+                SUSPICIOUS_STRINGS.iter().any(|pattern| s.contains(pattern))
+            })
+            .count();
+        if suspicious > 0 {
+            add(
+                "strings",
+                10,
+                format!("{suspicious} string(s) containing URLs or credentials"),
+            );
+        }
+
+        // a module without custom sections may have been parsed (or stored by a server) before
+        // they were collected, so whether it is signed isn't known
+        if !self.custom_sections.is_empty()
+            && !self
+                .custom_sections
+                .iter()
+                .any(|section| section.name == SIGNATURE_SECTION)
+        {
+            add("signature", 10, "not signed".into());
+        }
+
+        RiskScore {
+            score: signals
+                .iter()
+                .map(|s| s.points)
+                .sum::<u32>()
+                .min(MAX_RISK_SCORE),
+            signals,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use human_bytes::human_bytes;
+use serde::Serialize;
+
+use crate::{Module, RiskLevel, SourceLanguage};
+
+/// An overview of a module, containing the facts most often displayed about it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModuleSummary {
+    pub hash: String,
+    pub file_name: String,
+    pub version: Option<String>,
+    pub source_language: SourceLanguage,
+    /// size in bytes of the module
+    pub size: u64,
+    /// size of the module in human-readable units, e.g. "1.2 MiB"
+    pub human_size: String,
+    pub imports: usize,
+    pub exports: usize,
+    /// the namespaces from which the module imports functions, sorted by name
+    pub namespaces: Vec<String>,
+    pub memories: usize,
+    pub tables: usize,
+    pub globals: usize,
+    pub custom_sections: usize,
+    pub complexity: Option<u32>,
+    /// the risk level of the module's complexity, if known
+    pub risk: Option<RiskLevel>,
+    /// the composite risk score of the module, see `Module::risk_score`
+    pub risk_score: u32,
+    pub tags: Vec<String>,
+    /// SPDX identifiers of the licenses found in the module, see `Module::provenance`
+    pub licenses: Vec<String>,
+    /// libraries found in the module, e.g. `zlib 1.2.11`
+    pub libraries: Vec<String>,
+    /// why the module is quarantined, if it is
+    pub quarantine: Option<String>,
+}
+
+impl Module {
+    /// Summarize the module for display, e.g. in tables or JSON output.
+    pub fn summary(&self) -> ModuleSummary {
+        ModuleSummary {
+            hash: self.hash.clone(),
+            file_name: self.file_name(),
+            version: self.version.clone(),
+            source_language: self.source_language.clone(),
+            size: self.size,
+            human_size: human_bytes(self.size as f64),
+            imports: self.imports.len(),
+            exports: self.exports.len(),
+            namespaces: self
+                .get_import_namespaces()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            memories: self.memories.len(),
+            tables: self.tables.len(),
+            globals: self.globals.len(),
+            custom_sections: self.custom_sections.len(),
+            complexity: self.complexity,
+            risk: self.complexity.map(RiskLevel::from),
+            risk_score: self.risk_score().score,
+            tags: self.tags.clone(),
+            licenses: self.provenance.licenses.clone(),
+            libraries: self
+                .provenance
+                .libraries
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            quarantine: self.quarantine.as_ref().map(|q| q.reason.clone()),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // Code generated by protoc-gen-go. DO NOT EDIT.
 // versions:
 // 	protoc-gen-go v1.32.0
-// 	protoc        v3.15.8
+// 	protoc        v7.36.2
 // source: proto/v1/api.proto
 
 package modsurferpb
@@ -33,18 +33,45 @@ const (
 	ValType_V128      ValType = 4
 	ValType_FuncRef   ValType = 5
 	ValType_ExternRef ValType = 6
+	// component-model value types
+	ValType_Bool    ValType = 7
+	ValType_S8      ValType = 8
+	ValType_U8      ValType = 9
+	ValType_S16     ValType = 10
+	ValType_U16     ValType = 11
+	ValType_S32     ValType = 12
+	ValType_U32     ValType = 13
+	ValType_S64     ValType = 14
+	ValType_U64     ValType = 15
+	ValType_Float32 ValType = 16
+	ValType_Float64 ValType = 17
+	ValType_Char    ValType = 18
+	ValType_String  ValType = 19
 )
 
 // Enum value maps for ValType.
 var (
 	ValType_name = map[int32]string{
-		0: "I32",
-		1: "I64",
-		2: "F32",
-		3: "F64",
-		4: "V128",
-		5: "FuncRef",
-		6: "ExternRef",
+		0:  "I32",
+		1:  "I64",
+		2:  "F32",
+		3:  "F64",
+		4:  "V128",
+		5:  "FuncRef",
+		6:  "ExternRef",
+		7:  "Bool",
+		8:  "S8",
+		9:  "U8",
+		10: "S16",
+		11: "U16",
+		12: "S32",
+		13: "U32",
+		14: "S64",
+		15: "U64",
+		16: "Float32",
+		17: "Float64",
+		18: "Char",
+		19: "String",
 	}
 	ValType_value = map[string]int32{
 		"I32":       0,
@@ -54,6 +81,19 @@ var (
 		"V128":      4,
 		"FuncRef":   5,
 		"ExternRef": 6,
+		"Bool":      7,
+		"S8":        8,
+		"U8":        9,
+		"S16":       10,
+		"U16":       11,
+		"S32":       12,
+		"U32":       13,
+		"S64":       14,
+		"U64":       15,
+		"Float32":   16,
+		"Float64":   17,
+		"Char":      18,
+		"String":    19,
 	}
 )
 
@@ -214,6 +254,7 @@ const (
 	Field_ExportsCount Field = 5
 	Field_Sha256       Field = 6
 	Field_Complexity   Field = 7
+	Field_RiskScore    Field = 8
 )
 
 // Enum value maps for Field.
@@ -227,6 +268,7 @@ var (
 		5: "ExportsCount",
 		6: "Sha256",
 		7: "Complexity",
+		8: "RiskScore",
 	}
 	Field_value = map[string]int32{
 		"CreatedAt":    0,
@@ -237,6 +279,7 @@ var (
 		"ExportsCount": 5,
 		"Sha256":       6,
 		"Complexity":   7,
+		"RiskScore":    8,
 	}
 )
 
@@ -267,9 +310,55 @@ func (Field) EnumDescriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{3}
 }
 
-// Represents the expected outcome of an AuditModulesRequest. If PASS is provided, then
-// the audit returns modules which conform to the checkfile. If FAIL is provided, then
-// the audit returns modules which do not conform to the checkfile.
+type RiskLevel int32
+
+const (
+	RiskLevel_LOW    RiskLevel = 0
+	RiskLevel_MEDIUM RiskLevel = 1
+	RiskLevel_HIGH   RiskLevel = 2
+)
+
+// Enum value maps for RiskLevel.
+var (
+	RiskLevel_name = map[int32]string{
+		0: "LOW",
+		1: "MEDIUM",
+		2: "HIGH",
+	}
+	RiskLevel_value = map[string]int32{
+		"LOW":    0,
+		"MEDIUM": 1,
+		"HIGH":   2,
+	}
+)
+
+func (x RiskLevel) Enum() *RiskLevel {
+	p := new(RiskLevel)
+	*p = x
+	return p
+}
+
+func (x RiskLevel) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (RiskLevel) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_v1_api_proto_enumTypes[4].Descriptor()
+}
+
+func (RiskLevel) Type() protoreflect.EnumType {
+	return &file_proto_v1_api_proto_enumTypes[4]
+}
+
+func (x RiskLevel) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use RiskLevel.Descriptor instead.
+func (RiskLevel) EnumDescriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{4}
+}
+
 type AuditOutcome int32
 
 const (
@@ -300,11 +389,11 @@ func (x AuditOutcome) String() string {
 }
 
 func (AuditOutcome) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_v1_api_proto_enumTypes[4].Descriptor()
+	return file_proto_v1_api_proto_enumTypes[5].Descriptor()
 }
 
 func (AuditOutcome) Type() protoreflect.EnumType {
-	return &file_proto_v1_api_proto_enumTypes[4]
+	return &file_proto_v1_api_proto_enumTypes[5]
 }
 
 func (x AuditOutcome) Number() protoreflect.EnumNumber {
@@ -313,7 +402,64 @@ func (x AuditOutcome) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use AuditOutcome.Descriptor instead.
 func (AuditOutcome) EnumDescriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{4}
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{5}
+}
+
+// The kinds of changes to modules which are reported to subscribers.
+type ModuleEventKind int32
+
+const (
+	// never sent by the server, so that a missing kind is not mistaken for `CREATED`
+	ModuleEventKind_MODULE_EVENT_KIND_UNSPECIFIED ModuleEventKind = 0
+	ModuleEventKind_CREATED                       ModuleEventKind = 1
+	ModuleEventKind_UPDATED                       ModuleEventKind = 2
+	ModuleEventKind_DELETED                       ModuleEventKind = 3
+	ModuleEventKind_YANKED                        ModuleEventKind = 4
+)
+
+// Enum value maps for ModuleEventKind.
+var (
+	ModuleEventKind_name = map[int32]string{
+		0: "MODULE_EVENT_KIND_UNSPECIFIED",
+		1: "CREATED",
+		2: "UPDATED",
+		3: "DELETED",
+		4: "YANKED",
+	}
+	ModuleEventKind_value = map[string]int32{
+		"MODULE_EVENT_KIND_UNSPECIFIED": 0,
+		"CREATED":                       1,
+		"UPDATED":                       2,
+		"DELETED":                       3,
+		"YANKED":                        4,
+	}
+)
+
+func (x ModuleEventKind) Enum() *ModuleEventKind {
+	p := new(ModuleEventKind)
+	*p = x
+	return p
+}
+
+func (x ModuleEventKind) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ModuleEventKind) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_v1_api_proto_enumTypes[6].Descriptor()
+}
+
+func (ModuleEventKind) Type() protoreflect.EnumType {
+	return &file_proto_v1_api_proto_enumTypes[6]
+}
+
+func (x ModuleEventKind) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ModuleEventKind.Descriptor instead.
+func (ModuleEventKind) EnumDescriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{6}
 }
 
 // Contained by an import or export element within a wasm binary.
@@ -522,6 +668,28 @@ type Module struct {
 	Graph []byte `protobuf:"bytes,14,opt,name=graph,proto3,oneof" json:"graph,omitempty"`
 	// function hashes
 	FunctionHashes map[string]string `protobuf:"bytes,15,rep,name=function_hashes,json=functionHashes,proto3" json:"function_hashes,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// free-form labels used to group modules for fleet queries, e.g. `edge`
+	Tags []string `protobuf:"bytes,16,rep,name=tags,proto3" json:"tags,omitempty"`
+	// linear memories defined or imported by the module
+	Memories []*Memory `protobuf:"bytes,17,rep,name=memories,proto3" json:"memories,omitempty"`
+	// tables defined or imported by the module
+	Tables []*Table `protobuf:"bytes,18,rep,name=tables,proto3" json:"tables,omitempty"`
+	// globals defined or imported by the module
+	Globals []*Global `protobuf:"bytes,19,rep,name=globals,proto3" json:"globals,omitempty"`
+	// custom sections contained in the module
+	CustomSections []*CustomSection `protobuf:"bytes,20,rep,name=custom_sections,json=customSections,proto3" json:"custom_sections,omitempty"`
+	// metrics for each function defined by the module, keyed by function name
+	FunctionMetrics map[string]*FunctionMetrics `protobuf:"bytes,21,rep,name=function_metrics,json=functionMetrics,proto3" json:"function_metrics,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// tools which produced the module, from the `producers` section
+	Toolchain []*Tool `protobuf:"bytes,22,rep,name=toolchain,proto3" json:"toolchain,omitempty"`
+	// version of the module, e.g. `1.2.0`, if one was provided or found in the module
+	Version *string `protobuf:"bytes,23,opt,name=version,proto3,oneof" json:"version,omitempty"`
+	// licenses and libraries found in the module's strings and custom sections
+	Provenance *Provenance `protobuf:"bytes,24,opt,name=provenance,proto3" json:"provenance,omitempty"`
+	// set while the module is quarantined
+	Quarantine *Quarantine `protobuf:"bytes,25,opt,name=quarantine,proto3" json:"quarantine,omitempty"`
+	// structural problems found in the module's binary
+	Integrity []*IntegrityIssue `protobuf:"bytes,26,rep,name=integrity,proto3" json:"integrity,omitempty"`
 }
 
 func (x *Module) Reset() {
@@ -647,20 +815,98 @@ func (x *Module) GetFunctionHashes() map[string]string {
 	return nil
 }
 
-// Details about a wasm module graph
-type ModuleGraph struct {
+func (x *Module) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
+func (x *Module) GetMemories() []*Memory {
+	if x != nil {
+		return x.Memories
+	}
+	return nil
+}
+
+func (x *Module) GetTables() []*Table {
+	if x != nil {
+		return x.Tables
+	}
+	return nil
+}
+
+func (x *Module) GetGlobals() []*Global {
+	if x != nil {
+		return x.Globals
+	}
+	return nil
+}
+
+func (x *Module) GetCustomSections() []*CustomSection {
+	if x != nil {
+		return x.CustomSections
+	}
+	return nil
+}
+
+func (x *Module) GetFunctionMetrics() map[string]*FunctionMetrics {
+	if x != nil {
+		return x.FunctionMetrics
+	}
+	return nil
+}
+
+func (x *Module) GetToolchain() []*Tool {
+	if x != nil {
+		return x.Toolchain
+	}
+	return nil
+}
+
+func (x *Module) GetVersion() string {
+	if x != nil && x.Version != nil {
+		return *x.Version
+	}
+	return ""
+}
+
+func (x *Module) GetProvenance() *Provenance {
+	if x != nil {
+		return x.Provenance
+	}
+	return nil
+}
+
+func (x *Module) GetQuarantine() *Quarantine {
+	if x != nil {
+		return x.Quarantine
+	}
+	return nil
+}
+
+func (x *Module) GetIntegrity() []*IntegrityIssue {
+	if x != nil {
+		return x.Integrity
+	}
+	return nil
+}
+
+// A structural problem found in a module's binary, e.g. an out of bounds function index
+type IntegrityIssue struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// ID for this module, generated by the database.
-	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
-	// the serialized graph in json format
-	JsonBytes []byte `protobuf:"bytes,2,opt,name=json_bytes,json=jsonBytes,proto3" json:"json_bytes,omitempty"`
+	// e.g. "index_out_of_bounds" or "section_order"
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
+	// the offset of the problem in the binary, in bytes
+	Offset  uint64 `protobuf:"varint,2,opt,name=offset,proto3" json:"offset,omitempty"`
+	Message string `protobuf:"bytes,3,opt,name=message,proto3" json:"message,omitempty"`
 }
 
-func (x *ModuleGraph) Reset() {
-	*x = ModuleGraph{}
+func (x *IntegrityIssue) Reset() {
+	*x = IntegrityIssue{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[4]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -668,13 +914,13 @@ func (x *ModuleGraph) Reset() {
 	}
 }
 
-func (x *ModuleGraph) String() string {
+func (x *IntegrityIssue) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ModuleGraph) ProtoMessage() {}
+func (*IntegrityIssue) ProtoMessage() {}
 
-func (x *ModuleGraph) ProtoReflect() protoreflect.Message {
+func (x *IntegrityIssue) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[4]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -686,37 +932,46 @@ func (x *ModuleGraph) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ModuleGraph.ProtoReflect.Descriptor instead.
-func (*ModuleGraph) Descriptor() ([]byte, []int) {
+// Deprecated: Use IntegrityIssue.ProtoReflect.Descriptor instead.
+func (*IntegrityIssue) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{4}
 }
 
-func (x *ModuleGraph) GetId() int64 {
+func (x *IntegrityIssue) GetKind() string {
 	if x != nil {
-		return x.Id
+		return x.Kind
+	}
+	return ""
+}
+
+func (x *IntegrityIssue) GetOffset() uint64 {
+	if x != nil {
+		return x.Offset
 	}
 	return 0
 }
 
-func (x *ModuleGraph) GetJsonBytes() []byte {
+func (x *IntegrityIssue) GetMessage() string {
 	if x != nil {
-		return x.JsonBytes
+		return x.Message
 	}
-	return nil
+	return ""
 }
 
-// An error message indicating a problem in the API.
-type Error struct {
+// A module flagged as blocked, e.g. during incident response, without deleting
+// or yanking it
+type Quarantine struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Code    int32  `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
-	Message string `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	// why the module was quarantined, e.g. `CVE-2024-1234`
+	Reason        string                 `protobuf:"bytes,1,opt,name=reason,proto3" json:"reason,omitempty"`
+	QuarantinedAt *timestamppb.Timestamp `protobuf:"bytes,2,opt,name=quarantined_at,json=quarantinedAt,proto3" json:"quarantined_at,omitempty"`
 }
 
-func (x *Error) Reset() {
-	*x = Error{}
+func (x *Quarantine) Reset() {
+	*x = Quarantine{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[5]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -724,13 +979,13 @@ func (x *Error) Reset() {
 	}
 }
 
-func (x *Error) String() string {
+func (x *Quarantine) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*Error) ProtoMessage() {}
+func (*Quarantine) ProtoMessage() {}
 
-func (x *Error) ProtoReflect() protoreflect.Message {
+func (x *Quarantine) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[5]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -742,38 +997,38 @@ func (x *Error) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use Error.ProtoReflect.Descriptor instead.
-func (*Error) Descriptor() ([]byte, []int) {
+// Deprecated: Use Quarantine.ProtoReflect.Descriptor instead.
+func (*Quarantine) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{5}
 }
 
-func (x *Error) GetCode() int32 {
+func (x *Quarantine) GetReason() string {
 	if x != nil {
-		return x.Code
+		return x.Reason
 	}
-	return 0
+	return ""
 }
 
-func (x *Error) GetMessage() string {
+func (x *Quarantine) GetQuarantinedAt() *timestamppb.Timestamp {
 	if x != nil {
-		return x.Message
+		return x.QuarantinedAt
 	}
-	return ""
+	return nil
 }
 
-// Control/limit the way results are paginated when working with large
-// responses.
-type Pagination struct {
+// Licenses and third-party libraries found embedded in a module
+type Provenance struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Limit  uint32 `protobuf:"varint,1,opt,name=limit,proto3" json:"limit,omitempty"`
-	Offset uint32 `protobuf:"varint,2,opt,name=offset,proto3" json:"offset,omitempty"`
+	// SPDX identifiers, e.g. `GPL-3.0-only`
+	Licenses  []string   `protobuf:"bytes,1,rep,name=licenses,proto3" json:"licenses,omitempty"`
+	Libraries []*Library `protobuf:"bytes,2,rep,name=libraries,proto3" json:"libraries,omitempty"`
 }
 
-func (x *Pagination) Reset() {
-	*x = Pagination{}
+func (x *Provenance) Reset() {
+	*x = Provenance{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[6]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -781,13 +1036,13 @@ func (x *Pagination) Reset() {
 	}
 }
 
-func (x *Pagination) String() string {
+func (x *Provenance) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*Pagination) ProtoMessage() {}
+func (*Provenance) ProtoMessage() {}
 
-func (x *Pagination) ProtoReflect() protoreflect.Message {
+func (x *Provenance) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[6]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -799,37 +1054,37 @@ func (x *Pagination) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use Pagination.ProtoReflect.Descriptor instead.
-func (*Pagination) Descriptor() ([]byte, []int) {
+// Deprecated: Use Provenance.ProtoReflect.Descriptor instead.
+func (*Provenance) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{6}
 }
 
-func (x *Pagination) GetLimit() uint32 {
+func (x *Provenance) GetLicenses() []string {
 	if x != nil {
-		return x.Limit
+		return x.Licenses
 	}
-	return 0
+	return nil
 }
 
-func (x *Pagination) GetOffset() uint32 {
+func (x *Provenance) GetLibraries() []*Library {
 	if x != nil {
-		return x.Offset
+		return x.Libraries
 	}
-	return 0
+	return nil
 }
 
-// Determine how to sort results from the API
-type Sort struct {
+// A library identified by a version string found in a module, e.g. `zlib 1.2.11`
+type Library struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Direction Direction `protobuf:"varint,1,opt,name=direction,proto3,enum=Direction" json:"direction,omitempty"`
-	Field     Field     `protobuf:"varint,2,opt,name=field,proto3,enum=Field" json:"field,omitempty"`
+	Name    string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Version string `protobuf:"bytes,2,opt,name=version,proto3" json:"version,omitempty"`
 }
 
-func (x *Sort) Reset() {
-	*x = Sort{}
+func (x *Library) Reset() {
+	*x = Library{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[7]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -837,13 +1092,13 @@ func (x *Sort) Reset() {
 	}
 }
 
-func (x *Sort) String() string {
+func (x *Library) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*Sort) ProtoMessage() {}
+func (*Library) ProtoMessage() {}
 
-func (x *Sort) ProtoReflect() protoreflect.Message {
+func (x *Library) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[7]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -855,40 +1110,42 @@ func (x *Sort) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use Sort.ProtoReflect.Descriptor instead.
-func (*Sort) Descriptor() ([]byte, []int) {
+// Deprecated: Use Library.ProtoReflect.Descriptor instead.
+func (*Library) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{7}
 }
 
-func (x *Sort) GetDirection() Direction {
+func (x *Library) GetName() string {
 	if x != nil {
-		return x.Direction
+		return x.Name
 	}
-	return Direction_Desc
+	return ""
 }
 
-func (x *Sort) GetField() Field {
+func (x *Library) GetVersion() string {
 	if x != nil {
-		return x.Field
+		return x.Version
 	}
-	return Field_CreatedAt
+	return ""
 }
 
-// `PUT /api/v1/module:`
-// Insert a module, extract data from binary. Return the module ID & hash.
-type CreateModuleRequest struct {
+// A linear memory defined or imported by a module
+type Memory struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Wasm     []byte            `protobuf:"bytes,1,opt,name=wasm,proto3" json:"wasm,omitempty"`
-	Metadata map[string]string `protobuf:"bytes,2,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	// a valid URL with a scheme prefix e.g. `s3://`, `file://`, `https://`
-	Location *string `protobuf:"bytes,3,opt,name=location,proto3,oneof" json:"location,omitempty"`
+	// initial size of the memory, in 64KiB pages
+	Initial uint64 `protobuf:"varint,1,opt,name=initial,proto3" json:"initial,omitempty"`
+	// maximum size of the memory, in 64KiB pages, if limited
+	Maximum  *uint64 `protobuf:"varint,2,opt,name=maximum,proto3,oneof" json:"maximum,omitempty"`
+	Shared   bool    `protobuf:"varint,3,opt,name=shared,proto3" json:"shared,omitempty"`
+	Memory64 bool    `protobuf:"varint,4,opt,name=memory64,proto3" json:"memory64,omitempty"`
+	Imported bool    `protobuf:"varint,5,opt,name=imported,proto3" json:"imported,omitempty"`
 }
 
-func (x *CreateModuleRequest) Reset() {
-	*x = CreateModuleRequest{}
+func (x *Memory) Reset() {
+	*x = Memory{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[8]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -896,13 +1153,13 @@ func (x *CreateModuleRequest) Reset() {
 	}
 }
 
-func (x *CreateModuleRequest) String() string {
+func (x *Memory) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateModuleRequest) ProtoMessage() {}
+func (*Memory) ProtoMessage() {}
 
-func (x *CreateModuleRequest) ProtoReflect() protoreflect.Message {
+func (x *Memory) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[8]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -914,45 +1171,60 @@ func (x *CreateModuleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateModuleRequest.ProtoReflect.Descriptor instead.
-func (*CreateModuleRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use Memory.ProtoReflect.Descriptor instead.
+func (*Memory) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{8}
 }
 
-func (x *CreateModuleRequest) GetWasm() []byte {
+func (x *Memory) GetInitial() uint64 {
 	if x != nil {
-		return x.Wasm
+		return x.Initial
 	}
-	return nil
+	return 0
 }
 
-func (x *CreateModuleRequest) GetMetadata() map[string]string {
+func (x *Memory) GetMaximum() uint64 {
+	if x != nil && x.Maximum != nil {
+		return *x.Maximum
+	}
+	return 0
+}
+
+func (x *Memory) GetShared() bool {
 	if x != nil {
-		return x.Metadata
+		return x.Shared
 	}
-	return nil
+	return false
 }
 
-func (x *CreateModuleRequest) GetLocation() string {
-	if x != nil && x.Location != nil {
-		return *x.Location
+func (x *Memory) GetMemory64() bool {
+	if x != nil {
+		return x.Memory64
 	}
-	return ""
+	return false
 }
 
-// The message returned in response to a `CreateModuleRequest`.
-type CreateModuleResponse struct {
+func (x *Memory) GetImported() bool {
+	if x != nil {
+		return x.Imported
+	}
+	return false
+}
+
+// A table of references defined or imported by a module
+type Table struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	ModuleId int64  `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
-	Hash     string `protobuf:"bytes,2,opt,name=hash,proto3" json:"hash,omitempty"`
-	Error    *Error `protobuf:"bytes,3,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	ElementType ValType `protobuf:"varint,1,opt,name=element_type,json=elementType,proto3,enum=ValType" json:"element_type,omitempty"`
+	Initial     uint64  `protobuf:"varint,2,opt,name=initial,proto3" json:"initial,omitempty"`
+	Maximum     *uint64 `protobuf:"varint,3,opt,name=maximum,proto3,oneof" json:"maximum,omitempty"`
+	Imported    bool    `protobuf:"varint,4,opt,name=imported,proto3" json:"imported,omitempty"`
 }
 
-func (x *CreateModuleResponse) Reset() {
-	*x = CreateModuleResponse{}
+func (x *Table) Reset() {
+	*x = Table{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[9]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -960,13 +1232,13 @@ func (x *CreateModuleResponse) Reset() {
 	}
 }
 
-func (x *CreateModuleResponse) String() string {
+func (x *Table) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateModuleResponse) ProtoMessage() {}
+func (*Table) ProtoMessage() {}
 
-func (x *CreateModuleResponse) ProtoReflect() protoreflect.Message {
+func (x *Table) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[9]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -978,44 +1250,52 @@ func (x *CreateModuleResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateModuleResponse.ProtoReflect.Descriptor instead.
-func (*CreateModuleResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use Table.ProtoReflect.Descriptor instead.
+func (*Table) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{9}
 }
 
-func (x *CreateModuleResponse) GetModuleId() int64 {
+func (x *Table) GetElementType() ValType {
 	if x != nil {
-		return x.ModuleId
+		return x.ElementType
 	}
-	return 0
+	return ValType_I32
 }
 
-func (x *CreateModuleResponse) GetHash() string {
+func (x *Table) GetInitial() uint64 {
 	if x != nil {
-		return x.Hash
+		return x.Initial
 	}
-	return ""
+	return 0
 }
 
-func (x *CreateModuleResponse) GetError() *Error {
+func (x *Table) GetMaximum() uint64 {
+	if x != nil && x.Maximum != nil {
+		return *x.Maximum
+	}
+	return 0
+}
+
+func (x *Table) GetImported() bool {
 	if x != nil {
-		return x.Error
+		return x.Imported
 	}
-	return nil
+	return false
 }
 
-// `POST /api/v1/module:`
-// Return a single module.
-type GetModuleRequest struct {
+// A global variable defined or imported by a module
+type Global struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	ModuleId int64 `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+	Ty       ValType `protobuf:"varint,1,opt,name=ty,proto3,enum=ValType" json:"ty,omitempty"`
+	Mutable  bool    `protobuf:"varint,2,opt,name=mutable,proto3" json:"mutable,omitempty"`
+	Imported bool    `protobuf:"varint,3,opt,name=imported,proto3" json:"imported,omitempty"`
 }
 
-func (x *GetModuleRequest) Reset() {
-	*x = GetModuleRequest{}
+func (x *Global) Reset() {
+	*x = Global{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[10]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1023,13 +1303,13 @@ func (x *GetModuleRequest) Reset() {
 	}
 }
 
-func (x *GetModuleRequest) String() string {
+func (x *Global) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetModuleRequest) ProtoMessage() {}
+func (*Global) ProtoMessage() {}
 
-func (x *GetModuleRequest) ProtoReflect() protoreflect.Message {
+func (x *Global) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[10]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1041,30 +1321,45 @@ func (x *GetModuleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetModuleRequest.ProtoReflect.Descriptor instead.
-func (*GetModuleRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use Global.ProtoReflect.Descriptor instead.
+func (*Global) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{10}
 }
 
-func (x *GetModuleRequest) GetModuleId() int64 {
+func (x *Global) GetTy() ValType {
 	if x != nil {
-		return x.ModuleId
+		return x.Ty
 	}
-	return 0
+	return ValType_I32
 }
 
-// The message returned in response to a `GetModuleRequest`.
-type GetModuleResponse struct {
+func (x *Global) GetMutable() bool {
+	if x != nil {
+		return x.Mutable
+	}
+	return false
+}
+
+func (x *Global) GetImported() bool {
+	if x != nil {
+		return x.Imported
+	}
+	return false
+}
+
+// A custom section contained in a module
+type CustomSection struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Module *Module `protobuf:"bytes,1,opt,name=module,proto3" json:"module,omitempty"`
-	Error  *Error  `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Name string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	// size in bytes of the section's data
+	Size uint64 `protobuf:"varint,2,opt,name=size,proto3" json:"size,omitempty"`
 }
 
-func (x *GetModuleResponse) Reset() {
-	*x = GetModuleResponse{}
+func (x *CustomSection) Reset() {
+	*x = CustomSection{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[11]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1072,13 +1367,13 @@ func (x *GetModuleResponse) Reset() {
 	}
 }
 
-func (x *GetModuleResponse) String() string {
+func (x *CustomSection) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetModuleResponse) ProtoMessage() {}
+func (*CustomSection) ProtoMessage() {}
 
-func (x *GetModuleResponse) ProtoReflect() protoreflect.Message {
+func (x *CustomSection) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[11]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1090,38 +1385,39 @@ func (x *GetModuleResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetModuleResponse.ProtoReflect.Descriptor instead.
-func (*GetModuleResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use CustomSection.ProtoReflect.Descriptor instead.
+func (*CustomSection) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{11}
 }
 
-func (x *GetModuleResponse) GetModule() *Module {
+func (x *CustomSection) GetName() string {
 	if x != nil {
-		return x.Module
+		return x.Name
 	}
-	return nil
+	return ""
 }
 
-func (x *GetModuleResponse) GetError() *Error {
+func (x *CustomSection) GetSize() uint64 {
 	if x != nil {
-		return x.Error
+		return x.Size
 	}
-	return nil
+	return 0
 }
 
-// `POST /api/v1/modules:`
-// Return paginated list of all modules.
-type ListModulesRequest struct {
+// Measurements of a single function defined by a module
+type FunctionMetrics struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Pagination *Pagination `protobuf:"bytes,1,opt,name=pagination,proto3" json:"pagination,omitempty"`
-	Sort       *Sort       `protobuf:"bytes,2,opt,name=sort,proto3" json:"sort,omitempty"`
+	Complexity   uint32 `protobuf:"varint,1,opt,name=complexity,proto3" json:"complexity,omitempty"`
+	Instructions uint64 `protobuf:"varint,2,opt,name=instructions,proto3" json:"instructions,omitempty"`
+	// size in bytes of the function body
+	Size uint64 `protobuf:"varint,3,opt,name=size,proto3" json:"size,omitempty"`
 }
 
-func (x *ListModulesRequest) Reset() {
-	*x = ListModulesRequest{}
+func (x *FunctionMetrics) Reset() {
+	*x = FunctionMetrics{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[12]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1129,13 +1425,13 @@ func (x *ListModulesRequest) Reset() {
 	}
 }
 
-func (x *ListModulesRequest) String() string {
+func (x *FunctionMetrics) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListModulesRequest) ProtoMessage() {}
+func (*FunctionMetrics) ProtoMessage() {}
 
-func (x *ListModulesRequest) ProtoReflect() protoreflect.Message {
+func (x *FunctionMetrics) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[12]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1147,42 +1443,44 @@ func (x *ListModulesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListModulesRequest.ProtoReflect.Descriptor instead.
-func (*ListModulesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use FunctionMetrics.ProtoReflect.Descriptor instead.
+func (*FunctionMetrics) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{12}
 }
 
-func (x *ListModulesRequest) GetPagination() *Pagination {
+func (x *FunctionMetrics) GetComplexity() uint32 {
 	if x != nil {
-		return x.Pagination
+		return x.Complexity
 	}
-	return nil
+	return 0
 }
 
-func (x *ListModulesRequest) GetSort() *Sort {
+func (x *FunctionMetrics) GetInstructions() uint64 {
 	if x != nil {
-		return x.Sort
+		return x.Instructions
 	}
-	return nil
+	return 0
 }
 
-// The message returned in response to a `ListModulesRequest`.
-type ListModulesResponse struct {
+func (x *FunctionMetrics) GetSize() uint64 {
+	if x != nil {
+		return x.Size
+	}
+	return 0
+}
+
+// A tool which produced or processed a module
+type Tool struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Modules    []*Module   `protobuf:"bytes,1,rep,name=modules,proto3" json:"modules,omitempty"`
-	Pagination *Pagination `protobuf:"bytes,2,opt,name=pagination,proto3" json:"pagination,omitempty"`
-	// the full count of results in the database (not the count of this message's
-	// `modules`).
-	Total uint64 `protobuf:"varint,3,opt,name=total,proto3" json:"total,omitempty"`
-	Sort  *Sort  `protobuf:"bytes,4,opt,name=sort,proto3" json:"sort,omitempty"`
-	Error *Error `protobuf:"bytes,5,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Name    string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Version string `protobuf:"bytes,2,opt,name=version,proto3" json:"version,omitempty"`
 }
 
-func (x *ListModulesResponse) Reset() {
-	*x = ListModulesResponse{}
+func (x *Tool) Reset() {
+	*x = Tool{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[13]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1190,13 +1488,13 @@ func (x *ListModulesResponse) Reset() {
 	}
 }
 
-func (x *ListModulesResponse) String() string {
+func (x *Tool) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListModulesResponse) ProtoMessage() {}
+func (*Tool) ProtoMessage() {}
 
-func (x *ListModulesResponse) ProtoReflect() protoreflect.Message {
+func (x *Tool) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[13]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1208,92 +1506,39 @@ func (x *ListModulesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListModulesResponse.ProtoReflect.Descriptor instead.
-func (*ListModulesResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use Tool.ProtoReflect.Descriptor instead.
+func (*Tool) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{13}
 }
 
-func (x *ListModulesResponse) GetModules() []*Module {
-	if x != nil {
-		return x.Modules
-	}
-	return nil
-}
-
-func (x *ListModulesResponse) GetPagination() *Pagination {
-	if x != nil {
-		return x.Pagination
-	}
-	return nil
-}
-
-func (x *ListModulesResponse) GetTotal() uint64 {
-	if x != nil {
-		return x.Total
-	}
-	return 0
-}
-
-func (x *ListModulesResponse) GetSort() *Sort {
+func (x *Tool) GetName() string {
 	if x != nil {
-		return x.Sort
+		return x.Name
 	}
-	return nil
+	return ""
 }
 
-func (x *ListModulesResponse) GetError() *Error {
+func (x *Tool) GetVersion() string {
 	if x != nil {
-		return x.Error
+		return x.Version
 	}
-	return nil
+	return ""
 }
 
-// `POST /api/v1/search:`
-// Search for modules based on filter params provided (which should be any
-// dimension of the module schema, or string search in any metadata value).
-// Return a paginated list of matching modules.
-type SearchModulesRequest struct {
+// Details about a wasm module graph
+type ModuleGraph struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	// ID for this module, generated by the database.
-	Id *int64 `protobuf:"varint,1,opt,name=id,proto3,oneof" json:"id,omitempty"`
-	// original name of the binary module file
-	Hash *string `protobuf:"bytes,3,opt,name=hash,proto3,oneof" json:"hash,omitempty"`
-	// function imports called by the module (see:
-	// <https://github.com/WebAssembly/design/blob/main/Modules.md#imports>)
-	Imports []*Import `protobuf:"bytes,4,rep,name=imports,proto3" json:"imports,omitempty"`
-	// function exports provided by the module (see:
-	// <https://github.com/WebAssembly/design/blob/main/Modules.md#exports>)
-	Exports []*Export `protobuf:"bytes,5,rep,name=exports,proto3" json:"exports,omitempty"`
-	// minimum size in bytes of the module
-	MinSize *uint64 `protobuf:"varint,6,opt,name=min_size,json=minSize,proto3,oneof" json:"min_size,omitempty"`
-	// maximum size in bytes of the module
-	MaxSize *uint64 `protobuf:"varint,7,opt,name=max_size,json=maxSize,proto3,oneof" json:"max_size,omitempty"`
-	// optional path or locator to the module (TODO: maybe this is better stored
-	// as metadata)
-	Location *string `protobuf:"bytes,8,opt,name=location,proto3,oneof" json:"location,omitempty"`
-	// programming language used to produce this module
-	SourceLanguage *SourceLanguage `protobuf:"varint,9,opt,name=source_language,json=sourceLanguage,proto3,enum=SourceLanguage,oneof" json:"source_language,omitempty"`
-	// arbitrary metadata provided by the operator of this module
-	Metadata map[string]string `protobuf:"bytes,10,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	// timestamp when this module was loaded and stored
-	InsertedBefore *timestamppb.Timestamp `protobuf:"bytes,11,opt,name=inserted_before,json=insertedBefore,proto3,oneof" json:"inserted_before,omitempty"`
-	// timestamp when this module was loaded and stored
-	InsertedAfter *timestamppb.Timestamp `protobuf:"bytes,12,opt,name=inserted_after,json=insertedAfter,proto3,oneof" json:"inserted_after,omitempty"`
-	// the interned strings stored in the wasm binary (panic/abort messages, etc.)
-	Strings []string `protobuf:"bytes,13,rep,name=strings,proto3" json:"strings,omitempty"`
-	// match on any function name in an import or export.
-	FunctionName *string `protobuf:"bytes,14,opt,name=function_name,json=functionName,proto3,oneof" json:"function_name,omitempty"`
-	// match on the module name e.g. `env` or `wasi_snapshot_preview1`
-	ModuleName *string     `protobuf:"bytes,15,opt,name=module_name,json=moduleName,proto3,oneof" json:"module_name,omitempty"`
-	Pagination *Pagination `protobuf:"bytes,16,opt,name=pagination,proto3" json:"pagination,omitempty"`
-	Sort       *Sort       `protobuf:"bytes,17,opt,name=sort,proto3" json:"sort,omitempty"`
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+	// the serialized graph in json format
+	JsonBytes []byte `protobuf:"bytes,2,opt,name=json_bytes,json=jsonBytes,proto3" json:"json_bytes,omitempty"`
 }
 
-func (x *SearchModulesRequest) Reset() {
-	*x = SearchModulesRequest{}
+func (x *ModuleGraph) Reset() {
+	*x = ModuleGraph{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[14]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1301,13 +1546,13 @@ func (x *SearchModulesRequest) Reset() {
 	}
 }
 
-func (x *SearchModulesRequest) String() string {
+func (x *ModuleGraph) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SearchModulesRequest) ProtoMessage() {}
+func (*ModuleGraph) ProtoMessage() {}
 
-func (x *SearchModulesRequest) ProtoReflect() protoreflect.Message {
+func (x *ModuleGraph) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[14]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -1319,155 +1564,109 @@ func (x *SearchModulesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SearchModulesRequest.ProtoReflect.Descriptor instead.
-func (*SearchModulesRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use ModuleGraph.ProtoReflect.Descriptor instead.
+func (*ModuleGraph) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{14}
 }
 
-func (x *SearchModulesRequest) GetId() int64 {
-	if x != nil && x.Id != nil {
-		return *x.Id
+func (x *ModuleGraph) GetId() int64 {
+	if x != nil {
+		return x.Id
 	}
 	return 0
 }
 
-func (x *SearchModulesRequest) GetHash() string {
-	if x != nil && x.Hash != nil {
-		return *x.Hash
-	}
-	return ""
-}
-
-func (x *SearchModulesRequest) GetImports() []*Import {
+func (x *ModuleGraph) GetJsonBytes() []byte {
 	if x != nil {
-		return x.Imports
+		return x.JsonBytes
 	}
 	return nil
 }
 
-func (x *SearchModulesRequest) GetExports() []*Export {
-	if x != nil {
-		return x.Exports
-	}
-	return nil
+// An error message indicating a problem in the API.
+type Error struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Code    int32  `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	Message string `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
 }
 
-func (x *SearchModulesRequest) GetMinSize() uint64 {
-	if x != nil && x.MinSize != nil {
-		return *x.MinSize
+func (x *Error) Reset() {
+	*x = Error{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[15]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return 0
 }
 
-func (x *SearchModulesRequest) GetMaxSize() uint64 {
-	if x != nil && x.MaxSize != nil {
-		return *x.MaxSize
-	}
-	return 0
+func (x *Error) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-func (x *SearchModulesRequest) GetLocation() string {
-	if x != nil && x.Location != nil {
-		return *x.Location
+func (*Error) ProtoMessage() {}
+
+func (x *Error) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[15]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return ""
+	return mi.MessageOf(x)
 }
 
-func (x *SearchModulesRequest) GetSourceLanguage() SourceLanguage {
-	if x != nil && x.SourceLanguage != nil {
-		return *x.SourceLanguage
-	}
-	return SourceLanguage_Unknown
+// Deprecated: Use Error.ProtoReflect.Descriptor instead.
+func (*Error) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{15}
 }
 
-func (x *SearchModulesRequest) GetMetadata() map[string]string {
+func (x *Error) GetCode() int32 {
 	if x != nil {
-		return x.Metadata
+		return x.Code
 	}
-	return nil
+	return 0
 }
 
-func (x *SearchModulesRequest) GetInsertedBefore() *timestamppb.Timestamp {
-	if x != nil {
-		return x.InsertedBefore
-	}
-	return nil
-}
-
-func (x *SearchModulesRequest) GetInsertedAfter() *timestamppb.Timestamp {
-	if x != nil {
-		return x.InsertedAfter
-	}
-	return nil
-}
-
-func (x *SearchModulesRequest) GetStrings() []string {
+func (x *Error) GetMessage() string {
 	if x != nil {
-		return x.Strings
-	}
-	return nil
-}
-
-func (x *SearchModulesRequest) GetFunctionName() string {
-	if x != nil && x.FunctionName != nil {
-		return *x.FunctionName
-	}
-	return ""
-}
-
-func (x *SearchModulesRequest) GetModuleName() string {
-	if x != nil && x.ModuleName != nil {
-		return *x.ModuleName
+		return x.Message
 	}
 	return ""
 }
 
-func (x *SearchModulesRequest) GetPagination() *Pagination {
-	if x != nil {
-		return x.Pagination
-	}
-	return nil
-}
-
-func (x *SearchModulesRequest) GetSort() *Sort {
-	if x != nil {
-		return x.Sort
-	}
-	return nil
-}
-
-// The message returned in response to a `SearchModulesRequest`.
-type SearchModulesResponse struct {
+// Control/limit the way results are paginated when working with large
+// responses.
+type Pagination struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Modules    []*Module   `protobuf:"bytes,1,rep,name=modules,proto3" json:"modules,omitempty"`
-	Pagination *Pagination `protobuf:"bytes,2,opt,name=pagination,proto3" json:"pagination,omitempty"`
-	// the full count of results in the database (not the count of this message's
-	// `modules`).
-	Total uint64 `protobuf:"varint,3,opt,name=total,proto3" json:"total,omitempty"`
-	Sort  *Sort  `protobuf:"bytes,4,opt,name=sort,proto3" json:"sort,omitempty"`
-	Error *Error `protobuf:"bytes,5,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Limit  uint32 `protobuf:"varint,1,opt,name=limit,proto3" json:"limit,omitempty"`
+	Offset uint32 `protobuf:"varint,2,opt,name=offset,proto3" json:"offset,omitempty"`
 }
 
-func (x *SearchModulesResponse) Reset() {
-	*x = SearchModulesResponse{}
+func (x *Pagination) Reset() {
+	*x = Pagination{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[15]
+		mi := &file_proto_v1_api_proto_msgTypes[16]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *SearchModulesResponse) String() string {
+func (x *Pagination) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SearchModulesResponse) ProtoMessage() {}
+func (*Pagination) ProtoMessage() {}
 
-func (x *SearchModulesResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[15]
+func (x *Pagination) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[16]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1478,73 +1677,52 @@ func (x *SearchModulesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SearchModulesResponse.ProtoReflect.Descriptor instead.
-func (*SearchModulesResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{15}
-}
-
-func (x *SearchModulesResponse) GetModules() []*Module {
-	if x != nil {
-		return x.Modules
-	}
-	return nil
-}
-
-func (x *SearchModulesResponse) GetPagination() *Pagination {
-	if x != nil {
-		return x.Pagination
-	}
-	return nil
+// Deprecated: Use Pagination.ProtoReflect.Descriptor instead.
+func (*Pagination) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{16}
 }
 
-func (x *SearchModulesResponse) GetTotal() uint64 {
+func (x *Pagination) GetLimit() uint32 {
 	if x != nil {
-		return x.Total
+		return x.Limit
 	}
 	return 0
 }
 
-func (x *SearchModulesResponse) GetSort() *Sort {
-	if x != nil {
-		return x.Sort
-	}
-	return nil
-}
-
-func (x *SearchModulesResponse) GetError() *Error {
+func (x *Pagination) GetOffset() uint32 {
 	if x != nil {
-		return x.Error
+		return x.Offset
 	}
-	return nil
+	return 0
 }
 
-// `DELETE /api/v1/module:`
-// Remove a module from the database by its ID. Return the module IDs & hashes.
-type DeleteModulesRequest struct {
+// Determine how to sort results from the API
+type Sort struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	ModuleIds []int64 `protobuf:"varint,1,rep,packed,name=module_ids,json=moduleIds,proto3" json:"module_ids,omitempty"`
+	Direction Direction `protobuf:"varint,1,opt,name=direction,proto3,enum=Direction" json:"direction,omitempty"`
+	Field     Field     `protobuf:"varint,2,opt,name=field,proto3,enum=Field" json:"field,omitempty"`
 }
 
-func (x *DeleteModulesRequest) Reset() {
-	*x = DeleteModulesRequest{}
+func (x *Sort) Reset() {
+	*x = Sort{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[16]
+		mi := &file_proto_v1_api_proto_msgTypes[17]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeleteModulesRequest) String() string {
+func (x *Sort) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteModulesRequest) ProtoMessage() {}
+func (*Sort) ProtoMessage() {}
 
-func (x *DeleteModulesRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[16]
+func (x *Sort) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[17]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1555,45 +1733,59 @@ func (x *DeleteModulesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteModulesRequest.ProtoReflect.Descriptor instead.
-func (*DeleteModulesRequest) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{16}
+// Deprecated: Use Sort.ProtoReflect.Descriptor instead.
+func (*Sort) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{17}
 }
 
-func (x *DeleteModulesRequest) GetModuleIds() []int64 {
+func (x *Sort) GetDirection() Direction {
 	if x != nil {
-		return x.ModuleIds
+		return x.Direction
 	}
-	return nil
+	return Direction_Desc
 }
 
-// The message returned in response to a `DeleteModulesRequest`.
-type DeleteModulesResponse struct {
+func (x *Sort) GetField() Field {
+	if x != nil {
+		return x.Field
+	}
+	return Field_CreatedAt
+}
+
+// `PUT /api/v1/module:`
+// Insert a module, extract data from binary. Return the module ID & hash.
+type CreateModuleRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	ModuleIdHash map[int64]string `protobuf:"bytes,1,rep,name=module_id_hash,json=moduleIdHash,proto3" json:"module_id_hash,omitempty" protobuf_key:"varint,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	Error        *Error           `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Wasm     []byte            `protobuf:"bytes,1,opt,name=wasm,proto3" json:"wasm,omitempty"`
+	Metadata map[string]string `protobuf:"bytes,2,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// a valid URL with a scheme prefix e.g. `s3://`, `file://`, `https://`
+	Location *string `protobuf:"bytes,3,opt,name=location,proto3,oneof" json:"location,omitempty"`
+	// tags to attach to the module
+	Tags []string `protobuf:"bytes,4,rep,name=tags,proto3" json:"tags,omitempty"`
+	// version of the module, e.g. `1.2.0`
+	Version *string `protobuf:"bytes,5,opt,name=version,proto3,oneof" json:"version,omitempty"`
 }
 
-func (x *DeleteModulesResponse) Reset() {
-	*x = DeleteModulesResponse{}
+func (x *CreateModuleRequest) Reset() {
+	*x = CreateModuleRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[17]
+		mi := &file_proto_v1_api_proto_msgTypes[18]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeleteModulesResponse) String() string {
+func (x *CreateModuleRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteModulesResponse) ProtoMessage() {}
+func (*CreateModuleRequest) ProtoMessage() {}
 
-func (x *DeleteModulesResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[17]
+func (x *CreateModuleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[18]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1604,55 +1796,74 @@ func (x *DeleteModulesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteModulesResponse.ProtoReflect.Descriptor instead.
-func (*DeleteModulesResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{17}
+// Deprecated: Use CreateModuleRequest.ProtoReflect.Descriptor instead.
+func (*CreateModuleRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{18}
 }
 
-func (x *DeleteModulesResponse) GetModuleIdHash() map[int64]string {
+func (x *CreateModuleRequest) GetWasm() []byte {
 	if x != nil {
-		return x.ModuleIdHash
+		return x.Wasm
 	}
 	return nil
 }
 
-func (x *DeleteModulesResponse) GetError() *Error {
+func (x *CreateModuleRequest) GetMetadata() map[string]string {
 	if x != nil {
-		return x.Error
+		return x.Metadata
 	}
 	return nil
 }
 
-// `POST /api/v1/audit:`
-// Return a list of modules which match the outcome requirements using the provided checkfile.
-type AuditModulesRequest struct {
+func (x *CreateModuleRequest) GetLocation() string {
+	if x != nil && x.Location != nil {
+		return *x.Location
+	}
+	return ""
+}
+
+func (x *CreateModuleRequest) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
+func (x *CreateModuleRequest) GetVersion() string {
+	if x != nil && x.Version != nil {
+		return *x.Version
+	}
+	return ""
+}
+
+// The message returned in response to a `CreateModuleRequest`.
+type CreateModuleResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// the YAML checkfile (e.g. mod.yaml) bytes
-	Checkfile  []byte       `protobuf:"bytes,1,opt,name=checkfile,proto3" json:"checkfile,omitempty"`
-	Outcome    AuditOutcome `protobuf:"varint,2,opt,name=outcome,proto3,enum=AuditOutcome" json:"outcome,omitempty"`
-	Pagination *Pagination  `protobuf:"bytes,3,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	ModuleId int64  `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+	Hash     string `protobuf:"bytes,2,opt,name=hash,proto3" json:"hash,omitempty"`
+	Error    *Error `protobuf:"bytes,3,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *AuditModulesRequest) Reset() {
-	*x = AuditModulesRequest{}
+func (x *CreateModuleResponse) Reset() {
+	*x = CreateModuleResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[18]
+		mi := &file_proto_v1_api_proto_msgTypes[19]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AuditModulesRequest) String() string {
+func (x *CreateModuleResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AuditModulesRequest) ProtoMessage() {}
+func (*CreateModuleResponse) ProtoMessage() {}
 
-func (x *AuditModulesRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[18]
+func (x *CreateModuleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[19]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1663,65 +1874,59 @@ func (x *AuditModulesRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AuditModulesRequest.ProtoReflect.Descriptor instead.
-func (*AuditModulesRequest) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{18}
+// Deprecated: Use CreateModuleResponse.ProtoReflect.Descriptor instead.
+func (*CreateModuleResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{19}
 }
 
-func (x *AuditModulesRequest) GetCheckfile() []byte {
+func (x *CreateModuleResponse) GetModuleId() int64 {
 	if x != nil {
-		return x.Checkfile
+		return x.ModuleId
 	}
-	return nil
+	return 0
 }
 
-func (x *AuditModulesRequest) GetOutcome() AuditOutcome {
+func (x *CreateModuleResponse) GetHash() string {
 	if x != nil {
-		return x.Outcome
+		return x.Hash
 	}
-	return AuditOutcome_PASS
+	return ""
 }
 
-func (x *AuditModulesRequest) GetPagination() *Pagination {
+func (x *CreateModuleResponse) GetError() *Error {
 	if x != nil {
-		return x.Pagination
+		return x.Error
 	}
 	return nil
 }
 
-// The message returned in response to a `AuditModulesRequest`.
-type AuditModulesResponse struct {
+// `POST /api/v1/module:`
+// Return a single module.
+type GetModuleRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// each record contains the ID of the invalid Module which failed the audit, as well as the failure
-	// report produced by the validation check (encoded in JSON)
-	InvalidModuleReport map[int64][]byte `protobuf:"bytes,1,rep,name=invalid_module_report,json=invalidModuleReport,proto3" json:"invalid_module_report,omitempty" protobuf_key:"varint,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
-	Pagination          *Pagination      `protobuf:"bytes,2,opt,name=pagination,proto3" json:"pagination,omitempty"`
-	// the full count of results in the database (not the count of this message's
-	// `modules`).
-	Total uint64 `protobuf:"varint,3,opt,name=total,proto3" json:"total,omitempty"`
-	Error *Error `protobuf:"bytes,4,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	ModuleId int64 `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
 }
 
-func (x *AuditModulesResponse) Reset() {
-	*x = AuditModulesResponse{}
+func (x *GetModuleRequest) Reset() {
+	*x = GetModuleRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[19]
+		mi := &file_proto_v1_api_proto_msgTypes[20]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AuditModulesResponse) String() string {
+func (x *GetModuleRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AuditModulesResponse) ProtoMessage() {}
+func (*GetModuleRequest) ProtoMessage() {}
 
-func (x *AuditModulesResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[19]
+func (x *GetModuleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[20]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1732,69 +1937,45 @@ func (x *AuditModulesResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AuditModulesResponse.ProtoReflect.Descriptor instead.
-func (*AuditModulesResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{19}
-}
-
-func (x *AuditModulesResponse) GetInvalidModuleReport() map[int64][]byte {
-	if x != nil {
-		return x.InvalidModuleReport
-	}
-	return nil
-}
-
-func (x *AuditModulesResponse) GetPagination() *Pagination {
-	if x != nil {
-		return x.Pagination
-	}
-	return nil
+// Deprecated: Use GetModuleRequest.ProtoReflect.Descriptor instead.
+func (*GetModuleRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{20}
 }
 
-func (x *AuditModulesResponse) GetTotal() uint64 {
+func (x *GetModuleRequest) GetModuleId() int64 {
 	if x != nil {
-		return x.Total
+		return x.ModuleId
 	}
 	return 0
 }
 
-func (x *AuditModulesResponse) GetError() *Error {
-	if x != nil {
-		return x.Error
-	}
-	return nil
-}
-
-// `POST /api/v1/diff:`
-// Return the diff of two modules
-type DiffRequest struct {
+// The message returned in response to a `GetModuleRequest`.
+type GetModuleResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Module1       int64 `protobuf:"varint,1,opt,name=module1,proto3" json:"module1,omitempty"`
-	Module2       int64 `protobuf:"varint,2,opt,name=module2,proto3" json:"module2,omitempty"`
-	ColorTerminal bool  `protobuf:"varint,3,opt,name=color_terminal,json=colorTerminal,proto3" json:"color_terminal,omitempty"`
-	WithContext   bool  `protobuf:"varint,4,opt,name=with_context,json=withContext,proto3" json:"with_context,omitempty"`
+	Module *Module `protobuf:"bytes,1,opt,name=module,proto3" json:"module,omitempty"`
+	Error  *Error  `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *DiffRequest) Reset() {
-	*x = DiffRequest{}
+func (x *GetModuleResponse) Reset() {
+	*x = GetModuleResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[20]
+		mi := &file_proto_v1_api_proto_msgTypes[21]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DiffRequest) String() string {
+func (x *GetModuleResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DiffRequest) ProtoMessage() {}
+func (*GetModuleResponse) ProtoMessage() {}
 
-func (x *DiffRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[20]
+func (x *GetModuleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[21]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1805,67 +1986,53 @@ func (x *DiffRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DiffRequest.ProtoReflect.Descriptor instead.
-func (*DiffRequest) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{20}
-}
-
-func (x *DiffRequest) GetModule1() int64 {
-	if x != nil {
-		return x.Module1
-	}
-	return 0
-}
-
-func (x *DiffRequest) GetModule2() int64 {
-	if x != nil {
-		return x.Module2
-	}
-	return 0
+// Deprecated: Use GetModuleResponse.ProtoReflect.Descriptor instead.
+func (*GetModuleResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{21}
 }
 
-func (x *DiffRequest) GetColorTerminal() bool {
+func (x *GetModuleResponse) GetModule() *Module {
 	if x != nil {
-		return x.ColorTerminal
+		return x.Module
 	}
-	return false
+	return nil
 }
 
-func (x *DiffRequest) GetWithContext() bool {
+func (x *GetModuleResponse) GetError() *Error {
 	if x != nil {
-		return x.WithContext
+		return x.Error
 	}
-	return false
+	return nil
 }
 
-// The message returned in response to `DiffRequest`, contains a text representation of the difference
-// between the two specified modules.
-type DiffResponse struct {
+// `POST /api/v1/modules:`
+// Return paginated list of all modules.
+type ListModulesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Diff  string `protobuf:"bytes,1,opt,name=diff,proto3" json:"diff,omitempty"`
-	Error *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Pagination *Pagination `protobuf:"bytes,1,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	Sort       *Sort       `protobuf:"bytes,2,opt,name=sort,proto3" json:"sort,omitempty"`
 }
 
-func (x *DiffResponse) Reset() {
-	*x = DiffResponse{}
+func (x *ListModulesRequest) Reset() {
+	*x = ListModulesRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[21]
+		mi := &file_proto_v1_api_proto_msgTypes[22]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DiffResponse) String() string {
+func (x *ListModulesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DiffResponse) ProtoMessage() {}
+func (*ListModulesRequest) ProtoMessage() {}
 
-func (x *DiffResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[21]
+func (x *ListModulesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[22]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1876,61 +2043,57 @@ func (x *DiffResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DiffResponse.ProtoReflect.Descriptor instead.
-func (*DiffResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{21}
+// Deprecated: Use ListModulesRequest.ProtoReflect.Descriptor instead.
+func (*ListModulesRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{22}
 }
 
-func (x *DiffResponse) GetDiff() string {
+func (x *ListModulesRequest) GetPagination() *Pagination {
 	if x != nil {
-		return x.Diff
+		return x.Pagination
 	}
-	return ""
+	return nil
 }
 
-func (x *DiffResponse) GetError() *Error {
+func (x *ListModulesRequest) GetSort() *Sort {
 	if x != nil {
-		return x.Error
+		return x.Sort
 	}
 	return nil
 }
 
-// `POST /api/v1/validate:`
-// Return the failure report (if applicable) of a wasm module validation against a given checkfile.
-type ValidateModuleRequest struct {
+// The message returned in response to a `ListModulesRequest`.
+type ListModulesResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// the YAML checkfile (e.g. mod.yaml) bytes
-	Checkfile []byte `protobuf:"bytes,1,opt,name=checkfile,proto3" json:"checkfile,omitempty"`
-	// module_input is either an existing `module_id` that is known to the database, or the bytes of
-	// a raw wasm module. It is used to validate against the given checkfile.
-	//
-	// Types that are assignable to ModuleInput:
-	//
-	//	*ValidateModuleRequest_Module
-	//	*ValidateModuleRequest_ModuleId
-	ModuleInput isValidateModuleRequest_ModuleInput `protobuf_oneof:"module_input"`
+	Modules    []*Module   `protobuf:"bytes,1,rep,name=modules,proto3" json:"modules,omitempty"`
+	Pagination *Pagination `protobuf:"bytes,2,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	// the full count of results in the database (not the count of this message's
+	// `modules`).
+	Total uint64 `protobuf:"varint,3,opt,name=total,proto3" json:"total,omitempty"`
+	Sort  *Sort  `protobuf:"bytes,4,opt,name=sort,proto3" json:"sort,omitempty"`
+	Error *Error `protobuf:"bytes,5,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *ValidateModuleRequest) Reset() {
-	*x = ValidateModuleRequest{}
+func (x *ListModulesResponse) Reset() {
+	*x = ListModulesResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[22]
+		mi := &file_proto_v1_api_proto_msgTypes[23]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ValidateModuleRequest) String() string {
+func (x *ListModulesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ValidateModuleRequest) ProtoMessage() {}
+func (*ListModulesResponse) ProtoMessage() {}
 
-func (x *ValidateModuleRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[22]
+func (x *ListModulesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[23]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1941,82 +2104,118 @@ func (x *ValidateModuleRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ValidateModuleRequest.ProtoReflect.Descriptor instead.
-func (*ValidateModuleRequest) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{22}
+// Deprecated: Use ListModulesResponse.ProtoReflect.Descriptor instead.
+func (*ListModulesResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{23}
 }
 
-func (x *ValidateModuleRequest) GetCheckfile() []byte {
+func (x *ListModulesResponse) GetModules() []*Module {
 	if x != nil {
-		return x.Checkfile
-	}
-	return nil
-}
-
-func (m *ValidateModuleRequest) GetModuleInput() isValidateModuleRequest_ModuleInput {
-	if m != nil {
-		return m.ModuleInput
+		return x.Modules
 	}
 	return nil
 }
 
-func (x *ValidateModuleRequest) GetModule() []byte {
-	if x, ok := x.GetModuleInput().(*ValidateModuleRequest_Module); ok {
-		return x.Module
+func (x *ListModulesResponse) GetPagination() *Pagination {
+	if x != nil {
+		return x.Pagination
 	}
 	return nil
 }
 
-func (x *ValidateModuleRequest) GetModuleId() int64 {
-	if x, ok := x.GetModuleInput().(*ValidateModuleRequest_ModuleId); ok {
-		return x.ModuleId
+func (x *ListModulesResponse) GetTotal() uint64 {
+	if x != nil {
+		return x.Total
 	}
 	return 0
 }
 
-type isValidateModuleRequest_ModuleInput interface {
-	isValidateModuleRequest_ModuleInput()
-}
-
-type ValidateModuleRequest_Module struct {
-	Module []byte `protobuf:"bytes,2,opt,name=module,proto3,oneof"`
+func (x *ListModulesResponse) GetSort() *Sort {
+	if x != nil {
+		return x.Sort
+	}
+	return nil
 }
 
-type ValidateModuleRequest_ModuleId struct {
-	ModuleId int64 `protobuf:"varint,3,opt,name=module_id,json=moduleId,proto3,oneof"`
+func (x *ListModulesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
 }
 
-func (*ValidateModuleRequest_Module) isValidateModuleRequest_ModuleInput() {}
-
-func (*ValidateModuleRequest_ModuleId) isValidateModuleRequest_ModuleInput() {}
-
-// The failure report produced by the validation check (encoded in JSON).
-type ValidateModuleResponse struct {
+// `POST /api/v1/search:`
+// Search for modules based on filter params provided (which should be any
+// dimension of the module schema, or string search in any metadata value).
+// Return a paginated list of matching modules.
+type SearchModulesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	InvalidModuleReport []byte `protobuf:"bytes,1,opt,name=invalid_module_report,json=invalidModuleReport,proto3" json:"invalid_module_report,omitempty"`
-	Error               *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
-}
-
-func (x *ValidateModuleResponse) Reset() {
-	*x = ValidateModuleResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[23]
+	// ID for this module, generated by the database.
+	Id *int64 `protobuf:"varint,1,opt,name=id,proto3,oneof" json:"id,omitempty"`
+	// original name of the binary module file
+	Hash *string `protobuf:"bytes,3,opt,name=hash,proto3,oneof" json:"hash,omitempty"`
+	// function imports called by the module (see:
+	// <https://github.com/WebAssembly/design/blob/main/Modules.md#imports>)
+	Imports []*Import `protobuf:"bytes,4,rep,name=imports,proto3" json:"imports,omitempty"`
+	// function exports provided by the module (see:
+	// <https://github.com/WebAssembly/design/blob/main/Modules.md#exports>)
+	Exports []*Export `protobuf:"bytes,5,rep,name=exports,proto3" json:"exports,omitempty"`
+	// minimum size in bytes of the module
+	MinSize *uint64 `protobuf:"varint,6,opt,name=min_size,json=minSize,proto3,oneof" json:"min_size,omitempty"`
+	// maximum size in bytes of the module
+	MaxSize *uint64 `protobuf:"varint,7,opt,name=max_size,json=maxSize,proto3,oneof" json:"max_size,omitempty"`
+	// optional path or locator to the module (TODO: maybe this is better stored
+	// as metadata)
+	Location *string `protobuf:"bytes,8,opt,name=location,proto3,oneof" json:"location,omitempty"`
+	// programming language used to produce this module
+	SourceLanguage *SourceLanguage `protobuf:"varint,9,opt,name=source_language,json=sourceLanguage,proto3,enum=SourceLanguage,oneof" json:"source_language,omitempty"`
+	// arbitrary metadata provided by the operator of this module
+	Metadata map[string]string `protobuf:"bytes,10,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// timestamp when this module was loaded and stored
+	InsertedBefore *timestamppb.Timestamp `protobuf:"bytes,11,opt,name=inserted_before,json=insertedBefore,proto3,oneof" json:"inserted_before,omitempty"`
+	// timestamp when this module was loaded and stored
+	InsertedAfter *timestamppb.Timestamp `protobuf:"bytes,12,opt,name=inserted_after,json=insertedAfter,proto3,oneof" json:"inserted_after,omitempty"`
+	// the interned strings stored in the wasm binary (panic/abort messages, etc.)
+	Strings []string `protobuf:"bytes,13,rep,name=strings,proto3" json:"strings,omitempty"`
+	// match on any function name in an import or export.
+	FunctionName *string `protobuf:"bytes,14,opt,name=function_name,json=functionName,proto3,oneof" json:"function_name,omitempty"`
+	// match on the module name e.g. `env` or `wasi_snapshot_preview1`
+	ModuleName *string     `protobuf:"bytes,15,opt,name=module_name,json=moduleName,proto3,oneof" json:"module_name,omitempty"`
+	Pagination *Pagination `protobuf:"bytes,16,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	Sort       *Sort       `protobuf:"bytes,17,opt,name=sort,proto3" json:"sort,omitempty"`
+	// match modules which have all of the provided tags
+	Tags []string `protobuf:"bytes,18,rep,name=tags,proto3" json:"tags,omitempty"`
+	// match on the exact version of the module
+	Version *string `protobuf:"bytes,19,opt,name=version,proto3,oneof" json:"version,omitempty"`
+	// minimum cyclomatic complexity of the module
+	MinComplexity *uint32 `protobuf:"varint,20,opt,name=min_complexity,json=minComplexity,proto3,oneof" json:"min_complexity,omitempty"`
+	// maximum cyclomatic complexity of the module
+	MaxComplexity *uint32 `protobuf:"varint,21,opt,name=max_complexity,json=maxComplexity,proto3,oneof" json:"max_complexity,omitempty"`
+	// match modules which import from all of the provided namespaces, e.g.
+	// `wasi_snapshot_preview1`
+	Namespaces []string `protobuf:"bytes,22,rep,name=namespaces,proto3" json:"namespaces,omitempty"`
+}
+
+func (x *SearchModulesRequest) Reset() {
+	*x = SearchModulesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[24]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ValidateModuleResponse) String() string {
+func (x *SearchModulesRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ValidateModuleResponse) ProtoMessage() {}
+func (*SearchModulesRequest) ProtoMessage() {}
 
-func (x *ValidateModuleResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[23]
+func (x *SearchModulesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[24]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2027,159 +2226,190 @@ func (x *ValidateModuleResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ValidateModuleResponse.ProtoReflect.Descriptor instead.
-func (*ValidateModuleResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{23}
+// Deprecated: Use SearchModulesRequest.ProtoReflect.Descriptor instead.
+func (*SearchModulesRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{24}
 }
 
-func (x *ValidateModuleResponse) GetInvalidModuleReport() []byte {
+func (x *SearchModulesRequest) GetId() int64 {
+	if x != nil && x.Id != nil {
+		return *x.Id
+	}
+	return 0
+}
+
+func (x *SearchModulesRequest) GetHash() string {
+	if x != nil && x.Hash != nil {
+		return *x.Hash
+	}
+	return ""
+}
+
+func (x *SearchModulesRequest) GetImports() []*Import {
 	if x != nil {
-		return x.InvalidModuleReport
+		return x.Imports
 	}
 	return nil
 }
 
-func (x *ValidateModuleResponse) GetError() *Error {
+func (x *SearchModulesRequest) GetExports() []*Export {
 	if x != nil {
-		return x.Error
+		return x.Exports
 	}
 	return nil
 }
 
-// `POST /api/v1/module_graph:`
-// Return a single module_graph.
-type GetModuleGraphRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+func (x *SearchModulesRequest) GetMinSize() uint64 {
+	if x != nil && x.MinSize != nil {
+		return *x.MinSize
+	}
+	return 0
+}
 
-	ModuleId int64 `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+func (x *SearchModulesRequest) GetMaxSize() uint64 {
+	if x != nil && x.MaxSize != nil {
+		return *x.MaxSize
+	}
+	return 0
 }
 
-func (x *GetModuleGraphRequest) Reset() {
-	*x = GetModuleGraphRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[24]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *SearchModulesRequest) GetLocation() string {
+	if x != nil && x.Location != nil {
+		return *x.Location
 	}
+	return ""
 }
 
-func (x *GetModuleGraphRequest) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *SearchModulesRequest) GetSourceLanguage() SourceLanguage {
+	if x != nil && x.SourceLanguage != nil {
+		return *x.SourceLanguage
+	}
+	return SourceLanguage_Unknown
 }
 
-func (*GetModuleGraphRequest) ProtoMessage() {}
+func (x *SearchModulesRequest) GetMetadata() map[string]string {
+	if x != nil {
+		return x.Metadata
+	}
+	return nil
+}
 
-func (x *GetModuleGraphRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[24]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *SearchModulesRequest) GetInsertedBefore() *timestamppb.Timestamp {
+	if x != nil {
+		return x.InsertedBefore
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use GetModuleGraphRequest.ProtoReflect.Descriptor instead.
-func (*GetModuleGraphRequest) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{24}
+func (x *SearchModulesRequest) GetInsertedAfter() *timestamppb.Timestamp {
+	if x != nil {
+		return x.InsertedAfter
+	}
+	return nil
 }
 
-func (x *GetModuleGraphRequest) GetModuleId() int64 {
+func (x *SearchModulesRequest) GetStrings() []string {
 	if x != nil {
-		return x.ModuleId
+		return x.Strings
 	}
-	return 0
+	return nil
 }
 
-// The message returned in response to a `GetModuleGraphRequest`.
-type GetModuleGraphResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+func (x *SearchModulesRequest) GetFunctionName() string {
+	if x != nil && x.FunctionName != nil {
+		return *x.FunctionName
+	}
+	return ""
+}
 
-	ModuleGraph *ModuleGraph `protobuf:"bytes,1,opt,name=module_graph,json=moduleGraph,proto3" json:"module_graph,omitempty"`
-	Error       *Error       `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+func (x *SearchModulesRequest) GetModuleName() string {
+	if x != nil && x.ModuleName != nil {
+		return *x.ModuleName
+	}
+	return ""
 }
 
-func (x *GetModuleGraphResponse) Reset() {
-	*x = GetModuleGraphResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[25]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *SearchModulesRequest) GetPagination() *Pagination {
+	if x != nil {
+		return x.Pagination
 	}
+	return nil
 }
 
-func (x *GetModuleGraphResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *SearchModulesRequest) GetSort() *Sort {
+	if x != nil {
+		return x.Sort
+	}
+	return nil
 }
 
-func (*GetModuleGraphResponse) ProtoMessage() {}
+func (x *SearchModulesRequest) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
 
-func (x *GetModuleGraphResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[25]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *SearchModulesRequest) GetVersion() string {
+	if x != nil && x.Version != nil {
+		return *x.Version
 	}
-	return mi.MessageOf(x)
+	return ""
 }
 
-// Deprecated: Use GetModuleGraphResponse.ProtoReflect.Descriptor instead.
-func (*GetModuleGraphResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{25}
+func (x *SearchModulesRequest) GetMinComplexity() uint32 {
+	if x != nil && x.MinComplexity != nil {
+		return *x.MinComplexity
+	}
+	return 0
 }
 
-func (x *GetModuleGraphResponse) GetModuleGraph() *ModuleGraph {
-	if x != nil {
-		return x.ModuleGraph
+func (x *SearchModulesRequest) GetMaxComplexity() uint32 {
+	if x != nil && x.MaxComplexity != nil {
+		return *x.MaxComplexity
 	}
-	return nil
+	return 0
 }
 
-func (x *GetModuleGraphResponse) GetError() *Error {
+func (x *SearchModulesRequest) GetNamespaces() []string {
 	if x != nil {
-		return x.Error
+		return x.Namespaces
 	}
 	return nil
 }
 
-// PUT /api/v1/plugin:
-type InstallPluginRequest struct {
+// The message returned in response to a `SearchModulesRequest`.
+type SearchModulesResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Identifier string  `protobuf:"bytes,1,opt,name=identifier,proto3" json:"identifier,omitempty"`
-	Name       *string `protobuf:"bytes,2,opt,name=name,proto3,oneof" json:"name,omitempty"`
-	Location   string  `protobuf:"bytes,3,opt,name=location,proto3" json:"location,omitempty"`
-	Wasm       []byte  `protobuf:"bytes,4,opt,name=wasm,proto3" json:"wasm,omitempty"` // bytes config = 5;
+	Modules    []*Module   `protobuf:"bytes,1,rep,name=modules,proto3" json:"modules,omitempty"`
+	Pagination *Pagination `protobuf:"bytes,2,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	// the full count of results in the database (not the count of this message's
+	// `modules`).
+	Total uint64 `protobuf:"varint,3,opt,name=total,proto3" json:"total,omitempty"`
+	Sort  *Sort  `protobuf:"bytes,4,opt,name=sort,proto3" json:"sort,omitempty"`
+	Error *Error `protobuf:"bytes,5,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *InstallPluginRequest) Reset() {
-	*x = InstallPluginRequest{}
+func (x *SearchModulesResponse) Reset() {
+	*x = SearchModulesResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[26]
+		mi := &file_proto_v1_api_proto_msgTypes[25]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *InstallPluginRequest) String() string {
+func (x *SearchModulesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InstallPluginRequest) ProtoMessage() {}
+func (*SearchModulesResponse) ProtoMessage() {}
 
-func (x *InstallPluginRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[26]
+func (x *SearchModulesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[25]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2190,50 +2420,107 @@ func (x *InstallPluginRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InstallPluginRequest.ProtoReflect.Descriptor instead.
-func (*InstallPluginRequest) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{26}
+// Deprecated: Use SearchModulesResponse.ProtoReflect.Descriptor instead.
+func (*SearchModulesResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{25}
 }
 
-func (x *InstallPluginRequest) GetIdentifier() string {
+func (x *SearchModulesResponse) GetModules() []*Module {
 	if x != nil {
-		return x.Identifier
+		return x.Modules
 	}
-	return ""
+	return nil
 }
 
-func (x *InstallPluginRequest) GetName() string {
-	if x != nil && x.Name != nil {
-		return *x.Name
+func (x *SearchModulesResponse) GetPagination() *Pagination {
+	if x != nil {
+		return x.Pagination
 	}
-	return ""
+	return nil
 }
 
-func (x *InstallPluginRequest) GetLocation() string {
+func (x *SearchModulesResponse) GetTotal() uint64 {
 	if x != nil {
-		return x.Location
+		return x.Total
 	}
-	return ""
+	return 0
 }
 
-func (x *InstallPluginRequest) GetWasm() []byte {
+func (x *SearchModulesResponse) GetSort() *Sort {
 	if x != nil {
-		return x.Wasm
+		return x.Sort
 	}
 	return nil
 }
 
-type InstallPluginResponse struct {
+func (x *SearchModulesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// `DELETE /api/v1/module:`
+// Remove a module from the database by its ID. Return the module IDs & hashes.
+type DeleteModulesRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Hash  string `protobuf:"bytes,1,opt,name=hash,proto3" json:"hash,omitempty"`
-	Error *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	ModuleIds []int64 `protobuf:"varint,1,rep,packed,name=module_ids,json=moduleIds,proto3" json:"module_ids,omitempty"`
 }
 
-func (x *InstallPluginResponse) Reset() {
-	*x = InstallPluginResponse{}
+func (x *DeleteModulesRequest) Reset() {
+	*x = DeleteModulesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[26]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteModulesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteModulesRequest) ProtoMessage() {}
+
+func (x *DeleteModulesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[26]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteModulesRequest.ProtoReflect.Descriptor instead.
+func (*DeleteModulesRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{26}
+}
+
+func (x *DeleteModulesRequest) GetModuleIds() []int64 {
+	if x != nil {
+		return x.ModuleIds
+	}
+	return nil
+}
+
+// The message returned in response to a `DeleteModulesRequest`.
+type DeleteModulesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ModuleIdHash map[int64]string `protobuf:"bytes,1,rep,name=module_id_hash,json=moduleIdHash,proto3" json:"module_id_hash,omitempty" protobuf_key:"varint,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	Error        *Error           `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *DeleteModulesResponse) Reset() {
+	*x = DeleteModulesResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[27]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2241,13 +2528,13 @@ func (x *InstallPluginResponse) Reset() {
 	}
 }
 
-func (x *InstallPluginResponse) String() string {
+func (x *DeleteModulesResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InstallPluginResponse) ProtoMessage() {}
+func (*DeleteModulesResponse) ProtoMessage() {}
 
-func (x *InstallPluginResponse) ProtoReflect() protoreflect.Message {
+func (x *DeleteModulesResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[27]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2259,36 +2546,38 @@ func (x *InstallPluginResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InstallPluginResponse.ProtoReflect.Descriptor instead.
-func (*InstallPluginResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use DeleteModulesResponse.ProtoReflect.Descriptor instead.
+func (*DeleteModulesResponse) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{27}
 }
 
-func (x *InstallPluginResponse) GetHash() string {
+func (x *DeleteModulesResponse) GetModuleIdHash() map[int64]string {
 	if x != nil {
-		return x.Hash
+		return x.ModuleIdHash
 	}
-	return ""
+	return nil
 }
 
-func (x *InstallPluginResponse) GetError() *Error {
+func (x *DeleteModulesResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-// DELETE /api/v1/plugin:
-type UninstallPluginRequest struct {
+// `PUT /api/v1/module/tags:`
+// Replace the tags attached to a module. Return the module's resulting tags.
+type TagModuleRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Identifier string `protobuf:"bytes,1,opt,name=identifier,proto3" json:"identifier,omitempty"`
+	ModuleId int64    `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+	Tags     []string `protobuf:"bytes,2,rep,name=tags,proto3" json:"tags,omitempty"`
 }
 
-func (x *UninstallPluginRequest) Reset() {
-	*x = UninstallPluginRequest{}
+func (x *TagModuleRequest) Reset() {
+	*x = TagModuleRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[28]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2296,13 +2585,13 @@ func (x *UninstallPluginRequest) Reset() {
 	}
 }
 
-func (x *UninstallPluginRequest) String() string {
+func (x *TagModuleRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UninstallPluginRequest) ProtoMessage() {}
+func (*TagModuleRequest) ProtoMessage() {}
 
-func (x *UninstallPluginRequest) ProtoReflect() protoreflect.Message {
+func (x *TagModuleRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[28]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2314,28 +2603,37 @@ func (x *UninstallPluginRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UninstallPluginRequest.ProtoReflect.Descriptor instead.
-func (*UninstallPluginRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use TagModuleRequest.ProtoReflect.Descriptor instead.
+func (*TagModuleRequest) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{28}
 }
 
-func (x *UninstallPluginRequest) GetIdentifier() string {
+func (x *TagModuleRequest) GetModuleId() int64 {
 	if x != nil {
-		return x.Identifier
+		return x.ModuleId
 	}
-	return ""
+	return 0
 }
 
-type UninstallPluginResponse struct {
+func (x *TagModuleRequest) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
+// The message returned in response to a `TagModuleRequest`.
+type TagModuleResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Tags  []string `protobuf:"bytes,1,rep,name=tags,proto3" json:"tags,omitempty"`
+	Error *Error   `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
 }
 
-func (x *UninstallPluginResponse) Reset() {
-	*x = UninstallPluginResponse{}
+func (x *TagModuleResponse) Reset() {
+	*x = TagModuleResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[29]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2343,13 +2641,13 @@ func (x *UninstallPluginResponse) Reset() {
 	}
 }
 
-func (x *UninstallPluginResponse) String() string {
+func (x *TagModuleResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UninstallPluginResponse) ProtoMessage() {}
+func (*TagModuleResponse) ProtoMessage() {}
 
-func (x *UninstallPluginResponse) ProtoReflect() protoreflect.Message {
+func (x *TagModuleResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[29]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2361,32 +2659,42 @@ func (x *UninstallPluginResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UninstallPluginResponse.ProtoReflect.Descriptor instead.
-func (*UninstallPluginResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use TagModuleResponse.ProtoReflect.Descriptor instead.
+func (*TagModuleResponse) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{29}
 }
 
-func (x *UninstallPluginResponse) GetError() *Error {
+func (x *TagModuleResponse) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
+func (x *TagModuleResponse) GetError() *Error {
 	if x != nil {
 		return x.Error
 	}
 	return nil
 }
 
-// POST /api/v1/plugin:
-type CallPluginRequest struct {
+// `PUT /api/v1/module/quarantine:`
+// Quarantine a module, or lift its quarantine. Return the module's resulting
+// quarantine, if any.
+type QuarantineModuleRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Identifier   string  `protobuf:"bytes,1,opt,name=identifier,proto3" json:"identifier,omitempty"`
-	FunctionName string  `protobuf:"bytes,2,opt,name=function_name,json=functionName,proto3" json:"function_name,omitempty"`
-	Input        []byte  `protobuf:"bytes,3,opt,name=input,proto3" json:"input,omitempty"`
-	Hash         *string `protobuf:"bytes,4,opt,name=hash,proto3,oneof" json:"hash,omitempty"` // bytes config = 5;
+	ModuleId int64 `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+	// quarantine the module if true, otherwise lift its quarantine
+	Quarantined bool `protobuf:"varint,2,opt,name=quarantined,proto3" json:"quarantined,omitempty"`
+	// why the module is quarantined, required when `quarantined` is true
+	Reason string `protobuf:"bytes,3,opt,name=reason,proto3" json:"reason,omitempty"`
 }
 
-func (x *CallPluginRequest) Reset() {
-	*x = CallPluginRequest{}
+func (x *QuarantineModuleRequest) Reset() {
+	*x = QuarantineModuleRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_proto_v1_api_proto_msgTypes[30]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2394,13 +2702,13 @@ func (x *CallPluginRequest) Reset() {
 	}
 }
 
-func (x *CallPluginRequest) String() string {
+func (x *QuarantineModuleRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CallPluginRequest) ProtoMessage() {}
+func (*QuarantineModuleRequest) ProtoMessage() {}
 
-func (x *CallPluginRequest) ProtoReflect() protoreflect.Message {
+func (x *QuarantineModuleRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_proto_v1_api_proto_msgTypes[30]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -2412,564 +2720,4106 @@ func (x *CallPluginRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CallPluginRequest.ProtoReflect.Descriptor instead.
-func (*CallPluginRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use QuarantineModuleRequest.ProtoReflect.Descriptor instead.
+func (*QuarantineModuleRequest) Descriptor() ([]byte, []int) {
 	return file_proto_v1_api_proto_rawDescGZIP(), []int{30}
 }
 
-func (x *CallPluginRequest) GetIdentifier() string {
+func (x *QuarantineModuleRequest) GetModuleId() int64 {
 	if x != nil {
-		return x.Identifier
+		return x.ModuleId
 	}
-	return ""
+	return 0
 }
 
-func (x *CallPluginRequest) GetFunctionName() string {
+func (x *QuarantineModuleRequest) GetQuarantined() bool {
 	if x != nil {
-		return x.FunctionName
+		return x.Quarantined
+	}
+	return false
+}
+
+func (x *QuarantineModuleRequest) GetReason() string {
+	if x != nil {
+		return x.Reason
 	}
 	return ""
 }
 
-func (x *CallPluginRequest) GetInput() []byte {
+// The message returned in response to a `QuarantineModuleRequest`.
+type QuarantineModuleResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Quarantine *Quarantine `protobuf:"bytes,1,opt,name=quarantine,proto3" json:"quarantine,omitempty"`
+	Error      *Error      `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *QuarantineModuleResponse) Reset() {
+	*x = QuarantineModuleResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[31]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *QuarantineModuleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*QuarantineModuleResponse) ProtoMessage() {}
+
+func (x *QuarantineModuleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[31]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use QuarantineModuleResponse.ProtoReflect.Descriptor instead.
+func (*QuarantineModuleResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{31}
+}
+
+func (x *QuarantineModuleResponse) GetQuarantine() *Quarantine {
 	if x != nil {
-		return x.Input
+		return x.Quarantine
 	}
 	return nil
 }
 
-func (x *CallPluginRequest) GetHash() string {
-	if x != nil && x.Hash != nil {
-		return *x.Hash
+func (x *QuarantineModuleResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
 	}
-	return ""
+	return nil
 }
 
-type CallPluginResponse struct {
+// Represents the expected outcome of an AuditModulesRequest. If PASS is provided, then
+// the audit returns modules which conform to the checkfile. If FAIL is provided, then
+// the audit returns modules which do not conform to the checkfile.
+// A checkfile (e.g. mod.yaml), describing the requirements a module must meet to pass validation.
+type Checkfile struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Output []byte `protobuf:"bytes,1,opt,name=output,proto3" json:"output,omitempty"`
-	Error  *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	Validate *Check `protobuf:"bytes,1,opt,name=validate,proto3" json:"validate,omitempty"`
 }
 
-func (x *CallPluginResponse) Reset() {
-	*x = CallPluginResponse{}
+func (x *Checkfile) Reset() {
+	*x = Checkfile{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_proto_v1_api_proto_msgTypes[31]
+		mi := &file_proto_v1_api_proto_msgTypes[32]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CallPluginResponse) String() string {
+func (x *Checkfile) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CallPluginResponse) ProtoMessage() {}
+func (*Checkfile) ProtoMessage() {}
 
-func (x *CallPluginResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_v1_api_proto_msgTypes[31]
+func (x *Checkfile) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[32]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Checkfile.ProtoReflect.Descriptor instead.
+func (*Checkfile) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{32}
+}
+
+func (x *Checkfile) GetValidate() *Check {
+	if x != nil {
+		return x.Validate
+	}
+	return nil
+}
+
+// The individual requirements of a checkfile. Unset fields are not checked.
+type Check struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Url        *string          `protobuf:"bytes,1,opt,name=url,proto3,oneof" json:"url,omitempty"`
+	AllowWasi  *bool            `protobuf:"varint,2,opt,name=allow_wasi,json=allowWasi,proto3,oneof" json:"allow_wasi,omitempty"`
+	Imports    *CheckImports    `protobuf:"bytes,3,opt,name=imports,proto3" json:"imports,omitempty"`
+	Exports    *CheckExports    `protobuf:"bytes,4,opt,name=exports,proto3" json:"exports,omitempty"`
+	Size       *CheckSize       `protobuf:"bytes,5,opt,name=size,proto3" json:"size,omitempty"`
+	Complexity *CheckComplexity `protobuf:"bytes,6,opt,name=complexity,proto3" json:"complexity,omitempty"`
+	// the hex-encoded SHA-256 hash which the checkfile fetched from `url` must have
+	UrlSha256       *string               `protobuf:"bytes,7,opt,name=url_sha256,json=urlSha256,proto3,oneof" json:"url_sha256,omitempty"`
+	Risk            *CheckRisk            `protobuf:"bytes,8,opt,name=risk,proto3" json:"risk,omitempty"`
+	Capabilities    *CheckCapabilities    `protobuf:"bytes,9,opt,name=capabilities,proto3" json:"capabilities,omitempty"`
+	Licenses        *CheckLicenses        `protobuf:"bytes,10,opt,name=licenses,proto3" json:"licenses,omitempty"`
+	Vulnerabilities *CheckVulnerabilities `protobuf:"bytes,11,opt,name=vulnerabilities,proto3" json:"vulnerabilities,omitempty"`
+	Integrity       *CheckIntegrity       `protobuf:"bytes,12,opt,name=integrity,proto3" json:"integrity,omitempty"`
+	ModuleName      *CheckModuleName      `protobuf:"bytes,13,opt,name=module_name,json=moduleName,proto3" json:"module_name,omitempty"`
+	Location        *CheckLocation        `protobuf:"bytes,14,opt,name=location,proto3" json:"location,omitempty"`
+}
+
+func (x *Check) Reset() {
+	*x = Check{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[33]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Check) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Check) ProtoMessage() {}
+
+func (x *Check) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[33]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
 		}
-		return ms
-	}
-	return mi.MessageOf(x)
-}
-
-// Deprecated: Use CallPluginResponse.ProtoReflect.Descriptor instead.
-func (*CallPluginResponse) Descriptor() ([]byte, []int) {
-	return file_proto_v1_api_proto_rawDescGZIP(), []int{31}
-}
-
-func (x *CallPluginResponse) GetOutput() []byte {
-	if x != nil {
-		return x.Output
-	}
-	return nil
-}
-
-func (x *CallPluginResponse) GetError() *Error {
-	if x != nil {
-		return x.Error
-	}
-	return nil
-}
-
-var File_proto_v1_api_proto protoreflect.FileDescriptor
-
-var file_proto_v1_api_proto_rawDesc = []byte{
-	0x0a, 0x12, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x31, 0x2f, 0x61, 0x70, 0x69, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x64, 0x0a, 0x08, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f,
-	0x6e, 0x12, 0x20, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
-	0x0e, 0x32, 0x08, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x06, 0x70, 0x61, 0x72,
-	0x61, 0x6d, 0x73, 0x12, 0x22, 0x0a, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x02,
-	0x20, 0x03, 0x28, 0x0e, 0x32, 0x08, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x07,
-	0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-	0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x48, 0x0a, 0x06, 0x49,
-	0x6d, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x1f, 0x0a, 0x0b, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f,
-	0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x6d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1d, 0x0a, 0x04, 0x66, 0x75, 0x6e, 0x63, 0x18, 0x02,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52,
-	0x04, 0x66, 0x75, 0x6e, 0x63, 0x22, 0x27, 0x0a, 0x06, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x12,
-	0x1d, 0x0a, 0x04, 0x66, 0x75, 0x6e, 0x63, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e,
-	0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x04, 0x66, 0x75, 0x6e, 0x63, 0x22, 0x85,
-	0x05, 0x0a, 0x06, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73,
-	0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a,
-	0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07,
-	0x2e, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73,
-	0x12, 0x21, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28,
-	0x0b, 0x32, 0x07, 0x2e, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x07, 0x65, 0x78, 0x70, 0x6f,
-	0x72, 0x74, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28,
-	0x04, 0x52, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x12, 0x38, 0x0a, 0x0f, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x61,
-	0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x53,
-	0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x52, 0x0e, 0x73,
-	0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x12, 0x31, 0x0a,
-	0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0b, 0x32,
-	0x15, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
-	0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-	0x12, 0x3b, 0x0a, 0x0b, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18,
-	0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
-	0x70, 0x52, 0x0a, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x18, 0x0a,
-	0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x0b, 0x20, 0x03, 0x28, 0x09, 0x52, 0x07,
-	0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x12, 0x23, 0x0a, 0x0a, 0x63, 0x6f, 0x6d, 0x70, 0x6c,
-	0x65, 0x78, 0x69, 0x74, 0x79, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x0a, 0x63,
-	0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x88, 0x01, 0x01, 0x12, 0x19, 0x0a, 0x05,
-	0x67, 0x72, 0x61, 0x70, 0x68, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x01, 0x52, 0x05, 0x67,
-	0x72, 0x61, 0x70, 0x68, 0x88, 0x01, 0x01, 0x12, 0x44, 0x0a, 0x0f, 0x66, 0x75, 0x6e, 0x63, 0x74,
-	0x69, 0x6f, 0x6e, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x65, 0x73, 0x18, 0x0f, 0x20, 0x03, 0x28, 0x0b,
-	0x32, 0x1b, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x2e, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69,
-	0x6f, 0x6e, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x0e, 0x66,
-	0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x1a, 0x3b, 0x0a,
-	0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10,
-	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
-	0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x1a, 0x41, 0x0a, 0x13, 0x46, 0x75,
-	0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72,
-	0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03,
-	0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x0d, 0x0a,
-	0x0b, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x42, 0x08, 0x0a, 0x06,
-	0x5f, 0x67, 0x72, 0x61, 0x70, 0x68, 0x22, 0x3c, 0x0a, 0x0b, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x47, 0x72, 0x61, 0x70, 0x68, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x6a, 0x73, 0x6f, 0x6e, 0x5f, 0x62, 0x79,
-	0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x6a, 0x73, 0x6f, 0x6e, 0x42,
-	0x79, 0x74, 0x65, 0x73, 0x22, 0x35, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x12, 0x0a,
-	0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x52, 0x04, 0x63, 0x6f, 0x64,
-	0x65, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22, 0x3a, 0x0a, 0x0a, 0x50,
-	0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d,
-	0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x12,
-	0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52,
-	0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x4e, 0x0a, 0x04, 0x53, 0x6f, 0x72, 0x74, 0x12,
-	0x28, 0x0a, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x09,
-	0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c, 0x0a, 0x05, 0x66, 0x69, 0x65,
-	0x6c, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x06, 0x2e, 0x46, 0x69, 0x65, 0x6c, 0x64,
-	0x52, 0x05, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x22, 0xd4, 0x01, 0x0a, 0x13, 0x43, 0x72, 0x65, 0x61,
-	0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x12, 0x0a, 0x04, 0x77, 0x61, 0x73, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x77,
-	0x61, 0x73, 0x6d, 0x12, 0x3e, 0x0a, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18,
-	0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4d, 0x6f,
-	0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61,
-	0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64,
-	0x61, 0x74, 0x61, 0x12, 0x1f, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
-	0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f,
-	0x6e, 0x88, 0x01, 0x01, 0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-	0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38,
-	0x01, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x74,
-	0x0a, 0x14, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x6d, 0x6f, 0x64, 0x75, 0x6c,
-	0x65, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00,
-	0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x22, 0x2f, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c,
-	0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x6d, 0x6f, 0x64,
-	0x75, 0x6c, 0x65, 0x49, 0x64, 0x22, 0x61, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1f, 0x0a, 0x06, 0x6d, 0x6f,
-	0x64, 0x75, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x6f, 0x64,
-	0x75, 0x6c, 0x65, 0x52, 0x06, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x12, 0x21, 0x0a, 0x05, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72,
-	0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08,
-	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x5c, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74,
-	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2b,
-	0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52,
-	0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x04, 0x73,
-	0x6f, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x53, 0x6f, 0x72, 0x74,
-	0x52, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x22, 0xc3, 0x01, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x4d,
-	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x21,
-	0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
-	0x07, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x73, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
-	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69,
-	0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14,
-	0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x74,
-	0x6f, 0x74, 0x61, 0x6c, 0x12, 0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x18, 0x04, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x05, 0x2e, 0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x12,
-	0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06,
-	0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88,
-	0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x80, 0x07, 0x0a,
-	0x14, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65,
-	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x13, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x03, 0x48, 0x00, 0x52, 0x02, 0x69, 0x64, 0x88, 0x01, 0x01, 0x12, 0x17, 0x0a, 0x04, 0x68, 0x61,
-	0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68,
-	0x88, 0x01, 0x01, 0x12, 0x21, 0x0a, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x04,
-	0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x07, 0x69,
-	0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x21, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74,
-	0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74,
-	0x52, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x1e, 0x0a, 0x08, 0x6d, 0x69, 0x6e,
-	0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x48, 0x02, 0x52, 0x07, 0x6d,
-	0x69, 0x6e, 0x53, 0x69, 0x7a, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1e, 0x0a, 0x08, 0x6d, 0x61, 0x78,
-	0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x48, 0x03, 0x52, 0x07, 0x6d,
-	0x61, 0x78, 0x53, 0x69, 0x7a, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1f, 0x0a, 0x08, 0x6c, 0x6f, 0x63,
-	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x48, 0x04, 0x52, 0x08, 0x6c,
-	0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x3d, 0x0a, 0x0f, 0x73, 0x6f,
-	0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x18, 0x09, 0x20,
-	0x01, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67,
-	0x75, 0x61, 0x67, 0x65, 0x48, 0x05, 0x52, 0x0e, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61,
-	0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x88, 0x01, 0x01, 0x12, 0x3f, 0x0a, 0x08, 0x6d, 0x65, 0x74,
-	0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x0a, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x53, 0x65,
-	0x61, 0x72, 0x63, 0x68, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79,
-	0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x48, 0x0a, 0x0f, 0x69, 0x6e,
-	0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x18, 0x0b, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x48,
-	0x06, 0x52, 0x0e, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x42, 0x65, 0x66, 0x6f, 0x72,
-	0x65, 0x88, 0x01, 0x01, 0x12, 0x46, 0x0a, 0x0e, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64,
-	0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67,
-	0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54,
-	0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x48, 0x07, 0x52, 0x0d, 0x69, 0x6e, 0x73, 0x65,
-	0x72, 0x74, 0x65, 0x64, 0x41, 0x66, 0x74, 0x65, 0x72, 0x88, 0x01, 0x01, 0x12, 0x18, 0x0a, 0x07,
-	0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x0d, 0x20, 0x03, 0x28, 0x09, 0x52, 0x07, 0x73,
-	0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x12, 0x28, 0x0a, 0x0d, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69,
-	0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x48, 0x08, 0x52,
-	0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01,
-	0x12, 0x24, 0x0a, 0x0b, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-	0x0f, 0x20, 0x01, 0x28, 0x09, 0x48, 0x09, 0x52, 0x0a, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x4e,
-	0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61,
-	0x74, 0x69, 0x6f, 0x6e, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67,
-	0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x18, 0x11, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x05, 0x2e, 0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x1a, 0x3b,
-	0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12,
-	0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65,
-	0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x05, 0x0a, 0x03, 0x5f,
-	0x69, 0x64, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x42, 0x0b, 0x0a, 0x09, 0x5f,
-	0x6d, 0x69, 0x6e, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6d, 0x61, 0x78,
-	0x5f, 0x73, 0x69, 0x7a, 0x65, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69,
-	0x6f, 0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x61,
-	0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x6e, 0x73, 0x65, 0x72,
-	0x74, 0x65, 0x64, 0x5f, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x42, 0x11, 0x0a, 0x0f, 0x5f, 0x69,
-	0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x42, 0x10, 0x0a,
-	0x0e, 0x5f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x42,
-	0x0e, 0x0a, 0x0c, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x22,
-	0xc5, 0x01, 0x0a, 0x15, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x07, 0x6d, 0x6f, 0x64,
-	0x75, 0x6c, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x6f, 0x64,
-	0x75, 0x6c, 0x65, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x12, 0x2b, 0x0a, 0x0a,
-	0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70,
-	0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x74,
-	0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x12,
-	0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e,
-	0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72,
-	0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f,
-	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a,
-	0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x35, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74,
-	0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x1d, 0x0a, 0x0a, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x01, 0x20,
-	0x03, 0x28, 0x03, 0x52, 0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x73, 0x22, 0xd5,
-	0x01, 0x0a, 0x15, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4e, 0x0a, 0x0e, 0x6d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x5f, 0x69, 0x64, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
-	0x32, 0x28, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49,
-	0x64, 0x48, 0x61, 0x73, 0x68, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x0c, 0x6d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48,
-	0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x1a, 0x3f, 0x0a, 0x11, 0x4d,
-	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x45, 0x6e, 0x74, 0x72, 0x79,
-	0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x6b,
-	0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x08, 0x0a, 0x06,
-	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x89, 0x01, 0x0a, 0x13, 0x41, 0x75, 0x64, 0x69, 0x74,
-	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1c,
-	0x0a, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0c, 0x52, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x12, 0x27, 0x0a, 0x07,
-	0x6f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e,
-	0x41, 0x75, 0x64, 0x69, 0x74, 0x4f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x52, 0x07, 0x6f, 0x75,
-	0x74, 0x63, 0x6f, 0x6d, 0x65, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74,
-	0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69,
-	0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69,
-	0x6f, 0x6e, 0x22, 0xb2, 0x02, 0x0a, 0x14, 0x41, 0x75, 0x64, 0x69, 0x74, 0x4d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x62, 0x0a, 0x15, 0x69,
-	0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x72, 0x65,
-	0x70, 0x6f, 0x72, 0x74, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x41, 0x75, 0x64,
-	0x69, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x2e, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52,
-	0x65, 0x70, 0x6f, 0x72, 0x74, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x13, 0x69, 0x6e, 0x76, 0x61,
-	0x6c, 0x69, 0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x12,
-	0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-	0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05,
-	0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x74, 0x6f, 0x74,
-	0x61, 0x6c, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x88, 0x01, 0x01, 0x1a, 0x46, 0x0a, 0x18, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
-	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x45, 0x6e, 0x74, 0x72,
-	0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03,
-	0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x08, 0x0a,
-	0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x8b, 0x01, 0x0a, 0x0b, 0x44, 0x69, 0x66, 0x66,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c,
-	0x65, 0x31, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x31, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x32, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x03, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x32, 0x12, 0x25, 0x0a, 0x0e, 0x63,
-	0x6f, 0x6c, 0x6f, 0x72, 0x5f, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x03, 0x20,
-	0x01, 0x28, 0x08, 0x52, 0x0d, 0x63, 0x6f, 0x6c, 0x6f, 0x72, 0x54, 0x65, 0x72, 0x6d, 0x69, 0x6e,
-	0x61, 0x6c, 0x12, 0x21, 0x0a, 0x0c, 0x77, 0x69, 0x74, 0x68, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65,
-	0x78, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x77, 0x69, 0x74, 0x68, 0x43, 0x6f,
-	0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x4f, 0x0a, 0x0c, 0x44, 0x69, 0x66, 0x66, 0x52, 0x65, 0x73,
-	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x69, 0x66, 0x66, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x04, 0x64, 0x69, 0x66, 0x66, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
-	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06,
-	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x7e, 0x0a, 0x15, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x61,
-	0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x1c, 0x0a, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0c, 0x52, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x12, 0x18, 0x0a,
-	0x06, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52,
-	0x06, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x12, 0x1d, 0x0a, 0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x08, 0x6d, 0x6f,
-	0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x42, 0x0e, 0x0a, 0x0c, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x5f, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x22, 0x79, 0x0a, 0x16, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x61,
-	0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x32, 0x0a, 0x15, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x5f, 0x6d, 0x6f, 0x64, 0x75,
-	0x6c, 0x65, 0x5f, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
-	0x13, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65,
-	0x70, 0x6f, 0x72, 0x74, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
-	0x72, 0x22, 0x34, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47, 0x72,
-	0x61, 0x70, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x6f,
-	0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x6d,
-	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x22, 0x76, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x4d, 0x6f,
-	0x64, 0x75, 0x6c, 0x65, 0x47, 0x72, 0x61, 0x70, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x12, 0x2f, 0x0a, 0x0c, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x67, 0x72, 0x61, 0x70,
-	0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
-	0x47, 0x72, 0x61, 0x70, 0x68, 0x52, 0x0b, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47, 0x72, 0x61,
-	0x70, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22,
-	0x88, 0x01, 0x0a, 0x14, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69,
-	0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1e, 0x0a, 0x0a, 0x69, 0x64, 0x65, 0x6e,
-	0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x69, 0x64,
-	0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x88, 0x01,
-	0x01, 0x12, 0x1a, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a,
-	0x04, 0x77, 0x61, 0x73, 0x6d, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x77, 0x61, 0x73,
-	0x6d, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x58, 0x0a, 0x15, 0x49, 0x6e,
-	0x73, 0x74, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00,
-	0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x22, 0x38, 0x0a, 0x16, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c,
-	0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1e,
-	0x0a, 0x0a, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x0a, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x22, 0x46,
-	0x0a, 0x17, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69,
-	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72,
-	0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
-	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06,
-	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x90, 0x01, 0x0a, 0x11, 0x43, 0x61, 0x6c, 0x6c, 0x50,
-	0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1e, 0x0a, 0x0a,
-	0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0a, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x23, 0x0a, 0x0d,
-	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d,
-	0x65, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
-	0x52, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x12, 0x17, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18,
-	0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x88, 0x01, 0x01,
-	0x42, 0x07, 0x0a, 0x05, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x22, 0x59, 0x0a, 0x12, 0x43, 0x61, 0x6c,
-	0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x16, 0x0a, 0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
-	0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00,
-	0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65,
-	0x72, 0x72, 0x6f, 0x72, 0x2a, 0x53, 0x0a, 0x07, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x12,
-	0x07, 0x0a, 0x03, 0x49, 0x33, 0x32, 0x10, 0x00, 0x12, 0x07, 0x0a, 0x03, 0x49, 0x36, 0x34, 0x10,
-	0x01, 0x12, 0x07, 0x0a, 0x03, 0x46, 0x33, 0x32, 0x10, 0x02, 0x12, 0x07, 0x0a, 0x03, 0x46, 0x36,
-	0x34, 0x10, 0x03, 0x12, 0x08, 0x0a, 0x04, 0x56, 0x31, 0x32, 0x38, 0x10, 0x04, 0x12, 0x0b, 0x0a,
-	0x07, 0x46, 0x75, 0x6e, 0x63, 0x52, 0x65, 0x66, 0x10, 0x05, 0x12, 0x0d, 0x0a, 0x09, 0x45, 0x78,
-	0x74, 0x65, 0x72, 0x6e, 0x52, 0x65, 0x66, 0x10, 0x06, 0x2a, 0x84, 0x01, 0x0a, 0x0e, 0x53, 0x6f,
-	0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x12, 0x0b, 0x0a, 0x07,
-	0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x52, 0x75, 0x73,
-	0x74, 0x10, 0x01, 0x12, 0x06, 0x0a, 0x02, 0x47, 0x6f, 0x10, 0x02, 0x12, 0x05, 0x0a, 0x01, 0x43,
-	0x10, 0x03, 0x12, 0x07, 0x0a, 0x03, 0x43, 0x70, 0x70, 0x10, 0x04, 0x12, 0x12, 0x0a, 0x0e, 0x41,
-	0x73, 0x73, 0x65, 0x6d, 0x62, 0x6c, 0x79, 0x53, 0x63, 0x72, 0x69, 0x70, 0x74, 0x10, 0x05, 0x12,
-	0x09, 0x0a, 0x05, 0x53, 0x77, 0x69, 0x66, 0x74, 0x10, 0x06, 0x12, 0x0e, 0x0a, 0x0a, 0x4a, 0x61,
-	0x76, 0x61, 0x53, 0x63, 0x72, 0x69, 0x70, 0x74, 0x10, 0x07, 0x12, 0x0b, 0x0a, 0x07, 0x48, 0x61,
-	0x73, 0x6b, 0x65, 0x6c, 0x6c, 0x10, 0x08, 0x12, 0x07, 0x0a, 0x03, 0x5a, 0x69, 0x67, 0x10, 0x09,
-	0x2a, 0x1e, 0x0a, 0x09, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x08, 0x0a,
-	0x04, 0x44, 0x65, 0x73, 0x63, 0x10, 0x00, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x73, 0x63, 0x10, 0x01,
-	0x2a, 0x78, 0x0a, 0x05, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x72, 0x65,
-	0x61, 0x74, 0x65, 0x64, 0x41, 0x74, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x4e, 0x61, 0x6d, 0x65,
-	0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x53, 0x69, 0x7a, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08,
-	0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x10, 0x03, 0x12, 0x10, 0x0a, 0x0c, 0x49, 0x6d,
-	0x70, 0x6f, 0x72, 0x74, 0x73, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x04, 0x12, 0x10, 0x0a, 0x0c,
-	0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x05, 0x12, 0x0a,
-	0x0a, 0x06, 0x53, 0x68, 0x61, 0x32, 0x35, 0x36, 0x10, 0x06, 0x12, 0x0e, 0x0a, 0x0a, 0x43, 0x6f,
-	0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x10, 0x07, 0x2a, 0x22, 0x0a, 0x0c, 0x41, 0x75,
-	0x64, 0x69, 0x74, 0x4f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x12, 0x08, 0x0a, 0x04, 0x50, 0x41,
-	0x53, 0x53, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x41, 0x49, 0x4c, 0x10, 0x01, 0x42, 0x0f,
-	0x5a, 0x0d, 0x2e, 0x2f, 0x6d, 0x6f, 0x64, 0x73, 0x75, 0x72, 0x66, 0x65, 0x72, 0x70, 0x62, 0x62,
-	0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
-}
-
-var (
-	file_proto_v1_api_proto_rawDescOnce sync.Once
-	file_proto_v1_api_proto_rawDescData = file_proto_v1_api_proto_rawDesc
-)
-
-func file_proto_v1_api_proto_rawDescGZIP() []byte {
-	file_proto_v1_api_proto_rawDescOnce.Do(func() {
-		file_proto_v1_api_proto_rawDescData = protoimpl.X.CompressGZIP(file_proto_v1_api_proto_rawDescData)
-	})
-	return file_proto_v1_api_proto_rawDescData
-}
-
-var file_proto_v1_api_proto_enumTypes = make([]protoimpl.EnumInfo, 5)
-var file_proto_v1_api_proto_msgTypes = make([]protoimpl.MessageInfo, 38)
-var file_proto_v1_api_proto_goTypes = []interface{}{
-	(ValType)(0),                    // 0: ValType
-	(SourceLanguage)(0),             // 1: SourceLanguage
-	(Direction)(0),                  // 2: Direction
-	(Field)(0),                      // 3: Field
-	(AuditOutcome)(0),               // 4: AuditOutcome
-	(*Function)(nil),                // 5: Function
-	(*Import)(nil),                  // 6: Import
-	(*Export)(nil),                  // 7: Export
-	(*Module)(nil),                  // 8: Module
-	(*ModuleGraph)(nil),             // 9: ModuleGraph
-	(*Error)(nil),                   // 10: Error
-	(*Pagination)(nil),              // 11: Pagination
-	(*Sort)(nil),                    // 12: Sort
-	(*CreateModuleRequest)(nil),     // 13: CreateModuleRequest
-	(*CreateModuleResponse)(nil),    // 14: CreateModuleResponse
-	(*GetModuleRequest)(nil),        // 15: GetModuleRequest
-	(*GetModuleResponse)(nil),       // 16: GetModuleResponse
-	(*ListModulesRequest)(nil),      // 17: ListModulesRequest
-	(*ListModulesResponse)(nil),     // 18: ListModulesResponse
-	(*SearchModulesRequest)(nil),    // 19: SearchModulesRequest
-	(*SearchModulesResponse)(nil),   // 20: SearchModulesResponse
-	(*DeleteModulesRequest)(nil),    // 21: DeleteModulesRequest
-	(*DeleteModulesResponse)(nil),   // 22: DeleteModulesResponse
-	(*AuditModulesRequest)(nil),     // 23: AuditModulesRequest
-	(*AuditModulesResponse)(nil),    // 24: AuditModulesResponse
-	(*DiffRequest)(nil),             // 25: DiffRequest
-	(*DiffResponse)(nil),            // 26: DiffResponse
-	(*ValidateModuleRequest)(nil),   // 27: ValidateModuleRequest
-	(*ValidateModuleResponse)(nil),  // 28: ValidateModuleResponse
-	(*GetModuleGraphRequest)(nil),   // 29: GetModuleGraphRequest
-	(*GetModuleGraphResponse)(nil),  // 30: GetModuleGraphResponse
-	(*InstallPluginRequest)(nil),    // 31: InstallPluginRequest
-	(*InstallPluginResponse)(nil),   // 32: InstallPluginResponse
-	(*UninstallPluginRequest)(nil),  // 33: UninstallPluginRequest
-	(*UninstallPluginResponse)(nil), // 34: UninstallPluginResponse
-	(*CallPluginRequest)(nil),       // 35: CallPluginRequest
-	(*CallPluginResponse)(nil),      // 36: CallPluginResponse
-	nil,                             // 37: Module.MetadataEntry
-	nil,                             // 38: Module.FunctionHashesEntry
-	nil,                             // 39: CreateModuleRequest.MetadataEntry
-	nil,                             // 40: SearchModulesRequest.MetadataEntry
-	nil,                             // 41: DeleteModulesResponse.ModuleIdHashEntry
-	nil,                             // 42: AuditModulesResponse.InvalidModuleReportEntry
-	(*timestamppb.Timestamp)(nil),   // 43: google.protobuf.Timestamp
-}
-var file_proto_v1_api_proto_depIdxs = []int32{
-	0,  // 0: Function.params:type_name -> ValType
-	0,  // 1: Function.results:type_name -> ValType
-	5,  // 2: Import.func:type_name -> Function
-	5,  // 3: Export.func:type_name -> Function
-	6,  // 4: Module.imports:type_name -> Import
-	7,  // 5: Module.exports:type_name -> Export
-	1,  // 6: Module.source_language:type_name -> SourceLanguage
-	37, // 7: Module.metadata:type_name -> Module.MetadataEntry
-	43, // 8: Module.inserted_at:type_name -> google.protobuf.Timestamp
-	38, // 9: Module.function_hashes:type_name -> Module.FunctionHashesEntry
-	2,  // 10: Sort.direction:type_name -> Direction
-	3,  // 11: Sort.field:type_name -> Field
-	39, // 12: CreateModuleRequest.metadata:type_name -> CreateModuleRequest.MetadataEntry
-	10, // 13: CreateModuleResponse.error:type_name -> Error
-	8,  // 14: GetModuleResponse.module:type_name -> Module
-	10, // 15: GetModuleResponse.error:type_name -> Error
-	11, // 16: ListModulesRequest.pagination:type_name -> Pagination
-	12, // 17: ListModulesRequest.sort:type_name -> Sort
-	8,  // 18: ListModulesResponse.modules:type_name -> Module
-	11, // 19: ListModulesResponse.pagination:type_name -> Pagination
-	12, // 20: ListModulesResponse.sort:type_name -> Sort
-	10, // 21: ListModulesResponse.error:type_name -> Error
-	6,  // 22: SearchModulesRequest.imports:type_name -> Import
-	7,  // 23: SearchModulesRequest.exports:type_name -> Export
-	1,  // 24: SearchModulesRequest.source_language:type_name -> SourceLanguage
-	40, // 25: SearchModulesRequest.metadata:type_name -> SearchModulesRequest.MetadataEntry
-	43, // 26: SearchModulesRequest.inserted_before:type_name -> google.protobuf.Timestamp
-	43, // 27: SearchModulesRequest.inserted_after:type_name -> google.protobuf.Timestamp
-	11, // 28: SearchModulesRequest.pagination:type_name -> Pagination
-	12, // 29: SearchModulesRequest.sort:type_name -> Sort
-	8,  // 30: SearchModulesResponse.modules:type_name -> Module
-	11, // 31: SearchModulesResponse.pagination:type_name -> Pagination
-	12, // 32: SearchModulesResponse.sort:type_name -> Sort
-	10, // 33: SearchModulesResponse.error:type_name -> Error
-	41, // 34: DeleteModulesResponse.module_id_hash:type_name -> DeleteModulesResponse.ModuleIdHashEntry
-	10, // 35: DeleteModulesResponse.error:type_name -> Error
-	4,  // 36: AuditModulesRequest.outcome:type_name -> AuditOutcome
-	11, // 37: AuditModulesRequest.pagination:type_name -> Pagination
-	42, // 38: AuditModulesResponse.invalid_module_report:type_name -> AuditModulesResponse.InvalidModuleReportEntry
-	11, // 39: AuditModulesResponse.pagination:type_name -> Pagination
-	10, // 40: AuditModulesResponse.error:type_name -> Error
-	10, // 41: DiffResponse.error:type_name -> Error
-	10, // 42: ValidateModuleResponse.error:type_name -> Error
-	9,  // 43: GetModuleGraphResponse.module_graph:type_name -> ModuleGraph
-	10, // 44: GetModuleGraphResponse.error:type_name -> Error
-	10, // 45: InstallPluginResponse.error:type_name -> Error
-	10, // 46: UninstallPluginResponse.error:type_name -> Error
-	10, // 47: CallPluginResponse.error:type_name -> Error
-	48, // [48:48] is the sub-list for method output_type
-	48, // [48:48] is the sub-list for method input_type
-	48, // [48:48] is the sub-list for extension type_name
-	48, // [48:48] is the sub-list for extension extendee
-	0,  // [0:48] is the sub-list for field type_name
-}
-
-func init() { file_proto_v1_api_proto_init() }
-func file_proto_v1_api_proto_init() {
-	if File_proto_v1_api_proto != nil {
-		return
-	}
-	if !protoimpl.UnsafeEnabled {
-		file_proto_v1_api_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Function); i {
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Check.ProtoReflect.Descriptor instead.
+func (*Check) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{33}
+}
+
+func (x *Check) GetUrl() string {
+	if x != nil && x.Url != nil {
+		return *x.Url
+	}
+	return ""
+}
+
+func (x *Check) GetAllowWasi() bool {
+	if x != nil && x.AllowWasi != nil {
+		return *x.AllowWasi
+	}
+	return false
+}
+
+func (x *Check) GetImports() *CheckImports {
+	if x != nil {
+		return x.Imports
+	}
+	return nil
+}
+
+func (x *Check) GetExports() *CheckExports {
+	if x != nil {
+		return x.Exports
+	}
+	return nil
+}
+
+func (x *Check) GetSize() *CheckSize {
+	if x != nil {
+		return x.Size
+	}
+	return nil
+}
+
+func (x *Check) GetComplexity() *CheckComplexity {
+	if x != nil {
+		return x.Complexity
+	}
+	return nil
+}
+
+func (x *Check) GetUrlSha256() string {
+	if x != nil && x.UrlSha256 != nil {
+		return *x.UrlSha256
+	}
+	return ""
+}
+
+func (x *Check) GetRisk() *CheckRisk {
+	if x != nil {
+		return x.Risk
+	}
+	return nil
+}
+
+func (x *Check) GetCapabilities() *CheckCapabilities {
+	if x != nil {
+		return x.Capabilities
+	}
+	return nil
+}
+
+func (x *Check) GetLicenses() *CheckLicenses {
+	if x != nil {
+		return x.Licenses
+	}
+	return nil
+}
+
+func (x *Check) GetVulnerabilities() *CheckVulnerabilities {
+	if x != nil {
+		return x.Vulnerabilities
+	}
+	return nil
+}
+
+func (x *Check) GetIntegrity() *CheckIntegrity {
+	if x != nil {
+		return x.Integrity
+	}
+	return nil
+}
+
+func (x *Check) GetModuleName() *CheckModuleName {
+	if x != nil {
+		return x.ModuleName
+	}
+	return nil
+}
+
+func (x *Check) GetLocation() *CheckLocation {
+	if x != nil {
+		return x.Location
+	}
+	return nil
+}
+
+// A list of value types, wrapped so an unchecked list can be told apart from an empty one.
+type ValTypes struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Types []ValType `protobuf:"varint,1,rep,packed,name=types,proto3,enum=ValType" json:"types,omitempty"`
+}
+
+func (x *ValTypes) Reset() {
+	*x = ValTypes{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[34]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ValTypes) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ValTypes) ProtoMessage() {}
+
+func (x *ValTypes) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[34]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ValTypes.ProtoReflect.Descriptor instead.
+func (*ValTypes) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{34}
+}
+
+func (x *ValTypes) GetTypes() []ValType {
+	if x != nil {
+		return x.Types
+	}
+	return nil
+}
+
+type CheckImports struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Include   *CheckImportItems `protobuf:"bytes,1,opt,name=include,proto3" json:"include,omitempty"`
+	Exclude   *CheckImportItems `protobuf:"bytes,2,opt,name=exclude,proto3" json:"exclude,omitempty"`
+	Namespace *CheckNamespaces  `protobuf:"bytes,3,opt,name=namespace,proto3" json:"namespace,omitempty"`
+	// how `include` is checked: `require_all`, `allow_only` (the default) or `both`
+	Mode *string `protobuf:"bytes,4,opt,name=mode,proto3,oneof" json:"mode,omitempty"`
+	// the most namespaces a module may import from
+	MaxNamespaces *uint32 `protobuf:"varint,5,opt,name=max_namespaces,json=maxNamespaces,proto3,oneof" json:"max_namespaces,omitempty"`
+	// the most functions a module may import from any one namespace
+	MaxPerNamespace *uint32 `protobuf:"varint,6,opt,name=max_per_namespace,json=maxPerNamespace,proto3,oneof" json:"max_per_namespace,omitempty"`
+}
+
+func (x *CheckImports) Reset() {
+	*x = CheckImports{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[35]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckImports) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckImports) ProtoMessage() {}
+
+func (x *CheckImports) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[35]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckImports.ProtoReflect.Descriptor instead.
+func (*CheckImports) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{35}
+}
+
+func (x *CheckImports) GetInclude() *CheckImportItems {
+	if x != nil {
+		return x.Include
+	}
+	return nil
+}
+
+func (x *CheckImports) GetExclude() *CheckImportItems {
+	if x != nil {
+		return x.Exclude
+	}
+	return nil
+}
+
+func (x *CheckImports) GetNamespace() *CheckNamespaces {
+	if x != nil {
+		return x.Namespace
+	}
+	return nil
+}
+
+func (x *CheckImports) GetMode() string {
+	if x != nil && x.Mode != nil {
+		return *x.Mode
+	}
+	return ""
+}
+
+func (x *CheckImports) GetMaxNamespaces() uint32 {
+	if x != nil && x.MaxNamespaces != nil {
+		return *x.MaxNamespaces
+	}
+	return 0
+}
+
+func (x *CheckImports) GetMaxPerNamespace() uint32 {
+	if x != nil && x.MaxPerNamespace != nil {
+		return *x.MaxPerNamespace
+	}
+	return 0
+}
+
+type CheckImportItems struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Items []*CheckImportItem `protobuf:"bytes,1,rep,name=items,proto3" json:"items,omitempty"`
+}
+
+func (x *CheckImportItems) Reset() {
+	*x = CheckImportItems{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[36]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckImportItems) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckImportItems) ProtoMessage() {}
+
+func (x *CheckImportItems) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[36]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckImportItems.ProtoReflect.Descriptor instead.
+func (*CheckImportItems) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{36}
+}
+
+func (x *CheckImportItems) GetItems() []*CheckImportItem {
+	if x != nil {
+		return x.Items
+	}
+	return nil
+}
+
+type CheckImportItem struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Namespace *string   `protobuf:"bytes,1,opt,name=namespace,proto3,oneof" json:"namespace,omitempty"`
+	Name      string    `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	Params    *ValTypes `protobuf:"bytes,3,opt,name=params,proto3" json:"params,omitempty"`
+	Results   *ValTypes `protobuf:"bytes,4,opt,name=results,proto3" json:"results,omitempty"`
+}
+
+func (x *CheckImportItem) Reset() {
+	*x = CheckImportItem{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[37]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckImportItem) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckImportItem) ProtoMessage() {}
+
+func (x *CheckImportItem) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[37]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckImportItem.ProtoReflect.Descriptor instead.
+func (*CheckImportItem) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{37}
+}
+
+func (x *CheckImportItem) GetNamespace() string {
+	if x != nil && x.Namespace != nil {
+		return *x.Namespace
+	}
+	return ""
+}
+
+func (x *CheckImportItem) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *CheckImportItem) GetParams() *ValTypes {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *CheckImportItem) GetResults() *ValTypes {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+type CheckNamespaces struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Include *CheckNamespaceItems `protobuf:"bytes,1,opt,name=include,proto3" json:"include,omitempty"`
+	Exclude *CheckNamespaceItems `protobuf:"bytes,2,opt,name=exclude,proto3" json:"exclude,omitempty"`
+}
+
+func (x *CheckNamespaces) Reset() {
+	*x = CheckNamespaces{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[38]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckNamespaces) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckNamespaces) ProtoMessage() {}
+
+func (x *CheckNamespaces) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[38]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckNamespaces.ProtoReflect.Descriptor instead.
+func (*CheckNamespaces) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{38}
+}
+
+func (x *CheckNamespaces) GetInclude() *CheckNamespaceItems {
+	if x != nil {
+		return x.Include
+	}
+	return nil
+}
+
+func (x *CheckNamespaces) GetExclude() *CheckNamespaceItems {
+	if x != nil {
+		return x.Exclude
+	}
+	return nil
+}
+
+type CheckNamespaceItems struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Items []*CheckNamespaceItem `protobuf:"bytes,1,rep,name=items,proto3" json:"items,omitempty"`
+}
+
+func (x *CheckNamespaceItems) Reset() {
+	*x = CheckNamespaceItems{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[39]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckNamespaceItems) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckNamespaceItems) ProtoMessage() {}
+
+func (x *CheckNamespaceItems) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[39]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckNamespaceItems.ProtoReflect.Descriptor instead.
+func (*CheckNamespaceItems) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{39}
+}
+
+func (x *CheckNamespaceItems) GetItems() []*CheckNamespaceItem {
+	if x != nil {
+		return x.Items
+	}
+	return nil
+}
+
+type CheckNamespaceItem struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name                   string               `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Functions              []*CheckFunctionItem `protobuf:"bytes,2,rep,name=functions,proto3" json:"functions,omitempty"`
+	MaxFunctions           *uint32              `protobuf:"varint,3,opt,name=max_functions,json=maxFunctions,proto3,oneof" json:"max_functions,omitempty"`
+	ForbidUnknownFunctions *bool                `protobuf:"varint,4,opt,name=forbid_unknown_functions,json=forbidUnknownFunctions,proto3,oneof" json:"forbid_unknown_functions,omitempty"`
+}
+
+func (x *CheckNamespaceItem) Reset() {
+	*x = CheckNamespaceItem{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[40]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckNamespaceItem) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckNamespaceItem) ProtoMessage() {}
+
+func (x *CheckNamespaceItem) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[40]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckNamespaceItem.ProtoReflect.Descriptor instead.
+func (*CheckNamespaceItem) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{40}
+}
+
+func (x *CheckNamespaceItem) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *CheckNamespaceItem) GetFunctions() []*CheckFunctionItem {
+	if x != nil {
+		return x.Functions
+	}
+	return nil
+}
+
+func (x *CheckNamespaceItem) GetMaxFunctions() uint32 {
+	if x != nil && x.MaxFunctions != nil {
+		return *x.MaxFunctions
+	}
+	return 0
+}
+
+func (x *CheckNamespaceItem) GetForbidUnknownFunctions() bool {
+	if x != nil && x.ForbidUnknownFunctions != nil {
+		return *x.ForbidUnknownFunctions
+	}
+	return false
+}
+
+type CheckExports struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Include *CheckFunctionItems `protobuf:"bytes,1,opt,name=include,proto3" json:"include,omitempty"`
+	Exclude *CheckFunctionItems `protobuf:"bytes,2,opt,name=exclude,proto3" json:"exclude,omitempty"`
+	Max     *uint32             `protobuf:"varint,3,opt,name=max,proto3,oneof" json:"max,omitempty"`
+}
+
+func (x *CheckExports) Reset() {
+	*x = CheckExports{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[41]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckExports) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckExports) ProtoMessage() {}
+
+func (x *CheckExports) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[41]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckExports.ProtoReflect.Descriptor instead.
+func (*CheckExports) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{41}
+}
+
+func (x *CheckExports) GetInclude() *CheckFunctionItems {
+	if x != nil {
+		return x.Include
+	}
+	return nil
+}
+
+func (x *CheckExports) GetExclude() *CheckFunctionItems {
+	if x != nil {
+		return x.Exclude
+	}
+	return nil
+}
+
+func (x *CheckExports) GetMax() uint32 {
+	if x != nil && x.Max != nil {
+		return *x.Max
+	}
+	return 0
+}
+
+type CheckFunctionItems struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Items []*CheckFunctionItem `protobuf:"bytes,1,rep,name=items,proto3" json:"items,omitempty"`
+}
+
+func (x *CheckFunctionItems) Reset() {
+	*x = CheckFunctionItems{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[42]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckFunctionItems) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckFunctionItems) ProtoMessage() {}
+
+func (x *CheckFunctionItems) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[42]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckFunctionItems.ProtoReflect.Descriptor instead.
+func (*CheckFunctionItems) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{42}
+}
+
+func (x *CheckFunctionItems) GetItems() []*CheckFunctionItem {
+	if x != nil {
+		return x.Items
+	}
+	return nil
+}
+
+type CheckFunctionItem struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name    string    `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	Params  *ValTypes `protobuf:"bytes,2,opt,name=params,proto3" json:"params,omitempty"`
+	Results *ValTypes `protobuf:"bytes,3,opt,name=results,proto3" json:"results,omitempty"`
+	Hash    *string   `protobuf:"bytes,4,opt,name=hash,proto3,oneof" json:"hash,omitempty"`
+}
+
+func (x *CheckFunctionItem) Reset() {
+	*x = CheckFunctionItem{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[43]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckFunctionItem) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckFunctionItem) ProtoMessage() {}
+
+func (x *CheckFunctionItem) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[43]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckFunctionItem.ProtoReflect.Descriptor instead.
+func (*CheckFunctionItem) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{43}
+}
+
+func (x *CheckFunctionItem) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *CheckFunctionItem) GetParams() *ValTypes {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *CheckFunctionItem) GetResults() *ValTypes {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+func (x *CheckFunctionItem) GetHash() string {
+	if x != nil && x.Hash != nil {
+		return *x.Hash
+	}
+	return ""
+}
+
+type CheckSize struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// human-readable size limit, e.g. "4MB"
+	Max *string `protobuf:"bytes,1,opt,name=max,proto3,oneof" json:"max,omitempty"`
+}
+
+func (x *CheckSize) Reset() {
+	*x = CheckSize{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[44]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckSize) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckSize) ProtoMessage() {}
+
+func (x *CheckSize) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[44]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckSize.ProtoReflect.Descriptor instead.
+func (*CheckSize) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{44}
+}
+
+func (x *CheckSize) GetMax() string {
+	if x != nil && x.Max != nil {
+		return *x.Max
+	}
+	return ""
+}
+
+type CheckComplexity struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	MaxRisk  *RiskLevel `protobuf:"varint,1,opt,name=max_risk,json=maxRisk,proto3,enum=RiskLevel,oneof" json:"max_risk,omitempty"`
+	MaxScore *uint32    `protobuf:"varint,2,opt,name=max_score,json=maxScore,proto3,oneof" json:"max_score,omitempty"`
+}
+
+func (x *CheckComplexity) Reset() {
+	*x = CheckComplexity{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[45]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckComplexity) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckComplexity) ProtoMessage() {}
+
+func (x *CheckComplexity) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[45]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckComplexity.ProtoReflect.Descriptor instead.
+func (*CheckComplexity) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{45}
+}
+
+func (x *CheckComplexity) GetMaxRisk() RiskLevel {
+	if x != nil && x.MaxRisk != nil {
+		return *x.MaxRisk
+	}
+	return RiskLevel_LOW
+}
+
+func (x *CheckComplexity) GetMaxScore() uint32 {
+	if x != nil && x.MaxScore != nil {
+		return *x.MaxScore
+	}
+	return 0
+}
+
+// Limits on the composite risk score of a module, from 0 to 100.
+type CheckRisk struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	MaxScore *uint32 `protobuf:"varint,1,opt,name=max_score,json=maxScore,proto3,oneof" json:"max_score,omitempty"`
+}
+
+func (x *CheckRisk) Reset() {
+	*x = CheckRisk{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[46]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckRisk) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckRisk) ProtoMessage() {}
+
+func (x *CheckRisk) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[46]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckRisk.ProtoReflect.Descriptor instead.
+func (*CheckRisk) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{46}
+}
+
+func (x *CheckRisk) GetMaxScore() uint32 {
+	if x != nil && x.MaxScore != nil {
+		return *x.MaxScore
+	}
+	return 0
+}
+
+// The capabilities a module must not be granted by its imports, by name, e.g. "network".
+type CheckCapabilities struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Deny []string `protobuf:"bytes,1,rep,name=deny,proto3" json:"deny,omitempty"`
+}
+
+func (x *CheckCapabilities) Reset() {
+	*x = CheckCapabilities{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[47]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckCapabilities) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckCapabilities) ProtoMessage() {}
+
+func (x *CheckCapabilities) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[47]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckCapabilities.ProtoReflect.Descriptor instead.
+func (*CheckCapabilities) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{47}
+}
+
+func (x *CheckCapabilities) GetDeny() []string {
+	if x != nil {
+		return x.Deny
+	}
+	return nil
+}
+
+// SPDX identifiers of licenses which must not be found in a module, e.g. "GPL-3.0".
+type CheckLicenses struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Deny []string `protobuf:"bytes,1,rep,name=deny,proto3" json:"deny,omitempty"`
+}
+
+func (x *CheckLicenses) Reset() {
+	*x = CheckLicenses{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[48]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckLicenses) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckLicenses) ProtoMessage() {}
+
+func (x *CheckLicenses) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[48]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckLicenses.ProtoReflect.Descriptor instead.
+func (*CheckLicenses) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{48}
+}
+
+func (x *CheckLicenses) GetDeny() []string {
+	if x != nil {
+		return x.Deny
+	}
+	return nil
+}
+
+// The most severe known vulnerability allowed in the libraries found in a module.
+type CheckVulnerabilities struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// "low", "medium", "high" or "critical"
+	MaxSeverity *string `protobuf:"bytes,1,opt,name=max_severity,json=maxSeverity,proto3,oneof" json:"max_severity,omitempty"`
+	// "osv", or a path to a local OSV database
+	Db *string `protobuf:"bytes,2,opt,name=db,proto3,oneof" json:"db,omitempty"`
+}
+
+func (x *CheckVulnerabilities) Reset() {
+	*x = CheckVulnerabilities{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[49]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckVulnerabilities) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckVulnerabilities) ProtoMessage() {}
+
+func (x *CheckVulnerabilities) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[49]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckVulnerabilities.ProtoReflect.Descriptor instead.
+func (*CheckVulnerabilities) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{49}
+}
+
+func (x *CheckVulnerabilities) GetMaxSeverity() string {
+	if x != nil && x.MaxSeverity != nil {
+		return *x.MaxSeverity
+	}
+	return ""
+}
+
+func (x *CheckVulnerabilities) GetDb() string {
+	if x != nil && x.Db != nil {
+		return *x.Db
+	}
+	return ""
+}
+
+// Whether a module may import the same function with different signatures, export more than one
+// function with the same name, or have structural problems.
+type CheckIntegrity struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	AllowDuplicateImports *bool `protobuf:"varint,1,opt,name=allow_duplicate_imports,json=allowDuplicateImports,proto3,oneof" json:"allow_duplicate_imports,omitempty"`
+	AllowDuplicateExports *bool `protobuf:"varint,2,opt,name=allow_duplicate_exports,json=allowDuplicateExports,proto3,oneof" json:"allow_duplicate_exports,omitempty"`
+	AllowMalformed        *bool `protobuf:"varint,3,opt,name=allow_malformed,json=allowMalformed,proto3,oneof" json:"allow_malformed,omitempty"`
+}
+
+func (x *CheckIntegrity) Reset() {
+	*x = CheckIntegrity{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[50]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckIntegrity) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckIntegrity) ProtoMessage() {}
+
+func (x *CheckIntegrity) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[50]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckIntegrity.ProtoReflect.Descriptor instead.
+func (*CheckIntegrity) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{50}
+}
+
+func (x *CheckIntegrity) GetAllowDuplicateImports() bool {
+	if x != nil && x.AllowDuplicateImports != nil {
+		return *x.AllowDuplicateImports
+	}
+	return false
+}
+
+func (x *CheckIntegrity) GetAllowDuplicateExports() bool {
+	if x != nil && x.AllowDuplicateExports != nil {
+		return *x.AllowDuplicateExports
+	}
+	return false
+}
+
+func (x *CheckIntegrity) GetAllowMalformed() bool {
+	if x != nil && x.AllowMalformed != nil {
+		return *x.AllowMalformed
+	}
+	return false
+}
+
+// A regular expression which the whole file name of a module's location must match.
+type CheckModuleName struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Pattern *string `protobuf:"bytes,1,opt,name=pattern,proto3,oneof" json:"pattern,omitempty"`
+}
+
+func (x *CheckModuleName) Reset() {
+	*x = CheckModuleName{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[51]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckModuleName) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckModuleName) ProtoMessage() {}
+
+func (x *CheckModuleName) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[51]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckModuleName.ProtoReflect.Descriptor instead.
+func (*CheckModuleName) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{51}
+}
+
+func (x *CheckModuleName) GetPattern() string {
+	if x != nil && x.Pattern != nil {
+		return *x.Pattern
+	}
+	return ""
+}
+
+// The URL schemes a module's location may use, e.g. "s3" or "oci".
+type CheckLocation struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	AllowedSchemes []string `protobuf:"bytes,1,rep,name=allowed_schemes,json=allowedSchemes,proto3" json:"allowed_schemes,omitempty"`
+}
+
+func (x *CheckLocation) Reset() {
+	*x = CheckLocation{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[52]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CheckLocation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CheckLocation) ProtoMessage() {}
+
+func (x *CheckLocation) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[52]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CheckLocation.ProtoReflect.Descriptor instead.
+func (*CheckLocation) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{52}
+}
+
+func (x *CheckLocation) GetAllowedSchemes() []string {
+	if x != nil {
+		return x.AllowedSchemes
+	}
+	return nil
+}
+
+// `POST /api/v1/audit:`
+// Return a list of modules which match the outcome requirements using the provided checkfile.
+type AuditModulesRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// the YAML checkfile (e.g. mod.yaml) bytes
+	Checkfile  []byte       `protobuf:"bytes,1,opt,name=checkfile,proto3" json:"checkfile,omitempty"`
+	Outcome    AuditOutcome `protobuf:"varint,2,opt,name=outcome,proto3,enum=AuditOutcome" json:"outcome,omitempty"`
+	Pagination *Pagination  `protobuf:"bytes,3,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	// the typed checkfile, which takes precedence over `checkfile` when set
+	Validation *Checkfile `protobuf:"bytes,4,opt,name=validation,proto3" json:"validation,omitempty"`
+}
+
+func (x *AuditModulesRequest) Reset() {
+	*x = AuditModulesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[53]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AuditModulesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AuditModulesRequest) ProtoMessage() {}
+
+func (x *AuditModulesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[53]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AuditModulesRequest.ProtoReflect.Descriptor instead.
+func (*AuditModulesRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{53}
+}
+
+func (x *AuditModulesRequest) GetCheckfile() []byte {
+	if x != nil {
+		return x.Checkfile
+	}
+	return nil
+}
+
+func (x *AuditModulesRequest) GetOutcome() AuditOutcome {
+	if x != nil {
+		return x.Outcome
+	}
+	return AuditOutcome_PASS
+}
+
+func (x *AuditModulesRequest) GetPagination() *Pagination {
+	if x != nil {
+		return x.Pagination
+	}
+	return nil
+}
+
+func (x *AuditModulesRequest) GetValidation() *Checkfile {
+	if x != nil {
+		return x.Validation
+	}
+	return nil
+}
+
+// The message returned in response to a `AuditModulesRequest`.
+type AuditModulesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// each record contains the ID of the invalid Module which failed the audit, as well as the failure
+	// report produced by the validation check (encoded in JSON)
+	InvalidModuleReport map[int64][]byte `protobuf:"bytes,1,rep,name=invalid_module_report,json=invalidModuleReport,proto3" json:"invalid_module_report,omitempty" protobuf_key:"varint,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	Pagination          *Pagination      `protobuf:"bytes,2,opt,name=pagination,proto3" json:"pagination,omitempty"`
+	// the full count of results in the database (not the count of this message's
+	// `modules`).
+	Total uint64 `protobuf:"varint,3,opt,name=total,proto3" json:"total,omitempty"`
+	Error *Error `protobuf:"bytes,4,opt,name=error,proto3,oneof" json:"error,omitempty"`
+	// the SHA-256 hash of each module included in `invalid_module_report`, keyed by module ID
+	ModuleIdHash map[int64]string `protobuf:"bytes,5,rep,name=module_id_hash,json=moduleIdHash,proto3" json:"module_id_hash,omitempty" protobuf_key:"varint,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+}
+
+func (x *AuditModulesResponse) Reset() {
+	*x = AuditModulesResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[54]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AuditModulesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AuditModulesResponse) ProtoMessage() {}
+
+func (x *AuditModulesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[54]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AuditModulesResponse.ProtoReflect.Descriptor instead.
+func (*AuditModulesResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{54}
+}
+
+func (x *AuditModulesResponse) GetInvalidModuleReport() map[int64][]byte {
+	if x != nil {
+		return x.InvalidModuleReport
+	}
+	return nil
+}
+
+func (x *AuditModulesResponse) GetPagination() *Pagination {
+	if x != nil {
+		return x.Pagination
+	}
+	return nil
+}
+
+func (x *AuditModulesResponse) GetTotal() uint64 {
+	if x != nil {
+		return x.Total
+	}
+	return 0
+}
+
+func (x *AuditModulesResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+func (x *AuditModulesResponse) GetModuleIdHash() map[int64]string {
+	if x != nil {
+		return x.ModuleIdHash
+	}
+	return nil
+}
+
+// `POST /api/v1/diff:`
+// Return the diff of two modules
+type DiffRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Module1       int64 `protobuf:"varint,1,opt,name=module1,proto3" json:"module1,omitempty"`
+	Module2       int64 `protobuf:"varint,2,opt,name=module2,proto3" json:"module2,omitempty"`
+	ColorTerminal bool  `protobuf:"varint,3,opt,name=color_terminal,json=colorTerminal,proto3" json:"color_terminal,omitempty"`
+	WithContext   bool  `protobuf:"varint,4,opt,name=with_context,json=withContext,proto3" json:"with_context,omitempty"`
+}
+
+func (x *DiffRequest) Reset() {
+	*x = DiffRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[55]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DiffRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DiffRequest) ProtoMessage() {}
+
+func (x *DiffRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[55]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DiffRequest.ProtoReflect.Descriptor instead.
+func (*DiffRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{55}
+}
+
+func (x *DiffRequest) GetModule1() int64 {
+	if x != nil {
+		return x.Module1
+	}
+	return 0
+}
+
+func (x *DiffRequest) GetModule2() int64 {
+	if x != nil {
+		return x.Module2
+	}
+	return 0
+}
+
+func (x *DiffRequest) GetColorTerminal() bool {
+	if x != nil {
+		return x.ColorTerminal
+	}
+	return false
+}
+
+func (x *DiffRequest) GetWithContext() bool {
+	if x != nil {
+		return x.WithContext
+	}
+	return false
+}
+
+// The message returned in response to `DiffRequest`, contains a text representation of the difference
+// between the two specified modules.
+type DiffResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Diff  string `protobuf:"bytes,1,opt,name=diff,proto3" json:"diff,omitempty"`
+	Error *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *DiffResponse) Reset() {
+	*x = DiffResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[56]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DiffResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DiffResponse) ProtoMessage() {}
+
+func (x *DiffResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[56]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DiffResponse.ProtoReflect.Descriptor instead.
+func (*DiffResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{56}
+}
+
+func (x *DiffResponse) GetDiff() string {
+	if x != nil {
+		return x.Diff
+	}
+	return ""
+}
+
+func (x *DiffResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// `POST /api/v1/validate:`
+// Return the failure report (if applicable) of a wasm module validation against a given checkfile.
+type ValidateModuleRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// the YAML checkfile (e.g. mod.yaml) bytes
+	Checkfile []byte `protobuf:"bytes,1,opt,name=checkfile,proto3" json:"checkfile,omitempty"`
+	// module_input is either an existing `module_id` that is known to the database, or the bytes of
+	// a raw wasm module. It is used to validate against the given checkfile.
+	//
+	// Types that are assignable to ModuleInput:
+	//
+	//	*ValidateModuleRequest_Module
+	//	*ValidateModuleRequest_ModuleId
+	ModuleInput isValidateModuleRequest_ModuleInput `protobuf_oneof:"module_input"`
+	// the typed checkfile, which takes precedence over `checkfile` when set
+	Validation *Checkfile `protobuf:"bytes,4,opt,name=validation,proto3" json:"validation,omitempty"`
+}
+
+func (x *ValidateModuleRequest) Reset() {
+	*x = ValidateModuleRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[57]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ValidateModuleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ValidateModuleRequest) ProtoMessage() {}
+
+func (x *ValidateModuleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[57]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ValidateModuleRequest.ProtoReflect.Descriptor instead.
+func (*ValidateModuleRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{57}
+}
+
+func (x *ValidateModuleRequest) GetCheckfile() []byte {
+	if x != nil {
+		return x.Checkfile
+	}
+	return nil
+}
+
+func (m *ValidateModuleRequest) GetModuleInput() isValidateModuleRequest_ModuleInput {
+	if m != nil {
+		return m.ModuleInput
+	}
+	return nil
+}
+
+func (x *ValidateModuleRequest) GetModule() []byte {
+	if x, ok := x.GetModuleInput().(*ValidateModuleRequest_Module); ok {
+		return x.Module
+	}
+	return nil
+}
+
+func (x *ValidateModuleRequest) GetModuleId() int64 {
+	if x, ok := x.GetModuleInput().(*ValidateModuleRequest_ModuleId); ok {
+		return x.ModuleId
+	}
+	return 0
+}
+
+func (x *ValidateModuleRequest) GetValidation() *Checkfile {
+	if x != nil {
+		return x.Validation
+	}
+	return nil
+}
+
+type isValidateModuleRequest_ModuleInput interface {
+	isValidateModuleRequest_ModuleInput()
+}
+
+type ValidateModuleRequest_Module struct {
+	Module []byte `protobuf:"bytes,2,opt,name=module,proto3,oneof"`
+}
+
+type ValidateModuleRequest_ModuleId struct {
+	ModuleId int64 `protobuf:"varint,3,opt,name=module_id,json=moduleId,proto3,oneof"`
+}
+
+func (*ValidateModuleRequest_Module) isValidateModuleRequest_ModuleInput() {}
+
+func (*ValidateModuleRequest_ModuleId) isValidateModuleRequest_ModuleInput() {}
+
+// The failure report produced by the validation check (encoded in JSON).
+type ValidateModuleResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	InvalidModuleReport []byte `protobuf:"bytes,1,opt,name=invalid_module_report,json=invalidModuleReport,proto3" json:"invalid_module_report,omitempty"`
+	Error               *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *ValidateModuleResponse) Reset() {
+	*x = ValidateModuleResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[58]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ValidateModuleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ValidateModuleResponse) ProtoMessage() {}
+
+func (x *ValidateModuleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[58]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ValidateModuleResponse.ProtoReflect.Descriptor instead.
+func (*ValidateModuleResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{58}
+}
+
+func (x *ValidateModuleResponse) GetInvalidModuleReport() []byte {
+	if x != nil {
+		return x.InvalidModuleReport
+	}
+	return nil
+}
+
+func (x *ValidateModuleResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// `POST /api/v1/module_graph:`
+// Return a single module_graph.
+type GetModuleGraphRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ModuleId int64 `protobuf:"varint,1,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+}
+
+func (x *GetModuleGraphRequest) Reset() {
+	*x = GetModuleGraphRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[59]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetModuleGraphRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetModuleGraphRequest) ProtoMessage() {}
+
+func (x *GetModuleGraphRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[59]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetModuleGraphRequest.ProtoReflect.Descriptor instead.
+func (*GetModuleGraphRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{59}
+}
+
+func (x *GetModuleGraphRequest) GetModuleId() int64 {
+	if x != nil {
+		return x.ModuleId
+	}
+	return 0
+}
+
+// The message returned in response to a `GetModuleGraphRequest`.
+type GetModuleGraphResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ModuleGraph *ModuleGraph `protobuf:"bytes,1,opt,name=module_graph,json=moduleGraph,proto3" json:"module_graph,omitempty"`
+	Error       *Error       `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *GetModuleGraphResponse) Reset() {
+	*x = GetModuleGraphResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[60]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetModuleGraphResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetModuleGraphResponse) ProtoMessage() {}
+
+func (x *GetModuleGraphResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[60]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetModuleGraphResponse.ProtoReflect.Descriptor instead.
+func (*GetModuleGraphResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{60}
+}
+
+func (x *GetModuleGraphResponse) GetModuleGraph() *ModuleGraph {
+	if x != nil {
+		return x.ModuleGraph
+	}
+	return nil
+}
+
+func (x *GetModuleGraphResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// PUT /api/v1/plugin:
+type InstallPluginRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Identifier string  `protobuf:"bytes,1,opt,name=identifier,proto3" json:"identifier,omitempty"`
+	Name       *string `protobuf:"bytes,2,opt,name=name,proto3,oneof" json:"name,omitempty"`
+	Location   string  `protobuf:"bytes,3,opt,name=location,proto3" json:"location,omitempty"`
+	Wasm       []byte  `protobuf:"bytes,4,opt,name=wasm,proto3" json:"wasm,omitempty"` // bytes config = 5;
+}
+
+func (x *InstallPluginRequest) Reset() {
+	*x = InstallPluginRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[61]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *InstallPluginRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InstallPluginRequest) ProtoMessage() {}
+
+func (x *InstallPluginRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[61]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InstallPluginRequest.ProtoReflect.Descriptor instead.
+func (*InstallPluginRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{61}
+}
+
+func (x *InstallPluginRequest) GetIdentifier() string {
+	if x != nil {
+		return x.Identifier
+	}
+	return ""
+}
+
+func (x *InstallPluginRequest) GetName() string {
+	if x != nil && x.Name != nil {
+		return *x.Name
+	}
+	return ""
+}
+
+func (x *InstallPluginRequest) GetLocation() string {
+	if x != nil {
+		return x.Location
+	}
+	return ""
+}
+
+func (x *InstallPluginRequest) GetWasm() []byte {
+	if x != nil {
+		return x.Wasm
+	}
+	return nil
+}
+
+type InstallPluginResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Hash  string `protobuf:"bytes,1,opt,name=hash,proto3" json:"hash,omitempty"`
+	Error *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *InstallPluginResponse) Reset() {
+	*x = InstallPluginResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[62]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *InstallPluginResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InstallPluginResponse) ProtoMessage() {}
+
+func (x *InstallPluginResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[62]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InstallPluginResponse.ProtoReflect.Descriptor instead.
+func (*InstallPluginResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{62}
+}
+
+func (x *InstallPluginResponse) GetHash() string {
+	if x != nil {
+		return x.Hash
+	}
+	return ""
+}
+
+func (x *InstallPluginResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// DELETE /api/v1/plugin:
+type UninstallPluginRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Identifier string `protobuf:"bytes,1,opt,name=identifier,proto3" json:"identifier,omitempty"`
+}
+
+func (x *UninstallPluginRequest) Reset() {
+	*x = UninstallPluginRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[63]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UninstallPluginRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UninstallPluginRequest) ProtoMessage() {}
+
+func (x *UninstallPluginRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[63]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UninstallPluginRequest.ProtoReflect.Descriptor instead.
+func (*UninstallPluginRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{63}
+}
+
+func (x *UninstallPluginRequest) GetIdentifier() string {
+	if x != nil {
+		return x.Identifier
+	}
+	return ""
+}
+
+type UninstallPluginResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Error *Error `protobuf:"bytes,1,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *UninstallPluginResponse) Reset() {
+	*x = UninstallPluginResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[64]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UninstallPluginResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UninstallPluginResponse) ProtoMessage() {}
+
+func (x *UninstallPluginResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[64]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UninstallPluginResponse.ProtoReflect.Descriptor instead.
+func (*UninstallPluginResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{64}
+}
+
+func (x *UninstallPluginResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// POST /api/v1/plugin:
+type CallPluginRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Identifier   string  `protobuf:"bytes,1,opt,name=identifier,proto3" json:"identifier,omitempty"`
+	FunctionName string  `protobuf:"bytes,2,opt,name=function_name,json=functionName,proto3" json:"function_name,omitempty"`
+	Input        []byte  `protobuf:"bytes,3,opt,name=input,proto3" json:"input,omitempty"`
+	Hash         *string `protobuf:"bytes,4,opt,name=hash,proto3,oneof" json:"hash,omitempty"` // bytes config = 5;
+}
+
+func (x *CallPluginRequest) Reset() {
+	*x = CallPluginRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[65]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CallPluginRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CallPluginRequest) ProtoMessage() {}
+
+func (x *CallPluginRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[65]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CallPluginRequest.ProtoReflect.Descriptor instead.
+func (*CallPluginRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{65}
+}
+
+func (x *CallPluginRequest) GetIdentifier() string {
+	if x != nil {
+		return x.Identifier
+	}
+	return ""
+}
+
+func (x *CallPluginRequest) GetFunctionName() string {
+	if x != nil {
+		return x.FunctionName
+	}
+	return ""
+}
+
+func (x *CallPluginRequest) GetInput() []byte {
+	if x != nil {
+		return x.Input
+	}
+	return nil
+}
+
+func (x *CallPluginRequest) GetHash() string {
+	if x != nil && x.Hash != nil {
+		return *x.Hash
+	}
+	return ""
+}
+
+type CallPluginResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Output []byte `protobuf:"bytes,1,opt,name=output,proto3" json:"output,omitempty"`
+	Error  *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *CallPluginResponse) Reset() {
+	*x = CallPluginResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[66]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CallPluginResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CallPluginResponse) ProtoMessage() {}
+
+func (x *CallPluginResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[66]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CallPluginResponse.ProtoReflect.Descriptor instead.
+func (*CallPluginResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{66}
+}
+
+func (x *CallPluginResponse) GetOutput() []byte {
+	if x != nil {
+		return x.Output
+	}
+	return nil
+}
+
+func (x *CallPluginResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// `GET /api/v1/health:`
+// Check that the server is running and able to handle requests.
+type HealthRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *HealthRequest) Reset() {
+	*x = HealthRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[67]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *HealthRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*HealthRequest) ProtoMessage() {}
+
+func (x *HealthRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[67]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use HealthRequest.ProtoReflect.Descriptor instead.
+func (*HealthRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{67}
+}
+
+// The message returned in response to a `HealthRequest`.
+type HealthResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Ok    bool   `protobuf:"varint,1,opt,name=ok,proto3" json:"ok,omitempty"`
+	Error *Error `protobuf:"bytes,2,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *HealthResponse) Reset() {
+	*x = HealthResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[68]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *HealthResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*HealthResponse) ProtoMessage() {}
+
+func (x *HealthResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[68]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use HealthResponse.ProtoReflect.Descriptor instead.
+func (*HealthResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{68}
+}
+
+func (x *HealthResponse) GetOk() bool {
+	if x != nil {
+		return x.Ok
+	}
+	return false
+}
+
+func (x *HealthResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// `GET /api/v1/info:`
+// Describe the server's version and the API features it supports.
+type ServerInfoRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *ServerInfoRequest) Reset() {
+	*x = ServerInfoRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[69]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ServerInfoRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ServerInfoRequest) ProtoMessage() {}
+
+func (x *ServerInfoRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[69]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ServerInfoRequest.ProtoReflect.Descriptor instead.
+func (*ServerInfoRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{69}
+}
+
+// The message returned in response to a `ServerInfoRequest`.
+type ServerInfoResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Version string `protobuf:"bytes,1,opt,name=version,proto3" json:"version,omitempty"`
+	// the optional API features supported by the server, e.g. "tags", "audit", "diff"
+	Features []string `protobuf:"bytes,2,rep,name=features,proto3" json:"features,omitempty"`
+	// true if plugins can be installed and called on this server
+	PluginsEnabled bool   `protobuf:"varint,3,opt,name=plugins_enabled,json=pluginsEnabled,proto3" json:"plugins_enabled,omitempty"`
+	Error          *Error `protobuf:"bytes,4,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *ServerInfoResponse) Reset() {
+	*x = ServerInfoResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[70]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ServerInfoResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ServerInfoResponse) ProtoMessage() {}
+
+func (x *ServerInfoResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[70]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ServerInfoResponse.ProtoReflect.Descriptor instead.
+func (*ServerInfoResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{70}
+}
+
+func (x *ServerInfoResponse) GetVersion() string {
+	if x != nil {
+		return x.Version
+	}
+	return ""
+}
+
+func (x *ServerInfoResponse) GetFeatures() []string {
+	if x != nil {
+		return x.Features
+	}
+	return nil
+}
+
+func (x *ServerInfoResponse) GetPluginsEnabled() bool {
+	if x != nil {
+		return x.PluginsEnabled
+	}
+	return false
+}
+
+func (x *ServerInfoResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+// A change to a module stored in Modsurfer.
+type ModuleEvent struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// monotonically increasing ID of the event, used as a cursor by subscribers
+	Id         uint64                 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+	Kind       ModuleEventKind        `protobuf:"varint,2,opt,name=kind,proto3,enum=ModuleEventKind" json:"kind,omitempty"`
+	ModuleId   int64                  `protobuf:"varint,3,opt,name=module_id,json=moduleId,proto3" json:"module_id,omitempty"`
+	Hash       string                 `protobuf:"bytes,4,opt,name=hash,proto3" json:"hash,omitempty"`
+	OccurredAt *timestamppb.Timestamp `protobuf:"bytes,5,opt,name=occurred_at,json=occurredAt,proto3" json:"occurred_at,omitempty"`
+}
+
+func (x *ModuleEvent) Reset() {
+	*x = ModuleEvent{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[71]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ModuleEvent) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ModuleEvent) ProtoMessage() {}
+
+func (x *ModuleEvent) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[71]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ModuleEvent.ProtoReflect.Descriptor instead.
+func (*ModuleEvent) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{71}
+}
+
+func (x *ModuleEvent) GetId() uint64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+func (x *ModuleEvent) GetKind() ModuleEventKind {
+	if x != nil {
+		return x.Kind
+	}
+	return ModuleEventKind_MODULE_EVENT_KIND_UNSPECIFIED
+}
+
+func (x *ModuleEvent) GetModuleId() int64 {
+	if x != nil {
+		return x.ModuleId
+	}
+	return 0
+}
+
+func (x *ModuleEvent) GetHash() string {
+	if x != nil {
+		return x.Hash
+	}
+	return ""
+}
+
+func (x *ModuleEvent) GetOccurredAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.OccurredAt
+	}
+	return nil
+}
+
+// `POST /api/v1/events:`
+// Return the module events which occurred after `after_event_id` and match the filters. If no
+// events are available, the server holds the request open for up to `wait_seconds` (long-poll)
+// before returning an empty list of events.
+type SubscribeRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// when unset, only events occurring after the request is received are returned
+	AfterEventId *uint64 `protobuf:"varint,1,opt,name=after_event_id,json=afterEventId,proto3,oneof" json:"after_event_id,omitempty"`
+	WaitSeconds  uint32  `protobuf:"varint,2,opt,name=wait_seconds,json=waitSeconds,proto3" json:"wait_seconds,omitempty"`
+	// only return events of these kinds (all kinds if empty)
+	Kinds []ModuleEventKind `protobuf:"varint,3,rep,packed,name=kinds,proto3,enum=ModuleEventKind" json:"kinds,omitempty"`
+	// only return events for modules with all of these tags
+	Tags []string `protobuf:"bytes,4,rep,name=tags,proto3" json:"tags,omitempty"`
+	Hash *string  `protobuf:"bytes,5,opt,name=hash,proto3,oneof" json:"hash,omitempty"`
+}
+
+func (x *SubscribeRequest) Reset() {
+	*x = SubscribeRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[72]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SubscribeRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SubscribeRequest) ProtoMessage() {}
+
+func (x *SubscribeRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[72]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SubscribeRequest.ProtoReflect.Descriptor instead.
+func (*SubscribeRequest) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{72}
+}
+
+func (x *SubscribeRequest) GetAfterEventId() uint64 {
+	if x != nil && x.AfterEventId != nil {
+		return *x.AfterEventId
+	}
+	return 0
+}
+
+func (x *SubscribeRequest) GetWaitSeconds() uint32 {
+	if x != nil {
+		return x.WaitSeconds
+	}
+	return 0
+}
+
+func (x *SubscribeRequest) GetKinds() []ModuleEventKind {
+	if x != nil {
+		return x.Kinds
+	}
+	return nil
+}
+
+func (x *SubscribeRequest) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
+func (x *SubscribeRequest) GetHash() string {
+	if x != nil && x.Hash != nil {
+		return *x.Hash
+	}
+	return ""
+}
+
+// The message returned in response to a `SubscribeRequest`.
+type SubscribeResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Events []*ModuleEvent `protobuf:"bytes,1,rep,name=events,proto3" json:"events,omitempty"`
+	// the ID of the latest event known to the server, to be used as `after_event_id` in the next
+	// request
+	LastEventId uint64 `protobuf:"varint,2,opt,name=last_event_id,json=lastEventId,proto3" json:"last_event_id,omitempty"`
+	Error       *Error `protobuf:"bytes,3,opt,name=error,proto3,oneof" json:"error,omitempty"`
+}
+
+func (x *SubscribeResponse) Reset() {
+	*x = SubscribeResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_proto_v1_api_proto_msgTypes[73]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SubscribeResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SubscribeResponse) ProtoMessage() {}
+
+func (x *SubscribeResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_v1_api_proto_msgTypes[73]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SubscribeResponse.ProtoReflect.Descriptor instead.
+func (*SubscribeResponse) Descriptor() ([]byte, []int) {
+	return file_proto_v1_api_proto_rawDescGZIP(), []int{73}
+}
+
+func (x *SubscribeResponse) GetEvents() []*ModuleEvent {
+	if x != nil {
+		return x.Events
+	}
+	return nil
+}
+
+func (x *SubscribeResponse) GetLastEventId() uint64 {
+	if x != nil {
+		return x.LastEventId
+	}
+	return 0
+}
+
+func (x *SubscribeResponse) GetError() *Error {
+	if x != nil {
+		return x.Error
+	}
+	return nil
+}
+
+var File_proto_v1_api_proto protoreflect.FileDescriptor
+
+var file_proto_v1_api_proto_rawDesc = []byte{
+	0x0a, 0x12, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2f, 0x76, 0x31, 0x2f, 0x61, 0x70, 0x69, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x64, 0x0a, 0x08, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x12, 0x20, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+	0x0e, 0x32, 0x08, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x06, 0x70, 0x61, 0x72,
+	0x61, 0x6d, 0x73, 0x12, 0x22, 0x0a, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x02,
+	0x20, 0x03, 0x28, 0x0e, 0x32, 0x08, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x07,
+	0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x48, 0x0a, 0x06, 0x49,
+	0x6d, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x1f, 0x0a, 0x0b, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f,
+	0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x6d, 0x6f, 0x64, 0x75,
+	0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x1d, 0x0a, 0x04, 0x66, 0x75, 0x6e, 0x63, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52,
+	0x04, 0x66, 0x75, 0x6e, 0x63, 0x22, 0x27, 0x0a, 0x06, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x12,
+	0x1d, 0x0a, 0x04, 0x66, 0x75, 0x6e, 0x63, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e,
+	0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x04, 0x66, 0x75, 0x6e, 0x63, 0x22, 0xb2,
+	0x09, 0x0a, 0x06, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73,
+	0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a,
+	0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07,
+	0x2e, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73,
+	0x12, 0x21, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x07, 0x2e, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x07, 0x65, 0x78, 0x70, 0x6f,
+	0x72, 0x74, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x12, 0x38, 0x0a, 0x0f, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x61,
+	0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x53,
+	0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x52, 0x0e, 0x73,
+	0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x12, 0x31, 0x0a,
+	0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x15, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
+	0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
+	0x12, 0x3b, 0x0a, 0x0b, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18,
+	0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+	0x70, 0x52, 0x0a, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x18, 0x0a,
+	0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x0b, 0x20, 0x03, 0x28, 0x09, 0x52, 0x07,
+	0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x12, 0x23, 0x0a, 0x0a, 0x63, 0x6f, 0x6d, 0x70, 0x6c,
+	0x65, 0x78, 0x69, 0x74, 0x79, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x0a, 0x63,
+	0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x88, 0x01, 0x01, 0x12, 0x19, 0x0a, 0x05,
+	0x67, 0x72, 0x61, 0x70, 0x68, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x01, 0x52, 0x05, 0x67,
+	0x72, 0x61, 0x70, 0x68, 0x88, 0x01, 0x01, 0x12, 0x44, 0x0a, 0x0f, 0x66, 0x75, 0x6e, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x65, 0x73, 0x18, 0x0f, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x2e, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x0e, 0x66,
+	0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x12, 0x12, 0x0a,
+	0x04, 0x74, 0x61, 0x67, 0x73, 0x18, 0x10, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x74, 0x61, 0x67,
+	0x73, 0x12, 0x23, 0x0a, 0x08, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x69, 0x65, 0x73, 0x18, 0x11, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65,
+	0x6d, 0x6f, 0x72, 0x69, 0x65, 0x73, 0x12, 0x1e, 0x0a, 0x06, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x73,
+	0x18, 0x12, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x52, 0x06,
+	0x74, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x12, 0x21, 0x0a, 0x07, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
+	0x73, 0x18, 0x13, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
+	0x52, 0x07, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x73, 0x12, 0x37, 0x0a, 0x0f, 0x63, 0x75, 0x73,
+	0x74, 0x6f, 0x6d, 0x5f, 0x73, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x14, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x53, 0x65, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x52, 0x0e, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x53, 0x65, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x73, 0x12, 0x47, 0x0a, 0x10, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6d,
+	0x65, 0x74, 0x72, 0x69, 0x63, 0x73, 0x18, 0x15, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x4d,
+	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x2e, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65,
+	0x74, 0x72, 0x69, 0x63, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x0f, 0x66, 0x75, 0x6e, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x72, 0x69, 0x63, 0x73, 0x12, 0x23, 0x0a, 0x09, 0x74,
+	0x6f, 0x6f, 0x6c, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x18, 0x16, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x05,
+	0x2e, 0x54, 0x6f, 0x6f, 0x6c, 0x52, 0x09, 0x74, 0x6f, 0x6f, 0x6c, 0x63, 0x68, 0x61, 0x69, 0x6e,
+	0x12, 0x1d, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x17, 0x20, 0x01, 0x28,
+	0x09, 0x48, 0x02, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12,
+	0x2b, 0x0a, 0x0a, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x18, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65,
+	0x52, 0x0a, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x2b, 0x0a, 0x0a,
+	0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x18, 0x19, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x0b, 0x2e, 0x51, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x52, 0x0a, 0x71,
+	0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x12, 0x2d, 0x0a, 0x09, 0x69, 0x6e, 0x74,
+	0x65, 0x67, 0x72, 0x69, 0x74, 0x79, 0x18, 0x1a, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x49,
+	0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74, 0x79, 0x49, 0x73, 0x73, 0x75, 0x65, 0x52, 0x09, 0x69,
+	0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74, 0x79, 0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61,
+	0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76,
+	0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75,
+	0x65, 0x3a, 0x02, 0x38, 0x01, 0x1a, 0x41, 0x0a, 0x13, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03,
+	0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14,
+	0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76,
+	0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x1a, 0x54, 0x0a, 0x14, 0x46, 0x75, 0x6e, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x72, 0x69, 0x63, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79,
+	0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b,
+	0x65, 0x79, 0x12, 0x26, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x10, 0x2e, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x72,
+	0x69, 0x63, 0x73, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x0d,
+	0x0a, 0x0b, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x42, 0x08, 0x0a,
+	0x06, 0x5f, 0x67, 0x72, 0x61, 0x70, 0x68, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x76, 0x65, 0x72, 0x73,
+	0x69, 0x6f, 0x6e, 0x22, 0x56, 0x0a, 0x0e, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74, 0x79,
+	0x49, 0x73, 0x73, 0x75, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66,
+	0x73, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65,
+	0x74, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22, 0x67, 0x0a, 0x0a, 0x51,
+	0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x72, 0x65, 0x61,
+	0x73, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f,
+	0x6e, 0x12, 0x41, 0x0a, 0x0e, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64,
+	0x5f, 0x61, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67,
+	0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65,
+	0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x0d, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e,
+	0x65, 0x64, 0x41, 0x74, 0x22, 0x50, 0x0a, 0x0a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e,
+	0x63, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x6c, 0x69, 0x63, 0x65, 0x6e, 0x73, 0x65, 0x73, 0x18, 0x01,
+	0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x6c, 0x69, 0x63, 0x65, 0x6e, 0x73, 0x65, 0x73, 0x12, 0x26,
+	0x0a, 0x09, 0x6c, 0x69, 0x62, 0x72, 0x61, 0x72, 0x69, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x08, 0x2e, 0x4c, 0x69, 0x62, 0x72, 0x61, 0x72, 0x79, 0x52, 0x09, 0x6c, 0x69, 0x62,
+	0x72, 0x61, 0x72, 0x69, 0x65, 0x73, 0x22, 0x37, 0x0a, 0x07, 0x4c, 0x69, 0x62, 0x72, 0x61, 0x72,
+	0x79, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22,
+	0x9d, 0x01, 0x0a, 0x06, 0x4d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x69, 0x6e,
+	0x69, 0x74, 0x69, 0x61, 0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x69, 0x6e, 0x69,
+	0x74, 0x69, 0x61, 0x6c, 0x12, 0x1d, 0x0a, 0x07, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x07, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d,
+	0x88, 0x01, 0x01, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x68, 0x61, 0x72, 0x65, 0x64, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x08, 0x52, 0x06, 0x73, 0x68, 0x61, 0x72, 0x65, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x6d,
+	0x65, 0x6d, 0x6f, 0x72, 0x79, 0x36, 0x34, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x6d,
+	0x65, 0x6d, 0x6f, 0x72, 0x79, 0x36, 0x34, 0x12, 0x1a, 0x0a, 0x08, 0x69, 0x6d, 0x70, 0x6f, 0x72,
+	0x74, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x69, 0x6d, 0x70, 0x6f, 0x72,
+	0x74, 0x65, 0x64, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x22,
+	0x95, 0x01, 0x0a, 0x05, 0x54, 0x61, 0x62, 0x6c, 0x65, 0x12, 0x2b, 0x0a, 0x0c, 0x65, 0x6c, 0x65,
+	0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32,
+	0x08, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x0b, 0x65, 0x6c, 0x65, 0x6d, 0x65,
+	0x6e, 0x74, 0x54, 0x79, 0x70, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61,
+	0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c,
+	0x12, 0x1d, 0x0a, 0x07, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x04, 0x48, 0x00, 0x52, 0x07, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x88, 0x01, 0x01, 0x12,
+	0x1a, 0x0a, 0x08, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x08, 0x52, 0x08, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x0a, 0x0a, 0x08, 0x5f,
+	0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x22, 0x58, 0x0a, 0x06, 0x47, 0x6c, 0x6f, 0x62, 0x61,
+	0x6c, 0x12, 0x18, 0x0a, 0x02, 0x74, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x08, 0x2e,
+	0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x02, 0x74, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x6d,
+	0x75, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x6d, 0x75,
+	0x74, 0x61, 0x62, 0x6c, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65,
+	0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65,
+	0x64, 0x22, 0x37, 0x0a, 0x0d, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x53, 0x65, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x04, 0x52, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x22, 0x69, 0x0a, 0x0f, 0x46, 0x75,
+	0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x72, 0x69, 0x63, 0x73, 0x12, 0x1e, 0x0a,
+	0x0a, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0d, 0x52, 0x0a, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x12, 0x22, 0x0a,
+	0x0c, 0x69, 0x6e, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x0c, 0x69, 0x6e, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+	0x73, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52,
+	0x04, 0x73, 0x69, 0x7a, 0x65, 0x22, 0x34, 0x0a, 0x04, 0x54, 0x6f, 0x6f, 0x6c, 0x12, 0x12, 0x0a,
+	0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d,
+	0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x3c, 0x0a, 0x0b, 0x4d,
+	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47, 0x72, 0x61, 0x70, 0x68, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x6a, 0x73,
+	0x6f, 0x6e, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09,
+	0x6a, 0x73, 0x6f, 0x6e, 0x42, 0x79, 0x74, 0x65, 0x73, 0x22, 0x35, 0x0a, 0x05, 0x45, 0x72, 0x72,
+	0x6f, 0x72, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05,
+	0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+	0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+	0x22, 0x3a, 0x0a, 0x0a, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14,
+	0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c,
+	0x69, 0x6d, 0x69, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x4e, 0x0a, 0x04,
+	0x53, 0x6f, 0x72, 0x74, 0x12, 0x28, 0x0a, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x52, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1c,
+	0x0a, 0x05, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x06, 0x2e,
+	0x46, 0x69, 0x65, 0x6c, 0x64, 0x52, 0x05, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x22, 0x93, 0x02, 0x0a,
+	0x13, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x77, 0x61, 0x73, 0x6d, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x04, 0x77, 0x61, 0x73, 0x6d, 0x12, 0x3e, 0x0a, 0x08, 0x6d, 0x65, 0x74, 0x61,
+	0x64, 0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08,
+	0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x1f, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x08, 0x6c, 0x6f,
+	0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61, 0x67,
+	0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x12, 0x1d, 0x0a,
+	0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01,
+	0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x1a, 0x3b, 0x0a, 0x0d,
+	0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a,
+	0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12,
+	0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6c, 0x6f,
+	0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69,
+	0x6f, 0x6e, 0x22, 0x74, 0x0a, 0x14, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75,
+	0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x6f,
+	0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x6d,
+	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72,
+	0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08,
+	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x2f, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x4d,
+	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52,
+	0x08, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x22, 0x61, 0x0a, 0x11, 0x47, 0x65, 0x74,
+	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1f,
+	0x0a, 0x06, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x07,
+	0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x06, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x12,
+	0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06,
+	0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88,
+	0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x5c, 0x0a, 0x12,
+	0x4c, 0x69, 0x73, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12,
+	0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e,
+	0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x22, 0xc3, 0x01, 0x0a, 0x13, 0x4c,
+	0x69, 0x73, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x21, 0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x01, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x07, 0x6d, 0x6f,
+	0x64, 0x75, 0x6c, 0x65, 0x73, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69,
+	0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x12, 0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73,
+	0x6f, 0x72, 0x74, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x22, 0xdd, 0x08, 0x0a, 0x14, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x4d, 0x6f, 0x64, 0x75, 0x6c,
+	0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x13, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x02, 0x69, 0x64, 0x88, 0x01, 0x01, 0x12, 0x17,
+	0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x04,
+	0x68, 0x61, 0x73, 0x68, 0x88, 0x01, 0x01, 0x12, 0x21, 0x0a, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72,
+	0x74, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+	0x74, 0x52, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x21, 0x0a, 0x07, 0x65, 0x78,
+	0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x45, 0x78,
+	0x70, 0x6f, 0x72, 0x74, 0x52, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x1e, 0x0a,
+	0x08, 0x6d, 0x69, 0x6e, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x48,
+	0x02, 0x52, 0x07, 0x6d, 0x69, 0x6e, 0x53, 0x69, 0x7a, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1e, 0x0a,
+	0x08, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x48,
+	0x03, 0x52, 0x07, 0x6d, 0x61, 0x78, 0x53, 0x69, 0x7a, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1f, 0x0a,
+	0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x48,
+	0x04, 0x52, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x3d,
+	0x0a, 0x0f, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67,
+	0x65, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0f, 0x2e, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65,
+	0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x48, 0x05, 0x52, 0x0e, 0x73, 0x6f, 0x75, 0x72,
+	0x63, 0x65, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61, 0x67, 0x65, 0x88, 0x01, 0x01, 0x12, 0x3f, 0x0a,
+	0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x0a, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x23, 0x2e, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45,
+	0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x48,
+	0x0a, 0x0f, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x62, 0x65, 0x66, 0x6f, 0x72,
+	0x65, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74,
+	0x61, 0x6d, 0x70, 0x48, 0x06, 0x52, 0x0e, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x42,
+	0x65, 0x66, 0x6f, 0x72, 0x65, 0x88, 0x01, 0x01, 0x12, 0x46, 0x0a, 0x0e, 0x69, 0x6e, 0x73, 0x65,
+	0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
+	0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x48, 0x07, 0x52, 0x0d,
+	0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x41, 0x66, 0x74, 0x65, 0x72, 0x88, 0x01, 0x01,
+	0x12, 0x18, 0x0a, 0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x0d, 0x20, 0x03, 0x28,
+	0x09, 0x52, 0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x12, 0x28, 0x0a, 0x0d, 0x66, 0x75,
+	0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x0e, 0x20, 0x01, 0x28,
+	0x09, 0x48, 0x08, 0x52, 0x0c, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d,
+	0x65, 0x88, 0x01, 0x01, 0x12, 0x24, 0x0a, 0x0b, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x6e,
+	0x61, 0x6d, 0x65, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x09, 0x48, 0x09, 0x52, 0x0a, 0x6d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61,
+	0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b,
+	0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67,
+	0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x18,
+	0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05, 0x2e, 0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73, 0x6f,
+	0x72, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61, 0x67, 0x73, 0x18, 0x12, 0x20, 0x03, 0x28, 0x09,
+	0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x12, 0x1d, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+	0x6e, 0x18, 0x13, 0x20, 0x01, 0x28, 0x09, 0x48, 0x0a, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69,
+	0x6f, 0x6e, 0x88, 0x01, 0x01, 0x12, 0x2a, 0x0a, 0x0e, 0x6d, 0x69, 0x6e, 0x5f, 0x63, 0x6f, 0x6d,
+	0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x18, 0x14, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x0b, 0x52,
+	0x0d, 0x6d, 0x69, 0x6e, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x88, 0x01,
+	0x01, 0x12, 0x2a, 0x0a, 0x0e, 0x6d, 0x61, 0x78, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78,
+	0x69, 0x74, 0x79, 0x18, 0x15, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x0c, 0x52, 0x0d, 0x6d, 0x61, 0x78,
+	0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x88, 0x01, 0x01, 0x12, 0x1e, 0x0a,
+	0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x18, 0x16, 0x20, 0x03, 0x28,
+	0x09, 0x52, 0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x1a, 0x3b, 0x0a,
+	0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x05, 0x0a, 0x03, 0x5f, 0x69,
+	0x64, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6d,
+	0x69, 0x6e, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6d, 0x61, 0x78, 0x5f,
+	0x73, 0x69, 0x7a, 0x65, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x5f, 0x6c, 0x61, 0x6e,
+	0x67, 0x75, 0x61, 0x67, 0x65, 0x42, 0x12, 0x0a, 0x10, 0x5f, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74,
+	0x65, 0x64, 0x5f, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x42, 0x11, 0x0a, 0x0f, 0x5f, 0x69, 0x6e,
+	0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x42, 0x10, 0x0a, 0x0e,
+	0x5f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x42, 0x0e,
+	0x0a, 0x0c, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x42, 0x0a,
+	0x0a, 0x08, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x11, 0x0a, 0x0f, 0x5f, 0x6d,
+	0x69, 0x6e, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x42, 0x11, 0x0a,
+	0x0f, 0x5f, 0x6d, 0x61, 0x78, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79,
+	0x22, 0xc5, 0x01, 0x0a, 0x15, 0x53, 0x65, 0x61, 0x72, 0x63, 0x68, 0x4d, 0x6f, 0x64, 0x75, 0x6c,
+	0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x07, 0x6d, 0x6f,
+	0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x07, 0x2e, 0x4d, 0x6f,
+	0x64, 0x75, 0x6c, 0x65, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x12, 0x2b, 0x0a,
+	0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a,
+	0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f,
+	0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c,
+	0x12, 0x19, 0x0a, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x05,
+	0x2e, 0x53, 0x6f, 0x72, 0x74, 0x52, 0x04, 0x73, 0x6f, 0x72, 0x74, 0x12, 0x21, 0x0a, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72,
+	0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08,
+	0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x35, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65,
+	0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x1d, 0x0a, 0x0a, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x01,
+	0x20, 0x03, 0x28, 0x03, 0x52, 0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x73, 0x22,
+	0xd5, 0x01, 0x0a, 0x15, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4e, 0x0a, 0x0e, 0x6d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x28, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x0c, 0x6d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72,
+	0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x1a, 0x3f, 0x0a, 0x11,
+	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x45, 0x6e, 0x74, 0x72,
+	0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03,
+	0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x08, 0x0a,
+	0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x43, 0x0a, 0x10, 0x54, 0x61, 0x67, 0x4d, 0x6f,
+	0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x6d,
+	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61, 0x67, 0x73,
+	0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x22, 0x54, 0x0a, 0x11,
+	0x54, 0x61, 0x67, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61, 0x67, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52,
+	0x04, 0x74, 0x61, 0x67, 0x73, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x22, 0x70, 0x0a, 0x17, 0x51, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65,
+	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a,
+	0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x08, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x71, 0x75,
+	0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52,
+	0x0b, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06,
+	0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x72, 0x65,
+	0x61, 0x73, 0x6f, 0x6e, 0x22, 0x74, 0x0a, 0x18, 0x51, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69,
+	0x6e, 0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x2b, 0x0a, 0x0a, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x51, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e,
+	0x65, 0x52, 0x0a, 0x71, 0x75, 0x61, 0x72, 0x61, 0x6e, 0x74, 0x69, 0x6e, 0x65, 0x12, 0x21, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45,
+	0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01,
+	0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x2f, 0x0a, 0x09, 0x43, 0x68,
+	0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x12, 0x22, 0x0a, 0x08, 0x76, 0x61, 0x6c, 0x69, 0x64,
+	0x61, 0x74, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x43, 0x68, 0x65, 0x63,
+	0x6b, 0x52, 0x08, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x22, 0x83, 0x05, 0x0a, 0x05,
+	0x43, 0x68, 0x65, 0x63, 0x6b, 0x12, 0x15, 0x0a, 0x03, 0x75, 0x72, 0x6c, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x48, 0x00, 0x52, 0x03, 0x75, 0x72, 0x6c, 0x88, 0x01, 0x01, 0x12, 0x22, 0x0a, 0x0a,
+	0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x77, 0x61, 0x73, 0x69, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08,
+	0x48, 0x01, 0x52, 0x09, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x57, 0x61, 0x73, 0x69, 0x88, 0x01, 0x01,
+	0x12, 0x27, 0x0a, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0d, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73,
+	0x52, 0x07, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x27, 0x0a, 0x07, 0x65, 0x78, 0x70,
+	0x6f, 0x72, 0x74, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x43, 0x68, 0x65,
+	0x63, 0x6b, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x52, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x72,
+	0x74, 0x73, 0x12, 0x1e, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x0a, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x53, 0x69, 0x7a, 0x65, 0x52, 0x04, 0x73, 0x69,
+	0x7a, 0x65, 0x12, 0x30, 0x0a, 0x0a, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79,
+	0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x43, 0x6f,
+	0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x52, 0x0a, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65,
+	0x78, 0x69, 0x74, 0x79, 0x12, 0x22, 0x0a, 0x0a, 0x75, 0x72, 0x6c, 0x5f, 0x73, 0x68, 0x61, 0x32,
+	0x35, 0x36, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x48, 0x02, 0x52, 0x09, 0x75, 0x72, 0x6c, 0x53,
+	0x68, 0x61, 0x32, 0x35, 0x36, 0x88, 0x01, 0x01, 0x12, 0x1e, 0x0a, 0x04, 0x72, 0x69, 0x73, 0x6b,
+	0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x52, 0x69,
+	0x73, 0x6b, 0x52, 0x04, 0x72, 0x69, 0x73, 0x6b, 0x12, 0x36, 0x0a, 0x0c, 0x63, 0x61, 0x70, 0x61,
+	0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12,
+	0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x43, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69,
+	0x65, 0x73, 0x52, 0x0c, 0x63, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73,
+	0x12, 0x2a, 0x0a, 0x08, 0x6c, 0x69, 0x63, 0x65, 0x6e, 0x73, 0x65, 0x73, 0x18, 0x0a, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4c, 0x69, 0x63, 0x65, 0x6e, 0x73,
+	0x65, 0x73, 0x52, 0x08, 0x6c, 0x69, 0x63, 0x65, 0x6e, 0x73, 0x65, 0x73, 0x12, 0x3f, 0x0a, 0x0f,
+	0x76, 0x75, 0x6c, 0x6e, 0x65, 0x72, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x18,
+	0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x56, 0x75, 0x6c,
+	0x6e, 0x65, 0x72, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x52, 0x0f, 0x76, 0x75,
+	0x6c, 0x6e, 0x65, 0x72, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x69, 0x65, 0x73, 0x12, 0x2d, 0x0a,
+	0x09, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74, 0x79, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x0f, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74,
+	0x79, 0x52, 0x09, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74, 0x79, 0x12, 0x31, 0x0a, 0x0b,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x0d, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x10, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x4e,
+	0x61, 0x6d, 0x65, 0x52, 0x0a, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x12,
+	0x2a, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x0e, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0e, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x52, 0x08, 0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x06, 0x0a, 0x04, 0x5f,
+	0x75, 0x72, 0x6c, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x77, 0x61,
+	0x73, 0x69, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x75, 0x72, 0x6c, 0x5f, 0x73, 0x68, 0x61, 0x32, 0x35,
+	0x36, 0x22, 0x2a, 0x0a, 0x08, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x12, 0x1e, 0x0a,
+	0x05, 0x74, 0x79, 0x70, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x08, 0x2e, 0x56,
+	0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x52, 0x05, 0x74, 0x79, 0x70, 0x65, 0x73, 0x22, 0xc0, 0x02,
+	0x0a, 0x0c, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x2b,
+	0x0a, 0x07, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x11, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x49, 0x74, 0x65,
+	0x6d, 0x73, 0x52, 0x07, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x12, 0x2b, 0x0a, 0x07, 0x65,
+	0x78, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x43,
+	0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52,
+	0x07, 0x65, 0x78, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x12, 0x2e, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x43, 0x68,
+	0x65, 0x63, 0x6b, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x09, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x17, 0x0a, 0x04, 0x6d, 0x6f, 0x64, 0x65,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x6d, 0x6f, 0x64, 0x65, 0x88, 0x01,
+	0x01, 0x12, 0x2a, 0x0a, 0x0e, 0x6d, 0x61, 0x78, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x01, 0x52, 0x0d, 0x6d, 0x61, 0x78,
+	0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x88, 0x01, 0x01, 0x12, 0x2f, 0x0a,
+	0x11, 0x6d, 0x61, 0x78, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x02, 0x52, 0x0f, 0x6d, 0x61, 0x78, 0x50,
+	0x65, 0x72, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x88, 0x01, 0x01, 0x42, 0x07,
+	0x0a, 0x05, 0x5f, 0x6d, 0x6f, 0x64, 0x65, 0x42, 0x11, 0x0a, 0x0f, 0x5f, 0x6d, 0x61, 0x78, 0x5f,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x42, 0x14, 0x0a, 0x12, 0x5f, 0x6d,
+	0x61, 0x78, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x22, 0x3a, 0x0a, 0x10, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x49,
+	0x74, 0x65, 0x6d, 0x73, 0x12, 0x26, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x01, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+	0x74, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x22, 0x9e, 0x01, 0x0a,
+	0x0f, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x49, 0x74, 0x65, 0x6d,
+	0x12, 0x21, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x88, 0x01, 0x01, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x21, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d,
+	0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70,
+	0x65, 0x73, 0x52, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x23, 0x0a, 0x07, 0x72, 0x65,
+	0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x56, 0x61,
+	0x6c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x42,
+	0x0c, 0x0a, 0x0a, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x22, 0x71, 0x0a,
+	0x0f, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73,
+	0x12, 0x2e, 0x0a, 0x07, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x14, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x07, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65,
+	0x12, 0x2e, 0x0a, 0x07, 0x65, 0x78, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x14, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x07, 0x65, 0x78, 0x63, 0x6c, 0x75, 0x64, 0x65,
+	0x22, 0x40, 0x0a, 0x13, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x29, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x05, 0x69, 0x74, 0x65,
+	0x6d, 0x73, 0x22, 0xf2, 0x01, 0x0a, 0x12, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d,
+	0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x30, 0x0a,
+	0x09, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x12, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+	0x49, 0x74, 0x65, 0x6d, 0x52, 0x09, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12,
+	0x28, 0x0a, 0x0d, 0x6d, 0x61, 0x78, 0x5f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x0c, 0x6d, 0x61, 0x78, 0x46, 0x75, 0x6e,
+	0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x88, 0x01, 0x01, 0x12, 0x3d, 0x0a, 0x18, 0x66, 0x6f, 0x72,
+	0x62, 0x69, 0x64, 0x5f, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x5f, 0x66, 0x75, 0x6e, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x48, 0x01, 0x52, 0x16, 0x66,
+	0x6f, 0x72, 0x62, 0x69, 0x64, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x46, 0x75, 0x6e, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x73, 0x88, 0x01, 0x01, 0x42, 0x10, 0x0a, 0x0e, 0x5f, 0x6d, 0x61, 0x78,
+	0x5f, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x42, 0x1b, 0x0a, 0x19, 0x5f, 0x66,
+	0x6f, 0x72, 0x62, 0x69, 0x64, 0x5f, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x5f, 0x66, 0x75,
+	0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0x8b, 0x01, 0x0a, 0x0c, 0x43, 0x68, 0x65, 0x63,
+	0x6b, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x12, 0x2d, 0x0a, 0x07, 0x69, 0x6e, 0x63, 0x6c,
+	0x75, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x43, 0x68, 0x65, 0x63,
+	0x6b, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x07,
+	0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x12, 0x2d, 0x0a, 0x07, 0x65, 0x78, 0x63, 0x6c, 0x75,
+	0x64, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b,
+	0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x52, 0x07, 0x65,
+	0x78, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x12, 0x15, 0x0a, 0x03, 0x6d, 0x61, 0x78, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x03, 0x6d, 0x61, 0x78, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a,
+	0x04, 0x5f, 0x6d, 0x61, 0x78, 0x22, 0x3e, 0x0a, 0x12, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x46, 0x75,
+	0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x12, 0x28, 0x0a, 0x05, 0x69,
+	0x74, 0x65, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x43, 0x68, 0x65,
+	0x63, 0x6b, 0x46, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x05,
+	0x69, 0x74, 0x65, 0x6d, 0x73, 0x22, 0x91, 0x01, 0x0a, 0x11, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x46,
+	0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x12, 0x0a, 0x04, 0x6e,
+	0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12,
+	0x21, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x09, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52, 0x06, 0x70, 0x61, 0x72, 0x61,
+	0x6d, 0x73, 0x12, 0x23, 0x0a, 0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52, 0x07,
+	0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x12, 0x17, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x88, 0x01, 0x01,
+	0x42, 0x07, 0x0a, 0x05, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x22, 0x2a, 0x0a, 0x09, 0x43, 0x68, 0x65,
+	0x63, 0x6b, 0x53, 0x69, 0x7a, 0x65, 0x12, 0x15, 0x0a, 0x03, 0x6d, 0x61, 0x78, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x03, 0x6d, 0x61, 0x78, 0x88, 0x01, 0x01, 0x42, 0x06, 0x0a,
+	0x04, 0x5f, 0x6d, 0x61, 0x78, 0x22, 0x7a, 0x0a, 0x0f, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x43, 0x6f,
+	0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69, 0x74, 0x79, 0x12, 0x2a, 0x0a, 0x08, 0x6d, 0x61, 0x78, 0x5f,
+	0x72, 0x69, 0x73, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x52, 0x69, 0x73,
+	0x6b, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x48, 0x00, 0x52, 0x07, 0x6d, 0x61, 0x78, 0x52, 0x69, 0x73,
+	0x6b, 0x88, 0x01, 0x01, 0x12, 0x20, 0x0a, 0x09, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x63, 0x6f, 0x72,
+	0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x01, 0x52, 0x08, 0x6d, 0x61, 0x78, 0x53, 0x63,
+	0x6f, 0x72, 0x65, 0x88, 0x01, 0x01, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x6d, 0x61, 0x78, 0x5f, 0x72,
+	0x69, 0x73, 0x6b, 0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x63, 0x6f, 0x72,
+	0x65, 0x22, 0x3b, 0x0a, 0x09, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x52, 0x69, 0x73, 0x6b, 0x12, 0x20,
+	0x0a, 0x09, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0d, 0x48, 0x00, 0x52, 0x08, 0x6d, 0x61, 0x78, 0x53, 0x63, 0x6f, 0x72, 0x65, 0x88, 0x01, 0x01,
+	0x42, 0x0c, 0x0a, 0x0a, 0x5f, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x22, 0x27,
+	0x0a, 0x11, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x43, 0x61, 0x70, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74,
+	0x69, 0x65, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x65, 0x6e, 0x79, 0x18, 0x01, 0x20, 0x03, 0x28,
+	0x09, 0x52, 0x04, 0x64, 0x65, 0x6e, 0x79, 0x22, 0x23, 0x0a, 0x0d, 0x43, 0x68, 0x65, 0x63, 0x6b,
+	0x4c, 0x69, 0x63, 0x65, 0x6e, 0x73, 0x65, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x65, 0x6e, 0x79,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x64, 0x65, 0x6e, 0x79, 0x22, 0x6b, 0x0a, 0x14,
+	0x43, 0x68, 0x65, 0x63, 0x6b, 0x56, 0x75, 0x6c, 0x6e, 0x65, 0x72, 0x61, 0x62, 0x69, 0x6c, 0x69,
+	0x74, 0x69, 0x65, 0x73, 0x12, 0x26, 0x0a, 0x0c, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x65, 0x76, 0x65,
+	0x72, 0x69, 0x74, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0b, 0x6d, 0x61,
+	0x78, 0x53, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74, 0x79, 0x88, 0x01, 0x01, 0x12, 0x13, 0x0a, 0x02,
+	0x64, 0x62, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x02, 0x64, 0x62, 0x88, 0x01,
+	0x01, 0x42, 0x0f, 0x0a, 0x0d, 0x5f, 0x6d, 0x61, 0x78, 0x5f, 0x73, 0x65, 0x76, 0x65, 0x72, 0x69,
+	0x74, 0x79, 0x42, 0x05, 0x0a, 0x03, 0x5f, 0x64, 0x62, 0x22, 0x84, 0x02, 0x0a, 0x0e, 0x43, 0x68,
+	0x65, 0x63, 0x6b, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x72, 0x69, 0x74, 0x79, 0x12, 0x3b, 0x0a, 0x17,
+	0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x64, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f,
+	0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52,
+	0x15, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x44, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49,
+	0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x88, 0x01, 0x01, 0x12, 0x3b, 0x0a, 0x17, 0x61, 0x6c, 0x6c,
+	0x6f, 0x77, 0x5f, 0x64, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x65, 0x78, 0x70,
+	0x6f, 0x72, 0x74, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x48, 0x01, 0x52, 0x15, 0x61, 0x6c,
+	0x6c, 0x6f, 0x77, 0x44, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x45, 0x78, 0x70, 0x6f,
+	0x72, 0x74, 0x73, 0x88, 0x01, 0x01, 0x12, 0x2c, 0x0a, 0x0f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f,
+	0x6d, 0x61, 0x6c, 0x66, 0x6f, 0x72, 0x6d, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x48,
+	0x02, 0x52, 0x0e, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x4d, 0x61, 0x6c, 0x66, 0x6f, 0x72, 0x6d, 0x65,
+	0x64, 0x88, 0x01, 0x01, 0x42, 0x1a, 0x0a, 0x18, 0x5f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x64,
+	0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73,
+	0x42, 0x1a, 0x0a, 0x18, 0x5f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x64, 0x75, 0x70, 0x6c, 0x69,
+	0x63, 0x61, 0x74, 0x65, 0x5f, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x42, 0x12, 0x0a, 0x10,
+	0x5f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x6d, 0x61, 0x6c, 0x66, 0x6f, 0x72, 0x6d, 0x65, 0x64,
+	0x22, 0x3c, 0x0a, 0x0f, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x4e,
+	0x61, 0x6d, 0x65, 0x12, 0x1d, 0x0a, 0x07, 0x70, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x07, 0x70, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x88,
+	0x01, 0x01, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x70, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x22, 0x38,
+	0x0a, 0x0d, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12,
+	0x27, 0x0a, 0x0f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x5f, 0x73, 0x63, 0x68, 0x65, 0x6d,
+	0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0e, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65,
+	0x64, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x65, 0x73, 0x22, 0xb5, 0x01, 0x0a, 0x13, 0x41, 0x75, 0x64,
+	0x69, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x1c, 0x0a, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x12, 0x27,
+	0x0a, 0x07, 0x6f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32,
+	0x0d, 0x2e, 0x41, 0x75, 0x64, 0x69, 0x74, 0x4f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x52, 0x07,
+	0x6f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x12, 0x2b, 0x0a, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61,
+	0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x12, 0x2a, 0x0a, 0x0a, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69,
+	0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x43, 0x68, 0x65, 0x63, 0x6b,
+	0x66, 0x69, 0x6c, 0x65, 0x52, 0x0a, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+	0x22, 0xc2, 0x03, 0x0a, 0x14, 0x41, 0x75, 0x64, 0x69, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x62, 0x0a, 0x15, 0x69, 0x6e, 0x76,
+	0x61, 0x6c, 0x69, 0x64, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x72, 0x65, 0x70, 0x6f,
+	0x72, 0x74, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x41, 0x75, 0x64, 0x69, 0x74,
+	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e,
+	0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x70,
+	0x6f, 0x72, 0x74, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x13, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+	0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x2b, 0x0a,
+	0x0a, 0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0b, 0x2e, 0x50, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a,
+	0x70, 0x61, 0x67, 0x69, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f,
+	0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c,
+	0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x88, 0x01, 0x01, 0x12, 0x4d, 0x0a, 0x0e, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64,
+	0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x41, 0x75,
+	0x64, 0x69, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x45,
+	0x6e, 0x74, 0x72, 0x79, 0x52, 0x0c, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x48, 0x61,
+	0x73, 0x68, 0x1a, 0x46, 0x0a, 0x18, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x4d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
+	0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x1a, 0x3f, 0x0a, 0x11, 0x4d, 0x6f,
+	0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x48, 0x61, 0x73, 0x68, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12,
+	0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x6b, 0x65,
+	0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f,
+	0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x8b, 0x01, 0x0a, 0x0b, 0x44, 0x69, 0x66, 0x66, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x31,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x31, 0x12,
+	0x18, 0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x32, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x32, 0x12, 0x25, 0x0a, 0x0e, 0x63, 0x6f, 0x6c,
+	0x6f, 0x72, 0x5f, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x08, 0x52, 0x0d, 0x63, 0x6f, 0x6c, 0x6f, 0x72, 0x54, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x61, 0x6c,
+	0x12, 0x21, 0x0a, 0x0c, 0x77, 0x69, 0x74, 0x68, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x77, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x74,
+	0x65, 0x78, 0x74, 0x22, 0x4f, 0x0a, 0x0c, 0x44, 0x69, 0x66, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x69, 0x66, 0x66, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x04, 0x64, 0x69, 0x66, 0x66, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00,
+	0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x22, 0xaa, 0x01, 0x0a, 0x15, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74,
+	0x65, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1c,
+	0x0a, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0c, 0x52, 0x09, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x66, 0x69, 0x6c, 0x65, 0x12, 0x18, 0x0a, 0x06,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x06,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x12, 0x1d, 0x0a, 0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x48, 0x00, 0x52, 0x08, 0x6d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x49, 0x64, 0x12, 0x2a, 0x0a, 0x0a, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x43, 0x68, 0x65, 0x63,
+	0x6b, 0x66, 0x69, 0x6c, 0x65, 0x52, 0x0a, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x42, 0x0e, 0x0a, 0x0c, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x6e, 0x70, 0x75,
+	0x74, 0x22, 0x79, 0x0a, 0x16, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x4d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x32, 0x0a, 0x15, 0x69,
+	0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x72, 0x65,
+	0x70, 0x6f, 0x72, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x13, 0x69, 0x6e, 0x76, 0x61,
+	0x6c, 0x69, 0x64, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x12,
+	0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06,
+	0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88,
+	0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x34, 0x0a, 0x15,
+	0x47, 0x65, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47, 0x72, 0x61, 0x70, 0x68, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f,
+	0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x49, 0x64, 0x22, 0x76, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47,
+	0x72, 0x61, 0x70, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2f, 0x0a, 0x0c,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x67, 0x72, 0x61, 0x70, 0x68, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47, 0x72, 0x61, 0x70, 0x68,
+	0x52, 0x0b, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x47, 0x72, 0x61, 0x70, 0x68, 0x12, 0x21, 0x0a,
+	0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45,
+	0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01,
+	0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x88, 0x01, 0x0a, 0x14, 0x49,
+	0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x1e, 0x0a, 0x0a, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65,
+	0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66,
+	0x69, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x48, 0x00, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x88, 0x01, 0x01, 0x12, 0x1a, 0x0a, 0x08,
+	0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08,
+	0x6c, 0x6f, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x77, 0x61, 0x73, 0x6d,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x77, 0x61, 0x73, 0x6d, 0x42, 0x07, 0x0a, 0x05,
+	0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x58, 0x0a, 0x15, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
+	0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12,
+	0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61,
+	0x73, 0x68, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22,
+	0x38, 0x0a, 0x16, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67,
+	0x69, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1e, 0x0a, 0x0a, 0x69, 0x64, 0x65,
+	0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x69,
+	0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x22, 0x46, 0x0a, 0x17, 0x55, 0x6e, 0x69,
+	0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65,
+	0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f,
+	0x72, 0x22, 0x90, 0x01, 0x0a, 0x11, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67, 0x69, 0x6e,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1e, 0x0a, 0x0a, 0x69, 0x64, 0x65, 0x6e, 0x74,
+	0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x69, 0x64, 0x65,
+	0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x23, 0x0a, 0x0d, 0x66, 0x75, 0x6e, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0c,
+	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05,
+	0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x70,
+	0x75, 0x74, 0x12, 0x17, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+	0x48, 0x00, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x88, 0x01, 0x01, 0x42, 0x07, 0x0a, 0x05, 0x5f,
+	0x68, 0x61, 0x73, 0x68, 0x22, 0x59, 0x0a, 0x12, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x6c, 0x75, 0x67,
+	0x69, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x75,
+	0x74, 0x70, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x6f, 0x75, 0x74, 0x70,
+	0x75, 0x74, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22,
+	0x0f, 0x0a, 0x0d, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x22, 0x4d, 0x0a, 0x0e, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x6f, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x02,
+	0x6f, 0x6b, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72,
+	0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22,
+	0x13, 0x0a, 0x11, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x22, 0xa0, 0x01, 0x0a, 0x12, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x49,
+	0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76,
+	0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65,
+	0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x1a, 0x0a, 0x08, 0x66, 0x65, 0x61, 0x74, 0x75, 0x72, 0x65,
+	0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x66, 0x65, 0x61, 0x74, 0x75, 0x72, 0x65,
+	0x73, 0x12, 0x27, 0x0a, 0x0f, 0x70, 0x6c, 0x75, 0x67, 0x69, 0x6e, 0x73, 0x5f, 0x65, 0x6e, 0x61,
+	0x62, 0x6c, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x70, 0x6c, 0x75, 0x67,
+	0x69, 0x6e, 0x73, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x12, 0x21, 0x0a, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06, 0x2e, 0x45, 0x72, 0x72, 0x6f,
+	0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88, 0x01, 0x01, 0x42, 0x08, 0x0a,
+	0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xb1, 0x01, 0x0a, 0x0b, 0x4d, 0x6f, 0x64, 0x75,
+	0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x12, 0x24, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x45, 0x76,
+	0x65, 0x6e, 0x74, 0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1b, 0x0a,
+	0x09, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x08, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61,
+	0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x3b,
+	0x0a, 0x0b, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52,
+	0x0a, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x41, 0x74, 0x22, 0xd1, 0x01, 0x0a, 0x10,
+	0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x29, 0x0a, 0x0e, 0x61, 0x66, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x5f,
+	0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x52, 0x0c, 0x61, 0x66, 0x74, 0x65,
+	0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x64, 0x88, 0x01, 0x01, 0x12, 0x21, 0x0a, 0x0c, 0x77,
+	0x61, 0x69, 0x74, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0d, 0x52, 0x0b, 0x77, 0x61, 0x69, 0x74, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x12, 0x26,
+	0x0a, 0x05, 0x6b, 0x69, 0x6e, 0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x10, 0x2e,
+	0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x4b, 0x69, 0x6e, 0x64, 0x52,
+	0x05, 0x6b, 0x69, 0x6e, 0x64, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x61, 0x67, 0x73, 0x18, 0x04,
+	0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x12, 0x17, 0x0a, 0x04, 0x68, 0x61,
+	0x73, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x48, 0x01, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68,
+	0x88, 0x01, 0x01, 0x42, 0x11, 0x0a, 0x0f, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x5f, 0x65, 0x76,
+	0x65, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x42, 0x07, 0x0a, 0x05, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x22,
+	0x8a, 0x01, 0x0a, 0x11, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18,
+	0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x45, 0x76,
+	0x65, 0x6e, 0x74, 0x52, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x22, 0x0a, 0x0d, 0x6c,
+	0x61, 0x73, 0x74, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x0b, 0x6c, 0x61, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x64, 0x12,
+	0x21, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x06,
+	0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x88,
+	0x01, 0x01, 0x42, 0x08, 0x0a, 0x06, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2a, 0xd3, 0x01, 0x0a,
+	0x07, 0x56, 0x61, 0x6c, 0x54, 0x79, 0x70, 0x65, 0x12, 0x07, 0x0a, 0x03, 0x49, 0x33, 0x32, 0x10,
+	0x00, 0x12, 0x07, 0x0a, 0x03, 0x49, 0x36, 0x34, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x46, 0x33,
+	0x32, 0x10, 0x02, 0x12, 0x07, 0x0a, 0x03, 0x46, 0x36, 0x34, 0x10, 0x03, 0x12, 0x08, 0x0a, 0x04,
+	0x56, 0x31, 0x32, 0x38, 0x10, 0x04, 0x12, 0x0b, 0x0a, 0x07, 0x46, 0x75, 0x6e, 0x63, 0x52, 0x65,
+	0x66, 0x10, 0x05, 0x12, 0x0d, 0x0a, 0x09, 0x45, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x52, 0x65, 0x66,
+	0x10, 0x06, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x6f, 0x6f, 0x6c, 0x10, 0x07, 0x12, 0x06, 0x0a, 0x02,
+	0x53, 0x38, 0x10, 0x08, 0x12, 0x06, 0x0a, 0x02, 0x55, 0x38, 0x10, 0x09, 0x12, 0x07, 0x0a, 0x03,
+	0x53, 0x31, 0x36, 0x10, 0x0a, 0x12, 0x07, 0x0a, 0x03, 0x55, 0x31, 0x36, 0x10, 0x0b, 0x12, 0x07,
+	0x0a, 0x03, 0x53, 0x33, 0x32, 0x10, 0x0c, 0x12, 0x07, 0x0a, 0x03, 0x55, 0x33, 0x32, 0x10, 0x0d,
+	0x12, 0x07, 0x0a, 0x03, 0x53, 0x36, 0x34, 0x10, 0x0e, 0x12, 0x07, 0x0a, 0x03, 0x55, 0x36, 0x34,
+	0x10, 0x0f, 0x12, 0x0b, 0x0a, 0x07, 0x46, 0x6c, 0x6f, 0x61, 0x74, 0x33, 0x32, 0x10, 0x10, 0x12,
+	0x0b, 0x0a, 0x07, 0x46, 0x6c, 0x6f, 0x61, 0x74, 0x36, 0x34, 0x10, 0x11, 0x12, 0x08, 0x0a, 0x04,
+	0x43, 0x68, 0x61, 0x72, 0x10, 0x12, 0x12, 0x0a, 0x0a, 0x06, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67,
+	0x10, 0x13, 0x2a, 0x84, 0x01, 0x0a, 0x0e, 0x53, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x4c, 0x61, 0x6e,
+	0x67, 0x75, 0x61, 0x67, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e,
+	0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x52, 0x75, 0x73, 0x74, 0x10, 0x01, 0x12, 0x06, 0x0a, 0x02,
+	0x47, 0x6f, 0x10, 0x02, 0x12, 0x05, 0x0a, 0x01, 0x43, 0x10, 0x03, 0x12, 0x07, 0x0a, 0x03, 0x43,
+	0x70, 0x70, 0x10, 0x04, 0x12, 0x12, 0x0a, 0x0e, 0x41, 0x73, 0x73, 0x65, 0x6d, 0x62, 0x6c, 0x79,
+	0x53, 0x63, 0x72, 0x69, 0x70, 0x74, 0x10, 0x05, 0x12, 0x09, 0x0a, 0x05, 0x53, 0x77, 0x69, 0x66,
+	0x74, 0x10, 0x06, 0x12, 0x0e, 0x0a, 0x0a, 0x4a, 0x61, 0x76, 0x61, 0x53, 0x63, 0x72, 0x69, 0x70,
+	0x74, 0x10, 0x07, 0x12, 0x0b, 0x0a, 0x07, 0x48, 0x61, 0x73, 0x6b, 0x65, 0x6c, 0x6c, 0x10, 0x08,
+	0x12, 0x07, 0x0a, 0x03, 0x5a, 0x69, 0x67, 0x10, 0x09, 0x2a, 0x1e, 0x0a, 0x09, 0x44, 0x69, 0x72,
+	0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x08, 0x0a, 0x04, 0x44, 0x65, 0x73, 0x63, 0x10, 0x00,
+	0x12, 0x07, 0x0a, 0x03, 0x41, 0x73, 0x63, 0x10, 0x01, 0x2a, 0x87, 0x01, 0x0a, 0x05, 0x46, 0x69,
+	0x65, 0x6c, 0x64, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x41, 0x74,
+	0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x4e, 0x61, 0x6d, 0x65, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04,
+	0x53, 0x69, 0x7a, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x4c, 0x61, 0x6e, 0x67, 0x75, 0x61,
+	0x67, 0x65, 0x10, 0x03, 0x12, 0x10, 0x0a, 0x0c, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x43,
+	0x6f, 0x75, 0x6e, 0x74, 0x10, 0x04, 0x12, 0x10, 0x0a, 0x0c, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74,
+	0x73, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x10, 0x05, 0x12, 0x0a, 0x0a, 0x06, 0x53, 0x68, 0x61, 0x32,
+	0x35, 0x36, 0x10, 0x06, 0x12, 0x0e, 0x0a, 0x0a, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x78, 0x69,
+	0x74, 0x79, 0x10, 0x07, 0x12, 0x0d, 0x0a, 0x09, 0x52, 0x69, 0x73, 0x6b, 0x53, 0x63, 0x6f, 0x72,
+	0x65, 0x10, 0x08, 0x2a, 0x2a, 0x0a, 0x09, 0x52, 0x69, 0x73, 0x6b, 0x4c, 0x65, 0x76, 0x65, 0x6c,
+	0x12, 0x07, 0x0a, 0x03, 0x4c, 0x4f, 0x57, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x4d, 0x45, 0x44,
+	0x49, 0x55, 0x4d, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x48, 0x49, 0x47, 0x48, 0x10, 0x02, 0x2a,
+	0x22, 0x0a, 0x0c, 0x41, 0x75, 0x64, 0x69, 0x74, 0x4f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x12,
+	0x08, 0x0a, 0x04, 0x50, 0x41, 0x53, 0x53, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x46, 0x41, 0x49,
+	0x4c, 0x10, 0x01, 0x2a, 0x67, 0x0a, 0x0f, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x45, 0x76, 0x65,
+	0x6e, 0x74, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x21, 0x0a, 0x1d, 0x4d, 0x4f, 0x44, 0x55, 0x4c, 0x45,
+	0x5f, 0x45, 0x56, 0x45, 0x4e, 0x54, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+	0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x0b, 0x0a, 0x07, 0x43, 0x52, 0x45,
+	0x41, 0x54, 0x45, 0x44, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x50, 0x44, 0x41, 0x54, 0x45,
+	0x44, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x44, 0x45, 0x4c, 0x45, 0x54, 0x45, 0x44, 0x10, 0x03,
+	0x12, 0x0a, 0x0a, 0x06, 0x59, 0x41, 0x4e, 0x4b, 0x45, 0x44, 0x10, 0x04, 0x42, 0x0f, 0x5a, 0x0d,
+	0x2e, 0x2f, 0x6d, 0x6f, 0x64, 0x73, 0x75, 0x72, 0x66, 0x65, 0x72, 0x70, 0x62, 0x62, 0x06, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var (
+	file_proto_v1_api_proto_rawDescOnce sync.Once
+	file_proto_v1_api_proto_rawDescData = file_proto_v1_api_proto_rawDesc
+)
+
+func file_proto_v1_api_proto_rawDescGZIP() []byte {
+	file_proto_v1_api_proto_rawDescOnce.Do(func() {
+		file_proto_v1_api_proto_rawDescData = protoimpl.X.CompressGZIP(file_proto_v1_api_proto_rawDescData)
+	})
+	return file_proto_v1_api_proto_rawDescData
+}
+
+var file_proto_v1_api_proto_enumTypes = make([]protoimpl.EnumInfo, 7)
+var file_proto_v1_api_proto_msgTypes = make([]protoimpl.MessageInfo, 82)
+var file_proto_v1_api_proto_goTypes = []interface{}{
+	(ValType)(0),                     // 0: ValType
+	(SourceLanguage)(0),              // 1: SourceLanguage
+	(Direction)(0),                   // 2: Direction
+	(Field)(0),                       // 3: Field
+	(RiskLevel)(0),                   // 4: RiskLevel
+	(AuditOutcome)(0),                // 5: AuditOutcome
+	(ModuleEventKind)(0),             // 6: ModuleEventKind
+	(*Function)(nil),                 // 7: Function
+	(*Import)(nil),                   // 8: Import
+	(*Export)(nil),                   // 9: Export
+	(*Module)(nil),                   // 10: Module
+	(*IntegrityIssue)(nil),           // 11: IntegrityIssue
+	(*Quarantine)(nil),               // 12: Quarantine
+	(*Provenance)(nil),               // 13: Provenance
+	(*Library)(nil),                  // 14: Library
+	(*Memory)(nil),                   // 15: Memory
+	(*Table)(nil),                    // 16: Table
+	(*Global)(nil),                   // 17: Global
+	(*CustomSection)(nil),            // 18: CustomSection
+	(*FunctionMetrics)(nil),          // 19: FunctionMetrics
+	(*Tool)(nil),                     // 20: Tool
+	(*ModuleGraph)(nil),              // 21: ModuleGraph
+	(*Error)(nil),                    // 22: Error
+	(*Pagination)(nil),               // 23: Pagination
+	(*Sort)(nil),                     // 24: Sort
+	(*CreateModuleRequest)(nil),      // 25: CreateModuleRequest
+	(*CreateModuleResponse)(nil),     // 26: CreateModuleResponse
+	(*GetModuleRequest)(nil),         // 27: GetModuleRequest
+	(*GetModuleResponse)(nil),        // 28: GetModuleResponse
+	(*ListModulesRequest)(nil),       // 29: ListModulesRequest
+	(*ListModulesResponse)(nil),      // 30: ListModulesResponse
+	(*SearchModulesRequest)(nil),     // 31: SearchModulesRequest
+	(*SearchModulesResponse)(nil),    // 32: SearchModulesResponse
+	(*DeleteModulesRequest)(nil),     // 33: DeleteModulesRequest
+	(*DeleteModulesResponse)(nil),    // 34: DeleteModulesResponse
+	(*TagModuleRequest)(nil),         // 35: TagModuleRequest
+	(*TagModuleResponse)(nil),        // 36: TagModuleResponse
+	(*QuarantineModuleRequest)(nil),  // 37: QuarantineModuleRequest
+	(*QuarantineModuleResponse)(nil), // 38: QuarantineModuleResponse
+	(*Checkfile)(nil),                // 39: Checkfile
+	(*Check)(nil),                    // 40: Check
+	(*ValTypes)(nil),                 // 41: ValTypes
+	(*CheckImports)(nil),             // 42: CheckImports
+	(*CheckImportItems)(nil),         // 43: CheckImportItems
+	(*CheckImportItem)(nil),          // 44: CheckImportItem
+	(*CheckNamespaces)(nil),          // 45: CheckNamespaces
+	(*CheckNamespaceItems)(nil),      // 46: CheckNamespaceItems
+	(*CheckNamespaceItem)(nil),       // 47: CheckNamespaceItem
+	(*CheckExports)(nil),             // 48: CheckExports
+	(*CheckFunctionItems)(nil),       // 49: CheckFunctionItems
+	(*CheckFunctionItem)(nil),        // 50: CheckFunctionItem
+	(*CheckSize)(nil),                // 51: CheckSize
+	(*CheckComplexity)(nil),          // 52: CheckComplexity
+	(*CheckRisk)(nil),                // 53: CheckRisk
+	(*CheckCapabilities)(nil),        // 54: CheckCapabilities
+	(*CheckLicenses)(nil),            // 55: CheckLicenses
+	(*CheckVulnerabilities)(nil),     // 56: CheckVulnerabilities
+	(*CheckIntegrity)(nil),           // 57: CheckIntegrity
+	(*CheckModuleName)(nil),          // 58: CheckModuleName
+	(*CheckLocation)(nil),            // 59: CheckLocation
+	(*AuditModulesRequest)(nil),      // 60: AuditModulesRequest
+	(*AuditModulesResponse)(nil),     // 61: AuditModulesResponse
+	(*DiffRequest)(nil),              // 62: DiffRequest
+	(*DiffResponse)(nil),             // 63: DiffResponse
+	(*ValidateModuleRequest)(nil),    // 64: ValidateModuleRequest
+	(*ValidateModuleResponse)(nil),   // 65: ValidateModuleResponse
+	(*GetModuleGraphRequest)(nil),    // 66: GetModuleGraphRequest
+	(*GetModuleGraphResponse)(nil),   // 67: GetModuleGraphResponse
+	(*InstallPluginRequest)(nil),     // 68: InstallPluginRequest
+	(*InstallPluginResponse)(nil),    // 69: InstallPluginResponse
+	(*UninstallPluginRequest)(nil),   // 70: UninstallPluginRequest
+	(*UninstallPluginResponse)(nil),  // 71: UninstallPluginResponse
+	(*CallPluginRequest)(nil),        // 72: CallPluginRequest
+	(*CallPluginResponse)(nil),       // 73: CallPluginResponse
+	(*HealthRequest)(nil),            // 74: HealthRequest
+	(*HealthResponse)(nil),           // 75: HealthResponse
+	(*ServerInfoRequest)(nil),        // 76: ServerInfoRequest
+	(*ServerInfoResponse)(nil),       // 77: ServerInfoResponse
+	(*ModuleEvent)(nil),              // 78: ModuleEvent
+	(*SubscribeRequest)(nil),         // 79: SubscribeRequest
+	(*SubscribeResponse)(nil),        // 80: SubscribeResponse
+	nil,                              // 81: Module.MetadataEntry
+	nil,                              // 82: Module.FunctionHashesEntry
+	nil,                              // 83: Module.FunctionMetricsEntry
+	nil,                              // 84: CreateModuleRequest.MetadataEntry
+	nil,                              // 85: SearchModulesRequest.MetadataEntry
+	nil,                              // 86: DeleteModulesResponse.ModuleIdHashEntry
+	nil,                              // 87: AuditModulesResponse.InvalidModuleReportEntry
+	nil,                              // 88: AuditModulesResponse.ModuleIdHashEntry
+	(*timestamppb.Timestamp)(nil),    // 89: google.protobuf.Timestamp
+}
+var file_proto_v1_api_proto_depIdxs = []int32{
+	0,   // 0: Function.params:type_name -> ValType
+	0,   // 1: Function.results:type_name -> ValType
+	7,   // 2: Import.func:type_name -> Function
+	7,   // 3: Export.func:type_name -> Function
+	8,   // 4: Module.imports:type_name -> Import
+	9,   // 5: Module.exports:type_name -> Export
+	1,   // 6: Module.source_language:type_name -> SourceLanguage
+	81,  // 7: Module.metadata:type_name -> Module.MetadataEntry
+	89,  // 8: Module.inserted_at:type_name -> google.protobuf.Timestamp
+	82,  // 9: Module.function_hashes:type_name -> Module.FunctionHashesEntry
+	15,  // 10: Module.memories:type_name -> Memory
+	16,  // 11: Module.tables:type_name -> Table
+	17,  // 12: Module.globals:type_name -> Global
+	18,  // 13: Module.custom_sections:type_name -> CustomSection
+	83,  // 14: Module.function_metrics:type_name -> Module.FunctionMetricsEntry
+	20,  // 15: Module.toolchain:type_name -> Tool
+	13,  // 16: Module.provenance:type_name -> Provenance
+	12,  // 17: Module.quarantine:type_name -> Quarantine
+	11,  // 18: Module.integrity:type_name -> IntegrityIssue
+	89,  // 19: Quarantine.quarantined_at:type_name -> google.protobuf.Timestamp
+	14,  // 20: Provenance.libraries:type_name -> Library
+	0,   // 21: Table.element_type:type_name -> ValType
+	0,   // 22: Global.ty:type_name -> ValType
+	2,   // 23: Sort.direction:type_name -> Direction
+	3,   // 24: Sort.field:type_name -> Field
+	84,  // 25: CreateModuleRequest.metadata:type_name -> CreateModuleRequest.MetadataEntry
+	22,  // 26: CreateModuleResponse.error:type_name -> Error
+	10,  // 27: GetModuleResponse.module:type_name -> Module
+	22,  // 28: GetModuleResponse.error:type_name -> Error
+	23,  // 29: ListModulesRequest.pagination:type_name -> Pagination
+	24,  // 30: ListModulesRequest.sort:type_name -> Sort
+	10,  // 31: ListModulesResponse.modules:type_name -> Module
+	23,  // 32: ListModulesResponse.pagination:type_name -> Pagination
+	24,  // 33: ListModulesResponse.sort:type_name -> Sort
+	22,  // 34: ListModulesResponse.error:type_name -> Error
+	8,   // 35: SearchModulesRequest.imports:type_name -> Import
+	9,   // 36: SearchModulesRequest.exports:type_name -> Export
+	1,   // 37: SearchModulesRequest.source_language:type_name -> SourceLanguage
+	85,  // 38: SearchModulesRequest.metadata:type_name -> SearchModulesRequest.MetadataEntry
+	89,  // 39: SearchModulesRequest.inserted_before:type_name -> google.protobuf.Timestamp
+	89,  // 40: SearchModulesRequest.inserted_after:type_name -> google.protobuf.Timestamp
+	23,  // 41: SearchModulesRequest.pagination:type_name -> Pagination
+	24,  // 42: SearchModulesRequest.sort:type_name -> Sort
+	10,  // 43: SearchModulesResponse.modules:type_name -> Module
+	23,  // 44: SearchModulesResponse.pagination:type_name -> Pagination
+	24,  // 45: SearchModulesResponse.sort:type_name -> Sort
+	22,  // 46: SearchModulesResponse.error:type_name -> Error
+	86,  // 47: DeleteModulesResponse.module_id_hash:type_name -> DeleteModulesResponse.ModuleIdHashEntry
+	22,  // 48: DeleteModulesResponse.error:type_name -> Error
+	22,  // 49: TagModuleResponse.error:type_name -> Error
+	12,  // 50: QuarantineModuleResponse.quarantine:type_name -> Quarantine
+	22,  // 51: QuarantineModuleResponse.error:type_name -> Error
+	40,  // 52: Checkfile.validate:type_name -> Check
+	42,  // 53: Check.imports:type_name -> CheckImports
+	48,  // 54: Check.exports:type_name -> CheckExports
+	51,  // 55: Check.size:type_name -> CheckSize
+	52,  // 56: Check.complexity:type_name -> CheckComplexity
+	53,  // 57: Check.risk:type_name -> CheckRisk
+	54,  // 58: Check.capabilities:type_name -> CheckCapabilities
+	55,  // 59: Check.licenses:type_name -> CheckLicenses
+	56,  // 60: Check.vulnerabilities:type_name -> CheckVulnerabilities
+	57,  // 61: Check.integrity:type_name -> CheckIntegrity
+	58,  // 62: Check.module_name:type_name -> CheckModuleName
+	59,  // 63: Check.location:type_name -> CheckLocation
+	0,   // 64: ValTypes.types:type_name -> ValType
+	43,  // 65: CheckImports.include:type_name -> CheckImportItems
+	43,  // 66: CheckImports.exclude:type_name -> CheckImportItems
+	45,  // 67: CheckImports.namespace:type_name -> CheckNamespaces
+	44,  // 68: CheckImportItems.items:type_name -> CheckImportItem
+	41,  // 69: CheckImportItem.params:type_name -> ValTypes
+	41,  // 70: CheckImportItem.results:type_name -> ValTypes
+	46,  // 71: CheckNamespaces.include:type_name -> CheckNamespaceItems
+	46,  // 72: CheckNamespaces.exclude:type_name -> CheckNamespaceItems
+	47,  // 73: CheckNamespaceItems.items:type_name -> CheckNamespaceItem
+	50,  // 74: CheckNamespaceItem.functions:type_name -> CheckFunctionItem
+	49,  // 75: CheckExports.include:type_name -> CheckFunctionItems
+	49,  // 76: CheckExports.exclude:type_name -> CheckFunctionItems
+	50,  // 77: CheckFunctionItems.items:type_name -> CheckFunctionItem
+	41,  // 78: CheckFunctionItem.params:type_name -> ValTypes
+	41,  // 79: CheckFunctionItem.results:type_name -> ValTypes
+	4,   // 80: CheckComplexity.max_risk:type_name -> RiskLevel
+	5,   // 81: AuditModulesRequest.outcome:type_name -> AuditOutcome
+	23,  // 82: AuditModulesRequest.pagination:type_name -> Pagination
+	39,  // 83: AuditModulesRequest.validation:type_name -> Checkfile
+	87,  // 84: AuditModulesResponse.invalid_module_report:type_name -> AuditModulesResponse.InvalidModuleReportEntry
+	23,  // 85: AuditModulesResponse.pagination:type_name -> Pagination
+	22,  // 86: AuditModulesResponse.error:type_name -> Error
+	88,  // 87: AuditModulesResponse.module_id_hash:type_name -> AuditModulesResponse.ModuleIdHashEntry
+	22,  // 88: DiffResponse.error:type_name -> Error
+	39,  // 89: ValidateModuleRequest.validation:type_name -> Checkfile
+	22,  // 90: ValidateModuleResponse.error:type_name -> Error
+	21,  // 91: GetModuleGraphResponse.module_graph:type_name -> ModuleGraph
+	22,  // 92: GetModuleGraphResponse.error:type_name -> Error
+	22,  // 93: InstallPluginResponse.error:type_name -> Error
+	22,  // 94: UninstallPluginResponse.error:type_name -> Error
+	22,  // 95: CallPluginResponse.error:type_name -> Error
+	22,  // 96: HealthResponse.error:type_name -> Error
+	22,  // 97: ServerInfoResponse.error:type_name -> Error
+	6,   // 98: ModuleEvent.kind:type_name -> ModuleEventKind
+	89,  // 99: ModuleEvent.occurred_at:type_name -> google.protobuf.Timestamp
+	6,   // 100: SubscribeRequest.kinds:type_name -> ModuleEventKind
+	78,  // 101: SubscribeResponse.events:type_name -> ModuleEvent
+	22,  // 102: SubscribeResponse.error:type_name -> Error
+	19,  // 103: Module.FunctionMetricsEntry.value:type_name -> FunctionMetrics
+	104, // [104:104] is the sub-list for method output_type
+	104, // [104:104] is the sub-list for method input_type
+	104, // [104:104] is the sub-list for extension type_name
+	104, // [104:104] is the sub-list for extension extendee
+	0,   // [0:104] is the sub-list for field type_name
+}
+
+func init() { file_proto_v1_api_proto_init() }
+func file_proto_v1_api_proto_init() {
+	if File_proto_v1_api_proto != nil {
+		return
+	}
+	if !protoimpl.UnsafeEnabled {
+		file_proto_v1_api_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Function); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Import); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Export); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Module); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*IntegrityIssue); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Quarantine); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Provenance); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Library); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Memory); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Table); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Global); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CustomSection); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*FunctionMetrics); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Tool); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ModuleGraph); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Error); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Pagination); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Sort); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateModuleRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateModuleResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetModuleRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetModuleResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListModulesRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListModulesResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SearchModulesRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SearchModulesResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteModulesRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteModulesResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*TagModuleRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*TagModuleResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QuarantineModuleRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QuarantineModuleResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Checkfile); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Check); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ValTypes); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckImports); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2980,8 +6830,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Import); i {
+		file_proto_v1_api_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckImportItems); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2992,8 +6842,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Export); i {
+		file_proto_v1_api_proto_msgTypes[37].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckImportItem); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3004,8 +6854,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Module); i {
+		file_proto_v1_api_proto_msgTypes[38].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckNamespaces); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3016,8 +6866,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ModuleGraph); i {
+		file_proto_v1_api_proto_msgTypes[39].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckNamespaceItems); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3028,8 +6878,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Error); i {
+		file_proto_v1_api_proto_msgTypes[40].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckNamespaceItem); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3040,8 +6890,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Pagination); i {
+		file_proto_v1_api_proto_msgTypes[41].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckExports); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3052,8 +6902,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Sort); i {
+		file_proto_v1_api_proto_msgTypes[42].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckFunctionItems); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3064,8 +6914,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateModuleRequest); i {
+		file_proto_v1_api_proto_msgTypes[43].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckFunctionItem); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3076,8 +6926,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateModuleResponse); i {
+		file_proto_v1_api_proto_msgTypes[44].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckSize); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3088,8 +6938,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetModuleRequest); i {
+		file_proto_v1_api_proto_msgTypes[45].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckComplexity); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3100,8 +6950,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetModuleResponse); i {
+		file_proto_v1_api_proto_msgTypes[46].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckRisk); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3112,8 +6962,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListModulesRequest); i {
+		file_proto_v1_api_proto_msgTypes[47].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckCapabilities); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3124,8 +6974,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListModulesResponse); i {
+		file_proto_v1_api_proto_msgTypes[48].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckLicenses); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3136,8 +6986,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SearchModulesRequest); i {
+		file_proto_v1_api_proto_msgTypes[49].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckVulnerabilities); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3148,8 +6998,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SearchModulesResponse); i {
+		file_proto_v1_api_proto_msgTypes[50].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckIntegrity); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3160,8 +7010,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteModulesRequest); i {
+		file_proto_v1_api_proto_msgTypes[51].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckModuleName); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3172,8 +7022,8 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteModulesResponse); i {
+		file_proto_v1_api_proto_msgTypes[52].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CheckLocation); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3184,7 +7034,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[53].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AuditModulesRequest); i {
 			case 0:
 				return &v.state
@@ -3196,7 +7046,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[54].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AuditModulesResponse); i {
 			case 0:
 				return &v.state
@@ -3208,7 +7058,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[55].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*DiffRequest); i {
 			case 0:
 				return &v.state
@@ -3220,7 +7070,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[56].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*DiffResponse); i {
 			case 0:
 				return &v.state
@@ -3232,7 +7082,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[57].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*ValidateModuleRequest); i {
 			case 0:
 				return &v.state
@@ -3244,7 +7094,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[58].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*ValidateModuleResponse); i {
 			case 0:
 				return &v.state
@@ -3256,7 +7106,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[59].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*GetModuleGraphRequest); i {
 			case 0:
 				return &v.state
@@ -3268,7 +7118,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[60].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*GetModuleGraphResponse); i {
 			case 0:
 				return &v.state
@@ -3280,7 +7130,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[61].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*InstallPluginRequest); i {
 			case 0:
 				return &v.state
@@ -3292,7 +7142,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[62].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*InstallPluginResponse); i {
 			case 0:
 				return &v.state
@@ -3304,7 +7154,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[63].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*UninstallPluginRequest); i {
 			case 0:
 				return &v.state
@@ -3316,7 +7166,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[64].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*UninstallPluginResponse); i {
 			case 0:
 				return &v.state
@@ -3328,7 +7178,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[65].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CallPluginRequest); i {
 			case 0:
 				return &v.state
@@ -3340,7 +7190,7 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
-		file_proto_v1_api_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
+		file_proto_v1_api_proto_msgTypes[66].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CallPluginResponse); i {
 			case 0:
 				return &v.state
@@ -3352,35 +7202,139 @@ func file_proto_v1_api_proto_init() {
 				return nil
 			}
 		}
+		file_proto_v1_api_proto_msgTypes[67].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*HealthRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[68].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*HealthResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[69].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ServerInfoRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[70].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ServerInfoResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[71].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ModuleEvent); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[72].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SubscribeRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_proto_v1_api_proto_msgTypes[73].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SubscribeResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
 	}
 	file_proto_v1_api_proto_msgTypes[3].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[8].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[9].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[11].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[13].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[14].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[15].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[17].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[18].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[19].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[21].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[22].OneofWrappers = []interface{}{
-		(*ValidateModuleRequest_Module)(nil),
-		(*ValidateModuleRequest_ModuleId)(nil),
-	}
 	file_proto_v1_api_proto_msgTypes[23].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[24].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[25].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[26].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[27].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[29].OneofWrappers = []interface{}{}
-	file_proto_v1_api_proto_msgTypes[30].OneofWrappers = []interface{}{}
 	file_proto_v1_api_proto_msgTypes[31].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[33].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[35].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[37].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[40].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[41].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[43].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[44].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[45].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[46].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[49].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[50].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[51].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[54].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[56].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[57].OneofWrappers = []interface{}{
+		(*ValidateModuleRequest_Module)(nil),
+		(*ValidateModuleRequest_ModuleId)(nil),
+	}
+	file_proto_v1_api_proto_msgTypes[58].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[60].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[61].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[62].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[64].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[65].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[66].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[68].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[70].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[72].OneofWrappers = []interface{}{}
+	file_proto_v1_api_proto_msgTypes[73].OneofWrappers = []interface{}{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_proto_v1_api_proto_rawDesc,
-			NumEnums:      5,
-			NumMessages:   38,
+			NumEnums:      7,
+			NumMessages:   82,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
@@ -506,6 +506,39 @@ pub struct Module {
     ///  function hashes
     // @@protoc_insertion_point(field:Module.function_hashes)
     pub function_hashes: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    ///  free-form labels used to group modules for fleet queries, e.g. `edge`
+    // @@protoc_insertion_point(field:Module.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    ///  linear memories defined or imported by the module
+    // @@protoc_insertion_point(field:Module.memories)
+    pub memories: ::std::vec::Vec<Memory>,
+    ///  tables defined or imported by the module
+    // @@protoc_insertion_point(field:Module.tables)
+    pub tables: ::std::vec::Vec<Table>,
+    ///  globals defined or imported by the module
+    // @@protoc_insertion_point(field:Module.globals)
+    pub globals: ::std::vec::Vec<Global>,
+    ///  custom sections contained in the module
+    // @@protoc_insertion_point(field:Module.custom_sections)
+    pub custom_sections: ::std::vec::Vec<CustomSection>,
+    ///  metrics for each function defined by the module, keyed by function name
+    // @@protoc_insertion_point(field:Module.function_metrics)
+    pub function_metrics: ::std::collections::HashMap<::std::string::String, FunctionMetrics>,
+    ///  tools which produced the module, from the `producers` section
+    // @@protoc_insertion_point(field:Module.toolchain)
+    pub toolchain: ::std::vec::Vec<Tool>,
+    ///  version of the module, e.g. `1.2.0`, if one was provided or found in the module
+    // @@protoc_insertion_point(field:Module.version)
+    pub version: ::std::option::Option<::std::string::String>,
+    ///  licenses and libraries found in the module's strings and custom sections
+    // @@protoc_insertion_point(field:Module.provenance)
+    pub provenance: ::protobuf::MessageField<Provenance>,
+    ///  set while the module is quarantined
+    // @@protoc_insertion_point(field:Module.quarantine)
+    pub quarantine: ::protobuf::MessageField<Quarantine>,
+    ///  structural problems found in the module's binary
+    // @@protoc_insertion_point(field:Module.integrity)
+    pub integrity: ::std::vec::Vec<IntegrityIssue>,
     // special fields
     // @@protoc_insertion_point(special_field:Module.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -523,7 +556,7 @@ impl Module {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(13);
+        let mut fields = ::std::vec::Vec::with_capacity(24);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "id",
@@ -590,6 +623,61 @@ impl Module {
             |m: &Module| { &m.function_hashes },
             |m: &mut Module| { &mut m.function_hashes },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &Module| { &m.tags },
+            |m: &mut Module| { &mut m.tags },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "memories",
+            |m: &Module| { &m.memories },
+            |m: &mut Module| { &mut m.memories },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tables",
+            |m: &Module| { &m.tables },
+            |m: &mut Module| { &mut m.tables },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "globals",
+            |m: &Module| { &m.globals },
+            |m: &mut Module| { &mut m.globals },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "custom_sections",
+            |m: &Module| { &m.custom_sections },
+            |m: &mut Module| { &mut m.custom_sections },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "function_metrics",
+            |m: &Module| { &m.function_metrics },
+            |m: &mut Module| { &mut m.function_metrics },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "toolchain",
+            |m: &Module| { &m.toolchain },
+            |m: &mut Module| { &mut m.toolchain },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "version",
+            |m: &Module| { &m.version },
+            |m: &mut Module| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Provenance>(
+            "provenance",
+            |m: &Module| { &m.provenance },
+            |m: &mut Module| { &mut m.provenance },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Quarantine>(
+            "quarantine",
+            |m: &Module| { &m.quarantine },
+            |m: &mut Module| { &mut m.quarantine },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "integrity",
+            |m: &Module| { &m.integrity },
+            |m: &mut Module| { &mut m.integrity },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Module>(
             "Module",
             fields,
@@ -671,6 +759,51 @@ impl ::protobuf::Message for Module {
                     is.pop_limit(old_limit);
                     self.function_hashes.insert(key, value);
                 },
+                130 => {
+                    self.tags.push(is.read_string()?);
+                },
+                138 => {
+                    self.memories.push(is.read_message()?);
+                },
+                146 => {
+                    self.tables.push(is.read_message()?);
+                },
+                154 => {
+                    self.globals.push(is.read_message()?);
+                },
+                162 => {
+                    self.custom_sections.push(is.read_message()?);
+                },
+                170 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_message()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.function_metrics.insert(key, value);
+                },
+                178 => {
+                    self.toolchain.push(is.read_message()?);
+                },
+                186 => {
+                    self.version = ::std::option::Option::Some(is.read_string()?);
+                },
+                194 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.provenance)?;
+                },
+                202 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.quarantine)?;
+                },
+                210 => {
+                    self.integrity.push(is.read_message()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -731,6 +864,51 @@ impl ::protobuf::Message for Module {
             entry_size += ::protobuf::rt::string_size(2, &v);
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
         };
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(16, &value);
+        };
+        for value in &self.memories {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.tables {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.globals {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.custom_sections {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for (k, v) in &self.function_metrics {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.compute_size();
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        for value in &self.toolchain {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.version.as_ref() {
+            my_size += ::protobuf::rt::string_size(23, &v);
+        }
+        if let Some(v) = self.provenance.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.quarantine.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.integrity {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -788,6 +966,46 @@ impl ::protobuf::Message for Module {
             os.write_string(1, &k)?;
             os.write_string(2, &v)?;
         };
+        for v in &self.tags {
+            os.write_string(16, &v)?;
+        };
+        for v in &self.memories {
+            ::protobuf::rt::write_message_field_with_cached_size(17, v, os)?;
+        };
+        for v in &self.tables {
+            ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
+        };
+        for v in &self.globals {
+            ::protobuf::rt::write_message_field_with_cached_size(19, v, os)?;
+        };
+        for v in &self.custom_sections {
+            ::protobuf::rt::write_message_field_with_cached_size(20, v, os)?;
+        };
+        for (k, v) in &self.function_metrics {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.cached_size() as u64;
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            os.write_raw_varint32(170)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for v in &self.toolchain {
+            ::protobuf::rt::write_message_field_with_cached_size(22, v, os)?;
+        };
+        if let Some(v) = self.version.as_ref() {
+            os.write_string(23, v)?;
+        }
+        if let Some(v) = self.provenance.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(24, v, os)?;
+        }
+        if let Some(v) = self.quarantine.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(25, v, os)?;
+        }
+        for v in &self.integrity {
+            ::protobuf::rt::write_message_field_with_cached_size(26, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -818,6 +1036,17 @@ impl ::protobuf::Message for Module {
         self.complexity = ::std::option::Option::None;
         self.graph = ::std::option::Option::None;
         self.function_hashes.clear();
+        self.tags.clear();
+        self.memories.clear();
+        self.tables.clear();
+        self.globals.clear();
+        self.custom_sections.clear();
+        self.function_metrics.clear();
+        self.toolchain.clear();
+        self.version = ::std::option::Option::None;
+        self.provenance.clear();
+        self.quarantine.clear();
+        self.integrity.clear();
         self.special_fields.clear();
     }
 
@@ -844,56 +1073,63 @@ impl ::protobuf::reflect::ProtobufValue for Module {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  Details about a wasm module graph
-// @@protoc_insertion_point(message:ModuleGraph)
+///  A structural problem found in a module's binary, e.g. an out of bounds function index
+// @@protoc_insertion_point(message:IntegrityIssue)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ModuleGraph {
+pub struct IntegrityIssue {
     // message fields
-    ///  ID for this module, generated by the database.
-    // @@protoc_insertion_point(field:ModuleGraph.id)
-    pub id: i64,
-    ///  the serialized graph in json format
-    // @@protoc_insertion_point(field:ModuleGraph.json_bytes)
-    pub json_bytes: ::std::vec::Vec<u8>,
+    ///  e.g. "index_out_of_bounds" or "section_order"
+    // @@protoc_insertion_point(field:IntegrityIssue.kind)
+    pub kind: ::std::string::String,
+    ///  the offset of the problem in the binary, in bytes
+    // @@protoc_insertion_point(field:IntegrityIssue.offset)
+    pub offset: u64,
+    // @@protoc_insertion_point(field:IntegrityIssue.message)
+    pub message: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:ModuleGraph.special_fields)
+    // @@protoc_insertion_point(special_field:IntegrityIssue.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ModuleGraph {
-    fn default() -> &'a ModuleGraph {
-        <ModuleGraph as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a IntegrityIssue {
+    fn default() -> &'a IntegrityIssue {
+        <IntegrityIssue as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ModuleGraph {
-    pub fn new() -> ModuleGraph {
+impl IntegrityIssue {
+    pub fn new() -> IntegrityIssue {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "id",
-            |m: &ModuleGraph| { &m.id },
-            |m: &mut ModuleGraph| { &mut m.id },
+            "kind",
+            |m: &IntegrityIssue| { &m.kind },
+            |m: &mut IntegrityIssue| { &mut m.kind },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "json_bytes",
-            |m: &ModuleGraph| { &m.json_bytes },
-            |m: &mut ModuleGraph| { &mut m.json_bytes },
+            "offset",
+            |m: &IntegrityIssue| { &m.offset },
+            |m: &mut IntegrityIssue| { &mut m.offset },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModuleGraph>(
-            "ModuleGraph",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &IntegrityIssue| { &m.message },
+            |m: &mut IntegrityIssue| { &mut m.message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<IntegrityIssue>(
+            "IntegrityIssue",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ModuleGraph {
-    const NAME: &'static str = "ModuleGraph";
+impl ::protobuf::Message for IntegrityIssue {
+    const NAME: &'static str = "IntegrityIssue";
 
     fn is_initialized(&self) -> bool {
         true
@@ -902,11 +1138,14 @@ impl ::protobuf::Message for ModuleGraph {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.id = is.read_int64()?;
+                10 => {
+                    self.kind = is.read_string()?;
                 },
-                18 => {
-                    self.json_bytes = is.read_bytes()?;
+                16 => {
+                    self.offset = is.read_uint64()?;
+                },
+                26 => {
+                    self.message = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -920,11 +1159,14 @@ impl ::protobuf::Message for ModuleGraph {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.id != 0 {
-            my_size += ::protobuf::rt::int64_size(1, self.id);
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
         }
-        if !self.json_bytes.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.json_bytes);
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.offset);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -932,11 +1174,14 @@ impl ::protobuf::Message for ModuleGraph {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.id != 0 {
-            os.write_int64(1, self.id)?;
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
         }
-        if !self.json_bytes.is_empty() {
-            os.write_bytes(2, &self.json_bytes)?;
+        if self.offset != 0 {
+            os.write_uint64(2, self.offset)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -950,65 +1195,69 @@ impl ::protobuf::Message for ModuleGraph {
         &mut self.special_fields
     }
 
-    fn new() -> ModuleGraph {
-        ModuleGraph::new()
+    fn new() -> IntegrityIssue {
+        IntegrityIssue::new()
     }
 
     fn clear(&mut self) {
-        self.id = 0;
-        self.json_bytes.clear();
+        self.kind.clear();
+        self.offset = 0;
+        self.message.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ModuleGraph {
-        static instance: ModuleGraph = ModuleGraph {
-            id: 0,
-            json_bytes: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static IntegrityIssue {
+        static instance: IntegrityIssue = IntegrityIssue {
+            kind: ::std::string::String::new(),
+            offset: 0,
+            message: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ModuleGraph {
+impl ::protobuf::MessageFull for IntegrityIssue {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ModuleGraph").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("IntegrityIssue").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ModuleGraph {
+impl ::std::fmt::Display for IntegrityIssue {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ModuleGraph {
+impl ::protobuf::reflect::ProtobufValue for IntegrityIssue {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  An error message indicating a problem in the API.
-// @@protoc_insertion_point(message:Error)
+///  A module flagged as blocked, e.g. during incident response, without deleting
+///  or yanking it
+// @@protoc_insertion_point(message:Quarantine)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct Error {
+pub struct Quarantine {
     // message fields
-    // @@protoc_insertion_point(field:Error.code)
-    pub code: i32,
-    // @@protoc_insertion_point(field:Error.message)
-    pub message: ::std::string::String,
+    ///  why the module was quarantined, e.g. `CVE-2024-1234`
+    // @@protoc_insertion_point(field:Quarantine.reason)
+    pub reason: ::std::string::String,
+    // @@protoc_insertion_point(field:Quarantine.quarantined_at)
+    pub quarantined_at: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
     // special fields
-    // @@protoc_insertion_point(special_field:Error.special_fields)
+    // @@protoc_insertion_point(special_field:Quarantine.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Error {
-    fn default() -> &'a Error {
-        <Error as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Quarantine {
+    fn default() -> &'a Quarantine {
+        <Quarantine as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Error {
-    pub fn new() -> Error {
+impl Quarantine {
+    pub fn new() -> Quarantine {
         ::std::default::Default::default()
     }
 
@@ -1016,25 +1265,25 @@ impl Error {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "code",
-            |m: &Error| { &m.code },
-            |m: &mut Error| { &mut m.code },
+            "reason",
+            |m: &Quarantine| { &m.reason },
+            |m: &mut Quarantine| { &mut m.reason },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message",
-            |m: &Error| { &m.message },
-            |m: &mut Error| { &mut m.message },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "quarantined_at",
+            |m: &Quarantine| { &m.quarantined_at },
+            |m: &mut Quarantine| { &mut m.quarantined_at },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Error>(
-            "Error",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Quarantine>(
+            "Quarantine",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Error {
-    const NAME: &'static str = "Error";
+impl ::protobuf::Message for Quarantine {
+    const NAME: &'static str = "Quarantine";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1043,11 +1292,11 @@ impl ::protobuf::Message for Error {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.code = is.read_int32()?;
+                10 => {
+                    self.reason = is.read_string()?;
                 },
                 18 => {
-                    self.message = is.read_string()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.quarantined_at)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1061,11 +1310,12 @@ impl ::protobuf::Message for Error {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.code != 0 {
-            my_size += ::protobuf::rt::int32_size(1, self.code);
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reason);
         }
-        if !self.message.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.message);
+        if let Some(v) = self.quarantined_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1073,11 +1323,11 @@ impl ::protobuf::Message for Error {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.code != 0 {
-            os.write_int32(1, self.code)?;
+        if !self.reason.is_empty() {
+            os.write_string(1, &self.reason)?;
         }
-        if !self.message.is_empty() {
-            os.write_string(2, &self.message)?;
+        if let Some(v) = self.quarantined_at.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1091,92 +1341,92 @@ impl ::protobuf::Message for Error {
         &mut self.special_fields
     }
 
-    fn new() -> Error {
-        Error::new()
+    fn new() -> Quarantine {
+        Quarantine::new()
     }
 
     fn clear(&mut self) {
-        self.code = 0;
-        self.message.clear();
+        self.reason.clear();
+        self.quarantined_at.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Error {
-        static instance: Error = Error {
-            code: 0,
-            message: ::std::string::String::new(),
+    fn default_instance() -> &'static Quarantine {
+        static instance: Quarantine = Quarantine {
+            reason: ::std::string::String::new(),
+            quarantined_at: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Error {
+impl ::protobuf::MessageFull for Quarantine {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Error").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Quarantine").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Error {
+impl ::std::fmt::Display for Quarantine {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Error {
+impl ::protobuf::reflect::ProtobufValue for Quarantine {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  Control/limit the way results are paginated when working with large
-///  responses.
-// @@protoc_insertion_point(message:Pagination)
+///  Licenses and third-party libraries found embedded in a module
+// @@protoc_insertion_point(message:Provenance)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct Pagination {
+pub struct Provenance {
     // message fields
-    // @@protoc_insertion_point(field:Pagination.limit)
-    pub limit: u32,
-    // @@protoc_insertion_point(field:Pagination.offset)
-    pub offset: u32,
+    ///  SPDX identifiers, e.g. `GPL-3.0-only`
+    // @@protoc_insertion_point(field:Provenance.licenses)
+    pub licenses: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:Provenance.libraries)
+    pub libraries: ::std::vec::Vec<Library>,
     // special fields
-    // @@protoc_insertion_point(special_field:Pagination.special_fields)
+    // @@protoc_insertion_point(special_field:Provenance.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Pagination {
-    fn default() -> &'a Pagination {
-        <Pagination as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Provenance {
+    fn default() -> &'a Provenance {
+        <Provenance as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Pagination {
-    pub fn new() -> Pagination {
+impl Provenance {
+    pub fn new() -> Provenance {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "limit",
-            |m: &Pagination| { &m.limit },
-            |m: &mut Pagination| { &mut m.limit },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "licenses",
+            |m: &Provenance| { &m.licenses },
+            |m: &mut Provenance| { &mut m.licenses },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "offset",
-            |m: &Pagination| { &m.offset },
-            |m: &mut Pagination| { &mut m.offset },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "libraries",
+            |m: &Provenance| { &m.libraries },
+            |m: &mut Provenance| { &mut m.libraries },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Pagination>(
-            "Pagination",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Provenance>(
+            "Provenance",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Pagination {
-    const NAME: &'static str = "Pagination";
+impl ::protobuf::Message for Provenance {
+    const NAME: &'static str = "Provenance";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1185,11 +1435,11 @@ impl ::protobuf::Message for Pagination {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.limit = is.read_uint32()?;
+                10 => {
+                    self.licenses.push(is.read_string()?);
                 },
-                16 => {
-                    self.offset = is.read_uint32()?;
+                18 => {
+                    self.libraries.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1203,24 +1453,25 @@ impl ::protobuf::Message for Pagination {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.limit != 0 {
-            my_size += ::protobuf::rt::uint32_size(1, self.limit);
-        }
-        if self.offset != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.offset);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-        self.special_fields.cached_size().set(my_size as u32);
+        for value in &self.licenses {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.libraries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.limit != 0 {
-            os.write_uint32(1, self.limit)?;
-        }
-        if self.offset != 0 {
-            os.write_uint32(2, self.offset)?;
-        }
+        for v in &self.licenses {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.libraries {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1233,65 +1484,65 @@ impl ::protobuf::Message for Pagination {
         &mut self.special_fields
     }
 
-    fn new() -> Pagination {
-        Pagination::new()
+    fn new() -> Provenance {
+        Provenance::new()
     }
 
     fn clear(&mut self) {
-        self.limit = 0;
-        self.offset = 0;
+        self.licenses.clear();
+        self.libraries.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Pagination {
-        static instance: Pagination = Pagination {
-            limit: 0,
-            offset: 0,
+    fn default_instance() -> &'static Provenance {
+        static instance: Provenance = Provenance {
+            licenses: ::std::vec::Vec::new(),
+            libraries: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Pagination {
+impl ::protobuf::MessageFull for Provenance {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Pagination").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Provenance").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Pagination {
+impl ::std::fmt::Display for Provenance {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Pagination {
+impl ::protobuf::reflect::ProtobufValue for Provenance {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  Determine how to sort results from the API
-// @@protoc_insertion_point(message:Sort)
+///  A library identified by a version string found in a module, e.g. `zlib 1.2.11`
+// @@protoc_insertion_point(message:Library)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct Sort {
+pub struct Library {
     // message fields
-    // @@protoc_insertion_point(field:Sort.direction)
-    pub direction: ::protobuf::EnumOrUnknown<Direction>,
-    // @@protoc_insertion_point(field:Sort.field)
-    pub field: ::protobuf::EnumOrUnknown<Field>,
+    // @@protoc_insertion_point(field:Library.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:Library.version)
+    pub version: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:Sort.special_fields)
+    // @@protoc_insertion_point(special_field:Library.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Sort {
-    fn default() -> &'a Sort {
-        <Sort as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Library {
+    fn default() -> &'a Library {
+        <Library as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Sort {
-    pub fn new() -> Sort {
+impl Library {
+    pub fn new() -> Library {
         ::std::default::Default::default()
     }
 
@@ -1299,25 +1550,25 @@ impl Sort {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "direction",
-            |m: &Sort| { &m.direction },
-            |m: &mut Sort| { &mut m.direction },
+            "name",
+            |m: &Library| { &m.name },
+            |m: &mut Library| { &mut m.name },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "field",
-            |m: &Sort| { &m.field },
-            |m: &mut Sort| { &mut m.field },
+            "version",
+            |m: &Library| { &m.version },
+            |m: &mut Library| { &mut m.version },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Sort>(
-            "Sort",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Library>(
+            "Library",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Sort {
-    const NAME: &'static str = "Sort";
+impl ::protobuf::Message for Library {
+    const NAME: &'static str = "Library";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1326,11 +1577,11 @@ impl ::protobuf::Message for Sort {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.direction = is.read_enum_or_unknown()?;
+                10 => {
+                    self.name = is.read_string()?;
                 },
-                16 => {
-                    self.field = is.read_enum_or_unknown()?;
+                18 => {
+                    self.version = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1344,11 +1595,11 @@ impl ::protobuf::Message for Sort {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.direction != ::protobuf::EnumOrUnknown::new(Direction::Desc) {
-            my_size += ::protobuf::rt::int32_size(1, self.direction.value());
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
         }
-        if self.field != ::protobuf::EnumOrUnknown::new(Field::CreatedAt) {
-            my_size += ::protobuf::rt::int32_size(2, self.field.value());
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1356,11 +1607,11 @@ impl ::protobuf::Message for Sort {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.direction != ::protobuf::EnumOrUnknown::new(Direction::Desc) {
-            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.direction))?;
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
         }
-        if self.field != ::protobuf::EnumOrUnknown::new(Field::CreatedAt) {
-            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.field))?;
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1374,100 +1625,114 @@ impl ::protobuf::Message for Sort {
         &mut self.special_fields
     }
 
-    fn new() -> Sort {
-        Sort::new()
+    fn new() -> Library {
+        Library::new()
     }
 
     fn clear(&mut self) {
-        self.direction = ::protobuf::EnumOrUnknown::new(Direction::Desc);
-        self.field = ::protobuf::EnumOrUnknown::new(Field::CreatedAt);
+        self.name.clear();
+        self.version.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Sort {
-        static instance: Sort = Sort {
-            direction: ::protobuf::EnumOrUnknown::from_i32(0),
-            field: ::protobuf::EnumOrUnknown::from_i32(0),
+    fn default_instance() -> &'static Library {
+        static instance: Library = Library {
+            name: ::std::string::String::new(),
+            version: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Sort {
+impl ::protobuf::MessageFull for Library {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Sort").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Library").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Sort {
+impl ::std::fmt::Display for Library {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Sort {
+impl ::protobuf::reflect::ProtobufValue for Library {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `PUT /api/v1/module:`
-///  Insert a module, extract data from binary. Return the module ID & hash.
-// @@protoc_insertion_point(message:CreateModuleRequest)
+///  A linear memory defined or imported by a module
+// @@protoc_insertion_point(message:Memory)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CreateModuleRequest {
+pub struct Memory {
     // message fields
-    // @@protoc_insertion_point(field:CreateModuleRequest.wasm)
-    pub wasm: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:CreateModuleRequest.metadata)
-    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-    ///  a valid URL with a scheme prefix e.g. `s3://`, `file://`, `https://`
-    // @@protoc_insertion_point(field:CreateModuleRequest.location)
-    pub location: ::std::option::Option<::std::string::String>,
+    ///  initial size of the memory, in 64KiB pages
+    // @@protoc_insertion_point(field:Memory.initial)
+    pub initial: u64,
+    ///  maximum size of the memory, in 64KiB pages, if limited
+    // @@protoc_insertion_point(field:Memory.maximum)
+    pub maximum: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:Memory.shared)
+    pub shared: bool,
+    // @@protoc_insertion_point(field:Memory.memory64)
+    pub memory64: bool,
+    // @@protoc_insertion_point(field:Memory.imported)
+    pub imported: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:CreateModuleRequest.special_fields)
+    // @@protoc_insertion_point(special_field:Memory.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CreateModuleRequest {
-    fn default() -> &'a CreateModuleRequest {
-        <CreateModuleRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Memory {
+    fn default() -> &'a Memory {
+        <Memory as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CreateModuleRequest {
-    pub fn new() -> CreateModuleRequest {
+impl Memory {
+    pub fn new() -> Memory {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "wasm",
-            |m: &CreateModuleRequest| { &m.wasm },
-            |m: &mut CreateModuleRequest| { &mut m.wasm },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
-            "metadata",
-            |m: &CreateModuleRequest| { &m.metadata },
-            |m: &mut CreateModuleRequest| { &mut m.metadata },
+            "initial",
+            |m: &Memory| { &m.initial },
+            |m: &mut Memory| { &mut m.initial },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "location",
-            |m: &CreateModuleRequest| { &m.location },
-            |m: &mut CreateModuleRequest| { &mut m.location },
+            "maximum",
+            |m: &Memory| { &m.maximum },
+            |m: &mut Memory| { &mut m.maximum },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateModuleRequest>(
-            "CreateModuleRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "shared",
+            |m: &Memory| { &m.shared },
+            |m: &mut Memory| { &mut m.shared },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "memory64",
+            |m: &Memory| { &m.memory64 },
+            |m: &mut Memory| { &mut m.memory64 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "imported",
+            |m: &Memory| { &m.imported },
+            |m: &mut Memory| { &mut m.imported },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Memory>(
+            "Memory",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CreateModuleRequest {
-    const NAME: &'static str = "CreateModuleRequest";
+impl ::protobuf::Message for Memory {
+    const NAME: &'static str = "Memory";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1476,26 +1741,20 @@ impl ::protobuf::Message for CreateModuleRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.wasm = is.read_bytes()?;
+                8 => {
+                    self.initial = is.read_uint64()?;
                 },
-                18 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
-                        match tag {
-                            10 => key = is.read_string()?,
-                            18 => value = is.read_string()?,
-                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
-                        };
-                    }
-                    is.pop_limit(old_limit);
-                    self.metadata.insert(key, value);
+                16 => {
+                    self.maximum = ::std::option::Option::Some(is.read_uint64()?);
                 },
-                26 => {
-                    self.location = ::std::option::Option::Some(is.read_string()?);
+                24 => {
+                    self.shared = is.read_bool()?;
+                },
+                32 => {
+                    self.memory64 = is.read_bool()?;
+                },
+                40 => {
+                    self.imported = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1509,17 +1768,20 @@ impl ::protobuf::Message for CreateModuleRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.wasm.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.wasm);
+        if self.initial != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.initial);
         }
-        for (k, v) in &self.metadata {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
-        };
-        if let Some(v) = self.location.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
+        if let Some(v) = self.maximum {
+            my_size += ::protobuf::rt::uint64_size(2, v);
+        }
+        if self.shared != false {
+            my_size += 1 + 1;
+        }
+        if self.memory64 != false {
+            my_size += 1 + 1;
+        }
+        if self.imported != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1527,20 +1789,20 @@ impl ::protobuf::Message for CreateModuleRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.wasm.is_empty() {
-            os.write_bytes(1, &self.wasm)?;
+        if self.initial != 0 {
+            os.write_uint64(1, self.initial)?;
         }
-        for (k, v) in &self.metadata {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            os.write_raw_varint32(18)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_string(1, &k)?;
-            os.write_string(2, &v)?;
-        };
-        if let Some(v) = self.location.as_ref() {
-            os.write_string(3, v)?;
+        if let Some(v) = self.maximum {
+            os.write_uint64(2, v)?;
+        }
+        if self.shared != false {
+            os.write_bool(3, self.shared)?;
+        }
+        if self.memory64 != false {
+            os.write_bool(4, self.memory64)?;
+        }
+        if self.imported != false {
+            os.write_bool(5, self.imported)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1554,95 +1816,111 @@ impl ::protobuf::Message for CreateModuleRequest {
         &mut self.special_fields
     }
 
-    fn new() -> CreateModuleRequest {
-        CreateModuleRequest::new()
+    fn new() -> Memory {
+        Memory::new()
     }
 
     fn clear(&mut self) {
-        self.wasm.clear();
-        self.metadata.clear();
-        self.location = ::std::option::Option::None;
+        self.initial = 0;
+        self.maximum = ::std::option::Option::None;
+        self.shared = false;
+        self.memory64 = false;
+        self.imported = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CreateModuleRequest {
-        static instance: ::protobuf::rt::Lazy<CreateModuleRequest> = ::protobuf::rt::Lazy::new();
-        instance.get(CreateModuleRequest::new)
+    fn default_instance() -> &'static Memory {
+        static instance: Memory = Memory {
+            initial: 0,
+            maximum: ::std::option::Option::None,
+            shared: false,
+            memory64: false,
+            imported: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for CreateModuleRequest {
+impl ::protobuf::MessageFull for Memory {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateModuleRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Memory").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CreateModuleRequest {
+impl ::std::fmt::Display for Memory {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CreateModuleRequest {
+impl ::protobuf::reflect::ProtobufValue for Memory {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to a `CreateModuleRequest`.
-// @@protoc_insertion_point(message:CreateModuleResponse)
+///  A table of references defined or imported by a module
+// @@protoc_insertion_point(message:Table)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CreateModuleResponse {
+pub struct Table {
     // message fields
-    // @@protoc_insertion_point(field:CreateModuleResponse.module_id)
-    pub module_id: i64,
-    // @@protoc_insertion_point(field:CreateModuleResponse.hash)
-    pub hash: ::std::string::String,
-    // @@protoc_insertion_point(field:CreateModuleResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+    // @@protoc_insertion_point(field:Table.element_type)
+    pub element_type: ::protobuf::EnumOrUnknown<ValType>,
+    // @@protoc_insertion_point(field:Table.initial)
+    pub initial: u64,
+    // @@protoc_insertion_point(field:Table.maximum)
+    pub maximum: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:Table.imported)
+    pub imported: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:CreateModuleResponse.special_fields)
+    // @@protoc_insertion_point(special_field:Table.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CreateModuleResponse {
-    fn default() -> &'a CreateModuleResponse {
-        <CreateModuleResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Table {
+    fn default() -> &'a Table {
+        <Table as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CreateModuleResponse {
-    pub fn new() -> CreateModuleResponse {
+impl Table {
+    pub fn new() -> Table {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "module_id",
-            |m: &CreateModuleResponse| { &m.module_id },
-            |m: &mut CreateModuleResponse| { &mut m.module_id },
+            "element_type",
+            |m: &Table| { &m.element_type },
+            |m: &mut Table| { &mut m.element_type },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "hash",
-            |m: &CreateModuleResponse| { &m.hash },
-            |m: &mut CreateModuleResponse| { &mut m.hash },
+            "initial",
+            |m: &Table| { &m.initial },
+            |m: &mut Table| { &mut m.initial },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &CreateModuleResponse| { &m.error },
-            |m: &mut CreateModuleResponse| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "maximum",
+            |m: &Table| { &m.maximum },
+            |m: &mut Table| { &mut m.maximum },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateModuleResponse>(
-            "CreateModuleResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "imported",
+            |m: &Table| { &m.imported },
+            |m: &mut Table| { &mut m.imported },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Table>(
+            "Table",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CreateModuleResponse {
-    const NAME: &'static str = "CreateModuleResponse";
+impl ::protobuf::Message for Table {
+    const NAME: &'static str = "Table";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1652,13 +1930,16 @@ impl ::protobuf::Message for CreateModuleResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.module_id = is.read_int64()?;
+                    self.element_type = is.read_enum_or_unknown()?;
                 },
-                18 => {
-                    self.hash = is.read_string()?;
+                16 => {
+                    self.initial = is.read_uint64()?;
                 },
-                26 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                24 => {
+                    self.maximum = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                32 => {
+                    self.imported = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1672,15 +1953,17 @@ impl ::protobuf::Message for CreateModuleResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.module_id != 0 {
-            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        if self.element_type != ::protobuf::EnumOrUnknown::new(ValType::I32) {
+            my_size += ::protobuf::rt::int32_size(1, self.element_type.value());
         }
-        if !self.hash.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.hash);
+        if self.initial != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.initial);
         }
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if let Some(v) = self.maximum {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        if self.imported != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1688,14 +1971,17 @@ impl ::protobuf::Message for CreateModuleResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.module_id != 0 {
-            os.write_int64(1, self.module_id)?;
+        if self.element_type != ::protobuf::EnumOrUnknown::new(ValType::I32) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.element_type))?;
         }
-        if !self.hash.is_empty() {
-            os.write_string(2, &self.hash)?;
+        if self.initial != 0 {
+            os.write_uint64(2, self.initial)?;
         }
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        if let Some(v) = self.maximum {
+            os.write_uint64(3, v)?;
+        }
+        if self.imported != false {
+            os.write_bool(4, self.imported)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1709,87 +1995,102 @@ impl ::protobuf::Message for CreateModuleResponse {
         &mut self.special_fields
     }
 
-    fn new() -> CreateModuleResponse {
-        CreateModuleResponse::new()
+    fn new() -> Table {
+        Table::new()
     }
 
     fn clear(&mut self) {
-        self.module_id = 0;
-        self.hash.clear();
-        self.error.clear();
+        self.element_type = ::protobuf::EnumOrUnknown::new(ValType::I32);
+        self.initial = 0;
+        self.maximum = ::std::option::Option::None;
+        self.imported = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CreateModuleResponse {
-        static instance: CreateModuleResponse = CreateModuleResponse {
-            module_id: 0,
-            hash: ::std::string::String::new(),
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static Table {
+        static instance: Table = Table {
+            element_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            initial: 0,
+            maximum: ::std::option::Option::None,
+            imported: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for CreateModuleResponse {
+impl ::protobuf::MessageFull for Table {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateModuleResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Table").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CreateModuleResponse {
+impl ::std::fmt::Display for Table {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CreateModuleResponse {
+impl ::protobuf::reflect::ProtobufValue for Table {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/module:`
-///  Return a single module.
-// @@protoc_insertion_point(message:GetModuleRequest)
+///  A global variable defined or imported by a module
+// @@protoc_insertion_point(message:Global)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetModuleRequest {
+pub struct Global {
     // message fields
-    // @@protoc_insertion_point(field:GetModuleRequest.module_id)
-    pub module_id: i64,
+    // @@protoc_insertion_point(field:Global.ty)
+    pub ty: ::protobuf::EnumOrUnknown<ValType>,
+    // @@protoc_insertion_point(field:Global.mutable)
+    pub mutable: bool,
+    // @@protoc_insertion_point(field:Global.imported)
+    pub imported: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:GetModuleRequest.special_fields)
+    // @@protoc_insertion_point(special_field:Global.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetModuleRequest {
-    fn default() -> &'a GetModuleRequest {
-        <GetModuleRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Global {
+    fn default() -> &'a Global {
+        <Global as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetModuleRequest {
-    pub fn new() -> GetModuleRequest {
+impl Global {
+    pub fn new() -> Global {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "module_id",
-            |m: &GetModuleRequest| { &m.module_id },
-            |m: &mut GetModuleRequest| { &mut m.module_id },
+            "ty",
+            |m: &Global| { &m.ty },
+            |m: &mut Global| { &mut m.ty },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleRequest>(
-            "GetModuleRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mutable",
+            |m: &Global| { &m.mutable },
+            |m: &mut Global| { &mut m.mutable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "imported",
+            |m: &Global| { &m.imported },
+            |m: &mut Global| { &mut m.imported },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Global>(
+            "Global",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetModuleRequest {
-    const NAME: &'static str = "GetModuleRequest";
+impl ::protobuf::Message for Global {
+    const NAME: &'static str = "Global";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1799,7 +2100,13 @@ impl ::protobuf::Message for GetModuleRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.module_id = is.read_int64()?;
+                    self.ty = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.mutable = is.read_bool()?;
+                },
+                24 => {
+                    self.imported = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1813,8 +2120,14 @@ impl ::protobuf::Message for GetModuleRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.module_id != 0 {
-            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        if self.ty != ::protobuf::EnumOrUnknown::new(ValType::I32) {
+            my_size += ::protobuf::rt::int32_size(1, self.ty.value());
+        }
+        if self.mutable != false {
+            my_size += 1 + 1;
+        }
+        if self.imported != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1822,8 +2135,14 @@ impl ::protobuf::Message for GetModuleRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.module_id != 0 {
-            os.write_int64(1, self.module_id)?;
+        if self.ty != ::protobuf::EnumOrUnknown::new(ValType::I32) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.ty))?;
+        }
+        if self.mutable != false {
+            os.write_bool(2, self.mutable)?;
+        }
+        if self.imported != false {
+            os.write_bool(3, self.imported)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1837,89 +2156,94 @@ impl ::protobuf::Message for GetModuleRequest {
         &mut self.special_fields
     }
 
-    fn new() -> GetModuleRequest {
-        GetModuleRequest::new()
+    fn new() -> Global {
+        Global::new()
     }
 
     fn clear(&mut self) {
-        self.module_id = 0;
+        self.ty = ::protobuf::EnumOrUnknown::new(ValType::I32);
+        self.mutable = false;
+        self.imported = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetModuleRequest {
-        static instance: GetModuleRequest = GetModuleRequest {
-            module_id: 0,
+    fn default_instance() -> &'static Global {
+        static instance: Global = Global {
+            ty: ::protobuf::EnumOrUnknown::from_i32(0),
+            mutable: false,
+            imported: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetModuleRequest {
+impl ::protobuf::MessageFull for Global {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Global").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetModuleRequest {
+impl ::std::fmt::Display for Global {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetModuleRequest {
+impl ::protobuf::reflect::ProtobufValue for Global {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to a `GetModuleRequest`.
-// @@protoc_insertion_point(message:GetModuleResponse)
+///  A custom section contained in a module
+// @@protoc_insertion_point(message:CustomSection)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetModuleResponse {
+pub struct CustomSection {
     // message fields
-    // @@protoc_insertion_point(field:GetModuleResponse.module)
-    pub module: ::protobuf::MessageField<Module>,
-    // @@protoc_insertion_point(field:GetModuleResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+    // @@protoc_insertion_point(field:CustomSection.name)
+    pub name: ::std::string::String,
+    ///  size in bytes of the section's data
+    // @@protoc_insertion_point(field:CustomSection.size)
+    pub size: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:GetModuleResponse.special_fields)
+    // @@protoc_insertion_point(special_field:CustomSection.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetModuleResponse {
-    fn default() -> &'a GetModuleResponse {
-        <GetModuleResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CustomSection {
+    fn default() -> &'a CustomSection {
+        <CustomSection as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetModuleResponse {
-    pub fn new() -> GetModuleResponse {
+impl CustomSection {
+    pub fn new() -> CustomSection {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Module>(
-            "module",
-            |m: &GetModuleResponse| { &m.module },
-            |m: &mut GetModuleResponse| { &mut m.module },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &CustomSection| { &m.name },
+            |m: &mut CustomSection| { &mut m.name },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &GetModuleResponse| { &m.error },
-            |m: &mut GetModuleResponse| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &CustomSection| { &m.size },
+            |m: &mut CustomSection| { &mut m.size },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleResponse>(
-            "GetModuleResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CustomSection>(
+            "CustomSection",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetModuleResponse {
-    const NAME: &'static str = "GetModuleResponse";
+impl ::protobuf::Message for CustomSection {
+    const NAME: &'static str = "CustomSection";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1929,10 +2253,10 @@ impl ::protobuf::Message for GetModuleResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.module)?;
+                    self.name = is.read_string()?;
                 },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                16 => {
+                    self.size = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1946,13 +2270,11 @@ impl ::protobuf::Message for GetModuleResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.module.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
         }
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.size);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1960,11 +2282,11 @@ impl ::protobuf::Message for GetModuleResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.module.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
         }
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if self.size != 0 {
+            os.write_uint64(2, self.size)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1978,92 +2300,99 @@ impl ::protobuf::Message for GetModuleResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetModuleResponse {
-        GetModuleResponse::new()
+    fn new() -> CustomSection {
+        CustomSection::new()
     }
 
     fn clear(&mut self) {
-        self.module.clear();
-        self.error.clear();
+        self.name.clear();
+        self.size = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetModuleResponse {
-        static instance: GetModuleResponse = GetModuleResponse {
-            module: ::protobuf::MessageField::none(),
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static CustomSection {
+        static instance: CustomSection = CustomSection {
+            name: ::std::string::String::new(),
+            size: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetModuleResponse {
+impl ::protobuf::MessageFull for CustomSection {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CustomSection").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetModuleResponse {
+impl ::std::fmt::Display for CustomSection {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetModuleResponse {
+impl ::protobuf::reflect::ProtobufValue for CustomSection {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/modules:`
-///  Return paginated list of all modules.
-// @@protoc_insertion_point(message:ListModulesRequest)
+///  Measurements of a single function defined by a module
+// @@protoc_insertion_point(message:FunctionMetrics)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ListModulesRequest {
+pub struct FunctionMetrics {
     // message fields
-    // @@protoc_insertion_point(field:ListModulesRequest.pagination)
-    pub pagination: ::protobuf::MessageField<Pagination>,
-    // @@protoc_insertion_point(field:ListModulesRequest.sort)
-    pub sort: ::protobuf::MessageField<Sort>,
+    // @@protoc_insertion_point(field:FunctionMetrics.complexity)
+    pub complexity: u32,
+    // @@protoc_insertion_point(field:FunctionMetrics.instructions)
+    pub instructions: u64,
+    ///  size in bytes of the function body
+    // @@protoc_insertion_point(field:FunctionMetrics.size)
+    pub size: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:ListModulesRequest.special_fields)
+    // @@protoc_insertion_point(special_field:FunctionMetrics.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ListModulesRequest {
-    fn default() -> &'a ListModulesRequest {
-        <ListModulesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a FunctionMetrics {
+    fn default() -> &'a FunctionMetrics {
+        <FunctionMetrics as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ListModulesRequest {
-    pub fn new() -> ListModulesRequest {
+impl FunctionMetrics {
+    pub fn new() -> FunctionMetrics {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
-            "pagination",
-            |m: &ListModulesRequest| { &m.pagination },
-            |m: &mut ListModulesRequest| { &mut m.pagination },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "complexity",
+            |m: &FunctionMetrics| { &m.complexity },
+            |m: &mut FunctionMetrics| { &mut m.complexity },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
-            "sort",
-            |m: &ListModulesRequest| { &m.sort },
-            |m: &mut ListModulesRequest| { &mut m.sort },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "instructions",
+            |m: &FunctionMetrics| { &m.instructions },
+            |m: &mut FunctionMetrics| { &mut m.instructions },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListModulesRequest>(
-            "ListModulesRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &FunctionMetrics| { &m.size },
+            |m: &mut FunctionMetrics| { &mut m.size },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FunctionMetrics>(
+            "FunctionMetrics",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ListModulesRequest {
-    const NAME: &'static str = "ListModulesRequest";
+impl ::protobuf::Message for FunctionMetrics {
+    const NAME: &'static str = "FunctionMetrics";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2072,11 +2401,14 @@ impl ::protobuf::Message for ListModulesRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                8 => {
+                    self.complexity = is.read_uint32()?;
                 },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
+                16 => {
+                    self.instructions = is.read_uint64()?;
+                },
+                24 => {
+                    self.size = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2090,13 +2422,14 @@ impl ::protobuf::Message for ListModulesRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.pagination.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.complexity != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.complexity);
         }
-        if let Some(v) = self.sort.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.instructions != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.instructions);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.size);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2104,11 +2437,14 @@ impl ::protobuf::Message for ListModulesRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.pagination.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if self.complexity != 0 {
+            os.write_uint32(1, self.complexity)?;
         }
-        if let Some(v) = self.sort.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if self.instructions != 0 {
+            os.write_uint64(2, self.instructions)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(3, self.size)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2122,114 +2458,93 @@ impl ::protobuf::Message for ListModulesRequest {
         &mut self.special_fields
     }
 
-    fn new() -> ListModulesRequest {
-        ListModulesRequest::new()
+    fn new() -> FunctionMetrics {
+        FunctionMetrics::new()
     }
 
     fn clear(&mut self) {
-        self.pagination.clear();
-        self.sort.clear();
+        self.complexity = 0;
+        self.instructions = 0;
+        self.size = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ListModulesRequest {
-        static instance: ListModulesRequest = ListModulesRequest {
-            pagination: ::protobuf::MessageField::none(),
-            sort: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static FunctionMetrics {
+        static instance: FunctionMetrics = FunctionMetrics {
+            complexity: 0,
+            instructions: 0,
+            size: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ListModulesRequest {
+impl ::protobuf::MessageFull for FunctionMetrics {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListModulesRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FunctionMetrics").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ListModulesRequest {
+impl ::std::fmt::Display for FunctionMetrics {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ListModulesRequest {
+impl ::protobuf::reflect::ProtobufValue for FunctionMetrics {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to a `ListModulesRequest`.
-// @@protoc_insertion_point(message:ListModulesResponse)
+///  A tool which produced or processed a module
+// @@protoc_insertion_point(message:Tool)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ListModulesResponse {
+pub struct Tool {
     // message fields
-    // @@protoc_insertion_point(field:ListModulesResponse.modules)
-    pub modules: ::std::vec::Vec<Module>,
-    // @@protoc_insertion_point(field:ListModulesResponse.pagination)
-    pub pagination: ::protobuf::MessageField<Pagination>,
-    ///  the full count of results in the database (not the count of this message's
-    ///  `modules`).
-    // @@protoc_insertion_point(field:ListModulesResponse.total)
-    pub total: u64,
-    // @@protoc_insertion_point(field:ListModulesResponse.sort)
-    pub sort: ::protobuf::MessageField<Sort>,
-    // @@protoc_insertion_point(field:ListModulesResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+    // @@protoc_insertion_point(field:Tool.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:Tool.version)
+    pub version: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:ListModulesResponse.special_fields)
+    // @@protoc_insertion_point(special_field:Tool.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ListModulesResponse {
-    fn default() -> &'a ListModulesResponse {
-        <ListModulesResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Tool {
+    fn default() -> &'a Tool {
+        <Tool as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ListModulesResponse {
-    pub fn new() -> ListModulesResponse {
+impl Tool {
+    pub fn new() -> Tool {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "modules",
-            |m: &ListModulesResponse| { &m.modules },
-            |m: &mut ListModulesResponse| { &mut m.modules },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
-            "pagination",
-            |m: &ListModulesResponse| { &m.pagination },
-            |m: &mut ListModulesResponse| { &mut m.pagination },
-        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "total",
-            |m: &ListModulesResponse| { &m.total },
-            |m: &mut ListModulesResponse| { &mut m.total },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
-            "sort",
-            |m: &ListModulesResponse| { &m.sort },
-            |m: &mut ListModulesResponse| { &mut m.sort },
+            "name",
+            |m: &Tool| { &m.name },
+            |m: &mut Tool| { &mut m.name },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &ListModulesResponse| { &m.error },
-            |m: &mut ListModulesResponse| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Tool| { &m.version },
+            |m: &mut Tool| { &mut m.version },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListModulesResponse>(
-            "ListModulesResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Tool>(
+            "Tool",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ListModulesResponse {
-    const NAME: &'static str = "ListModulesResponse";
+impl ::protobuf::Message for Tool {
+    const NAME: &'static str = "Tool";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2239,19 +2554,10 @@ impl ::protobuf::Message for ListModulesResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.modules.push(is.read_message()?);
+                    self.name = is.read_string()?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
-                },
-                24 => {
-                    self.total = is.read_uint64()?;
-                },
-                34 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
-                },
-                42 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                    self.version = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2265,24 +2571,11 @@ impl ::protobuf::Message for ListModulesResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.modules {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        if let Some(v) = self.pagination.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if self.total != 0 {
-            my_size += ::protobuf::rt::uint64_size(3, self.total);
-        }
-        if let Some(v) = self.sort.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
         }
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2290,20 +2583,11 @@ impl ::protobuf::Message for ListModulesResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.modules {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
-        if let Some(v) = self.pagination.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
-        if self.total != 0 {
-            os.write_uint64(3, self.total)?;
-        }
-        if let Some(v) = self.sort.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
         }
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2317,215 +2601,93 @@ impl ::protobuf::Message for ListModulesResponse {
         &mut self.special_fields
     }
 
-    fn new() -> ListModulesResponse {
-        ListModulesResponse::new()
+    fn new() -> Tool {
+        Tool::new()
     }
 
     fn clear(&mut self) {
-        self.modules.clear();
-        self.pagination.clear();
-        self.total = 0;
-        self.sort.clear();
-        self.error.clear();
+        self.name.clear();
+        self.version.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ListModulesResponse {
-        static instance: ListModulesResponse = ListModulesResponse {
-            modules: ::std::vec::Vec::new(),
-            pagination: ::protobuf::MessageField::none(),
-            total: 0,
-            sort: ::protobuf::MessageField::none(),
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static Tool {
+        static instance: Tool = Tool {
+            name: ::std::string::String::new(),
+            version: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ListModulesResponse {
+impl ::protobuf::MessageFull for Tool {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListModulesResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Tool").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ListModulesResponse {
+impl ::std::fmt::Display for Tool {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ListModulesResponse {
+impl ::protobuf::reflect::ProtobufValue for Tool {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/search:`
-///  Search for modules based on filter params provided (which should be any
-///  dimension of the module schema, or string search in any metadata value).
-///  Return a paginated list of matching modules.
-// @@protoc_insertion_point(message:SearchModulesRequest)
+///  Details about a wasm module graph
+// @@protoc_insertion_point(message:ModuleGraph)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct SearchModulesRequest {
+pub struct ModuleGraph {
     // message fields
     ///  ID for this module, generated by the database.
-    // @@protoc_insertion_point(field:SearchModulesRequest.id)
-    pub id: ::std::option::Option<i64>,
-    ///  original name of the binary module file
-    // @@protoc_insertion_point(field:SearchModulesRequest.hash)
-    pub hash: ::std::option::Option<::std::string::String>,
-    ///  function imports called by the module (see:
-    ///  <https://github.com/WebAssembly/design/blob/main/Modules.md#imports>)
-    // @@protoc_insertion_point(field:SearchModulesRequest.imports)
-    pub imports: ::std::vec::Vec<Import>,
-    ///  function exports provided by the module (see:
-    ///  <https://github.com/WebAssembly/design/blob/main/Modules.md#exports>)
-    // @@protoc_insertion_point(field:SearchModulesRequest.exports)
-    pub exports: ::std::vec::Vec<Export>,
-    ///  minimum size in bytes of the module
-    // @@protoc_insertion_point(field:SearchModulesRequest.min_size)
-    pub min_size: ::std::option::Option<u64>,
-    ///  maximum size in bytes of the module
-    // @@protoc_insertion_point(field:SearchModulesRequest.max_size)
-    pub max_size: ::std::option::Option<u64>,
-    ///  optional path or locator to the module (TODO: maybe this is better stored
-    ///  as metadata)
-    // @@protoc_insertion_point(field:SearchModulesRequest.location)
-    pub location: ::std::option::Option<::std::string::String>,
-    ///  programming language used to produce this module
-    // @@protoc_insertion_point(field:SearchModulesRequest.source_language)
-    pub source_language: ::std::option::Option<::protobuf::EnumOrUnknown<SourceLanguage>>,
-    ///  arbitrary metadata provided by the operator of this module
-    // @@protoc_insertion_point(field:SearchModulesRequest.metadata)
-    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
-    ///  timestamp when this module was loaded and stored
-    // @@protoc_insertion_point(field:SearchModulesRequest.inserted_before)
-    pub inserted_before: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
-    ///  timestamp when this module was loaded and stored
-    // @@protoc_insertion_point(field:SearchModulesRequest.inserted_after)
-    pub inserted_after: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
-    ///  the interned strings stored in the wasm binary (panic/abort messages, etc.)
-    // @@protoc_insertion_point(field:SearchModulesRequest.strings)
-    pub strings: ::std::vec::Vec<::std::string::String>,
-    ///  match on any function name in an import or export.
-    // @@protoc_insertion_point(field:SearchModulesRequest.function_name)
-    pub function_name: ::std::option::Option<::std::string::String>,
-    ///  match on the module name e.g. `env` or `wasi_snapshot_preview1`
-    // @@protoc_insertion_point(field:SearchModulesRequest.module_name)
-    pub module_name: ::std::option::Option<::std::string::String>,
-    // @@protoc_insertion_point(field:SearchModulesRequest.pagination)
-    pub pagination: ::protobuf::MessageField<Pagination>,
-    // @@protoc_insertion_point(field:SearchModulesRequest.sort)
-    pub sort: ::protobuf::MessageField<Sort>,
+    // @@protoc_insertion_point(field:ModuleGraph.id)
+    pub id: i64,
+    ///  the serialized graph in json format
+    // @@protoc_insertion_point(field:ModuleGraph.json_bytes)
+    pub json_bytes: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:SearchModulesRequest.special_fields)
+    // @@protoc_insertion_point(special_field:ModuleGraph.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SearchModulesRequest {
-    fn default() -> &'a SearchModulesRequest {
-        <SearchModulesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ModuleGraph {
+    fn default() -> &'a ModuleGraph {
+        <ModuleGraph as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SearchModulesRequest {
-    pub fn new() -> SearchModulesRequest {
+impl ModuleGraph {
+    pub fn new() -> ModuleGraph {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(16);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "id",
-            |m: &SearchModulesRequest| { &m.id },
-            |m: &mut SearchModulesRequest| { &mut m.id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "hash",
-            |m: &SearchModulesRequest| { &m.hash },
-            |m: &mut SearchModulesRequest| { &mut m.hash },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "imports",
-            |m: &SearchModulesRequest| { &m.imports },
-            |m: &mut SearchModulesRequest| { &mut m.imports },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "exports",
-            |m: &SearchModulesRequest| { &m.exports },
-            |m: &mut SearchModulesRequest| { &mut m.exports },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "min_size",
-            |m: &SearchModulesRequest| { &m.min_size },
-            |m: &mut SearchModulesRequest| { &mut m.min_size },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "max_size",
-            |m: &SearchModulesRequest| { &m.max_size },
-            |m: &mut SearchModulesRequest| { &mut m.max_size },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "location",
-            |m: &SearchModulesRequest| { &m.location },
-            |m: &mut SearchModulesRequest| { &mut m.location },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "source_language",
-            |m: &SearchModulesRequest| { &m.source_language },
-            |m: &mut SearchModulesRequest| { &mut m.source_language },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
-            "metadata",
-            |m: &SearchModulesRequest| { &m.metadata },
-            |m: &mut SearchModulesRequest| { &mut m.metadata },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
-            "inserted_before",
-            |m: &SearchModulesRequest| { &m.inserted_before },
-            |m: &mut SearchModulesRequest| { &mut m.inserted_before },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
-            "inserted_after",
-            |m: &SearchModulesRequest| { &m.inserted_after },
-            |m: &mut SearchModulesRequest| { &mut m.inserted_after },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "strings",
-            |m: &SearchModulesRequest| { &m.strings },
-            |m: &mut SearchModulesRequest| { &mut m.strings },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "function_name",
-            |m: &SearchModulesRequest| { &m.function_name },
-            |m: &mut SearchModulesRequest| { &mut m.function_name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "module_name",
-            |m: &SearchModulesRequest| { &m.module_name },
-            |m: &mut SearchModulesRequest| { &mut m.module_name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
-            "pagination",
-            |m: &SearchModulesRequest| { &m.pagination },
-            |m: &mut SearchModulesRequest| { &mut m.pagination },
+            |m: &ModuleGraph| { &m.id },
+            |m: &mut ModuleGraph| { &mut m.id },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
-            "sort",
-            |m: &SearchModulesRequest| { &m.sort },
-            |m: &mut SearchModulesRequest| { &mut m.sort },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "json_bytes",
+            |m: &ModuleGraph| { &m.json_bytes },
+            |m: &mut ModuleGraph| { &mut m.json_bytes },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SearchModulesRequest>(
-            "SearchModulesRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModuleGraph>(
+            "ModuleGraph",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SearchModulesRequest {
-    const NAME: &'static str = "SearchModulesRequest";
+impl ::protobuf::Message for ModuleGraph {
+    const NAME: &'static str = "ModuleGraph";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2535,64 +2697,6815 @@ impl ::protobuf::Message for SearchModulesRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.id = ::std::option::Option::Some(is.read_int64()?);
-                },
-                26 => {
-                    self.hash = ::std::option::Option::Some(is.read_string()?);
-                },
-                34 => {
-                    self.imports.push(is.read_message()?);
-                },
-                42 => {
-                    self.exports.push(is.read_message()?);
-                },
-                48 => {
-                    self.min_size = ::std::option::Option::Some(is.read_uint64()?);
-                },
-                56 => {
-                    self.max_size = ::std::option::Option::Some(is.read_uint64()?);
+                    self.id = is.read_int64()?;
                 },
-                66 => {
-                    self.location = ::std::option::Option::Some(is.read_string()?);
+                18 => {
+                    self.json_bytes = is.read_bytes()?;
                 },
-                72 => {
-                    self.source_language = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
-                82 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.id);
+        }
+        if !self.json_bytes.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.json_bytes);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_int64(1, self.id)?;
+        }
+        if !self.json_bytes.is_empty() {
+            os.write_bytes(2, &self.json_bytes)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ModuleGraph {
+        ModuleGraph::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.json_bytes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ModuleGraph {
+        static instance: ModuleGraph = ModuleGraph {
+            id: 0,
+            json_bytes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ModuleGraph {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ModuleGraph").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ModuleGraph {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ModuleGraph {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  An error message indicating a problem in the API.
+// @@protoc_insertion_point(message:Error)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Error {
+    // message fields
+    // @@protoc_insertion_point(field:Error.code)
+    pub code: i32,
+    // @@protoc_insertion_point(field:Error.message)
+    pub message: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:Error.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Error {
+    fn default() -> &'a Error {
+        <Error as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Error {
+    pub fn new() -> Error {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &Error| { &m.code },
+            |m: &mut Error| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &Error| { &m.message },
+            |m: &mut Error| { &mut m.message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Error>(
+            "Error",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Error {
+    const NAME: &'static str = "Error";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_int32()?;
+                },
+                18 => {
+                    self.message = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.code);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != 0 {
+            os.write_int32(1, self.code)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(2, &self.message)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Error {
+        Error::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = 0;
+        self.message.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Error {
+        static instance: Error = Error {
+            code: 0,
+            message: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Error {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Error").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Error {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  Control/limit the way results are paginated when working with large
+///  responses.
+// @@protoc_insertion_point(message:Pagination)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Pagination {
+    // message fields
+    // @@protoc_insertion_point(field:Pagination.limit)
+    pub limit: u32,
+    // @@protoc_insertion_point(field:Pagination.offset)
+    pub offset: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:Pagination.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Pagination {
+    fn default() -> &'a Pagination {
+        <Pagination as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Pagination {
+    pub fn new() -> Pagination {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &Pagination| { &m.limit },
+            |m: &mut Pagination| { &mut m.limit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &Pagination| { &m.offset },
+            |m: &mut Pagination| { &mut m.offset },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Pagination>(
+            "Pagination",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Pagination {
+    const NAME: &'static str = "Pagination";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.limit = is.read_uint32()?;
+                },
+                16 => {
+                    self.offset = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.limit);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.offset);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.limit != 0 {
+            os.write_uint32(1, self.limit)?;
+        }
+        if self.offset != 0 {
+            os.write_uint32(2, self.offset)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Pagination {
+        Pagination::new()
+    }
+
+    fn clear(&mut self) {
+        self.limit = 0;
+        self.offset = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Pagination {
+        static instance: Pagination = Pagination {
+            limit: 0,
+            offset: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Pagination {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Pagination").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Pagination {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Pagination {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  Determine how to sort results from the API
+// @@protoc_insertion_point(message:Sort)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Sort {
+    // message fields
+    // @@protoc_insertion_point(field:Sort.direction)
+    pub direction: ::protobuf::EnumOrUnknown<Direction>,
+    // @@protoc_insertion_point(field:Sort.field)
+    pub field: ::protobuf::EnumOrUnknown<Field>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Sort.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Sort {
+    fn default() -> &'a Sort {
+        <Sort as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Sort {
+    pub fn new() -> Sort {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "direction",
+            |m: &Sort| { &m.direction },
+            |m: &mut Sort| { &mut m.direction },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "field",
+            |m: &Sort| { &m.field },
+            |m: &mut Sort| { &mut m.field },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Sort>(
+            "Sort",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Sort {
+    const NAME: &'static str = "Sort";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.direction = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.field = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.direction != ::protobuf::EnumOrUnknown::new(Direction::Desc) {
+            my_size += ::protobuf::rt::int32_size(1, self.direction.value());
+        }
+        if self.field != ::protobuf::EnumOrUnknown::new(Field::CreatedAt) {
+            my_size += ::protobuf::rt::int32_size(2, self.field.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.direction != ::protobuf::EnumOrUnknown::new(Direction::Desc) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.direction))?;
+        }
+        if self.field != ::protobuf::EnumOrUnknown::new(Field::CreatedAt) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.field))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Sort {
+        Sort::new()
+    }
+
+    fn clear(&mut self) {
+        self.direction = ::protobuf::EnumOrUnknown::new(Direction::Desc);
+        self.field = ::protobuf::EnumOrUnknown::new(Field::CreatedAt);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Sort {
+        static instance: Sort = Sort {
+            direction: ::protobuf::EnumOrUnknown::from_i32(0),
+            field: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Sort {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Sort").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Sort {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Sort {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `PUT /api/v1/module:`
+///  Insert a module, extract data from binary. Return the module ID & hash.
+// @@protoc_insertion_point(message:CreateModuleRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CreateModuleRequest {
+    // message fields
+    // @@protoc_insertion_point(field:CreateModuleRequest.wasm)
+    pub wasm: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:CreateModuleRequest.metadata)
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    ///  a valid URL with a scheme prefix e.g. `s3://`, `file://`, `https://`
+    // @@protoc_insertion_point(field:CreateModuleRequest.location)
+    pub location: ::std::option::Option<::std::string::String>,
+    ///  tags to attach to the module
+    // @@protoc_insertion_point(field:CreateModuleRequest.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    ///  version of the module, e.g. `1.2.0`
+    // @@protoc_insertion_point(field:CreateModuleRequest.version)
+    pub version: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CreateModuleRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CreateModuleRequest {
+    fn default() -> &'a CreateModuleRequest {
+        <CreateModuleRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateModuleRequest {
+    pub fn new() -> CreateModuleRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "wasm",
+            |m: &CreateModuleRequest| { &m.wasm },
+            |m: &mut CreateModuleRequest| { &mut m.wasm },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "metadata",
+            |m: &CreateModuleRequest| { &m.metadata },
+            |m: &mut CreateModuleRequest| { &mut m.metadata },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "location",
+            |m: &CreateModuleRequest| { &m.location },
+            |m: &mut CreateModuleRequest| { &mut m.location },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &CreateModuleRequest| { &m.tags },
+            |m: &mut CreateModuleRequest| { &mut m.tags },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "version",
+            |m: &CreateModuleRequest| { &m.version },
+            |m: &mut CreateModuleRequest| { &mut m.version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateModuleRequest>(
+            "CreateModuleRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CreateModuleRequest {
+    const NAME: &'static str = "CreateModuleRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.wasm = is.read_bytes()?;
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.metadata.insert(key, value);
+                },
+                26 => {
+                    self.location = ::std::option::Option::Some(is.read_string()?);
+                },
+                34 => {
+                    self.tags.push(is.read_string()?);
+                },
+                42 => {
+                    self.version = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.wasm.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.wasm);
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if let Some(v) = self.location.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if let Some(v) = self.version.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.wasm.is_empty() {
+            os.write_bytes(1, &self.wasm)?;
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        if let Some(v) = self.location.as_ref() {
+            os.write_string(3, v)?;
+        }
+        for v in &self.tags {
+            os.write_string(4, &v)?;
+        };
+        if let Some(v) = self.version.as_ref() {
+            os.write_string(5, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CreateModuleRequest {
+        CreateModuleRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.wasm.clear();
+        self.metadata.clear();
+        self.location = ::std::option::Option::None;
+        self.tags.clear();
+        self.version = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CreateModuleRequest {
+        static instance: ::protobuf::rt::Lazy<CreateModuleRequest> = ::protobuf::rt::Lazy::new();
+        instance.get(CreateModuleRequest::new)
+    }
+}
+
+impl ::protobuf::MessageFull for CreateModuleRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateModuleRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CreateModuleRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateModuleRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `CreateModuleRequest`.
+// @@protoc_insertion_point(message:CreateModuleResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CreateModuleResponse {
+    // message fields
+    // @@protoc_insertion_point(field:CreateModuleResponse.module_id)
+    pub module_id: i64,
+    // @@protoc_insertion_point(field:CreateModuleResponse.hash)
+    pub hash: ::std::string::String,
+    // @@protoc_insertion_point(field:CreateModuleResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CreateModuleResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CreateModuleResponse {
+    fn default() -> &'a CreateModuleResponse {
+        <CreateModuleResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateModuleResponse {
+    pub fn new() -> CreateModuleResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module_id",
+            |m: &CreateModuleResponse| { &m.module_id },
+            |m: &mut CreateModuleResponse| { &mut m.module_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash",
+            |m: &CreateModuleResponse| { &m.hash },
+            |m: &mut CreateModuleResponse| { &mut m.hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &CreateModuleResponse| { &m.error },
+            |m: &mut CreateModuleResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateModuleResponse>(
+            "CreateModuleResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CreateModuleResponse {
+    const NAME: &'static str = "CreateModuleResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.module_id = is.read_int64()?;
+                },
+                18 => {
+                    self.hash = is.read_string()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.module_id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        }
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.hash);
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.module_id != 0 {
+            os.write_int64(1, self.module_id)?;
+        }
+        if !self.hash.is_empty() {
+            os.write_string(2, &self.hash)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CreateModuleResponse {
+        CreateModuleResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.module_id = 0;
+        self.hash.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CreateModuleResponse {
+        static instance: CreateModuleResponse = CreateModuleResponse {
+            module_id: 0,
+            hash: ::std::string::String::new(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CreateModuleResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateModuleResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CreateModuleResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateModuleResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `POST /api/v1/module:`
+///  Return a single module.
+// @@protoc_insertion_point(message:GetModuleRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetModuleRequest {
+    // message fields
+    // @@protoc_insertion_point(field:GetModuleRequest.module_id)
+    pub module_id: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetModuleRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetModuleRequest {
+    fn default() -> &'a GetModuleRequest {
+        <GetModuleRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetModuleRequest {
+    pub fn new() -> GetModuleRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module_id",
+            |m: &GetModuleRequest| { &m.module_id },
+            |m: &mut GetModuleRequest| { &mut m.module_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleRequest>(
+            "GetModuleRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetModuleRequest {
+    const NAME: &'static str = "GetModuleRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.module_id = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.module_id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.module_id != 0 {
+            os.write_int64(1, self.module_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetModuleRequest {
+        GetModuleRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.module_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetModuleRequest {
+        static instance: GetModuleRequest = GetModuleRequest {
+            module_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetModuleRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetModuleRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetModuleRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `GetModuleRequest`.
+// @@protoc_insertion_point(message:GetModuleResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetModuleResponse {
+    // message fields
+    // @@protoc_insertion_point(field:GetModuleResponse.module)
+    pub module: ::protobuf::MessageField<Module>,
+    // @@protoc_insertion_point(field:GetModuleResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetModuleResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetModuleResponse {
+    fn default() -> &'a GetModuleResponse {
+        <GetModuleResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetModuleResponse {
+    pub fn new() -> GetModuleResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Module>(
+            "module",
+            |m: &GetModuleResponse| { &m.module },
+            |m: &mut GetModuleResponse| { &mut m.module },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &GetModuleResponse| { &m.error },
+            |m: &mut GetModuleResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleResponse>(
+            "GetModuleResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetModuleResponse {
+    const NAME: &'static str = "GetModuleResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.module)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.module.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.module.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetModuleResponse {
+        GetModuleResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.module.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetModuleResponse {
+        static instance: GetModuleResponse = GetModuleResponse {
+            module: ::protobuf::MessageField::none(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetModuleResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetModuleResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetModuleResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `POST /api/v1/modules:`
+///  Return paginated list of all modules.
+// @@protoc_insertion_point(message:ListModulesRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ListModulesRequest {
+    // message fields
+    // @@protoc_insertion_point(field:ListModulesRequest.pagination)
+    pub pagination: ::protobuf::MessageField<Pagination>,
+    // @@protoc_insertion_point(field:ListModulesRequest.sort)
+    pub sort: ::protobuf::MessageField<Sort>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ListModulesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListModulesRequest {
+    fn default() -> &'a ListModulesRequest {
+        <ListModulesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListModulesRequest {
+    pub fn new() -> ListModulesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
+            "pagination",
+            |m: &ListModulesRequest| { &m.pagination },
+            |m: &mut ListModulesRequest| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
+            "sort",
+            |m: &ListModulesRequest| { &m.sort },
+            |m: &mut ListModulesRequest| { &mut m.sort },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListModulesRequest>(
+            "ListModulesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListModulesRequest {
+    const NAME: &'static str = "ListModulesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.sort.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.sort.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListModulesRequest {
+        ListModulesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.pagination.clear();
+        self.sort.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListModulesRequest {
+        static instance: ListModulesRequest = ListModulesRequest {
+            pagination: ::protobuf::MessageField::none(),
+            sort: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListModulesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListModulesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListModulesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListModulesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `ListModulesRequest`.
+// @@protoc_insertion_point(message:ListModulesResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ListModulesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:ListModulesResponse.modules)
+    pub modules: ::std::vec::Vec<Module>,
+    // @@protoc_insertion_point(field:ListModulesResponse.pagination)
+    pub pagination: ::protobuf::MessageField<Pagination>,
+    ///  the full count of results in the database (not the count of this message's
+    ///  `modules`).
+    // @@protoc_insertion_point(field:ListModulesResponse.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:ListModulesResponse.sort)
+    pub sort: ::protobuf::MessageField<Sort>,
+    // @@protoc_insertion_point(field:ListModulesResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ListModulesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ListModulesResponse {
+    fn default() -> &'a ListModulesResponse {
+        <ListModulesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListModulesResponse {
+    pub fn new() -> ListModulesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "modules",
+            |m: &ListModulesResponse| { &m.modules },
+            |m: &mut ListModulesResponse| { &mut m.modules },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
+            "pagination",
+            |m: &ListModulesResponse| { &m.pagination },
+            |m: &mut ListModulesResponse| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &ListModulesResponse| { &m.total },
+            |m: &mut ListModulesResponse| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
+            "sort",
+            |m: &ListModulesResponse| { &m.sort },
+            |m: &mut ListModulesResponse| { &mut m.sort },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &ListModulesResponse| { &m.error },
+            |m: &mut ListModulesResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListModulesResponse>(
+            "ListModulesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ListModulesResponse {
+    const NAME: &'static str = "ListModulesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.modules.push(is.read_message()?);
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                24 => {
+                    self.total = is.read_uint64()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.modules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.total);
+        }
+        if let Some(v) = self.sort.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.modules {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(3, self.total)?;
+        }
+        if let Some(v) = self.sort.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ListModulesResponse {
+        ListModulesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.modules.clear();
+        self.pagination.clear();
+        self.total = 0;
+        self.sort.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ListModulesResponse {
+        static instance: ListModulesResponse = ListModulesResponse {
+            modules: ::std::vec::Vec::new(),
+            pagination: ::protobuf::MessageField::none(),
+            total: 0,
+            sort: ::protobuf::MessageField::none(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ListModulesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListModulesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ListModulesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListModulesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `POST /api/v1/search:`
+///  Search for modules based on filter params provided (which should be any
+///  dimension of the module schema, or string search in any metadata value).
+///  Return a paginated list of matching modules.
+// @@protoc_insertion_point(message:SearchModulesRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SearchModulesRequest {
+    // message fields
+    ///  ID for this module, generated by the database.
+    // @@protoc_insertion_point(field:SearchModulesRequest.id)
+    pub id: ::std::option::Option<i64>,
+    ///  original name of the binary module file
+    // @@protoc_insertion_point(field:SearchModulesRequest.hash)
+    pub hash: ::std::option::Option<::std::string::String>,
+    ///  function imports called by the module (see:
+    ///  <https://github.com/WebAssembly/design/blob/main/Modules.md#imports>)
+    // @@protoc_insertion_point(field:SearchModulesRequest.imports)
+    pub imports: ::std::vec::Vec<Import>,
+    ///  function exports provided by the module (see:
+    ///  <https://github.com/WebAssembly/design/blob/main/Modules.md#exports>)
+    // @@protoc_insertion_point(field:SearchModulesRequest.exports)
+    pub exports: ::std::vec::Vec<Export>,
+    ///  minimum size in bytes of the module
+    // @@protoc_insertion_point(field:SearchModulesRequest.min_size)
+    pub min_size: ::std::option::Option<u64>,
+    ///  maximum size in bytes of the module
+    // @@protoc_insertion_point(field:SearchModulesRequest.max_size)
+    pub max_size: ::std::option::Option<u64>,
+    ///  optional path or locator to the module (TODO: maybe this is better stored
+    ///  as metadata)
+    // @@protoc_insertion_point(field:SearchModulesRequest.location)
+    pub location: ::std::option::Option<::std::string::String>,
+    ///  programming language used to produce this module
+    // @@protoc_insertion_point(field:SearchModulesRequest.source_language)
+    pub source_language: ::std::option::Option<::protobuf::EnumOrUnknown<SourceLanguage>>,
+    ///  arbitrary metadata provided by the operator of this module
+    // @@protoc_insertion_point(field:SearchModulesRequest.metadata)
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    ///  timestamp when this module was loaded and stored
+    // @@protoc_insertion_point(field:SearchModulesRequest.inserted_before)
+    pub inserted_before: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    ///  timestamp when this module was loaded and stored
+    // @@protoc_insertion_point(field:SearchModulesRequest.inserted_after)
+    pub inserted_after: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    ///  the interned strings stored in the wasm binary (panic/abort messages, etc.)
+    // @@protoc_insertion_point(field:SearchModulesRequest.strings)
+    pub strings: ::std::vec::Vec<::std::string::String>,
+    ///  match on any function name in an import or export.
+    // @@protoc_insertion_point(field:SearchModulesRequest.function_name)
+    pub function_name: ::std::option::Option<::std::string::String>,
+    ///  match on the module name e.g. `env` or `wasi_snapshot_preview1`
+    // @@protoc_insertion_point(field:SearchModulesRequest.module_name)
+    pub module_name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SearchModulesRequest.pagination)
+    pub pagination: ::protobuf::MessageField<Pagination>,
+    // @@protoc_insertion_point(field:SearchModulesRequest.sort)
+    pub sort: ::protobuf::MessageField<Sort>,
+    ///  match modules which have all of the provided tags
+    // @@protoc_insertion_point(field:SearchModulesRequest.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    ///  match on the exact version of the module
+    // @@protoc_insertion_point(field:SearchModulesRequest.version)
+    pub version: ::std::option::Option<::std::string::String>,
+    ///  minimum cyclomatic complexity of the module
+    // @@protoc_insertion_point(field:SearchModulesRequest.min_complexity)
+    pub min_complexity: ::std::option::Option<u32>,
+    ///  maximum cyclomatic complexity of the module
+    // @@protoc_insertion_point(field:SearchModulesRequest.max_complexity)
+    pub max_complexity: ::std::option::Option<u32>,
+    ///  match modules which import from all of the provided namespaces, e.g.
+    ///  `wasi_snapshot_preview1`
+    // @@protoc_insertion_point(field:SearchModulesRequest.namespaces)
+    pub namespaces: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SearchModulesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SearchModulesRequest {
+    fn default() -> &'a SearchModulesRequest {
+        <SearchModulesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchModulesRequest {
+    pub fn new() -> SearchModulesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(21);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "id",
+            |m: &SearchModulesRequest| { &m.id },
+            |m: &mut SearchModulesRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "hash",
+            |m: &SearchModulesRequest| { &m.hash },
+            |m: &mut SearchModulesRequest| { &mut m.hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "imports",
+            |m: &SearchModulesRequest| { &m.imports },
+            |m: &mut SearchModulesRequest| { &mut m.imports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "exports",
+            |m: &SearchModulesRequest| { &m.exports },
+            |m: &mut SearchModulesRequest| { &mut m.exports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "min_size",
+            |m: &SearchModulesRequest| { &m.min_size },
+            |m: &mut SearchModulesRequest| { &mut m.min_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_size",
+            |m: &SearchModulesRequest| { &m.max_size },
+            |m: &mut SearchModulesRequest| { &mut m.max_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "location",
+            |m: &SearchModulesRequest| { &m.location },
+            |m: &mut SearchModulesRequest| { &mut m.location },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "source_language",
+            |m: &SearchModulesRequest| { &m.source_language },
+            |m: &mut SearchModulesRequest| { &mut m.source_language },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "metadata",
+            |m: &SearchModulesRequest| { &m.metadata },
+            |m: &mut SearchModulesRequest| { &mut m.metadata },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "inserted_before",
+            |m: &SearchModulesRequest| { &m.inserted_before },
+            |m: &mut SearchModulesRequest| { &mut m.inserted_before },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "inserted_after",
+            |m: &SearchModulesRequest| { &m.inserted_after },
+            |m: &mut SearchModulesRequest| { &mut m.inserted_after },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "strings",
+            |m: &SearchModulesRequest| { &m.strings },
+            |m: &mut SearchModulesRequest| { &mut m.strings },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "function_name",
+            |m: &SearchModulesRequest| { &m.function_name },
+            |m: &mut SearchModulesRequest| { &mut m.function_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "module_name",
+            |m: &SearchModulesRequest| { &m.module_name },
+            |m: &mut SearchModulesRequest| { &mut m.module_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
+            "pagination",
+            |m: &SearchModulesRequest| { &m.pagination },
+            |m: &mut SearchModulesRequest| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
+            "sort",
+            |m: &SearchModulesRequest| { &m.sort },
+            |m: &mut SearchModulesRequest| { &mut m.sort },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &SearchModulesRequest| { &m.tags },
+            |m: &mut SearchModulesRequest| { &mut m.tags },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "version",
+            |m: &SearchModulesRequest| { &m.version },
+            |m: &mut SearchModulesRequest| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "min_complexity",
+            |m: &SearchModulesRequest| { &m.min_complexity },
+            |m: &mut SearchModulesRequest| { &mut m.min_complexity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_complexity",
+            |m: &SearchModulesRequest| { &m.max_complexity },
+            |m: &mut SearchModulesRequest| { &mut m.max_complexity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "namespaces",
+            |m: &SearchModulesRequest| { &m.namespaces },
+            |m: &mut SearchModulesRequest| { &mut m.namespaces },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SearchModulesRequest>(
+            "SearchModulesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SearchModulesRequest {
+    const NAME: &'static str = "SearchModulesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = ::std::option::Option::Some(is.read_int64()?);
+                },
+                26 => {
+                    self.hash = ::std::option::Option::Some(is.read_string()?);
+                },
+                34 => {
+                    self.imports.push(is.read_message()?);
+                },
+                42 => {
+                    self.exports.push(is.read_message()?);
+                },
+                48 => {
+                    self.min_size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                56 => {
+                    self.max_size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                66 => {
+                    self.location = ::std::option::Option::Some(is.read_string()?);
+                },
+                72 => {
+                    self.source_language = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                82 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.metadata.insert(key, value);
+                },
+                90 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.inserted_before)?;
+                },
+                98 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.inserted_after)?;
+                },
+                106 => {
+                    self.strings.push(is.read_string()?);
+                },
+                114 => {
+                    self.function_name = ::std::option::Option::Some(is.read_string()?);
+                },
+                122 => {
+                    self.module_name = ::std::option::Option::Some(is.read_string()?);
+                },
+                130 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                138 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
+                },
+                146 => {
+                    self.tags.push(is.read_string()?);
+                },
+                154 => {
+                    self.version = ::std::option::Option::Some(is.read_string()?);
+                },
+                160 => {
+                    self.min_complexity = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                168 => {
+                    self.max_complexity = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                178 => {
+                    self.namespaces.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::int64_size(1, v);
+        }
+        if let Some(v) = self.hash.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        for value in &self.imports {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.exports {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.min_size {
+            my_size += ::protobuf::rt::uint64_size(6, v);
+        }
+        if let Some(v) = self.max_size {
+            my_size += ::protobuf::rt::uint64_size(7, v);
+        }
+        if let Some(v) = self.location.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(v) = self.source_language {
+            my_size += ::protobuf::rt::int32_size(9, v.value());
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if let Some(v) = self.inserted_before.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.inserted_after.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.strings {
+            my_size += ::protobuf::rt::string_size(13, &value);
+        };
+        if let Some(v) = self.function_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(14, &v);
+        }
+        if let Some(v) = self.module_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(15, &v);
+        }
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.sort.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(18, &value);
+        };
+        if let Some(v) = self.version.as_ref() {
+            my_size += ::protobuf::rt::string_size(19, &v);
+        }
+        if let Some(v) = self.min_complexity {
+            my_size += ::protobuf::rt::uint32_size(20, v);
+        }
+        if let Some(v) = self.max_complexity {
+            my_size += ::protobuf::rt::uint32_size(21, v);
+        }
+        for value in &self.namespaces {
+            my_size += ::protobuf::rt::string_size(22, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id {
+            os.write_int64(1, v)?;
+        }
+        if let Some(v) = self.hash.as_ref() {
+            os.write_string(3, v)?;
+        }
+        for v in &self.imports {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.exports {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if let Some(v) = self.min_size {
+            os.write_uint64(6, v)?;
+        }
+        if let Some(v) = self.max_size {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(v) = self.location.as_ref() {
+            os.write_string(8, v)?;
+        }
+        if let Some(v) = self.source_language {
+            os.write_enum(9, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(82)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        if let Some(v) = self.inserted_before.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+        }
+        if let Some(v) = self.inserted_after.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
+        for v in &self.strings {
+            os.write_string(13, &v)?;
+        };
+        if let Some(v) = self.function_name.as_ref() {
+            os.write_string(14, v)?;
+        }
+        if let Some(v) = self.module_name.as_ref() {
+            os.write_string(15, v)?;
+        }
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(16, v, os)?;
+        }
+        if let Some(v) = self.sort.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(17, v, os)?;
+        }
+        for v in &self.tags {
+            os.write_string(18, &v)?;
+        };
+        if let Some(v) = self.version.as_ref() {
+            os.write_string(19, v)?;
+        }
+        if let Some(v) = self.min_complexity {
+            os.write_uint32(20, v)?;
+        }
+        if let Some(v) = self.max_complexity {
+            os.write_uint32(21, v)?;
+        }
+        for v in &self.namespaces {
+            os.write_string(22, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SearchModulesRequest {
+        SearchModulesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = ::std::option::Option::None;
+        self.hash = ::std::option::Option::None;
+        self.imports.clear();
+        self.exports.clear();
+        self.min_size = ::std::option::Option::None;
+        self.max_size = ::std::option::Option::None;
+        self.location = ::std::option::Option::None;
+        self.source_language = ::std::option::Option::None;
+        self.metadata.clear();
+        self.inserted_before.clear();
+        self.inserted_after.clear();
+        self.strings.clear();
+        self.function_name = ::std::option::Option::None;
+        self.module_name = ::std::option::Option::None;
+        self.pagination.clear();
+        self.sort.clear();
+        self.tags.clear();
+        self.version = ::std::option::Option::None;
+        self.min_complexity = ::std::option::Option::None;
+        self.max_complexity = ::std::option::Option::None;
+        self.namespaces.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SearchModulesRequest {
+        static instance: ::protobuf::rt::Lazy<SearchModulesRequest> = ::protobuf::rt::Lazy::new();
+        instance.get(SearchModulesRequest::new)
+    }
+}
+
+impl ::protobuf::MessageFull for SearchModulesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SearchModulesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SearchModulesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchModulesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `SearchModulesRequest`.
+// @@protoc_insertion_point(message:SearchModulesResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SearchModulesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:SearchModulesResponse.modules)
+    pub modules: ::std::vec::Vec<Module>,
+    // @@protoc_insertion_point(field:SearchModulesResponse.pagination)
+    pub pagination: ::protobuf::MessageField<Pagination>,
+    ///  the full count of results in the database (not the count of this message's
+    ///  `modules`).
+    // @@protoc_insertion_point(field:SearchModulesResponse.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:SearchModulesResponse.sort)
+    pub sort: ::protobuf::MessageField<Sort>,
+    // @@protoc_insertion_point(field:SearchModulesResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SearchModulesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SearchModulesResponse {
+    fn default() -> &'a SearchModulesResponse {
+        <SearchModulesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchModulesResponse {
+    pub fn new() -> SearchModulesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "modules",
+            |m: &SearchModulesResponse| { &m.modules },
+            |m: &mut SearchModulesResponse| { &mut m.modules },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
+            "pagination",
+            |m: &SearchModulesResponse| { &m.pagination },
+            |m: &mut SearchModulesResponse| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &SearchModulesResponse| { &m.total },
+            |m: &mut SearchModulesResponse| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
+            "sort",
+            |m: &SearchModulesResponse| { &m.sort },
+            |m: &mut SearchModulesResponse| { &mut m.sort },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &SearchModulesResponse| { &m.error },
+            |m: &mut SearchModulesResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SearchModulesResponse>(
+            "SearchModulesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SearchModulesResponse {
+    const NAME: &'static str = "SearchModulesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.modules.push(is.read_message()?);
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                24 => {
+                    self.total = is.read_uint64()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.modules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.total);
+        }
+        if let Some(v) = self.sort.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.modules {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(3, self.total)?;
+        }
+        if let Some(v) = self.sort.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SearchModulesResponse {
+        SearchModulesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.modules.clear();
+        self.pagination.clear();
+        self.total = 0;
+        self.sort.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SearchModulesResponse {
+        static instance: SearchModulesResponse = SearchModulesResponse {
+            modules: ::std::vec::Vec::new(),
+            pagination: ::protobuf::MessageField::none(),
+            total: 0,
+            sort: ::protobuf::MessageField::none(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SearchModulesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SearchModulesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SearchModulesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchModulesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `DELETE /api/v1/module:`
+///  Remove a module from the database by its ID. Return the module IDs & hashes.
+// @@protoc_insertion_point(message:DeleteModulesRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeleteModulesRequest {
+    // message fields
+    // @@protoc_insertion_point(field:DeleteModulesRequest.module_ids)
+    pub module_ids: ::std::vec::Vec<i64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeleteModulesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteModulesRequest {
+    fn default() -> &'a DeleteModulesRequest {
+        <DeleteModulesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteModulesRequest {
+    pub fn new() -> DeleteModulesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "module_ids",
+            |m: &DeleteModulesRequest| { &m.module_ids },
+            |m: &mut DeleteModulesRequest| { &mut m.module_ids },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteModulesRequest>(
+            "DeleteModulesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteModulesRequest {
+    const NAME: &'static str = "DeleteModulesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    is.read_repeated_packed_int64_into(&mut self.module_ids)?;
+                },
+                8 => {
+                    self.module_ids.push(is.read_int64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.module_ids {
+            my_size += ::protobuf::rt::int64_size(1, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.module_ids {
+            os.write_int64(1, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteModulesRequest {
+        DeleteModulesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.module_ids.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteModulesRequest {
+        static instance: DeleteModulesRequest = DeleteModulesRequest {
+            module_ids: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteModulesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteModulesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteModulesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteModulesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `DeleteModulesRequest`.
+// @@protoc_insertion_point(message:DeleteModulesResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeleteModulesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:DeleteModulesResponse.module_id_hash)
+    pub module_id_hash: ::std::collections::HashMap<i64, ::std::string::String>,
+    // @@protoc_insertion_point(field:DeleteModulesResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeleteModulesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteModulesResponse {
+    fn default() -> &'a DeleteModulesResponse {
+        <DeleteModulesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteModulesResponse {
+    pub fn new() -> DeleteModulesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "module_id_hash",
+            |m: &DeleteModulesResponse| { &m.module_id_hash },
+            |m: &mut DeleteModulesResponse| { &mut m.module_id_hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &DeleteModulesResponse| { &m.error },
+            |m: &mut DeleteModulesResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteModulesResponse>(
+            "DeleteModulesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteModulesResponse {
+    const NAME: &'static str = "DeleteModulesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            8 => key = is.read_int64()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.module_id_hash.insert(key, value);
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for (k, v) in &self.module_id_hash {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::int64_size(1, *k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for (k, v) in &self.module_id_hash {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::int64_size(1, *k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(10)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_int64(1, *k)?;
+            os.write_string(2, &v)?;
+        };
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteModulesResponse {
+        DeleteModulesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.module_id_hash.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteModulesResponse {
+        static instance: ::protobuf::rt::Lazy<DeleteModulesResponse> = ::protobuf::rt::Lazy::new();
+        instance.get(DeleteModulesResponse::new)
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteModulesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteModulesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteModulesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteModulesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `PUT /api/v1/module/tags:`
+///  Replace the tags attached to a module. Return the module's resulting tags.
+// @@protoc_insertion_point(message:TagModuleRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TagModuleRequest {
+    // message fields
+    // @@protoc_insertion_point(field:TagModuleRequest.module_id)
+    pub module_id: i64,
+    // @@protoc_insertion_point(field:TagModuleRequest.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:TagModuleRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TagModuleRequest {
+    fn default() -> &'a TagModuleRequest {
+        <TagModuleRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TagModuleRequest {
+    pub fn new() -> TagModuleRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module_id",
+            |m: &TagModuleRequest| { &m.module_id },
+            |m: &mut TagModuleRequest| { &mut m.module_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &TagModuleRequest| { &m.tags },
+            |m: &mut TagModuleRequest| { &mut m.tags },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TagModuleRequest>(
+            "TagModuleRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TagModuleRequest {
+    const NAME: &'static str = "TagModuleRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.module_id = is.read_int64()?;
+                },
+                18 => {
+                    self.tags.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.module_id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.module_id != 0 {
+            os.write_int64(1, self.module_id)?;
+        }
+        for v in &self.tags {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TagModuleRequest {
+        TagModuleRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.module_id = 0;
+        self.tags.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TagModuleRequest {
+        static instance: TagModuleRequest = TagModuleRequest {
+            module_id: 0,
+            tags: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TagModuleRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TagModuleRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TagModuleRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TagModuleRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `TagModuleRequest`.
+// @@protoc_insertion_point(message:TagModuleResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TagModuleResponse {
+    // message fields
+    // @@protoc_insertion_point(field:TagModuleResponse.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:TagModuleResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:TagModuleResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TagModuleResponse {
+    fn default() -> &'a TagModuleResponse {
+        <TagModuleResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TagModuleResponse {
+    pub fn new() -> TagModuleResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &TagModuleResponse| { &m.tags },
+            |m: &mut TagModuleResponse| { &mut m.tags },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &TagModuleResponse| { &m.error },
+            |m: &mut TagModuleResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TagModuleResponse>(
+            "TagModuleResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TagModuleResponse {
+    const NAME: &'static str = "TagModuleResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.tags.push(is.read_string()?);
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.tags {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TagModuleResponse {
+        TagModuleResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.tags.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TagModuleResponse {
+        static instance: TagModuleResponse = TagModuleResponse {
+            tags: ::std::vec::Vec::new(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TagModuleResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TagModuleResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TagModuleResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TagModuleResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `PUT /api/v1/module/quarantine:`
+///  Quarantine a module, or lift its quarantine. Return the module's resulting
+///  quarantine, if any.
+// @@protoc_insertion_point(message:QuarantineModuleRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct QuarantineModuleRequest {
+    // message fields
+    // @@protoc_insertion_point(field:QuarantineModuleRequest.module_id)
+    pub module_id: i64,
+    ///  quarantine the module if true, otherwise lift its quarantine
+    // @@protoc_insertion_point(field:QuarantineModuleRequest.quarantined)
+    pub quarantined: bool,
+    ///  why the module is quarantined, required when `quarantined` is true
+    // @@protoc_insertion_point(field:QuarantineModuleRequest.reason)
+    pub reason: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:QuarantineModuleRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a QuarantineModuleRequest {
+    fn default() -> &'a QuarantineModuleRequest {
+        <QuarantineModuleRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuarantineModuleRequest {
+    pub fn new() -> QuarantineModuleRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module_id",
+            |m: &QuarantineModuleRequest| { &m.module_id },
+            |m: &mut QuarantineModuleRequest| { &mut m.module_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "quarantined",
+            |m: &QuarantineModuleRequest| { &m.quarantined },
+            |m: &mut QuarantineModuleRequest| { &mut m.quarantined },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reason",
+            |m: &QuarantineModuleRequest| { &m.reason },
+            |m: &mut QuarantineModuleRequest| { &mut m.reason },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<QuarantineModuleRequest>(
+            "QuarantineModuleRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for QuarantineModuleRequest {
+    const NAME: &'static str = "QuarantineModuleRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.module_id = is.read_int64()?;
+                },
+                16 => {
+                    self.quarantined = is.read_bool()?;
+                },
+                26 => {
+                    self.reason = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.module_id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        }
+        if self.quarantined != false {
+            my_size += 1 + 1;
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.module_id != 0 {
+            os.write_int64(1, self.module_id)?;
+        }
+        if self.quarantined != false {
+            os.write_bool(2, self.quarantined)?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(3, &self.reason)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> QuarantineModuleRequest {
+        QuarantineModuleRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.module_id = 0;
+        self.quarantined = false;
+        self.reason.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static QuarantineModuleRequest {
+        static instance: QuarantineModuleRequest = QuarantineModuleRequest {
+            module_id: 0,
+            quarantined: false,
+            reason: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for QuarantineModuleRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("QuarantineModuleRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for QuarantineModuleRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuarantineModuleRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `QuarantineModuleRequest`.
+// @@protoc_insertion_point(message:QuarantineModuleResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct QuarantineModuleResponse {
+    // message fields
+    // @@protoc_insertion_point(field:QuarantineModuleResponse.quarantine)
+    pub quarantine: ::protobuf::MessageField<Quarantine>,
+    // @@protoc_insertion_point(field:QuarantineModuleResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:QuarantineModuleResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a QuarantineModuleResponse {
+    fn default() -> &'a QuarantineModuleResponse {
+        <QuarantineModuleResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuarantineModuleResponse {
+    pub fn new() -> QuarantineModuleResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Quarantine>(
+            "quarantine",
+            |m: &QuarantineModuleResponse| { &m.quarantine },
+            |m: &mut QuarantineModuleResponse| { &mut m.quarantine },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &QuarantineModuleResponse| { &m.error },
+            |m: &mut QuarantineModuleResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<QuarantineModuleResponse>(
+            "QuarantineModuleResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for QuarantineModuleResponse {
+    const NAME: &'static str = "QuarantineModuleResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.quarantine)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.quarantine.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.quarantine.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> QuarantineModuleResponse {
+        QuarantineModuleResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.quarantine.clear();
+        self.error.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static QuarantineModuleResponse {
+        static instance: QuarantineModuleResponse = QuarantineModuleResponse {
+            quarantine: ::protobuf::MessageField::none(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for QuarantineModuleResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("QuarantineModuleResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for QuarantineModuleResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuarantineModuleResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  Represents the expected outcome of an AuditModulesRequest. If PASS is provided, then
+///  the audit returns modules which conform to the checkfile. If FAIL is provided, then
+///  the audit returns modules which do not conform to the checkfile.
+///  A checkfile (e.g. mod.yaml), describing the requirements a module must meet to pass validation.
+// @@protoc_insertion_point(message:Checkfile)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Checkfile {
+    // message fields
+    // @@protoc_insertion_point(field:Checkfile.validate)
+    pub validate: ::protobuf::MessageField<Check>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Checkfile.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Checkfile {
+    fn default() -> &'a Checkfile {
+        <Checkfile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Checkfile {
+    pub fn new() -> Checkfile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Check>(
+            "validate",
+            |m: &Checkfile| { &m.validate },
+            |m: &mut Checkfile| { &mut m.validate },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Checkfile>(
+            "Checkfile",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Checkfile {
+    const NAME: &'static str = "Checkfile";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.validate)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.validate.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.validate.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Checkfile {
+        Checkfile::new()
+    }
+
+    fn clear(&mut self) {
+        self.validate.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Checkfile {
+        static instance: Checkfile = Checkfile {
+            validate: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Checkfile {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Checkfile").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Checkfile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Checkfile {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The individual requirements of a checkfile. Unset fields are not checked.
+// @@protoc_insertion_point(message:Check)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Check {
+    // message fields
+    // @@protoc_insertion_point(field:Check.url)
+    pub url: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:Check.allow_wasi)
+    pub allow_wasi: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:Check.imports)
+    pub imports: ::protobuf::MessageField<CheckImports>,
+    // @@protoc_insertion_point(field:Check.exports)
+    pub exports: ::protobuf::MessageField<CheckExports>,
+    // @@protoc_insertion_point(field:Check.size)
+    pub size: ::protobuf::MessageField<CheckSize>,
+    // @@protoc_insertion_point(field:Check.complexity)
+    pub complexity: ::protobuf::MessageField<CheckComplexity>,
+    ///  the hex-encoded SHA-256 hash which the checkfile fetched from `url` must have
+    // @@protoc_insertion_point(field:Check.url_sha256)
+    pub url_sha256: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:Check.risk)
+    pub risk: ::protobuf::MessageField<CheckRisk>,
+    // @@protoc_insertion_point(field:Check.capabilities)
+    pub capabilities: ::protobuf::MessageField<CheckCapabilities>,
+    // @@protoc_insertion_point(field:Check.licenses)
+    pub licenses: ::protobuf::MessageField<CheckLicenses>,
+    // @@protoc_insertion_point(field:Check.vulnerabilities)
+    pub vulnerabilities: ::protobuf::MessageField<CheckVulnerabilities>,
+    // @@protoc_insertion_point(field:Check.integrity)
+    pub integrity: ::protobuf::MessageField<CheckIntegrity>,
+    // @@protoc_insertion_point(field:Check.module_name)
+    pub module_name: ::protobuf::MessageField<CheckModuleName>,
+    // @@protoc_insertion_point(field:Check.location)
+    pub location: ::protobuf::MessageField<CheckLocation>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Check.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Check {
+    fn default() -> &'a Check {
+        <Check as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Check {
+    pub fn new() -> Check {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(14);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "url",
+            |m: &Check| { &m.url },
+            |m: &mut Check| { &mut m.url },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "allow_wasi",
+            |m: &Check| { &m.allow_wasi },
+            |m: &mut Check| { &mut m.allow_wasi },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckImports>(
+            "imports",
+            |m: &Check| { &m.imports },
+            |m: &mut Check| { &mut m.imports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckExports>(
+            "exports",
+            |m: &Check| { &m.exports },
+            |m: &mut Check| { &mut m.exports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckSize>(
+            "size",
+            |m: &Check| { &m.size },
+            |m: &mut Check| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckComplexity>(
+            "complexity",
+            |m: &Check| { &m.complexity },
+            |m: &mut Check| { &mut m.complexity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "url_sha256",
+            |m: &Check| { &m.url_sha256 },
+            |m: &mut Check| { &mut m.url_sha256 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckRisk>(
+            "risk",
+            |m: &Check| { &m.risk },
+            |m: &mut Check| { &mut m.risk },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckCapabilities>(
+            "capabilities",
+            |m: &Check| { &m.capabilities },
+            |m: &mut Check| { &mut m.capabilities },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckLicenses>(
+            "licenses",
+            |m: &Check| { &m.licenses },
+            |m: &mut Check| { &mut m.licenses },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckVulnerabilities>(
+            "vulnerabilities",
+            |m: &Check| { &m.vulnerabilities },
+            |m: &mut Check| { &mut m.vulnerabilities },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckIntegrity>(
+            "integrity",
+            |m: &Check| { &m.integrity },
+            |m: &mut Check| { &mut m.integrity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckModuleName>(
+            "module_name",
+            |m: &Check| { &m.module_name },
+            |m: &mut Check| { &mut m.module_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckLocation>(
+            "location",
+            |m: &Check| { &m.location },
+            |m: &mut Check| { &mut m.location },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Check>(
+            "Check",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Check {
+    const NAME: &'static str = "Check";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.url = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.allow_wasi = ::std::option::Option::Some(is.read_bool()?);
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.imports)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.exports)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.size)?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.complexity)?;
+                },
+                58 => {
+                    self.url_sha256 = ::std::option::Option::Some(is.read_string()?);
+                },
+                66 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.risk)?;
+                },
+                74 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.capabilities)?;
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.licenses)?;
+                },
+                90 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.vulnerabilities)?;
+                },
+                98 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.integrity)?;
+                },
+                106 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.module_name)?;
+                },
+                114 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.location)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.url.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.allow_wasi {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.imports.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.exports.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.size.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.complexity.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.url_sha256.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(v) = self.risk.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.capabilities.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.licenses.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.vulnerabilities.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.integrity.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.module_name.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.url.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.allow_wasi {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.imports.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.exports.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.size.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        if let Some(v) = self.complexity.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if let Some(v) = self.url_sha256.as_ref() {
+            os.write_string(7, v)?;
+        }
+        if let Some(v) = self.risk.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+        }
+        if let Some(v) = self.capabilities.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        }
+        if let Some(v) = self.licenses.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if let Some(v) = self.vulnerabilities.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+        }
+        if let Some(v) = self.integrity.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
+        if let Some(v) = self.module_name.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+        }
+        if let Some(v) = self.location.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Check {
+        Check::new()
+    }
+
+    fn clear(&mut self) {
+        self.url = ::std::option::Option::None;
+        self.allow_wasi = ::std::option::Option::None;
+        self.imports.clear();
+        self.exports.clear();
+        self.size.clear();
+        self.complexity.clear();
+        self.url_sha256 = ::std::option::Option::None;
+        self.risk.clear();
+        self.capabilities.clear();
+        self.licenses.clear();
+        self.vulnerabilities.clear();
+        self.integrity.clear();
+        self.module_name.clear();
+        self.location.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Check {
+        static instance: Check = Check {
+            url: ::std::option::Option::None,
+            allow_wasi: ::std::option::Option::None,
+            imports: ::protobuf::MessageField::none(),
+            exports: ::protobuf::MessageField::none(),
+            size: ::protobuf::MessageField::none(),
+            complexity: ::protobuf::MessageField::none(),
+            url_sha256: ::std::option::Option::None,
+            risk: ::protobuf::MessageField::none(),
+            capabilities: ::protobuf::MessageField::none(),
+            licenses: ::protobuf::MessageField::none(),
+            vulnerabilities: ::protobuf::MessageField::none(),
+            integrity: ::protobuf::MessageField::none(),
+            module_name: ::protobuf::MessageField::none(),
+            location: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Check {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Check").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Check {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Check {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  A list of value types, wrapped so an unchecked list can be told apart from an empty one.
+// @@protoc_insertion_point(message:ValTypes)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ValTypes {
+    // message fields
+    // @@protoc_insertion_point(field:ValTypes.types)
+    pub types: ::std::vec::Vec<::protobuf::EnumOrUnknown<ValType>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ValTypes.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ValTypes {
+    fn default() -> &'a ValTypes {
+        <ValTypes as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ValTypes {
+    pub fn new() -> ValTypes {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "types",
+            |m: &ValTypes| { &m.types },
+            |m: &mut ValTypes| { &mut m.types },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ValTypes>(
+            "ValTypes",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ValTypes {
+    const NAME: &'static str = "ValTypes";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.types.push(is.read_enum_or_unknown()?);
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.types)?
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.types {
+            my_size += ::protobuf::rt::int32_size(1, value.value());
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.types {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ValTypes {
+        ValTypes::new()
+    }
+
+    fn clear(&mut self) {
+        self.types.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ValTypes {
+        static instance: ValTypes = ValTypes {
+            types: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ValTypes {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ValTypes").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ValTypes {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ValTypes {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckImports)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckImports {
+    // message fields
+    // @@protoc_insertion_point(field:CheckImports.include)
+    pub include: ::protobuf::MessageField<CheckImportItems>,
+    // @@protoc_insertion_point(field:CheckImports.exclude)
+    pub exclude: ::protobuf::MessageField<CheckImportItems>,
+    // @@protoc_insertion_point(field:CheckImports.namespace)
+    pub namespace: ::protobuf::MessageField<CheckNamespaces>,
+    ///  how `include` is checked: `require_all`, `allow_only` (the default) or `both`
+    // @@protoc_insertion_point(field:CheckImports.mode)
+    pub mode: ::std::option::Option<::std::string::String>,
+    ///  the most namespaces a module may import from
+    // @@protoc_insertion_point(field:CheckImports.max_namespaces)
+    pub max_namespaces: ::std::option::Option<u32>,
+    ///  the most functions a module may import from any one namespace
+    // @@protoc_insertion_point(field:CheckImports.max_per_namespace)
+    pub max_per_namespace: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckImports.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckImports {
+    fn default() -> &'a CheckImports {
+        <CheckImports as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckImports {
+    pub fn new() -> CheckImports {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckImportItems>(
+            "include",
+            |m: &CheckImports| { &m.include },
+            |m: &mut CheckImports| { &mut m.include },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckImportItems>(
+            "exclude",
+            |m: &CheckImports| { &m.exclude },
+            |m: &mut CheckImports| { &mut m.exclude },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckNamespaces>(
+            "namespace",
+            |m: &CheckImports| { &m.namespace },
+            |m: &mut CheckImports| { &mut m.namespace },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "mode",
+            |m: &CheckImports| { &m.mode },
+            |m: &mut CheckImports| { &mut m.mode },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_namespaces",
+            |m: &CheckImports| { &m.max_namespaces },
+            |m: &mut CheckImports| { &mut m.max_namespaces },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_per_namespace",
+            |m: &CheckImports| { &m.max_per_namespace },
+            |m: &mut CheckImports| { &mut m.max_per_namespace },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckImports>(
+            "CheckImports",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckImports {
+    const NAME: &'static str = "CheckImports";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.include)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.exclude)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.namespace)?;
+                },
+                34 => {
+                    self.mode = ::std::option::Option::Some(is.read_string()?);
+                },
+                40 => {
+                    self.max_namespaces = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                48 => {
+                    self.max_per_namespace = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.include.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.exclude.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.namespace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.mode.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.max_namespaces {
+            my_size += ::protobuf::rt::uint32_size(5, v);
+        }
+        if let Some(v) = self.max_per_namespace {
+            my_size += ::protobuf::rt::uint32_size(6, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.include.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.exclude.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.namespace.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.mode.as_ref() {
+            os.write_string(4, v)?;
+        }
+        if let Some(v) = self.max_namespaces {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(v) = self.max_per_namespace {
+            os.write_uint32(6, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckImports {
+        CheckImports::new()
+    }
+
+    fn clear(&mut self) {
+        self.include.clear();
+        self.exclude.clear();
+        self.namespace.clear();
+        self.mode = ::std::option::Option::None;
+        self.max_namespaces = ::std::option::Option::None;
+        self.max_per_namespace = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckImports {
+        static instance: CheckImports = CheckImports {
+            include: ::protobuf::MessageField::none(),
+            exclude: ::protobuf::MessageField::none(),
+            namespace: ::protobuf::MessageField::none(),
+            mode: ::std::option::Option::None,
+            max_namespaces: ::std::option::Option::None,
+            max_per_namespace: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckImports {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckImports").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckImports {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckImports {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckImportItems)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckImportItems {
+    // message fields
+    // @@protoc_insertion_point(field:CheckImportItems.items)
+    pub items: ::std::vec::Vec<CheckImportItem>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckImportItems.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckImportItems {
+    fn default() -> &'a CheckImportItems {
+        <CheckImportItems as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckImportItems {
+    pub fn new() -> CheckImportItems {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &CheckImportItems| { &m.items },
+            |m: &mut CheckImportItems| { &mut m.items },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckImportItems>(
+            "CheckImportItems",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckImportItems {
+    const NAME: &'static str = "CheckImportItems";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckImportItems {
+        CheckImportItems::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckImportItems {
+        static instance: CheckImportItems = CheckImportItems {
+            items: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckImportItems {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckImportItems").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckImportItems {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckImportItems {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckImportItem)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckImportItem {
+    // message fields
+    // @@protoc_insertion_point(field:CheckImportItem.namespace)
+    pub namespace: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:CheckImportItem.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:CheckImportItem.params)
+    pub params: ::protobuf::MessageField<ValTypes>,
+    // @@protoc_insertion_point(field:CheckImportItem.results)
+    pub results: ::protobuf::MessageField<ValTypes>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckImportItem.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckImportItem {
+    fn default() -> &'a CheckImportItem {
+        <CheckImportItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckImportItem {
+    pub fn new() -> CheckImportItem {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "namespace",
+            |m: &CheckImportItem| { &m.namespace },
+            |m: &mut CheckImportItem| { &mut m.namespace },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &CheckImportItem| { &m.name },
+            |m: &mut CheckImportItem| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ValTypes>(
+            "params",
+            |m: &CheckImportItem| { &m.params },
+            |m: &mut CheckImportItem| { &mut m.params },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ValTypes>(
+            "results",
+            |m: &CheckImportItem| { &m.results },
+            |m: &mut CheckImportItem| { &mut m.results },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckImportItem>(
+            "CheckImportItem",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckImportItem {
+    const NAME: &'static str = "CheckImportItem";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.namespace = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.params)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.results)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.namespace.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if let Some(v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.results.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.namespace.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if let Some(v) = self.params.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.results.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckImportItem {
+        CheckImportItem::new()
+    }
+
+    fn clear(&mut self) {
+        self.namespace = ::std::option::Option::None;
+        self.name.clear();
+        self.params.clear();
+        self.results.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckImportItem {
+        static instance: CheckImportItem = CheckImportItem {
+            namespace: ::std::option::Option::None,
+            name: ::std::string::String::new(),
+            params: ::protobuf::MessageField::none(),
+            results: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckImportItem {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckImportItem").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckImportItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckImportItem {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckNamespaces)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckNamespaces {
+    // message fields
+    // @@protoc_insertion_point(field:CheckNamespaces.include)
+    pub include: ::protobuf::MessageField<CheckNamespaceItems>,
+    // @@protoc_insertion_point(field:CheckNamespaces.exclude)
+    pub exclude: ::protobuf::MessageField<CheckNamespaceItems>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckNamespaces.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckNamespaces {
+    fn default() -> &'a CheckNamespaces {
+        <CheckNamespaces as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckNamespaces {
+    pub fn new() -> CheckNamespaces {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckNamespaceItems>(
+            "include",
+            |m: &CheckNamespaces| { &m.include },
+            |m: &mut CheckNamespaces| { &mut m.include },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckNamespaceItems>(
+            "exclude",
+            |m: &CheckNamespaces| { &m.exclude },
+            |m: &mut CheckNamespaces| { &mut m.exclude },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckNamespaces>(
+            "CheckNamespaces",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckNamespaces {
+    const NAME: &'static str = "CheckNamespaces";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.include)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.exclude)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.include.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.exclude.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.include.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.exclude.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckNamespaces {
+        CheckNamespaces::new()
+    }
+
+    fn clear(&mut self) {
+        self.include.clear();
+        self.exclude.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckNamespaces {
+        static instance: CheckNamespaces = CheckNamespaces {
+            include: ::protobuf::MessageField::none(),
+            exclude: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckNamespaces {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckNamespaces").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckNamespaces {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckNamespaces {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckNamespaceItems)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckNamespaceItems {
+    // message fields
+    // @@protoc_insertion_point(field:CheckNamespaceItems.items)
+    pub items: ::std::vec::Vec<CheckNamespaceItem>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckNamespaceItems.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckNamespaceItems {
+    fn default() -> &'a CheckNamespaceItems {
+        <CheckNamespaceItems as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckNamespaceItems {
+    pub fn new() -> CheckNamespaceItems {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &CheckNamespaceItems| { &m.items },
+            |m: &mut CheckNamespaceItems| { &mut m.items },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckNamespaceItems>(
+            "CheckNamespaceItems",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckNamespaceItems {
+    const NAME: &'static str = "CheckNamespaceItems";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckNamespaceItems {
+        CheckNamespaceItems::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckNamespaceItems {
+        static instance: CheckNamespaceItems = CheckNamespaceItems {
+            items: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckNamespaceItems {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckNamespaceItems").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckNamespaceItems {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckNamespaceItems {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckNamespaceItem)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckNamespaceItem {
+    // message fields
+    // @@protoc_insertion_point(field:CheckNamespaceItem.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:CheckNamespaceItem.functions)
+    pub functions: ::std::vec::Vec<CheckFunctionItem>,
+    // @@protoc_insertion_point(field:CheckNamespaceItem.max_functions)
+    pub max_functions: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:CheckNamespaceItem.forbid_unknown_functions)
+    pub forbid_unknown_functions: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckNamespaceItem.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckNamespaceItem {
+    fn default() -> &'a CheckNamespaceItem {
+        <CheckNamespaceItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckNamespaceItem {
+    pub fn new() -> CheckNamespaceItem {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &CheckNamespaceItem| { &m.name },
+            |m: &mut CheckNamespaceItem| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "functions",
+            |m: &CheckNamespaceItem| { &m.functions },
+            |m: &mut CheckNamespaceItem| { &mut m.functions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_functions",
+            |m: &CheckNamespaceItem| { &m.max_functions },
+            |m: &mut CheckNamespaceItem| { &mut m.max_functions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "forbid_unknown_functions",
+            |m: &CheckNamespaceItem| { &m.forbid_unknown_functions },
+            |m: &mut CheckNamespaceItem| { &mut m.forbid_unknown_functions },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckNamespaceItem>(
+            "CheckNamespaceItem",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckNamespaceItem {
+    const NAME: &'static str = "CheckNamespaceItem";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    self.functions.push(is.read_message()?);
+                },
+                24 => {
+                    self.max_functions = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                32 => {
+                    self.forbid_unknown_functions = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.functions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.max_functions {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        if let Some(v) = self.forbid_unknown_functions {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.functions {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if let Some(v) = self.max_functions {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.forbid_unknown_functions {
+            os.write_bool(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckNamespaceItem {
+        CheckNamespaceItem::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.functions.clear();
+        self.max_functions = ::std::option::Option::None;
+        self.forbid_unknown_functions = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckNamespaceItem {
+        static instance: CheckNamespaceItem = CheckNamespaceItem {
+            name: ::std::string::String::new(),
+            functions: ::std::vec::Vec::new(),
+            max_functions: ::std::option::Option::None,
+            forbid_unknown_functions: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckNamespaceItem {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckNamespaceItem").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckNamespaceItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckNamespaceItem {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckExports)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckExports {
+    // message fields
+    // @@protoc_insertion_point(field:CheckExports.include)
+    pub include: ::protobuf::MessageField<CheckFunctionItems>,
+    // @@protoc_insertion_point(field:CheckExports.exclude)
+    pub exclude: ::protobuf::MessageField<CheckFunctionItems>,
+    // @@protoc_insertion_point(field:CheckExports.max)
+    pub max: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckExports.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckExports {
+    fn default() -> &'a CheckExports {
+        <CheckExports as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckExports {
+    pub fn new() -> CheckExports {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckFunctionItems>(
+            "include",
+            |m: &CheckExports| { &m.include },
+            |m: &mut CheckExports| { &mut m.include },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, CheckFunctionItems>(
+            "exclude",
+            |m: &CheckExports| { &m.exclude },
+            |m: &mut CheckExports| { &mut m.exclude },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max",
+            |m: &CheckExports| { &m.max },
+            |m: &mut CheckExports| { &mut m.max },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckExports>(
+            "CheckExports",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckExports {
+    const NAME: &'static str = "CheckExports";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.include)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.exclude)?;
+                },
+                24 => {
+                    self.max = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.include.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.exclude.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.max {
+            my_size += ::protobuf::rt::uint32_size(3, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.include.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.exclude.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.max {
+            os.write_uint32(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckExports {
+        CheckExports::new()
+    }
+
+    fn clear(&mut self) {
+        self.include.clear();
+        self.exclude.clear();
+        self.max = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckExports {
+        static instance: CheckExports = CheckExports {
+            include: ::protobuf::MessageField::none(),
+            exclude: ::protobuf::MessageField::none(),
+            max: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckExports {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckExports").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckExports {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckExports {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckFunctionItems)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckFunctionItems {
+    // message fields
+    // @@protoc_insertion_point(field:CheckFunctionItems.items)
+    pub items: ::std::vec::Vec<CheckFunctionItem>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckFunctionItems.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckFunctionItems {
+    fn default() -> &'a CheckFunctionItems {
+        <CheckFunctionItems as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckFunctionItems {
+    pub fn new() -> CheckFunctionItems {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &CheckFunctionItems| { &m.items },
+            |m: &mut CheckFunctionItems| { &mut m.items },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckFunctionItems>(
+            "CheckFunctionItems",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckFunctionItems {
+    const NAME: &'static str = "CheckFunctionItems";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckFunctionItems {
+        CheckFunctionItems::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckFunctionItems {
+        static instance: CheckFunctionItems = CheckFunctionItems {
+            items: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckFunctionItems {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckFunctionItems").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckFunctionItems {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckFunctionItems {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckFunctionItem)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckFunctionItem {
+    // message fields
+    // @@protoc_insertion_point(field:CheckFunctionItem.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:CheckFunctionItem.params)
+    pub params: ::protobuf::MessageField<ValTypes>,
+    // @@protoc_insertion_point(field:CheckFunctionItem.results)
+    pub results: ::protobuf::MessageField<ValTypes>,
+    // @@protoc_insertion_point(field:CheckFunctionItem.hash)
+    pub hash: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckFunctionItem.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckFunctionItem {
+    fn default() -> &'a CheckFunctionItem {
+        <CheckFunctionItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckFunctionItem {
+    pub fn new() -> CheckFunctionItem {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &CheckFunctionItem| { &m.name },
+            |m: &mut CheckFunctionItem| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ValTypes>(
+            "params",
+            |m: &CheckFunctionItem| { &m.params },
+            |m: &mut CheckFunctionItem| { &mut m.params },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ValTypes>(
+            "results",
+            |m: &CheckFunctionItem| { &m.results },
+            |m: &mut CheckFunctionItem| { &mut m.results },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "hash",
+            |m: &CheckFunctionItem| { &m.hash },
+            |m: &mut CheckFunctionItem| { &mut m.hash },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckFunctionItem>(
+            "CheckFunctionItem",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckFunctionItem {
+    const NAME: &'static str = "CheckFunctionItem";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.params)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.results)?;
+                },
+                34 => {
+                    self.hash = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if let Some(v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.results.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.hash.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if let Some(v) = self.params.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.results.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.hash.as_ref() {
+            os.write_string(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckFunctionItem {
+        CheckFunctionItem::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.params.clear();
+        self.results.clear();
+        self.hash = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckFunctionItem {
+        static instance: CheckFunctionItem = CheckFunctionItem {
+            name: ::std::string::String::new(),
+            params: ::protobuf::MessageField::none(),
+            results: ::protobuf::MessageField::none(),
+            hash: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckFunctionItem {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckFunctionItem").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckFunctionItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckFunctionItem {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckSize)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckSize {
+    // message fields
+    ///  human-readable size limit, e.g. "4MB"
+    // @@protoc_insertion_point(field:CheckSize.max)
+    pub max: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckSize.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckSize {
+    fn default() -> &'a CheckSize {
+        <CheckSize as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckSize {
+    pub fn new() -> CheckSize {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max",
+            |m: &CheckSize| { &m.max },
+            |m: &mut CheckSize| { &mut m.max },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckSize>(
+            "CheckSize",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckSize {
+    const NAME: &'static str = "CheckSize";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.max = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.max.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.max.as_ref() {
+            os.write_string(1, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckSize {
+        CheckSize::new()
+    }
+
+    fn clear(&mut self) {
+        self.max = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckSize {
+        static instance: CheckSize = CheckSize {
+            max: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckSize {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckSize").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckSize {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckSize {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CheckComplexity)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckComplexity {
+    // message fields
+    // @@protoc_insertion_point(field:CheckComplexity.max_risk)
+    pub max_risk: ::std::option::Option<::protobuf::EnumOrUnknown<RiskLevel>>,
+    // @@protoc_insertion_point(field:CheckComplexity.max_score)
+    pub max_score: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckComplexity.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckComplexity {
+    fn default() -> &'a CheckComplexity {
+        <CheckComplexity as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckComplexity {
+    pub fn new() -> CheckComplexity {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_risk",
+            |m: &CheckComplexity| { &m.max_risk },
+            |m: &mut CheckComplexity| { &mut m.max_risk },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_score",
+            |m: &CheckComplexity| { &m.max_score },
+            |m: &mut CheckComplexity| { &mut m.max_score },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckComplexity>(
+            "CheckComplexity",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckComplexity {
+    const NAME: &'static str = "CheckComplexity";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.max_risk = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                16 => {
+                    self.max_score = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.max_risk {
+            my_size += ::protobuf::rt::int32_size(1, v.value());
+        }
+        if let Some(v) = self.max_score {
+            my_size += ::protobuf::rt::uint32_size(2, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.max_risk {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.max_score {
+            os.write_uint32(2, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckComplexity {
+        CheckComplexity::new()
+    }
+
+    fn clear(&mut self) {
+        self.max_risk = ::std::option::Option::None;
+        self.max_score = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckComplexity {
+        static instance: CheckComplexity = CheckComplexity {
+            max_risk: ::std::option::Option::None,
+            max_score: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckComplexity {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckComplexity").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckComplexity {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckComplexity {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  Limits on the composite risk score of a module, from 0 to 100.
+// @@protoc_insertion_point(message:CheckRisk)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckRisk {
+    // message fields
+    // @@protoc_insertion_point(field:CheckRisk.max_score)
+    pub max_score: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckRisk.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckRisk {
+    fn default() -> &'a CheckRisk {
+        <CheckRisk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckRisk {
+    pub fn new() -> CheckRisk {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_score",
+            |m: &CheckRisk| { &m.max_score },
+            |m: &mut CheckRisk| { &mut m.max_score },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckRisk>(
+            "CheckRisk",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckRisk {
+    const NAME: &'static str = "CheckRisk";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.max_score = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.max_score {
+            my_size += ::protobuf::rt::uint32_size(1, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.max_score {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckRisk {
+        CheckRisk::new()
+    }
+
+    fn clear(&mut self) {
+        self.max_score = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckRisk {
+        static instance: CheckRisk = CheckRisk {
+            max_score: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckRisk {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckRisk").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckRisk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckRisk {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The capabilities a module must not be granted by its imports, by name, e.g. "network".
+// @@protoc_insertion_point(message:CheckCapabilities)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckCapabilities {
+    // message fields
+    // @@protoc_insertion_point(field:CheckCapabilities.deny)
+    pub deny: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckCapabilities.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckCapabilities {
+    fn default() -> &'a CheckCapabilities {
+        <CheckCapabilities as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckCapabilities {
+    pub fn new() -> CheckCapabilities {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "deny",
+            |m: &CheckCapabilities| { &m.deny },
+            |m: &mut CheckCapabilities| { &mut m.deny },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckCapabilities>(
+            "CheckCapabilities",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckCapabilities {
+    const NAME: &'static str = "CheckCapabilities";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.deny.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.deny {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.deny {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckCapabilities {
+        CheckCapabilities::new()
+    }
+
+    fn clear(&mut self) {
+        self.deny.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckCapabilities {
+        static instance: CheckCapabilities = CheckCapabilities {
+            deny: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckCapabilities {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckCapabilities").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckCapabilities {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckCapabilities {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  SPDX identifiers of licenses which must not be found in a module, e.g. "GPL-3.0".
+// @@protoc_insertion_point(message:CheckLicenses)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckLicenses {
+    // message fields
+    // @@protoc_insertion_point(field:CheckLicenses.deny)
+    pub deny: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckLicenses.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckLicenses {
+    fn default() -> &'a CheckLicenses {
+        <CheckLicenses as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckLicenses {
+    pub fn new() -> CheckLicenses {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "deny",
+            |m: &CheckLicenses| { &m.deny },
+            |m: &mut CheckLicenses| { &mut m.deny },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckLicenses>(
+            "CheckLicenses",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckLicenses {
+    const NAME: &'static str = "CheckLicenses";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.deny.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.deny {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.deny {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckLicenses {
+        CheckLicenses::new()
+    }
+
+    fn clear(&mut self) {
+        self.deny.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckLicenses {
+        static instance: CheckLicenses = CheckLicenses {
+            deny: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckLicenses {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckLicenses").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckLicenses {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckLicenses {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The most severe known vulnerability allowed in the libraries found in a module.
+// @@protoc_insertion_point(message:CheckVulnerabilities)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckVulnerabilities {
+    // message fields
+    ///  "low", "medium", "high" or "critical"
+    // @@protoc_insertion_point(field:CheckVulnerabilities.max_severity)
+    pub max_severity: ::std::option::Option<::std::string::String>,
+    ///  "osv", or a path to a local OSV database
+    // @@protoc_insertion_point(field:CheckVulnerabilities.db)
+    pub db: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckVulnerabilities.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckVulnerabilities {
+    fn default() -> &'a CheckVulnerabilities {
+        <CheckVulnerabilities as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckVulnerabilities {
+    pub fn new() -> CheckVulnerabilities {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_severity",
+            |m: &CheckVulnerabilities| { &m.max_severity },
+            |m: &mut CheckVulnerabilities| { &mut m.max_severity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "db",
+            |m: &CheckVulnerabilities| { &m.db },
+            |m: &mut CheckVulnerabilities| { &mut m.db },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckVulnerabilities>(
+            "CheckVulnerabilities",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckVulnerabilities {
+    const NAME: &'static str = "CheckVulnerabilities";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.max_severity = ::std::option::Option::Some(is.read_string()?);
+                },
+                18 => {
+                    self.db = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.max_severity.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.db.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.max_severity.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.db.as_ref() {
+            os.write_string(2, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckVulnerabilities {
+        CheckVulnerabilities::new()
+    }
+
+    fn clear(&mut self) {
+        self.max_severity = ::std::option::Option::None;
+        self.db = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckVulnerabilities {
+        static instance: CheckVulnerabilities = CheckVulnerabilities {
+            max_severity: ::std::option::Option::None,
+            db: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckVulnerabilities {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckVulnerabilities").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckVulnerabilities {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckVulnerabilities {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  Whether a module may import the same function with different signatures, export more than one
+///  function with the same name, or have structural problems.
+// @@protoc_insertion_point(message:CheckIntegrity)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckIntegrity {
+    // message fields
+    // @@protoc_insertion_point(field:CheckIntegrity.allow_duplicate_imports)
+    pub allow_duplicate_imports: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:CheckIntegrity.allow_duplicate_exports)
+    pub allow_duplicate_exports: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:CheckIntegrity.allow_malformed)
+    pub allow_malformed: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckIntegrity.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckIntegrity {
+    fn default() -> &'a CheckIntegrity {
+        <CheckIntegrity as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckIntegrity {
+    pub fn new() -> CheckIntegrity {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "allow_duplicate_imports",
+            |m: &CheckIntegrity| { &m.allow_duplicate_imports },
+            |m: &mut CheckIntegrity| { &mut m.allow_duplicate_imports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "allow_duplicate_exports",
+            |m: &CheckIntegrity| { &m.allow_duplicate_exports },
+            |m: &mut CheckIntegrity| { &mut m.allow_duplicate_exports },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "allow_malformed",
+            |m: &CheckIntegrity| { &m.allow_malformed },
+            |m: &mut CheckIntegrity| { &mut m.allow_malformed },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckIntegrity>(
+            "CheckIntegrity",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckIntegrity {
+    const NAME: &'static str = "CheckIntegrity";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.allow_duplicate_imports = ::std::option::Option::Some(is.read_bool()?);
+                },
+                16 => {
+                    self.allow_duplicate_exports = ::std::option::Option::Some(is.read_bool()?);
+                },
+                24 => {
+                    self.allow_malformed = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.allow_duplicate_imports {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.allow_duplicate_exports {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.allow_malformed {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.allow_duplicate_imports {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.allow_duplicate_exports {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.allow_malformed {
+            os.write_bool(3, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckIntegrity {
+        CheckIntegrity::new()
+    }
+
+    fn clear(&mut self) {
+        self.allow_duplicate_imports = ::std::option::Option::None;
+        self.allow_duplicate_exports = ::std::option::Option::None;
+        self.allow_malformed = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckIntegrity {
+        static instance: CheckIntegrity = CheckIntegrity {
+            allow_duplicate_imports: ::std::option::Option::None,
+            allow_duplicate_exports: ::std::option::Option::None,
+            allow_malformed: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckIntegrity {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckIntegrity").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckIntegrity {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckIntegrity {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  A regular expression which the whole file name of a module's location must match.
+// @@protoc_insertion_point(message:CheckModuleName)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckModuleName {
+    // message fields
+    // @@protoc_insertion_point(field:CheckModuleName.pattern)
+    pub pattern: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckModuleName.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckModuleName {
+    fn default() -> &'a CheckModuleName {
+        <CheckModuleName as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckModuleName {
+    pub fn new() -> CheckModuleName {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "pattern",
+            |m: &CheckModuleName| { &m.pattern },
+            |m: &mut CheckModuleName| { &mut m.pattern },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckModuleName>(
+            "CheckModuleName",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckModuleName {
+    const NAME: &'static str = "CheckModuleName";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.pattern = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.pattern.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.pattern.as_ref() {
+            os.write_string(1, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckModuleName {
+        CheckModuleName::new()
+    }
+
+    fn clear(&mut self) {
+        self.pattern = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckModuleName {
+        static instance: CheckModuleName = CheckModuleName {
+            pattern: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckModuleName {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckModuleName").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckModuleName {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckModuleName {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The URL schemes a module's location may use, e.g. "s3" or "oci".
+// @@protoc_insertion_point(message:CheckLocation)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CheckLocation {
+    // message fields
+    // @@protoc_insertion_point(field:CheckLocation.allowed_schemes)
+    pub allowed_schemes: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:CheckLocation.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CheckLocation {
+    fn default() -> &'a CheckLocation {
+        <CheckLocation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CheckLocation {
+    pub fn new() -> CheckLocation {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "allowed_schemes",
+            |m: &CheckLocation| { &m.allowed_schemes },
+            |m: &mut CheckLocation| { &mut m.allowed_schemes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CheckLocation>(
+            "CheckLocation",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CheckLocation {
+    const NAME: &'static str = "CheckLocation";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.allowed_schemes.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.allowed_schemes {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.allowed_schemes {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CheckLocation {
+        CheckLocation::new()
+    }
+
+    fn clear(&mut self) {
+        self.allowed_schemes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CheckLocation {
+        static instance: CheckLocation = CheckLocation {
+            allowed_schemes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CheckLocation {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CheckLocation").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CheckLocation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CheckLocation {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `POST /api/v1/audit:`
+///  Return a list of modules which match the outcome requirements using the provided checkfile.
+// @@protoc_insertion_point(message:AuditModulesRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AuditModulesRequest {
+    // message fields
+    ///  the YAML checkfile (e.g. mod.yaml) bytes
+    // @@protoc_insertion_point(field:AuditModulesRequest.checkfile)
+    pub checkfile: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:AuditModulesRequest.outcome)
+    pub outcome: ::protobuf::EnumOrUnknown<AuditOutcome>,
+    // @@protoc_insertion_point(field:AuditModulesRequest.pagination)
+    pub pagination: ::protobuf::MessageField<Pagination>,
+    ///  the typed checkfile, which takes precedence over `checkfile` when set
+    // @@protoc_insertion_point(field:AuditModulesRequest.validation)
+    pub validation: ::protobuf::MessageField<Checkfile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:AuditModulesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AuditModulesRequest {
+    fn default() -> &'a AuditModulesRequest {
+        <AuditModulesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuditModulesRequest {
+    pub fn new() -> AuditModulesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "checkfile",
+            |m: &AuditModulesRequest| { &m.checkfile },
+            |m: &mut AuditModulesRequest| { &mut m.checkfile },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "outcome",
+            |m: &AuditModulesRequest| { &m.outcome },
+            |m: &mut AuditModulesRequest| { &mut m.outcome },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
+            "pagination",
+            |m: &AuditModulesRequest| { &m.pagination },
+            |m: &mut AuditModulesRequest| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Checkfile>(
+            "validation",
+            |m: &AuditModulesRequest| { &m.validation },
+            |m: &mut AuditModulesRequest| { &mut m.validation },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AuditModulesRequest>(
+            "AuditModulesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AuditModulesRequest {
+    const NAME: &'static str = "AuditModulesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.checkfile = is.read_bytes()?;
+                },
+                16 => {
+                    self.outcome = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.validation)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.checkfile.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.checkfile);
+        }
+        if self.outcome != ::protobuf::EnumOrUnknown::new(AuditOutcome::PASS) {
+            my_size += ::protobuf::rt::int32_size(2, self.outcome.value());
+        }
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.validation.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.checkfile.is_empty() {
+            os.write_bytes(1, &self.checkfile)?;
+        }
+        if self.outcome != ::protobuf::EnumOrUnknown::new(AuditOutcome::PASS) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.outcome))?;
+        }
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.validation.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AuditModulesRequest {
+        AuditModulesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.checkfile.clear();
+        self.outcome = ::protobuf::EnumOrUnknown::new(AuditOutcome::PASS);
+        self.pagination.clear();
+        self.validation.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AuditModulesRequest {
+        static instance: AuditModulesRequest = AuditModulesRequest {
+            checkfile: ::std::vec::Vec::new(),
+            outcome: ::protobuf::EnumOrUnknown::from_i32(0),
+            pagination: ::protobuf::MessageField::none(),
+            validation: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AuditModulesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AuditModulesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AuditModulesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuditModulesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to a `AuditModulesRequest`.
+// @@protoc_insertion_point(message:AuditModulesResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AuditModulesResponse {
+    // message fields
+    ///  each record contains the ID of the invalid Module which failed the audit, as well as the failure 
+    ///  report produced by the validation check (encoded in JSON)
+    // @@protoc_insertion_point(field:AuditModulesResponse.invalid_module_report)
+    pub invalid_module_report: ::std::collections::HashMap<i64, ::std::vec::Vec<u8>>,
+    // @@protoc_insertion_point(field:AuditModulesResponse.pagination)
+    pub pagination: ::protobuf::MessageField<Pagination>,
+    ///  the full count of results in the database (not the count of this message's
+    ///  `modules`).
+    // @@protoc_insertion_point(field:AuditModulesResponse.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:AuditModulesResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    ///  the SHA-256 hash of each module included in `invalid_module_report`, keyed by module ID
+    // @@protoc_insertion_point(field:AuditModulesResponse.module_id_hash)
+    pub module_id_hash: ::std::collections::HashMap<i64, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:AuditModulesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AuditModulesResponse {
+    fn default() -> &'a AuditModulesResponse {
+        <AuditModulesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AuditModulesResponse {
+    pub fn new() -> AuditModulesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "invalid_module_report",
+            |m: &AuditModulesResponse| { &m.invalid_module_report },
+            |m: &mut AuditModulesResponse| { &mut m.invalid_module_report },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
+            "pagination",
+            |m: &AuditModulesResponse| { &m.pagination },
+            |m: &mut AuditModulesResponse| { &mut m.pagination },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &AuditModulesResponse| { &m.total },
+            |m: &mut AuditModulesResponse| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &AuditModulesResponse| { &m.error },
+            |m: &mut AuditModulesResponse| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "module_id_hash",
+            |m: &AuditModulesResponse| { &m.module_id_hash },
+            |m: &mut AuditModulesResponse| { &mut m.module_id_hash },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AuditModulesResponse>(
+            "AuditModulesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AuditModulesResponse {
+    const NAME: &'static str = "AuditModulesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
                         match tag {
-                            10 => key = is.read_string()?,
-                            18 => value = is.read_string()?,
+                            8 => key = is.read_int64()?,
+                            18 => value = is.read_bytes()?,
                             _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
                         };
                     }
                     is.pop_limit(old_limit);
-                    self.metadata.insert(key, value);
+                    self.invalid_module_report.insert(key, value);
                 },
-                90 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.inserted_before)?;
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
                 },
-                98 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.inserted_after)?;
+                24 => {
+                    self.total = is.read_uint64()?;
                 },
-                106 => {
-                    self.strings.push(is.read_string()?);
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
-                114 => {
-                    self.function_name = ::std::option::Option::Some(is.read_string()?);
+                42 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            8 => key = is.read_int64()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.module_id_hash.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for (k, v) in &self.invalid_module_report {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::int64_size(1, *k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if let Some(v) = self.pagination.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.total);
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for (k, v) in &self.module_id_hash {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::int64_size(1, *k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for (k, v) in &self.invalid_module_report {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::int64_size(1, *k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            os.write_raw_varint32(10)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_int64(1, *k)?;
+            os.write_bytes(2, &v)?;
+        };
+        if let Some(v) = self.pagination.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(3, self.total)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        for (k, v) in &self.module_id_hash {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::int64_size(1, *k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(42)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_int64(1, *k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AuditModulesResponse {
+        AuditModulesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.invalid_module_report.clear();
+        self.pagination.clear();
+        self.total = 0;
+        self.error.clear();
+        self.module_id_hash.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AuditModulesResponse {
+        static instance: ::protobuf::rt::Lazy<AuditModulesResponse> = ::protobuf::rt::Lazy::new();
+        instance.get(AuditModulesResponse::new)
+    }
+}
+
+impl ::protobuf::MessageFull for AuditModulesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AuditModulesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AuditModulesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AuditModulesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  `POST /api/v1/diff:`
+///  Return the diff of two modules
+// @@protoc_insertion_point(message:DiffRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DiffRequest {
+    // message fields
+    // @@protoc_insertion_point(field:DiffRequest.module1)
+    pub module1: i64,
+    // @@protoc_insertion_point(field:DiffRequest.module2)
+    pub module2: i64,
+    // @@protoc_insertion_point(field:DiffRequest.color_terminal)
+    pub color_terminal: bool,
+    // @@protoc_insertion_point(field:DiffRequest.with_context)
+    pub with_context: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:DiffRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DiffRequest {
+    fn default() -> &'a DiffRequest {
+        <DiffRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DiffRequest {
+    pub fn new() -> DiffRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module1",
+            |m: &DiffRequest| { &m.module1 },
+            |m: &mut DiffRequest| { &mut m.module1 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module2",
+            |m: &DiffRequest| { &m.module2 },
+            |m: &mut DiffRequest| { &mut m.module2 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "color_terminal",
+            |m: &DiffRequest| { &m.color_terminal },
+            |m: &mut DiffRequest| { &mut m.color_terminal },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "with_context",
+            |m: &DiffRequest| { &m.with_context },
+            |m: &mut DiffRequest| { &mut m.with_context },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DiffRequest>(
+            "DiffRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DiffRequest {
+    const NAME: &'static str = "DiffRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.module1 = is.read_int64()?;
                 },
-                122 => {
-                    self.module_name = ::std::option::Option::Some(is.read_string()?);
+                16 => {
+                    self.module2 = is.read_int64()?;
                 },
-                130 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                24 => {
+                    self.color_terminal = is.read_bool()?;
                 },
-                138 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
+                32 => {
+                    self.with_context = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2606,62 +9519,17 @@ impl ::protobuf::Message for SearchModulesRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.id {
-            my_size += ::protobuf::rt::int64_size(1, v);
-        }
-        if let Some(v) = self.hash.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
-        }
-        for value in &self.imports {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        for value in &self.exports {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        if let Some(v) = self.min_size {
-            my_size += ::protobuf::rt::uint64_size(6, v);
-        }
-        if let Some(v) = self.max_size {
-            my_size += ::protobuf::rt::uint64_size(7, v);
-        }
-        if let Some(v) = self.location.as_ref() {
-            my_size += ::protobuf::rt::string_size(8, &v);
-        }
-        if let Some(v) = self.source_language {
-            my_size += ::protobuf::rt::int32_size(9, v.value());
-        }
-        for (k, v) in &self.metadata {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
-        };
-        if let Some(v) = self.inserted_before.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if let Some(v) = self.inserted_after.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        for value in &self.strings {
-            my_size += ::protobuf::rt::string_size(13, &value);
-        };
-        if let Some(v) = self.function_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(14, &v);
+        if self.module1 != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.module1);
         }
-        if let Some(v) = self.module_name.as_ref() {
-            my_size += ::protobuf::rt::string_size(15, &v);
+        if self.module2 != 0 {
+            my_size += ::protobuf::rt::int64_size(2, self.module2);
         }
-        if let Some(v) = self.pagination.as_ref() {
-            let len = v.compute_size();
-            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.color_terminal != false {
+            my_size += 1 + 1;
         }
-        if let Some(v) = self.sort.as_ref() {
-            let len = v.compute_size();
-            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.with_context != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2669,59 +9537,17 @@ impl ::protobuf::Message for SearchModulesRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.id {
-            os.write_int64(1, v)?;
-        }
-        if let Some(v) = self.hash.as_ref() {
-            os.write_string(3, v)?;
-        }
-        for v in &self.imports {
-            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
-        };
-        for v in &self.exports {
-            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
-        };
-        if let Some(v) = self.min_size {
-            os.write_uint64(6, v)?;
-        }
-        if let Some(v) = self.max_size {
-            os.write_uint64(7, v)?;
-        }
-        if let Some(v) = self.location.as_ref() {
-            os.write_string(8, v)?;
-        }
-        if let Some(v) = self.source_language {
-            os.write_enum(9, ::protobuf::EnumOrUnknown::value(&v))?;
-        }
-        for (k, v) in &self.metadata {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            os.write_raw_varint32(82)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_string(1, &k)?;
-            os.write_string(2, &v)?;
-        };
-        if let Some(v) = self.inserted_before.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
-        }
-        if let Some(v) = self.inserted_after.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
-        }
-        for v in &self.strings {
-            os.write_string(13, &v)?;
-        };
-        if let Some(v) = self.function_name.as_ref() {
-            os.write_string(14, v)?;
+        if self.module1 != 0 {
+            os.write_int64(1, self.module1)?;
         }
-        if let Some(v) = self.module_name.as_ref() {
-            os.write_string(15, v)?;
+        if self.module2 != 0 {
+            os.write_int64(2, self.module2)?;
         }
-        if let Some(v) = self.pagination.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(16, v, os)?;
+        if self.color_terminal != false {
+            os.write_bool(3, self.color_terminal)?;
         }
-        if let Some(v) = self.sort.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(17, v, os)?;
+        if self.with_context != false {
+            os.write_bool(4, self.with_context)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2735,124 +9561,96 @@ impl ::protobuf::Message for SearchModulesRequest {
         &mut self.special_fields
     }
 
-    fn new() -> SearchModulesRequest {
-        SearchModulesRequest::new()
+    fn new() -> DiffRequest {
+        DiffRequest::new()
     }
 
     fn clear(&mut self) {
-        self.id = ::std::option::Option::None;
-        self.hash = ::std::option::Option::None;
-        self.imports.clear();
-        self.exports.clear();
-        self.min_size = ::std::option::Option::None;
-        self.max_size = ::std::option::Option::None;
-        self.location = ::std::option::Option::None;
-        self.source_language = ::std::option::Option::None;
-        self.metadata.clear();
-        self.inserted_before.clear();
-        self.inserted_after.clear();
-        self.strings.clear();
-        self.function_name = ::std::option::Option::None;
-        self.module_name = ::std::option::Option::None;
-        self.pagination.clear();
-        self.sort.clear();
+        self.module1 = 0;
+        self.module2 = 0;
+        self.color_terminal = false;
+        self.with_context = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SearchModulesRequest {
-        static instance: ::protobuf::rt::Lazy<SearchModulesRequest> = ::protobuf::rt::Lazy::new();
-        instance.get(SearchModulesRequest::new)
+    fn default_instance() -> &'static DiffRequest {
+        static instance: DiffRequest = DiffRequest {
+            module1: 0,
+            module2: 0,
+            color_terminal: false,
+            with_context: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for SearchModulesRequest {
+impl ::protobuf::MessageFull for DiffRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SearchModulesRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DiffRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SearchModulesRequest {
+impl ::std::fmt::Display for DiffRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SearchModulesRequest {
-    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
-}
-
-///  The message returned in response to a `SearchModulesRequest`.
-// @@protoc_insertion_point(message:SearchModulesResponse)
-#[derive(PartialEq,Clone,Default,Debug)]
-pub struct SearchModulesResponse {
-    // message fields
-    // @@protoc_insertion_point(field:SearchModulesResponse.modules)
-    pub modules: ::std::vec::Vec<Module>,
-    // @@protoc_insertion_point(field:SearchModulesResponse.pagination)
-    pub pagination: ::protobuf::MessageField<Pagination>,
-    ///  the full count of results in the database (not the count of this message's
-    ///  `modules`).
-    // @@protoc_insertion_point(field:SearchModulesResponse.total)
-    pub total: u64,
-    // @@protoc_insertion_point(field:SearchModulesResponse.sort)
-    pub sort: ::protobuf::MessageField<Sort>,
-    // @@protoc_insertion_point(field:SearchModulesResponse.error)
+impl ::protobuf::reflect::ProtobufValue for DiffRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+///  The message returned in response to `DiffRequest`, contains a text representation of the difference
+///  between the two specified modules.
+// @@protoc_insertion_point(message:DiffResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DiffResponse {
+    // message fields
+    // @@protoc_insertion_point(field:DiffResponse.diff)
+    pub diff: ::std::string::String,
+    // @@protoc_insertion_point(field:DiffResponse.error)
     pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:SearchModulesResponse.special_fields)
+    // @@protoc_insertion_point(special_field:DiffResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SearchModulesResponse {
-    fn default() -> &'a SearchModulesResponse {
-        <SearchModulesResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DiffResponse {
+    fn default() -> &'a DiffResponse {
+        <DiffResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SearchModulesResponse {
-    pub fn new() -> SearchModulesResponse {
+impl DiffResponse {
+    pub fn new() -> DiffResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "modules",
-            |m: &SearchModulesResponse| { &m.modules },
-            |m: &mut SearchModulesResponse| { &mut m.modules },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
-            "pagination",
-            |m: &SearchModulesResponse| { &m.pagination },
-            |m: &mut SearchModulesResponse| { &mut m.pagination },
-        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "total",
-            |m: &SearchModulesResponse| { &m.total },
-            |m: &mut SearchModulesResponse| { &mut m.total },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Sort>(
-            "sort",
-            |m: &SearchModulesResponse| { &m.sort },
-            |m: &mut SearchModulesResponse| { &mut m.sort },
+            "diff",
+            |m: &DiffResponse| { &m.diff },
+            |m: &mut DiffResponse| { &mut m.diff },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
             "error",
-            |m: &SearchModulesResponse| { &m.error },
-            |m: &mut SearchModulesResponse| { &mut m.error },
+            |m: &DiffResponse| { &m.error },
+            |m: &mut DiffResponse| { &mut m.error },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SearchModulesResponse>(
-            "SearchModulesResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DiffResponse>(
+            "DiffResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SearchModulesResponse {
-    const NAME: &'static str = "SearchModulesResponse";
+impl ::protobuf::Message for DiffResponse {
+    const NAME: &'static str = "DiffResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2862,18 +9660,9 @@ impl ::protobuf::Message for SearchModulesResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.modules.push(is.read_message()?);
+                    self.diff = is.read_string()?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
-                },
-                24 => {
-                    self.total = is.read_uint64()?;
-                },
-                34 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort)?;
-                },
-                42 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
@@ -2888,20 +9677,8 @@ impl ::protobuf::Message for SearchModulesResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.modules {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
-        if let Some(v) = self.pagination.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if self.total != 0 {
-            my_size += ::protobuf::rt::uint64_size(3, self.total);
-        }
-        if let Some(v) = self.sort.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.diff.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.diff);
         }
         if let Some(v) = self.error.as_ref() {
             let len = v.compute_size();
@@ -2913,20 +9690,11 @@ impl ::protobuf::Message for SearchModulesResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.modules {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
-        if let Some(v) = self.pagination.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
-        if self.total != 0 {
-            os.write_uint64(3, self.total)?;
-        }
-        if let Some(v) = self.sort.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        if !self.diff.is_empty() {
+            os.write_string(1, &self.diff)?;
         }
         if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2940,25 +9708,19 @@ impl ::protobuf::Message for SearchModulesResponse {
         &mut self.special_fields
     }
 
-    fn new() -> SearchModulesResponse {
-        SearchModulesResponse::new()
+    fn new() -> DiffResponse {
+        DiffResponse::new()
     }
 
     fn clear(&mut self) {
-        self.modules.clear();
-        self.pagination.clear();
-        self.total = 0;
-        self.sort.clear();
+        self.diff.clear();
         self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SearchModulesResponse {
-        static instance: SearchModulesResponse = SearchModulesResponse {
-            modules: ::std::vec::Vec::new(),
-            pagination: ::protobuf::MessageField::none(),
-            total: 0,
-            sort: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static DiffResponse {
+        static instance: DiffResponse = DiffResponse {
+            diff: ::std::string::String::new(),
             error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -2966,65 +9728,163 @@ impl ::protobuf::Message for SearchModulesResponse {
     }
 }
 
-impl ::protobuf::MessageFull for SearchModulesResponse {
+impl ::protobuf::MessageFull for DiffResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SearchModulesResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DiffResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SearchModulesResponse {
+impl ::std::fmt::Display for DiffResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SearchModulesResponse {
+impl ::protobuf::reflect::ProtobufValue for DiffResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `DELETE /api/v1/module:`
-///  Remove a module from the database by its ID. Return the module IDs & hashes.
-// @@protoc_insertion_point(message:DeleteModulesRequest)
+///  `POST /api/v1/validate:`
+///  Return the failure report (if applicable) of a wasm module validation against a given checkfile.
+// @@protoc_insertion_point(message:ValidateModuleRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DeleteModulesRequest {
+pub struct ValidateModuleRequest {
     // message fields
-    // @@protoc_insertion_point(field:DeleteModulesRequest.module_ids)
-    pub module_ids: ::std::vec::Vec<i64>,
+    ///  the YAML checkfile (e.g. mod.yaml) bytes
+    // @@protoc_insertion_point(field:ValidateModuleRequest.checkfile)
+    pub checkfile: ::std::vec::Vec<u8>,
+    ///  the typed checkfile, which takes precedence over `checkfile` when set
+    // @@protoc_insertion_point(field:ValidateModuleRequest.validation)
+    pub validation: ::protobuf::MessageField<Checkfile>,
+    // message oneof groups
+    pub module_input: ::std::option::Option<validate_module_request::Module_input>,
     // special fields
-    // @@protoc_insertion_point(special_field:DeleteModulesRequest.special_fields)
+    // @@protoc_insertion_point(special_field:ValidateModuleRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteModulesRequest {
-    fn default() -> &'a DeleteModulesRequest {
-        <DeleteModulesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ValidateModuleRequest {
+    fn default() -> &'a ValidateModuleRequest {
+        <ValidateModuleRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteModulesRequest {
-    pub fn new() -> DeleteModulesRequest {
+impl ValidateModuleRequest {
+    pub fn new() -> ValidateModuleRequest {
         ::std::default::Default::default()
     }
 
+    // bytes module = 2;
+
+    pub fn module(&self) -> &[u8] {
+        match self.module_input {
+            ::std::option::Option::Some(validate_module_request::Module_input::Module(ref v)) => v,
+            _ => &[],
+        }
+    }
+
+    pub fn clear_module(&mut self) {
+        self.module_input = ::std::option::Option::None;
+    }
+
+    pub fn has_module(&self) -> bool {
+        match self.module_input {
+            ::std::option::Option::Some(validate_module_request::Module_input::Module(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_module(&mut self, v: ::std::vec::Vec<u8>) {
+        self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::Module(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_module(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(validate_module_request::Module_input::Module(_)) = self.module_input {
+        } else {
+            self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::Module(::std::vec::Vec::new()));
+        }
+        match self.module_input {
+            ::std::option::Option::Some(validate_module_request::Module_input::Module(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_module(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_module() {
+            match self.module_input.take() {
+                ::std::option::Option::Some(validate_module_request::Module_input::Module(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    // int64 module_id = 3;
+
+    pub fn module_id(&self) -> i64 {
+        match self.module_input {
+            ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(v)) => v,
+            _ => 0,
+        }
+    }
+
+    pub fn clear_module_id(&mut self) {
+        self.module_input = ::std::option::Option::None;
+    }
+
+    pub fn has_module_id(&self) -> bool {
+        match self.module_input {
+            ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_module_id(&mut self, v: i64) {
+        self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(v))
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
-        let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "module_ids",
-            |m: &DeleteModulesRequest| { &m.module_ids },
-            |m: &mut DeleteModulesRequest| { &mut m.module_ids },
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "checkfile",
+            |m: &ValidateModuleRequest| { &m.checkfile },
+            |m: &mut ValidateModuleRequest| { &mut m.checkfile },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteModulesRequest>(
-            "DeleteModulesRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "module",
+            ValidateModuleRequest::has_module,
+            ValidateModuleRequest::module,
+            ValidateModuleRequest::set_module,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "module_id",
+            ValidateModuleRequest::has_module_id,
+            ValidateModuleRequest::module_id,
+            ValidateModuleRequest::set_module_id,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Checkfile>(
+            "validation",
+            |m: &ValidateModuleRequest| { &m.validation },
+            |m: &mut ValidateModuleRequest| { &mut m.validation },
+        ));
+        oneofs.push(validate_module_request::Module_input::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ValidateModuleRequest>(
+            "ValidateModuleRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DeleteModulesRequest {
-    const NAME: &'static str = "DeleteModulesRequest";
+impl ::protobuf::Message for ValidateModuleRequest {
+    const NAME: &'static str = "ValidateModuleRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3034,10 +9894,16 @@ impl ::protobuf::Message for DeleteModulesRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    is.read_repeated_packed_int64_into(&mut self.module_ids)?;
+                    self.checkfile = is.read_bytes()?;
                 },
-                8 => {
-                    self.module_ids.push(is.read_int64()?);
+                18 => {
+                    self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::Module(is.read_bytes()?));
+                },
+                24 => {
+                    self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(is.read_int64()?));
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.validation)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3051,18 +9917,45 @@ impl ::protobuf::Message for DeleteModulesRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.module_ids {
-            my_size += ::protobuf::rt::int64_size(1, *value);
-        };
+        if !self.checkfile.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.checkfile);
+        }
+        if let Some(v) = self.validation.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.module_input {
+            match v {
+                &validate_module_request::Module_input::Module(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(2, &v);
+                },
+                &validate_module_request::Module_input::ModuleId(v) => {
+                    my_size += ::protobuf::rt::int64_size(3, v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.module_ids {
-            os.write_int64(1, *v)?;
-        };
+        if !self.checkfile.is_empty() {
+            os.write_bytes(1, &self.checkfile)?;
+        }
+        if let Some(v) = self.validation.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.module_input {
+            match v {
+                &validate_module_request::Module_input::Module(ref v) => {
+                    os.write_bytes(2, v)?;
+                },
+                &validate_module_request::Module_input::ModuleId(v) => {
+                    os.write_int64(3, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3075,89 +9968,124 @@ impl ::protobuf::Message for DeleteModulesRequest {
         &mut self.special_fields
     }
 
-    fn new() -> DeleteModulesRequest {
-        DeleteModulesRequest::new()
+    fn new() -> ValidateModuleRequest {
+        ValidateModuleRequest::new()
     }
 
     fn clear(&mut self) {
-        self.module_ids.clear();
+        self.checkfile.clear();
+        self.module_input = ::std::option::Option::None;
+        self.module_input = ::std::option::Option::None;
+        self.validation.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DeleteModulesRequest {
-        static instance: DeleteModulesRequest = DeleteModulesRequest {
-            module_ids: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static ValidateModuleRequest {
+        static instance: ValidateModuleRequest = ValidateModuleRequest {
+            checkfile: ::std::vec::Vec::new(),
+            validation: ::protobuf::MessageField::none(),
+            module_input: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for DeleteModulesRequest {
+impl ::protobuf::MessageFull for ValidateModuleRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteModulesRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ValidateModuleRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DeleteModulesRequest {
+impl ::std::fmt::Display for ValidateModuleRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteModulesRequest {
+impl ::protobuf::reflect::ProtobufValue for ValidateModuleRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to a `DeleteModulesRequest`.
-// @@protoc_insertion_point(message:DeleteModulesResponse)
+/// Nested message and enums of message `ValidateModuleRequest`
+pub mod validate_module_request {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:ValidateModuleRequest.module_input)
+    pub enum Module_input {
+        // @@protoc_insertion_point(oneof_field:ValidateModuleRequest.module)
+        Module(::std::vec::Vec<u8>),
+        // @@protoc_insertion_point(oneof_field:ValidateModuleRequest.module_id)
+        ModuleId(i64),
+    }
+
+    impl ::protobuf::Oneof for Module_input {
+    }
+
+    impl ::protobuf::OneofFull for Module_input {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::ValidateModuleRequest as ::protobuf::MessageFull>::descriptor().oneof_by_name("module_input").unwrap()).clone()
+        }
+    }
+
+    impl Module_input {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Module_input>("module_input")
+        }
+    }
+}
+
+///  The failure report produced by the validation check (encoded in JSON).
+// @@protoc_insertion_point(message:ValidateModuleResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DeleteModulesResponse {
+pub struct ValidateModuleResponse {
     // message fields
-    // @@protoc_insertion_point(field:DeleteModulesResponse.module_id_hash)
-    pub module_id_hash: ::std::collections::HashMap<i64, ::std::string::String>,
-    // @@protoc_insertion_point(field:DeleteModulesResponse.error)
+    // @@protoc_insertion_point(field:ValidateModuleResponse.invalid_module_report)
+    pub invalid_module_report: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:ValidateModuleResponse.error)
     pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:DeleteModulesResponse.special_fields)
+    // @@protoc_insertion_point(special_field:ValidateModuleResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteModulesResponse {
-    fn default() -> &'a DeleteModulesResponse {
-        <DeleteModulesResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ValidateModuleResponse {
+    fn default() -> &'a ValidateModuleResponse {
+        <ValidateModuleResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteModulesResponse {
-    pub fn new() -> DeleteModulesResponse {
+impl ValidateModuleResponse {
+    pub fn new() -> ValidateModuleResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
-            "module_id_hash",
-            |m: &DeleteModulesResponse| { &m.module_id_hash },
-            |m: &mut DeleteModulesResponse| { &mut m.module_id_hash },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "invalid_module_report",
+            |m: &ValidateModuleResponse| { &m.invalid_module_report },
+            |m: &mut ValidateModuleResponse| { &mut m.invalid_module_report },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
             "error",
-            |m: &DeleteModulesResponse| { &m.error },
-            |m: &mut DeleteModulesResponse| { &mut m.error },
+            |m: &ValidateModuleResponse| { &m.error },
+            |m: &mut ValidateModuleResponse| { &mut m.error },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteModulesResponse>(
-            "DeleteModulesResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ValidateModuleResponse>(
+            "ValidateModuleResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DeleteModulesResponse {
-    const NAME: &'static str = "DeleteModulesResponse";
+impl ::protobuf::Message for ValidateModuleResponse {
+    const NAME: &'static str = "ValidateModuleResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3167,19 +10095,7 @@ impl ::protobuf::Message for DeleteModulesResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
-                        match tag {
-                            8 => key = is.read_int64()?,
-                            18 => value = is.read_string()?,
-                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
-                        };
-                    }
-                    is.pop_limit(old_limit);
-                    self.module_id_hash.insert(key, value);
+                    self.invalid_module_report = is.read_bytes()?;
                 },
                 18 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
@@ -3196,12 +10112,9 @@ impl ::protobuf::Message for DeleteModulesResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for (k, v) in &self.module_id_hash {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::int64_size(1, *k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
-        };
+        if !self.invalid_module_report.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.invalid_module_report);
+        }
         if let Some(v) = self.error.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
@@ -3212,15 +10125,9 @@ impl ::protobuf::Message for DeleteModulesResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for (k, v) in &self.module_id_hash {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::int64_size(1, *k);
-            entry_size += ::protobuf::rt::string_size(2, &v);
-            os.write_raw_varint32(10)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_int64(1, *k)?;
-            os.write_string(2, &v)?;
-        };
+        if !self.invalid_module_report.is_empty() {
+            os.write_bytes(1, &self.invalid_module_report)?;
+        }
         if let Some(v) = self.error.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
@@ -3236,96 +10143,85 @@ impl ::protobuf::Message for DeleteModulesResponse {
         &mut self.special_fields
     }
 
-    fn new() -> DeleteModulesResponse {
-        DeleteModulesResponse::new()
+    fn new() -> ValidateModuleResponse {
+        ValidateModuleResponse::new()
     }
 
     fn clear(&mut self) {
-        self.module_id_hash.clear();
+        self.invalid_module_report.clear();
         self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DeleteModulesResponse {
-        static instance: ::protobuf::rt::Lazy<DeleteModulesResponse> = ::protobuf::rt::Lazy::new();
-        instance.get(DeleteModulesResponse::new)
+    fn default_instance() -> &'static ValidateModuleResponse {
+        static instance: ValidateModuleResponse = ValidateModuleResponse {
+            invalid_module_report: ::std::vec::Vec::new(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for DeleteModulesResponse {
+impl ::protobuf::MessageFull for ValidateModuleResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteModulesResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ValidateModuleResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DeleteModulesResponse {
+impl ::std::fmt::Display for ValidateModuleResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteModulesResponse {
+impl ::protobuf::reflect::ProtobufValue for ValidateModuleResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/audit:`
-///  Return a list of modules which match the outcome requirements using the provided checkfile.
-// @@protoc_insertion_point(message:AuditModulesRequest)
+///  `POST /api/v1/module_graph:`
+///  Return a single module_graph.
+// @@protoc_insertion_point(message:GetModuleGraphRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AuditModulesRequest {
+pub struct GetModuleGraphRequest {
     // message fields
-    ///  the YAML checkfile (e.g. mod.yaml) bytes
-    // @@protoc_insertion_point(field:AuditModulesRequest.checkfile)
-    pub checkfile: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:AuditModulesRequest.outcome)
-    pub outcome: ::protobuf::EnumOrUnknown<AuditOutcome>,
-    // @@protoc_insertion_point(field:AuditModulesRequest.pagination)
-    pub pagination: ::protobuf::MessageField<Pagination>,
+    // @@protoc_insertion_point(field:GetModuleGraphRequest.module_id)
+    pub module_id: i64,
     // special fields
-    // @@protoc_insertion_point(special_field:AuditModulesRequest.special_fields)
+    // @@protoc_insertion_point(special_field:GetModuleGraphRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AuditModulesRequest {
-    fn default() -> &'a AuditModulesRequest {
-        <AuditModulesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetModuleGraphRequest {
+    fn default() -> &'a GetModuleGraphRequest {
+        <GetModuleGraphRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AuditModulesRequest {
-    pub fn new() -> AuditModulesRequest {
+impl GetModuleGraphRequest {
+    pub fn new() -> GetModuleGraphRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
-        let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "checkfile",
-            |m: &AuditModulesRequest| { &m.checkfile },
-            |m: &mut AuditModulesRequest| { &mut m.checkfile },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "outcome",
-            |m: &AuditModulesRequest| { &m.outcome },
-            |m: &mut AuditModulesRequest| { &mut m.outcome },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
-            "pagination",
-            |m: &AuditModulesRequest| { &m.pagination },
-            |m: &mut AuditModulesRequest| { &mut m.pagination },
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module_id",
+            |m: &GetModuleGraphRequest| { &m.module_id },
+            |m: &mut GetModuleGraphRequest| { &mut m.module_id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AuditModulesRequest>(
-            "AuditModulesRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleGraphRequest>(
+            "GetModuleGraphRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AuditModulesRequest {
-    const NAME: &'static str = "AuditModulesRequest";
+impl ::protobuf::Message for GetModuleGraphRequest {
+    const NAME: &'static str = "GetModuleGraphRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3334,14 +10230,8 @@ impl ::protobuf::Message for AuditModulesRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.checkfile = is.read_bytes()?;
-                },
-                16 => {
-                    self.outcome = is.read_enum_or_unknown()?;
-                },
-                26 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
+                8 => {
+                    self.module_id = is.read_int64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3355,15 +10245,8 @@ impl ::protobuf::Message for AuditModulesRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.checkfile.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.checkfile);
-        }
-        if self.outcome != ::protobuf::EnumOrUnknown::new(AuditOutcome::PASS) {
-            my_size += ::protobuf::rt::int32_size(2, self.outcome.value());
-        }
-        if let Some(v) = self.pagination.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.module_id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.module_id);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -3371,14 +10254,8 @@ impl ::protobuf::Message for AuditModulesRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.checkfile.is_empty() {
-            os.write_bytes(1, &self.checkfile)?;
-        }
-        if self.outcome != ::protobuf::EnumOrUnknown::new(AuditOutcome::PASS) {
-            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.outcome))?;
-        }
-        if let Some(v) = self.pagination.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        if self.module_id != 0 {
+            os.write_int64(1, self.module_id)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3392,111 +10269,89 @@ impl ::protobuf::Message for AuditModulesRequest {
         &mut self.special_fields
     }
 
-    fn new() -> AuditModulesRequest {
-        AuditModulesRequest::new()
+    fn new() -> GetModuleGraphRequest {
+        GetModuleGraphRequest::new()
     }
 
     fn clear(&mut self) {
-        self.checkfile.clear();
-        self.outcome = ::protobuf::EnumOrUnknown::new(AuditOutcome::PASS);
-        self.pagination.clear();
+        self.module_id = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AuditModulesRequest {
-        static instance: AuditModulesRequest = AuditModulesRequest {
-            checkfile: ::std::vec::Vec::new(),
-            outcome: ::protobuf::EnumOrUnknown::from_i32(0),
-            pagination: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static GetModuleGraphRequest {
+        static instance: GetModuleGraphRequest = GetModuleGraphRequest {
+            module_id: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AuditModulesRequest {
+impl ::protobuf::MessageFull for GetModuleGraphRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AuditModulesRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleGraphRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AuditModulesRequest {
+impl ::std::fmt::Display for GetModuleGraphRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AuditModulesRequest {
+impl ::protobuf::reflect::ProtobufValue for GetModuleGraphRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to a `AuditModulesRequest`.
-// @@protoc_insertion_point(message:AuditModulesResponse)
+///  The message returned in response to a `GetModuleGraphRequest`.
+// @@protoc_insertion_point(message:GetModuleGraphResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AuditModulesResponse {
+pub struct GetModuleGraphResponse {
     // message fields
-    ///  each record contains the ID of the invalid Module which failed the audit, as well as the failure 
-    ///  report produced by the validation check (encoded in JSON)
-    // @@protoc_insertion_point(field:AuditModulesResponse.invalid_module_report)
-    pub invalid_module_report: ::std::collections::HashMap<i64, ::std::vec::Vec<u8>>,
-    // @@protoc_insertion_point(field:AuditModulesResponse.pagination)
-    pub pagination: ::protobuf::MessageField<Pagination>,
-    ///  the full count of results in the database (not the count of this message's
-    ///  `modules`).
-    // @@protoc_insertion_point(field:AuditModulesResponse.total)
-    pub total: u64,
-    // @@protoc_insertion_point(field:AuditModulesResponse.error)
+    // @@protoc_insertion_point(field:GetModuleGraphResponse.module_graph)
+    pub module_graph: ::protobuf::MessageField<ModuleGraph>,
+    // @@protoc_insertion_point(field:GetModuleGraphResponse.error)
     pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:AuditModulesResponse.special_fields)
+    // @@protoc_insertion_point(special_field:GetModuleGraphResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AuditModulesResponse {
-    fn default() -> &'a AuditModulesResponse {
-        <AuditModulesResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetModuleGraphResponse {
+    fn default() -> &'a GetModuleGraphResponse {
+        <GetModuleGraphResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AuditModulesResponse {
-    pub fn new() -> AuditModulesResponse {
+impl GetModuleGraphResponse {
+    pub fn new() -> GetModuleGraphResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
-            "invalid_module_report",
-            |m: &AuditModulesResponse| { &m.invalid_module_report },
-            |m: &mut AuditModulesResponse| { &mut m.invalid_module_report },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Pagination>(
-            "pagination",
-            |m: &AuditModulesResponse| { &m.pagination },
-            |m: &mut AuditModulesResponse| { &mut m.pagination },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "total",
-            |m: &AuditModulesResponse| { &m.total },
-            |m: &mut AuditModulesResponse| { &mut m.total },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ModuleGraph>(
+            "module_graph",
+            |m: &GetModuleGraphResponse| { &m.module_graph },
+            |m: &mut GetModuleGraphResponse| { &mut m.module_graph },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
             "error",
-            |m: &AuditModulesResponse| { &m.error },
-            |m: &mut AuditModulesResponse| { &mut m.error },
+            |m: &GetModuleGraphResponse| { &m.error },
+            |m: &mut GetModuleGraphResponse| { &mut m.error },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AuditModulesResponse>(
-            "AuditModulesResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleGraphResponse>(
+            "GetModuleGraphResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AuditModulesResponse {
-    const NAME: &'static str = "AuditModulesResponse";
+impl ::protobuf::Message for GetModuleGraphResponse {
+    const NAME: &'static str = "GetModuleGraphResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3506,27 +10361,9 @@ impl ::protobuf::Message for AuditModulesResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
-                        match tag {
-                            8 => key = is.read_int64()?,
-                            18 => value = is.read_bytes()?,
-                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
-                        };
-                    }
-                    is.pop_limit(old_limit);
-                    self.invalid_module_report.insert(key, value);
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.module_graph)?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.pagination)?;
-                },
-                24 => {
-                    self.total = is.read_uint64()?;
-                },
-                34 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
@@ -3541,19 +10378,10 @@ impl ::protobuf::Message for AuditModulesResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for (k, v) in &self.invalid_module_report {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::int64_size(1, *k);
-            entry_size += ::protobuf::rt::bytes_size(2, &v);
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
-        };
-        if let Some(v) = self.pagination.as_ref() {
+        if let Some(v) = self.module_graph.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if self.total != 0 {
-            my_size += ::protobuf::rt::uint64_size(3, self.total);
-        }
         if let Some(v) = self.error.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
@@ -3564,23 +10392,11 @@ impl ::protobuf::Message for AuditModulesResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for (k, v) in &self.invalid_module_report {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::int64_size(1, *k);
-            entry_size += ::protobuf::rt::bytes_size(2, &v);
-            os.write_raw_varint32(10)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_int64(1, *k)?;
-            os.write_bytes(2, &v)?;
-        };
-        if let Some(v) = self.pagination.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
-        if self.total != 0 {
-            os.write_uint64(3, self.total)?;
+        if let Some(v) = self.module_graph.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
         if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3594,68 +10410,69 @@ impl ::protobuf::Message for AuditModulesResponse {
         &mut self.special_fields
     }
 
-    fn new() -> AuditModulesResponse {
-        AuditModulesResponse::new()
+    fn new() -> GetModuleGraphResponse {
+        GetModuleGraphResponse::new()
     }
 
     fn clear(&mut self) {
-        self.invalid_module_report.clear();
-        self.pagination.clear();
-        self.total = 0;
+        self.module_graph.clear();
         self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AuditModulesResponse {
-        static instance: ::protobuf::rt::Lazy<AuditModulesResponse> = ::protobuf::rt::Lazy::new();
-        instance.get(AuditModulesResponse::new)
+    fn default_instance() -> &'static GetModuleGraphResponse {
+        static instance: GetModuleGraphResponse = GetModuleGraphResponse {
+            module_graph: ::protobuf::MessageField::none(),
+            error: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for AuditModulesResponse {
+impl ::protobuf::MessageFull for GetModuleGraphResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AuditModulesResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleGraphResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AuditModulesResponse {
+impl ::std::fmt::Display for GetModuleGraphResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AuditModulesResponse {
+impl ::protobuf::reflect::ProtobufValue for GetModuleGraphResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/diff:`
-///  Return the diff of two modules
-// @@protoc_insertion_point(message:DiffRequest)
+///  PUT /api/v1/plugin:
+// @@protoc_insertion_point(message:InstallPluginRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DiffRequest {
+pub struct InstallPluginRequest {
     // message fields
-    // @@protoc_insertion_point(field:DiffRequest.module1)
-    pub module1: i64,
-    // @@protoc_insertion_point(field:DiffRequest.module2)
-    pub module2: i64,
-    // @@protoc_insertion_point(field:DiffRequest.color_terminal)
-    pub color_terminal: bool,
-    // @@protoc_insertion_point(field:DiffRequest.with_context)
-    pub with_context: bool,
+    // @@protoc_insertion_point(field:InstallPluginRequest.identifier)
+    pub identifier: ::std::string::String,
+    // @@protoc_insertion_point(field:InstallPluginRequest.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:InstallPluginRequest.location)
+    pub location: ::std::string::String,
+    // @@protoc_insertion_point(field:InstallPluginRequest.wasm)
+    pub wasm: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:DiffRequest.special_fields)
+    // @@protoc_insertion_point(special_field:InstallPluginRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DiffRequest {
-    fn default() -> &'a DiffRequest {
-        <DiffRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a InstallPluginRequest {
+    fn default() -> &'a InstallPluginRequest {
+        <InstallPluginRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DiffRequest {
-    pub fn new() -> DiffRequest {
+impl InstallPluginRequest {
+    pub fn new() -> InstallPluginRequest {
         ::std::default::Default::default()
     }
 
@@ -3663,35 +10480,35 @@ impl DiffRequest {
         let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "module1",
-            |m: &DiffRequest| { &m.module1 },
-            |m: &mut DiffRequest| { &mut m.module1 },
+            "identifier",
+            |m: &InstallPluginRequest| { &m.identifier },
+            |m: &mut InstallPluginRequest| { &mut m.identifier },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "module2",
-            |m: &DiffRequest| { &m.module2 },
-            |m: &mut DiffRequest| { &mut m.module2 },
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &InstallPluginRequest| { &m.name },
+            |m: &mut InstallPluginRequest| { &mut m.name },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "color_terminal",
-            |m: &DiffRequest| { &m.color_terminal },
-            |m: &mut DiffRequest| { &mut m.color_terminal },
+            "location",
+            |m: &InstallPluginRequest| { &m.location },
+            |m: &mut InstallPluginRequest| { &mut m.location },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "with_context",
-            |m: &DiffRequest| { &m.with_context },
-            |m: &mut DiffRequest| { &mut m.with_context },
+            "wasm",
+            |m: &InstallPluginRequest| { &m.wasm },
+            |m: &mut InstallPluginRequest| { &mut m.wasm },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DiffRequest>(
-            "DiffRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InstallPluginRequest>(
+            "InstallPluginRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DiffRequest {
-    const NAME: &'static str = "DiffRequest";
+impl ::protobuf::Message for InstallPluginRequest {
+    const NAME: &'static str = "InstallPluginRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3700,17 +10517,17 @@ impl ::protobuf::Message for DiffRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.module1 = is.read_int64()?;
+                10 => {
+                    self.identifier = is.read_string()?;
                 },
-                16 => {
-                    self.module2 = is.read_int64()?;
+                18 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
                 },
-                24 => {
-                    self.color_terminal = is.read_bool()?;
+                26 => {
+                    self.location = is.read_string()?;
                 },
-                32 => {
-                    self.with_context = is.read_bool()?;
+                34 => {
+                    self.wasm = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3724,17 +10541,17 @@ impl ::protobuf::Message for DiffRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.module1 != 0 {
-            my_size += ::protobuf::rt::int64_size(1, self.module1);
+        if !self.identifier.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.identifier);
         }
-        if self.module2 != 0 {
-            my_size += ::protobuf::rt::int64_size(2, self.module2);
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
         }
-        if self.color_terminal != false {
-            my_size += 1 + 1;
+        if !self.location.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.location);
         }
-        if self.with_context != false {
-            my_size += 1 + 1;
+        if !self.wasm.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.wasm);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -3742,17 +10559,17 @@ impl ::protobuf::Message for DiffRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.module1 != 0 {
-            os.write_int64(1, self.module1)?;
+        if !self.identifier.is_empty() {
+            os.write_string(1, &self.identifier)?;
         }
-        if self.module2 != 0 {
-            os.write_int64(2, self.module2)?;
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(2, v)?;
         }
-        if self.color_terminal != false {
-            os.write_bool(3, self.color_terminal)?;
+        if !self.location.is_empty() {
+            os.write_string(3, &self.location)?;
         }
-        if self.with_context != false {
-            os.write_bool(4, self.with_context)?;
+        if !self.wasm.is_empty() {
+            os.write_bytes(4, &self.wasm)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3766,70 +10583,68 @@ impl ::protobuf::Message for DiffRequest {
         &mut self.special_fields
     }
 
-    fn new() -> DiffRequest {
-        DiffRequest::new()
+    fn new() -> InstallPluginRequest {
+        InstallPluginRequest::new()
     }
 
     fn clear(&mut self) {
-        self.module1 = 0;
-        self.module2 = 0;
-        self.color_terminal = false;
-        self.with_context = false;
+        self.identifier.clear();
+        self.name = ::std::option::Option::None;
+        self.location.clear();
+        self.wasm.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DiffRequest {
-        static instance: DiffRequest = DiffRequest {
-            module1: 0,
-            module2: 0,
-            color_terminal: false,
-            with_context: false,
+    fn default_instance() -> &'static InstallPluginRequest {
+        static instance: InstallPluginRequest = InstallPluginRequest {
+            identifier: ::std::string::String::new(),
+            name: ::std::option::Option::None,
+            location: ::std::string::String::new(),
+            wasm: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for DiffRequest {
+impl ::protobuf::MessageFull for InstallPluginRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DiffRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InstallPluginRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DiffRequest {
+impl ::std::fmt::Display for InstallPluginRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DiffRequest {
+impl ::protobuf::reflect::ProtobufValue for InstallPluginRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to `DiffRequest`, contains a text representation of the difference
-///  between the two specified modules.
-// @@protoc_insertion_point(message:DiffResponse)
+// @@protoc_insertion_point(message:InstallPluginResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DiffResponse {
+pub struct InstallPluginResponse {
     // message fields
-    // @@protoc_insertion_point(field:DiffResponse.diff)
-    pub diff: ::std::string::String,
-    // @@protoc_insertion_point(field:DiffResponse.error)
+    // @@protoc_insertion_point(field:InstallPluginResponse.hash)
+    pub hash: ::std::string::String,
+    // @@protoc_insertion_point(field:InstallPluginResponse.error)
     pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:DiffResponse.special_fields)
+    // @@protoc_insertion_point(special_field:InstallPluginResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DiffResponse {
-    fn default() -> &'a DiffResponse {
-        <DiffResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a InstallPluginResponse {
+    fn default() -> &'a InstallPluginResponse {
+        <InstallPluginResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DiffResponse {
-    pub fn new() -> DiffResponse {
+impl InstallPluginResponse {
+    pub fn new() -> InstallPluginResponse {
         ::std::default::Default::default()
     }
 
@@ -3837,25 +10652,25 @@ impl DiffResponse {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "diff",
-            |m: &DiffResponse| { &m.diff },
-            |m: &mut DiffResponse| { &mut m.diff },
+            "hash",
+            |m: &InstallPluginResponse| { &m.hash },
+            |m: &mut InstallPluginResponse| { &mut m.hash },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
             "error",
-            |m: &DiffResponse| { &m.error },
-            |m: &mut DiffResponse| { &mut m.error },
+            |m: &InstallPluginResponse| { &m.error },
+            |m: &mut InstallPluginResponse| { &mut m.error },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DiffResponse>(
-            "DiffResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InstallPluginResponse>(
+            "InstallPluginResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DiffResponse {
-    const NAME: &'static str = "DiffResponse";
+impl ::protobuf::Message for InstallPluginResponse {
+    const NAME: &'static str = "InstallPluginResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3865,7 +10680,7 @@ impl ::protobuf::Message for DiffResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.diff = is.read_string()?;
+                    self.hash = is.read_string()?;
                 },
                 18 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
@@ -3882,8 +10697,8 @@ impl ::protobuf::Message for DiffResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.diff.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.diff);
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash);
         }
         if let Some(v) = self.error.as_ref() {
             let len = v.compute_size();
@@ -3895,8 +10710,8 @@ impl ::protobuf::Message for DiffResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.diff.is_empty() {
-            os.write_string(1, &self.diff)?;
+        if !self.hash.is_empty() {
+            os.write_string(1, &self.hash)?;
         }
         if let Some(v) = self.error.as_ref() {
             ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
@@ -3913,19 +10728,19 @@ impl ::protobuf::Message for DiffResponse {
         &mut self.special_fields
     }
 
-    fn new() -> DiffResponse {
-        DiffResponse::new()
+    fn new() -> InstallPluginResponse {
+        InstallPluginResponse::new()
     }
 
     fn clear(&mut self) {
-        self.diff.clear();
+        self.hash.clear();
         self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DiffResponse {
-        static instance: DiffResponse = DiffResponse {
-            diff: ::std::string::String::new(),
+    fn default_instance() -> &'static InstallPluginResponse {
+        static instance: InstallPluginResponse = InstallPluginResponse {
+            hash: ::std::string::String::new(),
             error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -3933,155 +10748,186 @@ impl ::protobuf::Message for DiffResponse {
     }
 }
 
-impl ::protobuf::MessageFull for DiffResponse {
+impl ::protobuf::MessageFull for InstallPluginResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DiffResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InstallPluginResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DiffResponse {
+impl ::std::fmt::Display for InstallPluginResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DiffResponse {
+impl ::protobuf::reflect::ProtobufValue for InstallPluginResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/validate:`
-///  Return the failure report (if applicable) of a wasm module validation against a given checkfile.
-// @@protoc_insertion_point(message:ValidateModuleRequest)
+///  DELETE /api/v1/plugin:
+// @@protoc_insertion_point(message:UninstallPluginRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ValidateModuleRequest {
+pub struct UninstallPluginRequest {
     // message fields
-    ///  the YAML checkfile (e.g. mod.yaml) bytes
-    // @@protoc_insertion_point(field:ValidateModuleRequest.checkfile)
-    pub checkfile: ::std::vec::Vec<u8>,
-    // message oneof groups
-    pub module_input: ::std::option::Option<validate_module_request::Module_input>,
+    // @@protoc_insertion_point(field:UninstallPluginRequest.identifier)
+    pub identifier: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:ValidateModuleRequest.special_fields)
+    // @@protoc_insertion_point(special_field:UninstallPluginRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ValidateModuleRequest {
-    fn default() -> &'a ValidateModuleRequest {
-        <ValidateModuleRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UninstallPluginRequest {
+    fn default() -> &'a UninstallPluginRequest {
+        <UninstallPluginRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ValidateModuleRequest {
-    pub fn new() -> ValidateModuleRequest {
+impl UninstallPluginRequest {
+    pub fn new() -> UninstallPluginRequest {
         ::std::default::Default::default()
     }
 
-    // bytes module = 2;
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "identifier",
+            |m: &UninstallPluginRequest| { &m.identifier },
+            |m: &mut UninstallPluginRequest| { &mut m.identifier },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UninstallPluginRequest>(
+            "UninstallPluginRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
 
-    pub fn module(&self) -> &[u8] {
-        match self.module_input {
-            ::std::option::Option::Some(validate_module_request::Module_input::Module(ref v)) => v,
-            _ => &[],
+impl ::protobuf::Message for UninstallPluginRequest {
+    const NAME: &'static str = "UninstallPluginRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.identifier = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.identifier.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.identifier);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.identifier.is_empty() {
+            os.write_string(1, &self.identifier)?;
         }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    pub fn clear_module(&mut self) {
-        self.module_input = ::std::option::Option::None;
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
     }
 
-    pub fn has_module(&self) -> bool {
-        match self.module_input {
-            ::std::option::Option::Some(validate_module_request::Module_input::Module(..)) => true,
-            _ => false,
-        }
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
     }
 
-    // Param is passed by value, moved
-    pub fn set_module(&mut self, v: ::std::vec::Vec<u8>) {
-        self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::Module(v))
+    fn new() -> UninstallPluginRequest {
+        UninstallPluginRequest::new()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_module(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if let ::std::option::Option::Some(validate_module_request::Module_input::Module(_)) = self.module_input {
-        } else {
-            self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::Module(::std::vec::Vec::new()));
-        }
-        match self.module_input {
-            ::std::option::Option::Some(validate_module_request::Module_input::Module(ref mut v)) => v,
-            _ => panic!(),
-        }
+    fn clear(&mut self) {
+        self.identifier.clear();
+        self.special_fields.clear();
     }
 
-    // Take field
-    pub fn take_module(&mut self) -> ::std::vec::Vec<u8> {
-        if self.has_module() {
-            match self.module_input.take() {
-                ::std::option::Option::Some(validate_module_request::Module_input::Module(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::vec::Vec::new()
-        }
+    fn default_instance() -> &'static UninstallPluginRequest {
+        static instance: UninstallPluginRequest = UninstallPluginRequest {
+            identifier: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
+}
 
-    // int64 module_id = 3;
-
-    pub fn module_id(&self) -> i64 {
-        match self.module_input {
-            ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(v)) => v,
-            _ => 0,
-        }
+impl ::protobuf::MessageFull for UninstallPluginRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UninstallPluginRequest").unwrap()).clone()
     }
+}
 
-    pub fn clear_module_id(&mut self) {
-        self.module_input = ::std::option::Option::None;
+impl ::std::fmt::Display for UninstallPluginRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    pub fn has_module_id(&self) -> bool {
-        match self.module_input {
-            ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(..)) => true,
-            _ => false,
-        }
+impl ::protobuf::reflect::ProtobufValue for UninstallPluginRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:UninstallPluginResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UninstallPluginResponse {
+    // message fields
+    // @@protoc_insertion_point(field:UninstallPluginResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
+    // special fields
+    // @@protoc_insertion_point(special_field:UninstallPluginResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UninstallPluginResponse {
+    fn default() -> &'a UninstallPluginResponse {
+        <UninstallPluginResponse as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_module_id(&mut self, v: i64) {
-        self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(v))
+impl UninstallPluginResponse {
+    pub fn new() -> UninstallPluginResponse {
+        ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
-        let mut oneofs = ::std::vec::Vec::with_capacity(1);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "checkfile",
-            |m: &ValidateModuleRequest| { &m.checkfile },
-            |m: &mut ValidateModuleRequest| { &mut m.checkfile },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
-            "module",
-            ValidateModuleRequest::has_module,
-            ValidateModuleRequest::module,
-            ValidateModuleRequest::set_module,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
-            "module_id",
-            ValidateModuleRequest::has_module_id,
-            ValidateModuleRequest::module_id,
-            ValidateModuleRequest::set_module_id,
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &UninstallPluginResponse| { &m.error },
+            |m: &mut UninstallPluginResponse| { &mut m.error },
         ));
-        oneofs.push(validate_module_request::Module_input::generated_oneof_descriptor_data());
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ValidateModuleRequest>(
-            "ValidateModuleRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UninstallPluginResponse>(
+            "UninstallPluginResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ValidateModuleRequest {
-    const NAME: &'static str = "ValidateModuleRequest";
+impl ::protobuf::Message for UninstallPluginResponse {
+    const NAME: &'static str = "UninstallPluginResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4091,13 +10937,7 @@ impl ::protobuf::Message for ValidateModuleRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.checkfile = is.read_bytes()?;
-                },
-                18 => {
-                    self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::Module(is.read_bytes()?));
-                },
-                24 => {
-                    self.module_input = ::std::option::Option::Some(validate_module_request::Module_input::ModuleId(is.read_int64()?));
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4111,18 +10951,9 @@ impl ::protobuf::Message for ValidateModuleRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.checkfile.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.checkfile);
-        }
-        if let ::std::option::Option::Some(ref v) = self.module_input {
-            match v {
-                &validate_module_request::Module_input::Module(ref v) => {
-                    my_size += ::protobuf::rt::bytes_size(2, &v);
-                },
-                &validate_module_request::Module_input::ModuleId(v) => {
-                    my_size += ::protobuf::rt::int64_size(3, v);
-                },
-            };
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4130,18 +10961,8 @@ impl ::protobuf::Message for ValidateModuleRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.checkfile.is_empty() {
-            os.write_bytes(1, &self.checkfile)?;
-        }
-        if let ::std::option::Option::Some(ref v) = self.module_input {
-            match v {
-                &validate_module_request::Module_input::Module(ref v) => {
-                    os.write_bytes(2, v)?;
-                },
-                &validate_module_request::Module_input::ModuleId(v) => {
-                    os.write_int64(3, v)?;
-                },
-            };
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4155,122 +10976,103 @@ impl ::protobuf::Message for ValidateModuleRequest {
         &mut self.special_fields
     }
 
-    fn new() -> ValidateModuleRequest {
-        ValidateModuleRequest::new()
+    fn new() -> UninstallPluginResponse {
+        UninstallPluginResponse::new()
     }
 
     fn clear(&mut self) {
-        self.checkfile.clear();
-        self.module_input = ::std::option::Option::None;
-        self.module_input = ::std::option::Option::None;
+        self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ValidateModuleRequest {
-        static instance: ValidateModuleRequest = ValidateModuleRequest {
-            checkfile: ::std::vec::Vec::new(),
-            module_input: ::std::option::Option::None,
+    fn default_instance() -> &'static UninstallPluginResponse {
+        static instance: UninstallPluginResponse = UninstallPluginResponse {
+            error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ValidateModuleRequest {
+impl ::protobuf::MessageFull for UninstallPluginResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ValidateModuleRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UninstallPluginResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ValidateModuleRequest {
+impl ::std::fmt::Display for UninstallPluginResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ValidateModuleRequest {
+impl ::protobuf::reflect::ProtobufValue for UninstallPluginResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `ValidateModuleRequest`
-pub mod validate_module_request {
-
-    #[derive(Clone,PartialEq,Debug)]
-    #[non_exhaustive]
-    // @@protoc_insertion_point(oneof:ValidateModuleRequest.module_input)
-    pub enum Module_input {
-        // @@protoc_insertion_point(oneof_field:ValidateModuleRequest.module)
-        Module(::std::vec::Vec<u8>),
-        // @@protoc_insertion_point(oneof_field:ValidateModuleRequest.module_id)
-        ModuleId(i64),
-    }
-
-    impl ::protobuf::Oneof for Module_input {
-    }
-
-    impl ::protobuf::OneofFull for Module_input {
-        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
-            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| <super::ValidateModuleRequest as ::protobuf::MessageFull>::descriptor().oneof_by_name("module_input").unwrap()).clone()
-        }
-    }
-
-    impl Module_input {
-        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
-            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Module_input>("module_input")
-        }
-    }
-}
-
-///  The failure report produced by the validation check (encoded in JSON).
-// @@protoc_insertion_point(message:ValidateModuleResponse)
+///  POST /api/v1/plugin:
+// @@protoc_insertion_point(message:CallPluginRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct ValidateModuleResponse {
+pub struct CallPluginRequest {
     // message fields
-    // @@protoc_insertion_point(field:ValidateModuleResponse.invalid_module_report)
-    pub invalid_module_report: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:ValidateModuleResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+    // @@protoc_insertion_point(field:CallPluginRequest.identifier)
+    pub identifier: ::std::string::String,
+    // @@protoc_insertion_point(field:CallPluginRequest.function_name)
+    pub function_name: ::std::string::String,
+    // @@protoc_insertion_point(field:CallPluginRequest.input)
+    pub input: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:CallPluginRequest.hash)
+    pub hash: ::std::option::Option<::std::string::String>,
     // special fields
-    // @@protoc_insertion_point(special_field:ValidateModuleResponse.special_fields)
+    // @@protoc_insertion_point(special_field:CallPluginRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ValidateModuleResponse {
-    fn default() -> &'a ValidateModuleResponse {
-        <ValidateModuleResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CallPluginRequest {
+    fn default() -> &'a CallPluginRequest {
+        <CallPluginRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ValidateModuleResponse {
-    pub fn new() -> ValidateModuleResponse {
+impl CallPluginRequest {
+    pub fn new() -> CallPluginRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "invalid_module_report",
-            |m: &ValidateModuleResponse| { &m.invalid_module_report },
-            |m: &mut ValidateModuleResponse| { &mut m.invalid_module_report },
+            "identifier",
+            |m: &CallPluginRequest| { &m.identifier },
+            |m: &mut CallPluginRequest| { &mut m.identifier },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "function_name",
+            |m: &CallPluginRequest| { &m.function_name },
+            |m: &mut CallPluginRequest| { &mut m.function_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "input",
+            |m: &CallPluginRequest| { &m.input },
+            |m: &mut CallPluginRequest| { &mut m.input },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &ValidateModuleResponse| { &m.error },
-            |m: &mut ValidateModuleResponse| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "hash",
+            |m: &CallPluginRequest| { &m.hash },
+            |m: &mut CallPluginRequest| { &mut m.hash },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ValidateModuleResponse>(
-            "ValidateModuleResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CallPluginRequest>(
+            "CallPluginRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ValidateModuleResponse {
-    const NAME: &'static str = "ValidateModuleResponse";
+impl ::protobuf::Message for CallPluginRequest {
+    const NAME: &'static str = "CallPluginRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4280,10 +11082,16 @@ impl ::protobuf::Message for ValidateModuleResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.invalid_module_report = is.read_bytes()?;
+                    self.identifier = is.read_string()?;
                 },
                 18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                    self.function_name = is.read_string()?;
+                },
+                26 => {
+                    self.input = is.read_bytes()?;
+                },
+                34 => {
+                    self.hash = ::std::option::Option::Some(is.read_string()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4297,12 +11105,17 @@ impl ::protobuf::Message for ValidateModuleResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.invalid_module_report.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.invalid_module_report);
+        if !self.identifier.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.identifier);
         }
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if !self.function_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.function_name);
+        }
+        if !self.input.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.input);
+        }
+        if let Some(v) = self.hash.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4310,11 +11123,17 @@ impl ::protobuf::Message for ValidateModuleResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.invalid_module_report.is_empty() {
-            os.write_bytes(1, &self.invalid_module_report)?;
+        if !self.identifier.is_empty() {
+            os.write_string(1, &self.identifier)?;
         }
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if !self.function_name.is_empty() {
+            os.write_string(2, &self.function_name)?;
+        }
+        if !self.input.is_empty() {
+            os.write_bytes(3, &self.input)?;
+        }
+        if let Some(v) = self.hash.as_ref() {
+            os.write_string(4, v)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4328,85 +11147,94 @@ impl ::protobuf::Message for ValidateModuleResponse {
         &mut self.special_fields
     }
 
-    fn new() -> ValidateModuleResponse {
-        ValidateModuleResponse::new()
+    fn new() -> CallPluginRequest {
+        CallPluginRequest::new()
     }
 
     fn clear(&mut self) {
-        self.invalid_module_report.clear();
-        self.error.clear();
+        self.identifier.clear();
+        self.function_name.clear();
+        self.input.clear();
+        self.hash = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ValidateModuleResponse {
-        static instance: ValidateModuleResponse = ValidateModuleResponse {
-            invalid_module_report: ::std::vec::Vec::new(),
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static CallPluginRequest {
+        static instance: CallPluginRequest = CallPluginRequest {
+            identifier: ::std::string::String::new(),
+            function_name: ::std::string::String::new(),
+            input: ::std::vec::Vec::new(),
+            hash: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ValidateModuleResponse {
+impl ::protobuf::MessageFull for CallPluginRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ValidateModuleResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CallPluginRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ValidateModuleResponse {
+impl ::std::fmt::Display for CallPluginRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ValidateModuleResponse {
+impl ::protobuf::reflect::ProtobufValue for CallPluginRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  `POST /api/v1/module_graph:`
-///  Return a single module_graph.
-// @@protoc_insertion_point(message:GetModuleGraphRequest)
+// @@protoc_insertion_point(message:CallPluginResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetModuleGraphRequest {
+pub struct CallPluginResponse {
     // message fields
-    // @@protoc_insertion_point(field:GetModuleGraphRequest.module_id)
-    pub module_id: i64,
+    // @@protoc_insertion_point(field:CallPluginResponse.output)
+    pub output: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:CallPluginResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:GetModuleGraphRequest.special_fields)
+    // @@protoc_insertion_point(special_field:CallPluginResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetModuleGraphRequest {
-    fn default() -> &'a GetModuleGraphRequest {
-        <GetModuleGraphRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CallPluginResponse {
+    fn default() -> &'a CallPluginResponse {
+        <CallPluginResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetModuleGraphRequest {
-    pub fn new() -> GetModuleGraphRequest {
+impl CallPluginResponse {
+    pub fn new() -> CallPluginResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "module_id",
-            |m: &GetModuleGraphRequest| { &m.module_id },
-            |m: &mut GetModuleGraphRequest| { &mut m.module_id },
+            "output",
+            |m: &CallPluginResponse| { &m.output },
+            |m: &mut CallPluginResponse| { &mut m.output },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleGraphRequest>(
-            "GetModuleGraphRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &CallPluginResponse| { &m.error },
+            |m: &mut CallPluginResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CallPluginResponse>(
+            "CallPluginResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetModuleGraphRequest {
-    const NAME: &'static str = "GetModuleGraphRequest";
+impl ::protobuf::Message for CallPluginResponse {
+    const NAME: &'static str = "CallPluginResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4415,8 +11243,11 @@ impl ::protobuf::Message for GetModuleGraphRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.module_id = is.read_int64()?;
+                10 => {
+                    self.output = is.read_bytes()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4430,8 +11261,12 @@ impl ::protobuf::Message for GetModuleGraphRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.module_id != 0 {
-            my_size += ::protobuf::rt::int64_size(1, self.module_id);
+        if !self.output.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.output);
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4439,8 +11274,11 @@ impl ::protobuf::Message for GetModuleGraphRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.module_id != 0 {
-            os.write_int64(1, self.module_id)?;
+        if !self.output.is_empty() {
+            os.write_bytes(1, &self.output)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4454,89 +11292,77 @@ impl ::protobuf::Message for GetModuleGraphRequest {
         &mut self.special_fields
     }
 
-    fn new() -> GetModuleGraphRequest {
-        GetModuleGraphRequest::new()
+    fn new() -> CallPluginResponse {
+        CallPluginResponse::new()
     }
 
     fn clear(&mut self) {
-        self.module_id = 0;
+        self.output.clear();
+        self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetModuleGraphRequest {
-        static instance: GetModuleGraphRequest = GetModuleGraphRequest {
-            module_id: 0,
+    fn default_instance() -> &'static CallPluginResponse {
+        static instance: CallPluginResponse = CallPluginResponse {
+            output: ::std::vec::Vec::new(),
+            error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetModuleGraphRequest {
+impl ::protobuf::MessageFull for CallPluginResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleGraphRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CallPluginResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetModuleGraphRequest {
+impl ::std::fmt::Display for CallPluginResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetModuleGraphRequest {
+impl ::protobuf::reflect::ProtobufValue for CallPluginResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  The message returned in response to a `GetModuleGraphRequest`.
-// @@protoc_insertion_point(message:GetModuleGraphResponse)
+///  `GET /api/v1/health:`
+///  Check that the server is running and able to handle requests.
+// @@protoc_insertion_point(message:HealthRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetModuleGraphResponse {
-    // message fields
-    // @@protoc_insertion_point(field:GetModuleGraphResponse.module_graph)
-    pub module_graph: ::protobuf::MessageField<ModuleGraph>,
-    // @@protoc_insertion_point(field:GetModuleGraphResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+pub struct HealthRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:GetModuleGraphResponse.special_fields)
+    // @@protoc_insertion_point(special_field:HealthRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetModuleGraphResponse {
-    fn default() -> &'a GetModuleGraphResponse {
-        <GetModuleGraphResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a HealthRequest {
+    fn default() -> &'a HealthRequest {
+        <HealthRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetModuleGraphResponse {
-    pub fn new() -> GetModuleGraphResponse {
+impl HealthRequest {
+    pub fn new() -> HealthRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ModuleGraph>(
-            "module_graph",
-            |m: &GetModuleGraphResponse| { &m.module_graph },
-            |m: &mut GetModuleGraphResponse| { &mut m.module_graph },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &GetModuleGraphResponse| { &m.error },
-            |m: &mut GetModuleGraphResponse| { &mut m.error },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetModuleGraphResponse>(
-            "GetModuleGraphResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HealthRequest>(
+            "HealthRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetModuleGraphResponse {
-    const NAME: &'static str = "GetModuleGraphResponse";
+impl ::protobuf::Message for HealthRequest {
+    const NAME: &'static str = "HealthRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4545,12 +11371,6 @@ impl ::protobuf::Message for GetModuleGraphResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.module_graph)?;
-                },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -4563,26 +11383,12 @@ impl ::protobuf::Message for GetModuleGraphResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.module_graph.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.module_graph.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        }
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4595,105 +11401,87 @@ impl ::protobuf::Message for GetModuleGraphResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetModuleGraphResponse {
-        GetModuleGraphResponse::new()
+    fn new() -> HealthRequest {
+        HealthRequest::new()
     }
 
     fn clear(&mut self) {
-        self.module_graph.clear();
-        self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetModuleGraphResponse {
-        static instance: GetModuleGraphResponse = GetModuleGraphResponse {
-            module_graph: ::protobuf::MessageField::none(),
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static HealthRequest {
+        static instance: HealthRequest = HealthRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetModuleGraphResponse {
+impl ::protobuf::MessageFull for HealthRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetModuleGraphResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HealthRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetModuleGraphResponse {
+impl ::std::fmt::Display for HealthRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetModuleGraphResponse {
+impl ::protobuf::reflect::ProtobufValue for HealthRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
-
-///  PUT /api/v1/plugin:
-// @@protoc_insertion_point(message:InstallPluginRequest)
-#[derive(PartialEq,Clone,Default,Debug)]
-pub struct InstallPluginRequest {
-    // message fields
-    // @@protoc_insertion_point(field:InstallPluginRequest.identifier)
-    pub identifier: ::std::string::String,
-    // @@protoc_insertion_point(field:InstallPluginRequest.name)
-    pub name: ::std::option::Option<::std::string::String>,
-    // @@protoc_insertion_point(field:InstallPluginRequest.location)
-    pub location: ::std::string::String,
-    // @@protoc_insertion_point(field:InstallPluginRequest.wasm)
-    pub wasm: ::std::vec::Vec<u8>,
+
+///  The message returned in response to a `HealthRequest`.
+// @@protoc_insertion_point(message:HealthResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HealthResponse {
+    // message fields
+    // @@protoc_insertion_point(field:HealthResponse.ok)
+    pub ok: bool,
+    // @@protoc_insertion_point(field:HealthResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:InstallPluginRequest.special_fields)
+    // @@protoc_insertion_point(special_field:HealthResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a InstallPluginRequest {
-    fn default() -> &'a InstallPluginRequest {
-        <InstallPluginRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a HealthResponse {
+    fn default() -> &'a HealthResponse {
+        <HealthResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl InstallPluginRequest {
-    pub fn new() -> InstallPluginRequest {
+impl HealthResponse {
+    pub fn new() -> HealthResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "identifier",
-            |m: &InstallPluginRequest| { &m.identifier },
-            |m: &mut InstallPluginRequest| { &mut m.identifier },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "name",
-            |m: &InstallPluginRequest| { &m.name },
-            |m: &mut InstallPluginRequest| { &mut m.name },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "location",
-            |m: &InstallPluginRequest| { &m.location },
-            |m: &mut InstallPluginRequest| { &mut m.location },
+            "ok",
+            |m: &HealthResponse| { &m.ok },
+            |m: &mut HealthResponse| { &mut m.ok },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "wasm",
-            |m: &InstallPluginRequest| { &m.wasm },
-            |m: &mut InstallPluginRequest| { &mut m.wasm },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &HealthResponse| { &m.error },
+            |m: &mut HealthResponse| { &mut m.error },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InstallPluginRequest>(
-            "InstallPluginRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HealthResponse>(
+            "HealthResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for InstallPluginRequest {
-    const NAME: &'static str = "InstallPluginRequest";
+impl ::protobuf::Message for HealthResponse {
+    const NAME: &'static str = "HealthResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4702,17 +11490,11 @@ impl ::protobuf::Message for InstallPluginRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.identifier = is.read_string()?;
+                8 => {
+                    self.ok = is.read_bool()?;
                 },
                 18 => {
-                    self.name = ::std::option::Option::Some(is.read_string()?);
-                },
-                26 => {
-                    self.location = is.read_string()?;
-                },
-                34 => {
-                    self.wasm = is.read_bytes()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -4726,17 +11508,12 @@ impl ::protobuf::Message for InstallPluginRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.identifier.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.identifier);
-        }
-        if let Some(v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        if !self.location.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.location);
+        if self.ok != false {
+            my_size += 1 + 1;
         }
-        if !self.wasm.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.wasm);
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -4744,17 +11521,11 @@ impl ::protobuf::Message for InstallPluginRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.identifier.is_empty() {
-            os.write_string(1, &self.identifier)?;
-        }
-        if let Some(v) = self.name.as_ref() {
-            os.write_string(2, v)?;
-        }
-        if !self.location.is_empty() {
-            os.write_string(3, &self.location)?;
+        if self.ok != false {
+            os.write_bool(1, self.ok)?;
         }
-        if !self.wasm.is_empty() {
-            os.write_bytes(4, &self.wasm)?;
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4768,94 +11539,77 @@ impl ::protobuf::Message for InstallPluginRequest {
         &mut self.special_fields
     }
 
-    fn new() -> InstallPluginRequest {
-        InstallPluginRequest::new()
+    fn new() -> HealthResponse {
+        HealthResponse::new()
     }
 
     fn clear(&mut self) {
-        self.identifier.clear();
-        self.name = ::std::option::Option::None;
-        self.location.clear();
-        self.wasm.clear();
+        self.ok = false;
+        self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static InstallPluginRequest {
-        static instance: InstallPluginRequest = InstallPluginRequest {
-            identifier: ::std::string::String::new(),
-            name: ::std::option::Option::None,
-            location: ::std::string::String::new(),
-            wasm: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static HealthResponse {
+        static instance: HealthResponse = HealthResponse {
+            ok: false,
+            error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for InstallPluginRequest {
+impl ::protobuf::MessageFull for HealthResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("InstallPluginRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HealthResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for InstallPluginRequest {
+impl ::std::fmt::Display for HealthResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for InstallPluginRequest {
+impl ::protobuf::reflect::ProtobufValue for HealthResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:InstallPluginResponse)
+///  `GET /api/v1/info:`
+///  Describe the server's version and the API features it supports.
+// @@protoc_insertion_point(message:ServerInfoRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct InstallPluginResponse {
-    // message fields
-    // @@protoc_insertion_point(field:InstallPluginResponse.hash)
-    pub hash: ::std::string::String,
-    // @@protoc_insertion_point(field:InstallPluginResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+pub struct ServerInfoRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:InstallPluginResponse.special_fields)
+    // @@protoc_insertion_point(special_field:ServerInfoRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a InstallPluginResponse {
-    fn default() -> &'a InstallPluginResponse {
-        <InstallPluginResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ServerInfoRequest {
+    fn default() -> &'a ServerInfoRequest {
+        <ServerInfoRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl InstallPluginResponse {
-    pub fn new() -> InstallPluginResponse {
+impl ServerInfoRequest {
+    pub fn new() -> ServerInfoRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "hash",
-            |m: &InstallPluginResponse| { &m.hash },
-            |m: &mut InstallPluginResponse| { &mut m.hash },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &InstallPluginResponse| { &m.error },
-            |m: &mut InstallPluginResponse| { &mut m.error },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InstallPluginResponse>(
-            "InstallPluginResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ServerInfoRequest>(
+            "ServerInfoRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for InstallPluginResponse {
-    const NAME: &'static str = "InstallPluginResponse";
+impl ::protobuf::Message for ServerInfoRequest {
+    const NAME: &'static str = "ServerInfoRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -4864,12 +11618,6 @@ impl ::protobuf::Message for InstallPluginResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.hash = is.read_string()?;
-                },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -4882,25 +11630,12 @@ impl ::protobuf::Message for InstallPluginResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.hash.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hash);
-        }
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.hash.is_empty() {
-            os.write_string(1, &self.hash)?;
-        }
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4913,84 +11648,103 @@ impl ::protobuf::Message for InstallPluginResponse {
         &mut self.special_fields
     }
 
-    fn new() -> InstallPluginResponse {
-        InstallPluginResponse::new()
+    fn new() -> ServerInfoRequest {
+        ServerInfoRequest::new()
     }
 
     fn clear(&mut self) {
-        self.hash.clear();
-        self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static InstallPluginResponse {
-        static instance: InstallPluginResponse = InstallPluginResponse {
-            hash: ::std::string::String::new(),
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static ServerInfoRequest {
+        static instance: ServerInfoRequest = ServerInfoRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for InstallPluginResponse {
+impl ::protobuf::MessageFull for ServerInfoRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("InstallPluginResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServerInfoRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for InstallPluginResponse {
+impl ::std::fmt::Display for ServerInfoRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for InstallPluginResponse {
+impl ::protobuf::reflect::ProtobufValue for ServerInfoRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  DELETE /api/v1/plugin:
-// @@protoc_insertion_point(message:UninstallPluginRequest)
+///  The message returned in response to a `ServerInfoRequest`.
+// @@protoc_insertion_point(message:ServerInfoResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct UninstallPluginRequest {
+pub struct ServerInfoResponse {
     // message fields
-    // @@protoc_insertion_point(field:UninstallPluginRequest.identifier)
-    pub identifier: ::std::string::String,
+    // @@protoc_insertion_point(field:ServerInfoResponse.version)
+    pub version: ::std::string::String,
+    ///  the optional API features supported by the server, e.g. "tags", "audit", "diff"
+    // @@protoc_insertion_point(field:ServerInfoResponse.features)
+    pub features: ::std::vec::Vec<::std::string::String>,
+    ///  true if plugins can be installed and called on this server
+    // @@protoc_insertion_point(field:ServerInfoResponse.plugins_enabled)
+    pub plugins_enabled: bool,
+    // @@protoc_insertion_point(field:ServerInfoResponse.error)
+    pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:UninstallPluginRequest.special_fields)
+    // @@protoc_insertion_point(special_field:ServerInfoResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a UninstallPluginRequest {
-    fn default() -> &'a UninstallPluginRequest {
-        <UninstallPluginRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ServerInfoResponse {
+    fn default() -> &'a ServerInfoResponse {
+        <ServerInfoResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl UninstallPluginRequest {
-    pub fn new() -> UninstallPluginRequest {
+impl ServerInfoResponse {
+    pub fn new() -> ServerInfoResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "identifier",
-            |m: &UninstallPluginRequest| { &m.identifier },
-            |m: &mut UninstallPluginRequest| { &mut m.identifier },
+            "version",
+            |m: &ServerInfoResponse| { &m.version },
+            |m: &mut ServerInfoResponse| { &mut m.version },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UninstallPluginRequest>(
-            "UninstallPluginRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "features",
+            |m: &ServerInfoResponse| { &m.features },
+            |m: &mut ServerInfoResponse| { &mut m.features },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "plugins_enabled",
+            |m: &ServerInfoResponse| { &m.plugins_enabled },
+            |m: &mut ServerInfoResponse| { &mut m.plugins_enabled },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
+            "error",
+            |m: &ServerInfoResponse| { &m.error },
+            |m: &mut ServerInfoResponse| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ServerInfoResponse>(
+            "ServerInfoResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for UninstallPluginRequest {
-    const NAME: &'static str = "UninstallPluginRequest";
+impl ::protobuf::Message for ServerInfoResponse {
+    const NAME: &'static str = "ServerInfoResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -5000,7 +11754,16 @@ impl ::protobuf::Message for UninstallPluginRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.identifier = is.read_string()?;
+                    self.version = is.read_string()?;
+                },
+                18 => {
+                    self.features.push(is.read_string()?);
+                },
+                24 => {
+                    self.plugins_enabled = is.read_bool()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -5014,8 +11777,18 @@ impl ::protobuf::Message for UninstallPluginRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.identifier.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.identifier);
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.version);
+        }
+        for value in &self.features {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.plugins_enabled != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -5023,8 +11796,17 @@ impl ::protobuf::Message for UninstallPluginRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.identifier.is_empty() {
-            os.write_string(1, &self.identifier)?;
+        if !self.version.is_empty() {
+            os.write_string(1, &self.version)?;
+        }
+        for v in &self.features {
+            os.write_string(2, &v)?;
+        };
+        if self.plugins_enabled != false {
+            os.write_bool(3, self.plugins_enabled)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5038,81 +11820,117 @@ impl ::protobuf::Message for UninstallPluginRequest {
         &mut self.special_fields
     }
 
-    fn new() -> UninstallPluginRequest {
-        UninstallPluginRequest::new()
+    fn new() -> ServerInfoResponse {
+        ServerInfoResponse::new()
     }
 
     fn clear(&mut self) {
-        self.identifier.clear();
+        self.version.clear();
+        self.features.clear();
+        self.plugins_enabled = false;
+        self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static UninstallPluginRequest {
-        static instance: UninstallPluginRequest = UninstallPluginRequest {
-            identifier: ::std::string::String::new(),
+    fn default_instance() -> &'static ServerInfoResponse {
+        static instance: ServerInfoResponse = ServerInfoResponse {
+            version: ::std::string::String::new(),
+            features: ::std::vec::Vec::new(),
+            plugins_enabled: false,
+            error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for UninstallPluginRequest {
+impl ::protobuf::MessageFull for ServerInfoResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("UninstallPluginRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ServerInfoResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for UninstallPluginRequest {
+impl ::std::fmt::Display for ServerInfoResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UninstallPluginRequest {
+impl ::protobuf::reflect::ProtobufValue for ServerInfoResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:UninstallPluginResponse)
+///  A change to a module stored in Modsurfer.
+// @@protoc_insertion_point(message:ModuleEvent)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct UninstallPluginResponse {
+pub struct ModuleEvent {
     // message fields
-    // @@protoc_insertion_point(field:UninstallPluginResponse.error)
-    pub error: ::protobuf::MessageField<Error>,
+    ///  monotonically increasing ID of the event, used as a cursor by subscribers
+    // @@protoc_insertion_point(field:ModuleEvent.id)
+    pub id: u64,
+    // @@protoc_insertion_point(field:ModuleEvent.kind)
+    pub kind: ::protobuf::EnumOrUnknown<ModuleEventKind>,
+    // @@protoc_insertion_point(field:ModuleEvent.module_id)
+    pub module_id: i64,
+    // @@protoc_insertion_point(field:ModuleEvent.hash)
+    pub hash: ::std::string::String,
+    // @@protoc_insertion_point(field:ModuleEvent.occurred_at)
+    pub occurred_at: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
     // special fields
-    // @@protoc_insertion_point(special_field:UninstallPluginResponse.special_fields)
+    // @@protoc_insertion_point(special_field:ModuleEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a UninstallPluginResponse {
-    fn default() -> &'a UninstallPluginResponse {
-        <UninstallPluginResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ModuleEvent {
+    fn default() -> &'a ModuleEvent {
+        <ModuleEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl UninstallPluginResponse {
-    pub fn new() -> UninstallPluginResponse {
+impl ModuleEvent {
+    pub fn new() -> ModuleEvent {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
-            "error",
-            |m: &UninstallPluginResponse| { &m.error },
-            |m: &mut UninstallPluginResponse| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ModuleEvent| { &m.id },
+            |m: &mut ModuleEvent| { &mut m.id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UninstallPluginResponse>(
-            "UninstallPluginResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &ModuleEvent| { &m.kind },
+            |m: &mut ModuleEvent| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "module_id",
+            |m: &ModuleEvent| { &m.module_id },
+            |m: &mut ModuleEvent| { &mut m.module_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash",
+            |m: &ModuleEvent| { &m.hash },
+            |m: &mut ModuleEvent| { &mut m.hash },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "occurred_at",
+            |m: &ModuleEvent| { &m.occurred_at },
+            |m: &mut ModuleEvent| { &mut m.occurred_at },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModuleEvent>(
+            "ModuleEvent",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for UninstallPluginResponse {
-    const NAME: &'static str = "UninstallPluginResponse";
+impl ::protobuf::Message for ModuleEvent {
+    const NAME: &'static str = "ModuleEvent";
 
     fn is_initialized(&self) -> bool {
         true
@@ -5121,8 +11939,20 @@ impl ::protobuf::Message for UninstallPluginResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
+                8 => {
+                    self.id = is.read_uint64()?;
+                },
+                16 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.module_id = is.read_int64()?;
+                },
+                34 => {
+                    self.hash = is.read_string()?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.occurred_at)?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -5136,7 +11966,19 @@ impl ::protobuf::Message for UninstallPluginResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.error.as_ref() {
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.id);
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED) {
+            my_size += ::protobuf::rt::int32_size(2, self.kind.value());
+        }
+        if self.module_id != 0 {
+            my_size += ::protobuf::rt::int64_size(3, self.module_id);
+        }
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.hash);
+        }
+        if let Some(v) = self.occurred_at.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
@@ -5146,8 +11988,20 @@ impl ::protobuf::Message for UninstallPluginResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if self.module_id != 0 {
+            os.write_int64(3, self.module_id)?;
+        }
+        if !self.hash.is_empty() {
+            os.write_string(4, &self.hash)?;
+        }
+        if let Some(v) = self.occurred_at.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5161,103 +12015,124 @@ impl ::protobuf::Message for UninstallPluginResponse {
         &mut self.special_fields
     }
 
-    fn new() -> UninstallPluginResponse {
-        UninstallPluginResponse::new()
+    fn new() -> ModuleEvent {
+        ModuleEvent::new()
     }
 
     fn clear(&mut self) {
-        self.error.clear();
+        self.id = 0;
+        self.kind = ::protobuf::EnumOrUnknown::new(ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED);
+        self.module_id = 0;
+        self.hash.clear();
+        self.occurred_at.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static UninstallPluginResponse {
-        static instance: UninstallPluginResponse = UninstallPluginResponse {
-            error: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static ModuleEvent {
+        static instance: ModuleEvent = ModuleEvent {
+            id: 0,
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            module_id: 0,
+            hash: ::std::string::String::new(),
+            occurred_at: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for UninstallPluginResponse {
+impl ::protobuf::MessageFull for ModuleEvent {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("UninstallPluginResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ModuleEvent").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for UninstallPluginResponse {
+impl ::std::fmt::Display for ModuleEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UninstallPluginResponse {
+impl ::protobuf::reflect::ProtobufValue for ModuleEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-///  POST /api/v1/plugin:
-// @@protoc_insertion_point(message:CallPluginRequest)
+///  `POST /api/v1/events:`
+///  Return the module events which occurred after `after_event_id` and match the filters. If no
+///  events are available, the server holds the request open for up to `wait_seconds` (long-poll)
+///  before returning an empty list of events.
+// @@protoc_insertion_point(message:SubscribeRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CallPluginRequest {
+pub struct SubscribeRequest {
     // message fields
-    // @@protoc_insertion_point(field:CallPluginRequest.identifier)
-    pub identifier: ::std::string::String,
-    // @@protoc_insertion_point(field:CallPluginRequest.function_name)
-    pub function_name: ::std::string::String,
-    // @@protoc_insertion_point(field:CallPluginRequest.input)
-    pub input: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:CallPluginRequest.hash)
+    ///  when unset, only events occurring after the request is received are returned
+    // @@protoc_insertion_point(field:SubscribeRequest.after_event_id)
+    pub after_event_id: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:SubscribeRequest.wait_seconds)
+    pub wait_seconds: u32,
+    ///  only return events of these kinds (all kinds if empty)
+    // @@protoc_insertion_point(field:SubscribeRequest.kinds)
+    pub kinds: ::std::vec::Vec<::protobuf::EnumOrUnknown<ModuleEventKind>>,
+    ///  only return events for modules with all of these tags
+    // @@protoc_insertion_point(field:SubscribeRequest.tags)
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:SubscribeRequest.hash)
     pub hash: ::std::option::Option<::std::string::String>,
     // special fields
-    // @@protoc_insertion_point(special_field:CallPluginRequest.special_fields)
+    // @@protoc_insertion_point(special_field:SubscribeRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CallPluginRequest {
-    fn default() -> &'a CallPluginRequest {
-        <CallPluginRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SubscribeRequest {
+    fn default() -> &'a SubscribeRequest {
+        <SubscribeRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CallPluginRequest {
-    pub fn new() -> CallPluginRequest {
+impl SubscribeRequest {
+    pub fn new() -> SubscribeRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "identifier",
-            |m: &CallPluginRequest| { &m.identifier },
-            |m: &mut CallPluginRequest| { &mut m.identifier },
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "after_event_id",
+            |m: &SubscribeRequest| { &m.after_event_id },
+            |m: &mut SubscribeRequest| { &mut m.after_event_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "function_name",
-            |m: &CallPluginRequest| { &m.function_name },
-            |m: &mut CallPluginRequest| { &mut m.function_name },
+            "wait_seconds",
+            |m: &SubscribeRequest| { &m.wait_seconds },
+            |m: &mut SubscribeRequest| { &mut m.wait_seconds },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "input",
-            |m: &CallPluginRequest| { &m.input },
-            |m: &mut CallPluginRequest| { &mut m.input },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "kinds",
+            |m: &SubscribeRequest| { &m.kinds },
+            |m: &mut SubscribeRequest| { &mut m.kinds },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &SubscribeRequest| { &m.tags },
+            |m: &mut SubscribeRequest| { &mut m.tags },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
             "hash",
-            |m: &CallPluginRequest| { &m.hash },
-            |m: &mut CallPluginRequest| { &mut m.hash },
+            |m: &SubscribeRequest| { &m.hash },
+            |m: &mut SubscribeRequest| { &mut m.hash },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CallPluginRequest>(
-            "CallPluginRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SubscribeRequest>(
+            "SubscribeRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CallPluginRequest {
-    const NAME: &'static str = "CallPluginRequest";
+impl ::protobuf::Message for SubscribeRequest {
+    const NAME: &'static str = "SubscribeRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -5266,16 +12141,22 @@ impl ::protobuf::Message for CallPluginRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.identifier = is.read_string()?;
+                8 => {
+                    self.after_event_id = ::std::option::Option::Some(is.read_uint64()?);
                 },
-                18 => {
-                    self.function_name = is.read_string()?;
+                16 => {
+                    self.wait_seconds = is.read_uint32()?;
+                },
+                24 => {
+                    self.kinds.push(is.read_enum_or_unknown()?);
                 },
                 26 => {
-                    self.input = is.read_bytes()?;
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.kinds)?
                 },
                 34 => {
+                    self.tags.push(is.read_string()?);
+                },
+                42 => {
                     self.hash = ::std::option::Option::Some(is.read_string()?);
                 },
                 tag => {
@@ -5290,17 +12171,20 @@ impl ::protobuf::Message for CallPluginRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.identifier.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.identifier);
-        }
-        if !self.function_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.function_name);
+        if let Some(v) = self.after_event_id {
+            my_size += ::protobuf::rt::uint64_size(1, v);
         }
-        if !self.input.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.input);
+        if self.wait_seconds != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.wait_seconds);
         }
+        for value in &self.kinds {
+            my_size += ::protobuf::rt::int32_size(3, value.value());
+        };
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
         if let Some(v) = self.hash.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
+            my_size += ::protobuf::rt::string_size(5, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -5308,17 +12192,20 @@ impl ::protobuf::Message for CallPluginRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.identifier.is_empty() {
-            os.write_string(1, &self.identifier)?;
-        }
-        if !self.function_name.is_empty() {
-            os.write_string(2, &self.function_name)?;
+        if let Some(v) = self.after_event_id {
+            os.write_uint64(1, v)?;
         }
-        if !self.input.is_empty() {
-            os.write_bytes(3, &self.input)?;
+        if self.wait_seconds != 0 {
+            os.write_uint32(2, self.wait_seconds)?;
         }
+        for v in &self.kinds {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        for v in &self.tags {
+            os.write_string(4, &v)?;
+        };
         if let Some(v) = self.hash.as_ref() {
-            os.write_string(4, v)?;
+            os.write_string(5, v)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5332,23 +12219,25 @@ impl ::protobuf::Message for CallPluginRequest {
         &mut self.special_fields
     }
 
-    fn new() -> CallPluginRequest {
-        CallPluginRequest::new()
+    fn new() -> SubscribeRequest {
+        SubscribeRequest::new()
     }
 
     fn clear(&mut self) {
-        self.identifier.clear();
-        self.function_name.clear();
-        self.input.clear();
+        self.after_event_id = ::std::option::Option::None;
+        self.wait_seconds = 0;
+        self.kinds.clear();
+        self.tags.clear();
         self.hash = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CallPluginRequest {
-        static instance: CallPluginRequest = CallPluginRequest {
-            identifier: ::std::string::String::new(),
-            function_name: ::std::string::String::new(),
-            input: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SubscribeRequest {
+        static instance: SubscribeRequest = SubscribeRequest {
+            after_event_id: ::std::option::Option::None,
+            wait_seconds: 0,
+            kinds: ::std::vec::Vec::new(),
+            tags: ::std::vec::Vec::new(),
             hash: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -5356,70 +12245,80 @@ impl ::protobuf::Message for CallPluginRequest {
     }
 }
 
-impl ::protobuf::MessageFull for CallPluginRequest {
+impl ::protobuf::MessageFull for SubscribeRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CallPluginRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SubscribeRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CallPluginRequest {
+impl ::std::fmt::Display for SubscribeRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CallPluginRequest {
+impl ::protobuf::reflect::ProtobufValue for SubscribeRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:CallPluginResponse)
+///  The message returned in response to a `SubscribeRequest`.
+// @@protoc_insertion_point(message:SubscribeResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CallPluginResponse {
+pub struct SubscribeResponse {
     // message fields
-    // @@protoc_insertion_point(field:CallPluginResponse.output)
-    pub output: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:CallPluginResponse.error)
+    // @@protoc_insertion_point(field:SubscribeResponse.events)
+    pub events: ::std::vec::Vec<ModuleEvent>,
+    ///  the ID of the latest event known to the server, to be used as `after_event_id` in the next
+    ///  request
+    // @@protoc_insertion_point(field:SubscribeResponse.last_event_id)
+    pub last_event_id: u64,
+    // @@protoc_insertion_point(field:SubscribeResponse.error)
     pub error: ::protobuf::MessageField<Error>,
     // special fields
-    // @@protoc_insertion_point(special_field:CallPluginResponse.special_fields)
+    // @@protoc_insertion_point(special_field:SubscribeResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CallPluginResponse {
-    fn default() -> &'a CallPluginResponse {
-        <CallPluginResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SubscribeResponse {
+    fn default() -> &'a SubscribeResponse {
+        <SubscribeResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CallPluginResponse {
-    pub fn new() -> CallPluginResponse {
+impl SubscribeResponse {
+    pub fn new() -> SubscribeResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &SubscribeResponse| { &m.events },
+            |m: &mut SubscribeResponse| { &mut m.events },
+        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "output",
-            |m: &CallPluginResponse| { &m.output },
-            |m: &mut CallPluginResponse| { &mut m.output },
+            "last_event_id",
+            |m: &SubscribeResponse| { &m.last_event_id },
+            |m: &mut SubscribeResponse| { &mut m.last_event_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Error>(
             "error",
-            |m: &CallPluginResponse| { &m.error },
-            |m: &mut CallPluginResponse| { &mut m.error },
+            |m: &SubscribeResponse| { &m.error },
+            |m: &mut SubscribeResponse| { &mut m.error },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CallPluginResponse>(
-            "CallPluginResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SubscribeResponse>(
+            "SubscribeResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CallPluginResponse {
-    const NAME: &'static str = "CallPluginResponse";
+impl ::protobuf::Message for SubscribeResponse {
+    const NAME: &'static str = "SubscribeResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -5429,9 +12328,12 @@ impl ::protobuf::Message for CallPluginResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.output = is.read_bytes()?;
+                    self.events.push(is.read_message()?);
                 },
-                18 => {
+                16 => {
+                    self.last_event_id = is.read_uint64()?;
+                },
+                26 => {
                     ::protobuf::rt::read_singular_message_into_field(is, &mut self.error)?;
                 },
                 tag => {
@@ -5446,8 +12348,12 @@ impl ::protobuf::Message for CallPluginResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.output.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.output);
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.last_event_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.last_event_id);
         }
         if let Some(v) = self.error.as_ref() {
             let len = v.compute_size();
@@ -5459,11 +12365,14 @@ impl ::protobuf::Message for CallPluginResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.output.is_empty() {
-            os.write_bytes(1, &self.output)?;
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.last_event_id != 0 {
+            os.write_uint64(2, self.last_event_id)?;
         }
         if let Some(v) = self.error.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5477,19 +12386,21 @@ impl ::protobuf::Message for CallPluginResponse {
         &mut self.special_fields
     }
 
-    fn new() -> CallPluginResponse {
-        CallPluginResponse::new()
+    fn new() -> SubscribeResponse {
+        SubscribeResponse::new()
     }
 
     fn clear(&mut self) {
-        self.output.clear();
+        self.events.clear();
+        self.last_event_id = 0;
         self.error.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CallPluginResponse {
-        static instance: CallPluginResponse = CallPluginResponse {
-            output: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SubscribeResponse {
+        static instance: SubscribeResponse = SubscribeResponse {
+            events: ::std::vec::Vec::new(),
+            last_event_id: 0,
             error: ::protobuf::MessageField::none(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -5497,20 +12408,20 @@ impl ::protobuf::Message for CallPluginResponse {
     }
 }
 
-impl ::protobuf::MessageFull for CallPluginResponse {
+impl ::protobuf::MessageFull for SubscribeResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CallPluginResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SubscribeResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CallPluginResponse {
+impl ::std::fmt::Display for SubscribeResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CallPluginResponse {
+impl ::protobuf::reflect::ProtobufValue for SubscribeResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -5533,6 +12444,32 @@ pub enum ValType {
     FuncRef = 5,
     // @@protoc_insertion_point(enum_value:ValType.ExternRef)
     ExternRef = 6,
+    // @@protoc_insertion_point(enum_value:ValType.Bool)
+    Bool = 7,
+    // @@protoc_insertion_point(enum_value:ValType.S8)
+    S8 = 8,
+    // @@protoc_insertion_point(enum_value:ValType.U8)
+    U8 = 9,
+    // @@protoc_insertion_point(enum_value:ValType.S16)
+    S16 = 10,
+    // @@protoc_insertion_point(enum_value:ValType.U16)
+    U16 = 11,
+    // @@protoc_insertion_point(enum_value:ValType.S32)
+    S32 = 12,
+    // @@protoc_insertion_point(enum_value:ValType.U32)
+    U32 = 13,
+    // @@protoc_insertion_point(enum_value:ValType.S64)
+    S64 = 14,
+    // @@protoc_insertion_point(enum_value:ValType.U64)
+    U64 = 15,
+    // @@protoc_insertion_point(enum_value:ValType.Float32)
+    Float32 = 16,
+    // @@protoc_insertion_point(enum_value:ValType.Float64)
+    Float64 = 17,
+    // @@protoc_insertion_point(enum_value:ValType.Char)
+    Char = 18,
+    // @@protoc_insertion_point(enum_value:ValType.String)
+    String = 19,
 }
 
 impl ::protobuf::Enum for ValType {
@@ -5551,6 +12488,19 @@ impl ::protobuf::Enum for ValType {
             4 => ::std::option::Option::Some(ValType::V128),
             5 => ::std::option::Option::Some(ValType::FuncRef),
             6 => ::std::option::Option::Some(ValType::ExternRef),
+            7 => ::std::option::Option::Some(ValType::Bool),
+            8 => ::std::option::Option::Some(ValType::S8),
+            9 => ::std::option::Option::Some(ValType::U8),
+            10 => ::std::option::Option::Some(ValType::S16),
+            11 => ::std::option::Option::Some(ValType::U16),
+            12 => ::std::option::Option::Some(ValType::S32),
+            13 => ::std::option::Option::Some(ValType::U32),
+            14 => ::std::option::Option::Some(ValType::S64),
+            15 => ::std::option::Option::Some(ValType::U64),
+            16 => ::std::option::Option::Some(ValType::Float32),
+            17 => ::std::option::Option::Some(ValType::Float64),
+            18 => ::std::option::Option::Some(ValType::Char),
+            19 => ::std::option::Option::Some(ValType::String),
             _ => ::std::option::Option::None
         }
     }
@@ -5564,6 +12514,19 @@ impl ::protobuf::Enum for ValType {
             "V128" => ::std::option::Option::Some(ValType::V128),
             "FuncRef" => ::std::option::Option::Some(ValType::FuncRef),
             "ExternRef" => ::std::option::Option::Some(ValType::ExternRef),
+            "Bool" => ::std::option::Option::Some(ValType::Bool),
+            "S8" => ::std::option::Option::Some(ValType::S8),
+            "U8" => ::std::option::Option::Some(ValType::U8),
+            "S16" => ::std::option::Option::Some(ValType::S16),
+            "U16" => ::std::option::Option::Some(ValType::U16),
+            "S32" => ::std::option::Option::Some(ValType::S32),
+            "U32" => ::std::option::Option::Some(ValType::U32),
+            "S64" => ::std::option::Option::Some(ValType::S64),
+            "U64" => ::std::option::Option::Some(ValType::U64),
+            "Float32" => ::std::option::Option::Some(ValType::Float32),
+            "Float64" => ::std::option::Option::Some(ValType::Float64),
+            "Char" => ::std::option::Option::Some(ValType::Char),
+            "String" => ::std::option::Option::Some(ValType::String),
             _ => ::std::option::Option::None
         }
     }
@@ -5576,6 +12539,19 @@ impl ::protobuf::Enum for ValType {
         ValType::V128,
         ValType::FuncRef,
         ValType::ExternRef,
+        ValType::Bool,
+        ValType::S8,
+        ValType::U8,
+        ValType::S16,
+        ValType::U16,
+        ValType::S32,
+        ValType::U32,
+        ValType::S64,
+        ValType::U64,
+        ValType::Float32,
+        ValType::Float64,
+        ValType::Char,
+        ValType::String,
     ];
 }
 
@@ -5789,6 +12765,8 @@ pub enum Field {
     Sha256 = 6,
     // @@protoc_insertion_point(enum_value:Field.Complexity)
     Complexity = 7,
+    // @@protoc_insertion_point(enum_value:Field.RiskScore)
+    RiskScore = 8,
 }
 
 impl ::protobuf::Enum for Field {
@@ -5808,6 +12786,7 @@ impl ::protobuf::Enum for Field {
             5 => ::std::option::Option::Some(Field::ExportsCount),
             6 => ::std::option::Option::Some(Field::Sha256),
             7 => ::std::option::Option::Some(Field::Complexity),
+            8 => ::std::option::Option::Some(Field::RiskScore),
             _ => ::std::option::Option::None
         }
     }
@@ -5822,6 +12801,7 @@ impl ::protobuf::Enum for Field {
             "ExportsCount" => ::std::option::Option::Some(Field::ExportsCount),
             "Sha256" => ::std::option::Option::Some(Field::Sha256),
             "Complexity" => ::std::option::Option::Some(Field::Complexity),
+            "RiskScore" => ::std::option::Option::Some(Field::RiskScore),
             _ => ::std::option::Option::None
         }
     }
@@ -5835,6 +12815,7 @@ impl ::protobuf::Enum for Field {
         Field::ExportsCount,
         Field::Sha256,
         Field::Complexity,
+        Field::RiskScore,
     ];
 }
 
@@ -5862,9 +12843,73 @@ impl Field {
     }
 }
 
-///  Represents the expected outcome of an AuditModulesRequest. If PASS is provided, then
-///  the audit returns modules which conform to the checkfile. If FAIL is provided, then
-///  the audit returns modules which do not conform to the checkfile.
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:RiskLevel)
+pub enum RiskLevel {
+    // @@protoc_insertion_point(enum_value:RiskLevel.LOW)
+    LOW = 0,
+    // @@protoc_insertion_point(enum_value:RiskLevel.MEDIUM)
+    MEDIUM = 1,
+    // @@protoc_insertion_point(enum_value:RiskLevel.HIGH)
+    HIGH = 2,
+}
+
+impl ::protobuf::Enum for RiskLevel {
+    const NAME: &'static str = "RiskLevel";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RiskLevel> {
+        match value {
+            0 => ::std::option::Option::Some(RiskLevel::LOW),
+            1 => ::std::option::Option::Some(RiskLevel::MEDIUM),
+            2 => ::std::option::Option::Some(RiskLevel::HIGH),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<RiskLevel> {
+        match str {
+            "LOW" => ::std::option::Option::Some(RiskLevel::LOW),
+            "MEDIUM" => ::std::option::Option::Some(RiskLevel::MEDIUM),
+            "HIGH" => ::std::option::Option::Some(RiskLevel::HIGH),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [RiskLevel] = &[
+        RiskLevel::LOW,
+        RiskLevel::MEDIUM,
+        RiskLevel::HIGH,
+    ];
+}
+
+impl ::protobuf::EnumFull for RiskLevel {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("RiskLevel").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for RiskLevel {
+    fn default() -> Self {
+        RiskLevel::LOW
+    }
+}
+
+impl RiskLevel {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<RiskLevel>("RiskLevel")
+    }
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:AuditOutcome)
 pub enum AuditOutcome {
@@ -5927,6 +12972,84 @@ impl AuditOutcome {
     }
 }
 
+///  The kinds of changes to modules which are reported to subscribers.
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:ModuleEventKind)
+pub enum ModuleEventKind {
+    // @@protoc_insertion_point(enum_value:ModuleEventKind.MODULE_EVENT_KIND_UNSPECIFIED)
+    MODULE_EVENT_KIND_UNSPECIFIED = 0,
+    // @@protoc_insertion_point(enum_value:ModuleEventKind.CREATED)
+    CREATED = 1,
+    // @@protoc_insertion_point(enum_value:ModuleEventKind.UPDATED)
+    UPDATED = 2,
+    // @@protoc_insertion_point(enum_value:ModuleEventKind.DELETED)
+    DELETED = 3,
+    // @@protoc_insertion_point(enum_value:ModuleEventKind.YANKED)
+    YANKED = 4,
+}
+
+impl ::protobuf::Enum for ModuleEventKind {
+    const NAME: &'static str = "ModuleEventKind";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ModuleEventKind> {
+        match value {
+            0 => ::std::option::Option::Some(ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED),
+            1 => ::std::option::Option::Some(ModuleEventKind::CREATED),
+            2 => ::std::option::Option::Some(ModuleEventKind::UPDATED),
+            3 => ::std::option::Option::Some(ModuleEventKind::DELETED),
+            4 => ::std::option::Option::Some(ModuleEventKind::YANKED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ModuleEventKind> {
+        match str {
+            "MODULE_EVENT_KIND_UNSPECIFIED" => ::std::option::Option::Some(ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED),
+            "CREATED" => ::std::option::Option::Some(ModuleEventKind::CREATED),
+            "UPDATED" => ::std::option::Option::Some(ModuleEventKind::UPDATED),
+            "DELETED" => ::std::option::Option::Some(ModuleEventKind::DELETED),
+            "YANKED" => ::std::option::Option::Some(ModuleEventKind::YANKED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ModuleEventKind] = &[
+        ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED,
+        ModuleEventKind::CREATED,
+        ModuleEventKind::UPDATED,
+        ModuleEventKind::DELETED,
+        ModuleEventKind::YANKED,
+    ];
+}
+
+impl ::protobuf::EnumFull for ModuleEventKind {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ModuleEventKind").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for ModuleEventKind {
+    fn default() -> Self {
+        ModuleEventKind::MODULE_EVENT_KIND_UNSPECIFIED
+    }
+}
+
+impl ModuleEventKind {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ModuleEventKind>("ModuleEventKind")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x12proto/v1/api.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"d\n\x08\
     Function\x12\x20\n\x06params\x18\x01\x20\x03(\x0e2\x08.ValTypeR\x06param\
@@ -5934,7 +13057,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12\n\x04name\x18\x03\x20\x01(\tR\x04name\"H\n\x06Import\x12\x1f\n\x0bm\
     odule_name\x18\x01\x20\x01(\tR\nmoduleName\x12\x1d\n\x04func\x18\x02\x20\
     \x01(\x0b2\t.FunctionR\x04func\"'\n\x06Export\x12\x1d\n\x04func\x18\x01\
-    \x20\x01(\x0b2\t.FunctionR\x04func\"\x85\x05\n\x06Module\x12\x0e\n\x02id\
+    \x20\x01(\x0b2\t.FunctionR\x04func\"\xb2\t\n\x06Module\x12\x0e\n\x02id\
     \x18\x01\x20\x01(\x03R\x02id\x12\x12\n\x04hash\x18\x03\x20\x01(\tR\x04ha\
     sh\x12!\n\x07imports\x18\x04\x20\x03(\x0b2\x07.ImportR\x07imports\x12!\n\
     \x07exports\x18\x05\x20\x03(\x0b2\x07.ExportR\x07exports\x12\x12\n\x04si\
@@ -5946,663 +13069,1449 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x18\x0b\x20\x03(\tR\x07strings\x12#\n\ncomplexity\x18\r\x20\x01(\rH\0R\
     \ncomplexity\x88\x01\x01\x12\x19\n\x05graph\x18\x0e\x20\x01(\x0cH\x01R\
     \x05graph\x88\x01\x01\x12D\n\x0ffunction_hashes\x18\x0f\x20\x03(\x0b2\
-    \x1b.Module.FunctionHashesEntryR\x0efunctionHashes\x1a;\n\rMetadataEntry\
-    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\
-    \x20\x01(\tR\x05value:\x028\x01\x1aA\n\x13FunctionHashesEntry\x12\x10\n\
-    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
-    R\x05value:\x028\x01B\r\n\x0b_complexityB\x08\n\x06_graph\"<\n\x0bModule\
-    Graph\x12\x0e\n\x02id\x18\x01\x20\x01(\x03R\x02id\x12\x1d\n\njson_bytes\
-    \x18\x02\x20\x01(\x0cR\tjsonBytes\"5\n\x05Error\x12\x12\n\x04code\x18\
-    \x01\x20\x01(\x05R\x04code\x12\x18\n\x07message\x18\x02\x20\x01(\tR\x07m\
-    essage\":\n\nPagination\x12\x14\n\x05limit\x18\x01\x20\x01(\rR\x05limit\
-    \x12\x16\n\x06offset\x18\x02\x20\x01(\rR\x06offset\"N\n\x04Sort\x12(\n\t\
-    direction\x18\x01\x20\x01(\x0e2\n.DirectionR\tdirection\x12\x1c\n\x05fie\
-    ld\x18\x02\x20\x01(\x0e2\x06.FieldR\x05field\"\xd4\x01\n\x13CreateModule\
-    Request\x12\x12\n\x04wasm\x18\x01\x20\x01(\x0cR\x04wasm\x12>\n\x08metada\
-    ta\x18\x02\x20\x03(\x0b2\".CreateModuleRequest.MetadataEntryR\x08metadat\
-    a\x12\x1f\n\x08location\x18\x03\x20\x01(\tH\0R\x08location\x88\x01\x01\
+    \x1b.Module.FunctionHashesEntryR\x0efunctionHashes\x12\x12\n\x04tags\x18\
+    \x10\x20\x03(\tR\x04tags\x12#\n\x08memories\x18\x11\x20\x03(\x0b2\x07.Me\
+    moryR\x08memories\x12\x1e\n\x06tables\x18\x12\x20\x03(\x0b2\x06.TableR\
+    \x06tables\x12!\n\x07globals\x18\x13\x20\x03(\x0b2\x07.GlobalR\x07global\
+    s\x127\n\x0fcustom_sections\x18\x14\x20\x03(\x0b2\x0e.CustomSectionR\x0e\
+    customSections\x12G\n\x10function_metrics\x18\x15\x20\x03(\x0b2\x1c.Modu\
+    le.FunctionMetricsEntryR\x0ffunctionMetrics\x12#\n\ttoolchain\x18\x16\
+    \x20\x03(\x0b2\x05.ToolR\ttoolchain\x12\x1d\n\x07version\x18\x17\x20\x01\
+    (\tH\x02R\x07version\x88\x01\x01\x12+\n\nprovenance\x18\x18\x20\x01(\x0b\
+    2\x0b.ProvenanceR\nprovenance\x12+\n\nquarantine\x18\x19\x20\x01(\x0b2\
+    \x0b.QuarantineR\nquarantine\x12-\n\tintegrity\x18\x1a\x20\x03(\x0b2\x0f\
+    .IntegrityIssueR\tintegrity\x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\
+    \x028\x01\x1aA\n\x13FunctionHashesEntry\x12\x10\n\x03key\x18\x01\x20\x01\
+    (\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\
+    \x1aT\n\x14FunctionMetricsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03\
+    key\x12&\n\x05value\x18\x02\x20\x01(\x0b2\x10.FunctionMetricsR\x05value:\
+    \x028\x01B\r\n\x0b_complexityB\x08\n\x06_graphB\n\n\x08_version\"V\n\x0e\
+    IntegrityIssue\x12\x12\n\x04kind\x18\x01\x20\x01(\tR\x04kind\x12\x16\n\
+    \x06offset\x18\x02\x20\x01(\x04R\x06offset\x12\x18\n\x07message\x18\x03\
+    \x20\x01(\tR\x07message\"g\n\nQuarantine\x12\x16\n\x06reason\x18\x01\x20\
+    \x01(\tR\x06reason\x12A\n\x0equarantined_at\x18\x02\x20\x01(\x0b2\x1a.go\
+    ogle.protobuf.TimestampR\rquarantinedAt\"P\n\nProvenance\x12\x1a\n\x08li\
+    censes\x18\x01\x20\x03(\tR\x08licenses\x12&\n\tlibraries\x18\x02\x20\x03\
+    (\x0b2\x08.LibraryR\tlibraries\"7\n\x07Library\x12\x12\n\x04name\x18\x01\
+    \x20\x01(\tR\x04name\x12\x18\n\x07version\x18\x02\x20\x01(\tR\x07version\
+    \"\x9d\x01\n\x06Memory\x12\x18\n\x07initial\x18\x01\x20\x01(\x04R\x07ini\
+    tial\x12\x1d\n\x07maximum\x18\x02\x20\x01(\x04H\0R\x07maximum\x88\x01\
+    \x01\x12\x16\n\x06shared\x18\x03\x20\x01(\x08R\x06shared\x12\x1a\n\x08me\
+    mory64\x18\x04\x20\x01(\x08R\x08memory64\x12\x1a\n\x08imported\x18\x05\
+    \x20\x01(\x08R\x08importedB\n\n\x08_maximum\"\x95\x01\n\x05Table\x12+\n\
+    \x0celement_type\x18\x01\x20\x01(\x0e2\x08.ValTypeR\x0belementType\x12\
+    \x18\n\x07initial\x18\x02\x20\x01(\x04R\x07initial\x12\x1d\n\x07maximum\
+    \x18\x03\x20\x01(\x04H\0R\x07maximum\x88\x01\x01\x12\x1a\n\x08imported\
+    \x18\x04\x20\x01(\x08R\x08importedB\n\n\x08_maximum\"X\n\x06Global\x12\
+    \x18\n\x02ty\x18\x01\x20\x01(\x0e2\x08.ValTypeR\x02ty\x12\x18\n\x07mutab\
+    le\x18\x02\x20\x01(\x08R\x07mutable\x12\x1a\n\x08imported\x18\x03\x20\
+    \x01(\x08R\x08imported\"7\n\rCustomSection\x12\x12\n\x04name\x18\x01\x20\
+    \x01(\tR\x04name\x12\x12\n\x04size\x18\x02\x20\x01(\x04R\x04size\"i\n\
+    \x0fFunctionMetrics\x12\x1e\n\ncomplexity\x18\x01\x20\x01(\rR\ncomplexit\
+    y\x12\"\n\x0cinstructions\x18\x02\x20\x01(\x04R\x0cinstructions\x12\x12\
+    \n\x04size\x18\x03\x20\x01(\x04R\x04size\"4\n\x04Tool\x12\x12\n\x04name\
+    \x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07version\x18\x02\x20\x01(\tR\
+    \x07version\"<\n\x0bModuleGraph\x12\x0e\n\x02id\x18\x01\x20\x01(\x03R\
+    \x02id\x12\x1d\n\njson_bytes\x18\x02\x20\x01(\x0cR\tjsonBytes\"5\n\x05Er\
+    ror\x12\x12\n\x04code\x18\x01\x20\x01(\x05R\x04code\x12\x18\n\x07message\
+    \x18\x02\x20\x01(\tR\x07message\":\n\nPagination\x12\x14\n\x05limit\x18\
+    \x01\x20\x01(\rR\x05limit\x12\x16\n\x06offset\x18\x02\x20\x01(\rR\x06off\
+    set\"N\n\x04Sort\x12(\n\tdirection\x18\x01\x20\x01(\x0e2\n.DirectionR\td\
+    irection\x12\x1c\n\x05field\x18\x02\x20\x01(\x0e2\x06.FieldR\x05field\"\
+    \x93\x02\n\x13CreateModuleRequest\x12\x12\n\x04wasm\x18\x01\x20\x01(\x0c\
+    R\x04wasm\x12>\n\x08metadata\x18\x02\x20\x03(\x0b2\".CreateModuleRequest\
+    .MetadataEntryR\x08metadata\x12\x1f\n\x08location\x18\x03\x20\x01(\tH\0R\
+    \x08location\x88\x01\x01\x12\x12\n\x04tags\x18\x04\x20\x03(\tR\x04tags\
+    \x12\x1d\n\x07version\x18\x05\x20\x01(\tH\x01R\x07version\x88\x01\x01\
     \x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\
     \x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01B\x0b\n\t_location\
-    \"t\n\x14CreateModuleResponse\x12\x1b\n\tmodule_id\x18\x01\x20\x01(\x03R\
-    \x08moduleId\x12\x12\n\x04hash\x18\x02\x20\x01(\tR\x04hash\x12!\n\x05err\
-    or\x18\x03\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_e\
-    rror\"/\n\x10GetModuleRequest\x12\x1b\n\tmodule_id\x18\x01\x20\x01(\x03R\
-    \x08moduleId\"a\n\x11GetModuleResponse\x12\x1f\n\x06module\x18\x01\x20\
-    \x01(\x0b2\x07.ModuleR\x06module\x12!\n\x05error\x18\x02\x20\x01(\x0b2\
-    \x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"\\\n\x12ListModule\
-    sRequest\x12+\n\npagination\x18\x01\x20\x01(\x0b2\x0b.PaginationR\npagin\
-    ation\x12\x19\n\x04sort\x18\x02\x20\x01(\x0b2\x05.SortR\x04sort\"\xc3\
-    \x01\n\x13ListModulesResponse\x12!\n\x07modules\x18\x01\x20\x03(\x0b2\
+    B\n\n\x08_version\"t\n\x14CreateModuleResponse\x12\x1b\n\tmodule_id\x18\
+    \x01\x20\x01(\x03R\x08moduleId\x12\x12\n\x04hash\x18\x02\x20\x01(\tR\x04\
+    hash\x12!\n\x05error\x18\x03\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\
+    \x01\x01B\x08\n\x06_error\"/\n\x10GetModuleRequest\x12\x1b\n\tmodule_id\
+    \x18\x01\x20\x01(\x03R\x08moduleId\"a\n\x11GetModuleResponse\x12\x1f\n\
+    \x06module\x18\x01\x20\x01(\x0b2\x07.ModuleR\x06module\x12!\n\x05error\
+    \x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_err\
+    or\"\\\n\x12ListModulesRequest\x12+\n\npagination\x18\x01\x20\x01(\x0b2\
+    \x0b.PaginationR\npagination\x12\x19\n\x04sort\x18\x02\x20\x01(\x0b2\x05\
+    .SortR\x04sort\"\xc3\x01\n\x13ListModulesResponse\x12!\n\x07modules\x18\
+    \x01\x20\x03(\x0b2\x07.ModuleR\x07modules\x12+\n\npagination\x18\x02\x20\
+    \x01(\x0b2\x0b.PaginationR\npagination\x12\x14\n\x05total\x18\x03\x20\
+    \x01(\x04R\x05total\x12\x19\n\x04sort\x18\x04\x20\x01(\x0b2\x05.SortR\
+    \x04sort\x12!\n\x05error\x18\x05\x20\x01(\x0b2\x06.ErrorH\0R\x05error\
+    \x88\x01\x01B\x08\n\x06_error\"\xdd\x08\n\x14SearchModulesRequest\x12\
+    \x13\n\x02id\x18\x01\x20\x01(\x03H\0R\x02id\x88\x01\x01\x12\x17\n\x04has\
+    h\x18\x03\x20\x01(\tH\x01R\x04hash\x88\x01\x01\x12!\n\x07imports\x18\x04\
+    \x20\x03(\x0b2\x07.ImportR\x07imports\x12!\n\x07exports\x18\x05\x20\x03(\
+    \x0b2\x07.ExportR\x07exports\x12\x1e\n\x08min_size\x18\x06\x20\x01(\x04H\
+    \x02R\x07minSize\x88\x01\x01\x12\x1e\n\x08max_size\x18\x07\x20\x01(\x04H\
+    \x03R\x07maxSize\x88\x01\x01\x12\x1f\n\x08location\x18\x08\x20\x01(\tH\
+    \x04R\x08location\x88\x01\x01\x12=\n\x0fsource_language\x18\t\x20\x01(\
+    \x0e2\x0f.SourceLanguageH\x05R\x0esourceLanguage\x88\x01\x01\x12?\n\x08m\
+    etadata\x18\n\x20\x03(\x0b2#.SearchModulesRequest.MetadataEntryR\x08meta\
+    data\x12H\n\x0finserted_before\x18\x0b\x20\x01(\x0b2\x1a.google.protobuf\
+    .TimestampH\x06R\x0einsertedBefore\x88\x01\x01\x12F\n\x0einserted_after\
+    \x18\x0c\x20\x01(\x0b2\x1a.google.protobuf.TimestampH\x07R\rinsertedAfte\
+    r\x88\x01\x01\x12\x18\n\x07strings\x18\r\x20\x03(\tR\x07strings\x12(\n\r\
+    function_name\x18\x0e\x20\x01(\tH\x08R\x0cfunctionName\x88\x01\x01\x12$\
+    \n\x0bmodule_name\x18\x0f\x20\x01(\tH\tR\nmoduleName\x88\x01\x01\x12+\n\
+    \npagination\x18\x10\x20\x01(\x0b2\x0b.PaginationR\npagination\x12\x19\n\
+    \x04sort\x18\x11\x20\x01(\x0b2\x05.SortR\x04sort\x12\x12\n\x04tags\x18\
+    \x12\x20\x03(\tR\x04tags\x12\x1d\n\x07version\x18\x13\x20\x01(\tH\nR\x07\
+    version\x88\x01\x01\x12*\n\x0emin_complexity\x18\x14\x20\x01(\rH\x0bR\rm\
+    inComplexity\x88\x01\x01\x12*\n\x0emax_complexity\x18\x15\x20\x01(\rH\
+    \x0cR\rmaxComplexity\x88\x01\x01\x12\x1e\n\nnamespaces\x18\x16\x20\x03(\
+    \tR\nnamespaces\x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\
+    \tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01B\
+    \x05\n\x03_idB\x07\n\x05_hashB\x0b\n\t_min_sizeB\x0b\n\t_max_sizeB\x0b\n\
+    \t_locationB\x12\n\x10_source_languageB\x12\n\x10_inserted_beforeB\x11\n\
+    \x0f_inserted_afterB\x10\n\x0e_function_nameB\x0e\n\x0c_module_nameB\n\n\
+    \x08_versionB\x11\n\x0f_min_complexityB\x11\n\x0f_max_complexity\"\xc5\
+    \x01\n\x15SearchModulesResponse\x12!\n\x07modules\x18\x01\x20\x03(\x0b2\
     \x07.ModuleR\x07modules\x12+\n\npagination\x18\x02\x20\x01(\x0b2\x0b.Pag\
     inationR\npagination\x12\x14\n\x05total\x18\x03\x20\x01(\x04R\x05total\
     \x12\x19\n\x04sort\x18\x04\x20\x01(\x0b2\x05.SortR\x04sort\x12!\n\x05err\
     or\x18\x05\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_e\
-    rror\"\x80\x07\n\x14SearchModulesRequest\x12\x13\n\x02id\x18\x01\x20\x01\
-    (\x03H\0R\x02id\x88\x01\x01\x12\x17\n\x04hash\x18\x03\x20\x01(\tH\x01R\
-    \x04hash\x88\x01\x01\x12!\n\x07imports\x18\x04\x20\x03(\x0b2\x07.ImportR\
-    \x07imports\x12!\n\x07exports\x18\x05\x20\x03(\x0b2\x07.ExportR\x07expor\
-    ts\x12\x1e\n\x08min_size\x18\x06\x20\x01(\x04H\x02R\x07minSize\x88\x01\
-    \x01\x12\x1e\n\x08max_size\x18\x07\x20\x01(\x04H\x03R\x07maxSize\x88\x01\
-    \x01\x12\x1f\n\x08location\x18\x08\x20\x01(\tH\x04R\x08location\x88\x01\
-    \x01\x12=\n\x0fsource_language\x18\t\x20\x01(\x0e2\x0f.SourceLanguageH\
-    \x05R\x0esourceLanguage\x88\x01\x01\x12?\n\x08metadata\x18\n\x20\x03(\
-    \x0b2#.SearchModulesRequest.MetadataEntryR\x08metadata\x12H\n\x0finserte\
-    d_before\x18\x0b\x20\x01(\x0b2\x1a.google.protobuf.TimestampH\x06R\x0ein\
-    sertedBefore\x88\x01\x01\x12F\n\x0einserted_after\x18\x0c\x20\x01(\x0b2\
-    \x1a.google.protobuf.TimestampH\x07R\rinsertedAfter\x88\x01\x01\x12\x18\
-    \n\x07strings\x18\r\x20\x03(\tR\x07strings\x12(\n\rfunction_name\x18\x0e\
-    \x20\x01(\tH\x08R\x0cfunctionName\x88\x01\x01\x12$\n\x0bmodule_name\x18\
-    \x0f\x20\x01(\tH\tR\nmoduleName\x88\x01\x01\x12+\n\npagination\x18\x10\
-    \x20\x01(\x0b2\x0b.PaginationR\npagination\x12\x19\n\x04sort\x18\x11\x20\
-    \x01(\x0b2\x05.SortR\x04sort\x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\
-    \x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\
-    \x028\x01B\x05\n\x03_idB\x07\n\x05_hashB\x0b\n\t_min_sizeB\x0b\n\t_max_s\
-    izeB\x0b\n\t_locationB\x12\n\x10_source_languageB\x12\n\x10_inserted_bef\
-    oreB\x11\n\x0f_inserted_afterB\x10\n\x0e_function_nameB\x0e\n\x0c_module\
-    _name\"\xc5\x01\n\x15SearchModulesResponse\x12!\n\x07modules\x18\x01\x20\
-    \x03(\x0b2\x07.ModuleR\x07modules\x12+\n\npagination\x18\x02\x20\x01(\
-    \x0b2\x0b.PaginationR\npagination\x12\x14\n\x05total\x18\x03\x20\x01(\
-    \x04R\x05total\x12\x19\n\x04sort\x18\x04\x20\x01(\x0b2\x05.SortR\x04sort\
-    \x12!\n\x05error\x18\x05\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\
-    \x01B\x08\n\x06_error\"5\n\x14DeleteModulesRequest\x12\x1d\n\nmodule_ids\
-    \x18\x01\x20\x03(\x03R\tmoduleIds\"\xd5\x01\n\x15DeleteModulesResponse\
-    \x12N\n\x0emodule_id_hash\x18\x01\x20\x03(\x0b2(.DeleteModulesResponse.M\
-    oduleIdHashEntryR\x0cmoduleIdHash\x12!\n\x05error\x18\x02\x20\x01(\x0b2\
-    \x06.ErrorH\0R\x05error\x88\x01\x01\x1a?\n\x11ModuleIdHashEntry\x12\x10\
-    \n\x03key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\
-    \x01(\tR\x05value:\x028\x01B\x08\n\x06_error\"\x89\x01\n\x13AuditModules\
-    Request\x12\x1c\n\tcheckfile\x18\x01\x20\x01(\x0cR\tcheckfile\x12'\n\x07\
-    outcome\x18\x02\x20\x01(\x0e2\r.AuditOutcomeR\x07outcome\x12+\n\npaginat\
-    ion\x18\x03\x20\x01(\x0b2\x0b.PaginationR\npagination\"\xb2\x02\n\x14Aud\
-    itModulesResponse\x12b\n\x15invalid_module_report\x18\x01\x20\x03(\x0b2.\
-    .AuditModulesResponse.InvalidModuleReportEntryR\x13invalidModuleReport\
-    \x12+\n\npagination\x18\x02\x20\x01(\x0b2\x0b.PaginationR\npagination\
-    \x12\x14\n\x05total\x18\x03\x20\x01(\x04R\x05total\x12!\n\x05error\x18\
-    \x04\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01\x1aF\n\x18InvalidM\
-    oduleReportEntry\x12\x10\n\x03key\x18\x01\x20\x01(\x03R\x03key\x12\x14\n\
-    \x05value\x18\x02\x20\x01(\x0cR\x05value:\x028\x01B\x08\n\x06_error\"\
-    \x8b\x01\n\x0bDiffRequest\x12\x18\n\x07module1\x18\x01\x20\x01(\x03R\x07\
-    module1\x12\x18\n\x07module2\x18\x02\x20\x01(\x03R\x07module2\x12%\n\x0e\
-    color_terminal\x18\x03\x20\x01(\x08R\rcolorTerminal\x12!\n\x0cwith_conte\
-    xt\x18\x04\x20\x01(\x08R\x0bwithContext\"O\n\x0cDiffResponse\x12\x12\n\
-    \x04diff\x18\x01\x20\x01(\tR\x04diff\x12!\n\x05error\x18\x02\x20\x01(\
-    \x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"~\n\x15Valida\
-    teModuleRequest\x12\x1c\n\tcheckfile\x18\x01\x20\x01(\x0cR\tcheckfile\
-    \x12\x18\n\x06module\x18\x02\x20\x01(\x0cH\0R\x06module\x12\x1d\n\tmodul\
-    e_id\x18\x03\x20\x01(\x03H\0R\x08moduleIdB\x0e\n\x0cmodule_input\"y\n\
-    \x16ValidateModuleResponse\x122\n\x15invalid_module_report\x18\x01\x20\
-    \x01(\x0cR\x13invalidModuleReport\x12!\n\x05error\x18\x02\x20\x01(\x0b2\
-    \x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"4\n\x15GetModuleGr\
-    aphRequest\x12\x1b\n\tmodule_id\x18\x01\x20\x01(\x03R\x08moduleId\"v\n\
-    \x16GetModuleGraphResponse\x12/\n\x0cmodule_graph\x18\x01\x20\x01(\x0b2\
-    \x0c.ModuleGraphR\x0bmoduleGraph\x12!\n\x05error\x18\x02\x20\x01(\x0b2\
-    \x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"\x88\x01\n\x14Inst\
-    allPluginRequest\x12\x1e\n\nidentifier\x18\x01\x20\x01(\tR\nidentifier\
-    \x12\x17\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x88\x01\x01\x12\x1a\n\
-    \x08location\x18\x03\x20\x01(\tR\x08location\x12\x12\n\x04wasm\x18\x04\
-    \x20\x01(\x0cR\x04wasmB\x07\n\x05_name\"X\n\x15InstallPluginResponse\x12\
-    \x12\n\x04hash\x18\x01\x20\x01(\tR\x04hash\x12!\n\x05error\x18\x02\x20\
-    \x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"8\n\x16U\
-    ninstallPluginRequest\x12\x1e\n\nidentifier\x18\x01\x20\x01(\tR\nidentif\
-    ier\"F\n\x17UninstallPluginResponse\x12!\n\x05error\x18\x01\x20\x01(\x0b\
-    2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"\x90\x01\n\x11Cal\
-    lPluginRequest\x12\x1e\n\nidentifier\x18\x01\x20\x01(\tR\nidentifier\x12\
-    #\n\rfunction_name\x18\x02\x20\x01(\tR\x0cfunctionName\x12\x14\n\x05inpu\
-    t\x18\x03\x20\x01(\x0cR\x05input\x12\x17\n\x04hash\x18\x04\x20\x01(\tH\0\
-    R\x04hash\x88\x01\x01B\x07\n\x05_hash\"Y\n\x12CallPluginResponse\x12\x16\
-    \n\x06output\x18\x01\x20\x01(\x0cR\x06output\x12!\n\x05error\x18\x02\x20\
-    \x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error*S\n\x07Va\
-    lType\x12\x07\n\x03I32\x10\0\x12\x07\n\x03I64\x10\x01\x12\x07\n\x03F32\
+    rror\"5\n\x14DeleteModulesRequest\x12\x1d\n\nmodule_ids\x18\x01\x20\x03(\
+    \x03R\tmoduleIds\"\xd5\x01\n\x15DeleteModulesResponse\x12N\n\x0emodule_i\
+    d_hash\x18\x01\x20\x03(\x0b2(.DeleteModulesResponse.ModuleIdHashEntryR\
+    \x0cmoduleIdHash\x12!\n\x05error\x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05\
+    error\x88\x01\x01\x1a?\n\x11ModuleIdHashEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\
+    \x028\x01B\x08\n\x06_error\"C\n\x10TagModuleRequest\x12\x1b\n\tmodule_id\
+    \x18\x01\x20\x01(\x03R\x08moduleId\x12\x12\n\x04tags\x18\x02\x20\x03(\tR\
+    \x04tags\"T\n\x11TagModuleResponse\x12\x12\n\x04tags\x18\x01\x20\x03(\tR\
+    \x04tags\x12!\n\x05error\x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\
+    \x88\x01\x01B\x08\n\x06_error\"p\n\x17QuarantineModuleRequest\x12\x1b\n\
+    \tmodule_id\x18\x01\x20\x01(\x03R\x08moduleId\x12\x20\n\x0bquarantined\
+    \x18\x02\x20\x01(\x08R\x0bquarantined\x12\x16\n\x06reason\x18\x03\x20\
+    \x01(\tR\x06reason\"t\n\x18QuarantineModuleResponse\x12+\n\nquarantine\
+    \x18\x01\x20\x01(\x0b2\x0b.QuarantineR\nquarantine\x12!\n\x05error\x18\
+    \x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"\
+    /\n\tCheckfile\x12\"\n\x08validate\x18\x01\x20\x01(\x0b2\x06.CheckR\x08v\
+    alidate\"\x83\x05\n\x05Check\x12\x15\n\x03url\x18\x01\x20\x01(\tH\0R\x03\
+    url\x88\x01\x01\x12\"\n\nallow_wasi\x18\x02\x20\x01(\x08H\x01R\tallowWas\
+    i\x88\x01\x01\x12'\n\x07imports\x18\x03\x20\x01(\x0b2\r.CheckImportsR\
+    \x07imports\x12'\n\x07exports\x18\x04\x20\x01(\x0b2\r.CheckExportsR\x07e\
+    xports\x12\x1e\n\x04size\x18\x05\x20\x01(\x0b2\n.CheckSizeR\x04size\x120\
+    \n\ncomplexity\x18\x06\x20\x01(\x0b2\x10.CheckComplexityR\ncomplexity\
+    \x12\"\n\nurl_sha256\x18\x07\x20\x01(\tH\x02R\turlSha256\x88\x01\x01\x12\
+    \x1e\n\x04risk\x18\x08\x20\x01(\x0b2\n.CheckRiskR\x04risk\x126\n\x0ccapa\
+    bilities\x18\t\x20\x01(\x0b2\x12.CheckCapabilitiesR\x0ccapabilities\x12*\
+    \n\x08licenses\x18\n\x20\x01(\x0b2\x0e.CheckLicensesR\x08licenses\x12?\n\
+    \x0fvulnerabilities\x18\x0b\x20\x01(\x0b2\x15.CheckVulnerabilitiesR\x0fv\
+    ulnerabilities\x12-\n\tintegrity\x18\x0c\x20\x01(\x0b2\x0f.CheckIntegrit\
+    yR\tintegrity\x121\n\x0bmodule_name\x18\r\x20\x01(\x0b2\x10.CheckModuleN\
+    ameR\nmoduleName\x12*\n\x08location\x18\x0e\x20\x01(\x0b2\x0e.CheckLocat\
+    ionR\x08locationB\x06\n\x04_urlB\r\n\x0b_allow_wasiB\r\n\x0b_url_sha256\
+    \"*\n\x08ValTypes\x12\x1e\n\x05types\x18\x01\x20\x03(\x0e2\x08.ValTypeR\
+    \x05types\"\xc0\x02\n\x0cCheckImports\x12+\n\x07include\x18\x01\x20\x01(\
+    \x0b2\x11.CheckImportItemsR\x07include\x12+\n\x07exclude\x18\x02\x20\x01\
+    (\x0b2\x11.CheckImportItemsR\x07exclude\x12.\n\tnamespace\x18\x03\x20\
+    \x01(\x0b2\x10.CheckNamespacesR\tnamespace\x12\x17\n\x04mode\x18\x04\x20\
+    \x01(\tH\0R\x04mode\x88\x01\x01\x12*\n\x0emax_namespaces\x18\x05\x20\x01\
+    (\rH\x01R\rmaxNamespaces\x88\x01\x01\x12/\n\x11max_per_namespace\x18\x06\
+    \x20\x01(\rH\x02R\x0fmaxPerNamespace\x88\x01\x01B\x07\n\x05_modeB\x11\n\
+    \x0f_max_namespacesB\x14\n\x12_max_per_namespace\":\n\x10CheckImportItem\
+    s\x12&\n\x05items\x18\x01\x20\x03(\x0b2\x10.CheckImportItemR\x05items\"\
+    \x9e\x01\n\x0fCheckImportItem\x12!\n\tnamespace\x18\x01\x20\x01(\tH\0R\t\
+    namespace\x88\x01\x01\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12!\
+    \n\x06params\x18\x03\x20\x01(\x0b2\t.ValTypesR\x06params\x12#\n\x07resul\
+    ts\x18\x04\x20\x01(\x0b2\t.ValTypesR\x07resultsB\x0c\n\n_namespace\"q\n\
+    \x0fCheckNamespaces\x12.\n\x07include\x18\x01\x20\x01(\x0b2\x14.CheckNam\
+    espaceItemsR\x07include\x12.\n\x07exclude\x18\x02\x20\x01(\x0b2\x14.Chec\
+    kNamespaceItemsR\x07exclude\"@\n\x13CheckNamespaceItems\x12)\n\x05items\
+    \x18\x01\x20\x03(\x0b2\x13.CheckNamespaceItemR\x05items\"\xf2\x01\n\x12C\
+    heckNamespaceItem\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x120\n\t\
+    functions\x18\x02\x20\x03(\x0b2\x12.CheckFunctionItemR\tfunctions\x12(\n\
+    \rmax_functions\x18\x03\x20\x01(\rH\0R\x0cmaxFunctions\x88\x01\x01\x12=\
+    \n\x18forbid_unknown_functions\x18\x04\x20\x01(\x08H\x01R\x16forbidUnkno\
+    wnFunctions\x88\x01\x01B\x10\n\x0e_max_functionsB\x1b\n\x19_forbid_unkno\
+    wn_functions\"\x8b\x01\n\x0cCheckExports\x12-\n\x07include\x18\x01\x20\
+    \x01(\x0b2\x13.CheckFunctionItemsR\x07include\x12-\n\x07exclude\x18\x02\
+    \x20\x01(\x0b2\x13.CheckFunctionItemsR\x07exclude\x12\x15\n\x03max\x18\
+    \x03\x20\x01(\rH\0R\x03max\x88\x01\x01B\x06\n\x04_max\">\n\x12CheckFunct\
+    ionItems\x12(\n\x05items\x18\x01\x20\x03(\x0b2\x12.CheckFunctionItemR\
+    \x05items\"\x91\x01\n\x11CheckFunctionItem\x12\x12\n\x04name\x18\x01\x20\
+    \x01(\tR\x04name\x12!\n\x06params\x18\x02\x20\x01(\x0b2\t.ValTypesR\x06p\
+    arams\x12#\n\x07results\x18\x03\x20\x01(\x0b2\t.ValTypesR\x07results\x12\
+    \x17\n\x04hash\x18\x04\x20\x01(\tH\0R\x04hash\x88\x01\x01B\x07\n\x05_has\
+    h\"*\n\tCheckSize\x12\x15\n\x03max\x18\x01\x20\x01(\tH\0R\x03max\x88\x01\
+    \x01B\x06\n\x04_max\"z\n\x0fCheckComplexity\x12*\n\x08max_risk\x18\x01\
+    \x20\x01(\x0e2\n.RiskLevelH\0R\x07maxRisk\x88\x01\x01\x12\x20\n\tmax_sco\
+    re\x18\x02\x20\x01(\rH\x01R\x08maxScore\x88\x01\x01B\x0b\n\t_max_riskB\
+    \x0c\n\n_max_score\";\n\tCheckRisk\x12\x20\n\tmax_score\x18\x01\x20\x01(\
+    \rH\0R\x08maxScore\x88\x01\x01B\x0c\n\n_max_score\"'\n\x11CheckCapabilit\
+    ies\x12\x12\n\x04deny\x18\x01\x20\x03(\tR\x04deny\"#\n\rCheckLicenses\
+    \x12\x12\n\x04deny\x18\x01\x20\x03(\tR\x04deny\"k\n\x14CheckVulnerabilit\
+    ies\x12&\n\x0cmax_severity\x18\x01\x20\x01(\tH\0R\x0bmaxSeverity\x88\x01\
+    \x01\x12\x13\n\x02db\x18\x02\x20\x01(\tH\x01R\x02db\x88\x01\x01B\x0f\n\r\
+    _max_severityB\x05\n\x03_db\"\x84\x02\n\x0eCheckIntegrity\x12;\n\x17allo\
+    w_duplicate_imports\x18\x01\x20\x01(\x08H\0R\x15allowDuplicateImports\
+    \x88\x01\x01\x12;\n\x17allow_duplicate_exports\x18\x02\x20\x01(\x08H\x01\
+    R\x15allowDuplicateExports\x88\x01\x01\x12,\n\x0fallow_malformed\x18\x03\
+    \x20\x01(\x08H\x02R\x0eallowMalformed\x88\x01\x01B\x1a\n\x18_allow_dupli\
+    cate_importsB\x1a\n\x18_allow_duplicate_exportsB\x12\n\x10_allow_malform\
+    ed\"<\n\x0fCheckModuleName\x12\x1d\n\x07pattern\x18\x01\x20\x01(\tH\0R\
+    \x07pattern\x88\x01\x01B\n\n\x08_pattern\"8\n\rCheckLocation\x12'\n\x0fa\
+    llowed_schemes\x18\x01\x20\x03(\tR\x0eallowedSchemes\"\xb5\x01\n\x13Audi\
+    tModulesRequest\x12\x1c\n\tcheckfile\x18\x01\x20\x01(\x0cR\tcheckfile\
+    \x12'\n\x07outcome\x18\x02\x20\x01(\x0e2\r.AuditOutcomeR\x07outcome\x12+\
+    \n\npagination\x18\x03\x20\x01(\x0b2\x0b.PaginationR\npagination\x12*\n\
+    \nvalidation\x18\x04\x20\x01(\x0b2\n.CheckfileR\nvalidation\"\xc2\x03\n\
+    \x14AuditModulesResponse\x12b\n\x15invalid_module_report\x18\x01\x20\x03\
+    (\x0b2..AuditModulesResponse.InvalidModuleReportEntryR\x13invalidModuleR\
+    eport\x12+\n\npagination\x18\x02\x20\x01(\x0b2\x0b.PaginationR\npaginati\
+    on\x12\x14\n\x05total\x18\x03\x20\x01(\x04R\x05total\x12!\n\x05error\x18\
+    \x04\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01\x12M\n\x0emodule_i\
+    d_hash\x18\x05\x20\x03(\x0b2'.AuditModulesResponse.ModuleIdHashEntryR\
+    \x0cmoduleIdHash\x1aF\n\x18InvalidModuleReportEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05va\
+    lue:\x028\x01\x1a?\n\x11ModuleIdHashEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\x03R\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\
+    \x01B\x08\n\x06_error\"\x8b\x01\n\x0bDiffRequest\x12\x18\n\x07module1\
+    \x18\x01\x20\x01(\x03R\x07module1\x12\x18\n\x07module2\x18\x02\x20\x01(\
+    \x03R\x07module2\x12%\n\x0ecolor_terminal\x18\x03\x20\x01(\x08R\rcolorTe\
+    rminal\x12!\n\x0cwith_context\x18\x04\x20\x01(\x08R\x0bwithContext\"O\n\
+    \x0cDiffResponse\x12\x12\n\x04diff\x18\x01\x20\x01(\tR\x04diff\x12!\n\
+    \x05error\x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\
+    \n\x06_error\"\xaa\x01\n\x15ValidateModuleRequest\x12\x1c\n\tcheckfile\
+    \x18\x01\x20\x01(\x0cR\tcheckfile\x12\x18\n\x06module\x18\x02\x20\x01(\
+    \x0cH\0R\x06module\x12\x1d\n\tmodule_id\x18\x03\x20\x01(\x03H\0R\x08modu\
+    leId\x12*\n\nvalidation\x18\x04\x20\x01(\x0b2\n.CheckfileR\nvalidationB\
+    \x0e\n\x0cmodule_input\"y\n\x16ValidateModuleResponse\x122\n\x15invalid_\
+    module_report\x18\x01\x20\x01(\x0cR\x13invalidModuleReport\x12!\n\x05err\
+    or\x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_e\
+    rror\"4\n\x15GetModuleGraphRequest\x12\x1b\n\tmodule_id\x18\x01\x20\x01(\
+    \x03R\x08moduleId\"v\n\x16GetModuleGraphResponse\x12/\n\x0cmodule_graph\
+    \x18\x01\x20\x01(\x0b2\x0c.ModuleGraphR\x0bmoduleGraph\x12!\n\x05error\
+    \x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_err\
+    or\"\x88\x01\n\x14InstallPluginRequest\x12\x1e\n\nidentifier\x18\x01\x20\
+    \x01(\tR\nidentifier\x12\x17\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\
+    \x88\x01\x01\x12\x1a\n\x08location\x18\x03\x20\x01(\tR\x08location\x12\
+    \x12\n\x04wasm\x18\x04\x20\x01(\x0cR\x04wasmB\x07\n\x05_name\"X\n\x15Ins\
+    tallPluginResponse\x12\x12\n\x04hash\x18\x01\x20\x01(\tR\x04hash\x12!\n\
+    \x05error\x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\
+    \n\x06_error\"8\n\x16UninstallPluginRequest\x12\x1e\n\nidentifier\x18\
+    \x01\x20\x01(\tR\nidentifier\"F\n\x17UninstallPluginResponse\x12!\n\x05e\
+    rror\x18\x01\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06\
+    _error\"\x90\x01\n\x11CallPluginRequest\x12\x1e\n\nidentifier\x18\x01\
+    \x20\x01(\tR\nidentifier\x12#\n\rfunction_name\x18\x02\x20\x01(\tR\x0cfu\
+    nctionName\x12\x14\n\x05input\x18\x03\x20\x01(\x0cR\x05input\x12\x17\n\
+    \x04hash\x18\x04\x20\x01(\tH\0R\x04hash\x88\x01\x01B\x07\n\x05_hash\"Y\n\
+    \x12CallPluginResponse\x12\x16\n\x06output\x18\x01\x20\x01(\x0cR\x06outp\
+    ut\x12!\n\x05error\x18\x02\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\x01\
+    \x01B\x08\n\x06_error\"\x0f\n\rHealthRequest\"M\n\x0eHealthResponse\x12\
+    \x0e\n\x02ok\x18\x01\x20\x01(\x08R\x02ok\x12!\n\x05error\x18\x02\x20\x01\
+    (\x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error\"\x13\n\x11Se\
+    rverInfoRequest\"\xa0\x01\n\x12ServerInfoResponse\x12\x18\n\x07version\
+    \x18\x01\x20\x01(\tR\x07version\x12\x1a\n\x08features\x18\x02\x20\x03(\t\
+    R\x08features\x12'\n\x0fplugins_enabled\x18\x03\x20\x01(\x08R\x0eplugins\
+    Enabled\x12!\n\x05error\x18\x04\x20\x01(\x0b2\x06.ErrorH\0R\x05error\x88\
+    \x01\x01B\x08\n\x06_error\"\xb1\x01\n\x0bModuleEvent\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\x04R\x02id\x12$\n\x04kind\x18\x02\x20\x01(\x0e2\x10.Module\
+    EventKindR\x04kind\x12\x1b\n\tmodule_id\x18\x03\x20\x01(\x03R\x08moduleI\
+    d\x12\x12\n\x04hash\x18\x04\x20\x01(\tR\x04hash\x12;\n\x0boccurred_at\
+    \x18\x05\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\noccurredAt\"\xd1\
+    \x01\n\x10SubscribeRequest\x12)\n\x0eafter_event_id\x18\x01\x20\x01(\x04\
+    H\0R\x0cafterEventId\x88\x01\x01\x12!\n\x0cwait_seconds\x18\x02\x20\x01(\
+    \rR\x0bwaitSeconds\x12&\n\x05kinds\x18\x03\x20\x03(\x0e2\x10.ModuleEvent\
+    KindR\x05kinds\x12\x12\n\x04tags\x18\x04\x20\x03(\tR\x04tags\x12\x17\n\
+    \x04hash\x18\x05\x20\x01(\tH\x01R\x04hash\x88\x01\x01B\x11\n\x0f_after_e\
+    vent_idB\x07\n\x05_hash\"\x8a\x01\n\x11SubscribeResponse\x12$\n\x06event\
+    s\x18\x01\x20\x03(\x0b2\x0c.ModuleEventR\x06events\x12\"\n\rlast_event_i\
+    d\x18\x02\x20\x01(\x04R\x0blastEventId\x12!\n\x05error\x18\x03\x20\x01(\
+    \x0b2\x06.ErrorH\0R\x05error\x88\x01\x01B\x08\n\x06_error*\xd3\x01\n\x07\
+    ValType\x12\x07\n\x03I32\x10\0\x12\x07\n\x03I64\x10\x01\x12\x07\n\x03F32\
     \x10\x02\x12\x07\n\x03F64\x10\x03\x12\x08\n\x04V128\x10\x04\x12\x0b\n\
-    \x07FuncRef\x10\x05\x12\r\n\tExternRef\x10\x06*\x84\x01\n\x0eSourceLangu\
-    age\x12\x0b\n\x07Unknown\x10\0\x12\x08\n\x04Rust\x10\x01\x12\x06\n\x02Go\
-    \x10\x02\x12\x05\n\x01C\x10\x03\x12\x07\n\x03Cpp\x10\x04\x12\x12\n\x0eAs\
-    semblyScript\x10\x05\x12\t\n\x05Swift\x10\x06\x12\x0e\n\nJavaScript\x10\
-    \x07\x12\x0b\n\x07Haskell\x10\x08\x12\x07\n\x03Zig\x10\t*\x1e\n\tDirecti\
-    on\x12\x08\n\x04Desc\x10\0\x12\x07\n\x03Asc\x10\x01*x\n\x05Field\x12\r\n\
-    \tCreatedAt\x10\0\x12\x08\n\x04Name\x10\x01\x12\x08\n\x04Size\x10\x02\
-    \x12\x0c\n\x08Language\x10\x03\x12\x10\n\x0cImportsCount\x10\x04\x12\x10\
-    \n\x0cExportsCount\x10\x05\x12\n\n\x06Sha256\x10\x06\x12\x0e\n\nComplexi\
-    ty\x10\x07*\"\n\x0cAuditOutcome\x12\x08\n\x04PASS\x10\0\x12\x08\n\x04FAI\
-    L\x10\x01B\x0fZ\r./modsurferpbJ\xc6n\n\x07\x12\x05\0\0\xd8\x02\x01\n\x08\
-    \n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x08\x12\x03\x02\0$\n\t\n\x02\x08\
-    \x0b\x12\x03\x02\0$\n\t\n\x02\x03\0\x12\x03\x04\0)\nr\n\x02\x05\0\x12\
-    \x04\x08\0\x10\x01\x1af\x20Used\x20to\x20type\x20the\x20arguments\x20and\
-    \x20return\x20types\x20from\x20wasm\x20elements\x20such\x20as\x20import\
-    \n\x20and\x20export\x20functions.\n\n\n\n\x03\x05\0\x01\x12\x03\x08\x05\
-    \x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\t\x02\n\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\t\x02\x05\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\t\x08\t\n\x0b\n\
-    \x04\x05\0\x02\x01\x12\x03\n\x02\n\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\
-    \n\x02\x05\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\n\x08\t\n\x0b\n\x04\x05\
-    \0\x02\x02\x12\x03\x0b\x02\n\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x0b\
-    \x02\x05\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x0b\x08\t\n\x0b\n\x04\x05\
-    \0\x02\x03\x12\x03\x0c\x02\n\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x0c\
-    \x02\x05\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x0c\x08\t\n\x0b\n\x04\x05\
-    \0\x02\x04\x12\x03\r\x02\x0b\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\r\x02\
-    \x06\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\r\t\n\n\x0b\n\x04\x05\0\x02\
-    \x05\x12\x03\x0e\x02\x0e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x0e\x02\t\
-    \n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x0e\x0c\r\n\x0b\n\x04\x05\0\x02\
-    \x06\x12\x03\x0f\x02\x10\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\x0f\x02\
-    \x0b\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\x0f\x0e\x0f\nL\n\x02\x04\0\
-    \x12\x04\x13\0\x17\x01\x1a@\x20Contained\x20by\x20an\x20import\x20or\x20\
-    export\x20element\x20within\x20a\x20wasm\x20binary.\n\n\n\n\x03\x04\0\
-    \x01\x12\x03\x13\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x14\x02\x1e\n\
-    \x0c\n\x05\x04\0\x02\0\x04\x12\x03\x14\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\
-    \x12\x03\x14\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x14\x13\x19\n\
-    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x14\x1c\x1d\n\x0b\n\x04\x04\0\x02\x01\
-    \x12\x03\x15\x02\x1f\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x15\x02\n\n\
-    \x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x15\x0b\x12\n\x0c\n\x05\x04\0\x02\
-    \x01\x01\x12\x03\x15\x13\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x15\
-    \x1d\x1e\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x16\x02\x12\n\x0c\n\x05\x04\0\
-    \x02\x02\x05\x12\x03\x16\x02\x08\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\
-    \x16\t\r\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x16\x10\x11\n\x8d\x01\n\
-    \x02\x04\x01\x12\x04\x1b\0\x1e\x01\x1a\x80\x01\x20A\x20function\x20and\
-    \x20module\x20namespace\x20that\x20is\x20defined\x20outside\x20of\x20the\
-    \x20current\n\x20module,\x20and\x20referenced\x20&\x20called\x20by\x20th\
-    e\x20current\x20module.\n\n\n\n\x03\x04\x01\x01\x12\x03\x1b\x08\x0e\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x1c\x02\x19\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x1c\x02\x08\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x1c\t\x14\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x1c\x17\x18\n\x0b\n\x04\x04\x01\
-    \x02\x01\x12\x03\x1d\x02\x14\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x1d\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x1d\x0b\x0f\n\x0c\n\x05\
-    \x04\x01\x02\x01\x03\x12\x03\x1d\x12\x13\nu\n\x02\x04\x02\x12\x03\"\0%\
-    \x1aj\x20A\x20function\x20that\x20is\x20defined\x20inside\x20the\x20curr\
-    ent\x20module,\x20made\x20available\x20to\n\x20outside\x20modules\x20/\
-    \x20environments.\n\n\n\n\x03\x04\x02\x01\x12\x03\"\x08\x0e\n\x0b\n\x04\
-    \x04\x02\x02\0\x12\x03\"\x11#\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\"\
-    \x11\x19\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\"\x1a\x1e\n\x0c\n\x05\x04\
-    \x02\x02\0\x03\x12\x03\"!\"\nQ\n\x02\x05\x01\x12\x04%\00\x01\x1aE\x20The\
-    \x20language\x20(or\x20most\x20similar\x20match)\x20used\x20to\x20produc\
-    e\x20a\x20wasm\x20module.\n\n\n\n\x03\x05\x01\x01\x12\x03%\x05\x13\n\x0b\
-    \n\x04\x05\x01\x02\0\x12\x03&\x02\x0e\n\x0c\n\x05\x05\x01\x02\0\x01\x12\
-    \x03&\x02\t\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03&\x0c\r\n\x0b\n\x04\x05\
-    \x01\x02\x01\x12\x03'\x02\x0b\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03'\
-    \x02\x06\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03'\t\n\n\x0b\n\x04\x05\
-    \x01\x02\x02\x12\x03(\x02\t\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03(\x02\
-    \x04\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03(\x07\x08\n\x0b\n\x04\x05\
-    \x01\x02\x03\x12\x03)\x02\x08\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03)\
-    \x02\x03\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03)\x06\x07\n\x0b\n\x04\
-    \x05\x01\x02\x04\x12\x03*\x02\n\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\x03*\
-    \x02\x05\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03*\x08\t\n\x0b\n\x04\x05\
-    \x01\x02\x05\x12\x03+\x02\x15\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\x03+\
-    \x02\x10\n\x0c\n\x05\x05\x01\x02\x05\x02\x12\x03+\x13\x14\n\x0b\n\x04\
-    \x05\x01\x02\x06\x12\x03,\x02\x0c\n\x0c\n\x05\x05\x01\x02\x06\x01\x12\
-    \x03,\x02\x07\n\x0c\n\x05\x05\x01\x02\x06\x02\x12\x03,\n\x0b\n\x0b\n\x04\
-    \x05\x01\x02\x07\x12\x03-\x02\x11\n\x0c\n\x05\x05\x01\x02\x07\x01\x12\
-    \x03-\x02\x0c\n\x0c\n\x05\x05\x01\x02\x07\x02\x12\x03-\x0f\x10\n\x0b\n\
-    \x04\x05\x01\x02\x08\x12\x03.\x02\x0e\n\x0c\n\x05\x05\x01\x02\x08\x01\
-    \x12\x03.\x02\t\n\x0c\n\x05\x05\x01\x02\x08\x02\x12\x03.\x0c\r\n\x0b\n\
-    \x04\x05\x01\x02\t\x12\x03/\x02\n\n\x0c\n\x05\x05\x01\x02\t\x01\x12\x03/\
-    \x02\x05\n\x0c\n\x05\x05\x01\x02\t\x02\x12\x03/\x08\t\nk\n\x02\x04\x03\
-    \x12\x044\0R\x01\x1a_\x20Details\x20about\x20a\x20wasm\x20module,\x20eit\
-    her\x20extracted\x20directly\x20from\x20the\x20binary,\x20or\n\x20inferr\
-    ed\x20somehow.\n\n\n\n\x03\x04\x03\x01\x12\x034\x08\x0e\n=\n\x04\x04\x03\
-    \x02\0\x12\x036\x02\x0f\x1a0\x20ID\x20for\x20this\x20module,\x20generate\
-    d\x20by\x20the\x20database.\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x036\x02\
-    \x07\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x036\x08\n\n\x0c\n\x05\x04\x03\
-    \x02\0\x03\x12\x036\r\x0e\n3\n\x04\x04\x03\x02\x01\x12\x038\x02\x12\x1a&\
-    \x20sha256\x20hash\x20of\x20the\x20modules\x20raw\x20bytes\n\n\x0c\n\x05\
-    \x04\x03\x02\x01\x05\x12\x038\x02\x08\n\x0c\n\x05\x04\x03\x02\x01\x01\
-    \x12\x038\t\r\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x038\x10\x11\n\x81\x01\
-    \n\x04\x04\x03\x02\x02\x12\x03;\x02\x1e\x1at\x20function\x20imports\x20c\
-    alled\x20by\x20the\x20module\x20(see:\n\x20<https://github.com/WebAssemb\
-    ly/design/blob/main/Modules.md#imports)>\n\n\x0c\n\x05\x04\x03\x02\x02\
-    \x04\x12\x03;\x02\n\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\x03;\x0b\x11\n\
-    \x0c\n\x05\x04\x03\x02\x02\x01\x12\x03;\x12\x19\n\x0c\n\x05\x04\x03\x02\
-    \x02\x03\x12\x03;\x1c\x1d\n\x83\x01\n\x04\x04\x03\x02\x03\x12\x03>\x02\
-    \x1e\x1av\x20function\x20exports\x20provided\x20by\x20the\x20module\x20(\
-    see:\n\x20<https://github.com/WebAssembly/design/blob/main/Modules.md#ex\
-    ports)>\n\n\x0c\n\x05\x04\x03\x02\x03\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\
-    \x03\x02\x03\x06\x12\x03>\x0b\x11\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\
-    \x03>\x12\x19\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03>\x1c\x1d\n*\n\x04\
-    \x04\x03\x02\x04\x12\x03@\x02\x12\x1a\x1d\x20size\x20in\x20bytes\x20of\
-    \x20the\x20module\n\n\x0c\n\x05\x04\x03\x02\x04\x05\x12\x03@\x02\x08\n\
-    \x0c\n\x05\x04\x03\x02\x04\x01\x12\x03@\t\r\n\x0c\n\x05\x04\x03\x02\x04\
-    \x03\x12\x03@\x10\x11\n,\n\x04\x04\x03\x02\x05\x12\x03B\x02\x16\x1a\x1f\
-    \x20path\x20or\x20locator\x20to\x20the\x20module\n\n\x0c\n\x05\x04\x03\
-    \x02\x05\x05\x12\x03B\x02\x08\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03B\t\
-    \x11\n\x0c\n\x05\x04\x03\x02\x05\x03\x12\x03B\x14\x15\n?\n\x04\x04\x03\
-    \x02\x06\x12\x03D\x02%\x1a2\x20programming\x20language\x20used\x20to\x20\
-    produce\x20this\x20module\n\n\x0c\n\x05\x04\x03\x02\x06\x06\x12\x03D\x02\
-    \x10\n\x0c\n\x05\x04\x03\x02\x06\x01\x12\x03D\x11\x20\n\x0c\n\x05\x04\
-    \x03\x02\x06\x03\x12\x03D#$\nI\n\x04\x04\x03\x02\x07\x12\x03F\x02#\x1a<\
-    \x20arbitrary\x20metadata\x20provided\x20by\x20the\x20operator\x20of\x20\
-    this\x20module\n\n\x0c\n\x05\x04\x03\x02\x07\x06\x12\x03F\x02\x15\n\x0c\
-    \n\x05\x04\x03\x02\x07\x01\x12\x03F\x16\x1e\n\x0c\n\x05\x04\x03\x02\x07\
-    \x03\x12\x03F!\"\n?\n\x04\x04\x03\x02\x08\x12\x03H\x02-\x1a2\x20timestam\
-    p\x20when\x20this\x20module\x20was\x20loaded\x20and\x20stored\n\n\x0c\n\
-    \x05\x04\x03\x02\x08\x06\x12\x03H\x02\x1b\n\x0c\n\x05\x04\x03\x02\x08\
-    \x01\x12\x03H\x1c'\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03H*,\nZ\n\x04\
-    \x04\x03\x02\t\x12\x03J\x02\x1f\x1aM\x20the\x20interned\x20strings\x20st\
-    ored\x20in\x20the\x20wasm\x20binary\x20(panic/abort\x20messages,\x20etc.\
-    )\n\n\x0c\n\x05\x04\x03\x02\t\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\x03\x02\
-    \t\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03J\x12\x19\n\
-    \x0c\n\x05\x04\x03\x02\t\x03\x12\x03J\x1c\x1e\nu\n\x04\x04\x03\x02\n\x12\
-    \x03M\x02\"\x1ah\x20the\x20cyclomatic\x20complexity\n\x20(<https://en.wi\
-    kipedia.org/wiki/Cyclomatic_complexity>)\x20of\x20the\x20instructions\n\
-    \n\x0c\n\x05\x04\x03\x02\n\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\x03\x02\n\
-    \x05\x12\x03M\x0b\x11\n\x0c\n\x05\x04\x03\x02\n\x01\x12\x03M\x12\x1c\n\
-    \x0c\n\x05\x04\x03\x02\n\x03\x12\x03M\x1f!\n2\n\x04\x04\x03\x02\x0b\x12\
-    \x03O\x02\x1c\x1a%\x20the\x20serialized\x20graph\x20in\x20json\x20format\
-    \n\n\x0c\n\x05\x04\x03\x02\x0b\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x03\
-    \x02\x0b\x05\x12\x03O\x0b\x10\n\x0c\n\x05\x04\x03\x02\x0b\x01\x12\x03O\
-    \x11\x16\n\x0c\n\x05\x04\x03\x02\x0b\x03\x12\x03O\x19\x1b\n\x1e\n\x04\
-    \x04\x03\x02\x0c\x12\x03Q\x02+\x1a\x11\x20function\x20hashes\n\n\x0c\n\
-    \x05\x04\x03\x02\x0c\x06\x12\x03Q\x02\x15\n\x0c\n\x05\x04\x03\x02\x0c\
-    \x01\x12\x03Q\x16%\n\x0c\n\x05\x04\x03\x02\x0c\x03\x12\x03Q(*\n/\n\x02\
-    \x04\x04\x12\x04U\0Z\x01\x1a#\x20Details\x20about\x20a\x20wasm\x20module\
-    \x20graph\n\n\n\n\x03\x04\x04\x01\x12\x03U\x08\x13\n=\n\x04\x04\x04\x02\
-    \0\x12\x03W\x02\x0f\x1a0\x20ID\x20for\x20this\x20module,\x20generated\
-    \x20by\x20the\x20database.\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03W\x02\
-    \x07\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03W\x08\n\n\x0c\n\x05\x04\x04\
-    \x02\0\x03\x12\x03W\r\x0e\n2\n\x04\x04\x04\x02\x01\x12\x03Y\x02\x17\x1a%\
+    \x07FuncRef\x10\x05\x12\r\n\tExternRef\x10\x06\x12\x08\n\x04Bool\x10\x07\
+    \x12\x06\n\x02S8\x10\x08\x12\x06\n\x02U8\x10\t\x12\x07\n\x03S16\x10\n\
+    \x12\x07\n\x03U16\x10\x0b\x12\x07\n\x03S32\x10\x0c\x12\x07\n\x03U32\x10\
+    \r\x12\x07\n\x03S64\x10\x0e\x12\x07\n\x03U64\x10\x0f\x12\x0b\n\x07Float3\
+    2\x10\x10\x12\x0b\n\x07Float64\x10\x11\x12\x08\n\x04Char\x10\x12\x12\n\n\
+    \x06String\x10\x13*\x84\x01\n\x0eSourceLanguage\x12\x0b\n\x07Unknown\x10\
+    \0\x12\x08\n\x04Rust\x10\x01\x12\x06\n\x02Go\x10\x02\x12\x05\n\x01C\x10\
+    \x03\x12\x07\n\x03Cpp\x10\x04\x12\x12\n\x0eAssemblyScript\x10\x05\x12\t\
+    \n\x05Swift\x10\x06\x12\x0e\n\nJavaScript\x10\x07\x12\x0b\n\x07Haskell\
+    \x10\x08\x12\x07\n\x03Zig\x10\t*\x1e\n\tDirection\x12\x08\n\x04Desc\x10\
+    \0\x12\x07\n\x03Asc\x10\x01*\x87\x01\n\x05Field\x12\r\n\tCreatedAt\x10\0\
+    \x12\x08\n\x04Name\x10\x01\x12\x08\n\x04Size\x10\x02\x12\x0c\n\x08Langua\
+    ge\x10\x03\x12\x10\n\x0cImportsCount\x10\x04\x12\x10\n\x0cExportsCount\
+    \x10\x05\x12\n\n\x06Sha256\x10\x06\x12\x0e\n\nComplexity\x10\x07\x12\r\n\
+    \tRiskScore\x10\x08**\n\tRiskLevel\x12\x07\n\x03LOW\x10\0\x12\n\n\x06MED\
+    IUM\x10\x01\x12\x08\n\x04HIGH\x10\x02*\"\n\x0cAuditOutcome\x12\x08\n\x04\
+    PASS\x10\0\x12\x08\n\x04FAIL\x10\x01*g\n\x0fModuleEventKind\x12!\n\x1dMO\
+    DULE_EVENT_KIND_UNSPECIFIED\x10\0\x12\x0b\n\x07CREATED\x10\x01\x12\x0b\n\
+    \x07UPDATED\x10\x02\x12\x0b\n\x07DELETED\x10\x03\x12\n\n\x06YANKED\x10\
+    \x04B\x0fZ\r./modsurferpbJ\xcb\xed\x01\n\x07\x12\x05\0\0\xcf\x05\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x08\x12\x03\x02\0$\n\t\n\x02\
+    \x08\x0b\x12\x03\x02\0$\n\t\n\x02\x03\0\x12\x03\x04\0)\nr\n\x02\x05\0\
+    \x12\x04\x08\0\x1e\x01\x1af\x20Used\x20to\x20type\x20the\x20arguments\
+    \x20and\x20return\x20types\x20from\x20wasm\x20elements\x20such\x20as\x20\
+    import\n\x20and\x20export\x20functions.\n\n\n\n\x03\x05\0\x01\x12\x03\
+    \x08\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\t\x02\n\n\x0c\n\x05\x05\0\
+    \x02\0\x01\x12\x03\t\x02\x05\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\t\x08\t\
+    \n\x0b\n\x04\x05\0\x02\x01\x12\x03\n\x02\n\n\x0c\n\x05\x05\0\x02\x01\x01\
+    \x12\x03\n\x02\x05\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\n\x08\t\n\x0b\n\
+    \x04\x05\0\x02\x02\x12\x03\x0b\x02\n\n\x0c\n\x05\x05\0\x02\x02\x01\x12\
+    \x03\x0b\x02\x05\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x0b\x08\t\n\x0b\n\
+    \x04\x05\0\x02\x03\x12\x03\x0c\x02\n\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
+    \x03\x0c\x02\x05\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x0c\x08\t\n\x0b\n\
+    \x04\x05\0\x02\x04\x12\x03\r\x02\x0b\n\x0c\n\x05\x05\0\x02\x04\x01\x12\
+    \x03\r\x02\x06\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\r\t\n\n\x0b\n\x04\
+    \x05\0\x02\x05\x12\x03\x0e\x02\x0e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\
+    \x0e\x02\t\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x0e\x0c\r\n\x0b\n\x04\
+    \x05\0\x02\x06\x12\x03\x0f\x02\x10\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\
+    \x0f\x02\x0b\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\x0f\x0e\x0f\n*\n\x04\
+    \x05\0\x02\x07\x12\x03\x11\x02\x0b\x1a\x1d\x20component-model\x20value\
+    \x20types\n\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\x11\x02\x06\n\x0c\n\
+    \x05\x05\0\x02\x07\x02\x12\x03\x11\t\n\n\x0b\n\x04\x05\0\x02\x08\x12\x03\
+    \x12\x02\t\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x12\x02\x04\n\x0c\n\x05\
+    \x05\0\x02\x08\x02\x12\x03\x12\x07\x08\n\x0b\n\x04\x05\0\x02\t\x12\x03\
+    \x13\x02\t\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x13\x02\x04\n\x0c\n\x05\
+    \x05\0\x02\t\x02\x12\x03\x13\x07\x08\n\x0b\n\x04\x05\0\x02\n\x12\x03\x14\
+    \x02\x0b\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\x14\x02\x05\n\x0c\n\x05\x05\
+    \0\x02\n\x02\x12\x03\x14\x08\n\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x15\x02\
+    \x0b\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x15\x02\x05\n\x0c\n\x05\x05\0\
+    \x02\x0b\x02\x12\x03\x15\x08\n\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x16\x02\
+    \x0b\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x16\x02\x05\n\x0c\n\x05\x05\0\
+    \x02\x0c\x02\x12\x03\x16\x08\n\n\x0b\n\x04\x05\0\x02\r\x12\x03\x17\x02\
+    \x0b\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x17\x02\x05\n\x0c\n\x05\x05\0\
+    \x02\r\x02\x12\x03\x17\x08\n\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x18\x02\
+    \x0b\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x18\x02\x05\n\x0c\n\x05\x05\0\
+    \x02\x0e\x02\x12\x03\x18\x08\n\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x19\x02\
+    \x0b\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x19\x02\x05\n\x0c\n\x05\x05\0\
+    \x02\x0f\x02\x12\x03\x19\x08\n\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x1a\x02\
+    \x0f\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x1a\x02\t\n\x0c\n\x05\x05\0\
+    \x02\x10\x02\x12\x03\x1a\x0c\x0e\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x1b\
+    \x02\x0f\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x1b\x02\t\n\x0c\n\x05\x05\
+    \0\x02\x11\x02\x12\x03\x1b\x0c\x0e\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x1c\
+    \x02\x0c\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x1c\x02\x06\n\x0c\n\x05\
+    \x05\0\x02\x12\x02\x12\x03\x1c\t\x0b\n\x0b\n\x04\x05\0\x02\x13\x12\x03\
+    \x1d\x02\x0e\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x1d\x02\x08\n\x0c\n\
+    \x05\x05\0\x02\x13\x02\x12\x03\x1d\x0b\r\nL\n\x02\x04\0\x12\x04!\0%\x01\
+    \x1a@\x20Contained\x20by\x20an\x20import\x20or\x20export\x20element\x20w\
+    ithin\x20a\x20wasm\x20binary.\n\n\n\n\x03\x04\0\x01\x12\x03!\x08\x10\n\
+    \x0b\n\x04\x04\0\x02\0\x12\x03\"\x02\x1e\n\x0c\n\x05\x04\0\x02\0\x04\x12\
+    \x03\"\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\"\x0b\x12\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\"\x13\x19\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\"\
+    \x1c\x1d\n\x0b\n\x04\x04\0\x02\x01\x12\x03#\x02\x1f\n\x0c\n\x05\x04\0\
+    \x02\x01\x04\x12\x03#\x02\n\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03#\x0b\
+    \x12\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03#\x13\x1a\n\x0c\n\x05\x04\0\
+    \x02\x01\x03\x12\x03#\x1d\x1e\n\x0b\n\x04\x04\0\x02\x02\x12\x03$\x02\x12\
+    \n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03$\x02\x08\n\x0c\n\x05\x04\0\x02\
+    \x02\x01\x12\x03$\t\r\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03$\x10\x11\n\
+    \x8d\x01\n\x02\x04\x01\x12\x04)\0,\x01\x1a\x80\x01\x20A\x20function\x20a\
+    nd\x20module\x20namespace\x20that\x20is\x20defined\x20outside\x20of\x20t\
+    he\x20current\n\x20module,\x20and\x20referenced\x20&\x20called\x20by\x20\
+    the\x20current\x20module.\n\n\n\n\x03\x04\x01\x01\x12\x03)\x08\x0e\n\x0b\
+    \n\x04\x04\x01\x02\0\x12\x03*\x02\x19\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03*\x02\x08\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03*\t\x14\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03*\x17\x18\n\x0b\n\x04\x04\x01\x02\x01\x12\x03+\
+    \x02\x14\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03+\x02\n\n\x0c\n\x05\x04\
+    \x01\x02\x01\x01\x12\x03+\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
+    \x03+\x12\x13\nu\n\x02\x04\x02\x12\x030\0%\x1aj\x20A\x20function\x20that\
+    \x20is\x20defined\x20inside\x20the\x20current\x20module,\x20made\x20avai\
+    lable\x20to\n\x20outside\x20modules\x20/\x20environments.\n\n\n\n\x03\
+    \x04\x02\x01\x12\x030\x08\x0e\n\x0b\n\x04\x04\x02\x02\0\x12\x030\x11#\n\
+    \x0c\n\x05\x04\x02\x02\0\x06\x12\x030\x11\x19\n\x0c\n\x05\x04\x02\x02\0\
+    \x01\x12\x030\x1a\x1e\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x030!\"\nQ\n\x02\
+    \x05\x01\x12\x043\0>\x01\x1aE\x20The\x20language\x20(or\x20most\x20simil\
+    ar\x20match)\x20used\x20to\x20produce\x20a\x20wasm\x20module.\n\n\n\n\
+    \x03\x05\x01\x01\x12\x033\x05\x13\n\x0b\n\x04\x05\x01\x02\0\x12\x034\x02\
+    \x0e\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x034\x02\t\n\x0c\n\x05\x05\x01\
+    \x02\0\x02\x12\x034\x0c\r\n\x0b\n\x04\x05\x01\x02\x01\x12\x035\x02\x0b\n\
+    \x0c\n\x05\x05\x01\x02\x01\x01\x12\x035\x02\x06\n\x0c\n\x05\x05\x01\x02\
+    \x01\x02\x12\x035\t\n\n\x0b\n\x04\x05\x01\x02\x02\x12\x036\x02\t\n\x0c\n\
+    \x05\x05\x01\x02\x02\x01\x12\x036\x02\x04\n\x0c\n\x05\x05\x01\x02\x02\
+    \x02\x12\x036\x07\x08\n\x0b\n\x04\x05\x01\x02\x03\x12\x037\x02\x08\n\x0c\
+    \n\x05\x05\x01\x02\x03\x01\x12\x037\x02\x03\n\x0c\n\x05\x05\x01\x02\x03\
+    \x02\x12\x037\x06\x07\n\x0b\n\x04\x05\x01\x02\x04\x12\x038\x02\n\n\x0c\n\
+    \x05\x05\x01\x02\x04\x01\x12\x038\x02\x05\n\x0c\n\x05\x05\x01\x02\x04\
+    \x02\x12\x038\x08\t\n\x0b\n\x04\x05\x01\x02\x05\x12\x039\x02\x15\n\x0c\n\
+    \x05\x05\x01\x02\x05\x01\x12\x039\x02\x10\n\x0c\n\x05\x05\x01\x02\x05\
+    \x02\x12\x039\x13\x14\n\x0b\n\x04\x05\x01\x02\x06\x12\x03:\x02\x0c\n\x0c\
+    \n\x05\x05\x01\x02\x06\x01\x12\x03:\x02\x07\n\x0c\n\x05\x05\x01\x02\x06\
+    \x02\x12\x03:\n\x0b\n\x0b\n\x04\x05\x01\x02\x07\x12\x03;\x02\x11\n\x0c\n\
+    \x05\x05\x01\x02\x07\x01\x12\x03;\x02\x0c\n\x0c\n\x05\x05\x01\x02\x07\
+    \x02\x12\x03;\x0f\x10\n\x0b\n\x04\x05\x01\x02\x08\x12\x03<\x02\x0e\n\x0c\
+    \n\x05\x05\x01\x02\x08\x01\x12\x03<\x02\t\n\x0c\n\x05\x05\x01\x02\x08\
+    \x02\x12\x03<\x0c\r\n\x0b\n\x04\x05\x01\x02\t\x12\x03=\x02\n\n\x0c\n\x05\
+    \x05\x01\x02\t\x01\x12\x03=\x02\x05\n\x0c\n\x05\x05\x01\x02\t\x02\x12\
+    \x03=\x08\t\nk\n\x02\x04\x03\x12\x04B\0v\x01\x1a_\x20Details\x20about\
+    \x20a\x20wasm\x20module,\x20either\x20extracted\x20directly\x20from\x20t\
+    he\x20binary,\x20or\n\x20inferred\x20somehow.\n\n\n\n\x03\x04\x03\x01\
+    \x12\x03B\x08\x0e\n=\n\x04\x04\x03\x02\0\x12\x03D\x02\x0f\x1a0\x20ID\x20\
+    for\x20this\x20module,\x20generated\x20by\x20the\x20database.\n\n\x0c\n\
+    \x05\x04\x03\x02\0\x05\x12\x03D\x02\x07\n\x0c\n\x05\x04\x03\x02\0\x01\
+    \x12\x03D\x08\n\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03D\r\x0e\n3\n\x04\
+    \x04\x03\x02\x01\x12\x03F\x02\x12\x1a&\x20sha256\x20hash\x20of\x20the\
+    \x20modules\x20raw\x20bytes\n\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03F\
+    \x02\x08\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03F\t\r\n\x0c\n\x05\x04\
+    \x03\x02\x01\x03\x12\x03F\x10\x11\n\x81\x01\n\x04\x04\x03\x02\x02\x12\
+    \x03I\x02\x1e\x1at\x20function\x20imports\x20called\x20by\x20the\x20modu\
+    le\x20(see:\n\x20<https://github.com/WebAssembly/design/blob/main/Module\
+    s.md#imports)>\n\n\x0c\n\x05\x04\x03\x02\x02\x04\x12\x03I\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\x02\x06\x12\x03I\x0b\x11\n\x0c\n\x05\x04\x03\x02\x02\
+    \x01\x12\x03I\x12\x19\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03I\x1c\x1d\n\
+    \x83\x01\n\x04\x04\x03\x02\x03\x12\x03L\x02\x1e\x1av\x20function\x20expo\
+    rts\x20provided\x20by\x20the\x20module\x20(see:\n\x20<https://github.com\
+    /WebAssembly/design/blob/main/Modules.md#exports)>\n\n\x0c\n\x05\x04\x03\
+    \x02\x03\x04\x12\x03L\x02\n\n\x0c\n\x05\x04\x03\x02\x03\x06\x12\x03L\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03L\x12\x19\n\x0c\n\x05\x04\
+    \x03\x02\x03\x03\x12\x03L\x1c\x1d\n*\n\x04\x04\x03\x02\x04\x12\x03N\x02\
+    \x12\x1a\x1d\x20size\x20in\x20bytes\x20of\x20the\x20module\n\n\x0c\n\x05\
+    \x04\x03\x02\x04\x05\x12\x03N\x02\x08\n\x0c\n\x05\x04\x03\x02\x04\x01\
+    \x12\x03N\t\r\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03N\x10\x11\n,\n\x04\
+    \x04\x03\x02\x05\x12\x03P\x02\x16\x1a\x1f\x20path\x20or\x20locator\x20to\
+    \x20the\x20module\n\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x03P\x02\x08\n\
+    \x0c\n\x05\x04\x03\x02\x05\x01\x12\x03P\t\x11\n\x0c\n\x05\x04\x03\x02\
+    \x05\x03\x12\x03P\x14\x15\n?\n\x04\x04\x03\x02\x06\x12\x03R\x02%\x1a2\
+    \x20programming\x20language\x20used\x20to\x20produce\x20this\x20module\n\
+    \n\x0c\n\x05\x04\x03\x02\x06\x06\x12\x03R\x02\x10\n\x0c\n\x05\x04\x03\
+    \x02\x06\x01\x12\x03R\x11\x20\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03R#$\
+    \nI\n\x04\x04\x03\x02\x07\x12\x03T\x02#\x1a<\x20arbitrary\x20metadata\
+    \x20provided\x20by\x20the\x20operator\x20of\x20this\x20module\n\n\x0c\n\
+    \x05\x04\x03\x02\x07\x06\x12\x03T\x02\x15\n\x0c\n\x05\x04\x03\x02\x07\
+    \x01\x12\x03T\x16\x1e\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\x03T!\"\n?\n\
+    \x04\x04\x03\x02\x08\x12\x03V\x02-\x1a2\x20timestamp\x20when\x20this\x20\
+    module\x20was\x20loaded\x20and\x20stored\n\n\x0c\n\x05\x04\x03\x02\x08\
+    \x06\x12\x03V\x02\x1b\n\x0c\n\x05\x04\x03\x02\x08\x01\x12\x03V\x1c'\n\
+    \x0c\n\x05\x04\x03\x02\x08\x03\x12\x03V*,\nZ\n\x04\x04\x03\x02\t\x12\x03\
+    X\x02\x1f\x1aM\x20the\x20interned\x20strings\x20stored\x20in\x20the\x20w\
+    asm\x20binary\x20(panic/abort\x20messages,\x20etc.)\n\n\x0c\n\x05\x04\
+    \x03\x02\t\x04\x12\x03X\x02\n\n\x0c\n\x05\x04\x03\x02\t\x05\x12\x03X\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03X\x12\x19\n\x0c\n\x05\x04\x03\
+    \x02\t\x03\x12\x03X\x1c\x1e\nu\n\x04\x04\x03\x02\n\x12\x03[\x02\"\x1ah\
+    \x20the\x20cyclomatic\x20complexity\n\x20(<https://en.wikipedia.org/wiki\
+    /Cyclomatic_complexity>)\x20of\x20the\x20instructions\n\n\x0c\n\x05\x04\
+    \x03\x02\n\x04\x12\x03[\x02\n\n\x0c\n\x05\x04\x03\x02\n\x05\x12\x03[\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\n\x01\x12\x03[\x12\x1c\n\x0c\n\x05\x04\x03\
+    \x02\n\x03\x12\x03[\x1f!\n2\n\x04\x04\x03\x02\x0b\x12\x03]\x02\x1c\x1a%\
     \x20the\x20serialized\x20graph\x20in\x20json\x20format\n\n\x0c\n\x05\x04\
-    \x04\x02\x01\x05\x12\x03Y\x02\x07\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\
-    \x03Y\x08\x12\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03Y\x15\x16\n?\n\x02\
-    \x04\x05\x12\x04]\0`\x01\x1a3\x20An\x20error\x20message\x20indicating\
-    \x20a\x20problem\x20in\x20the\x20API.\n\n\n\n\x03\x04\x05\x01\x12\x03]\
-    \x08\r\n\x0b\n\x04\x04\x05\x02\0\x12\x03^\x02\x11\n\x0c\n\x05\x04\x05\
-    \x02\0\x05\x12\x03^\x02\x07\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03^\x08\
-    \x0c\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03^\x0f\x10\n\x0b\n\x04\x04\x05\
-    \x02\x01\x12\x03_\x02\x15\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03_\x02\
-    \x08\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03_\t\x10\n\x0c\n\x05\x04\x05\
-    \x02\x01\x03\x12\x03_\x13\x14\n]\n\x02\x04\x06\x12\x04d\0g\x01\x1aQ\x20C\
-    ontrol/limit\x20the\x20way\x20results\x20are\x20paginated\x20when\x20wor\
-    king\x20with\x20large\n\x20responses.\n\n\n\n\x03\x04\x06\x01\x12\x03d\
-    \x08\x12\n\x0b\n\x04\x04\x06\x02\0\x12\x03e\x02\x13\n\x0c\n\x05\x04\x06\
-    \x02\0\x05\x12\x03e\x02\x08\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03e\t\x0e\
-    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03e\x11\x12\n\x0b\n\x04\x04\x06\x02\
-    \x01\x12\x03f\x02\x14\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03f\x02\x08\n\
-    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03f\t\x0f\n\x0c\n\x05\x04\x06\x02\
-    \x01\x03\x12\x03f\x12\x13\n8\n\x02\x04\x07\x12\x04j\0m\x01\x1a,\x20Deter\
-    mine\x20how\x20to\x20sort\x20results\x20from\x20the\x20API\n\n\n\n\x03\
-    \x04\x07\x01\x12\x03j\x08\x0c\n\x0b\n\x04\x04\x07\x02\0\x12\x03k\x02\x1a\
-    \n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03k\x02\x0b\n\x0c\n\x05\x04\x07\x02\
-    \0\x01\x12\x03k\x0c\x15\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03k\x18\x19\n\
-    \x0b\n\x04\x04\x07\x02\x01\x12\x03l\x02\x12\n\x0c\n\x05\x04\x07\x02\x01\
-    \x06\x12\x03l\x02\x07\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03l\x08\r\n\
-    \x0c\n\x05\x04\x07\x02\x01\x03\x12\x03l\x10\x11\nL\n\x02\x05\x02\x12\x04\
-    p\0s\x01\x1a@\x20The\x20direction,\x20descending\x20or\x20ascending,\x20\
-    of\x20the\x20sort\x20operation.\n\n\n\n\x03\x05\x02\x01\x12\x03p\x05\x0e\
-    \n\x0b\n\x04\x05\x02\x02\0\x12\x03q\x02\x0b\n\x0c\n\x05\x05\x02\x02\0\
-    \x01\x12\x03q\x02\x06\n\x0c\n\x05\x05\x02\x02\0\x02\x12\x03q\t\n\n\x0b\n\
-    \x04\x05\x02\x02\x01\x12\x03r\x02\n\n\x0c\n\x05\x05\x02\x02\x01\x01\x12\
-    \x03r\x02\x05\n\x0c\n\x05\x05\x02\x02\x01\x02\x12\x03r\x08\t\nW\n\x02\
-    \x05\x03\x12\x04v\0\x7f\x01\x1aK\x20The\x20field\x20within\x20the\x20Mod\
-    ule\x20schema\x20that\x20is\x20used\x20as\x20the\x20sorting\x20dimension\
-    .\n\n\n\n\x03\x05\x03\x01\x12\x03v\x05\n\n\x0b\n\x04\x05\x03\x02\0\x12\
-    \x03w\x02\x10\n\x0c\n\x05\x05\x03\x02\0\x01\x12\x03w\x02\x0b\n\x0c\n\x05\
-    \x05\x03\x02\0\x02\x12\x03w\x0e\x0f\n\x0b\n\x04\x05\x03\x02\x01\x12\x03x\
-    \x02\x0b\n\x0c\n\x05\x05\x03\x02\x01\x01\x12\x03x\x02\x06\n\x0c\n\x05\
-    \x05\x03\x02\x01\x02\x12\x03x\t\n\n\x0b\n\x04\x05\x03\x02\x02\x12\x03y\
-    \x02\x0b\n\x0c\n\x05\x05\x03\x02\x02\x01\x12\x03y\x02\x06\n\x0c\n\x05\
-    \x05\x03\x02\x02\x02\x12\x03y\t\n\n\x0b\n\x04\x05\x03\x02\x03\x12\x03z\
-    \x02\x0f\n\x0c\n\x05\x05\x03\x02\x03\x01\x12\x03z\x02\n\n\x0c\n\x05\x05\
-    \x03\x02\x03\x02\x12\x03z\r\x0e\n\x0b\n\x04\x05\x03\x02\x04\x12\x03{\x02\
-    \x13\n\x0c\n\x05\x05\x03\x02\x04\x01\x12\x03{\x02\x0e\n\x0c\n\x05\x05\
-    \x03\x02\x04\x02\x12\x03{\x11\x12\n\x0b\n\x04\x05\x03\x02\x05\x12\x03|\
-    \x02\x13\n\x0c\n\x05\x05\x03\x02\x05\x01\x12\x03|\x02\x0e\n\x0c\n\x05\
-    \x05\x03\x02\x05\x02\x12\x03|\x11\x12\n\x0b\n\x04\x05\x03\x02\x06\x12\
-    \x03}\x02\r\n\x0c\n\x05\x05\x03\x02\x06\x01\x12\x03}\x02\x08\n\x0c\n\x05\
-    \x05\x03\x02\x06\x02\x12\x03}\x0b\x0c\n\x0b\n\x04\x05\x03\x02\x07\x12\
-    \x03~\x02\x11\n\x0c\n\x05\x05\x03\x02\x07\x01\x12\x03~\x02\x0c\n\x0c\n\
-    \x05\x05\x03\x02\x07\x02\x12\x03~\x0f\x10\nn\n\x02\x04\x08\x12\x06\x83\
-    \x01\0\x88\x01\x01\x1a`\x20`PUT\x20/api/v1/module:`\n\x20Insert\x20a\x20\
-    module,\x20extract\x20data\x20from\x20binary.\x20Return\x20the\x20module\
-    \x20ID\x20&\x20hash.\n\n\x0b\n\x03\x04\x08\x01\x12\x04\x83\x01\x08\x1b\n\
-    \x0c\n\x04\x04\x08\x02\0\x12\x04\x84\x01\x02\x11\n\r\n\x05\x04\x08\x02\0\
-    \x05\x12\x04\x84\x01\x02\x07\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\x84\x01\
-    \x08\x0c\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\x84\x01\x0f\x10\n\x0c\n\x04\
-    \x04\x08\x02\x01\x12\x04\x85\x01\x02#\n\r\n\x05\x04\x08\x02\x01\x06\x12\
-    \x04\x85\x01\x02\x15\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\x85\x01\x16\
-    \x1e\n\r\n\x05\x04\x08\x02\x01\x03\x12\x04\x85\x01!\"\nT\n\x04\x04\x08\
-    \x02\x02\x12\x04\x87\x01\x02\x1f\x1aF\x20a\x20valid\x20URL\x20with\x20a\
-    \x20scheme\x20prefix\x20e.g.\x20`s3://`,\x20`file://`,\x20`https://`\n\n\
-    \r\n\x05\x04\x08\x02\x02\x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\x08\x02\
-    \x02\x05\x12\x04\x87\x01\x0b\x11\n\r\n\x05\x04\x08\x02\x02\x01\x12\x04\
-    \x87\x01\x12\x1a\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\x87\x01\x1d\x1e\n\
-    L\n\x02\x04\t\x12\x06\x8b\x01\0\x8f\x01\x01\x1a>\x20The\x20message\x20re\
-    turned\x20in\x20response\x20to\x20a\x20`CreateModuleRequest`.\n\n\x0b\n\
-    \x03\x04\t\x01\x12\x04\x8b\x01\x08\x1c\n\x0c\n\x04\x04\t\x02\0\x12\x04\
-    \x8c\x01\x02\x16\n\r\n\x05\x04\t\x02\0\x05\x12\x04\x8c\x01\x02\x07\n\r\n\
-    \x05\x04\t\x02\0\x01\x12\x04\x8c\x01\x08\x11\n\r\n\x05\x04\t\x02\0\x03\
-    \x12\x04\x8c\x01\x14\x15\n\x0c\n\x04\x04\t\x02\x01\x12\x04\x8d\x01\x02\
-    \x12\n\r\n\x05\x04\t\x02\x01\x05\x12\x04\x8d\x01\x02\x08\n\r\n\x05\x04\t\
-    \x02\x01\x01\x12\x04\x8d\x01\t\r\n\r\n\x05\x04\t\x02\x01\x03\x12\x04\x8d\
-    \x01\x10\x11\n\x0c\n\x04\x04\t\x02\x02\x12\x04\x8e\x01\x02\x1b\n\r\n\x05\
-    \x04\t\x02\x02\x04\x12\x04\x8e\x01\x02\n\n\r\n\x05\x04\t\x02\x02\x06\x12\
-    \x04\x8e\x01\x0b\x10\n\r\n\x05\x04\t\x02\x02\x01\x12\x04\x8e\x01\x11\x16\
-    \n\r\n\x05\x04\t\x02\x02\x03\x12\x04\x8e\x01\x19\x1a\n=\n\x02\x04\n\x12\
-    \x04\x93\x01\01\x1a1\x20`POST\x20/api/v1/module:`\n\x20Return\x20a\x20si\
-    ngle\x20module.\n\n\x0b\n\x03\x04\n\x01\x12\x04\x93\x01\x08\x18\n\x0c\n\
-    \x04\x04\n\x02\0\x12\x04\x93\x01\x1b/\n\r\n\x05\x04\n\x02\0\x05\x12\x04\
-    \x93\x01\x1b\x20\n\r\n\x05\x04\n\x02\0\x01\x12\x04\x93\x01!*\n\r\n\x05\
-    \x04\n\x02\0\x03\x12\x04\x93\x01-.\nI\n\x02\x04\x0b\x12\x06\x96\x01\0\
-    \x99\x01\x01\x1a;\x20The\x20message\x20returned\x20in\x20response\x20to\
-    \x20a\x20`GetModuleRequest`.\n\n\x0b\n\x03\x04\x0b\x01\x12\x04\x96\x01\
-    \x08\x19\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\x97\x01\x02\x14\n\r\n\x05\x04\
-    \x0b\x02\0\x06\x12\x04\x97\x01\x02\x08\n\r\n\x05\x04\x0b\x02\0\x01\x12\
-    \x04\x97\x01\t\x0f\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\x97\x01\x12\x13\n\
-    \x0c\n\x04\x04\x0b\x02\x01\x12\x04\x98\x01\x02\x1b\n\r\n\x05\x04\x0b\x02\
-    \x01\x04\x12\x04\x98\x01\x02\n\n\r\n\x05\x04\x0b\x02\x01\x06\x12\x04\x98\
-    \x01\x0b\x10\n\r\n\x05\x04\x0b\x02\x01\x01\x12\x04\x98\x01\x11\x16\n\r\n\
-    \x05\x04\x0b\x02\x01\x03\x12\x04\x98\x01\x19\x1a\nN\n\x02\x04\x0c\x12\
-    \x06\x9d\x01\0\xa0\x01\x01\x1a@\x20`POST\x20/api/v1/modules:`\n\x20Retur\
-    n\x20paginated\x20list\x20of\x20all\x20modules.\n\n\x0b\n\x03\x04\x0c\
-    \x01\x12\x04\x9d\x01\x08\x1a\n\x0c\n\x04\x04\x0c\x02\0\x12\x04\x9e\x01\
-    \x02\x1c\n\r\n\x05\x04\x0c\x02\0\x06\x12\x04\x9e\x01\x02\x0c\n\r\n\x05\
-    \x04\x0c\x02\0\x01\x12\x04\x9e\x01\r\x17\n\r\n\x05\x04\x0c\x02\0\x03\x12\
-    \x04\x9e\x01\x1a\x1b\n\x0c\n\x04\x04\x0c\x02\x01\x12\x04\x9f\x01\x02\x10\
-    \n\r\n\x05\x04\x0c\x02\x01\x06\x12\x04\x9f\x01\x02\x06\n\r\n\x05\x04\x0c\
-    \x02\x01\x01\x12\x04\x9f\x01\x07\x0b\n\r\n\x05\x04\x0c\x02\x01\x03\x12\
-    \x04\x9f\x01\x0e\x0f\nK\n\x02\x04\r\x12\x06\xa3\x01\0\xab\x01\x01\x1a=\
-    \x20The\x20message\x20returned\x20in\x20response\x20to\x20a\x20`ListModu\
-    lesRequest`.\n\n\x0b\n\x03\x04\r\x01\x12\x04\xa3\x01\x08\x1b\n\x0c\n\x04\
-    \x04\r\x02\0\x12\x04\xa4\x01\x02\x1e\n\r\n\x05\x04\r\x02\0\x04\x12\x04\
-    \xa4\x01\x02\n\n\r\n\x05\x04\r\x02\0\x06\x12\x04\xa4\x01\x0b\x11\n\r\n\
-    \x05\x04\r\x02\0\x01\x12\x04\xa4\x01\x12\x19\n\r\n\x05\x04\r\x02\0\x03\
-    \x12\x04\xa4\x01\x1c\x1d\n\x0c\n\x04\x04\r\x02\x01\x12\x04\xa5\x01\x02\
-    \x1c\n\r\n\x05\x04\r\x02\x01\x06\x12\x04\xa5\x01\x02\x0c\n\r\n\x05\x04\r\
-    \x02\x01\x01\x12\x04\xa5\x01\r\x17\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\
-    \xa5\x01\x1a\x1b\ng\n\x04\x04\r\x02\x02\x12\x04\xa8\x01\x02\x13\x1aY\x20\
-    the\x20full\x20count\x20of\x20results\x20in\x20the\x20database\x20(not\
-    \x20the\x20count\x20of\x20this\x20message's\n\x20`modules`).\n\n\r\n\x05\
-    \x04\r\x02\x02\x05\x12\x04\xa8\x01\x02\x08\n\r\n\x05\x04\r\x02\x02\x01\
-    \x12\x04\xa8\x01\t\x0e\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\xa8\x01\x11\
-    \x12\n\x0c\n\x04\x04\r\x02\x03\x12\x04\xa9\x01\x02\x10\n\r\n\x05\x04\r\
-    \x02\x03\x06\x12\x04\xa9\x01\x02\x06\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\
-    \xa9\x01\x07\x0b\n\r\n\x05\x04\r\x02\x03\x03\x12\x04\xa9\x01\x0e\x0f\n\
-    \x0c\n\x04\x04\r\x02\x04\x12\x04\xaa\x01\x02\x1b\n\r\n\x05\x04\r\x02\x04\
-    \x04\x12\x04\xaa\x01\x02\n\n\r\n\x05\x04\r\x02\x04\x06\x12\x04\xaa\x01\
-    \x0b\x10\n\r\n\x05\x04\r\x02\x04\x01\x12\x04\xaa\x01\x11\x16\n\r\n\x05\
-    \x04\r\x02\x04\x03\x12\x04\xaa\x01\x19\x1a\n\xe8\x01\n\x02\x04\x0e\x12\
-    \x06\xb1\x01\0\xd4\x01\x01\x1a\xd9\x01\x20`POST\x20/api/v1/search:`\n\
-    \x20Search\x20for\x20modules\x20based\x20on\x20filter\x20params\x20provi\
-    ded\x20(which\x20should\x20be\x20any\n\x20dimension\x20of\x20the\x20modu\
-    le\x20schema,\x20or\x20string\x20search\x20in\x20any\x20metadata\x20valu\
-    e).\n\x20Return\x20a\x20paginated\x20list\x20of\x20matching\x20modules.\
-    \n\n\x0b\n\x03\x04\x0e\x01\x12\x04\xb1\x01\x08\x1c\n>\n\x04\x04\x0e\x02\
-    \0\x12\x04\xb3\x01\x02\x18\x1a0\x20ID\x20for\x20this\x20module,\x20gener\
-    ated\x20by\x20the\x20database.\n\n\r\n\x05\x04\x0e\x02\0\x04\x12\x04\xb3\
-    \x01\x02\n\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\xb3\x01\x0b\x10\n\r\n\x05\
-    \x04\x0e\x02\0\x01\x12\x04\xb3\x01\x11\x13\n\r\n\x05\x04\x0e\x02\0\x03\
-    \x12\x04\xb3\x01\x16\x17\n7\n\x04\x04\x0e\x02\x01\x12\x04\xb5\x01\x02\
-    \x1b\x1a)\x20original\x20name\x20of\x20the\x20binary\x20module\x20file\n\
-    \n\r\n\x05\x04\x0e\x02\x01\x04\x12\x04\xb5\x01\x02\n\n\r\n\x05\x04\x0e\
-    \x02\x01\x05\x12\x04\xb5\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x01\x01\x12\
-    \x04\xb5\x01\x12\x16\n\r\n\x05\x04\x0e\x02\x01\x03\x12\x04\xb5\x01\x19\
-    \x1a\n\x82\x01\n\x04\x04\x0e\x02\x02\x12\x04\xb8\x01\x02\x1e\x1at\x20fun\
-    ction\x20imports\x20called\x20by\x20the\x20module\x20(see:\n\x20<https:/\
-    /github.com/WebAssembly/design/blob/main/Modules.md#imports>)\n\n\r\n\
-    \x05\x04\x0e\x02\x02\x04\x12\x04\xb8\x01\x02\n\n\r\n\x05\x04\x0e\x02\x02\
-    \x06\x12\x04\xb8\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x02\x01\x12\x04\xb8\
-    \x01\x12\x19\n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\xb8\x01\x1c\x1d\n\x84\
-    \x01\n\x04\x04\x0e\x02\x03\x12\x04\xbb\x01\x02\x1e\x1av\x20function\x20e\
-    xports\x20provided\x20by\x20the\x20module\x20(see:\n\x20<https://github.\
-    com/WebAssembly/design/blob/main/Modules.md#exports>)\n\n\r\n\x05\x04\
-    \x0e\x02\x03\x04\x12\x04\xbb\x01\x02\n\n\r\n\x05\x04\x0e\x02\x03\x06\x12\
-    \x04\xbb\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x03\x01\x12\x04\xbb\x01\x12\
-    \x19\n\r\n\x05\x04\x0e\x02\x03\x03\x12\x04\xbb\x01\x1c\x1d\n3\n\x04\x04\
-    \x0e\x02\x04\x12\x04\xbd\x01\x02\x1f\x1a%\x20minimum\x20size\x20in\x20by\
-    tes\x20of\x20the\x20module\n\n\r\n\x05\x04\x0e\x02\x04\x04\x12\x04\xbd\
-    \x01\x02\n\n\r\n\x05\x04\x0e\x02\x04\x05\x12\x04\xbd\x01\x0b\x11\n\r\n\
-    \x05\x04\x0e\x02\x04\x01\x12\x04\xbd\x01\x12\x1a\n\r\n\x05\x04\x0e\x02\
-    \x04\x03\x12\x04\xbd\x01\x1d\x1e\n3\n\x04\x04\x0e\x02\x05\x12\x04\xbf\
-    \x01\x02\x1f\x1a%\x20maximum\x20size\x20in\x20bytes\x20of\x20the\x20modu\
-    le\n\n\r\n\x05\x04\x0e\x02\x05\x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\
-    \x0e\x02\x05\x05\x12\x04\xbf\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x05\x01\
-    \x12\x04\xbf\x01\x12\x1a\n\r\n\x05\x04\x0e\x02\x05\x03\x12\x04\xbf\x01\
-    \x1d\x1e\ng\n\x04\x04\x0e\x02\x06\x12\x04\xc2\x01\x02\x1f\x1aY\x20option\
-    al\x20path\x20or\x20locator\x20to\x20the\x20module\x20(TODO:\x20maybe\
-    \x20this\x20is\x20better\x20stored\n\x20as\x20metadata)\n\n\r\n\x05\x04\
-    \x0e\x02\x06\x04\x12\x04\xc2\x01\x02\n\n\r\n\x05\x04\x0e\x02\x06\x05\x12\
-    \x04\xc2\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x06\x01\x12\x04\xc2\x01\x12\
-    \x1a\n\r\n\x05\x04\x0e\x02\x06\x03\x12\x04\xc2\x01\x1d\x1e\n@\n\x04\x04\
-    \x0e\x02\x07\x12\x04\xc4\x01\x02.\x1a2\x20programming\x20language\x20use\
-    d\x20to\x20produce\x20this\x20module\n\n\r\n\x05\x04\x0e\x02\x07\x04\x12\
-    \x04\xc4\x01\x02\n\n\r\n\x05\x04\x0e\x02\x07\x06\x12\x04\xc4\x01\x0b\x19\
-    \n\r\n\x05\x04\x0e\x02\x07\x01\x12\x04\xc4\x01\x1a)\n\r\n\x05\x04\x0e\
-    \x02\x07\x03\x12\x04\xc4\x01,-\nJ\n\x04\x04\x0e\x02\x08\x12\x04\xc6\x01\
-    \x02$\x1a<\x20arbitrary\x20metadata\x20provided\x20by\x20the\x20operator\
-    \x20of\x20this\x20module\n\n\r\n\x05\x04\x0e\x02\x08\x06\x12\x04\xc6\x01\
-    \x02\x15\n\r\n\x05\x04\x0e\x02\x08\x01\x12\x04\xc6\x01\x16\x1e\n\r\n\x05\
-    \x04\x0e\x02\x08\x03\x12\x04\xc6\x01!#\n@\n\x04\x04\x0e\x02\t\x12\x04\
-    \xc8\x01\x02:\x1a2\x20timestamp\x20when\x20this\x20module\x20was\x20load\
-    ed\x20and\x20stored\n\n\r\n\x05\x04\x0e\x02\t\x04\x12\x04\xc8\x01\x02\n\
-    \n\r\n\x05\x04\x0e\x02\t\x06\x12\x04\xc8\x01\x0b$\n\r\n\x05\x04\x0e\x02\
-    \t\x01\x12\x04\xc8\x01%4\n\r\n\x05\x04\x0e\x02\t\x03\x12\x04\xc8\x0179\n\
-    @\n\x04\x04\x0e\x02\n\x12\x04\xca\x01\x029\x1a2\x20timestamp\x20when\x20\
-    this\x20module\x20was\x20loaded\x20and\x20stored\n\n\r\n\x05\x04\x0e\x02\
-    \n\x04\x12\x04\xca\x01\x02\n\n\r\n\x05\x04\x0e\x02\n\x06\x12\x04\xca\x01\
-    \x0b$\n\r\n\x05\x04\x0e\x02\n\x01\x12\x04\xca\x01%3\n\r\n\x05\x04\x0e\
-    \x02\n\x03\x12\x04\xca\x0168\n[\n\x04\x04\x0e\x02\x0b\x12\x04\xcc\x01\
-    \x02\x1f\x1aM\x20the\x20interned\x20strings\x20stored\x20in\x20the\x20wa\
-    sm\x20binary\x20(panic/abort\x20messages,\x20etc.)\n\n\r\n\x05\x04\x0e\
-    \x02\x0b\x04\x12\x04\xcc\x01\x02\n\n\r\n\x05\x04\x0e\x02\x0b\x05\x12\x04\
-    \xcc\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x0b\x01\x12\x04\xcc\x01\x12\x19\n\
-    \r\n\x05\x04\x0e\x02\x0b\x03\x12\x04\xcc\x01\x1c\x1e\nB\n\x04\x04\x0e\
-    \x02\x0c\x12\x04\xce\x01\x02%\x1a4\x20match\x20on\x20any\x20function\x20\
-    name\x20in\x20an\x20import\x20or\x20export.\n\n\r\n\x05\x04\x0e\x02\x0c\
-    \x04\x12\x04\xce\x01\x02\n\n\r\n\x05\x04\x0e\x02\x0c\x05\x12\x04\xce\x01\
-    \x0b\x11\n\r\n\x05\x04\x0e\x02\x0c\x01\x12\x04\xce\x01\x12\x1f\n\r\n\x05\
-    \x04\x0e\x02\x0c\x03\x12\x04\xce\x01\"$\nO\n\x04\x04\x0e\x02\r\x12\x04\
-    \xd0\x01\x02#\x1aA\x20match\x20on\x20the\x20module\x20name\x20e.g.\x20`e\
-    nv`\x20or\x20`wasi_snapshot_preview1`\n\n\r\n\x05\x04\x0e\x02\r\x04\x12\
-    \x04\xd0\x01\x02\n\n\r\n\x05\x04\x0e\x02\r\x05\x12\x04\xd0\x01\x0b\x11\n\
-    \r\n\x05\x04\x0e\x02\r\x01\x12\x04\xd0\x01\x12\x1d\n\r\n\x05\x04\x0e\x02\
-    \r\x03\x12\x04\xd0\x01\x20\"\n\x0c\n\x04\x04\x0e\x02\x0e\x12\x04\xd2\x01\
-    \x02\x1d\n\r\n\x05\x04\x0e\x02\x0e\x06\x12\x04\xd2\x01\x02\x0c\n\r\n\x05\
-    \x04\x0e\x02\x0e\x01\x12\x04\xd2\x01\r\x17\n\r\n\x05\x04\x0e\x02\x0e\x03\
-    \x12\x04\xd2\x01\x1a\x1c\n\x0c\n\x04\x04\x0e\x02\x0f\x12\x04\xd3\x01\x02\
-    \x11\n\r\n\x05\x04\x0e\x02\x0f\x06\x12\x04\xd3\x01\x02\x06\n\r\n\x05\x04\
-    \x0e\x02\x0f\x01\x12\x04\xd3\x01\x07\x0b\n\r\n\x05\x04\x0e\x02\x0f\x03\
-    \x12\x04\xd3\x01\x0e\x10\nM\n\x02\x04\x0f\x12\x06\xd7\x01\0\xdf\x01\x01\
-    \x1a?\x20The\x20message\x20returned\x20in\x20response\x20to\x20a\x20`Sea\
-    rchModulesRequest`.\n\n\x0b\n\x03\x04\x0f\x01\x12\x04\xd7\x01\x08\x1d\n\
-    \x0c\n\x04\x04\x0f\x02\0\x12\x04\xd8\x01\x02\x1e\n\r\n\x05\x04\x0f\x02\0\
-    \x04\x12\x04\xd8\x01\x02\n\n\r\n\x05\x04\x0f\x02\0\x06\x12\x04\xd8\x01\
-    \x0b\x11\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xd8\x01\x12\x19\n\r\n\x05\
-    \x04\x0f\x02\0\x03\x12\x04\xd8\x01\x1c\x1d\n\x0c\n\x04\x04\x0f\x02\x01\
-    \x12\x04\xd9\x01\x02\x1c\n\r\n\x05\x04\x0f\x02\x01\x06\x12\x04\xd9\x01\
-    \x02\x0c\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xd9\x01\r\x17\n\r\n\x05\
-    \x04\x0f\x02\x01\x03\x12\x04\xd9\x01\x1a\x1b\ng\n\x04\x04\x0f\x02\x02\
-    \x12\x04\xdc\x01\x02\x13\x1aY\x20the\x20full\x20count\x20of\x20results\
+    \x03\x02\x0b\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x03\x02\x0b\x05\x12\x03]\
+    \x0b\x10\n\x0c\n\x05\x04\x03\x02\x0b\x01\x12\x03]\x11\x16\n\x0c\n\x05\
+    \x04\x03\x02\x0b\x03\x12\x03]\x19\x1b\n\x1e\n\x04\x04\x03\x02\x0c\x12\
+    \x03_\x02+\x1a\x11\x20function\x20hashes\n\n\x0c\n\x05\x04\x03\x02\x0c\
+    \x06\x12\x03_\x02\x15\n\x0c\n\x05\x04\x03\x02\x0c\x01\x12\x03_\x16%\n\
+    \x0c\n\x05\x04\x03\x02\x0c\x03\x12\x03_(*\nT\n\x04\x04\x03\x02\r\x12\x03\
+    a\x02\x1c\x1aG\x20free-form\x20labels\x20used\x20to\x20group\x20modules\
+    \x20for\x20fleet\x20queries,\x20e.g.\x20`edge`\n\n\x0c\n\x05\x04\x03\x02\
+    \r\x04\x12\x03a\x02\n\n\x0c\n\x05\x04\x03\x02\r\x05\x12\x03a\x0b\x11\n\
+    \x0c\n\x05\x04\x03\x02\r\x01\x12\x03a\x12\x16\n\x0c\n\x05\x04\x03\x02\r\
+    \x03\x12\x03a\x19\x1b\n@\n\x04\x04\x03\x02\x0e\x12\x03c\x02\x20\x1a3\x20\
+    linear\x20memories\x20defined\x20or\x20imported\x20by\x20the\x20module\n\
+    \n\x0c\n\x05\x04\x03\x02\x0e\x04\x12\x03c\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x0e\x06\x12\x03c\x0b\x11\n\x0c\n\x05\x04\x03\x02\x0e\x01\x12\x03c\x12\
+    \x1a\n\x0c\n\x05\x04\x03\x02\x0e\x03\x12\x03c\x1d\x1f\n7\n\x04\x04\x03\
+    \x02\x0f\x12\x03e\x02\x1d\x1a*\x20tables\x20defined\x20or\x20imported\
+    \x20by\x20the\x20module\n\n\x0c\n\x05\x04\x03\x02\x0f\x04\x12\x03e\x02\n\
+    \n\x0c\n\x05\x04\x03\x02\x0f\x06\x12\x03e\x0b\x10\n\x0c\n\x05\x04\x03\
+    \x02\x0f\x01\x12\x03e\x11\x17\n\x0c\n\x05\x04\x03\x02\x0f\x03\x12\x03e\
+    \x1a\x1c\n8\n\x04\x04\x03\x02\x10\x12\x03g\x02\x1f\x1a+\x20globals\x20de\
+    fined\x20or\x20imported\x20by\x20the\x20module\n\n\x0c\n\x05\x04\x03\x02\
+    \x10\x04\x12\x03g\x02\n\n\x0c\n\x05\x04\x03\x02\x10\x06\x12\x03g\x0b\x11\
+    \n\x0c\n\x05\x04\x03\x02\x10\x01\x12\x03g\x12\x19\n\x0c\n\x05\x04\x03\
+    \x02\x10\x03\x12\x03g\x1c\x1e\n6\n\x04\x04\x03\x02\x11\x12\x03i\x02.\x1a\
+    )\x20custom\x20sections\x20contained\x20in\x20the\x20module\n\n\x0c\n\
+    \x05\x04\x03\x02\x11\x04\x12\x03i\x02\n\n\x0c\n\x05\x04\x03\x02\x11\x06\
+    \x12\x03i\x0b\x18\n\x0c\n\x05\x04\x03\x02\x11\x01\x12\x03i\x19(\n\x0c\n\
+    \x05\x04\x03\x02\x11\x03\x12\x03i+-\nV\n\x04\x04\x03\x02\x12\x12\x03k\
+    \x025\x1aI\x20metrics\x20for\x20each\x20function\x20defined\x20by\x20the\
+    \x20module,\x20keyed\x20by\x20function\x20name\n\n\x0c\n\x05\x04\x03\x02\
+    \x12\x06\x12\x03k\x02\x1e\n\x0c\n\x05\x04\x03\x02\x12\x01\x12\x03k\x1f/\
+    \n\x0c\n\x05\x04\x03\x02\x12\x03\x12\x03k24\nL\n\x04\x04\x03\x02\x13\x12\
+    \x03m\x02\x1f\x1a?\x20tools\x20which\x20produced\x20the\x20module,\x20fr\
+    om\x20the\x20`producers`\x20section\n\n\x0c\n\x05\x04\x03\x02\x13\x04\
+    \x12\x03m\x02\n\n\x0c\n\x05\x04\x03\x02\x13\x06\x12\x03m\x0b\x0f\n\x0c\n\
+    \x05\x04\x03\x02\x13\x01\x12\x03m\x10\x19\n\x0c\n\x05\x04\x03\x02\x13\
+    \x03\x12\x03m\x1c\x1e\n^\n\x04\x04\x03\x02\x14\x12\x03o\x02\x1f\x1aQ\x20\
+    version\x20of\x20the\x20module,\x20e.g.\x20`1.2.0`,\x20if\x20one\x20was\
+    \x20provided\x20or\x20found\x20in\x20the\x20module\n\n\x0c\n\x05\x04\x03\
+    \x02\x14\x04\x12\x03o\x02\n\n\x0c\n\x05\x04\x03\x02\x14\x05\x12\x03o\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\x14\x01\x12\x03o\x12\x19\n\x0c\n\x05\x04\
+    \x03\x02\x14\x03\x12\x03o\x1c\x1e\nW\n\x04\x04\x03\x02\x15\x12\x03q\x02\
+    \x1d\x1aJ\x20licenses\x20and\x20libraries\x20found\x20in\x20the\x20modul\
+    e's\x20strings\x20and\x20custom\x20sections\n\n\x0c\n\x05\x04\x03\x02\
+    \x15\x06\x12\x03q\x02\x0c\n\x0c\n\x05\x04\x03\x02\x15\x01\x12\x03q\r\x17\
+    \n\x0c\n\x05\x04\x03\x02\x15\x03\x12\x03q\x1a\x1c\n2\n\x04\x04\x03\x02\
+    \x16\x12\x03s\x02\x1d\x1a%\x20set\x20while\x20the\x20module\x20is\x20qua\
+    rantined\n\n\x0c\n\x05\x04\x03\x02\x16\x06\x12\x03s\x02\x0c\n\x0c\n\x05\
+    \x04\x03\x02\x16\x01\x12\x03s\r\x17\n\x0c\n\x05\x04\x03\x02\x16\x03\x12\
+    \x03s\x1a\x1c\n?\n\x04\x04\x03\x02\x17\x12\x03u\x02)\x1a2\x20structural\
+    \x20problems\x20found\x20in\x20the\x20module's\x20binary\n\n\x0c\n\x05\
+    \x04\x03\x02\x17\x04\x12\x03u\x02\n\n\x0c\n\x05\x04\x03\x02\x17\x06\x12\
+    \x03u\x0b\x19\n\x0c\n\x05\x04\x03\x02\x17\x01\x12\x03u\x1a#\n\x0c\n\x05\
+    \x04\x03\x02\x17\x03\x12\x03u&(\nc\n\x02\x04\x04\x12\x04y\0\x7f\x01\x1aW\
+    \x20A\x20structural\x20problem\x20found\x20in\x20a\x20module's\x20binary\
+    ,\x20e.g.\x20an\x20out\x20of\x20bounds\x20function\x20index\n\n\n\n\x03\
+    \x04\x04\x01\x12\x03y\x08\x16\n<\n\x04\x04\x04\x02\0\x12\x03{\x02\x12\
+    \x1a/\x20e.g.\x20\"index_out_of_bounds\"\x20or\x20\"section_order\"\n\n\
+    \x0c\n\x05\x04\x04\x02\0\x05\x12\x03{\x02\x08\n\x0c\n\x05\x04\x04\x02\0\
+    \x01\x12\x03{\t\r\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03{\x10\x11\n@\n\
+    \x04\x04\x04\x02\x01\x12\x03}\x02\x14\x1a3\x20the\x20offset\x20of\x20the\
+    \x20problem\x20in\x20the\x20binary,\x20in\x20bytes\n\n\x0c\n\x05\x04\x04\
+    \x02\x01\x05\x12\x03}\x02\x08\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03}\t\
+    \x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03}\x12\x13\n\x0b\n\x04\x04\
+    \x04\x02\x02\x12\x03~\x02\x15\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x03~\
+    \x02\x08\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03~\t\x10\n\x0c\n\x05\x04\
+    \x04\x02\x02\x03\x12\x03~\x13\x14\nk\n\x02\x04\x05\x12\x06\x83\x01\0\x87\
+    \x01\x01\x1a]\x20A\x20module\x20flagged\x20as\x20blocked,\x20e.g.\x20dur\
+    ing\x20incident\x20response,\x20without\x20deleting\n\x20or\x20yanking\
+    \x20it\n\n\x0b\n\x03\x04\x05\x01\x12\x04\x83\x01\x08\x12\nD\n\x04\x04\
+    \x05\x02\0\x12\x04\x85\x01\x02\x14\x1a6\x20why\x20the\x20module\x20was\
+    \x20quarantined,\x20e.g.\x20`CVE-2024-1234`\n\n\r\n\x05\x04\x05\x02\0\
+    \x05\x12\x04\x85\x01\x02\x08\n\r\n\x05\x04\x05\x02\0\x01\x12\x04\x85\x01\
+    \t\x0f\n\r\n\x05\x04\x05\x02\0\x03\x12\x04\x85\x01\x12\x13\n\x0c\n\x04\
+    \x04\x05\x02\x01\x12\x04\x86\x01\x02/\n\r\n\x05\x04\x05\x02\x01\x06\x12\
+    \x04\x86\x01\x02\x1b\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\x86\x01\x1c*\
+    \n\r\n\x05\x04\x05\x02\x01\x03\x12\x04\x86\x01-.\nM\n\x02\x04\x06\x12\
+    \x06\x8a\x01\0\x8e\x01\x01\x1a?\x20Licenses\x20and\x20third-party\x20lib\
+    raries\x20found\x20embedded\x20in\x20a\x20module\n\n\x0b\n\x03\x04\x06\
+    \x01\x12\x04\x8a\x01\x08\x12\n5\n\x04\x04\x06\x02\0\x12\x04\x8c\x01\x02\
+    \x1f\x1a'\x20SPDX\x20identifiers,\x20e.g.\x20`GPL-3.0-only`\n\n\r\n\x05\
+    \x04\x06\x02\0\x04\x12\x04\x8c\x01\x02\n\n\r\n\x05\x04\x06\x02\0\x05\x12\
+    \x04\x8c\x01\x0b\x11\n\r\n\x05\x04\x06\x02\0\x01\x12\x04\x8c\x01\x12\x1a\
+    \n\r\n\x05\x04\x06\x02\0\x03\x12\x04\x8c\x01\x1d\x1e\n\x0c\n\x04\x04\x06\
+    \x02\x01\x12\x04\x8d\x01\x02!\n\r\n\x05\x04\x06\x02\x01\x04\x12\x04\x8d\
+    \x01\x02\n\n\r\n\x05\x04\x06\x02\x01\x06\x12\x04\x8d\x01\x0b\x12\n\r\n\
+    \x05\x04\x06\x02\x01\x01\x12\x04\x8d\x01\x13\x1c\n\r\n\x05\x04\x06\x02\
+    \x01\x03\x12\x04\x8d\x01\x1f\x20\n^\n\x02\x04\x07\x12\x06\x91\x01\0\x94\
+    \x01\x01\x1aP\x20A\x20library\x20identified\x20by\x20a\x20version\x20str\
+    ing\x20found\x20in\x20a\x20module,\x20e.g.\x20`zlib\x201.2.11`\n\n\x0b\n\
+    \x03\x04\x07\x01\x12\x04\x91\x01\x08\x0f\n\x0c\n\x04\x04\x07\x02\0\x12\
+    \x04\x92\x01\x02\x12\n\r\n\x05\x04\x07\x02\0\x05\x12\x04\x92\x01\x02\x08\
+    \n\r\n\x05\x04\x07\x02\0\x01\x12\x04\x92\x01\t\r\n\r\n\x05\x04\x07\x02\0\
+    \x03\x12\x04\x92\x01\x10\x11\n\x0c\n\x04\x04\x07\x02\x01\x12\x04\x93\x01\
+    \x02\x15\n\r\n\x05\x04\x07\x02\x01\x05\x12\x04\x93\x01\x02\x08\n\r\n\x05\
+    \x04\x07\x02\x01\x01\x12\x04\x93\x01\t\x10\n\r\n\x05\x04\x07\x02\x01\x03\
+    \x12\x04\x93\x01\x13\x14\n?\n\x02\x04\x08\x12\x06\x97\x01\0\x9f\x01\x01\
+    \x1a1\x20A\x20linear\x20memory\x20defined\x20or\x20imported\x20by\x20a\
+    \x20module\n\n\x0b\n\x03\x04\x08\x01\x12\x04\x97\x01\x08\x0e\n:\n\x04\
+    \x04\x08\x02\0\x12\x04\x99\x01\x02\x15\x1a,\x20initial\x20size\x20of\x20\
+    the\x20memory,\x20in\x2064KiB\x20pages\n\n\r\n\x05\x04\x08\x02\0\x05\x12\
+    \x04\x99\x01\x02\x08\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\x99\x01\t\x10\n\
+    \r\n\x05\x04\x08\x02\0\x03\x12\x04\x99\x01\x13\x14\nF\n\x04\x04\x08\x02\
+    \x01\x12\x04\x9b\x01\x02\x1e\x1a8\x20maximum\x20size\x20of\x20the\x20mem\
+    ory,\x20in\x2064KiB\x20pages,\x20if\x20limited\n\n\r\n\x05\x04\x08\x02\
+    \x01\x04\x12\x04\x9b\x01\x02\n\n\r\n\x05\x04\x08\x02\x01\x05\x12\x04\x9b\
+    \x01\x0b\x11\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\x9b\x01\x12\x19\n\r\n\
+    \x05\x04\x08\x02\x01\x03\x12\x04\x9b\x01\x1c\x1d\n\x0c\n\x04\x04\x08\x02\
+    \x02\x12\x04\x9c\x01\x02\x12\n\r\n\x05\x04\x08\x02\x02\x05\x12\x04\x9c\
+    \x01\x02\x06\n\r\n\x05\x04\x08\x02\x02\x01\x12\x04\x9c\x01\x07\r\n\r\n\
+    \x05\x04\x08\x02\x02\x03\x12\x04\x9c\x01\x10\x11\n\x0c\n\x04\x04\x08\x02\
+    \x03\x12\x04\x9d\x01\x02\x14\n\r\n\x05\x04\x08\x02\x03\x05\x12\x04\x9d\
+    \x01\x02\x06\n\r\n\x05\x04\x08\x02\x03\x01\x12\x04\x9d\x01\x07\x0f\n\r\n\
+    \x05\x04\x08\x02\x03\x03\x12\x04\x9d\x01\x12\x13\n\x0c\n\x04\x04\x08\x02\
+    \x04\x12\x04\x9e\x01\x02\x14\n\r\n\x05\x04\x08\x02\x04\x05\x12\x04\x9e\
+    \x01\x02\x06\n\r\n\x05\x04\x08\x02\x04\x01\x12\x04\x9e\x01\x07\x0f\n\r\n\
+    \x05\x04\x08\x02\x04\x03\x12\x04\x9e\x01\x12\x13\nE\n\x02\x04\t\x12\x06\
+    \xa2\x01\0\xa7\x01\x01\x1a7\x20A\x20table\x20of\x20references\x20defined\
+    \x20or\x20imported\x20by\x20a\x20module\n\n\x0b\n\x03\x04\t\x01\x12\x04\
+    \xa2\x01\x08\r\n\x0c\n\x04\x04\t\x02\0\x12\x04\xa3\x01\x02\x1b\n\r\n\x05\
+    \x04\t\x02\0\x06\x12\x04\xa3\x01\x02\t\n\r\n\x05\x04\t\x02\0\x01\x12\x04\
+    \xa3\x01\n\x16\n\r\n\x05\x04\t\x02\0\x03\x12\x04\xa3\x01\x19\x1a\n\x0c\n\
+    \x04\x04\t\x02\x01\x12\x04\xa4\x01\x02\x15\n\r\n\x05\x04\t\x02\x01\x05\
+    \x12\x04\xa4\x01\x02\x08\n\r\n\x05\x04\t\x02\x01\x01\x12\x04\xa4\x01\t\
+    \x10\n\r\n\x05\x04\t\x02\x01\x03\x12\x04\xa4\x01\x13\x14\n\x0c\n\x04\x04\
+    \t\x02\x02\x12\x04\xa5\x01\x02\x1e\n\r\n\x05\x04\t\x02\x02\x04\x12\x04\
+    \xa5\x01\x02\n\n\r\n\x05\x04\t\x02\x02\x05\x12\x04\xa5\x01\x0b\x11\n\r\n\
+    \x05\x04\t\x02\x02\x01\x12\x04\xa5\x01\x12\x19\n\r\n\x05\x04\t\x02\x02\
+    \x03\x12\x04\xa5\x01\x1c\x1d\n\x0c\n\x04\x04\t\x02\x03\x12\x04\xa6\x01\
+    \x02\x14\n\r\n\x05\x04\t\x02\x03\x05\x12\x04\xa6\x01\x02\x06\n\r\n\x05\
+    \x04\t\x02\x03\x01\x12\x04\xa6\x01\x07\x0f\n\r\n\x05\x04\t\x02\x03\x03\
+    \x12\x04\xa6\x01\x12\x13\nA\n\x02\x04\n\x12\x06\xaa\x01\0\xae\x01\x01\
+    \x1a3\x20A\x20global\x20variable\x20defined\x20or\x20imported\x20by\x20a\
+    \x20module\n\n\x0b\n\x03\x04\n\x01\x12\x04\xaa\x01\x08\x0e\n\x0c\n\x04\
+    \x04\n\x02\0\x12\x04\xab\x01\x02\x11\n\r\n\x05\x04\n\x02\0\x06\x12\x04\
+    \xab\x01\x02\t\n\r\n\x05\x04\n\x02\0\x01\x12\x04\xab\x01\n\x0c\n\r\n\x05\
+    \x04\n\x02\0\x03\x12\x04\xab\x01\x0f\x10\n\x0c\n\x04\x04\n\x02\x01\x12\
+    \x04\xac\x01\x02\x13\n\r\n\x05\x04\n\x02\x01\x05\x12\x04\xac\x01\x02\x06\
+    \n\r\n\x05\x04\n\x02\x01\x01\x12\x04\xac\x01\x07\x0e\n\r\n\x05\x04\n\x02\
+    \x01\x03\x12\x04\xac\x01\x11\x12\n\x0c\n\x04\x04\n\x02\x02\x12\x04\xad\
+    \x01\x02\x14\n\r\n\x05\x04\n\x02\x02\x05\x12\x04\xad\x01\x02\x06\n\r\n\
+    \x05\x04\n\x02\x02\x01\x12\x04\xad\x01\x07\x0f\n\r\n\x05\x04\n\x02\x02\
+    \x03\x12\x04\xad\x01\x12\x13\n6\n\x02\x04\x0b\x12\x06\xb1\x01\0\xb5\x01\
+    \x01\x1a(\x20A\x20custom\x20section\x20contained\x20in\x20a\x20module\n\
+    \n\x0b\n\x03\x04\x0b\x01\x12\x04\xb1\x01\x08\x15\n\x0c\n\x04\x04\x0b\x02\
+    \0\x12\x04\xb2\x01\x02\x12\n\r\n\x05\x04\x0b\x02\0\x05\x12\x04\xb2\x01\
+    \x02\x08\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\xb2\x01\t\r\n\r\n\x05\x04\
+    \x0b\x02\0\x03\x12\x04\xb2\x01\x10\x11\n3\n\x04\x04\x0b\x02\x01\x12\x04\
+    \xb4\x01\x02\x12\x1a%\x20size\x20in\x20bytes\x20of\x20the\x20section's\
+    \x20data\n\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\xb4\x01\x02\x08\n\r\n\
+    \x05\x04\x0b\x02\x01\x01\x12\x04\xb4\x01\t\r\n\r\n\x05\x04\x0b\x02\x01\
+    \x03\x12\x04\xb4\x01\x10\x11\nE\n\x02\x04\x0c\x12\x06\xb8\x01\0\xbd\x01\
+    \x01\x1a7\x20Measurements\x20of\x20a\x20single\x20function\x20defined\
+    \x20by\x20a\x20module\n\n\x0b\n\x03\x04\x0c\x01\x12\x04\xb8\x01\x08\x17\
+    \n\x0c\n\x04\x04\x0c\x02\0\x12\x04\xb9\x01\x02\x18\n\r\n\x05\x04\x0c\x02\
+    \0\x05\x12\x04\xb9\x01\x02\x08\n\r\n\x05\x04\x0c\x02\0\x01\x12\x04\xb9\
+    \x01\t\x13\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\xb9\x01\x16\x17\n\x0c\n\
+    \x04\x04\x0c\x02\x01\x12\x04\xba\x01\x02\x1a\n\r\n\x05\x04\x0c\x02\x01\
+    \x05\x12\x04\xba\x01\x02\x08\n\r\n\x05\x04\x0c\x02\x01\x01\x12\x04\xba\
+    \x01\t\x15\n\r\n\x05\x04\x0c\x02\x01\x03\x12\x04\xba\x01\x18\x19\n2\n\
+    \x04\x04\x0c\x02\x02\x12\x04\xbc\x01\x02\x12\x1a$\x20size\x20in\x20bytes\
+    \x20of\x20the\x20function\x20body\n\n\r\n\x05\x04\x0c\x02\x02\x05\x12\
+    \x04\xbc\x01\x02\x08\n\r\n\x05\x04\x0c\x02\x02\x01\x12\x04\xbc\x01\t\r\n\
+    \r\n\x05\x04\x0c\x02\x02\x03\x12\x04\xbc\x01\x10\x11\n;\n\x02\x04\r\x12\
+    \x06\xc0\x01\0\xc3\x01\x01\x1a-\x20A\x20tool\x20which\x20produced\x20or\
+    \x20processed\x20a\x20module\n\n\x0b\n\x03\x04\r\x01\x12\x04\xc0\x01\x08\
+    \x0c\n\x0c\n\x04\x04\r\x02\0\x12\x04\xc1\x01\x02\x12\n\r\n\x05\x04\r\x02\
+    \0\x05\x12\x04\xc1\x01\x02\x08\n\r\n\x05\x04\r\x02\0\x01\x12\x04\xc1\x01\
+    \t\r\n\r\n\x05\x04\r\x02\0\x03\x12\x04\xc1\x01\x10\x11\n\x0c\n\x04\x04\r\
+    \x02\x01\x12\x04\xc2\x01\x02\x15\n\r\n\x05\x04\r\x02\x01\x05\x12\x04\xc2\
+    \x01\x02\x08\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\xc2\x01\t\x10\n\r\n\x05\
+    \x04\r\x02\x01\x03\x12\x04\xc2\x01\x13\x14\n1\n\x02\x04\x0e\x12\x06\xc6\
+    \x01\0\xcb\x01\x01\x1a#\x20Details\x20about\x20a\x20wasm\x20module\x20gr\
+    aph\n\n\x0b\n\x03\x04\x0e\x01\x12\x04\xc6\x01\x08\x13\n>\n\x04\x04\x0e\
+    \x02\0\x12\x04\xc8\x01\x02\x0f\x1a0\x20ID\x20for\x20this\x20module,\x20g\
+    enerated\x20by\x20the\x20database.\n\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\
+    \xc8\x01\x02\x07\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xc8\x01\x08\n\n\r\n\
+    \x05\x04\x0e\x02\0\x03\x12\x04\xc8\x01\r\x0e\n3\n\x04\x04\x0e\x02\x01\
+    \x12\x04\xca\x01\x02\x17\x1a%\x20the\x20serialized\x20graph\x20in\x20jso\
+    n\x20format\n\n\r\n\x05\x04\x0e\x02\x01\x05\x12\x04\xca\x01\x02\x07\n\r\
+    \n\x05\x04\x0e\x02\x01\x01\x12\x04\xca\x01\x08\x12\n\r\n\x05\x04\x0e\x02\
+    \x01\x03\x12\x04\xca\x01\x15\x16\nA\n\x02\x04\x0f\x12\x06\xce\x01\0\xd1\
+    \x01\x01\x1a3\x20An\x20error\x20message\x20indicating\x20a\x20problem\
+    \x20in\x20the\x20API.\n\n\x0b\n\x03\x04\x0f\x01\x12\x04\xce\x01\x08\r\n\
+    \x0c\n\x04\x04\x0f\x02\0\x12\x04\xcf\x01\x02\x11\n\r\n\x05\x04\x0f\x02\0\
+    \x05\x12\x04\xcf\x01\x02\x07\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xcf\x01\
+    \x08\x0c\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xcf\x01\x0f\x10\n\x0c\n\x04\
+    \x04\x0f\x02\x01\x12\x04\xd0\x01\x02\x15\n\r\n\x05\x04\x0f\x02\x01\x05\
+    \x12\x04\xd0\x01\x02\x08\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xd0\x01\t\
+    \x10\n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\xd0\x01\x13\x14\n_\n\x02\x04\
+    \x10\x12\x06\xd5\x01\0\xd8\x01\x01\x1aQ\x20Control/limit\x20the\x20way\
+    \x20results\x20are\x20paginated\x20when\x20working\x20with\x20large\n\
+    \x20responses.\n\n\x0b\n\x03\x04\x10\x01\x12\x04\xd5\x01\x08\x12\n\x0c\n\
+    \x04\x04\x10\x02\0\x12\x04\xd6\x01\x02\x13\n\r\n\x05\x04\x10\x02\0\x05\
+    \x12\x04\xd6\x01\x02\x08\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\xd6\x01\t\
+    \x0e\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xd6\x01\x11\x12\n\x0c\n\x04\x04\
+    \x10\x02\x01\x12\x04\xd7\x01\x02\x14\n\r\n\x05\x04\x10\x02\x01\x05\x12\
+    \x04\xd7\x01\x02\x08\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\xd7\x01\t\x0f\
+    \n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\xd7\x01\x12\x13\n:\n\x02\x04\x11\
+    \x12\x06\xdb\x01\0\xde\x01\x01\x1a,\x20Determine\x20how\x20to\x20sort\
+    \x20results\x20from\x20the\x20API\n\n\x0b\n\x03\x04\x11\x01\x12\x04\xdb\
+    \x01\x08\x0c\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xdc\x01\x02\x1a\n\r\n\x05\
+    \x04\x11\x02\0\x06\x12\x04\xdc\x01\x02\x0b\n\r\n\x05\x04\x11\x02\0\x01\
+    \x12\x04\xdc\x01\x0c\x15\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xdc\x01\x18\
+    \x19\n\x0c\n\x04\x04\x11\x02\x01\x12\x04\xdd\x01\x02\x12\n\r\n\x05\x04\
+    \x11\x02\x01\x06\x12\x04\xdd\x01\x02\x07\n\r\n\x05\x04\x11\x02\x01\x01\
+    \x12\x04\xdd\x01\x08\r\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xdd\x01\x10\
+    \x11\nN\n\x02\x05\x02\x12\x06\xe1\x01\0\xe4\x01\x01\x1a@\x20The\x20direc\
+    tion,\x20descending\x20or\x20ascending,\x20of\x20the\x20sort\x20operatio\
+    n.\n\n\x0b\n\x03\x05\x02\x01\x12\x04\xe1\x01\x05\x0e\n\x0c\n\x04\x05\x02\
+    \x02\0\x12\x04\xe2\x01\x02\x0b\n\r\n\x05\x05\x02\x02\0\x01\x12\x04\xe2\
+    \x01\x02\x06\n\r\n\x05\x05\x02\x02\0\x02\x12\x04\xe2\x01\t\n\n\x0c\n\x04\
+    \x05\x02\x02\x01\x12\x04\xe3\x01\x02\n\n\r\n\x05\x05\x02\x02\x01\x01\x12\
+    \x04\xe3\x01\x02\x05\n\r\n\x05\x05\x02\x02\x01\x02\x12\x04\xe3\x01\x08\t\
+    \nY\n\x02\x05\x03\x12\x06\xe7\x01\0\xf1\x01\x01\x1aK\x20The\x20field\x20\
+    within\x20the\x20Module\x20schema\x20that\x20is\x20used\x20as\x20the\x20\
+    sorting\x20dimension.\n\n\x0b\n\x03\x05\x03\x01\x12\x04\xe7\x01\x05\n\n\
+    \x0c\n\x04\x05\x03\x02\0\x12\x04\xe8\x01\x02\x10\n\r\n\x05\x05\x03\x02\0\
+    \x01\x12\x04\xe8\x01\x02\x0b\n\r\n\x05\x05\x03\x02\0\x02\x12\x04\xe8\x01\
+    \x0e\x0f\n\x0c\n\x04\x05\x03\x02\x01\x12\x04\xe9\x01\x02\x0b\n\r\n\x05\
+    \x05\x03\x02\x01\x01\x12\x04\xe9\x01\x02\x06\n\r\n\x05\x05\x03\x02\x01\
+    \x02\x12\x04\xe9\x01\t\n\n\x0c\n\x04\x05\x03\x02\x02\x12\x04\xea\x01\x02\
+    \x0b\n\r\n\x05\x05\x03\x02\x02\x01\x12\x04\xea\x01\x02\x06\n\r\n\x05\x05\
+    \x03\x02\x02\x02\x12\x04\xea\x01\t\n\n\x0c\n\x04\x05\x03\x02\x03\x12\x04\
+    \xeb\x01\x02\x0f\n\r\n\x05\x05\x03\x02\x03\x01\x12\x04\xeb\x01\x02\n\n\r\
+    \n\x05\x05\x03\x02\x03\x02\x12\x04\xeb\x01\r\x0e\n\x0c\n\x04\x05\x03\x02\
+    \x04\x12\x04\xec\x01\x02\x13\n\r\n\x05\x05\x03\x02\x04\x01\x12\x04\xec\
+    \x01\x02\x0e\n\r\n\x05\x05\x03\x02\x04\x02\x12\x04\xec\x01\x11\x12\n\x0c\
+    \n\x04\x05\x03\x02\x05\x12\x04\xed\x01\x02\x13\n\r\n\x05\x05\x03\x02\x05\
+    \x01\x12\x04\xed\x01\x02\x0e\n\r\n\x05\x05\x03\x02\x05\x02\x12\x04\xed\
+    \x01\x11\x12\n\x0c\n\x04\x05\x03\x02\x06\x12\x04\xee\x01\x02\r\n\r\n\x05\
+    \x05\x03\x02\x06\x01\x12\x04\xee\x01\x02\x08\n\r\n\x05\x05\x03\x02\x06\
+    \x02\x12\x04\xee\x01\x0b\x0c\n\x0c\n\x04\x05\x03\x02\x07\x12\x04\xef\x01\
+    \x02\x11\n\r\n\x05\x05\x03\x02\x07\x01\x12\x04\xef\x01\x02\x0c\n\r\n\x05\
+    \x05\x03\x02\x07\x02\x12\x04\xef\x01\x0f\x10\n\x0c\n\x04\x05\x03\x02\x08\
+    \x12\x04\xf0\x01\x02\x10\n\r\n\x05\x05\x03\x02\x08\x01\x12\x04\xf0\x01\
+    \x02\x0b\n\r\n\x05\x05\x03\x02\x08\x02\x12\x04\xf0\x01\x0e\x0f\nn\n\x02\
+    \x04\x12\x12\x06\xf5\x01\0\xfe\x01\x01\x1a`\x20`PUT\x20/api/v1/module:`\
+    \n\x20Insert\x20a\x20module,\x20extract\x20data\x20from\x20binary.\x20Re\
+    turn\x20the\x20module\x20ID\x20&\x20hash.\n\n\x0b\n\x03\x04\x12\x01\x12\
+    \x04\xf5\x01\x08\x1b\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xf6\x01\x02\x11\n\
+    \r\n\x05\x04\x12\x02\0\x05\x12\x04\xf6\x01\x02\x07\n\r\n\x05\x04\x12\x02\
+    \0\x01\x12\x04\xf6\x01\x08\x0c\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xf6\
+    \x01\x0f\x10\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\xf7\x01\x02#\n\r\n\x05\
+    \x04\x12\x02\x01\x06\x12\x04\xf7\x01\x02\x15\n\r\n\x05\x04\x12\x02\x01\
+    \x01\x12\x04\xf7\x01\x16\x1e\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\xf7\
+    \x01!\"\nT\n\x04\x04\x12\x02\x02\x12\x04\xf9\x01\x02\x1f\x1aF\x20a\x20va\
+    lid\x20URL\x20with\x20a\x20scheme\x20prefix\x20e.g.\x20`s3://`,\x20`file\
+    ://`,\x20`https://`\n\n\r\n\x05\x04\x12\x02\x02\x04\x12\x04\xf9\x01\x02\
+    \n\n\r\n\x05\x04\x12\x02\x02\x05\x12\x04\xf9\x01\x0b\x11\n\r\n\x05\x04\
+    \x12\x02\x02\x01\x12\x04\xf9\x01\x12\x1a\n\r\n\x05\x04\x12\x02\x02\x03\
+    \x12\x04\xf9\x01\x1d\x1e\n,\n\x04\x04\x12\x02\x03\x12\x04\xfb\x01\x02\
+    \x1b\x1a\x1e\x20tags\x20to\x20attach\x20to\x20the\x20module\n\n\r\n\x05\
+    \x04\x12\x02\x03\x04\x12\x04\xfb\x01\x02\n\n\r\n\x05\x04\x12\x02\x03\x05\
+    \x12\x04\xfb\x01\x0b\x11\n\r\n\x05\x04\x12\x02\x03\x01\x12\x04\xfb\x01\
+    \x12\x16\n\r\n\x05\x04\x12\x02\x03\x03\x12\x04\xfb\x01\x19\x1a\n3\n\x04\
+    \x04\x12\x02\x04\x12\x04\xfd\x01\x02\x1e\x1a%\x20version\x20of\x20the\
+    \x20module,\x20e.g.\x20`1.2.0`\n\n\r\n\x05\x04\x12\x02\x04\x04\x12\x04\
+    \xfd\x01\x02\n\n\r\n\x05\x04\x12\x02\x04\x05\x12\x04\xfd\x01\x0b\x11\n\r\
+    \n\x05\x04\x12\x02\x04\x01\x12\x04\xfd\x01\x12\x19\n\r\n\x05\x04\x12\x02\
+    \x04\x03\x12\x04\xfd\x01\x1c\x1d\nL\n\x02\x04\x13\x12\x06\x81\x02\0\x85\
+    \x02\x01\x1a>\x20The\x20message\x20returned\x20in\x20response\x20to\x20a\
+    \x20`CreateModuleRequest`.\n\n\x0b\n\x03\x04\x13\x01\x12\x04\x81\x02\x08\
+    \x1c\n\x0c\n\x04\x04\x13\x02\0\x12\x04\x82\x02\x02\x16\n\r\n\x05\x04\x13\
+    \x02\0\x05\x12\x04\x82\x02\x02\x07\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\
+    \x82\x02\x08\x11\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\x82\x02\x14\x15\n\
+    \x0c\n\x04\x04\x13\x02\x01\x12\x04\x83\x02\x02\x12\n\r\n\x05\x04\x13\x02\
+    \x01\x05\x12\x04\x83\x02\x02\x08\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\
+    \x83\x02\t\r\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\x83\x02\x10\x11\n\x0c\
+    \n\x04\x04\x13\x02\x02\x12\x04\x84\x02\x02\x1b\n\r\n\x05\x04\x13\x02\x02\
+    \x04\x12\x04\x84\x02\x02\n\n\r\n\x05\x04\x13\x02\x02\x06\x12\x04\x84\x02\
+    \x0b\x10\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\x84\x02\x11\x16\n\r\n\x05\
+    \x04\x13\x02\x02\x03\x12\x04\x84\x02\x19\x1a\n=\n\x02\x04\x14\x12\x04\
+    \x89\x02\01\x1a1\x20`POST\x20/api/v1/module:`\n\x20Return\x20a\x20single\
+    \x20module.\n\n\x0b\n\x03\x04\x14\x01\x12\x04\x89\x02\x08\x18\n\x0c\n\
+    \x04\x04\x14\x02\0\x12\x04\x89\x02\x1b/\n\r\n\x05\x04\x14\x02\0\x05\x12\
+    \x04\x89\x02\x1b\x20\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\x89\x02!*\n\r\n\
+    \x05\x04\x14\x02\0\x03\x12\x04\x89\x02-.\nI\n\x02\x04\x15\x12\x06\x8c\
+    \x02\0\x8f\x02\x01\x1a;\x20The\x20message\x20returned\x20in\x20response\
+    \x20to\x20a\x20`GetModuleRequest`.\n\n\x0b\n\x03\x04\x15\x01\x12\x04\x8c\
+    \x02\x08\x19\n\x0c\n\x04\x04\x15\x02\0\x12\x04\x8d\x02\x02\x14\n\r\n\x05\
+    \x04\x15\x02\0\x06\x12\x04\x8d\x02\x02\x08\n\r\n\x05\x04\x15\x02\0\x01\
+    \x12\x04\x8d\x02\t\x0f\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\x8d\x02\x12\
+    \x13\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\x8e\x02\x02\x1b\n\r\n\x05\x04\
+    \x15\x02\x01\x04\x12\x04\x8e\x02\x02\n\n\r\n\x05\x04\x15\x02\x01\x06\x12\
+    \x04\x8e\x02\x0b\x10\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\x8e\x02\x11\
+    \x16\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\x8e\x02\x19\x1a\nN\n\x02\x04\
+    \x16\x12\x06\x93\x02\0\x96\x02\x01\x1a@\x20`POST\x20/api/v1/modules:`\n\
+    \x20Return\x20paginated\x20list\x20of\x20all\x20modules.\n\n\x0b\n\x03\
+    \x04\x16\x01\x12\x04\x93\x02\x08\x1a\n\x0c\n\x04\x04\x16\x02\0\x12\x04\
+    \x94\x02\x02\x1c\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\x94\x02\x02\x0c\n\r\
+    \n\x05\x04\x16\x02\0\x01\x12\x04\x94\x02\r\x17\n\r\n\x05\x04\x16\x02\0\
+    \x03\x12\x04\x94\x02\x1a\x1b\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\x95\x02\
+    \x02\x10\n\r\n\x05\x04\x16\x02\x01\x06\x12\x04\x95\x02\x02\x06\n\r\n\x05\
+    \x04\x16\x02\x01\x01\x12\x04\x95\x02\x07\x0b\n\r\n\x05\x04\x16\x02\x01\
+    \x03\x12\x04\x95\x02\x0e\x0f\nK\n\x02\x04\x17\x12\x06\x99\x02\0\xa1\x02\
+    \x01\x1a=\x20The\x20message\x20returned\x20in\x20response\x20to\x20a\x20\
+    `ListModulesRequest`.\n\n\x0b\n\x03\x04\x17\x01\x12\x04\x99\x02\x08\x1b\
+    \n\x0c\n\x04\x04\x17\x02\0\x12\x04\x9a\x02\x02\x1e\n\r\n\x05\x04\x17\x02\
+    \0\x04\x12\x04\x9a\x02\x02\n\n\r\n\x05\x04\x17\x02\0\x06\x12\x04\x9a\x02\
+    \x0b\x11\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\x9a\x02\x12\x19\n\r\n\x05\
+    \x04\x17\x02\0\x03\x12\x04\x9a\x02\x1c\x1d\n\x0c\n\x04\x04\x17\x02\x01\
+    \x12\x04\x9b\x02\x02\x1c\n\r\n\x05\x04\x17\x02\x01\x06\x12\x04\x9b\x02\
+    \x02\x0c\n\r\n\x05\x04\x17\x02\x01\x01\x12\x04\x9b\x02\r\x17\n\r\n\x05\
+    \x04\x17\x02\x01\x03\x12\x04\x9b\x02\x1a\x1b\ng\n\x04\x04\x17\x02\x02\
+    \x12\x04\x9e\x02\x02\x13\x1aY\x20the\x20full\x20count\x20of\x20results\
     \x20in\x20the\x20database\x20(not\x20the\x20count\x20of\x20this\x20messa\
-    ge's\n\x20`modules`).\n\n\r\n\x05\x04\x0f\x02\x02\x05\x12\x04\xdc\x01\
-    \x02\x08\n\r\n\x05\x04\x0f\x02\x02\x01\x12\x04\xdc\x01\t\x0e\n\r\n\x05\
-    \x04\x0f\x02\x02\x03\x12\x04\xdc\x01\x11\x12\n\x0c\n\x04\x04\x0f\x02\x03\
-    \x12\x04\xdd\x01\x02\x10\n\r\n\x05\x04\x0f\x02\x03\x06\x12\x04\xdd\x01\
-    \x02\x06\n\r\n\x05\x04\x0f\x02\x03\x01\x12\x04\xdd\x01\x07\x0b\n\r\n\x05\
-    \x04\x0f\x02\x03\x03\x12\x04\xdd\x01\x0e\x0f\n\x0c\n\x04\x04\x0f\x02\x04\
-    \x12\x04\xde\x01\x02\x1b\n\r\n\x05\x04\x0f\x02\x04\x04\x12\x04\xde\x01\
-    \x02\n\n\r\n\x05\x04\x0f\x02\x04\x06\x12\x04\xde\x01\x0b\x10\n\r\n\x05\
-    \x04\x0f\x02\x04\x01\x12\x04\xde\x01\x11\x16\n\r\n\x05\x04\x0f\x02\x04\
-    \x03\x12\x04\xde\x01\x19\x1a\nt\n\x02\x04\x10\x12\x04\xe3\x01\0?\x1ah\
+    ge's\n\x20`modules`).\n\n\r\n\x05\x04\x17\x02\x02\x05\x12\x04\x9e\x02\
+    \x02\x08\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\x9e\x02\t\x0e\n\r\n\x05\
+    \x04\x17\x02\x02\x03\x12\x04\x9e\x02\x11\x12\n\x0c\n\x04\x04\x17\x02\x03\
+    \x12\x04\x9f\x02\x02\x10\n\r\n\x05\x04\x17\x02\x03\x06\x12\x04\x9f\x02\
+    \x02\x06\n\r\n\x05\x04\x17\x02\x03\x01\x12\x04\x9f\x02\x07\x0b\n\r\n\x05\
+    \x04\x17\x02\x03\x03\x12\x04\x9f\x02\x0e\x0f\n\x0c\n\x04\x04\x17\x02\x04\
+    \x12\x04\xa0\x02\x02\x1b\n\r\n\x05\x04\x17\x02\x04\x04\x12\x04\xa0\x02\
+    \x02\n\n\r\n\x05\x04\x17\x02\x04\x06\x12\x04\xa0\x02\x0b\x10\n\r\n\x05\
+    \x04\x17\x02\x04\x01\x12\x04\xa0\x02\x11\x16\n\r\n\x05\x04\x17\x02\x04\
+    \x03\x12\x04\xa0\x02\x19\x1a\n\xe8\x01\n\x02\x04\x18\x12\x06\xa7\x02\0\
+    \xd5\x02\x01\x1a\xd9\x01\x20`POST\x20/api/v1/search:`\n\x20Search\x20for\
+    \x20modules\x20based\x20on\x20filter\x20params\x20provided\x20(which\x20\
+    should\x20be\x20any\n\x20dimension\x20of\x20the\x20module\x20schema,\x20\
+    or\x20string\x20search\x20in\x20any\x20metadata\x20value).\n\x20Return\
+    \x20a\x20paginated\x20list\x20of\x20matching\x20modules.\n\n\x0b\n\x03\
+    \x04\x18\x01\x12\x04\xa7\x02\x08\x1c\n>\n\x04\x04\x18\x02\0\x12\x04\xa9\
+    \x02\x02\x18\x1a0\x20ID\x20for\x20this\x20module,\x20generated\x20by\x20\
+    the\x20database.\n\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\xa9\x02\x02\n\n\r\
+    \n\x05\x04\x18\x02\0\x05\x12\x04\xa9\x02\x0b\x10\n\r\n\x05\x04\x18\x02\0\
+    \x01\x12\x04\xa9\x02\x11\x13\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xa9\x02\
+    \x16\x17\n7\n\x04\x04\x18\x02\x01\x12\x04\xab\x02\x02\x1b\x1a)\x20origin\
+    al\x20name\x20of\x20the\x20binary\x20module\x20file\n\n\r\n\x05\x04\x18\
+    \x02\x01\x04\x12\x04\xab\x02\x02\n\n\r\n\x05\x04\x18\x02\x01\x05\x12\x04\
+    \xab\x02\x0b\x11\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xab\x02\x12\x16\n\
+    \r\n\x05\x04\x18\x02\x01\x03\x12\x04\xab\x02\x19\x1a\n\x82\x01\n\x04\x04\
+    \x18\x02\x02\x12\x04\xae\x02\x02\x1e\x1at\x20function\x20imports\x20call\
+    ed\x20by\x20the\x20module\x20(see:\n\x20<https://github.com/WebAssembly/\
+    design/blob/main/Modules.md#imports>)\n\n\r\n\x05\x04\x18\x02\x02\x04\
+    \x12\x04\xae\x02\x02\n\n\r\n\x05\x04\x18\x02\x02\x06\x12\x04\xae\x02\x0b\
+    \x11\n\r\n\x05\x04\x18\x02\x02\x01\x12\x04\xae\x02\x12\x19\n\r\n\x05\x04\
+    \x18\x02\x02\x03\x12\x04\xae\x02\x1c\x1d\n\x84\x01\n\x04\x04\x18\x02\x03\
+    \x12\x04\xb1\x02\x02\x1e\x1av\x20function\x20exports\x20provided\x20by\
+    \x20the\x20module\x20(see:\n\x20<https://github.com/WebAssembly/design/b\
+    lob/main/Modules.md#exports>)\n\n\r\n\x05\x04\x18\x02\x03\x04\x12\x04\
+    \xb1\x02\x02\n\n\r\n\x05\x04\x18\x02\x03\x06\x12\x04\xb1\x02\x0b\x11\n\r\
+    \n\x05\x04\x18\x02\x03\x01\x12\x04\xb1\x02\x12\x19\n\r\n\x05\x04\x18\x02\
+    \x03\x03\x12\x04\xb1\x02\x1c\x1d\n3\n\x04\x04\x18\x02\x04\x12\x04\xb3\
+    \x02\x02\x1f\x1a%\x20minimum\x20size\x20in\x20bytes\x20of\x20the\x20modu\
+    le\n\n\r\n\x05\x04\x18\x02\x04\x04\x12\x04\xb3\x02\x02\n\n\r\n\x05\x04\
+    \x18\x02\x04\x05\x12\x04\xb3\x02\x0b\x11\n\r\n\x05\x04\x18\x02\x04\x01\
+    \x12\x04\xb3\x02\x12\x1a\n\r\n\x05\x04\x18\x02\x04\x03\x12\x04\xb3\x02\
+    \x1d\x1e\n3\n\x04\x04\x18\x02\x05\x12\x04\xb5\x02\x02\x1f\x1a%\x20maximu\
+    m\x20size\x20in\x20bytes\x20of\x20the\x20module\n\n\r\n\x05\x04\x18\x02\
+    \x05\x04\x12\x04\xb5\x02\x02\n\n\r\n\x05\x04\x18\x02\x05\x05\x12\x04\xb5\
+    \x02\x0b\x11\n\r\n\x05\x04\x18\x02\x05\x01\x12\x04\xb5\x02\x12\x1a\n\r\n\
+    \x05\x04\x18\x02\x05\x03\x12\x04\xb5\x02\x1d\x1e\ng\n\x04\x04\x18\x02\
+    \x06\x12\x04\xb8\x02\x02\x1f\x1aY\x20optional\x20path\x20or\x20locator\
+    \x20to\x20the\x20module\x20(TODO:\x20maybe\x20this\x20is\x20better\x20st\
+    ored\n\x20as\x20metadata)\n\n\r\n\x05\x04\x18\x02\x06\x04\x12\x04\xb8\
+    \x02\x02\n\n\r\n\x05\x04\x18\x02\x06\x05\x12\x04\xb8\x02\x0b\x11\n\r\n\
+    \x05\x04\x18\x02\x06\x01\x12\x04\xb8\x02\x12\x1a\n\r\n\x05\x04\x18\x02\
+    \x06\x03\x12\x04\xb8\x02\x1d\x1e\n@\n\x04\x04\x18\x02\x07\x12\x04\xba\
+    \x02\x02.\x1a2\x20programming\x20language\x20used\x20to\x20produce\x20th\
+    is\x20module\n\n\r\n\x05\x04\x18\x02\x07\x04\x12\x04\xba\x02\x02\n\n\r\n\
+    \x05\x04\x18\x02\x07\x06\x12\x04\xba\x02\x0b\x19\n\r\n\x05\x04\x18\x02\
+    \x07\x01\x12\x04\xba\x02\x1a)\n\r\n\x05\x04\x18\x02\x07\x03\x12\x04\xba\
+    \x02,-\nJ\n\x04\x04\x18\x02\x08\x12\x04\xbc\x02\x02$\x1a<\x20arbitrary\
+    \x20metadata\x20provided\x20by\x20the\x20operator\x20of\x20this\x20modul\
+    e\n\n\r\n\x05\x04\x18\x02\x08\x06\x12\x04\xbc\x02\x02\x15\n\r\n\x05\x04\
+    \x18\x02\x08\x01\x12\x04\xbc\x02\x16\x1e\n\r\n\x05\x04\x18\x02\x08\x03\
+    \x12\x04\xbc\x02!#\n@\n\x04\x04\x18\x02\t\x12\x04\xbe\x02\x02:\x1a2\x20t\
+    imestamp\x20when\x20this\x20module\x20was\x20loaded\x20and\x20stored\n\n\
+    \r\n\x05\x04\x18\x02\t\x04\x12\x04\xbe\x02\x02\n\n\r\n\x05\x04\x18\x02\t\
+    \x06\x12\x04\xbe\x02\x0b$\n\r\n\x05\x04\x18\x02\t\x01\x12\x04\xbe\x02%4\
+    \n\r\n\x05\x04\x18\x02\t\x03\x12\x04\xbe\x0279\n@\n\x04\x04\x18\x02\n\
+    \x12\x04\xc0\x02\x029\x1a2\x20timestamp\x20when\x20this\x20module\x20was\
+    \x20loaded\x20and\x20stored\n\n\r\n\x05\x04\x18\x02\n\x04\x12\x04\xc0\
+    \x02\x02\n\n\r\n\x05\x04\x18\x02\n\x06\x12\x04\xc0\x02\x0b$\n\r\n\x05\
+    \x04\x18\x02\n\x01\x12\x04\xc0\x02%3\n\r\n\x05\x04\x18\x02\n\x03\x12\x04\
+    \xc0\x0268\n[\n\x04\x04\x18\x02\x0b\x12\x04\xc2\x02\x02\x1f\x1aM\x20the\
+    \x20interned\x20strings\x20stored\x20in\x20the\x20wasm\x20binary\x20(pan\
+    ic/abort\x20messages,\x20etc.)\n\n\r\n\x05\x04\x18\x02\x0b\x04\x12\x04\
+    \xc2\x02\x02\n\n\r\n\x05\x04\x18\x02\x0b\x05\x12\x04\xc2\x02\x0b\x11\n\r\
+    \n\x05\x04\x18\x02\x0b\x01\x12\x04\xc2\x02\x12\x19\n\r\n\x05\x04\x18\x02\
+    \x0b\x03\x12\x04\xc2\x02\x1c\x1e\nB\n\x04\x04\x18\x02\x0c\x12\x04\xc4\
+    \x02\x02%\x1a4\x20match\x20on\x20any\x20function\x20name\x20in\x20an\x20\
+    import\x20or\x20export.\n\n\r\n\x05\x04\x18\x02\x0c\x04\x12\x04\xc4\x02\
+    \x02\n\n\r\n\x05\x04\x18\x02\x0c\x05\x12\x04\xc4\x02\x0b\x11\n\r\n\x05\
+    \x04\x18\x02\x0c\x01\x12\x04\xc4\x02\x12\x1f\n\r\n\x05\x04\x18\x02\x0c\
+    \x03\x12\x04\xc4\x02\"$\nO\n\x04\x04\x18\x02\r\x12\x04\xc6\x02\x02#\x1aA\
+    \x20match\x20on\x20the\x20module\x20name\x20e.g.\x20`env`\x20or\x20`wasi\
+    _snapshot_preview1`\n\n\r\n\x05\x04\x18\x02\r\x04\x12\x04\xc6\x02\x02\n\
+    \n\r\n\x05\x04\x18\x02\r\x05\x12\x04\xc6\x02\x0b\x11\n\r\n\x05\x04\x18\
+    \x02\r\x01\x12\x04\xc6\x02\x12\x1d\n\r\n\x05\x04\x18\x02\r\x03\x12\x04\
+    \xc6\x02\x20\"\n\x0c\n\x04\x04\x18\x02\x0e\x12\x04\xc8\x02\x02\x1d\n\r\n\
+    \x05\x04\x18\x02\x0e\x06\x12\x04\xc8\x02\x02\x0c\n\r\n\x05\x04\x18\x02\
+    \x0e\x01\x12\x04\xc8\x02\r\x17\n\r\n\x05\x04\x18\x02\x0e\x03\x12\x04\xc8\
+    \x02\x1a\x1c\n\x0c\n\x04\x04\x18\x02\x0f\x12\x04\xc9\x02\x02\x11\n\r\n\
+    \x05\x04\x18\x02\x0f\x06\x12\x04\xc9\x02\x02\x06\n\r\n\x05\x04\x18\x02\
+    \x0f\x01\x12\x04\xc9\x02\x07\x0b\n\r\n\x05\x04\x18\x02\x0f\x03\x12\x04\
+    \xc9\x02\x0e\x10\nA\n\x04\x04\x18\x02\x10\x12\x04\xcb\x02\x02\x1c\x1a3\
+    \x20match\x20modules\x20which\x20have\x20all\x20of\x20the\x20provided\
+    \x20tags\n\n\r\n\x05\x04\x18\x02\x10\x04\x12\x04\xcb\x02\x02\n\n\r\n\x05\
+    \x04\x18\x02\x10\x05\x12\x04\xcb\x02\x0b\x11\n\r\n\x05\x04\x18\x02\x10\
+    \x01\x12\x04\xcb\x02\x12\x16\n\r\n\x05\x04\x18\x02\x10\x03\x12\x04\xcb\
+    \x02\x19\x1b\n8\n\x04\x04\x18\x02\x11\x12\x04\xcd\x02\x02\x1f\x1a*\x20ma\
+    tch\x20on\x20the\x20exact\x20version\x20of\x20the\x20module\n\n\r\n\x05\
+    \x04\x18\x02\x11\x04\x12\x04\xcd\x02\x02\n\n\r\n\x05\x04\x18\x02\x11\x05\
+    \x12\x04\xcd\x02\x0b\x11\n\r\n\x05\x04\x18\x02\x11\x01\x12\x04\xcd\x02\
+    \x12\x19\n\r\n\x05\x04\x18\x02\x11\x03\x12\x04\xcd\x02\x1c\x1e\n;\n\x04\
+    \x04\x18\x02\x12\x12\x04\xcf\x02\x02&\x1a-\x20minimum\x20cyclomatic\x20c\
+    omplexity\x20of\x20the\x20module\n\n\r\n\x05\x04\x18\x02\x12\x04\x12\x04\
+    \xcf\x02\x02\n\n\r\n\x05\x04\x18\x02\x12\x05\x12\x04\xcf\x02\x0b\x11\n\r\
+    \n\x05\x04\x18\x02\x12\x01\x12\x04\xcf\x02\x12\x20\n\r\n\x05\x04\x18\x02\
+    \x12\x03\x12\x04\xcf\x02#%\n;\n\x04\x04\x18\x02\x13\x12\x04\xd1\x02\x02&\
+    \x1a-\x20maximum\x20cyclomatic\x20complexity\x20of\x20the\x20module\n\n\
+    \r\n\x05\x04\x18\x02\x13\x04\x12\x04\xd1\x02\x02\n\n\r\n\x05\x04\x18\x02\
+    \x13\x05\x12\x04\xd1\x02\x0b\x11\n\r\n\x05\x04\x18\x02\x13\x01\x12\x04\
+    \xd1\x02\x12\x20\n\r\n\x05\x04\x18\x02\x13\x03\x12\x04\xd1\x02#%\nn\n\
+    \x04\x04\x18\x02\x14\x12\x04\xd4\x02\x02\"\x1a`\x20match\x20modules\x20w\
+    hich\x20import\x20from\x20all\x20of\x20the\x20provided\x20namespaces,\
+    \x20e.g.\n\x20`wasi_snapshot_preview1`\n\n\r\n\x05\x04\x18\x02\x14\x04\
+    \x12\x04\xd4\x02\x02\n\n\r\n\x05\x04\x18\x02\x14\x05\x12\x04\xd4\x02\x0b\
+    \x11\n\r\n\x05\x04\x18\x02\x14\x01\x12\x04\xd4\x02\x12\x1c\n\r\n\x05\x04\
+    \x18\x02\x14\x03\x12\x04\xd4\x02\x1f!\nM\n\x02\x04\x19\x12\x06\xd8\x02\0\
+    \xe0\x02\x01\x1a?\x20The\x20message\x20returned\x20in\x20response\x20to\
+    \x20a\x20`SearchModulesRequest`.\n\n\x0b\n\x03\x04\x19\x01\x12\x04\xd8\
+    \x02\x08\x1d\n\x0c\n\x04\x04\x19\x02\0\x12\x04\xd9\x02\x02\x1e\n\r\n\x05\
+    \x04\x19\x02\0\x04\x12\x04\xd9\x02\x02\n\n\r\n\x05\x04\x19\x02\0\x06\x12\
+    \x04\xd9\x02\x0b\x11\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xd9\x02\x12\x19\
+    \n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xd9\x02\x1c\x1d\n\x0c\n\x04\x04\x19\
+    \x02\x01\x12\x04\xda\x02\x02\x1c\n\r\n\x05\x04\x19\x02\x01\x06\x12\x04\
+    \xda\x02\x02\x0c\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xda\x02\r\x17\n\r\
+    \n\x05\x04\x19\x02\x01\x03\x12\x04\xda\x02\x1a\x1b\ng\n\x04\x04\x19\x02\
+    \x02\x12\x04\xdd\x02\x02\x13\x1aY\x20the\x20full\x20count\x20of\x20resul\
+    ts\x20in\x20the\x20database\x20(not\x20the\x20count\x20of\x20this\x20mes\
+    sage's\n\x20`modules`).\n\n\r\n\x05\x04\x19\x02\x02\x05\x12\x04\xdd\x02\
+    \x02\x08\n\r\n\x05\x04\x19\x02\x02\x01\x12\x04\xdd\x02\t\x0e\n\r\n\x05\
+    \x04\x19\x02\x02\x03\x12\x04\xdd\x02\x11\x12\n\x0c\n\x04\x04\x19\x02\x03\
+    \x12\x04\xde\x02\x02\x10\n\r\n\x05\x04\x19\x02\x03\x06\x12\x04\xde\x02\
+    \x02\x06\n\r\n\x05\x04\x19\x02\x03\x01\x12\x04\xde\x02\x07\x0b\n\r\n\x05\
+    \x04\x19\x02\x03\x03\x12\x04\xde\x02\x0e\x0f\n\x0c\n\x04\x04\x19\x02\x04\
+    \x12\x04\xdf\x02\x02\x1b\n\r\n\x05\x04\x19\x02\x04\x04\x12\x04\xdf\x02\
+    \x02\n\n\r\n\x05\x04\x19\x02\x04\x06\x12\x04\xdf\x02\x0b\x10\n\r\n\x05\
+    \x04\x19\x02\x04\x01\x12\x04\xdf\x02\x11\x16\n\r\n\x05\x04\x19\x02\x04\
+    \x03\x12\x04\xdf\x02\x19\x1a\nt\n\x02\x04\x1a\x12\x04\xe4\x02\0?\x1ah\
     \x20`DELETE\x20/api/v1/module:`\n\x20Remove\x20a\x20module\x20from\x20th\
     e\x20database\x20by\x20its\x20ID.\x20Return\x20the\x20module\x20IDs\x20&\
-    \x20hashes.\n\n\x0b\n\x03\x04\x10\x01\x12\x04\xe3\x01\x08\x1c\n\x0c\n\
-    \x04\x04\x10\x02\0\x12\x04\xe3\x01\x1f=\n\r\n\x05\x04\x10\x02\0\x04\x12\
-    \x04\xe3\x01\x1f'\n\r\n\x05\x04\x10\x02\0\x05\x12\x04\xe3\x01(-\n\r\n\
-    \x05\x04\x10\x02\0\x01\x12\x04\xe3\x01.8\n\r\n\x05\x04\x10\x02\0\x03\x12\
-    \x04\xe3\x01;<\nM\n\x02\x04\x11\x12\x06\xe6\x01\0\xe9\x01\x01\x1a?\x20Th\
+    \x20hashes.\n\n\x0b\n\x03\x04\x1a\x01\x12\x04\xe4\x02\x08\x1c\n\x0c\n\
+    \x04\x04\x1a\x02\0\x12\x04\xe4\x02\x1f=\n\r\n\x05\x04\x1a\x02\0\x04\x12\
+    \x04\xe4\x02\x1f'\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xe4\x02(-\n\r\n\
+    \x05\x04\x1a\x02\0\x01\x12\x04\xe4\x02.8\n\r\n\x05\x04\x1a\x02\0\x03\x12\
+    \x04\xe4\x02;<\nM\n\x02\x04\x1b\x12\x06\xe7\x02\0\xea\x02\x01\x1a?\x20Th\
     e\x20message\x20returned\x20in\x20response\x20to\x20a\x20`DeleteModulesR\
-    equest`.\n\n\x0b\n\x03\x04\x11\x01\x12\x04\xe6\x01\x08\x1d\n\x0c\n\x04\
-    \x04\x11\x02\0\x12\x04\xe7\x01\x02(\n\r\n\x05\x04\x11\x02\0\x06\x12\x04\
-    \xe7\x01\x02\x14\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xe7\x01\x15#\n\r\n\
-    \x05\x04\x11\x02\0\x03\x12\x04\xe7\x01&'\n\x0c\n\x04\x04\x11\x02\x01\x12\
-    \x04\xe8\x01\x02\x1b\n\r\n\x05\x04\x11\x02\x01\x04\x12\x04\xe8\x01\x02\n\
-    \n\r\n\x05\x04\x11\x02\x01\x06\x12\x04\xe8\x01\x0b\x10\n\r\n\x05\x04\x11\
-    \x02\x01\x01\x12\x04\xe8\x01\x11\x16\n\r\n\x05\x04\x11\x02\x01\x03\x12\
-    \x04\xe8\x01\x19\x1a\n\xfc\x01\n\x02\x05\x04\x12\x06\xee\x01\0\xf1\x01\
-    \x01\x1a\xed\x01\x20Represents\x20the\x20expected\x20outcome\x20of\x20an\
-    \x20AuditModulesRequest.\x20If\x20PASS\x20is\x20provided,\x20then\n\x20t\
-    he\x20audit\x20returns\x20modules\x20which\x20conform\x20to\x20the\x20ch\
-    eckfile.\x20If\x20FAIL\x20is\x20provided,\x20then\n\x20the\x20audit\x20r\
-    eturns\x20modules\x20which\x20do\x20not\x20conform\x20to\x20the\x20check\
-    file.\n\n\x0b\n\x03\x05\x04\x01\x12\x04\xee\x01\x05\x11\n\x0c\n\x04\x05\
-    \x04\x02\0\x12\x04\xef\x01\x02\x0b\n\r\n\x05\x05\x04\x02\0\x01\x12\x04\
-    \xef\x01\x02\x06\n\r\n\x05\x05\x04\x02\0\x02\x12\x04\xef\x01\t\n\n\x0c\n\
-    \x04\x05\x04\x02\x01\x12\x04\xf0\x01\x02\x0b\n\r\n\x05\x05\x04\x02\x01\
-    \x01\x12\x04\xf0\x01\x02\x06\n\r\n\x05\x05\x04\x02\x01\x02\x12\x04\xf0\
-    \x01\t\n\n\x82\x01\n\x02\x04\x12\x12\x06\xf5\x01\0\xfa\x01\x01\x1at\x20`\
-    POST\x20/api/v1/audit:`\n\x20Return\x20a\x20list\x20of\x20modules\x20whi\
-    ch\x20match\x20the\x20outcome\x20requirements\x20using\x20the\x20provide\
-    d\x20checkfile.\n\n\x0b\n\x03\x04\x12\x01\x12\x04\xf5\x01\x08\x1b\n8\n\
-    \x04\x04\x12\x02\0\x12\x04\xf7\x01\x02\x16\x1a*\x20the\x20YAML\x20checkf\
-    ile\x20(e.g.\x20mod.yaml)\x20bytes\n\n\r\n\x05\x04\x12\x02\0\x05\x12\x04\
-    \xf7\x01\x02\x07\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\xf7\x01\x08\x11\n\r\
-    \n\x05\x04\x12\x02\0\x03\x12\x04\xf7\x01\x14\x15\n\x0c\n\x04\x04\x12\x02\
-    \x01\x12\x04\xf8\x01\x02\x1b\n\r\n\x05\x04\x12\x02\x01\x06\x12\x04\xf8\
-    \x01\x02\x0e\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\xf8\x01\x0f\x16\n\r\n\
-    \x05\x04\x12\x02\x01\x03\x12\x04\xf8\x01\x19\x1a\n\x0c\n\x04\x04\x12\x02\
-    \x02\x12\x04\xf9\x01\x02\x1c\n\r\n\x05\x04\x12\x02\x02\x06\x12\x04\xf9\
-    \x01\x02\x0c\n\r\n\x05\x04\x12\x02\x02\x01\x12\x04\xf9\x01\r\x17\n\r\n\
-    \x05\x04\x12\x02\x02\x03\x12\x04\xf9\x01\x1a\x1b\nL\n\x02\x04\x13\x12\
-    \x06\xfd\x01\0\x86\x02\x01\x1a>\x20The\x20message\x20returned\x20in\x20r\
-    esponse\x20to\x20a\x20`AuditModulesRequest`.\n\n\x0b\n\x03\x04\x13\x01\
-    \x12\x04\xfd\x01\x08\x1c\n\xad\x01\n\x04\x04\x13\x02\0\x12\x04\x80\x02\
-    \x02.\x1a\x9e\x01\x20each\x20record\x20contains\x20the\x20ID\x20of\x20th\
-    e\x20invalid\x20Module\x20which\x20failed\x20the\x20audit,\x20as\x20well\
-    \x20as\x20the\x20failure\x20\n\x20report\x20produced\x20by\x20the\x20val\
-    idation\x20check\x20(encoded\x20in\x20JSON)\n\n\r\n\x05\x04\x13\x02\0\
-    \x06\x12\x04\x80\x02\x02\x13\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\x80\x02\
-    \x14)\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\x80\x02,-\n\x0c\n\x04\x04\x13\
-    \x02\x01\x12\x04\x81\x02\x02\x1c\n\r\n\x05\x04\x13\x02\x01\x06\x12\x04\
-    \x81\x02\x02\x0c\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\x81\x02\r\x17\n\r\
-    \n\x05\x04\x13\x02\x01\x03\x12\x04\x81\x02\x1a\x1b\ng\n\x04\x04\x13\x02\
-    \x02\x12\x04\x84\x02\x02\x13\x1aY\x20the\x20full\x20count\x20of\x20resul\
-    ts\x20in\x20the\x20database\x20(not\x20the\x20count\x20of\x20this\x20mes\
-    sage's\n\x20`modules`).\n\n\r\n\x05\x04\x13\x02\x02\x05\x12\x04\x84\x02\
-    \x02\x08\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\x84\x02\t\x0e\n\r\n\x05\
-    \x04\x13\x02\x02\x03\x12\x04\x84\x02\x11\x12\n\x0c\n\x04\x04\x13\x02\x03\
-    \x12\x04\x85\x02\x02\x1b\n\r\n\x05\x04\x13\x02\x03\x04\x12\x04\x85\x02\
-    \x02\n\n\r\n\x05\x04\x13\x02\x03\x06\x12\x04\x85\x02\x0b\x10\n\r\n\x05\
-    \x04\x13\x02\x03\x01\x12\x04\x85\x02\x11\x16\n\r\n\x05\x04\x13\x02\x03\
-    \x03\x12\x04\x85\x02\x19\x1a\nD\n\x02\x04\x14\x12\x06\x8a\x02\0\x8f\x02\
-    \x01\x1a6\x20`POST\x20/api/v1/diff:`\n\x20Return\x20the\x20diff\x20of\
-    \x20two\x20modules\n\n\x0b\n\x03\x04\x14\x01\x12\x04\x8a\x02\x08\x13\n\
-    \x0c\n\x04\x04\x14\x02\0\x12\x04\x8b\x02\x02\x14\n\r\n\x05\x04\x14\x02\0\
-    \x05\x12\x04\x8b\x02\x02\x07\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\x8b\x02\
-    \x08\x0f\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\x8b\x02\x12\x13\n\x0c\n\x04\
-    \x04\x14\x02\x01\x12\x04\x8c\x02\x02\x14\n\r\n\x05\x04\x14\x02\x01\x05\
-    \x12\x04\x8c\x02\x02\x07\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\x8c\x02\
-    \x08\x0f\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\x8c\x02\x12\x13\n\x0c\n\
-    \x04\x04\x14\x02\x02\x12\x04\x8d\x02\x02\x1a\n\r\n\x05\x04\x14\x02\x02\
-    \x05\x12\x04\x8d\x02\x02\x06\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\x8d\
-    \x02\x07\x15\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\x8d\x02\x18\x19\n\x0c\
-    \n\x04\x04\x14\x02\x03\x12\x04\x8e\x02\x02\x18\n\r\n\x05\x04\x14\x02\x03\
-    \x05\x12\x04\x8e\x02\x02\x06\n\r\n\x05\x04\x14\x02\x03\x01\x12\x04\x8e\
-    \x02\x07\x13\n\r\n\x05\x04\x14\x02\x03\x03\x12\x04\x8e\x02\x16\x17\n\x98\
-    \x01\n\x02\x04\x15\x12\x06\x93\x02\0\x96\x02\x01\x1a\x89\x01\x20The\x20m\
-    essage\x20returned\x20in\x20response\x20to\x20`DiffRequest`,\x20contains\
-    \x20a\x20text\x20representation\x20of\x20the\x20difference\n\x20between\
-    \x20the\x20two\x20specified\x20modules.\n\n\x0b\n\x03\x04\x15\x01\x12\
-    \x04\x93\x02\x08\x14\n\x0c\n\x04\x04\x15\x02\0\x12\x04\x94\x02\x02\x12\n\
-    \r\n\x05\x04\x15\x02\0\x05\x12\x04\x94\x02\x02\x08\n\r\n\x05\x04\x15\x02\
-    \0\x01\x12\x04\x94\x02\t\r\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\x94\x02\
-    \x10\x11\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\x95\x02\x02\x1b\n\r\n\x05\
-    \x04\x15\x02\x01\x04\x12\x04\x95\x02\x02\n\n\r\n\x05\x04\x15\x02\x01\x06\
-    \x12\x04\x95\x02\x0b\x10\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\x95\x02\
-    \x11\x16\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\x95\x02\x19\x1a\n\x8a\x01\
-    \n\x02\x04\x16\x12\x06\x9a\x02\0\xa3\x02\x01\x1a|\x20`POST\x20/api/v1/va\
-    lidate:`\n\x20Return\x20the\x20failure\x20report\x20(if\x20applicable)\
-    \x20of\x20a\x20wasm\x20module\x20validation\x20against\x20a\x20given\x20\
-    checkfile.\n\n\x0b\n\x03\x04\x16\x01\x12\x04\x9a\x02\x08\x1d\n8\n\x04\
-    \x04\x16\x02\0\x12\x04\x9c\x02\x02\x16\x1a*\x20the\x20YAML\x20checkfile\
-    \x20(e.g.\x20mod.yaml)\x20bytes\n\n\r\n\x05\x04\x16\x02\0\x05\x12\x04\
-    \x9c\x02\x02\x07\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\x9c\x02\x08\x11\n\r\
-    \n\x05\x04\x16\x02\0\x03\x12\x04\x9c\x02\x14\x15\n\xb8\x01\n\x04\x04\x16\
-    \x08\0\x12\x06\x9f\x02\x02\xa2\x02\x03\x1a\xa7\x01\x20module_input\x20is\
-    \x20either\x20an\x20existing\x20`module_id`\x20that\x20is\x20known\x20to\
-    \x20the\x20database,\x20or\x20the\x20bytes\x20of\n\x20a\x20raw\x20wasm\
-    \x20module.\x20It\x20is\x20used\x20to\x20validate\x20against\x20the\x20g\
-    iven\x20checkfile.\n\n\r\n\x05\x04\x16\x08\0\x01\x12\x04\x9f\x02\x08\x14\
-    \n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xa0\x02\x04\x15\n\r\n\x05\x04\x16\
-    \x02\x01\x05\x12\x04\xa0\x02\x04\t\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\
-    \xa0\x02\n\x10\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\xa0\x02\x13\x14\n\
-    \x0c\n\x04\x04\x16\x02\x02\x12\x04\xa1\x02\x04\x18\n\r\n\x05\x04\x16\x02\
-    \x02\x05\x12\x04\xa1\x02\x04\t\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\xa1\
-    \x02\n\x13\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\xa1\x02\x16\x17\nV\n\
-    \x02\x04\x17\x12\x06\xa6\x02\0\xa9\x02\x01\x1aH\x20The\x20failure\x20rep\
-    ort\x20produced\x20by\x20the\x20validation\x20check\x20(encoded\x20in\
-    \x20JSON).\n\n\x0b\n\x03\x04\x17\x01\x12\x04\xa6\x02\x08\x1e\n\x0c\n\x04\
-    \x04\x17\x02\0\x12\x04\xa7\x02\x02\"\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\
-    \xa7\x02\x02\x07\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xa7\x02\x08\x1d\n\r\
-    \n\x05\x04\x17\x02\0\x03\x12\x04\xa7\x02\x20!\n\x0c\n\x04\x04\x17\x02\
-    \x01\x12\x04\xa8\x02\x02\x1b\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\xa8\
-    \x02\x02\n\n\r\n\x05\x04\x17\x02\x01\x06\x12\x04\xa8\x02\x0b\x10\n\r\n\
-    \x05\x04\x17\x02\x01\x01\x12\x04\xa8\x02\x11\x16\n\r\n\x05\x04\x17\x02\
-    \x01\x03\x12\x04\xa8\x02\x19\x1a\nI\n\x02\x04\x18\x12\x04\xad\x02\06\x1a\
-    =\x20`POST\x20/api/v1/module_graph:`\n\x20Return\x20a\x20single\x20modul\
-    e_graph.\n\n\x0b\n\x03\x04\x18\x01\x12\x04\xad\x02\x08\x1d\n\x0c\n\x04\
-    \x04\x18\x02\0\x12\x04\xad\x02\x204\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\
-    \xad\x02\x20%\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xad\x02&/\n\r\n\x05\
-    \x04\x18\x02\0\x03\x12\x04\xad\x0223\nN\n\x02\x04\x19\x12\x06\xb0\x02\0\
-    \xb3\x02\x01\x1a@\x20The\x20message\x20returned\x20in\x20response\x20to\
-    \x20a\x20`GetModuleGraphRequest`.\n\n\x0b\n\x03\x04\x19\x01\x12\x04\xb0\
-    \x02\x08\x1e\n\x0c\n\x04\x04\x19\x02\0\x12\x04\xb1\x02\x02\x1f\n\r\n\x05\
-    \x04\x19\x02\0\x06\x12\x04\xb1\x02\x02\r\n\r\n\x05\x04\x19\x02\0\x01\x12\
-    \x04\xb1\x02\x0e\x1a\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xb1\x02\x1d\x1e\
-    \n\x0c\n\x04\x04\x19\x02\x01\x12\x04\xb2\x02\x02\x1b\n\r\n\x05\x04\x19\
-    \x02\x01\x04\x12\x04\xb2\x02\x02\n\n\r\n\x05\x04\x19\x02\x01\x06\x12\x04\
-    \xb2\x02\x0b\x10\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xb2\x02\x11\x16\n\
-    \r\n\x05\x04\x19\x02\x01\x03\x12\x04\xb2\x02\x19\x1a\n#\n\x02\x04\x1a\
-    \x12\x06\xb6\x02\0\xbc\x02\x01\x1a\x15\x20PUT\x20/api/v1/plugin:\n\n\x0b\
-    \n\x03\x04\x1a\x01\x12\x04\xb6\x02\x08\x1c\n\x0c\n\x04\x04\x1a\x02\0\x12\
-    \x04\xb7\x02\x02\x18\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xb7\x02\x02\x08\
-    \n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xb7\x02\t\x13\n\r\n\x05\x04\x1a\x02\
-    \0\x03\x12\x04\xb7\x02\x16\x17\n\x0c\n\x04\x04\x1a\x02\x01\x12\x04\xb8\
-    \x02\x02\x1b\n\r\n\x05\x04\x1a\x02\x01\x04\x12\x04\xb8\x02\x02\n\n\r\n\
-    \x05\x04\x1a\x02\x01\x05\x12\x04\xb8\x02\x0b\x11\n\r\n\x05\x04\x1a\x02\
-    \x01\x01\x12\x04\xb8\x02\x12\x16\n\r\n\x05\x04\x1a\x02\x01\x03\x12\x04\
-    \xb8\x02\x19\x1a\n\x0c\n\x04\x04\x1a\x02\x02\x12\x04\xb9\x02\x02\x16\n\r\
-    \n\x05\x04\x1a\x02\x02\x05\x12\x04\xb9\x02\x02\x08\n\r\n\x05\x04\x1a\x02\
-    \x02\x01\x12\x04\xb9\x02\t\x11\n\r\n\x05\x04\x1a\x02\x02\x03\x12\x04\xb9\
-    \x02\x14\x15\n!\n\x04\x04\x1a\x02\x03\x12\x04\xba\x02\x02\x11\"\x13\x20b\
-    ytes\x20config\x20=\x205;\n\n\r\n\x05\x04\x1a\x02\x03\x05\x12\x04\xba\
-    \x02\x02\x07\n\r\n\x05\x04\x1a\x02\x03\x01\x12\x04\xba\x02\x08\x0c\n\r\n\
-    \x05\x04\x1a\x02\x03\x03\x12\x04\xba\x02\x0f\x10\n\x0c\n\x02\x04\x1b\x12\
-    \x06\xbe\x02\0\xc1\x02\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\xbe\x02\x08\
-    \x1d\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\xbf\x02\x02\x12\n\r\n\x05\x04\x1b\
-    \x02\0\x05\x12\x04\xbf\x02\x02\x08\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\
-    \xbf\x02\t\r\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xbf\x02\x10\x11\n\x0c\n\
-    \x04\x04\x1b\x02\x01\x12\x04\xc0\x02\x02\x1b\n\r\n\x05\x04\x1b\x02\x01\
-    \x04\x12\x04\xc0\x02\x02\n\n\r\n\x05\x04\x1b\x02\x01\x06\x12\x04\xc0\x02\
-    \x0b\x10\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\xc0\x02\x11\x16\n\r\n\x05\
-    \x04\x1b\x02\x01\x03\x12\x04\xc0\x02\x19\x1a\n&\n\x02\x04\x1c\x12\x06\
-    \xc4\x02\0\xc6\x02\x01\x1a\x18\x20DELETE\x20/api/v1/plugin:\n\n\x0b\n\
-    \x03\x04\x1c\x01\x12\x04\xc4\x02\x08\x1e\n\x0c\n\x04\x04\x1c\x02\0\x12\
-    \x04\xc5\x02\x02\x18\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\xc5\x02\x02\x08\
-    \n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\xc5\x02\t\x13\n\r\n\x05\x04\x1c\x02\
-    \0\x03\x12\x04\xc5\x02\x16\x17\n\x0c\n\x02\x04\x1d\x12\x06\xc8\x02\0\xca\
-    \x02\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\xc8\x02\x08\x1f\n\x0c\n\x04\x04\
-    \x1d\x02\0\x12\x04\xc9\x02\x02\x1b\n\r\n\x05\x04\x1d\x02\0\x04\x12\x04\
-    \xc9\x02\x02\n\n\r\n\x05\x04\x1d\x02\0\x06\x12\x04\xc9\x02\x0b\x10\n\r\n\
-    \x05\x04\x1d\x02\0\x01\x12\x04\xc9\x02\x11\x16\n\r\n\x05\x04\x1d\x02\0\
-    \x03\x12\x04\xc9\x02\x19\x1a\n$\n\x02\x04\x1e\x12\x06\xcd\x02\0\xd3\x02\
-    \x01\x1a\x16\x20POST\x20/api/v1/plugin:\n\n\x0b\n\x03\x04\x1e\x01\x12\
-    \x04\xcd\x02\x08\x19\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\xce\x02\x02\x18\n\
-    \r\n\x05\x04\x1e\x02\0\x05\x12\x04\xce\x02\x02\x08\n\r\n\x05\x04\x1e\x02\
-    \0\x01\x12\x04\xce\x02\t\x13\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\xce\x02\
-    \x16\x17\n\x0c\n\x04\x04\x1e\x02\x01\x12\x04\xcf\x02\x02\x1b\n\r\n\x05\
-    \x04\x1e\x02\x01\x05\x12\x04\xcf\x02\x02\x08\n\r\n\x05\x04\x1e\x02\x01\
-    \x01\x12\x04\xcf\x02\t\x16\n\r\n\x05\x04\x1e\x02\x01\x03\x12\x04\xcf\x02\
-    \x19\x1a\n\x0c\n\x04\x04\x1e\x02\x02\x12\x04\xd0\x02\x02\x12\n\r\n\x05\
-    \x04\x1e\x02\x02\x05\x12\x04\xd0\x02\x02\x07\n\r\n\x05\x04\x1e\x02\x02\
-    \x01\x12\x04\xd0\x02\x08\r\n\r\n\x05\x04\x1e\x02\x02\x03\x12\x04\xd0\x02\
-    \x10\x11\n!\n\x04\x04\x1e\x02\x03\x12\x04\xd1\x02\x02\x1b\"\x13\x20bytes\
-    \x20config\x20=\x205;\n\n\r\n\x05\x04\x1e\x02\x03\x04\x12\x04\xd1\x02\
-    \x02\n\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\xd1\x02\x0b\x11\n\r\n\x05\
-    \x04\x1e\x02\x03\x01\x12\x04\xd1\x02\x12\x16\n\r\n\x05\x04\x1e\x02\x03\
-    \x03\x12\x04\xd1\x02\x19\x1a\n\x0c\n\x02\x04\x1f\x12\x06\xd5\x02\0\xd8\
-    \x02\x01\n\x0b\n\x03\x04\x1f\x01\x12\x04\xd5\x02\x08\x1a\n\x0c\n\x04\x04\
-    \x1f\x02\0\x12\x04\xd6\x02\x02\x13\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\
-    \xd6\x02\x02\x07\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\xd6\x02\x08\x0e\n\r\
-    \n\x05\x04\x1f\x02\0\x03\x12\x04\xd6\x02\x11\x12\n\x0c\n\x04\x04\x1f\x02\
-    \x01\x12\x04\xd7\x02\x02\x1b\n\r\n\x05\x04\x1f\x02\x01\x04\x12\x04\xd7\
-    \x02\x02\n\n\r\n\x05\x04\x1f\x02\x01\x06\x12\x04\xd7\x02\x0b\x10\n\r\n\
-    \x05\x04\x1f\x02\x01\x01\x12\x04\xd7\x02\x11\x16\n\r\n\x05\x04\x1f\x02\
-    \x01\x03\x12\x04\xd7\x02\x19\x1ab\x06proto3\
+    equest`.\n\n\x0b\n\x03\x04\x1b\x01\x12\x04\xe7\x02\x08\x1d\n\x0c\n\x04\
+    \x04\x1b\x02\0\x12\x04\xe8\x02\x02(\n\r\n\x05\x04\x1b\x02\0\x06\x12\x04\
+    \xe8\x02\x02\x14\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\xe8\x02\x15#\n\r\n\
+    \x05\x04\x1b\x02\0\x03\x12\x04\xe8\x02&'\n\x0c\n\x04\x04\x1b\x02\x01\x12\
+    \x04\xe9\x02\x02\x1b\n\r\n\x05\x04\x1b\x02\x01\x04\x12\x04\xe9\x02\x02\n\
+    \n\r\n\x05\x04\x1b\x02\x01\x06\x12\x04\xe9\x02\x0b\x10\n\r\n\x05\x04\x1b\
+    \x02\x01\x01\x12\x04\xe9\x02\x11\x16\n\r\n\x05\x04\x1b\x02\x01\x03\x12\
+    \x04\xe9\x02\x19\x1a\nv\n\x02\x04\x1c\x12\x06\xee\x02\0\xf1\x02\x01\x1ah\
+    \x20`PUT\x20/api/v1/module/tags:`\n\x20Replace\x20the\x20tags\x20attache\
+    d\x20to\x20a\x20module.\x20Return\x20the\x20module's\x20resulting\x20tag\
+    s.\n\n\x0b\n\x03\x04\x1c\x01\x12\x04\xee\x02\x08\x18\n\x0c\n\x04\x04\x1c\
+    \x02\0\x12\x04\xef\x02\x02\x16\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\xef\
+    \x02\x02\x07\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\xef\x02\x08\x11\n\r\n\
+    \x05\x04\x1c\x02\0\x03\x12\x04\xef\x02\x14\x15\n\x0c\n\x04\x04\x1c\x02\
+    \x01\x12\x04\xf0\x02\x02\x1b\n\r\n\x05\x04\x1c\x02\x01\x04\x12\x04\xf0\
+    \x02\x02\n\n\r\n\x05\x04\x1c\x02\x01\x05\x12\x04\xf0\x02\x0b\x11\n\r\n\
+    \x05\x04\x1c\x02\x01\x01\x12\x04\xf0\x02\x12\x16\n\r\n\x05\x04\x1c\x02\
+    \x01\x03\x12\x04\xf0\x02\x19\x1a\nI\n\x02\x04\x1d\x12\x06\xf4\x02\0\xf7\
+    \x02\x01\x1a;\x20The\x20message\x20returned\x20in\x20response\x20to\x20a\
+    \x20`TagModuleRequest`.\n\n\x0b\n\x03\x04\x1d\x01\x12\x04\xf4\x02\x08\
+    \x19\n\x0c\n\x04\x04\x1d\x02\0\x12\x04\xf5\x02\x02\x1b\n\r\n\x05\x04\x1d\
+    \x02\0\x04\x12\x04\xf5\x02\x02\n\n\r\n\x05\x04\x1d\x02\0\x05\x12\x04\xf5\
+    \x02\x0b\x11\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\xf5\x02\x12\x16\n\r\n\
+    \x05\x04\x1d\x02\0\x03\x12\x04\xf5\x02\x19\x1a\n\x0c\n\x04\x04\x1d\x02\
+    \x01\x12\x04\xf6\x02\x02\x1b\n\r\n\x05\x04\x1d\x02\x01\x04\x12\x04\xf6\
+    \x02\x02\n\n\r\n\x05\x04\x1d\x02\x01\x06\x12\x04\xf6\x02\x0b\x10\n\r\n\
+    \x05\x04\x1d\x02\x01\x01\x12\x04\xf6\x02\x11\x16\n\r\n\x05\x04\x1d\x02\
+    \x01\x03\x12\x04\xf6\x02\x19\x1a\n\x92\x01\n\x02\x04\x1e\x12\x06\xfc\x02\
+    \0\x82\x03\x01\x1a\x83\x01\x20`PUT\x20/api/v1/module/quarantine:`\n\x20Q\
+    uarantine\x20a\x20module,\x20or\x20lift\x20its\x20quarantine.\x20Return\
+    \x20the\x20module's\x20resulting\n\x20quarantine,\x20if\x20any.\n\n\x0b\
+    \n\x03\x04\x1e\x01\x12\x04\xfc\x02\x08\x1f\n\x0c\n\x04\x04\x1e\x02\0\x12\
+    \x04\xfd\x02\x02\x16\n\r\n\x05\x04\x1e\x02\0\x05\x12\x04\xfd\x02\x02\x07\
+    \n\r\n\x05\x04\x1e\x02\0\x01\x12\x04\xfd\x02\x08\x11\n\r\n\x05\x04\x1e\
+    \x02\0\x03\x12\x04\xfd\x02\x14\x15\nL\n\x04\x04\x1e\x02\x01\x12\x04\xff\
+    \x02\x02\x17\x1a>\x20quarantine\x20the\x20module\x20if\x20true,\x20other\
+    wise\x20lift\x20its\x20quarantine\n\n\r\n\x05\x04\x1e\x02\x01\x05\x12\
+    \x04\xff\x02\x02\x06\n\r\n\x05\x04\x1e\x02\x01\x01\x12\x04\xff\x02\x07\
+    \x12\n\r\n\x05\x04\x1e\x02\x01\x03\x12\x04\xff\x02\x15\x16\nR\n\x04\x04\
+    \x1e\x02\x02\x12\x04\x81\x03\x02\x14\x1aD\x20why\x20the\x20module\x20is\
+    \x20quarantined,\x20required\x20when\x20`quarantined`\x20is\x20true\n\n\
+    \r\n\x05\x04\x1e\x02\x02\x05\x12\x04\x81\x03\x02\x08\n\r\n\x05\x04\x1e\
+    \x02\x02\x01\x12\x04\x81\x03\t\x0f\n\r\n\x05\x04\x1e\x02\x02\x03\x12\x04\
+    \x81\x03\x12\x13\nP\n\x02\x04\x1f\x12\x06\x85\x03\0\x88\x03\x01\x1aB\x20\
+    The\x20message\x20returned\x20in\x20response\x20to\x20a\x20`QuarantineMo\
+    duleRequest`.\n\n\x0b\n\x03\x04\x1f\x01\x12\x04\x85\x03\x08\x20\n\x0c\n\
+    \x04\x04\x1f\x02\0\x12\x04\x86\x03\x02\x1c\n\r\n\x05\x04\x1f\x02\0\x06\
+    \x12\x04\x86\x03\x02\x0c\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\x86\x03\r\
+    \x17\n\r\n\x05\x04\x1f\x02\0\x03\x12\x04\x86\x03\x1a\x1b\n\x0c\n\x04\x04\
+    \x1f\x02\x01\x12\x04\x87\x03\x02\x1b\n\r\n\x05\x04\x1f\x02\x01\x04\x12\
+    \x04\x87\x03\x02\n\n\r\n\x05\x04\x1f\x02\x01\x06\x12\x04\x87\x03\x0b\x10\
+    \n\r\n\x05\x04\x1f\x02\x01\x01\x12\x04\x87\x03\x11\x16\n\r\n\x05\x04\x1f\
+    \x02\x01\x03\x12\x04\x87\x03\x19\x1a\n\xdd\x02\n\x02\x04\x20\x12\x06\x8e\
+    \x03\0\x90\x03\x01\x1a\xce\x02\x20Represents\x20the\x20expected\x20outco\
+    me\x20of\x20an\x20AuditModulesRequest.\x20If\x20PASS\x20is\x20provided,\
+    \x20then\n\x20the\x20audit\x20returns\x20modules\x20which\x20conform\x20\
+    to\x20the\x20checkfile.\x20If\x20FAIL\x20is\x20provided,\x20then\n\x20th\
+    e\x20audit\x20returns\x20modules\x20which\x20do\x20not\x20conform\x20to\
+    \x20the\x20checkfile.\n\x20A\x20checkfile\x20(e.g.\x20mod.yaml),\x20desc\
+    ribing\x20the\x20requirements\x20a\x20module\x20must\x20meet\x20to\x20pa\
+    ss\x20validation.\n\n\x0b\n\x03\x04\x20\x01\x12\x04\x8e\x03\x08\x11\n\
+    \x0c\n\x04\x04\x20\x02\0\x12\x04\x8f\x03\x02\x15\n\r\n\x05\x04\x20\x02\0\
+    \x06\x12\x04\x8f\x03\x02\x07\n\r\n\x05\x04\x20\x02\0\x01\x12\x04\x8f\x03\
+    \x08\x10\n\r\n\x05\x04\x20\x02\0\x03\x12\x04\x8f\x03\x13\x14\nY\n\x02\
+    \x04!\x12\x06\x93\x03\0\xa3\x03\x01\x1aK\x20The\x20individual\x20require\
+    ments\x20of\x20a\x20checkfile.\x20Unset\x20fields\x20are\x20not\x20check\
+    ed.\n\n\x0b\n\x03\x04!\x01\x12\x04\x93\x03\x08\r\n\x0c\n\x04\x04!\x02\0\
+    \x12\x04\x94\x03\x02\x1a\n\r\n\x05\x04!\x02\0\x04\x12\x04\x94\x03\x02\n\
+    \n\r\n\x05\x04!\x02\0\x05\x12\x04\x94\x03\x0b\x11\n\r\n\x05\x04!\x02\0\
+    \x01\x12\x04\x94\x03\x12\x15\n\r\n\x05\x04!\x02\0\x03\x12\x04\x94\x03\
+    \x18\x19\n\x0c\n\x04\x04!\x02\x01\x12\x04\x95\x03\x02\x1f\n\r\n\x05\x04!\
+    \x02\x01\x04\x12\x04\x95\x03\x02\n\n\r\n\x05\x04!\x02\x01\x05\x12\x04\
+    \x95\x03\x0b\x0f\n\r\n\x05\x04!\x02\x01\x01\x12\x04\x95\x03\x10\x1a\n\r\
+    \n\x05\x04!\x02\x01\x03\x12\x04\x95\x03\x1d\x1e\n\x0c\n\x04\x04!\x02\x02\
+    \x12\x04\x96\x03\x02\x1b\n\r\n\x05\x04!\x02\x02\x06\x12\x04\x96\x03\x02\
+    \x0e\n\r\n\x05\x04!\x02\x02\x01\x12\x04\x96\x03\x0f\x16\n\r\n\x05\x04!\
+    \x02\x02\x03\x12\x04\x96\x03\x19\x1a\n\x0c\n\x04\x04!\x02\x03\x12\x04\
+    \x97\x03\x02\x1b\n\r\n\x05\x04!\x02\x03\x06\x12\x04\x97\x03\x02\x0e\n\r\
+    \n\x05\x04!\x02\x03\x01\x12\x04\x97\x03\x0f\x16\n\r\n\x05\x04!\x02\x03\
+    \x03\x12\x04\x97\x03\x19\x1a\n\x0c\n\x04\x04!\x02\x04\x12\x04\x98\x03\
+    \x02\x15\n\r\n\x05\x04!\x02\x04\x06\x12\x04\x98\x03\x02\x0b\n\r\n\x05\
+    \x04!\x02\x04\x01\x12\x04\x98\x03\x0c\x10\n\r\n\x05\x04!\x02\x04\x03\x12\
+    \x04\x98\x03\x13\x14\n\x0c\n\x04\x04!\x02\x05\x12\x04\x99\x03\x02!\n\r\n\
+    \x05\x04!\x02\x05\x06\x12\x04\x99\x03\x02\x11\n\r\n\x05\x04!\x02\x05\x01\
+    \x12\x04\x99\x03\x12\x1c\n\r\n\x05\x04!\x02\x05\x03\x12\x04\x99\x03\x1f\
+    \x20\n]\n\x04\x04!\x02\x06\x12\x04\x9b\x03\x02!\x1aO\x20the\x20hex-encod\
+    ed\x20SHA-256\x20hash\x20which\x20the\x20checkfile\x20fetched\x20from\
+    \x20`url`\x20must\x20have\n\n\r\n\x05\x04!\x02\x06\x04\x12\x04\x9b\x03\
+    \x02\n\n\r\n\x05\x04!\x02\x06\x05\x12\x04\x9b\x03\x0b\x11\n\r\n\x05\x04!\
+    \x02\x06\x01\x12\x04\x9b\x03\x12\x1c\n\r\n\x05\x04!\x02\x06\x03\x12\x04\
+    \x9b\x03\x1f\x20\n\x0c\n\x04\x04!\x02\x07\x12\x04\x9c\x03\x02\x15\n\r\n\
+    \x05\x04!\x02\x07\x06\x12\x04\x9c\x03\x02\x0b\n\r\n\x05\x04!\x02\x07\x01\
+    \x12\x04\x9c\x03\x0c\x10\n\r\n\x05\x04!\x02\x07\x03\x12\x04\x9c\x03\x13\
+    \x14\n\x0c\n\x04\x04!\x02\x08\x12\x04\x9d\x03\x02%\n\r\n\x05\x04!\x02\
+    \x08\x06\x12\x04\x9d\x03\x02\x13\n\r\n\x05\x04!\x02\x08\x01\x12\x04\x9d\
+    \x03\x14\x20\n\r\n\x05\x04!\x02\x08\x03\x12\x04\x9d\x03#$\n\x0c\n\x04\
+    \x04!\x02\t\x12\x04\x9e\x03\x02\x1e\n\r\n\x05\x04!\x02\t\x06\x12\x04\x9e\
+    \x03\x02\x0f\n\r\n\x05\x04!\x02\t\x01\x12\x04\x9e\x03\x10\x18\n\r\n\x05\
+    \x04!\x02\t\x03\x12\x04\x9e\x03\x1b\x1d\n\x0c\n\x04\x04!\x02\n\x12\x04\
+    \x9f\x03\x02,\n\r\n\x05\x04!\x02\n\x06\x12\x04\x9f\x03\x02\x16\n\r\n\x05\
+    \x04!\x02\n\x01\x12\x04\x9f\x03\x17&\n\r\n\x05\x04!\x02\n\x03\x12\x04\
+    \x9f\x03)+\n\x0c\n\x04\x04!\x02\x0b\x12\x04\xa0\x03\x02\x20\n\r\n\x05\
+    \x04!\x02\x0b\x06\x12\x04\xa0\x03\x02\x10\n\r\n\x05\x04!\x02\x0b\x01\x12\
+    \x04\xa0\x03\x11\x1a\n\r\n\x05\x04!\x02\x0b\x03\x12\x04\xa0\x03\x1d\x1f\
+    \n\x0c\n\x04\x04!\x02\x0c\x12\x04\xa1\x03\x02#\n\r\n\x05\x04!\x02\x0c\
+    \x06\x12\x04\xa1\x03\x02\x11\n\r\n\x05\x04!\x02\x0c\x01\x12\x04\xa1\x03\
+    \x12\x1d\n\r\n\x05\x04!\x02\x0c\x03\x12\x04\xa1\x03\x20\"\n\x0c\n\x04\
+    \x04!\x02\r\x12\x04\xa2\x03\x02\x1e\n\r\n\x05\x04!\x02\r\x06\x12\x04\xa2\
+    \x03\x02\x0f\n\r\n\x05\x04!\x02\r\x01\x12\x04\xa2\x03\x10\x18\n\r\n\x05\
+    \x04!\x02\r\x03\x12\x04\xa2\x03\x1b\x1d\nh\n\x02\x04\"\x12\x06\xa6\x03\0\
+    \xa8\x03\x01\x1aZ\x20A\x20list\x20of\x20value\x20types,\x20wrapped\x20so\
+    \x20an\x20unchecked\x20list\x20can\x20be\x20told\x20apart\x20from\x20an\
+    \x20empty\x20one.\n\n\x0b\n\x03\x04\"\x01\x12\x04\xa6\x03\x08\x10\n\x0c\
+    \n\x04\x04\"\x02\0\x12\x04\xa7\x03\x02\x1d\n\r\n\x05\x04\"\x02\0\x04\x12\
+    \x04\xa7\x03\x02\n\n\r\n\x05\x04\"\x02\0\x06\x12\x04\xa7\x03\x0b\x12\n\r\
+    \n\x05\x04\"\x02\0\x01\x12\x04\xa7\x03\x13\x18\n\r\n\x05\x04\"\x02\0\x03\
+    \x12\x04\xa7\x03\x1b\x1c\n\x0c\n\x02\x04#\x12\x06\xaa\x03\0\xb4\x03\x01\
+    \n\x0b\n\x03\x04#\x01\x12\x04\xaa\x03\x08\x14\n\x0c\n\x04\x04#\x02\0\x12\
+    \x04\xab\x03\x02\x1f\n\r\n\x05\x04#\x02\0\x06\x12\x04\xab\x03\x02\x12\n\
+    \r\n\x05\x04#\x02\0\x01\x12\x04\xab\x03\x13\x1a\n\r\n\x05\x04#\x02\0\x03\
+    \x12\x04\xab\x03\x1d\x1e\n\x0c\n\x04\x04#\x02\x01\x12\x04\xac\x03\x02\
+    \x1f\n\r\n\x05\x04#\x02\x01\x06\x12\x04\xac\x03\x02\x12\n\r\n\x05\x04#\
+    \x02\x01\x01\x12\x04\xac\x03\x13\x1a\n\r\n\x05\x04#\x02\x01\x03\x12\x04\
+    \xac\x03\x1d\x1e\n\x0c\n\x04\x04#\x02\x02\x12\x04\xad\x03\x02\x20\n\r\n\
+    \x05\x04#\x02\x02\x06\x12\x04\xad\x03\x02\x11\n\r\n\x05\x04#\x02\x02\x01\
+    \x12\x04\xad\x03\x12\x1b\n\r\n\x05\x04#\x02\x02\x03\x12\x04\xad\x03\x1e\
+    \x1f\n]\n\x04\x04#\x02\x03\x12\x04\xaf\x03\x02\x1b\x1aO\x20how\x20`inclu\
+    de`\x20is\x20checked:\x20`require_all`,\x20`allow_only`\x20(the\x20defau\
+    lt)\x20or\x20`both`\n\n\r\n\x05\x04#\x02\x03\x04\x12\x04\xaf\x03\x02\n\n\
+    \r\n\x05\x04#\x02\x03\x05\x12\x04\xaf\x03\x0b\x11\n\r\n\x05\x04#\x02\x03\
+    \x01\x12\x04\xaf\x03\x12\x16\n\r\n\x05\x04#\x02\x03\x03\x12\x04\xaf\x03\
+    \x19\x1a\n<\n\x04\x04#\x02\x04\x12\x04\xb1\x03\x02%\x1a.\x20the\x20most\
+    \x20namespaces\x20a\x20module\x20may\x20import\x20from\n\n\r\n\x05\x04#\
+    \x02\x04\x04\x12\x04\xb1\x03\x02\n\n\r\n\x05\x04#\x02\x04\x05\x12\x04\
+    \xb1\x03\x0b\x11\n\r\n\x05\x04#\x02\x04\x01\x12\x04\xb1\x03\x12\x20\n\r\
+    \n\x05\x04#\x02\x04\x03\x12\x04\xb1\x03#$\nM\n\x04\x04#\x02\x05\x12\x04\
+    \xb3\x03\x02(\x1a?\x20the\x20most\x20functions\x20a\x20module\x20may\x20\
+    import\x20from\x20any\x20one\x20namespace\n\n\r\n\x05\x04#\x02\x05\x04\
+    \x12\x04\xb3\x03\x02\n\n\r\n\x05\x04#\x02\x05\x05\x12\x04\xb3\x03\x0b\
+    \x11\n\r\n\x05\x04#\x02\x05\x01\x12\x04\xb3\x03\x12#\n\r\n\x05\x04#\x02\
+    \x05\x03\x12\x04\xb3\x03&'\n\x0c\n\x02\x04$\x12\x06\xb6\x03\0\xb8\x03\
+    \x01\n\x0b\n\x03\x04$\x01\x12\x04\xb6\x03\x08\x18\n\x0c\n\x04\x04$\x02\0\
+    \x12\x04\xb7\x03\x02%\n\r\n\x05\x04$\x02\0\x04\x12\x04\xb7\x03\x02\n\n\r\
+    \n\x05\x04$\x02\0\x06\x12\x04\xb7\x03\x0b\x1a\n\r\n\x05\x04$\x02\0\x01\
+    \x12\x04\xb7\x03\x1b\x20\n\r\n\x05\x04$\x02\0\x03\x12\x04\xb7\x03#$\n\
+    \x0c\n\x02\x04%\x12\x06\xba\x03\0\xbf\x03\x01\n\x0b\n\x03\x04%\x01\x12\
+    \x04\xba\x03\x08\x17\n\x0c\n\x04\x04%\x02\0\x12\x04\xbb\x03\x02\x20\n\r\
+    \n\x05\x04%\x02\0\x04\x12\x04\xbb\x03\x02\n\n\r\n\x05\x04%\x02\0\x05\x12\
+    \x04\xbb\x03\x0b\x11\n\r\n\x05\x04%\x02\0\x01\x12\x04\xbb\x03\x12\x1b\n\
+    \r\n\x05\x04%\x02\0\x03\x12\x04\xbb\x03\x1e\x1f\n\x0c\n\x04\x04%\x02\x01\
+    \x12\x04\xbc\x03\x02\x12\n\r\n\x05\x04%\x02\x01\x05\x12\x04\xbc\x03\x02\
+    \x08\n\r\n\x05\x04%\x02\x01\x01\x12\x04\xbc\x03\t\r\n\r\n\x05\x04%\x02\
+    \x01\x03\x12\x04\xbc\x03\x10\x11\n\x0c\n\x04\x04%\x02\x02\x12\x04\xbd\
+    \x03\x02\x16\n\r\n\x05\x04%\x02\x02\x06\x12\x04\xbd\x03\x02\n\n\r\n\x05\
+    \x04%\x02\x02\x01\x12\x04\xbd\x03\x0b\x11\n\r\n\x05\x04%\x02\x02\x03\x12\
+    \x04\xbd\x03\x14\x15\n\x0c\n\x04\x04%\x02\x03\x12\x04\xbe\x03\x02\x17\n\
+    \r\n\x05\x04%\x02\x03\x06\x12\x04\xbe\x03\x02\n\n\r\n\x05\x04%\x02\x03\
+    \x01\x12\x04\xbe\x03\x0b\x12\n\r\n\x05\x04%\x02\x03\x03\x12\x04\xbe\x03\
+    \x15\x16\n\x0c\n\x02\x04&\x12\x06\xc1\x03\0\xc4\x03\x01\n\x0b\n\x03\x04&\
+    \x01\x12\x04\xc1\x03\x08\x17\n\x0c\n\x04\x04&\x02\0\x12\x04\xc2\x03\x02\
+    \"\n\r\n\x05\x04&\x02\0\x06\x12\x04\xc2\x03\x02\x15\n\r\n\x05\x04&\x02\0\
+    \x01\x12\x04\xc2\x03\x16\x1d\n\r\n\x05\x04&\x02\0\x03\x12\x04\xc2\x03\
+    \x20!\n\x0c\n\x04\x04&\x02\x01\x12\x04\xc3\x03\x02\"\n\r\n\x05\x04&\x02\
+    \x01\x06\x12\x04\xc3\x03\x02\x15\n\r\n\x05\x04&\x02\x01\x01\x12\x04\xc3\
+    \x03\x16\x1d\n\r\n\x05\x04&\x02\x01\x03\x12\x04\xc3\x03\x20!\n\x0c\n\x02\
+    \x04'\x12\x06\xc6\x03\0\xc8\x03\x01\n\x0b\n\x03\x04'\x01\x12\x04\xc6\x03\
+    \x08\x1b\n\x0c\n\x04\x04'\x02\0\x12\x04\xc7\x03\x02(\n\r\n\x05\x04'\x02\
+    \0\x04\x12\x04\xc7\x03\x02\n\n\r\n\x05\x04'\x02\0\x06\x12\x04\xc7\x03\
+    \x0b\x1d\n\r\n\x05\x04'\x02\0\x01\x12\x04\xc7\x03\x1e#\n\r\n\x05\x04'\
+    \x02\0\x03\x12\x04\xc7\x03&'\n\x0c\n\x02\x04(\x12\x06\xca\x03\0\xcf\x03\
+    \x01\n\x0b\n\x03\x04(\x01\x12\x04\xca\x03\x08\x1a\n\x0c\n\x04\x04(\x02\0\
+    \x12\x04\xcb\x03\x02\x12\n\r\n\x05\x04(\x02\0\x05\x12\x04\xcb\x03\x02\
+    \x08\n\r\n\x05\x04(\x02\0\x01\x12\x04\xcb\x03\t\r\n\r\n\x05\x04(\x02\0\
+    \x03\x12\x04\xcb\x03\x10\x11\n\x0c\n\x04\x04(\x02\x01\x12\x04\xcc\x03\
+    \x02+\n\r\n\x05\x04(\x02\x01\x04\x12\x04\xcc\x03\x02\n\n\r\n\x05\x04(\
+    \x02\x01\x06\x12\x04\xcc\x03\x0b\x1c\n\r\n\x05\x04(\x02\x01\x01\x12\x04\
+    \xcc\x03\x1d&\n\r\n\x05\x04(\x02\x01\x03\x12\x04\xcc\x03)*\n\x0c\n\x04\
+    \x04(\x02\x02\x12\x04\xcd\x03\x02$\n\r\n\x05\x04(\x02\x02\x04\x12\x04\
+    \xcd\x03\x02\n\n\r\n\x05\x04(\x02\x02\x05\x12\x04\xcd\x03\x0b\x11\n\r\n\
+    \x05\x04(\x02\x02\x01\x12\x04\xcd\x03\x12\x1f\n\r\n\x05\x04(\x02\x02\x03\
+    \x12\x04\xcd\x03\"#\n\x0c\n\x04\x04(\x02\x03\x12\x04\xce\x03\x02-\n\r\n\
+    \x05\x04(\x02\x03\x04\x12\x04\xce\x03\x02\n\n\r\n\x05\x04(\x02\x03\x05\
+    \x12\x04\xce\x03\x0b\x0f\n\r\n\x05\x04(\x02\x03\x01\x12\x04\xce\x03\x10(\
+    \n\r\n\x05\x04(\x02\x03\x03\x12\x04\xce\x03+,\n\x0c\n\x02\x04)\x12\x06\
+    \xd1\x03\0\xd5\x03\x01\n\x0b\n\x03\x04)\x01\x12\x04\xd1\x03\x08\x14\n\
+    \x0c\n\x04\x04)\x02\0\x12\x04\xd2\x03\x02!\n\r\n\x05\x04)\x02\0\x06\x12\
+    \x04\xd2\x03\x02\x14\n\r\n\x05\x04)\x02\0\x01\x12\x04\xd2\x03\x15\x1c\n\
+    \r\n\x05\x04)\x02\0\x03\x12\x04\xd2\x03\x1f\x20\n\x0c\n\x04\x04)\x02\x01\
+    \x12\x04\xd3\x03\x02!\n\r\n\x05\x04)\x02\x01\x06\x12\x04\xd3\x03\x02\x14\
+    \n\r\n\x05\x04)\x02\x01\x01\x12\x04\xd3\x03\x15\x1c\n\r\n\x05\x04)\x02\
+    \x01\x03\x12\x04\xd3\x03\x1f\x20\n\x0c\n\x04\x04)\x02\x02\x12\x04\xd4\
+    \x03\x02\x1a\n\r\n\x05\x04)\x02\x02\x04\x12\x04\xd4\x03\x02\n\n\r\n\x05\
+    \x04)\x02\x02\x05\x12\x04\xd4\x03\x0b\x11\n\r\n\x05\x04)\x02\x02\x01\x12\
+    \x04\xd4\x03\x12\x15\n\r\n\x05\x04)\x02\x02\x03\x12\x04\xd4\x03\x18\x19\
+    \n\x0c\n\x02\x04*\x12\x06\xd7\x03\0\xd9\x03\x01\n\x0b\n\x03\x04*\x01\x12\
+    \x04\xd7\x03\x08\x1a\n\x0c\n\x04\x04*\x02\0\x12\x04\xd8\x03\x02'\n\r\n\
+    \x05\x04*\x02\0\x04\x12\x04\xd8\x03\x02\n\n\r\n\x05\x04*\x02\0\x06\x12\
+    \x04\xd8\x03\x0b\x1c\n\r\n\x05\x04*\x02\0\x01\x12\x04\xd8\x03\x1d\"\n\r\
+    \n\x05\x04*\x02\0\x03\x12\x04\xd8\x03%&\n\x0c\n\x02\x04+\x12\x06\xdb\x03\
+    \0\xe0\x03\x01\n\x0b\n\x03\x04+\x01\x12\x04\xdb\x03\x08\x19\n\x0c\n\x04\
+    \x04+\x02\0\x12\x04\xdc\x03\x02\x12\n\r\n\x05\x04+\x02\0\x05\x12\x04\xdc\
+    \x03\x02\x08\n\r\n\x05\x04+\x02\0\x01\x12\x04\xdc\x03\t\r\n\r\n\x05\x04+\
+    \x02\0\x03\x12\x04\xdc\x03\x10\x11\n\x0c\n\x04\x04+\x02\x01\x12\x04\xdd\
+    \x03\x02\x16\n\r\n\x05\x04+\x02\x01\x06\x12\x04\xdd\x03\x02\n\n\r\n\x05\
+    \x04+\x02\x01\x01\x12\x04\xdd\x03\x0b\x11\n\r\n\x05\x04+\x02\x01\x03\x12\
+    \x04\xdd\x03\x14\x15\n\x0c\n\x04\x04+\x02\x02\x12\x04\xde\x03\x02\x17\n\
+    \r\n\x05\x04+\x02\x02\x06\x12\x04\xde\x03\x02\n\n\r\n\x05\x04+\x02\x02\
+    \x01\x12\x04\xde\x03\x0b\x12\n\r\n\x05\x04+\x02\x02\x03\x12\x04\xde\x03\
+    \x15\x16\n\x0c\n\x04\x04+\x02\x03\x12\x04\xdf\x03\x02\x1b\n\r\n\x05\x04+\
+    \x02\x03\x04\x12\x04\xdf\x03\x02\n\n\r\n\x05\x04+\x02\x03\x05\x12\x04\
+    \xdf\x03\x0b\x11\n\r\n\x05\x04+\x02\x03\x01\x12\x04\xdf\x03\x12\x16\n\r\
+    \n\x05\x04+\x02\x03\x03\x12\x04\xdf\x03\x19\x1a\n\x0c\n\x02\x04,\x12\x06\
+    \xe2\x03\0\xe5\x03\x01\n\x0b\n\x03\x04,\x01\x12\x04\xe2\x03\x08\x11\n5\n\
+    \x04\x04,\x02\0\x12\x04\xe4\x03\x02\x1a\x1a'\x20human-readable\x20size\
+    \x20limit,\x20e.g.\x20\"4MB\"\n\n\r\n\x05\x04,\x02\0\x04\x12\x04\xe4\x03\
+    \x02\n\n\r\n\x05\x04,\x02\0\x05\x12\x04\xe4\x03\x0b\x11\n\r\n\x05\x04,\
+    \x02\0\x01\x12\x04\xe4\x03\x12\x15\n\r\n\x05\x04,\x02\0\x03\x12\x04\xe4\
+    \x03\x18\x19\n\x0c\n\x02\x05\x04\x12\x06\xe7\x03\0\xeb\x03\x01\n\x0b\n\
+    \x03\x05\x04\x01\x12\x04\xe7\x03\x05\x0e\n\x0c\n\x04\x05\x04\x02\0\x12\
+    \x04\xe8\x03\x02\n\n\r\n\x05\x05\x04\x02\0\x01\x12\x04\xe8\x03\x02\x05\n\
+    \r\n\x05\x05\x04\x02\0\x02\x12\x04\xe8\x03\x08\t\n\x0c\n\x04\x05\x04\x02\
+    \x01\x12\x04\xe9\x03\x02\r\n\r\n\x05\x05\x04\x02\x01\x01\x12\x04\xe9\x03\
+    \x02\x08\n\r\n\x05\x05\x04\x02\x01\x02\x12\x04\xe9\x03\x0b\x0c\n\x0c\n\
+    \x04\x05\x04\x02\x02\x12\x04\xea\x03\x02\x0b\n\r\n\x05\x05\x04\x02\x02\
+    \x01\x12\x04\xea\x03\x02\x06\n\r\n\x05\x05\x04\x02\x02\x02\x12\x04\xea\
+    \x03\t\n\n\x0c\n\x02\x04-\x12\x06\xed\x03\0\xf0\x03\x01\n\x0b\n\x03\x04-\
+    \x01\x12\x04\xed\x03\x08\x17\n\x0c\n\x04\x04-\x02\0\x12\x04\xee\x03\x02\
+    \"\n\r\n\x05\x04-\x02\0\x04\x12\x04\xee\x03\x02\n\n\r\n\x05\x04-\x02\0\
+    \x06\x12\x04\xee\x03\x0b\x14\n\r\n\x05\x04-\x02\0\x01\x12\x04\xee\x03\
+    \x15\x1d\n\r\n\x05\x04-\x02\0\x03\x12\x04\xee\x03\x20!\n\x0c\n\x04\x04-\
+    \x02\x01\x12\x04\xef\x03\x02\x20\n\r\n\x05\x04-\x02\x01\x04\x12\x04\xef\
+    \x03\x02\n\n\r\n\x05\x04-\x02\x01\x05\x12\x04\xef\x03\x0b\x11\n\r\n\x05\
+    \x04-\x02\x01\x01\x12\x04\xef\x03\x12\x1b\n\r\n\x05\x04-\x02\x01\x03\x12\
+    \x04\xef\x03\x1e\x1f\nN\n\x02\x04.\x12\x06\xf3\x03\0\xf5\x03\x01\x1a@\
+    \x20Limits\x20on\x20the\x20composite\x20risk\x20score\x20of\x20a\x20modu\
+    le,\x20from\x200\x20to\x20100.\n\n\x0b\n\x03\x04.\x01\x12\x04\xf3\x03\
+    \x08\x11\n\x0c\n\x04\x04.\x02\0\x12\x04\xf4\x03\x02\x20\n\r\n\x05\x04.\
+    \x02\0\x04\x12\x04\xf4\x03\x02\n\n\r\n\x05\x04.\x02\0\x05\x12\x04\xf4\
+    \x03\x0b\x11\n\r\n\x05\x04.\x02\0\x01\x12\x04\xf4\x03\x12\x1b\n\r\n\x05\
+    \x04.\x02\0\x03\x12\x04\xf4\x03\x1e\x1f\nf\n\x02\x04/\x12\x06\xf8\x03\0\
+    \xfa\x03\x01\x1aX\x20The\x20capabilities\x20a\x20module\x20must\x20not\
+    \x20be\x20granted\x20by\x20its\x20imports,\x20by\x20name,\x20e.g.\x20\"n\
+    etwork\".\n\n\x0b\n\x03\x04/\x01\x12\x04\xf8\x03\x08\x19\n\x0c\n\x04\x04\
+    /\x02\0\x12\x04\xf9\x03\x02\x1b\n\r\n\x05\x04/\x02\0\x04\x12\x04\xf9\x03\
+    \x02\n\n\r\n\x05\x04/\x02\0\x05\x12\x04\xf9\x03\x0b\x11\n\r\n\x05\x04/\
+    \x02\0\x01\x12\x04\xf9\x03\x12\x16\n\r\n\x05\x04/\x02\0\x03\x12\x04\xf9\
+    \x03\x19\x1a\na\n\x02\x040\x12\x06\xfd\x03\0\xff\x03\x01\x1aS\x20SPDX\
+    \x20identifiers\x20of\x20licenses\x20which\x20must\x20not\x20be\x20found\
+    \x20in\x20a\x20module,\x20e.g.\x20\"GPL-3.0\".\n\n\x0b\n\x03\x040\x01\
+    \x12\x04\xfd\x03\x08\x15\n\x0c\n\x04\x040\x02\0\x12\x04\xfe\x03\x02\x1b\
+    \n\r\n\x05\x040\x02\0\x04\x12\x04\xfe\x03\x02\n\n\r\n\x05\x040\x02\0\x05\
+    \x12\x04\xfe\x03\x0b\x11\n\r\n\x05\x040\x02\0\x01\x12\x04\xfe\x03\x12\
+    \x16\n\r\n\x05\x040\x02\0\x03\x12\x04\xfe\x03\x19\x1a\n_\n\x02\x041\x12\
+    \x06\x82\x04\0\x87\x04\x01\x1aQ\x20The\x20most\x20severe\x20known\x20vul\
+    nerability\x20allowed\x20in\x20the\x20libraries\x20found\x20in\x20a\x20m\
+    odule.\n\n\x0b\n\x03\x041\x01\x12\x04\x82\x04\x08\x1c\n5\n\x04\x041\x02\
+    \0\x12\x04\x84\x04\x02#\x1a'\x20\"low\",\x20\"medium\",\x20\"high\"\x20o\
+    r\x20\"critical\"\n\n\r\n\x05\x041\x02\0\x04\x12\x04\x84\x04\x02\n\n\r\n\
+    \x05\x041\x02\0\x05\x12\x04\x84\x04\x0b\x11\n\r\n\x05\x041\x02\0\x01\x12\
+    \x04\x84\x04\x12\x1e\n\r\n\x05\x041\x02\0\x03\x12\x04\x84\x04!\"\n8\n\
+    \x04\x041\x02\x01\x12\x04\x86\x04\x02\x19\x1a*\x20\"osv\",\x20or\x20a\
+    \x20path\x20to\x20a\x20local\x20OSV\x20database\n\n\r\n\x05\x041\x02\x01\
+    \x04\x12\x04\x86\x04\x02\n\n\r\n\x05\x041\x02\x01\x05\x12\x04\x86\x04\
+    \x0b\x11\n\r\n\x05\x041\x02\x01\x01\x12\x04\x86\x04\x12\x14\n\r\n\x05\
+    \x041\x02\x01\x03\x12\x04\x86\x04\x17\x18\n\xa9\x01\n\x02\x042\x12\x06\
+    \x8b\x04\0\x8f\x04\x01\x1a\x9a\x01\x20Whether\x20a\x20module\x20may\x20i\
+    mport\x20the\x20same\x20function\x20with\x20different\x20signatures,\x20\
+    export\x20more\x20than\x20one\n\x20function\x20with\x20the\x20same\x20na\
+    me,\x20or\x20have\x20structural\x20problems.\n\n\x0b\n\x03\x042\x01\x12\
+    \x04\x8b\x04\x08\x16\n\x0c\n\x04\x042\x02\0\x12\x04\x8c\x04\x02,\n\r\n\
+    \x05\x042\x02\0\x04\x12\x04\x8c\x04\x02\n\n\r\n\x05\x042\x02\0\x05\x12\
+    \x04\x8c\x04\x0b\x0f\n\r\n\x05\x042\x02\0\x01\x12\x04\x8c\x04\x10'\n\r\n\
+    \x05\x042\x02\0\x03\x12\x04\x8c\x04*+\n\x0c\n\x04\x042\x02\x01\x12\x04\
+    \x8d\x04\x02,\n\r\n\x05\x042\x02\x01\x04\x12\x04\x8d\x04\x02\n\n\r\n\x05\
+    \x042\x02\x01\x05\x12\x04\x8d\x04\x0b\x0f\n\r\n\x05\x042\x02\x01\x01\x12\
+    \x04\x8d\x04\x10'\n\r\n\x05\x042\x02\x01\x03\x12\x04\x8d\x04*+\n\x0c\n\
+    \x04\x042\x02\x02\x12\x04\x8e\x04\x02$\n\r\n\x05\x042\x02\x02\x04\x12\
+    \x04\x8e\x04\x02\n\n\r\n\x05\x042\x02\x02\x05\x12\x04\x8e\x04\x0b\x0f\n\
+    \r\n\x05\x042\x02\x02\x01\x12\x04\x8e\x04\x10\x1f\n\r\n\x05\x042\x02\x02\
+    \x03\x12\x04\x8e\x04\"#\na\n\x02\x043\x12\x06\x92\x04\0\x94\x04\x01\x1aS\
+    \x20A\x20regular\x20expression\x20which\x20the\x20whole\x20file\x20name\
+    \x20of\x20a\x20module's\x20location\x20must\x20match.\n\n\x0b\n\x03\x043\
+    \x01\x12\x04\x92\x04\x08\x17\n\x0c\n\x04\x043\x02\0\x12\x04\x93\x04\x02\
+    \x1e\n\r\n\x05\x043\x02\0\x04\x12\x04\x93\x04\x02\n\n\r\n\x05\x043\x02\0\
+    \x05\x12\x04\x93\x04\x0b\x11\n\r\n\x05\x043\x02\0\x01\x12\x04\x93\x04\
+    \x12\x19\n\r\n\x05\x043\x02\0\x03\x12\x04\x93\x04\x1c\x1d\nP\n\x02\x044\
+    \x12\x06\x97\x04\0\x99\x04\x01\x1aB\x20The\x20URL\x20schemes\x20a\x20mod\
+    ule's\x20location\x20may\x20use,\x20e.g.\x20\"s3\"\x20or\x20\"oci\".\n\n\
+    \x0b\n\x03\x044\x01\x12\x04\x97\x04\x08\x15\n\x0c\n\x04\x044\x02\0\x12\
+    \x04\x98\x04\x02&\n\r\n\x05\x044\x02\0\x04\x12\x04\x98\x04\x02\n\n\r\n\
+    \x05\x044\x02\0\x05\x12\x04\x98\x04\x0b\x11\n\r\n\x05\x044\x02\0\x01\x12\
+    \x04\x98\x04\x12!\n\r\n\x05\x044\x02\0\x03\x12\x04\x98\x04$%\n\x0c\n\x02\
+    \x05\x05\x12\x06\x9b\x04\0\x9e\x04\x01\n\x0b\n\x03\x05\x05\x01\x12\x04\
+    \x9b\x04\x05\x11\n\x0c\n\x04\x05\x05\x02\0\x12\x04\x9c\x04\x02\x0b\n\r\n\
+    \x05\x05\x05\x02\0\x01\x12\x04\x9c\x04\x02\x06\n\r\n\x05\x05\x05\x02\0\
+    \x02\x12\x04\x9c\x04\t\n\n\x0c\n\x04\x05\x05\x02\x01\x12\x04\x9d\x04\x02\
+    \x0b\n\r\n\x05\x05\x05\x02\x01\x01\x12\x04\x9d\x04\x02\x06\n\r\n\x05\x05\
+    \x05\x02\x01\x02\x12\x04\x9d\x04\t\n\n\x82\x01\n\x02\x045\x12\x06\xa2\
+    \x04\0\xa9\x04\x01\x1at\x20`POST\x20/api/v1/audit:`\n\x20Return\x20a\x20\
+    list\x20of\x20modules\x20which\x20match\x20the\x20outcome\x20requirement\
+    s\x20using\x20the\x20provided\x20checkfile.\n\n\x0b\n\x03\x045\x01\x12\
+    \x04\xa2\x04\x08\x1b\n8\n\x04\x045\x02\0\x12\x04\xa4\x04\x02\x16\x1a*\
+    \x20the\x20YAML\x20checkfile\x20(e.g.\x20mod.yaml)\x20bytes\n\n\r\n\x05\
+    \x045\x02\0\x05\x12\x04\xa4\x04\x02\x07\n\r\n\x05\x045\x02\0\x01\x12\x04\
+    \xa4\x04\x08\x11\n\r\n\x05\x045\x02\0\x03\x12\x04\xa4\x04\x14\x15\n\x0c\
+    \n\x04\x045\x02\x01\x12\x04\xa5\x04\x02\x1b\n\r\n\x05\x045\x02\x01\x06\
+    \x12\x04\xa5\x04\x02\x0e\n\r\n\x05\x045\x02\x01\x01\x12\x04\xa5\x04\x0f\
+    \x16\n\r\n\x05\x045\x02\x01\x03\x12\x04\xa5\x04\x19\x1a\n\x0c\n\x04\x045\
+    \x02\x02\x12\x04\xa6\x04\x02\x1c\n\r\n\x05\x045\x02\x02\x06\x12\x04\xa6\
+    \x04\x02\x0c\n\r\n\x05\x045\x02\x02\x01\x12\x04\xa6\x04\r\x17\n\r\n\x05\
+    \x045\x02\x02\x03\x12\x04\xa6\x04\x1a\x1b\nU\n\x04\x045\x02\x03\x12\x04\
+    \xa8\x04\x02\x1b\x1aG\x20the\x20typed\x20checkfile,\x20which\x20takes\
+    \x20precedence\x20over\x20`checkfile`\x20when\x20set\n\n\r\n\x05\x045\
+    \x02\x03\x06\x12\x04\xa8\x04\x02\x0b\n\r\n\x05\x045\x02\x03\x01\x12\x04\
+    \xa8\x04\x0c\x16\n\r\n\x05\x045\x02\x03\x03\x12\x04\xa8\x04\x19\x1a\nL\n\
+    \x02\x046\x12\x06\xac\x04\0\xb7\x04\x01\x1a>\x20The\x20message\x20return\
+    ed\x20in\x20response\x20to\x20a\x20`AuditModulesRequest`.\n\n\x0b\n\x03\
+    \x046\x01\x12\x04\xac\x04\x08\x1c\n\xad\x01\n\x04\x046\x02\0\x12\x04\xaf\
+    \x04\x02.\x1a\x9e\x01\x20each\x20record\x20contains\x20the\x20ID\x20of\
+    \x20the\x20invalid\x20Module\x20which\x20failed\x20the\x20audit,\x20as\
+    \x20well\x20as\x20the\x20failure\x20\n\x20report\x20produced\x20by\x20th\
+    e\x20validation\x20check\x20(encoded\x20in\x20JSON)\n\n\r\n\x05\x046\x02\
+    \0\x06\x12\x04\xaf\x04\x02\x13\n\r\n\x05\x046\x02\0\x01\x12\x04\xaf\x04\
+    \x14)\n\r\n\x05\x046\x02\0\x03\x12\x04\xaf\x04,-\n\x0c\n\x04\x046\x02\
+    \x01\x12\x04\xb0\x04\x02\x1c\n\r\n\x05\x046\x02\x01\x06\x12\x04\xb0\x04\
+    \x02\x0c\n\r\n\x05\x046\x02\x01\x01\x12\x04\xb0\x04\r\x17\n\r\n\x05\x046\
+    \x02\x01\x03\x12\x04\xb0\x04\x1a\x1b\ng\n\x04\x046\x02\x02\x12\x04\xb3\
+    \x04\x02\x13\x1aY\x20the\x20full\x20count\x20of\x20results\x20in\x20the\
+    \x20database\x20(not\x20the\x20count\x20of\x20this\x20message's\n\x20`mo\
+    dules`).\n\n\r\n\x05\x046\x02\x02\x05\x12\x04\xb3\x04\x02\x08\n\r\n\x05\
+    \x046\x02\x02\x01\x12\x04\xb3\x04\t\x0e\n\r\n\x05\x046\x02\x02\x03\x12\
+    \x04\xb3\x04\x11\x12\n\x0c\n\x04\x046\x02\x03\x12\x04\xb4\x04\x02\x1b\n\
+    \r\n\x05\x046\x02\x03\x04\x12\x04\xb4\x04\x02\n\n\r\n\x05\x046\x02\x03\
+    \x06\x12\x04\xb4\x04\x0b\x10\n\r\n\x05\x046\x02\x03\x01\x12\x04\xb4\x04\
+    \x11\x16\n\r\n\x05\x046\x02\x03\x03\x12\x04\xb4\x04\x19\x1a\ng\n\x04\x04\
+    6\x02\x04\x12\x04\xb6\x04\x02(\x1aY\x20the\x20SHA-256\x20hash\x20of\x20e\
+    ach\x20module\x20included\x20in\x20`invalid_module_report`,\x20keyed\x20\
+    by\x20module\x20ID\n\n\r\n\x05\x046\x02\x04\x06\x12\x04\xb6\x04\x02\x14\
+    \n\r\n\x05\x046\x02\x04\x01\x12\x04\xb6\x04\x15#\n\r\n\x05\x046\x02\x04\
+    \x03\x12\x04\xb6\x04&'\nD\n\x02\x047\x12\x06\xbb\x04\0\xc0\x04\x01\x1a6\
+    \x20`POST\x20/api/v1/diff:`\n\x20Return\x20the\x20diff\x20of\x20two\x20m\
+    odules\n\n\x0b\n\x03\x047\x01\x12\x04\xbb\x04\x08\x13\n\x0c\n\x04\x047\
+    \x02\0\x12\x04\xbc\x04\x02\x14\n\r\n\x05\x047\x02\0\x05\x12\x04\xbc\x04\
+    \x02\x07\n\r\n\x05\x047\x02\0\x01\x12\x04\xbc\x04\x08\x0f\n\r\n\x05\x047\
+    \x02\0\x03\x12\x04\xbc\x04\x12\x13\n\x0c\n\x04\x047\x02\x01\x12\x04\xbd\
+    \x04\x02\x14\n\r\n\x05\x047\x02\x01\x05\x12\x04\xbd\x04\x02\x07\n\r\n\
+    \x05\x047\x02\x01\x01\x12\x04\xbd\x04\x08\x0f\n\r\n\x05\x047\x02\x01\x03\
+    \x12\x04\xbd\x04\x12\x13\n\x0c\n\x04\x047\x02\x02\x12\x04\xbe\x04\x02\
+    \x1a\n\r\n\x05\x047\x02\x02\x05\x12\x04\xbe\x04\x02\x06\n\r\n\x05\x047\
+    \x02\x02\x01\x12\x04\xbe\x04\x07\x15\n\r\n\x05\x047\x02\x02\x03\x12\x04\
+    \xbe\x04\x18\x19\n\x0c\n\x04\x047\x02\x03\x12\x04\xbf\x04\x02\x18\n\r\n\
+    \x05\x047\x02\x03\x05\x12\x04\xbf\x04\x02\x06\n\r\n\x05\x047\x02\x03\x01\
+    \x12\x04\xbf\x04\x07\x13\n\r\n\x05\x047\x02\x03\x03\x12\x04\xbf\x04\x16\
+    \x17\n\x98\x01\n\x02\x048\x12\x06\xc4\x04\0\xc7\x04\x01\x1a\x89\x01\x20T\
+    he\x20message\x20returned\x20in\x20response\x20to\x20`DiffRequest`,\x20c\
+    ontains\x20a\x20text\x20representation\x20of\x20the\x20difference\n\x20b\
+    etween\x20the\x20two\x20specified\x20modules.\n\n\x0b\n\x03\x048\x01\x12\
+    \x04\xc4\x04\x08\x14\n\x0c\n\x04\x048\x02\0\x12\x04\xc5\x04\x02\x12\n\r\
+    \n\x05\x048\x02\0\x05\x12\x04\xc5\x04\x02\x08\n\r\n\x05\x048\x02\0\x01\
+    \x12\x04\xc5\x04\t\r\n\r\n\x05\x048\x02\0\x03\x12\x04\xc5\x04\x10\x11\n\
+    \x0c\n\x04\x048\x02\x01\x12\x04\xc6\x04\x02\x1b\n\r\n\x05\x048\x02\x01\
+    \x04\x12\x04\xc6\x04\x02\n\n\r\n\x05\x048\x02\x01\x06\x12\x04\xc6\x04\
+    \x0b\x10\n\r\n\x05\x048\x02\x01\x01\x12\x04\xc6\x04\x11\x16\n\r\n\x05\
+    \x048\x02\x01\x03\x12\x04\xc6\x04\x19\x1a\n\x8a\x01\n\x02\x049\x12\x06\
+    \xcb\x04\0\xd6\x04\x01\x1a|\x20`POST\x20/api/v1/validate:`\n\x20Return\
+    \x20the\x20failure\x20report\x20(if\x20applicable)\x20of\x20a\x20wasm\
+    \x20module\x20validation\x20against\x20a\x20given\x20checkfile.\n\n\x0b\
+    \n\x03\x049\x01\x12\x04\xcb\x04\x08\x1d\n8\n\x04\x049\x02\0\x12\x04\xcd\
+    \x04\x02\x16\x1a*\x20the\x20YAML\x20checkfile\x20(e.g.\x20mod.yaml)\x20b\
+    ytes\n\n\r\n\x05\x049\x02\0\x05\x12\x04\xcd\x04\x02\x07\n\r\n\x05\x049\
+    \x02\0\x01\x12\x04\xcd\x04\x08\x11\n\r\n\x05\x049\x02\0\x03\x12\x04\xcd\
+    \x04\x14\x15\n\xb8\x01\n\x04\x049\x08\0\x12\x06\xd0\x04\x02\xd3\x04\x03\
+    \x1a\xa7\x01\x20module_input\x20is\x20either\x20an\x20existing\x20`modul\
+    e_id`\x20that\x20is\x20known\x20to\x20the\x20database,\x20or\x20the\x20b\
+    ytes\x20of\n\x20a\x20raw\x20wasm\x20module.\x20It\x20is\x20used\x20to\
+    \x20validate\x20against\x20the\x20given\x20checkfile.\n\n\r\n\x05\x049\
+    \x08\0\x01\x12\x04\xd0\x04\x08\x14\n\x0c\n\x04\x049\x02\x01\x12\x04\xd1\
+    \x04\x04\x15\n\r\n\x05\x049\x02\x01\x05\x12\x04\xd1\x04\x04\t\n\r\n\x05\
+    \x049\x02\x01\x01\x12\x04\xd1\x04\n\x10\n\r\n\x05\x049\x02\x01\x03\x12\
+    \x04\xd1\x04\x13\x14\n\x0c\n\x04\x049\x02\x02\x12\x04\xd2\x04\x04\x18\n\
+    \r\n\x05\x049\x02\x02\x05\x12\x04\xd2\x04\x04\t\n\r\n\x05\x049\x02\x02\
+    \x01\x12\x04\xd2\x04\n\x13\n\r\n\x05\x049\x02\x02\x03\x12\x04\xd2\x04\
+    \x16\x17\nU\n\x04\x049\x02\x03\x12\x04\xd5\x04\x02\x1b\x1aG\x20the\x20ty\
+    ped\x20checkfile,\x20which\x20takes\x20precedence\x20over\x20`checkfile`\
+    \x20when\x20set\n\n\r\n\x05\x049\x02\x03\x06\x12\x04\xd5\x04\x02\x0b\n\r\
+    \n\x05\x049\x02\x03\x01\x12\x04\xd5\x04\x0c\x16\n\r\n\x05\x049\x02\x03\
+    \x03\x12\x04\xd5\x04\x19\x1a\nV\n\x02\x04:\x12\x06\xd9\x04\0\xdc\x04\x01\
+    \x1aH\x20The\x20failure\x20report\x20produced\x20by\x20the\x20validation\
+    \x20check\x20(encoded\x20in\x20JSON).\n\n\x0b\n\x03\x04:\x01\x12\x04\xd9\
+    \x04\x08\x1e\n\x0c\n\x04\x04:\x02\0\x12\x04\xda\x04\x02\"\n\r\n\x05\x04:\
+    \x02\0\x05\x12\x04\xda\x04\x02\x07\n\r\n\x05\x04:\x02\0\x01\x12\x04\xda\
+    \x04\x08\x1d\n\r\n\x05\x04:\x02\0\x03\x12\x04\xda\x04\x20!\n\x0c\n\x04\
+    \x04:\x02\x01\x12\x04\xdb\x04\x02\x1b\n\r\n\x05\x04:\x02\x01\x04\x12\x04\
+    \xdb\x04\x02\n\n\r\n\x05\x04:\x02\x01\x06\x12\x04\xdb\x04\x0b\x10\n\r\n\
+    \x05\x04:\x02\x01\x01\x12\x04\xdb\x04\x11\x16\n\r\n\x05\x04:\x02\x01\x03\
+    \x12\x04\xdb\x04\x19\x1a\nI\n\x02\x04;\x12\x04\xe0\x04\06\x1a=\x20`POST\
+    \x20/api/v1/module_graph:`\n\x20Return\x20a\x20single\x20module_graph.\n\
+    \n\x0b\n\x03\x04;\x01\x12\x04\xe0\x04\x08\x1d\n\x0c\n\x04\x04;\x02\0\x12\
+    \x04\xe0\x04\x204\n\r\n\x05\x04;\x02\0\x05\x12\x04\xe0\x04\x20%\n\r\n\
+    \x05\x04;\x02\0\x01\x12\x04\xe0\x04&/\n\r\n\x05\x04;\x02\0\x03\x12\x04\
+    \xe0\x0423\nN\n\x02\x04<\x12\x06\xe3\x04\0\xe6\x04\x01\x1a@\x20The\x20me\
+    ssage\x20returned\x20in\x20response\x20to\x20a\x20`GetModuleGraphRequest\
+    `.\n\n\x0b\n\x03\x04<\x01\x12\x04\xe3\x04\x08\x1e\n\x0c\n\x04\x04<\x02\0\
+    \x12\x04\xe4\x04\x02\x1f\n\r\n\x05\x04<\x02\0\x06\x12\x04\xe4\x04\x02\r\
+    \n\r\n\x05\x04<\x02\0\x01\x12\x04\xe4\x04\x0e\x1a\n\r\n\x05\x04<\x02\0\
+    \x03\x12\x04\xe4\x04\x1d\x1e\n\x0c\n\x04\x04<\x02\x01\x12\x04\xe5\x04\
+    \x02\x1b\n\r\n\x05\x04<\x02\x01\x04\x12\x04\xe5\x04\x02\n\n\r\n\x05\x04<\
+    \x02\x01\x06\x12\x04\xe5\x04\x0b\x10\n\r\n\x05\x04<\x02\x01\x01\x12\x04\
+    \xe5\x04\x11\x16\n\r\n\x05\x04<\x02\x01\x03\x12\x04\xe5\x04\x19\x1a\n#\n\
+    \x02\x04=\x12\x06\xe9\x04\0\xef\x04\x01\x1a\x15\x20PUT\x20/api/v1/plugin\
+    :\n\n\x0b\n\x03\x04=\x01\x12\x04\xe9\x04\x08\x1c\n\x0c\n\x04\x04=\x02\0\
+    \x12\x04\xea\x04\x02\x18\n\r\n\x05\x04=\x02\0\x05\x12\x04\xea\x04\x02\
+    \x08\n\r\n\x05\x04=\x02\0\x01\x12\x04\xea\x04\t\x13\n\r\n\x05\x04=\x02\0\
+    \x03\x12\x04\xea\x04\x16\x17\n\x0c\n\x04\x04=\x02\x01\x12\x04\xeb\x04\
+    \x02\x1b\n\r\n\x05\x04=\x02\x01\x04\x12\x04\xeb\x04\x02\n\n\r\n\x05\x04=\
+    \x02\x01\x05\x12\x04\xeb\x04\x0b\x11\n\r\n\x05\x04=\x02\x01\x01\x12\x04\
+    \xeb\x04\x12\x16\n\r\n\x05\x04=\x02\x01\x03\x12\x04\xeb\x04\x19\x1a\n\
+    \x0c\n\x04\x04=\x02\x02\x12\x04\xec\x04\x02\x16\n\r\n\x05\x04=\x02\x02\
+    \x05\x12\x04\xec\x04\x02\x08\n\r\n\x05\x04=\x02\x02\x01\x12\x04\xec\x04\
+    \t\x11\n\r\n\x05\x04=\x02\x02\x03\x12\x04\xec\x04\x14\x15\n!\n\x04\x04=\
+    \x02\x03\x12\x04\xed\x04\x02\x11\"\x13\x20bytes\x20config\x20=\x205;\n\n\
+    \r\n\x05\x04=\x02\x03\x05\x12\x04\xed\x04\x02\x07\n\r\n\x05\x04=\x02\x03\
+    \x01\x12\x04\xed\x04\x08\x0c\n\r\n\x05\x04=\x02\x03\x03\x12\x04\xed\x04\
+    \x0f\x10\n\x0c\n\x02\x04>\x12\x06\xf1\x04\0\xf4\x04\x01\n\x0b\n\x03\x04>\
+    \x01\x12\x04\xf1\x04\x08\x1d\n\x0c\n\x04\x04>\x02\0\x12\x04\xf2\x04\x02\
+    \x12\n\r\n\x05\x04>\x02\0\x05\x12\x04\xf2\x04\x02\x08\n\r\n\x05\x04>\x02\
+    \0\x01\x12\x04\xf2\x04\t\r\n\r\n\x05\x04>\x02\0\x03\x12\x04\xf2\x04\x10\
+    \x11\n\x0c\n\x04\x04>\x02\x01\x12\x04\xf3\x04\x02\x1b\n\r\n\x05\x04>\x02\
+    \x01\x04\x12\x04\xf3\x04\x02\n\n\r\n\x05\x04>\x02\x01\x06\x12\x04\xf3\
+    \x04\x0b\x10\n\r\n\x05\x04>\x02\x01\x01\x12\x04\xf3\x04\x11\x16\n\r\n\
+    \x05\x04>\x02\x01\x03\x12\x04\xf3\x04\x19\x1a\n&\n\x02\x04?\x12\x06\xf7\
+    \x04\0\xf9\x04\x01\x1a\x18\x20DELETE\x20/api/v1/plugin:\n\n\x0b\n\x03\
+    \x04?\x01\x12\x04\xf7\x04\x08\x1e\n\x0c\n\x04\x04?\x02\0\x12\x04\xf8\x04\
+    \x02\x18\n\r\n\x05\x04?\x02\0\x05\x12\x04\xf8\x04\x02\x08\n\r\n\x05\x04?\
+    \x02\0\x01\x12\x04\xf8\x04\t\x13\n\r\n\x05\x04?\x02\0\x03\x12\x04\xf8\
+    \x04\x16\x17\n\x0c\n\x02\x04@\x12\x06\xfb\x04\0\xfd\x04\x01\n\x0b\n\x03\
+    \x04@\x01\x12\x04\xfb\x04\x08\x1f\n\x0c\n\x04\x04@\x02\0\x12\x04\xfc\x04\
+    \x02\x1b\n\r\n\x05\x04@\x02\0\x04\x12\x04\xfc\x04\x02\n\n\r\n\x05\x04@\
+    \x02\0\x06\x12\x04\xfc\x04\x0b\x10\n\r\n\x05\x04@\x02\0\x01\x12\x04\xfc\
+    \x04\x11\x16\n\r\n\x05\x04@\x02\0\x03\x12\x04\xfc\x04\x19\x1a\n$\n\x02\
+    \x04A\x12\x06\x80\x05\0\x86\x05\x01\x1a\x16\x20POST\x20/api/v1/plugin:\n\
+    \n\x0b\n\x03\x04A\x01\x12\x04\x80\x05\x08\x19\n\x0c\n\x04\x04A\x02\0\x12\
+    \x04\x81\x05\x02\x18\n\r\n\x05\x04A\x02\0\x05\x12\x04\x81\x05\x02\x08\n\
+    \r\n\x05\x04A\x02\0\x01\x12\x04\x81\x05\t\x13\n\r\n\x05\x04A\x02\0\x03\
+    \x12\x04\x81\x05\x16\x17\n\x0c\n\x04\x04A\x02\x01\x12\x04\x82\x05\x02\
+    \x1b\n\r\n\x05\x04A\x02\x01\x05\x12\x04\x82\x05\x02\x08\n\r\n\x05\x04A\
+    \x02\x01\x01\x12\x04\x82\x05\t\x16\n\r\n\x05\x04A\x02\x01\x03\x12\x04\
+    \x82\x05\x19\x1a\n\x0c\n\x04\x04A\x02\x02\x12\x04\x83\x05\x02\x12\n\r\n\
+    \x05\x04A\x02\x02\x05\x12\x04\x83\x05\x02\x07\n\r\n\x05\x04A\x02\x02\x01\
+    \x12\x04\x83\x05\x08\r\n\r\n\x05\x04A\x02\x02\x03\x12\x04\x83\x05\x10\
+    \x11\n!\n\x04\x04A\x02\x03\x12\x04\x84\x05\x02\x1b\"\x13\x20bytes\x20con\
+    fig\x20=\x205;\n\n\r\n\x05\x04A\x02\x03\x04\x12\x04\x84\x05\x02\n\n\r\n\
+    \x05\x04A\x02\x03\x05\x12\x04\x84\x05\x0b\x11\n\r\n\x05\x04A\x02\x03\x01\
+    \x12\x04\x84\x05\x12\x16\n\r\n\x05\x04A\x02\x03\x03\x12\x04\x84\x05\x19\
+    \x1a\n\x0c\n\x02\x04B\x12\x06\x88\x05\0\x8b\x05\x01\n\x0b\n\x03\x04B\x01\
+    \x12\x04\x88\x05\x08\x1a\n\x0c\n\x04\x04B\x02\0\x12\x04\x89\x05\x02\x13\
+    \n\r\n\x05\x04B\x02\0\x05\x12\x04\x89\x05\x02\x07\n\r\n\x05\x04B\x02\0\
+    \x01\x12\x04\x89\x05\x08\x0e\n\r\n\x05\x04B\x02\0\x03\x12\x04\x89\x05\
+    \x11\x12\n\x0c\n\x04\x04B\x02\x01\x12\x04\x8a\x05\x02\x1b\n\r\n\x05\x04B\
+    \x02\x01\x04\x12\x04\x8a\x05\x02\n\n\r\n\x05\x04B\x02\x01\x06\x12\x04\
+    \x8a\x05\x0b\x10\n\r\n\x05\x04B\x02\x01\x01\x12\x04\x8a\x05\x11\x16\n\r\
+    \n\x05\x04B\x02\x01\x03\x12\x04\x8a\x05\x19\x1a\nb\n\x02\x04C\x12\x04\
+    \x8f\x05\0\x18\x1aV\x20`GET\x20/api/v1/health:`\n\x20Check\x20that\x20th\
+    e\x20server\x20is\x20running\x20and\x20able\x20to\x20handle\x20requests.\
+    \n\n\x0b\n\x03\x04C\x01\x12\x04\x8f\x05\x08\x15\nF\n\x02\x04D\x12\x06\
+    \x92\x05\0\x95\x05\x01\x1a8\x20The\x20message\x20returned\x20in\x20respo\
+    nse\x20to\x20a\x20`HealthRequest`.\n\n\x0b\n\x03\x04D\x01\x12\x04\x92\
+    \x05\x08\x16\n\x0c\n\x04\x04D\x02\0\x12\x04\x93\x05\x02\x0e\n\r\n\x05\
+    \x04D\x02\0\x05\x12\x04\x93\x05\x02\x06\n\r\n\x05\x04D\x02\0\x01\x12\x04\
+    \x93\x05\x07\t\n\r\n\x05\x04D\x02\0\x03\x12\x04\x93\x05\x0c\r\n\x0c\n\
+    \x04\x04D\x02\x01\x12\x04\x94\x05\x02\x1b\n\r\n\x05\x04D\x02\x01\x04\x12\
+    \x04\x94\x05\x02\n\n\r\n\x05\x04D\x02\x01\x06\x12\x04\x94\x05\x0b\x10\n\
+    \r\n\x05\x04D\x02\x01\x01\x12\x04\x94\x05\x11\x16\n\r\n\x05\x04D\x02\x01\
+    \x03\x12\x04\x94\x05\x19\x1a\nb\n\x02\x04E\x12\x04\x99\x05\0\x1c\x1aV\
+    \x20`GET\x20/api/v1/info:`\n\x20Describe\x20the\x20server's\x20version\
+    \x20and\x20the\x20API\x20features\x20it\x20supports.\n\n\x0b\n\x03\x04E\
+    \x01\x12\x04\x99\x05\x08\x19\nJ\n\x02\x04F\x12\x06\x9c\x05\0\xa3\x05\x01\
+    \x1a<\x20The\x20message\x20returned\x20in\x20response\x20to\x20a\x20`Ser\
+    verInfoRequest`.\n\n\x0b\n\x03\x04F\x01\x12\x04\x9c\x05\x08\x1a\n\x0c\n\
+    \x04\x04F\x02\0\x12\x04\x9d\x05\x02\x15\n\r\n\x05\x04F\x02\0\x05\x12\x04\
+    \x9d\x05\x02\x08\n\r\n\x05\x04F\x02\0\x01\x12\x04\x9d\x05\t\x10\n\r\n\
+    \x05\x04F\x02\0\x03\x12\x04\x9d\x05\x13\x14\n_\n\x04\x04F\x02\x01\x12\
+    \x04\x9f\x05\x02\x1f\x1aQ\x20the\x20optional\x20API\x20features\x20suppo\
+    rted\x20by\x20the\x20server,\x20e.g.\x20\"tags\",\x20\"audit\",\x20\"dif\
+    f\"\n\n\r\n\x05\x04F\x02\x01\x04\x12\x04\x9f\x05\x02\n\n\r\n\x05\x04F\
+    \x02\x01\x05\x12\x04\x9f\x05\x0b\x11\n\r\n\x05\x04F\x02\x01\x01\x12\x04\
+    \x9f\x05\x12\x1a\n\r\n\x05\x04F\x02\x01\x03\x12\x04\x9f\x05\x1d\x1e\nJ\n\
+    \x04\x04F\x02\x02\x12\x04\xa1\x05\x02\x1b\x1a<\x20true\x20if\x20plugins\
+    \x20can\x20be\x20installed\x20and\x20called\x20on\x20this\x20server\n\n\
+    \r\n\x05\x04F\x02\x02\x05\x12\x04\xa1\x05\x02\x06\n\r\n\x05\x04F\x02\x02\
+    \x01\x12\x04\xa1\x05\x07\x16\n\r\n\x05\x04F\x02\x02\x03\x12\x04\xa1\x05\
+    \x19\x1a\n\x0c\n\x04\x04F\x02\x03\x12\x04\xa2\x05\x02\x1b\n\r\n\x05\x04F\
+    \x02\x03\x04\x12\x04\xa2\x05\x02\n\n\r\n\x05\x04F\x02\x03\x06\x12\x04\
+    \xa2\x05\x0b\x10\n\r\n\x05\x04F\x02\x03\x01\x12\x04\xa2\x05\x11\x16\n\r\
+    \n\x05\x04F\x02\x03\x03\x12\x04\xa2\x05\x19\x1a\nR\n\x02\x05\x06\x12\x06\
+    \xa6\x05\0\xad\x05\x01\x1aD\x20The\x20kinds\x20of\x20changes\x20to\x20mo\
+    dules\x20which\x20are\x20reported\x20to\x20subscribers.\n\n\x0b\n\x03\
+    \x05\x06\x01\x12\x04\xa6\x05\x05\x14\n^\n\x04\x05\x06\x02\0\x12\x04\xa8\
+    \x05\x02$\x1aP\x20never\x20sent\x20by\x20the\x20server,\x20so\x20that\
+    \x20a\x20missing\x20kind\x20is\x20not\x20mistaken\x20for\x20`CREATED`\n\
+    \n\r\n\x05\x05\x06\x02\0\x01\x12\x04\xa8\x05\x02\x1f\n\r\n\x05\x05\x06\
+    \x02\0\x02\x12\x04\xa8\x05\"#\n\x0c\n\x04\x05\x06\x02\x01\x12\x04\xa9\
+    \x05\x02\x0e\n\r\n\x05\x05\x06\x02\x01\x01\x12\x04\xa9\x05\x02\t\n\r\n\
+    \x05\x05\x06\x02\x01\x02\x12\x04\xa9\x05\x0c\r\n\x0c\n\x04\x05\x06\x02\
+    \x02\x12\x04\xaa\x05\x02\x0e\n\r\n\x05\x05\x06\x02\x02\x01\x12\x04\xaa\
+    \x05\x02\t\n\r\n\x05\x05\x06\x02\x02\x02\x12\x04\xaa\x05\x0c\r\n\x0c\n\
+    \x04\x05\x06\x02\x03\x12\x04\xab\x05\x02\x0e\n\r\n\x05\x05\x06\x02\x03\
+    \x01\x12\x04\xab\x05\x02\t\n\r\n\x05\x05\x06\x02\x03\x02\x12\x04\xab\x05\
+    \x0c\r\n\x0c\n\x04\x05\x06\x02\x04\x12\x04\xac\x05\x02\r\n\r\n\x05\x05\
+    \x06\x02\x04\x01\x12\x04\xac\x05\x02\x08\n\r\n\x05\x05\x06\x02\x04\x02\
+    \x12\x04\xac\x05\x0b\x0c\n9\n\x02\x04G\x12\x06\xb0\x05\0\xb7\x05\x01\x1a\
+    +\x20A\x20change\x20to\x20a\x20module\x20stored\x20in\x20Modsurfer.\n\n\
+    \x0b\n\x03\x04G\x01\x12\x04\xb0\x05\x08\x13\nY\n\x04\x04G\x02\0\x12\x04\
+    \xb2\x05\x02\x10\x1aK\x20monotonically\x20increasing\x20ID\x20of\x20the\
+    \x20event,\x20used\x20as\x20a\x20cursor\x20by\x20subscribers\n\n\r\n\x05\
+    \x04G\x02\0\x05\x12\x04\xb2\x05\x02\x08\n\r\n\x05\x04G\x02\0\x01\x12\x04\
+    \xb2\x05\t\x0b\n\r\n\x05\x04G\x02\0\x03\x12\x04\xb2\x05\x0e\x0f\n\x0c\n\
+    \x04\x04G\x02\x01\x12\x04\xb3\x05\x02\x1b\n\r\n\x05\x04G\x02\x01\x06\x12\
+    \x04\xb3\x05\x02\x11\n\r\n\x05\x04G\x02\x01\x01\x12\x04\xb3\x05\x12\x16\
+    \n\r\n\x05\x04G\x02\x01\x03\x12\x04\xb3\x05\x19\x1a\n\x0c\n\x04\x04G\x02\
+    \x02\x12\x04\xb4\x05\x02\x16\n\r\n\x05\x04G\x02\x02\x05\x12\x04\xb4\x05\
+    \x02\x07\n\r\n\x05\x04G\x02\x02\x01\x12\x04\xb4\x05\x08\x11\n\r\n\x05\
+    \x04G\x02\x02\x03\x12\x04\xb4\x05\x14\x15\n\x0c\n\x04\x04G\x02\x03\x12\
+    \x04\xb5\x05\x02\x12\n\r\n\x05\x04G\x02\x03\x05\x12\x04\xb5\x05\x02\x08\
+    \n\r\n\x05\x04G\x02\x03\x01\x12\x04\xb5\x05\t\r\n\r\n\x05\x04G\x02\x03\
+    \x03\x12\x04\xb5\x05\x10\x11\n\x0c\n\x04\x04G\x02\x04\x12\x04\xb6\x05\
+    \x02,\n\r\n\x05\x04G\x02\x04\x06\x12\x04\xb6\x05\x02\x1b\n\r\n\x05\x04G\
+    \x02\x04\x01\x12\x04\xb6\x05\x1c'\n\r\n\x05\x04G\x02\x04\x03\x12\x04\xb6\
+    \x05*+\n\x8d\x02\n\x02\x04H\x12\x06\xbd\x05\0\xc6\x05\x01\x1a\xfe\x01\
+    \x20`POST\x20/api/v1/events:`\n\x20Return\x20the\x20module\x20events\x20\
+    which\x20occurred\x20after\x20`after_event_id`\x20and\x20match\x20the\
+    \x20filters.\x20If\x20no\n\x20events\x20are\x20available,\x20the\x20serv\
+    er\x20holds\x20the\x20request\x20open\x20for\x20up\x20to\x20`wait_second\
+    s`\x20(long-poll)\n\x20before\x20returning\x20an\x20empty\x20list\x20of\
+    \x20events.\n\n\x0b\n\x03\x04H\x01\x12\x04\xbd\x05\x08\x18\n\\\n\x04\x04\
+    H\x02\0\x12\x04\xbf\x05\x02%\x1aN\x20when\x20unset,\x20only\x20events\
+    \x20occurring\x20after\x20the\x20request\x20is\x20received\x20are\x20ret\
+    urned\n\n\r\n\x05\x04H\x02\0\x04\x12\x04\xbf\x05\x02\n\n\r\n\x05\x04H\
+    \x02\0\x05\x12\x04\xbf\x05\x0b\x11\n\r\n\x05\x04H\x02\0\x01\x12\x04\xbf\
+    \x05\x12\x20\n\r\n\x05\x04H\x02\0\x03\x12\x04\xbf\x05#$\n\x0c\n\x04\x04H\
+    \x02\x01\x12\x04\xc0\x05\x02\x1a\n\r\n\x05\x04H\x02\x01\x05\x12\x04\xc0\
+    \x05\x02\x08\n\r\n\x05\x04H\x02\x01\x01\x12\x04\xc0\x05\t\x15\n\r\n\x05\
+    \x04H\x02\x01\x03\x12\x04\xc0\x05\x18\x19\nF\n\x04\x04H\x02\x02\x12\x04\
+    \xc2\x05\x02%\x1a8\x20only\x20return\x20events\x20of\x20these\x20kinds\
+    \x20(all\x20kinds\x20if\x20empty)\n\n\r\n\x05\x04H\x02\x02\x04\x12\x04\
+    \xc2\x05\x02\n\n\r\n\x05\x04H\x02\x02\x06\x12\x04\xc2\x05\x0b\x1a\n\r\n\
+    \x05\x04H\x02\x02\x01\x12\x04\xc2\x05\x1b\x20\n\r\n\x05\x04H\x02\x02\x03\
+    \x12\x04\xc2\x05#$\nE\n\x04\x04H\x02\x03\x12\x04\xc4\x05\x02\x1b\x1a7\
+    \x20only\x20return\x20events\x20for\x20modules\x20with\x20all\x20of\x20t\
+    hese\x20tags\n\n\r\n\x05\x04H\x02\x03\x04\x12\x04\xc4\x05\x02\n\n\r\n\
+    \x05\x04H\x02\x03\x05\x12\x04\xc4\x05\x0b\x11\n\r\n\x05\x04H\x02\x03\x01\
+    \x12\x04\xc4\x05\x12\x16\n\r\n\x05\x04H\x02\x03\x03\x12\x04\xc4\x05\x19\
+    \x1a\n\x0c\n\x04\x04H\x02\x04\x12\x04\xc5\x05\x02\x1b\n\r\n\x05\x04H\x02\
+    \x04\x04\x12\x04\xc5\x05\x02\n\n\r\n\x05\x04H\x02\x04\x05\x12\x04\xc5\
+    \x05\x0b\x11\n\r\n\x05\x04H\x02\x04\x01\x12\x04\xc5\x05\x12\x16\n\r\n\
+    \x05\x04H\x02\x04\x03\x12\x04\xc5\x05\x19\x1a\nI\n\x02\x04I\x12\x06\xc9\
+    \x05\0\xcf\x05\x01\x1a;\x20The\x20message\x20returned\x20in\x20response\
+    \x20to\x20a\x20`SubscribeRequest`.\n\n\x0b\n\x03\x04I\x01\x12\x04\xc9\
+    \x05\x08\x19\n\x0c\n\x04\x04I\x02\0\x12\x04\xca\x05\x02\"\n\r\n\x05\x04I\
+    \x02\0\x04\x12\x04\xca\x05\x02\n\n\r\n\x05\x04I\x02\0\x06\x12\x04\xca\
+    \x05\x0b\x16\n\r\n\x05\x04I\x02\0\x01\x12\x04\xca\x05\x17\x1d\n\r\n\x05\
+    \x04I\x02\0\x03\x12\x04\xca\x05\x20!\ns\n\x04\x04I\x02\x01\x12\x04\xcd\
+    \x05\x02\x1b\x1ae\x20the\x20ID\x20of\x20the\x20latest\x20event\x20known\
+    \x20to\x20the\x20server,\x20to\x20be\x20used\x20as\x20`after_event_id`\
+    \x20in\x20the\x20next\n\x20request\n\n\r\n\x05\x04I\x02\x01\x05\x12\x04\
+    \xcd\x05\x02\x08\n\r\n\x05\x04I\x02\x01\x01\x12\x04\xcd\x05\t\x16\n\r\n\
+    \x05\x04I\x02\x01\x03\x12\x04\xcd\x05\x19\x1a\n\x0c\n\x04\x04I\x02\x02\
+    \x12\x04\xce\x05\x02\x1b\n\r\n\x05\x04I\x02\x02\x04\x12\x04\xce\x05\x02\
+    \n\n\r\n\x05\x04I\x02\x02\x06\x12\x04\xce\x05\x0b\x10\n\r\n\x05\x04I\x02\
+    \x02\x01\x12\x04\xce\x05\x11\x16\n\r\n\x05\x04I\x02\x02\x03\x12\x04\xce\
+    \x05\x19\x1ab\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -6621,11 +14530,21 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(32);
+            let mut messages = ::std::vec::Vec::with_capacity(74);
             messages.push(Function::generated_message_descriptor_data());
             messages.push(Import::generated_message_descriptor_data());
             messages.push(Export::generated_message_descriptor_data());
             messages.push(Module::generated_message_descriptor_data());
+            messages.push(IntegrityIssue::generated_message_descriptor_data());
+            messages.push(Quarantine::generated_message_descriptor_data());
+            messages.push(Provenance::generated_message_descriptor_data());
+            messages.push(Library::generated_message_descriptor_data());
+            messages.push(Memory::generated_message_descriptor_data());
+            messages.push(Table::generated_message_descriptor_data());
+            messages.push(Global::generated_message_descriptor_data());
+            messages.push(CustomSection::generated_message_descriptor_data());
+            messages.push(FunctionMetrics::generated_message_descriptor_data());
+            messages.push(Tool::generated_message_descriptor_data());
             messages.push(ModuleGraph::generated_message_descriptor_data());
             messages.push(Error::generated_message_descriptor_data());
             messages.push(Pagination::generated_message_descriptor_data());
@@ -6640,6 +14559,31 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(SearchModulesResponse::generated_message_descriptor_data());
             messages.push(DeleteModulesRequest::generated_message_descriptor_data());
             messages.push(DeleteModulesResponse::generated_message_descriptor_data());
+            messages.push(TagModuleRequest::generated_message_descriptor_data());
+            messages.push(TagModuleResponse::generated_message_descriptor_data());
+            messages.push(QuarantineModuleRequest::generated_message_descriptor_data());
+            messages.push(QuarantineModuleResponse::generated_message_descriptor_data());
+            messages.push(Checkfile::generated_message_descriptor_data());
+            messages.push(Check::generated_message_descriptor_data());
+            messages.push(ValTypes::generated_message_descriptor_data());
+            messages.push(CheckImports::generated_message_descriptor_data());
+            messages.push(CheckImportItems::generated_message_descriptor_data());
+            messages.push(CheckImportItem::generated_message_descriptor_data());
+            messages.push(CheckNamespaces::generated_message_descriptor_data());
+            messages.push(CheckNamespaceItems::generated_message_descriptor_data());
+            messages.push(CheckNamespaceItem::generated_message_descriptor_data());
+            messages.push(CheckExports::generated_message_descriptor_data());
+            messages.push(CheckFunctionItems::generated_message_descriptor_data());
+            messages.push(CheckFunctionItem::generated_message_descriptor_data());
+            messages.push(CheckSize::generated_message_descriptor_data());
+            messages.push(CheckComplexity::generated_message_descriptor_data());
+            messages.push(CheckRisk::generated_message_descriptor_data());
+            messages.push(CheckCapabilities::generated_message_descriptor_data());
+            messages.push(CheckLicenses::generated_message_descriptor_data());
+            messages.push(CheckVulnerabilities::generated_message_descriptor_data());
+            messages.push(CheckIntegrity::generated_message_descriptor_data());
+            messages.push(CheckModuleName::generated_message_descriptor_data());
+            messages.push(CheckLocation::generated_message_descriptor_data());
             messages.push(AuditModulesRequest::generated_message_descriptor_data());
             messages.push(AuditModulesResponse::generated_message_descriptor_data());
             messages.push(DiffRequest::generated_message_descriptor_data());
@@ -6654,12 +14598,21 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(UninstallPluginResponse::generated_message_descriptor_data());
             messages.push(CallPluginRequest::generated_message_descriptor_data());
             messages.push(CallPluginResponse::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(5);
+            messages.push(HealthRequest::generated_message_descriptor_data());
+            messages.push(HealthResponse::generated_message_descriptor_data());
+            messages.push(ServerInfoRequest::generated_message_descriptor_data());
+            messages.push(ServerInfoResponse::generated_message_descriptor_data());
+            messages.push(ModuleEvent::generated_message_descriptor_data());
+            messages.push(SubscribeRequest::generated_message_descriptor_data());
+            messages.push(SubscribeResponse::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(7);
             enums.push(ValType::generated_enum_descriptor_data());
             enums.push(SourceLanguage::generated_enum_descriptor_data());
             enums.push(Direction::generated_enum_descriptor_data());
             enums.push(Field::generated_enum_descriptor_data());
+            enums.push(RiskLevel::generated_enum_descriptor_data());
             enums.push(AuditOutcome::generated_enum_descriptor_data());
+            enums.push(ModuleEventKind::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
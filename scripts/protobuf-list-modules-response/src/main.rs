@@ -6,8 +6,8 @@ use modsurfer_convert::{
     api::{ListModulesResponse, Module as ProtoModule},
     to_api,
 };
-use modsurfer_module::Module;
-use modsurfer::ModuleParser;
+use modsurfer_module::ModuleParser;
+use modsurfer_validation::Module as Parser;
 use protobuf::Message;
 
 #[tokio::main]
@@ -40,7 +40,7 @@ async fn main() -> Result<()> {
 
     for file in files.iter().take(take as usize) {
         println!("reading: {:?}", file.path());
-        let m = Module::new_from_file(file.path()).await?;
+        let m = Parser {}.parse_file(&file.path())?;
         modules.push(m);
     }
 
@@ -0,0 +1,72 @@
+//! JavaScript bindings to the validation core, built for `wasm32-unknown-unknown` with
+//! wasm-bindgen, so that modules can be checked in the browser before they are uploaded. Modules
+//! are passed as `Uint8Array`s, and reports and modules are returned as JSON strings, in the same
+//! formats as the `modsurfer` CLI.
+use modsurfer_module::ModuleParser;
+use modsurfer_validation::{Diff, Validation, Verdict};
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+mod parser;
+
+pub use parser::BrowserParser;
+
+/// Parse a module, returning the facts collected about it as JSON (the format written by
+/// `modsurfer snapshot`).
+#[wasm_bindgen]
+pub fn parse(wasm: &[u8]) -> Result<String, JsError> {
+    let module = BrowserParser.parse_bytes(wasm).map_err(js_error)?;
+    Ok(serde_json::to_string(&module)?)
+}
+
+/// Validate a module against the YAML contents of a checkfile, returning
+/// `{ "passed": bool, "report": { "fails": { ... } } }` as JSON.
+///
+/// Checkfiles with a `url` or a `vulnerabilities` check are rejected, as these need network access
+/// which the browser doesn't allow, and should be validated by the server.
+#[wasm_bindgen]
+pub fn validate(wasm: &[u8], checkfile: &str) -> Result<String, JsError> {
+    let validation: Validation = serde_yaml::from_str(checkfile)?;
+    if validation.validate.url.is_some() {
+        return Err(JsError::new(
+            "remote checkfiles (`url`) can't be fetched in the browser",
+        ));
+    }
+    if validation.validate.vulnerabilities.is_some() {
+        return Err(JsError::new(
+            "`vulnerabilities` can't be checked in the browser",
+        ));
+    }
+
+    let module = BrowserParser.parse_bytes(wasm).map_err(js_error)?;
+    let report = modsurfer_validation::validate(validation, module).map_err(js_error)?;
+    let passed = Verdict::new(&report).passed();
+
+    Ok(serde_json::to_string(
+        &json!({ "passed": passed, "report": report }),
+    )?)
+}
+
+/// Generate a checkfile which the module passes, as YAML (like `modsurfer generate`).
+#[wasm_bindgen]
+pub fn generate_checkfile(wasm: &[u8]) -> Result<String, JsError> {
+    let module = BrowserParser.parse_bytes(wasm).map_err(js_error)?;
+    let validation = modsurfer_validation::generate_checkfile(&module).map_err(js_error)?;
+    validation.to_yaml().map_err(js_error)
+}
+
+/// Compare two modules, returning the differences between them as text (the output of
+/// `modsurfer diff`, without colors). With `with_context`, unchanged lines around each difference
+/// are included.
+#[wasm_bindgen]
+pub fn diff(wasm1: &[u8], wasm2: &[u8], with_context: bool) -> Result<String, JsError> {
+    let module1 = BrowserParser.parse_bytes(wasm1).map_err(js_error)?;
+    let module2 = BrowserParser.parse_bytes(wasm2).map_err(js_error)?;
+    let diff = Diff::new(&module1, &module2, false, with_context).map_err(js_error)?;
+    Ok(diff.to_string())
+}
+
+// `JsError` can't be built from an `anyhow::Error` with `?`, which isn't a `std::error::Error`
+fn js_error(e: anyhow::Error) -> JsError {
+    JsError::new(&format!("{:#}", e))
+}
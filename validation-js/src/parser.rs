@@ -0,0 +1,139 @@
+//! A parser which runs in the browser. The parser used by `modsurfer-validation` is a WebAssembly
+//! plugin run by Extism, which isn't available on `wasm32-unknown-unknown`, so this reads the
+//! facts it can directly from the binary with `wasmparser`.
+//!
+//! Complexity and the call graph are not computed, so a checkfile with a `complexity` check fails
+//! to validate rather than passing without it.
+use anyhow::Result;
+use modsurfer_module::{
+    custom_section_strings, function_hashes, integrity_issues, Export, Function, FunctionHashes,
+    FunctionType, Import, Module, ModuleFacts, ModuleParser, Provenance,
+};
+use sha2::{Digest, Sha256};
+use wasmparser::{Encoding, ExternalKind, Parser, Payload, ProducersSectionReader, Type, TypeRef};
+
+// the shortest run of printable bytes in a data segment kept as a string
+const MIN_STRING_LEN: usize = 8;
+
+/// Parses modules with `wasmparser`, without running the parser plugin.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BrowserParser;
+
+impl ModuleParser for BrowserParser {
+    fn parse_bytes(&self, wasm: &[u8]) -> Result<Module> {
+        let mut module = Module {
+            hash: format!("{:x}", Sha256::digest(wasm)),
+            size: wasm.len() as u64,
+            ..Default::default()
+        };
+
+        let mut types = vec![];
+        // the type of each function, imported functions first
+        let mut funcs = vec![];
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload? {
+                Payload::Version {
+                    encoding: Encoding::Component,
+                    ..
+                } => anyhow::bail!("components are not supported in the browser yet"),
+                Payload::TypeSection(reader) => {
+                    for ty in reader {
+                        let Type::Func(ty) = ty? else {
+                            anyhow::bail!("types other than function types are not supported")
+                        };
+                        types.push(FunctionType::from(&ty));
+                    }
+                }
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import?;
+                        if let TypeRef::Func(ty) = import.ty {
+                            let ty = func_type(&types, ty)?;
+                            funcs.push(ty.clone());
+                            module.imports.push(Import {
+                                module_name: import.module.to_string(),
+                                func: Function {
+                                    name: import.name.to_string(),
+                                    ty,
+                                },
+                            });
+                        }
+                    }
+                }
+                Payload::FunctionSection(reader) => {
+                    for ty in reader {
+                        funcs.push(func_type(&types, ty?)?);
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export?;
+                        if export.kind != ExternalKind::Func {
+                            continue;
+                        }
+                        let ty = funcs.get(export.index as usize).cloned().ok_or_else(|| {
+                            anyhow::anyhow!("export `{}` has no function", export.name)
+                        })?;
+                        module.exports.push(Export {
+                            func: Function {
+                                name: export.name.to_string(),
+                                ty,
+                            },
+                        });
+                    }
+                }
+                Payload::DataSection(reader) => {
+                    for data in reader {
+                        module.strings.extend(
+                            data?
+                                .data
+                                .split(|b| !(b.is_ascii_graphic() || *b == b' '))
+                                .filter(|run| run.len() >= MIN_STRING_LEN)
+                                .map(|run| String::from_utf8_lossy(run).into_owned()),
+                        );
+                    }
+                }
+                Payload::CustomSection(section) if section.name() == "producers" => {
+                    // a malformed producers section only costs us the source language
+                    let _ = read_language(&mut module, section.data(), section.data_offset());
+                }
+                _ => {}
+            }
+        }
+
+        ModuleFacts::read(wasm)?.fill(&mut module);
+        module.function_hashes = function_hashes(wasm, FunctionHashes::All)?;
+        module.integrity = integrity_issues(wasm);
+        let custom_strings = custom_section_strings(wasm)?;
+        module.provenance = Provenance::scan(
+            module
+                .strings
+                .iter()
+                .chain(custom_strings.iter())
+                .map(String::as_str),
+        );
+
+        Ok(module)
+    }
+}
+
+fn func_type(types: &[FunctionType], index: u32) -> Result<FunctionType> {
+    types
+        .get(index as usize)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("function type {} is out of bounds", index))
+}
+
+fn read_language(module: &mut Module, data: &[u8], offset: usize) -> Result<()> {
+    for field in ProducersSectionReader::new(data, offset)? {
+        let field = field?;
+        if field.name != "language" {
+            continue;
+        }
+        if let Some(value) = field.values.into_iter().next() {
+            module.source_language = value?.name.to_string().into();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use modsurfer_module::{Export, Function, FunctionType, Import, Module, ValType};
+
+/// The number of imports (and of exports) in the modules benchmarked, up to the scale of
+/// wasm-bindgen output.
+pub const FUNCTIONS: &[usize] = &[100, 1_000, 10_000];
+
+/// A module with `functions` imports and as many exports, each named with `prefix`.
+pub fn module(functions: usize, prefix: &str) -> Module {
+    let ty = FunctionType {
+        params: vec![ValType::I32, ValType::I32],
+        results: vec![ValType::I32],
+    };
+
+    let mut builder = Module::builder().size(functions as u64 * 128).complexity(1);
+    for i in 0..functions {
+        let export = format!("{prefix}_export_{i}");
+        builder = builder
+            .import(Import {
+                module_name: "__wbindgen_placeholder__".into(),
+                func: Function {
+                    name: format!("__wbg_{prefix}_{i}"),
+                    ty: ty.clone(),
+                },
+            })
+            .function_hash(&export, format!("{:064x}", i))
+            .export(Export {
+                func: Function {
+                    name: export,
+                    ty: ty.clone(),
+                },
+            });
+    }
+
+    builder.build()
+}
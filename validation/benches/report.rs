@@ -0,0 +1,61 @@
+//! Validation of a module which passes every check, counting the allocations it makes. Failures
+//! are the only part of a report which needs formatted strings, so a clean validation should
+//! allocate about the same whether the module has a hundred imports and exports or ten thousand.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use modsurfer_validation::{generate_checkfile, validate};
+
+mod common;
+
+use common::{module, FUNCTIONS};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn clean(c: &mut Criterion) {
+    let mut group = c.benchmark_group("report/clean");
+    for &functions in FUNCTIONS {
+        let module = module(functions, "app");
+        let validation = generate_checkfile(&module).unwrap();
+
+        let (v, m) = (validation.clone(), module.clone());
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let report = validate(v, m).unwrap();
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+        assert!(!report.has_failures());
+        eprintln!("report/clean/{functions}: {allocations} allocations per validation");
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(functions),
+            &(validation, module),
+            |b, (validation, module)| {
+                b.iter_batched(
+                    || (validation.clone(), module.clone()),
+                    |(validation, module)| validate(validation, module).unwrap(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, clean);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+//! Validation of modules with many imports and exports, like those produced by wasm-bindgen,
+//! against a checkfile listing each of them (as `modsurfer generate` writes), and against one
+//! which every import and export fails.
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use modsurfer_validation::{generate_checkfile, validate, Validation};
+
+mod common;
+
+use common::{module, FUNCTIONS};
+
+fn bench_validate(c: &mut Criterion, name: &str, checkfile: impl Fn(usize) -> Validation) {
+    let mut group = c.benchmark_group(name);
+    for &functions in FUNCTIONS {
+        let input = (checkfile(functions), module(functions, "app"));
+        group.throughput(Throughput::Elements(2 * functions as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(functions),
+            &input,
+            |b, (validation, module)| {
+                b.iter_batched(
+                    || (validation.clone(), module.clone()),
+                    |(validation, module)| validate(validation, module).unwrap(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn passing(c: &mut Criterion) {
+    bench_validate(c, "validate/passing", |functions| {
+        generate_checkfile(&module(functions, "app")).unwrap()
+    });
+}
+
+fn failing(c: &mut Criterion) {
+    bench_validate(c, "validate/failing", |functions| {
+        generate_checkfile(&module(functions, "other")).unwrap()
+    });
+}
+
+criterion_group!(benches, passing, failing);
+criterion_main!(benches);
@@ -0,0 +1,154 @@
+//! Concrete suggestions for shrinking a module which fails a size check, with the bytes each would
+//! save estimated from where the module's bytes go (see `Bloat`).
+use modsurfer_module::{Bloat, BloatKind, Module};
+use serde::Serialize;
+
+use crate::Report;
+
+// strings which Rust embeds for panic messages
+const PANIC_PATTERNS: &[&str] = &[
+    "panicked at",
+    "called `Option::unwrap()` on a `None` value",
+    "called `Result::unwrap()` on an `Err` value",
+    "index out of bounds: the len is",
+    "attempt to add with overflow",
+    "attempt to subtract with overflow",
+    "attempt to multiply with overflow",
+    "assertion failed: ",
+];
+
+#[derive(Debug, Serialize)]
+pub struct Suggestion {
+    pub action: String,
+    /// a command which makes the change, if one exists
+    pub command: Option<String>,
+    /// the estimated bytes saved, where they can be known without rebuilding the module
+    pub savings: Option<u64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Advice {
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Advice {
+    /// Whether the report has failures which shrinking the module would fix.
+    pub fn applies(report: &Report) -> bool {
+        report
+            .fails
+            .keys()
+            .any(|property| property.starts_with("size."))
+    }
+
+    /// Suggest ways to shrink the module, largest savings first.
+    pub fn new(module: &Module, bloat: &Bloat) -> Advice {
+        let mut suggestions = vec![];
+        let custom = |matches: &dyn Fn(&str) -> bool| {
+            bloat
+                .items
+                .iter()
+                .filter(|item| item.kind == BloatKind::CustomSection && matches(&item.name))
+                .map(|item| item.size)
+                .sum::<u64>()
+        };
+
+        let dwarf = custom(&|name| name.starts_with(".debug"));
+        if dwarf > 0 {
+            suggestions.push(Suggestion {
+                action: "strip DWARF debug info".into(),
+                command: Some("wasm-opt --strip-dwarf".into()),
+                savings: Some(dwarf),
+            });
+        }
+
+        let names = custom(&|name| name == "name");
+        if names > 0 {
+            suggestions.push(Suggestion {
+                action: "strip the name section".into(),
+                command: Some("wasm-opt --strip-debug".into()),
+                savings: Some(names),
+            });
+        }
+
+        let producers = custom(&|name| name == "producers");
+        if producers > 0 {
+            suggestions.push(Suggestion {
+                action: "strip the producers section".into(),
+                command: Some("wasm-opt --strip-producers".into()),
+                savings: Some(producers),
+            });
+        }
+
+        let unreachable = bloat.unreachable().count();
+        if unreachable > 0 {
+            suggestions.push(Suggestion {
+                action: format!("remove {} unreachable function(s)", unreachable),
+                command: Some("wasm-opt --remove-unused-module-elements".into()),
+                savings: Some(bloat.unreachable_size),
+            });
+        }
+
+        let panics = module
+            .strings
+            .iter()
+            .filter(|s| is_panic_string(s))
+            .collect::<Vec<_>>();
+        if !panics.is_empty() {
+            suggestions.push(Suggestion {
+                action: format!(
+                    "remove {} panic message(s) and source locations by aborting on panic",
+                    panics.len()
+                ),
+                command: Some("cargo +nightly build -Z build-std=std,panic_abort -Z build-std-features=panic_immediate_abort".into()),
+                savings: Some(panics.iter().map(|s| s.len() as u64).sum()),
+            });
+        }
+
+        suggestions.sort_by_key(|s| std::cmp::Reverse(s.savings));
+
+        // the savings of optimizing can't be estimated without running the optimizer. Whether the
+        // module was optimized is only known from its producers section, which is often stripped
+        // and may not have been read, so nothing is suggested without it
+        if !module.toolchain.is_empty()
+            && !module.toolchain.iter().any(|tool| tool.name == "wasm-opt")
+        {
+            suggestions.push(Suggestion {
+                action: "optimize for size (the module wasn't processed by wasm-opt)".into(),
+                command: Some("wasm-opt -Oz".into()),
+                savings: None,
+            });
+        }
+
+        Advice { suggestions }
+    }
+}
+
+// a panic message, or the path of a Rust source file embedded for the location of a panic, e.g.
+// `src/lib.rs` or `/rustc/<commit>/library/core/src/str/mod.rs`
+fn is_panic_string(s: &str) -> bool {
+    let source_path = s.ends_with(".rs") && s.contains('/') && !s.contains(char::is_whitespace);
+    source_path || PANIC_PATTERNS.iter().any(|p| s.contains(p))
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl std::fmt::Display for Advice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.suggestions.is_empty() {
+            return f.write_str("No suggestions to reduce the module's size.");
+        }
+
+        let mut table = crate::new_table(vec!["Suggestion", "Command", "Saves"]);
+        self.suggestions.iter().for_each(|s| {
+            table.add_row(vec![
+                s.action.clone(),
+                s.command.clone().unwrap_or_default(),
+                s.savings
+                    .map(|bytes| human_bytes::human_bytes(bytes as f64))
+                    .unwrap_or_else(|| "unknown".into()),
+            ]);
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
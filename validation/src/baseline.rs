@@ -0,0 +1,52 @@
+//! Known failures to ignore when validating, so that a module which will never be fully clean can
+//! still be held to its current state, reporting only new findings.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Report;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Baseline {
+    /// k/v pair of the dot-separated path to each known failure and what was found there
+    pub fails: BTreeMap<String, KnownFailure>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KnownFailure {
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Baseline {
+    /// A baseline which accepts every failure in the report.
+    pub fn new(report: &Report) -> Self {
+        Baseline {
+            fails: report
+                .fails
+                .iter()
+                .map(|(property, detail)| {
+                    (
+                        property.clone(),
+                        KnownFailure {
+                            expected: detail.expected.clone(),
+                            actual: detail.actual.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Remove the failures recorded in this baseline from the report, returning how many were
+    /// removed. A failure is known if its property is in the baseline, even if the value found
+    /// there has changed since, e.g. a module which was already too large has grown.
+    pub fn suppress(&self, report: &mut Report) -> usize {
+        let before = report.fails.len();
+        report
+            .fails
+            .retain(|property, _| !self.fails.contains_key(property));
+
+        before - report.fails.len()
+    }
+}
@@ -0,0 +1,152 @@
+//! Validation within a memory budget, for CI runners too small to parse the largest modules they
+//! check without being killed. The parser plugin may only grow to what the budget leaves once the
+//! module is read, and is dropped after parsing rather than kept for reuse. Facts which would
+//! take the process over the budget aren't collected, and the checks needing them are skipped
+//! (see `Skipped`) rather than passing on missing data.
+//!
+//! The module and the facts collected about it are still held in memory whole: nothing is parsed
+//! as a stream or spilled to disk, so a module too large for the budget fails up front.
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use human_bytes::human_bytes;
+use parse_size::parse_size;
+use serde::Serialize;
+
+use crate::timings::resident_memory;
+use crate::{
+    resolve_checkfile, validate_parsed, FunctionHashes, ParseOptions, Parser, Report, Validation,
+};
+
+/// The most memory the process may use to parse and validate a module, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBudget(u64);
+
+impl MemoryBudget {
+    pub fn new(bytes: u64) -> Self {
+        MemoryBudget(bytes)
+    }
+
+    /// Parse a human-readable budget, e.g. `512MB` or `1GiB`.
+    pub fn parse(s: &str) -> Result<Self> {
+        parse_size(s)
+            .map(MemoryBudget)
+            .map_err(|e| anyhow::anyhow!("invalid memory budget `{}`: {}", s, e))
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for MemoryBudget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&human_bytes(self.0 as f64))
+    }
+}
+
+/// A fact about a module which was not collected to stay within a `MemoryBudget`, and the checks
+/// of the checkfile which did not run without it.
+#[derive(Debug, Serialize)]
+pub struct Skipped {
+    pub fact: &'static str,
+    pub checks: Vec<&'static str>,
+}
+
+impl Display for Skipped {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Skipped extracting {} to stay within the memory budget, so these checks did not run: {}",
+            self.fact,
+            self.checks.join(", ")
+        )
+    }
+}
+
+/// Validate a module like `validate_module_with`, using at most `budget` bytes of memory, or fail
+/// before parsing if the module cannot fit. Returns the facts skipped to stay within the budget.
+pub async fn validate_module_within(
+    file: &PathBuf,
+    check: &PathBuf,
+    overrides: &[(String, String)],
+    budget: MemoryBudget,
+) -> Result<(Report, Vec<Skipped>)> {
+    let (mut validation, _) = resolve_checkfile(check, overrides).await?;
+
+    // the module is held by the process while the plugin parses its own copy
+    let size = tokio::fs::metadata(file).await?.len();
+    let used = resident_memory().unwrap_or_default();
+    let available = budget.bytes().saturating_sub(used);
+    if size.saturating_mul(2) > available {
+        anyhow::bail!(
+            "{} is {}, so parsing it needs at least {}, but only {} of the {} memory budget is left",
+            file.display(),
+            human_bytes(size as f64),
+            human_bytes(size.saturating_mul(2) as f64),
+            human_bytes(available as f64),
+            budget
+        );
+    }
+
+    let mut options =
+        ParseOptions::for_checkfile(&validation).intersect(Parser::shared().options());
+    let mut skipped = vec![];
+    // scanning strings copies up to the module's size again
+    if options.extract_strings && size.saturating_mul(3) > available {
+        options.extract_strings = false;
+        skipped.push(Skipped {
+            fact: "strings",
+            checks: skip_string_checks(&mut validation),
+        });
+    }
+
+    // a lower limit set on the shared parser still applies
+    let limits = Parser::shared().limits();
+    let memory_max = limits
+        .memory_max
+        .map_or(available - size, |max| max.min(available - size));
+    let parser = Parser::with_max_idle(0).memory_max(memory_max);
+    parser.set_timeout(limits.timeout);
+    let module = crate::plugin::in_module(
+        parser.parse_with_options(
+            tokio::fs::read(file).await?,
+            FunctionHashes::Exports,
+            options,
+        ),
+        file,
+    )
+    .with_context(|| {
+        format!(
+            "failed to parse {} within the {} memory budget",
+            file.display(),
+            budget
+        )
+    })?;
+
+    Ok((validate_parsed(validation, module).await?, skipped))
+}
+
+// remove the checks which need the module's strings, returning their names
+fn skip_string_checks(validation: &mut Validation) -> Vec<&'static str> {
+    let mut checks = vec![];
+    if validation.validate.licenses.take().is_some() {
+        checks.push("licenses");
+    }
+    if validation.validate.vulnerabilities.take().is_some() {
+        checks.push("vulnerabilities");
+    }
+    // the risk score counts suspicious strings
+    if let Some(risk) = validation.validate.risk.as_mut() {
+        if risk.max_score.take().is_some() {
+            checks.push("risk.max_score");
+        }
+    }
+    // plugins may read the module's strings
+    if validation.validate.plugins.take().is_some() {
+        checks.push("plugins");
+    }
+
+    checks
+}
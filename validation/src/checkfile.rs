@@ -0,0 +1,360 @@
+use anyhow::Result;
+use human_bytes::human_bytes;
+use modsurfer_module::{Capability, RiskLevel};
+
+use crate::{
+    Capabilities, Complexity, Exports, FunctionItem, Hooks, ImportItem, Imports, IncludeMode,
+    Integrity, Licenses, Location, ModuleName, Namespace, NamespaceItem, ReportHook, Risk,
+    Severity, Size, SizeLimit, Validation, ValidatorPlugin, Vulnerabilities,
+};
+
+/// Construct a checkfile programmatically, e.g. to generate policies from a service catalog,
+/// without assembling the nested `Validation` structs by hand. Any check which is not set is left
+/// out of the checkfile.
+///
+/// ```
+/// # use modsurfer_validation::Checkfile;
+/// let yaml = Checkfile::new()
+///     .allow_wasi(false)
+///     .import_namespace_exclude("env")
+///     .size_max("4MB")
+///     .to_yaml()?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Checkfile {
+    validation: Validation,
+}
+
+impl Checkfile {
+    pub fn new() -> Self {
+        Checkfile::default()
+    }
+
+    /// Use the checkfile at `url` in place of any checks set on this builder.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.validation.validate.url = Some(url.into());
+        self
+    }
+
+    /// Require the checkfile fetched from `url` to have this hex-encoded SHA-256 hash.
+    pub fn url_sha256(mut self, sha256: impl Into<String>) -> Self {
+        self.validation.validate.url_sha256 = Some(sha256.into());
+        self
+    }
+
+    pub fn allow_wasi(mut self, allow: bool) -> Self {
+        self.validation.validate.allow_wasi = Some(allow);
+        self
+    }
+
+    /// Add a function to `imports.include`, given by name or as an `ImportItem`.
+    pub fn import_include(mut self, import: impl Into<ImportItem>) -> Self {
+        push(&mut self.imports().include, import.into());
+        self
+    }
+
+    /// Set whether the functions given to `import_include` are the only imports allowed (the
+    /// default), imports which are required, or both.
+    pub fn import_include_mode(mut self, mode: IncludeMode) -> Self {
+        self.imports().mode = Some(mode);
+        self
+    }
+
+    /// Limit the number of namespaces the module imports from.
+    pub fn import_max_namespaces(mut self, max: u32) -> Self {
+        self.imports().max_namespaces = Some(max);
+        self
+    }
+
+    /// Limit the number of functions the module imports from any one namespace.
+    pub fn import_max_per_namespace(mut self, max: u32) -> Self {
+        self.imports().max_per_namespace = Some(max);
+        self
+    }
+
+    /// Require the module not to import a function, given by name or as an `ImportItem`.
+    pub fn import_exclude(mut self, import: impl Into<ImportItem>) -> Self {
+        push(&mut self.imports().exclude, import.into());
+        self
+    }
+
+    /// Require the module to import from a namespace, given by name or as a `NamespaceItem`.
+    pub fn import_namespace_include(mut self, namespace: impl Into<NamespaceItem>) -> Self {
+        push(&mut self.namespaces().include, namespace.into());
+        self
+    }
+
+    /// Require the module not to import from a namespace, given by name or as a `NamespaceItem`.
+    pub fn import_namespace_exclude(mut self, namespace: impl Into<NamespaceItem>) -> Self {
+        push(&mut self.namespaces().exclude, namespace.into());
+        self
+    }
+
+    /// Require the module to export a function, given by name or as a `FunctionItem`.
+    pub fn export_include(mut self, export: impl Into<FunctionItem>) -> Self {
+        push(&mut self.exports().include, export.into());
+        self
+    }
+
+    /// Require the module not to export a function, given by name or as a `FunctionItem`.
+    pub fn export_exclude(mut self, export: impl Into<FunctionItem>) -> Self {
+        push(&mut self.exports().exclude, export.into());
+        self
+    }
+
+    pub fn export_max(mut self, max: u32) -> Self {
+        self.exports().max = Some(max);
+        self
+    }
+
+    /// Limit the size of the module, given in bytes or in human-readable form, e.g. `4MB` or
+    /// `512KiB`.
+    pub fn size_max(mut self, max: impl Into<SizeLimit>) -> Self {
+        self.validation.validate.size = Some(Size {
+            max: Some(max.into()),
+        });
+        self
+    }
+
+    pub fn complexity_max_risk(mut self, risk: RiskLevel) -> Self {
+        self.validation.validate.complexity = Some(Complexity {
+            max_risk: Some(risk),
+            max_score: None,
+        });
+        self
+    }
+
+    /// Limit the composite risk score of the module, from 0 to 100.
+    pub fn risk_max_score(mut self, max: u32) -> Self {
+        self.validation.validate.risk = Some(Risk {
+            max_score: Some(max),
+        });
+        self
+    }
+
+    pub fn deny_capability(mut self, capability: Capability) -> Self {
+        let capabilities = self
+            .validation
+            .validate
+            .capabilities
+            .get_or_insert_with(Capabilities::default);
+        push(&mut capabilities.deny, capability);
+        self
+    }
+
+    /// Deny a license by its SPDX identifier, e.g. `GPL-3.0`.
+    pub fn deny_license(mut self, license: impl Into<String>) -> Self {
+        let licenses = self
+            .validation
+            .validate
+            .licenses
+            .get_or_insert_with(Licenses::default);
+        push(&mut licenses.deny, license.into());
+        self
+    }
+
+    pub fn vulnerabilities_max_severity(mut self, severity: Severity) -> Self {
+        self.validation
+            .validate
+            .vulnerabilities
+            .get_or_insert_with(Vulnerabilities::default)
+            .max_severity = Some(severity);
+        self
+    }
+
+    /// Query this advisory database for vulnerabilities: `osv` or a path to a local OSV database.
+    pub fn vulnerabilities_db(mut self, db: impl Into<String>) -> Self {
+        self.validation
+            .validate
+            .vulnerabilities
+            .get_or_insert_with(Vulnerabilities::default)
+            .db = Some(db.into());
+        self
+    }
+
+    /// Set whether the module may import the same function more than once with different
+    /// signatures.
+    pub fn allow_duplicate_imports(mut self, allow: bool) -> Self {
+        self.validation
+            .validate
+            .integrity
+            .get_or_insert_with(Integrity::default)
+            .allow_duplicate_imports = Some(allow);
+        self
+    }
+
+    /// Set whether the module may export more than one function with the same name.
+    pub fn allow_duplicate_exports(mut self, allow: bool) -> Self {
+        self.validation
+            .validate
+            .integrity
+            .get_or_insert_with(Integrity::default)
+            .allow_duplicate_exports = Some(allow);
+        self
+    }
+
+    /// Set whether the module may have structural problems, which are checked unless allowed.
+    pub fn allow_malformed(mut self, allow: bool) -> Self {
+        self.validation
+            .validate
+            .integrity
+            .get_or_insert_with(Integrity::default)
+            .allow_malformed = Some(allow);
+        self
+    }
+
+    /// Require the file name of the module's location to match a regular expression.
+    pub fn module_name_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.validation
+            .validate
+            .module_name
+            .get_or_insert_with(ModuleName::default)
+            .pattern = Some(pattern.into());
+        self
+    }
+
+    /// Allow a URL scheme for the module's location, e.g. `s3`.
+    pub fn allow_location_scheme(mut self, scheme: impl Into<String>) -> Self {
+        let location = self
+            .validation
+            .validate
+            .location
+            .get_or_insert_with(Location::default);
+        push(&mut location.allowed_schemes, scheme.into());
+        self
+    }
+
+    /// Run the validator plugin at `path` against the module.
+    pub fn validator_plugin(mut self, path: impl Into<String>) -> Self {
+        self.validation
+            .validate
+            .plugins
+            .get_or_insert_with(Vec::new)
+            .push(ValidatorPlugin {
+                path: path.into(),
+                ..Default::default()
+            });
+        self
+    }
+
+    /// Run the report hook at `path` once the module is validated.
+    pub fn post_report_hook(mut self, path: impl Into<String>) -> Self {
+        self.validation
+            .validate
+            .hooks
+            .get_or_insert_with(Hooks::default)
+            .post_report
+            .get_or_insert_with(Vec::new)
+            .push(ReportHook {
+                path: path.into(),
+                ..Default::default()
+            });
+        self
+    }
+
+    pub fn build(self) -> Validation {
+        self.validation
+    }
+
+    /// Write the checkfile as YAML, to be passed to `modsurfer validate -c`.
+    pub fn to_yaml(&self) -> Result<String> {
+        self.validation.to_yaml()
+    }
+
+    fn imports(&mut self) -> &mut Imports {
+        self.validation
+            .validate
+            .imports
+            .get_or_insert_with(Imports::default)
+    }
+
+    fn namespaces(&mut self) -> &mut Namespace {
+        self.imports()
+            .namespace
+            .get_or_insert_with(Namespace::default)
+    }
+
+    fn exports(&mut self) -> &mut Exports {
+        self.validation
+            .validate
+            .exports
+            .get_or_insert_with(Exports::default)
+    }
+}
+
+impl Validation {
+    /// Write the checkfile as YAML. A size given in bytes is followed by a comment with the size
+    /// in human-readable form, e.g. `max: 311091 # 303.8 KiB`.
+    pub fn to_yaml(&self) -> Result<String> {
+        let yaml = serde_yaml::to_string(self)?;
+        let mut out = String::with_capacity(yaml.len());
+        let mut section = "";
+        for line in yaml.lines() {
+            // the sections under `validate` are indented by two spaces
+            if let Some(key) = line.strip_prefix("  ").filter(|l| !l.starts_with(' ')) {
+                section = key;
+            }
+            out.push_str(line);
+            if section == "size:" {
+                if let Some(bytes) = line
+                    .trim_start()
+                    .strip_prefix("max: ")
+                    .and_then(|max| max.parse::<u64>().ok())
+                {
+                    out.push_str(" # ");
+                    out.push_str(&human_bytes(bytes as f64));
+                }
+            }
+            out.push('\n');
+        }
+
+        Ok(out)
+    }
+}
+
+impl From<Checkfile> for Validation {
+    fn from(checkfile: Checkfile) -> Self {
+        checkfile.build()
+    }
+}
+
+impl From<&str> for ImportItem {
+    fn from(name: &str) -> Self {
+        ImportItem::Name(name.to_string())
+    }
+}
+
+impl From<String> for ImportItem {
+    fn from(name: String) -> Self {
+        ImportItem::Name(name)
+    }
+}
+
+impl From<&str> for FunctionItem {
+    fn from(name: &str) -> Self {
+        FunctionItem::Name(name.to_string())
+    }
+}
+
+impl From<String> for FunctionItem {
+    fn from(name: String) -> Self {
+        FunctionItem::Name(name)
+    }
+}
+
+impl From<&str> for NamespaceItem {
+    fn from(name: &str) -> Self {
+        NamespaceItem::Name(name.to_string())
+    }
+}
+
+impl From<String> for NamespaceItem {
+    fn from(name: String) -> Self {
+        NamespaceItem::Name(name)
+    }
+}
+
+fn push<T>(items: &mut Option<Vec<T>>, item: T) {
+    items.get_or_insert_with(Vec::new).push(item);
+}
@@ -0,0 +1,861 @@
+//! The built-in rules, one for each section of a checkfile.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use human_bytes::human_bytes;
+use modsurfer_module::{Module, RiskLevel};
+use rayon::prelude::*;
+
+use crate::index::{module_exports, ImportIndex, ModuleImports};
+use crate::registry::{Finding, Rule, RuleConfig};
+use crate::{namespace_prefix, Classification, ComplexityKind, Exist, Report, SizeLimit};
+
+pub(crate) fn builtin() -> Vec<Arc<dyn Rule>> {
+    vec![
+        Arc::new(AllowWasi),
+        Arc::new(Imports),
+        Arc::new(Exports),
+        Arc::new(Size),
+        Arc::new(Complexity),
+        Arc::new(Risk),
+        Arc::new(Capabilities),
+        Arc::new(Licenses),
+        Arc::new(Integrity),
+        Arc::new(ModuleName),
+        Arc::new(Location),
+        // plugins only run on this machine, see `validator`
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        Arc::new(crate::validator::Plugins),
+    ]
+}
+
+struct AllowWasi;
+
+impl Rule for AllowWasi {
+    fn name(&self) -> &'static str {
+        "allow_wasi"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::AbiCompatibilty
+    }
+
+    fn default_severity(&self) -> usize {
+        10
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config.check.allow_wasi.is_some()
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        if let Some(allowed) = config.check.allow_wasi {
+            let actual = module
+                .get_import_namespaces()
+                .contains(&"wasi_snapshot_preview1");
+            if !allowed && actual {
+                findings.push(Finding::new(
+                    self,
+                    "allow_wasi",
+                    allowed.to_string(),
+                    actual.to_string(),
+                ));
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Imports;
+
+impl Rule for Imports {
+    fn name(&self) -> &'static str {
+        "imports"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::AbiCompatibilty
+    }
+
+    fn default_severity(&self) -> usize {
+        10
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config.check.imports.is_some()
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut report = Report::new();
+        let Some(imports) = &config.check.imports else {
+            return Ok(vec![]);
+        };
+        let index = ModuleImports::new(module);
+
+        let mode = imports.mode.unwrap_or_default();
+
+        // expect that all actual imports parsed from the module are within a subset of the import
+        // functions listed in the checkfile
+        if let Some(include) = imports.include.as_ref().filter(|_| mode.allows()) {
+            let include = ImportIndex::new(include);
+            check_each(&mut report, &module.imports, |report, actual| {
+                let namespace = actual.module_name.as_str();
+                let name = actual.func.name.as_str();
+
+                // check that we have at minimum a match for name and namespace, use this module
+                // to further check the params and results
+                match include.get(namespace, name) {
+                    None => report.validate_fn(
+                        format_args!("imports.include.{namespace}::{name}"),
+                        Exist(false),
+                        Exist(true),
+                        false,
+                        10,
+                        Classification::AbiCompatibilty,
+                    ),
+                    // if an import _is_ contained in the checkfile, also validate that the
+                    // function type is equivalent to the expected type in the checkfile
+                    Some(checkfile_import) => report.validate_fn_type(
+                        format_args!("imports.include.{namespace}::{name}"),
+                        &actual.func.ty,
+                        checkfile_import.params(),
+                        checkfile_import.results(),
+                    ),
+                }
+            });
+        }
+
+        // expect that every import function listed in the checkfile is imported by the module
+        if let Some(include) = imports.include.as_ref().filter(|_| mode.requires()) {
+            check_each(&mut report, include, |report, imp| {
+                let name = imp.name();
+                let ty = index.find_import(imp.namespace(), name);
+                report.validate_fn(
+                    format_args!("imports.include.{}", namespace_prefix(imp, name)),
+                    Exist(true),
+                    Exist(ty.is_some()),
+                    ty.is_some(),
+                    10,
+                    Classification::AbiCompatibilty,
+                );
+
+                if let Some(ty) = ty {
+                    report.validate_fn_type(
+                        format_args!("imports.include.{}", namespace_prefix(imp, name)),
+                        ty,
+                        imp.params(),
+                        imp.results(),
+                    );
+                }
+            });
+        }
+
+        if let Some(exclude) = &imports.exclude {
+            check_each(&mut report, exclude, |report, imp| {
+                let name = imp.name();
+                let ty = index.find_import(imp.namespace(), name);
+                let test = ty.is_some();
+
+                if let Some(ty) = ty {
+                    report.validate_fn_type(
+                        format_args!("imports.exclude.{}", namespace_prefix(imp, name)),
+                        ty,
+                        imp.params(),
+                        imp.results(),
+                    );
+                };
+
+                report.validate_fn(
+                    format_args!("imports.exclude.{}", namespace_prefix(imp, name)),
+                    Exist(false),
+                    Exist(test),
+                    !test,
+                    5,
+                    Classification::AbiCompatibilty,
+                );
+            });
+        }
+
+        // bound the breadth of the host API the module uses, without listing each function
+        if let Some(max) = imports.max_namespaces {
+            let mut namespaces = index.namespaces.iter().copied().collect::<Vec<_>>();
+            namespaces.sort_unstable();
+            let num = namespaces.len() as u32;
+            report.validate_fn(
+                "imports.max_namespaces",
+                format_args!("<= {max}"),
+                format_args!("{num} ({})", namespaces.join(", ")),
+                num <= max,
+                8,
+                Classification::Security,
+            );
+        }
+
+        if let Some(max) = imports.max_per_namespace {
+            for (namespace, num) in module.import_namespace_counts() {
+                report.validate_fn(
+                    format_args!("imports.max_per_namespace.{namespace}"),
+                    format_args!("<= {max}"),
+                    num,
+                    num as u32 <= max,
+                    8,
+                    Classification::Security,
+                );
+            }
+        }
+
+        if let Some(namespace) = &imports.namespace {
+            if let Some(include) = &namespace.include {
+                check_each(&mut report, include, |report, ns| {
+                    let name = ns.name();
+                    let test = index.namespaces.contains(name.as_str());
+                    report.validate_fn(
+                        format_args!("imports.namespace.include.{}", name),
+                        Exist(true),
+                        Exist(test),
+                        test,
+                        8,
+                        Classification::AbiCompatibilty,
+                    );
+
+                    for f in ns.functions() {
+                        let ty = index.imports.get(&(name.as_str(), f.name().as_str()));
+                        report.validate_fn(
+                            format_args!("imports.namespace.include.{name}::{}", f.name()),
+                            Exist(true),
+                            Exist(ty.is_some()),
+                            ty.is_some(),
+                            8,
+                            Classification::AbiCompatibilty,
+                        );
+
+                        if let Some(ty) = ty {
+                            report.validate_fn_type(
+                                format_args!("imports.namespace.include.{name}::{}", f.name()),
+                                ty,
+                                f.params(),
+                                f.results(),
+                            );
+                        }
+                    }
+
+                    let imported = || module.imports.iter().filter(|imp| imp.module_name == *name);
+
+                    if let Some(max) = ns.max_functions() {
+                        let num = imported().count() as u32;
+                        report.validate_fn(
+                            format_args!("imports.namespace.include.{name}.max_functions"),
+                            format!("<= {max}"),
+                            num.to_string(),
+                            num <= max,
+                            8,
+                            Classification::AbiCompatibilty,
+                        );
+                    }
+
+                    // expect that nothing is imported from the namespace except the functions
+                    // listed for it
+                    if ns.forbid_unknown_functions() {
+                        for imp in imported() {
+                            let fn_name = imp.func.name.as_str();
+                            if ns.functions().iter().any(|f| f.name() == fn_name) {
+                                continue;
+                            }
+                            report.validate_fn(
+                                format_args!("imports.namespace.include.{name}::{fn_name}"),
+                                Exist(false),
+                                Exist(true),
+                                false,
+                                8,
+                                Classification::AbiCompatibilty,
+                            );
+                        }
+                    }
+                });
+            }
+
+            if let Some(exclude) = &namespace.exclude {
+                check_each(&mut report, exclude, |report, ns| {
+                    let name = ns.name();
+                    let test = index.namespaces.contains(name.as_str());
+
+                    report.validate_fn(
+                        format_args!("imports.namespace.exclude.{}", name),
+                        Exist(false),
+                        Exist(test),
+                        !test,
+                        10,
+                        Classification::AbiCompatibilty,
+                    );
+
+                    for f in ns.functions() {
+                        let ty = index.imports.get(&(name.as_str(), f.name().as_str()));
+
+                        if let Some(ty) = ty {
+                            report.validate_fn_type(
+                                format_args!("imports.namespace.exclude.{name}::{}", f.name()),
+                                ty,
+                                f.params(),
+                                f.results(),
+                            );
+                        };
+
+                        report.validate_fn(
+                            format_args!("imports.namespace.exclude.{name}::{}", f.name()),
+                            Exist(false),
+                            Exist(ty.is_some()),
+                            ty.is_none(),
+                            10,
+                            Classification::AbiCompatibilty,
+                        );
+                    }
+                });
+            }
+        }
+
+        Ok(report.into_findings())
+    }
+}
+
+struct Exports;
+
+impl Rule for Exports {
+    fn name(&self) -> &'static str {
+        "exports"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::AbiCompatibilty
+    }
+
+    fn default_severity(&self) -> usize {
+        10
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config.check.exports.is_some()
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut report = Report::new();
+        let Some(exports) = &config.check.exports else {
+            return Ok(vec![]);
+        };
+        let index = module_exports(module);
+
+        if let Some(max) = exports.max {
+            let num = index.len() as u32;
+            let overage = num.saturating_sub(max);
+            let max = if max == 0 { 1 } else { max };
+            let severity = ((overage as f32 / max as f32) * 10.0).ceil() as usize;
+            let test = num <= max;
+            report.validate_fn(
+                "exports.max",
+                format!("<= {max}"),
+                num.to_string(),
+                test,
+                severity,
+                Classification::Security,
+            );
+        }
+
+        if let Some(include) = &exports.include {
+            check_each(&mut report, include, |report, f| {
+                let name = f.name();
+                let ty = index.get(name.as_str());
+                report.validate_fn(
+                    format_args!("exports.include.{}", name),
+                    Exist(true),
+                    Exist(ty.is_some()),
+                    ty.is_some(),
+                    10,
+                    Classification::AbiCompatibilty,
+                );
+
+                if let Some(ty) = ty {
+                    report.validate_fn_type(
+                        format_args!("exports.include.{}", name),
+                        ty,
+                        f.params(),
+                        f.results(),
+                    );
+                }
+
+                if let Some(hash) = f.hash() {
+                    report.validate_fn_hash(
+                        format_args!("exports.hash.{}", name),
+                        hash,
+                        module.function_hashes.get(name).map(String::as_str),
+                    );
+                }
+            });
+        }
+
+        if let Some(exclude) = &exports.exclude {
+            check_each(&mut report, exclude, |report, f| {
+                let name = f.name();
+
+                let ty = index.get(name.as_str());
+                if let Some(ty) = ty {
+                    report.validate_fn_type(
+                        format_args!("exports.include.{}", name),
+                        ty,
+                        f.params(),
+                        f.results(),
+                    );
+                }
+
+                report.validate_fn(
+                    format_args!("exports.exclude.{}", name),
+                    Exist(false),
+                    Exist(ty.is_some()),
+                    ty.is_none(),
+                    5,
+                    Classification::AbiCompatibilty,
+                );
+            });
+        }
+
+        Ok(report.into_findings())
+    }
+}
+
+struct Size;
+
+impl Rule for Size {
+    fn name(&self) -> &'static str {
+        "size"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::ResourceLimit
+    }
+
+    fn default_severity(&self) -> usize {
+        1
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config.check.size.is_some()
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        if let Some(max) = config.check.size.as_ref().and_then(|s| s.max.as_ref()) {
+            let parsed = max
+                .bytes()
+                .map_err(|e| anyhow::anyhow!("invalid size.max: {}", e))?;
+            if module.size > parsed {
+                // an exact limit is compared with the exact size, as both may round to the same
+                // human-readable size
+                let actual = match max {
+                    SizeLimit::Bytes(_) => format!("{} bytes", module.size),
+                    SizeLimit::Human(_) => human_bytes(module.size as f64),
+                };
+                findings.push(
+                    Finding::new(self, "size.max", format!("<= {max}"), actual)
+                        .severity((module.size / parsed.max(1)) as usize),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Complexity;
+
+impl Rule for Complexity {
+    fn name(&self) -> &'static str {
+        "complexity"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::ResourceLimit
+    }
+
+    fn default_severity(&self) -> usize {
+        1
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config.check.complexity.is_some()
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        if let Some(complexity) = &config.check.complexity {
+            let module_complexity = module.complexity.ok_or_else(|| anyhow::anyhow!("Could not determine module complexity, please remove the complexity parameter from your checkfile."))?;
+            match complexity.kind()? {
+                ComplexityKind::MaxRisk(risk) => {
+                    if risk.max() < module_complexity {
+                        findings.push(
+                            Finding::new(
+                                self,
+                                "complexity.max_risk",
+                                format!("<= {}", risk),
+                                RiskLevel::from(module_complexity).to_string(),
+                            )
+                            .severity((module_complexity / risk.max()) as usize),
+                        );
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Risk;
+
+impl Rule for Risk {
+    fn name(&self) -> &'static str {
+        "risk"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Security
+    }
+
+    fn default_severity(&self) -> usize {
+        1
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config
+            .check
+            .risk
+            .as_ref()
+            .is_some_and(|risk| risk.max_score.is_some())
+    }
+
+    // the score counts the strings which look like endpoints or credentials
+    fn needs_strings(&self) -> bool {
+        true
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        if let Some(max) = config.check.risk.as_ref().and_then(|risk| risk.max_score) {
+            let score = module.risk_score();
+            if score.score > max {
+                findings.push(
+                    Finding::new(
+                        self,
+                        "risk.max_score",
+                        format!("<= {max}"),
+                        score.to_string(),
+                    )
+                    .severity((score.score / max.max(1)) as usize),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Capabilities;
+
+impl Rule for Capabilities {
+    fn name(&self) -> &'static str {
+        "capabilities"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Security
+    }
+
+    fn default_severity(&self) -> usize {
+        10
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config
+            .check
+            .capabilities
+            .as_ref()
+            .is_some_and(|c| c.deny.is_some())
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        if let Some(deny) = config
+            .check
+            .capabilities
+            .as_ref()
+            .and_then(|c| c.deny.as_ref())
+        {
+            let granted = module.capabilities();
+            for capability in deny {
+                if let Some(imports) = granted.get(capability) {
+                    findings.push(Finding::new(
+                        self,
+                        format!("capabilities.deny.{capability}"),
+                        "not granted",
+                        format!("granted by {}", imports.join(", ")),
+                    ));
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Licenses;
+
+impl Rule for Licenses {
+    fn name(&self) -> &'static str {
+        "licenses"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Licensing
+    }
+
+    fn default_severity(&self) -> usize {
+        10
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config
+            .check
+            .licenses
+            .as_ref()
+            .is_some_and(|l| l.deny.is_some())
+    }
+
+    fn needs_strings(&self) -> bool {
+        true
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        if let Some(deny) = config.check.licenses.as_ref().and_then(|l| l.deny.as_ref()) {
+            for id in deny {
+                let found = module.provenance.find_license(id);
+                if !found.is_empty() {
+                    findings.push(Finding::new(
+                        self,
+                        format!("licenses.deny.{id}"),
+                        "not found",
+                        format!("found {}", found.join(", ")),
+                    ));
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Integrity;
+
+impl Rule for Integrity {
+    fn name(&self) -> &'static str {
+        "integrity"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Integrity
+    }
+
+    fn default_severity(&self) -> usize {
+        10
+    }
+
+    // structural problems are checked for unless allowed, so this rule always runs
+    fn enabled(&self, _config: &RuleConfig) -> bool {
+        true
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let integrity = config.check.integrity.clone().unwrap_or_default();
+
+        if integrity.allow_malformed != Some(true) {
+            for issue in &module.integrity {
+                findings.push(Finding::new(
+                    self,
+                    format!("integrity.malformed.{}.{}", issue.kind, issue.offset),
+                    "well-formed",
+                    issue.message.clone(),
+                ));
+            }
+        }
+
+        if integrity.allow_duplicate_imports == Some(false) {
+            let mut signatures: BTreeMap<(&str, &str), Vec<String>> = BTreeMap::new();
+            for import in &module.imports {
+                let ty = import.func.ty.to_string();
+                let seen = signatures
+                    .entry((&import.module_name, &import.func.name))
+                    .or_default();
+                if !seen.contains(&ty) {
+                    seen.push(ty);
+                }
+            }
+            for ((namespace, name), signatures) in signatures {
+                if signatures.len() > 1 {
+                    findings.push(Finding::new(
+                        self,
+                        format!("integrity.duplicate_imports.{namespace}::{name}"),
+                        "imported with one signature",
+                        format!("imported as {}", signatures.join(" and ")),
+                    ));
+                }
+            }
+        }
+
+        if integrity.allow_duplicate_exports == Some(false) {
+            let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+            for export in &module.exports {
+                *counts.entry(&export.func.name).or_default() += 1;
+            }
+            for (name, count) in counts {
+                if count > 1 {
+                    findings.push(Finding::new(
+                        self,
+                        format!("integrity.duplicate_exports.{name}"),
+                        "exported once",
+                        format!("exported {count} times"),
+                    ));
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct ModuleName;
+
+impl Rule for ModuleName {
+    fn name(&self) -> &'static str {
+        "module_name"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Identity
+    }
+
+    fn default_severity(&self) -> usize {
+        5
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config
+            .check
+            .module_name
+            .as_ref()
+            .is_some_and(|n| n.pattern.is_some())
+    }
+
+    // a module parsed from a file has no location, so its name is unknown and isn't checked
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let pattern = config
+            .check
+            .module_name
+            .as_ref()
+            .and_then(|n| n.pattern.as_ref());
+        if let (Some(pattern), false) = (pattern, module.location.is_empty()) {
+            let regex = regex::Regex::new(&format!("^(?:{pattern})$"))
+                .map_err(|e| anyhow::anyhow!("invalid module_name.pattern: {}", e))?;
+            let name = module.file_name();
+            if !regex.is_match(&name) {
+                findings.push(Finding::new(
+                    self,
+                    "module_name.pattern",
+                    format!("matching {pattern}"),
+                    if name.is_empty() {
+                        "<NONE>".into()
+                    } else {
+                        name
+                    },
+                ));
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+struct Location;
+
+impl Rule for Location {
+    fn name(&self) -> &'static str {
+        "location"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Identity
+    }
+
+    fn default_severity(&self) -> usize {
+        7
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config
+            .check
+            .location
+            .as_ref()
+            .is_some_and(|l| l.allowed_schemes.is_some())
+    }
+
+    // as with `ModuleName`, a module without a location isn't checked
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let allowed = config
+            .check
+            .location
+            .as_ref()
+            .and_then(|l| l.allowed_schemes.as_ref());
+        if let (Some(allowed), false) = (allowed, module.location.is_empty()) {
+            // a plain path on disk has no scheme, and is never allowed
+            let scheme = module
+                .try_location_url()
+                .ok()
+                .map(|url| url.scheme().to_string());
+            if !scheme
+                .as_ref()
+                .is_some_and(|scheme| allowed.iter().any(|a| a.eq_ignore_ascii_case(scheme)))
+            {
+                findings.push(Finding::new(
+                    self,
+                    "location.allowed_schemes",
+                    format!("a URL with scheme {}", allowed.join(" or ")),
+                    module.location.clone(),
+                ));
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+// the fewest items checked by each parallel task, below which splitting the work costs more than the
+// checks themselves
+const MIN_CHECKS_PER_TASK: usize = 256;
+
+// run a check on each item in parallel, then add the failures to the report in the order of the
+// items, as if the checks had run one after another
+fn check_each<T: Sync>(report: &mut Report, items: &[T], check: impl Fn(&mut Report, &T) + Sync) {
+    let fails = items
+        .par_iter()
+        .with_min_len(MIN_CHECKS_PER_TASK)
+        .flat_map_iter(|item| {
+            let mut report = Report::new();
+            check(&mut report, item);
+            report.fails
+        })
+        .collect::<Vec<_>>();
+    report.fails.extend(fails);
+}
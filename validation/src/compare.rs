@@ -0,0 +1,228 @@
+//! A semantic comparison of two checkfiles: which checks were added or removed, and whether each
+//! change loosens or tightens the policy, regardless of how the YAML is ordered or formatted.
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{IncludeMode, PlannedCheck, Validation};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// fewer modules pass the new checkfile
+    Tightened,
+    /// more modules pass the new checkfile
+    Loosened,
+    /// the check changed in a way which neither loosens nor tightens it, e.g. a new function
+    /// signature
+    Changed,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Direction::Tightened => "tightened",
+            Direction::Loosened => "loosened",
+            Direction::Changed => "changed",
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PolicyChange {
+    pub direction: Direction,
+    /// the rule code, see `modsurfer explain`
+    pub rule: &'static str,
+    /// the report property path of the check
+    pub property: String,
+    /// what the old checkfile expects, or `None` if it didn't make this check
+    pub before: Option<String>,
+    /// what the new checkfile expects, or `None` if it doesn't make this check
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Comparison {
+    pub changes: Vec<PolicyChange>,
+}
+
+impl Comparison {
+    /// Compare the checks made by two checkfiles, in property order. Remote checkfiles referred
+    /// to by `url` are not fetched, only a change of `url` is reported.
+    pub fn new(before: &Validation, after: &Validation) -> Result<Self> {
+        let index = |checks: Vec<PlannedCheck>| {
+            checks
+                .into_iter()
+                .map(|check| (check.property.clone(), check))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let mut old = index(before.plan()?);
+        let mut new = index(after.plan()?);
+
+        let mut changes = vec![];
+        if before.validate.url != after.validate.url {
+            changes.push(PolicyChange {
+                direction: Direction::Changed,
+                rule: "",
+                property: "url".into(),
+                before: before.validate.url.clone(),
+                after: after.validate.url.clone(),
+            });
+        }
+
+        let mut properties = old.keys().chain(new.keys()).cloned().collect::<Vec<_>>();
+        properties.sort();
+        properties.dedup();
+
+        for property in properties {
+            let change = match (old.remove(&property), new.remove(&property)) {
+                (Some(a), Some(b)) if a.expected == b.expected => continue,
+                (Some(a), Some(b)) => PolicyChange {
+                    direction: limit_direction(&property, before, after),
+                    rule: b.rule,
+                    property,
+                    before: Some(a.expected),
+                    after: Some(b.expected),
+                },
+                (None, Some(b)) => PolicyChange {
+                    direction: added_direction(&property, after),
+                    rule: b.rule,
+                    property,
+                    before: None,
+                    after: Some(b.expected),
+                },
+                (Some(a), None) => PolicyChange {
+                    direction: match added_direction(&property, before) {
+                        Direction::Tightened => Direction::Loosened,
+                        Direction::Loosened => Direction::Tightened,
+                        Direction::Changed => Direction::Changed,
+                    },
+                    rule: a.rule,
+                    property,
+                    before: Some(a.expected),
+                    after: None,
+                },
+                (None, None) => continue,
+            };
+            changes.push(change);
+        }
+
+        Ok(Comparison { changes })
+    }
+}
+
+// adding a check to `checkfile` tightens a policy, except adding an entry to the list of allowed
+// imports, which loosens it unless the entry is also required
+fn added_direction(property: &str, checkfile: &Validation) -> Direction {
+    if !property.starts_with("imports.include.") {
+        return Direction::Tightened;
+    }
+
+    let mode = checkfile
+        .validate
+        .imports
+        .as_ref()
+        .and_then(|imports| imports.mode)
+        .unwrap_or_default();
+    match mode {
+        IncludeMode::AllowOnly => Direction::Loosened,
+        IncludeMode::RequireAll => Direction::Tightened,
+        IncludeMode::Both => Direction::Changed,
+    }
+}
+
+// the direction of a change to a check which both checkfiles make
+fn limit_direction(property: &str, before: &Validation, after: &Validation) -> Direction {
+    let (a, b) = (&before.validate, &after.validate);
+    let order = match property {
+        "allow_wasi" => a.allow_wasi.cmp(&b.allow_wasi),
+        "imports.max_namespaces" => {
+            let max =
+                |imports: &Option<crate::Imports>| imports.as_ref().and_then(|i| i.max_namespaces);
+            max(&a.imports).cmp(&max(&b.imports))
+        }
+        "imports.max_per_namespace" => {
+            let max = |imports: &Option<crate::Imports>| {
+                imports.as_ref().and_then(|i| i.max_per_namespace)
+            };
+            max(&a.imports).cmp(&max(&b.imports))
+        }
+        "exports.max" => {
+            let max = |exports: &Option<crate::Exports>| exports.as_ref().and_then(|e| e.max);
+            max(&a.exports).cmp(&max(&b.exports))
+        }
+        "size.max" => {
+            let max = |size: &Option<crate::Size>| {
+                size.as_ref()
+                    .and_then(|s| s.max.as_ref())
+                    .and_then(|max| max.bytes().ok())
+            };
+            max(&a.size).cmp(&max(&b.size))
+        }
+        "complexity.max_risk" => {
+            let max = |complexity: &Option<crate::Complexity>| {
+                complexity
+                    .as_ref()
+                    .and_then(|c| c.max_risk)
+                    .map(|risk| risk.max())
+            };
+            max(&a.complexity).cmp(&max(&b.complexity))
+        }
+        "risk.max_score" => {
+            let max = |risk: &Option<crate::Risk>| risk.as_ref().and_then(|r| r.max_score);
+            max(&a.risk).cmp(&max(&b.risk))
+        }
+        "vulnerabilities.max_severity" => {
+            let max = |v: &Option<crate::Vulnerabilities>| v.as_ref().and_then(|v| v.max_severity);
+            max(&a.vulnerabilities).cmp(&max(&b.vulnerabilities))
+        }
+        _ => match property
+            .strip_prefix("imports.namespace.include.")
+            .and_then(|p| p.strip_suffix(".max_functions"))
+        {
+            Some(namespace) => {
+                let max = |imports: &Option<crate::Imports>| {
+                    imports
+                        .as_ref()
+                        .and_then(|i| i.namespace.as_ref())
+                        .and_then(|ns| ns.include.as_ref())
+                        .and_then(|include| include.iter().find(|ns| ns.name() == namespace))
+                        .and_then(|ns| ns.max_functions())
+                };
+                max(&a.imports).cmp(&max(&b.imports))
+            }
+            None => return Direction::Changed,
+        },
+    };
+
+    match order {
+        // the old limit was higher, or WASI was allowed and no longer is
+        std::cmp::Ordering::Greater => Direction::Tightened,
+        std::cmp::Ordering::Less => Direction::Loosened,
+        std::cmp::Ordering::Equal => Direction::Changed,
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.changes.is_empty() {
+            return f.write_str("No policy changes.");
+        }
+
+        let mut table = crate::new_table(vec!["Change", "Rule", "Property", "Before", "After"]);
+        self.changes.iter().for_each(|change| {
+            table.add_row(vec![
+                change.direction.to_string(),
+                change.rule.to_string(),
+                change.property.clone(),
+                change.before.clone().unwrap_or_default(),
+                change.after.clone().unwrap_or_default(),
+            ]);
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
@@ -0,0 +1,91 @@
+//! A report together with what it was made for: the module, the checkfile, the version of the
+//! tool and the time of validation, so that an archived report describes itself.
+use std::path::Path;
+
+use chrono::{SecondsFormat, Utc};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use sha2::{Digest, Sha256};
+
+use crate::{ModuleIdentity, Report, Validation};
+
+/// A report, with the facts identifying the module and checkfile it was made for.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct ReportEnvelope<'a> {
+    pub module: Option<ModuleIdentity>,
+    pub checkfile: Option<CheckfileIdentity>,
+    pub tool: ToolIdentity,
+    /// when the envelope was made, in RFC 3339 format
+    pub validated_at: String,
+    pub report: &'a Report,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CheckfileIdentity {
+    pub path: String,
+    /// the hex-encoded SHA-256 hash of the checkfile on disk
+    pub sha256: String,
+    /// the remote checkfile the checkfile on disk refers to, if any
+    pub url: Option<String>,
+}
+
+impl CheckfileIdentity {
+    /// Identify a checkfile from its path and contents.
+    pub fn new(path: &Path, contents: &[u8]) -> Self {
+        CheckfileIdentity {
+            path: path.display().to_string(),
+            sha256: format!("{:x}", Sha256::digest(contents)),
+            url: serde_yaml::from_slice::<Validation>(contents)
+                .ok()
+                .and_then(|validation| validation.validate.url),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ToolIdentity {
+    pub name: String,
+    pub version: String,
+}
+
+impl<'a> ReportEnvelope<'a> {
+    /// Wrap a report made now, with the module it was made for, if known, and this crate as the
+    /// tool which made it.
+    pub fn new(report: &'a Report) -> Self {
+        ReportEnvelope {
+            module: report.module.clone(),
+            checkfile: None,
+            tool: ToolIdentity {
+                name: env!("CARGO_PKG_NAME").into(),
+                version: env!("CARGO_PKG_VERSION").into(),
+            },
+            validated_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            report,
+        }
+    }
+
+    /// Name the file the module was read from.
+    pub fn module_file(mut self, path: &Path) -> Self {
+        if let Some(module) = &mut self.module {
+            module.file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+        }
+        self
+    }
+
+    pub fn checkfile(mut self, checkfile: CheckfileIdentity) -> Self {
+        self.checkfile = Some(checkfile);
+        self
+    }
+
+    pub fn tool(mut self, name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.tool = ToolIdentity {
+            name: name.into(),
+            version: version.into(),
+        };
+        self
+    }
+}
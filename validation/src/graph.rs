@@ -0,0 +1,283 @@
+//! A graph of the host API a set of modules depends on: each module, with its exports, linked to
+//! the functions it imports from each namespace. Rendered as Dot, JSON or SVG.
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use modsurfer_module::Module;
+use serde::Serialize;
+
+/// Modules and the namespaced functions they import, e.g. for a fleet of plugins, the functions
+/// of each host API and which plugins call them.
+#[derive(Debug, Default, Serialize)]
+pub struct NamespaceGraph {
+    pub modules: Vec<ModuleNode>,
+    pub namespaces: Vec<NamespaceNode>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleNode {
+    pub name: String,
+    pub exports: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NamespaceNode {
+    pub name: String,
+    pub functions: Vec<FunctionNode>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunctionNode {
+    pub name: String,
+    /// the names of the modules importing the function
+    pub imported_by: Vec<String>,
+}
+
+impl NamespaceGraph {
+    /// Build the graph of named modules, in the order given. Namespaces and functions are sorted
+    /// by name. A name given more than once is numbered, e.g. `plugin.wasm (2)`.
+    pub fn new<'a>(modules: impl IntoIterator<Item = (String, &'a Module)>) -> Self {
+        let mut graph = NamespaceGraph::default();
+        let mut namespaces: BTreeMap<&str, BTreeMap<&str, Vec<String>>> = BTreeMap::new();
+        let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+
+        for (name, module) in modules {
+            let count = seen.entry(name.clone()).or_default();
+            *count += 1;
+            let name = match *count {
+                1 => name,
+                n => format!("{} ({})", name, n),
+            };
+            for import in &module.imports {
+                let importers = namespaces
+                    .entry(&import.module_name)
+                    .or_default()
+                    .entry(&import.func.name)
+                    .or_default();
+                if importers.last() != Some(&name) {
+                    importers.push(name.clone());
+                }
+            }
+            graph.modules.push(ModuleNode {
+                name,
+                exports: module
+                    .exports
+                    .iter()
+                    .map(|export| export.func.name.clone())
+                    .collect(),
+            });
+        }
+
+        graph.namespaces = namespaces
+            .into_iter()
+            .map(|(name, functions)| NamespaceNode {
+                name: name.to_string(),
+                functions: functions
+                    .into_iter()
+                    .map(|(name, imported_by)| FunctionNode {
+                        name: name.to_string(),
+                        imported_by,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        graph
+    }
+
+    /// Render the graph in Dot format, for Graphviz, with a cluster per namespace.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph namespaces {\n");
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [shape=box, fontname=\"Helvetica\"];\n");
+
+        for (i, module) in self.modules.iter().enumerate() {
+            // the name centered, then each export left-justified
+            let mut label = format!("{}\\n", dot_escape(&module.name));
+            for export in &module.exports {
+                let _ = write!(label, "{}\\l", dot_escape(export));
+            }
+            let _ = writeln!(dot, "  m{} [label=\"{}\", style=bold];", i, label);
+        }
+
+        for (i, namespace) in self.namespaces.iter().enumerate() {
+            let _ = writeln!(dot, "  subgraph cluster_{} {{", i);
+            let _ = writeln!(dot, "    label=\"{}\";", dot_escape(&namespace.name));
+            for (j, function) in namespace.functions.iter().enumerate() {
+                let _ = writeln!(
+                    dot,
+                    "    n{}_{} [label=\"{}\"];",
+                    i,
+                    j,
+                    dot_escape(&function.name)
+                );
+            }
+            dot.push_str("  }\n");
+        }
+
+        self.edges(|module, namespace, function| {
+            let _ = writeln!(dot, "  m{} -> n{}_{};", module, namespace, function);
+        });
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Render the graph as an SVG image, with the modules in a column on the left, and the
+    /// functions of each namespace in a column on the right.
+    pub fn to_svg(&self) -> String {
+        const CHAR_WIDTH: usize = 7;
+        const ROW: usize = 18;
+        const TITLE: usize = 26;
+        const GAP: usize = 16;
+        const MARGIN: usize = 20;
+        const SPACING: usize = 200;
+
+        let width_of = |names: &mut dyn Iterator<Item = &String>| {
+            names.map(|name| name.chars().count()).max().unwrap_or(0) * CHAR_WIDTH + 24
+        };
+        let left_width = width_of(
+            &mut self
+                .modules
+                .iter()
+                .flat_map(|m| std::iter::once(&m.name).chain(&m.exports)),
+        )
+        .max(160);
+        let right_width =
+            width_of(&mut self.namespaces.iter().flat_map(|ns| {
+                std::iter::once(&ns.name).chain(ns.functions.iter().map(|f| &f.name))
+            }))
+            .max(160);
+        let right_x = MARGIN + left_width + SPACING;
+
+        let mut boxes = String::new();
+        let mut module_anchors = Vec::with_capacity(self.modules.len());
+        let mut y = MARGIN;
+        for module in &self.modules {
+            let height = TITLE + ROW * module.exports.len() + 6;
+            let _ = writeln!(
+                boxes,
+                r#"  <rect x="{}" y="{}" width="{}" height="{}" class="module"/>"#,
+                MARGIN, y, left_width, height
+            );
+            let _ = writeln!(
+                boxes,
+                r#"  <text x="{}" y="{}" class="title">{}</text>"#,
+                MARGIN + 10,
+                y + 18,
+                xml_escape(&module.name)
+            );
+            for (i, export) in module.exports.iter().enumerate() {
+                let _ = writeln!(
+                    boxes,
+                    r#"  <text x="{}" y="{}">{}</text>"#,
+                    MARGIN + 10,
+                    y + TITLE + ROW * i + 12,
+                    xml_escape(export)
+                );
+            }
+            module_anchors.push(y + TITLE / 2);
+            y += height + GAP;
+        }
+        let mut height = y;
+
+        let mut function_anchors = Vec::with_capacity(self.namespaces.len());
+        let mut y = MARGIN;
+        for namespace in &self.namespaces {
+            let group_height = TITLE + (ROW + 4) * namespace.functions.len() + 4;
+            let _ = writeln!(
+                boxes,
+                r#"  <rect x="{}" y="{}" width="{}" height="{}" class="namespace"/>"#,
+                right_x, y, right_width, group_height
+            );
+            let _ = writeln!(
+                boxes,
+                r#"  <text x="{}" y="{}" class="title">{}</text>"#,
+                right_x + 10,
+                y + 18,
+                xml_escape(&namespace.name)
+            );
+            let mut anchors = Vec::with_capacity(namespace.functions.len());
+            for (i, function) in namespace.functions.iter().enumerate() {
+                let row_y = y + TITLE + (ROW + 4) * i;
+                let _ = writeln!(
+                    boxes,
+                    r#"  <rect x="{}" y="{}" width="{}" height="{}" class="function"/>"#,
+                    right_x + 6,
+                    row_y,
+                    right_width - 12,
+                    ROW
+                );
+                let _ = writeln!(
+                    boxes,
+                    r#"  <text x="{}" y="{}">{}</text>"#,
+                    right_x + 12,
+                    row_y + 13,
+                    xml_escape(&function.name)
+                );
+                anchors.push(row_y + ROW / 2);
+            }
+            function_anchors.push(anchors);
+            y += group_height + GAP;
+        }
+        height = height.max(y);
+
+        let (x1, x2) = (MARGIN + left_width, right_x + 6);
+        let mut edges = String::new();
+        self.edges(|module, namespace, function| {
+            let (y1, y2) = (
+                module_anchors[module],
+                function_anchors[namespace][function],
+            );
+            let _ = writeln!(
+                edges,
+                r#"  <path d="M{x1},{y1} C{c1},{y1} {c2},{y2} {x2},{y2}"/>"#,
+                c1 = x1 + SPACING / 2,
+                c2 = x2 - SPACING / 2,
+            );
+        });
+
+        let width = right_x + right_width + MARGIN;
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="Helvetica, Arial, sans-serif" font-size="12">
+  <style>
+    rect {{ stroke: #444; rx: 4; }}
+    .module {{ fill: #e8f0fe; }}
+    .namespace {{ fill: #f4f4f4; }}
+    .function {{ fill: #ffffff; }}
+    .title {{ font-weight: bold; }}
+    path {{ fill: none; stroke: #6b8ccc; stroke-opacity: 0.7; }}
+  </style>
+{edges}{boxes}</svg>
+"#
+        )
+    }
+
+    // call `f` with the indices of the module, namespace and function of each import
+    fn edges(&self, mut f: impl FnMut(usize, usize, usize)) {
+        let index: BTreeMap<&str, usize> = self
+            .modules
+            .iter()
+            .enumerate()
+            .map(|(i, module)| (module.name.as_str(), i))
+            .collect();
+        for (i, namespace) in self.namespaces.iter().enumerate() {
+            for (j, function) in namespace.functions.iter().enumerate() {
+                for module in &function.imported_by {
+                    f(index[module.as_str()], i, j);
+                }
+            }
+        }
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,97 @@
+//! Report hooks, listed under `hooks.post_report` in a checkfile (or given to `modsurfer validate
+//! --post-hook`), which are run once a module is validated, e.g. to enrich findings, file tickets
+//! or compute custom scores:
+//!
+//! ```yaml
+//! validate:
+//!   hooks:
+//!     post_report:
+//!       - path: hooks/score.wasm
+//!       - path: hooks/file-ticket.wasm
+//!         allowed_hosts: [tickets.example.com]
+//! ```
+//!
+//! Each hook exports a `post_report` function, called with the report as JSON, in a
+//! `ReportEnvelope` naming the module. The hook may return nothing, leaving the report as it is, or
+//! a report of its own (`{"fails": {...}}`), which replaces it for the hooks after it and in the
+//! output of validation.
+//!
+//! Hooks run in the order they are listed, within the same `PluginLimits` as the parser and
+//! without WASI.
+//!
+//! Generate hooks, given to `modsurfer generate --post-hook` (or the profile's `generate_hooks`),
+//! post-process each checkfile generated, e.g. to add an organization's defaults or strip noisy
+//! imports. Each exports a `post_generate` function, called with
+//! `{"module": {...}, "checkfile": {"validate": {...}}}`, and may likewise return nothing or a
+//! checkfile (as JSON or YAML) which replaces it.
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::plugin::{read_wasm, LocalPlugin};
+use crate::{ModuleIdentity, Parser, Report, ReportEnvelope, ReportHook, Validation};
+
+/// The function each report hook must export.
+const POST_REPORT_FUNCTION: &str = "post_report";
+/// The function each generate hook must export.
+const POST_GENERATE_FUNCTION: &str = "post_generate";
+
+#[derive(Serialize)]
+struct GenerateInput<'a> {
+    module: ModuleIdentity,
+    checkfile: &'a Validation,
+}
+
+/// Run each of `hooks` on `report` in turn, returning the report left by the last.
+pub fn run_report_hooks(hooks: &[ReportHook], mut report: Report) -> Result<Report> {
+    for hook in hooks {
+        let wasm = read_wasm(&hook.path, hook.sha256.as_deref())?;
+        let input = serde_json::to_vec(&ReportEnvelope::new(&report))?;
+        let output = LocalPlugin::with_allowed_hosts(
+            &hook.path,
+            &wasm,
+            Parser::shared().limits(),
+            hook.allowed_hosts.as_deref().unwrap_or_default(),
+        )?
+        .call(POST_REPORT_FUNCTION, &input)?;
+        if output.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+
+        // the module validated is kept, since reports are serialized without it
+        let replaced: Report = serde_json::from_slice(&output)
+            .with_context(|| format!("report hook {} returned an invalid report", hook.path))?;
+        report.fails = replaced.fails;
+    }
+
+    Ok(report)
+}
+
+/// Run each of the generate hooks at `hooks` on the checkfile generated from `module` in turn,
+/// returning the checkfile left by the last.
+pub fn run_generate_hooks(
+    hooks: &[impl AsRef<Path>],
+    module: &modsurfer_module::Module,
+    mut validation: Validation,
+) -> Result<Validation> {
+    for hook in hooks {
+        let path = hook.as_ref().display().to_string();
+        let wasm = read_wasm(&path, None)?;
+        let input = serde_json::to_vec(&GenerateInput {
+            module: ModuleIdentity::new(module),
+            checkfile: &validation,
+        })?;
+        let output = LocalPlugin::new(&path, &wasm, Parser::shared().limits())?
+            .call(POST_GENERATE_FUNCTION, &input)?;
+        if output.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+
+        // JSON is read as YAML too
+        validation = serde_yaml::from_slice(&output)
+            .with_context(|| format!("generate hook {} returned an invalid checkfile", path))?;
+    }
+
+    Ok(validation)
+}
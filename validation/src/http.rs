@@ -0,0 +1,207 @@
+//! The HTTP client used to fetch remote checkfiles. Like every `reqwest` client it honors the
+//! standard `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY` and `NO_PROXY` environment variables, unless
+//! a proxy is set explicitly (e.g. from CLI flags) for every request made by the process.
+//!
+//! Fetches are limited in size and time, and cached on disk for a short while, so that a slow or
+//! unavailable policy host can't stall a CI run.
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{bail, Context, Result};
+use sha2::{Digest, Sha256};
+
+/// The largest remote checkfile which will be read.
+pub const MAX_CHECKFILE_SIZE: usize = 1024 * 1024;
+/// How long to wait for a remote checkfile, unless set in seconds by `FETCH_TIMEOUT_ENV`.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+const FETCH_TIMEOUT_ENV: &str = "MODSURFER_FETCH_TIMEOUT";
+/// How long a fetched checkfile is reused for, unless set in seconds by `CACHE_TTL_ENV`. A TTL of
+/// 0 disables the cache.
+const CACHE_TTL: Duration = Duration::from_secs(300);
+const CACHE_TTL_ENV: &str = "MODSURFER_CHECKFILE_TTL";
+/// Where fetched checkfiles are cached, defaulting to `modsurfer/checkfiles` in the user's cache
+/// directory (`$XDG_CACHE_HOME`, `~/.cache` or `%LOCALAPPDATA%`).
+const CACHE_DIR_ENV: &str = "MODSURFER_CACHE_DIR";
+
+static PROXY: RwLock<Option<String>> = RwLock::new(None);
+
+/// Send every request made by `http_client` from here on through the proxy at this URL, except
+/// requests to hosts listed in `NO_PROXY`.
+pub fn set_proxy(proxy: Option<String>) {
+    *PROXY.write().unwrap_or_else(|e| e.into_inner()) = proxy;
+}
+
+/// The proxy currently applied by `http_client`, if set explicitly.
+pub fn proxy() -> Option<String> {
+    PROXY.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Create a client which uses the current proxy.
+pub fn http_client() -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(url) = proxy() {
+        let proxy = reqwest::Proxy::all(&url)
+            .map_err(|e| anyhow::anyhow!("invalid proxy `{}`: {}", url, e))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Fetch the remote checkfile at `url`, from the cache if it was fetched within the TTL, checking
+/// its hash matches `sha256` if given.
+pub(crate) async fn fetch_checkfile(url: &str, sha256: Option<&str>) -> Result<Vec<u8>> {
+    let ttl = env_secs(CACHE_TTL_ENV)?.unwrap_or(CACHE_TTL);
+    let cached = cache_dir()
+        .filter(|_| !ttl.is_zero())
+        .map(|dir| dir.join(format!("{:x}.yaml", Sha256::digest(url))));
+
+    if let Some(cached) = &cached {
+        if let Some(buf) = read_cached(cached, ttl).await {
+            // a cached checkfile which no longer matches the pinned hash is fetched again
+            if verify(url, &buf, sha256).is_ok() {
+                eprintln!("Using cached validation schema from URL: {}", url);
+                return Ok(buf);
+            }
+        }
+    }
+
+    eprintln!("Fetching validation schema from URL: {}", url);
+    let mut resp = http_client()?
+        .get(url)
+        .timeout(fetch_timeout()?)
+        .send()
+        .await
+        .with_context(|| format!("Failed to fetch remote validation schema: {}", url))?;
+    if !resp.status().is_success() {
+        bail!(
+            "Failed to make request for remote validation schema: {} ({})",
+            url,
+            resp.status()
+        );
+    }
+
+    let too_large = || {
+        anyhow::anyhow!(
+            "Remote validation schema {} is larger than the {} byte limit",
+            url,
+            MAX_CHECKFILE_SIZE
+        )
+    };
+    if resp
+        .content_length()
+        .is_some_and(|len| len > MAX_CHECKFILE_SIZE as u64)
+    {
+        return Err(too_large());
+    }
+    let mut buf = vec![];
+    while let Some(chunk) = resp.chunk().await? {
+        if buf.len() + chunk.len() > MAX_CHECKFILE_SIZE {
+            return Err(too_large());
+        }
+        buf.extend_from_slice(&chunk);
+    }
+
+    verify(url, &buf, sha256)?;
+
+    if let Some(cached) = &cached {
+        // the cache is only an optimization, so failing to write it isn't an error
+        let _ = write_cached(cached, &buf).await;
+    }
+
+    Ok(buf)
+}
+
+/// How long to wait for a remote checkfile or other remote resource, e.g. an advisory database.
+pub(crate) fn fetch_timeout() -> Result<Duration> {
+    Ok(env_secs(FETCH_TIMEOUT_ENV)?.unwrap_or(FETCH_TIMEOUT))
+}
+
+fn verify(url: &str, buf: &[u8], sha256: Option<&str>) -> Result<()> {
+    if let Some(expected) = sha256 {
+        let actual = format!("{:x}", Sha256::digest(buf));
+        if !actual.eq_ignore_ascii_case(expected.trim()) {
+            bail!(
+                "Remote validation schema {} has SHA-256 {}, but `url_sha256` expects {}",
+                url,
+                actual,
+                expected
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn read_cached(path: &Path, ttl: Duration) -> Option<Vec<u8>> {
+    let metadata = tokio::fs::metadata(path).await.ok()?;
+    // another user could have planted a checkfile for this URL
+    if !metadata.is_file() || !owned_by_user(&metadata) {
+        return None;
+    }
+    let modified = metadata.modified().ok()?;
+    let age = SystemTime::now().duration_since(modified).ok()?;
+    if age > ttl {
+        return None;
+    }
+
+    tokio::fs::read(path).await.ok()
+}
+
+// write a checkfile to the cache, in a directory only the current user can read
+async fn write_cached(path: &Path, buf: &[u8]) -> Result<()> {
+    let dir = path.parent().context("cached checkfile has no directory")?;
+    let mut builder = tokio::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    builder.mode(0o700);
+    builder.create(dir).await?;
+
+    let metadata = tokio::fs::metadata(dir).await?;
+    if !owned_by_user(&metadata) {
+        bail!("cache directory {} is owned by another user", dir.display());
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        tokio::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700)).await?;
+    }
+
+    Ok(tokio::fs::write(path, buf).await?)
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_ENV) {
+        return Some(dir.into());
+    }
+    let dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))?;
+
+    Some(dir.join("modsurfer").join("checkfiles"))
+}
+
+#[cfg(unix)]
+fn owned_by_user(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    // SAFETY: geteuid has no preconditions and can't fail
+    metadata.uid() == unsafe { libc::geteuid() }
+}
+
+#[cfg(not(unix))]
+fn owned_by_user(_metadata: &std::fs::Metadata) -> bool {
+    true
+}
+
+fn env_secs(name: &str) -> Result<Option<Duration>> {
+    match std::env::var(name) {
+        Ok(v) if !v.is_empty() => v
+            .parse()
+            .map(|secs| Some(Duration::from_secs(secs)))
+            .map_err(|e| anyhow::anyhow!("invalid {}: {}", name, e)),
+        _ => Ok(None),
+    }
+}
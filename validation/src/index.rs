@@ -0,0 +1,83 @@
+//! Lookups over a module's imports and exports, and a checkfile's import list, built once by the
+//! rule which needs them so that each check is a hash lookup rather than a scan. Modules produced
+//! by e.g. wasm-bindgen can have thousands of imports and exports.
+use std::collections::{HashMap, HashSet};
+
+use modsurfer_module::{FunctionType, Module};
+
+use crate::ImportItem;
+
+pub(crate) struct ModuleImports<'a> {
+    /// function types of the imports, keyed by namespace and name
+    pub imports: HashMap<(&'a str, &'a str), &'a FunctionType>,
+    /// function types of the imports keyed by name alone, for checkfile imports without a
+    /// namespace
+    pub import_names: HashMap<&'a str, &'a FunctionType>,
+    pub namespaces: HashSet<&'a str>,
+}
+
+impl<'a> ModuleImports<'a> {
+    pub fn new(module: &'a Module) -> Self {
+        let mut imports = HashMap::with_capacity(module.imports.len());
+        let mut import_names = HashMap::with_capacity(module.imports.len());
+        let mut namespaces = HashSet::new();
+        for imp in &module.imports {
+            imports
+                .entry((imp.module_name.as_str(), imp.func.name.as_str()))
+                .or_insert(&imp.func.ty);
+            import_names
+                .entry(imp.func.name.as_str())
+                .or_insert(&imp.func.ty);
+            namespaces.insert(imp.module_name.as_str());
+        }
+
+        ModuleImports {
+            imports,
+            import_names,
+            namespaces,
+        }
+    }
+
+    /// The type of the import matching a checkfile item, by name and namespace if it has one.
+    pub fn find_import(&self, namespace: Option<&str>, name: &str) -> Option<&'a FunctionType> {
+        match namespace {
+            Some(ns) => self.imports.get(&(ns, name)).copied(),
+            None => self.import_names.get(name).copied(),
+        }
+    }
+}
+
+/// Function types of a module's exports, keyed by name.
+pub(crate) fn module_exports(module: &Module) -> HashMap<&str, &FunctionType> {
+    let mut exports = HashMap::with_capacity(module.exports.len());
+    for exp in &module.exports {
+        exports
+            .entry(exp.func.name.as_str())
+            .or_insert(&exp.func.ty);
+    }
+
+    exports
+}
+
+/// The first of a checkfile's imports with each namespace and name.
+pub(crate) struct ImportIndex<'a> {
+    items: HashMap<(Option<&'a str>, &'a str), &'a ImportItem>,
+}
+
+impl<'a> ImportIndex<'a> {
+    pub fn new(items: &'a [ImportItem]) -> Self {
+        let mut index = HashMap::with_capacity(items.len());
+        for item in items {
+            index
+                .entry((item.namespace(), item.name().as_str()))
+                .or_insert(item);
+        }
+
+        ImportIndex { items: index }
+    }
+
+    /// The checkfile import naming exactly this namespace and function.
+    pub fn get(&self, namespace: &str, name: &str) -> Option<&'a ImportItem> {
+        self.items.get(&(Some(namespace), name)).copied()
+    }
+}
@@ -1,109 +1,266 @@
 #![allow(suspicious_double_ref_op)]
 
 #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use std::{collections::BTreeMap, fmt::Display, process::ExitCode};
 
 #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-use comfy_table::{modifiers::UTF8_SOLID_INNER_BORDERS, presets::UTF8_FULL, Row, Table};
-#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-use extism::Plugin;
+use comfy_table::Row;
 #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 use extism_convert::Protobuf;
 
-use modsurfer_convert::from_api;
 use modsurfer_proto_v1::api::Module as ApiModule;
+use timings::Stopwatch;
 
-use anyhow::Result;
-use human_bytes::human_bytes;
+use anyhow::{Context, Result};
 use parse_size::parse_size;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+mod advice;
+mod baseline;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod budget;
+mod checkfile;
+mod checks;
+mod compare;
 mod diff;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod envelope;
+mod graph;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod hooks;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod http;
+mod index;
+mod link;
+mod overrides;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod parser;
+mod plan;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod plugin;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod policy_test;
+mod proto;
+pub mod registry;
+mod rules;
+mod sarif;
+mod schema;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod table;
+mod timings;
+mod upgrade;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod validator;
+mod verdict;
+mod vuln;
+mod wit;
 
+pub use advice::{Advice, Suggestion};
+pub use baseline::{Baseline, KnownFailure};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use budget::{validate_module_within, MemoryBudget, Skipped};
+pub use checkfile::Checkfile;
+pub use compare::{Comparison, Direction, PolicyChange};
 pub use diff::Diff;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use envelope::{CheckfileIdentity, ReportEnvelope, ToolIdentity};
+pub use graph::{FunctionNode, ModuleNode, NamespaceGraph, NamespaceNode};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use hooks::{run_generate_hooks, run_report_hooks};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use http::{http_client, proxy, set_proxy};
+pub use link::{link_check, Host};
+pub use modsurfer_module::{Capability, FunctionHashes, RiskLevel};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use parser::{ParseOptions, Parser};
+pub use plan::{Plan, PlannedCheck};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use plugin::{LocalPlugin, PluginError, PluginLimits};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use policy_test::{
+    test_policy, Expectation, Fixture, FixtureResult, PolicyTestReport, PolicyTests,
+};
+pub use registry::{register_rule, RuleConfig, RuleRegistry};
+pub use rules::{find_rule, Rule, RULES};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use schema::{check_checkfile, schema_errors, SchemaError};
+pub use schema::{checkfile_schema, set_strict_checkfile, strict_checkfile};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use table::{new_table, set_table_style, table_style, TableStyle};
+pub use timings::{peak_memory, CheckTiming, Timings};
+pub use upgrade::upgrade_check;
+pub use verdict::{ClassificationSummary, FailurePolicy, Verdict};
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use vuln::find_vulnerabilities;
+pub use vuln::{Finding, Findings, Severity, OSV_DB};
+pub use wit::{validate_world, World};
 
-#[derive(Debug, Deserialize, Default, Serialize)]
+#[derive(Clone, Debug, Deserialize, Default, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Validation {
     pub validate: Check,
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Default, Serialize)]
+#[derive(Clone, Debug, Deserialize, Default, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Check {
     pub url: Option<String>,
+    /// the hex-encoded SHA-256 hash which the checkfile fetched from `url` must have
+    pub url_sha256: Option<String>,
     pub allow_wasi: Option<bool>,
     pub imports: Option<Imports>,
     pub exports: Option<Exports>,
     pub size: Option<Size>,
     pub complexity: Option<Complexity>,
+    pub risk: Option<Risk>,
+    pub capabilities: Option<Capabilities>,
+    pub licenses: Option<Licenses>,
+    pub vulnerabilities: Option<Vulnerabilities>,
+    pub integrity: Option<Integrity>,
+    pub module_name: Option<ModuleName>,
+    pub location: Option<Location>,
+    /// plugins which check the module with code of their own, see `ValidatorPlugin`
+    pub plugins: Option<Vec<ValidatorPlugin>>,
+    /// plugins run once the module is validated, see `Hooks`
+    pub hooks: Option<Hooks>,
+    /// options for rules added with `register_rule`, keyed by rule name
+    #[schemars(with = "Option<BTreeMap<String, serde_json::Value>>")]
+    pub rules: Option<BTreeMap<String, serde_yaml::Value>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
-pub enum RiskLevel {
-    #[serde(rename = "low")]
-    Low,
-    #[serde(rename = "medium")]
-    Medium,
-    #[serde(rename = "high")]
-    High,
-}
-
-/// The output of the "Cyclomatic Complexity" algorithm run on a graph analysis of the WebAssembly
-/// code inside the provided module. The risk is purely related to computational resource usage,
-/// not code security or any other interpretation of risk.
-impl RiskLevel {
-    fn max(&self) -> u32 {
-        match self {
-            RiskLevel::Low => std::env::var("MODSURFER_RISK_LOW")
-                .unwrap_or(2500.to_string())
-                .parse::<u32>()
-                .expect("valid low risk level setting"),
-            RiskLevel::Medium => std::env::var("MODSURFER_RISK_MEDIUM")
-                .unwrap_or(50000.to_string())
-                .parse::<u32>()
-                .expect("valid medium risk level setting"),
-            RiskLevel::High => std::env::var("MODSURFER_RISK_HIGH")
-                .unwrap_or(u32::MAX.to_string())
-                .parse::<u32>()
-                .expect("valid high risk level setting"),
-        }
-    }
+pub struct Complexity {
+    pub max_risk: Option<RiskLevel>,
+    pub max_score: Option<u32>,
 }
 
-impl From<u32> for RiskLevel {
-    fn from(value: u32) -> Self {
-        if value <= RiskLevel::Low.max() {
-            RiskLevel::Low
-        } else if value <= RiskLevel::Medium.max() {
-            RiskLevel::Medium
-        } else {
-            RiskLevel::High
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Risk {
+    /// the largest composite risk score allowed, from 0 to 100, see `Module::risk_score`
+    pub max_score: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Capabilities {
+    /// capabilities the module's imports must not grant, see `Module::capabilities`
+    pub deny: Option<Vec<Capability>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Licenses {
+    /// SPDX identifiers of licenses which must not be found in the module, e.g. `GPL-3.0`, which
+    /// also denies variants such as `GPL-3.0-only`. See `Module::provenance`.
+    pub deny: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Integrity {
+    /// whether the module may import the same function (by namespace and name) more than once
+    /// with different signatures
+    pub allow_duplicate_imports: Option<bool>,
+    /// whether the module may export more than one function with the same name, which makes it
+    /// invalid
+    pub allow_duplicate_exports: Option<bool>,
+    /// whether the module may have structural problems, see `Module::integrity`. Unlike other
+    /// checks, these are made unless this is true.
+    pub allow_malformed: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ModuleName {
+    /// a regular expression which the whole file name of the module's location must match, e.g.
+    /// `[a-z][a-z0-9-]*\.wasm`. Only checked when the module's location is known, e.g. by `create`.
+    pub pattern: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Location {
+    /// the URL schemes the module's location may use, e.g. `s3` or `oci`. Only checked when the
+    /// module's location is known, e.g. by `create`.
+    pub allowed_schemes: Option<Vec<String>>,
+}
+
+/// A validator plugin: a WebAssembly module whose `validate` function is called for each module
+/// validated, reporting failures through the host functions described in `validator`. Plugins
+/// run on this machine, without WASI, so they are not checked by a Modsurfer server nor in the
+/// browser.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorPlugin {
+    /// the path of the plugin's wasm, relative to the current directory
+    pub path: String,
+    /// the name the plugin's failures are reported under, as `plugins.<name>.<property>`,
+    /// defaulting to the file stem of `path`
+    pub name: Option<String>,
+    /// the hex-encoded SHA-256 hash which the plugin's wasm must have
+    pub sha256: Option<String>,
+}
+
+impl ValidatorPlugin {
+    pub fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => std::path::Path::new(&self.path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| self.path.clone()),
         }
     }
 }
 
-impl Display for RiskLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(match self {
-            RiskLevel::Low => "low",
-            RiskLevel::Medium => "medium",
-            RiskLevel::High => "high",
-        })
-    }
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Hooks {
+    /// plugins given the report of each module validated, which may replace it
+    pub post_report: Option<Vec<ReportHook>>,
 }
 
+/// A plugin exporting a `post_report` function, called with the report once a module is
+/// validated, see `run_report_hooks`.
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
-pub struct Complexity {
-    pub max_risk: Option<RiskLevel>,
-    pub max_score: Option<u32>,
+pub struct ReportHook {
+    /// the path of the hook's wasm, relative to the current directory
+    pub path: String,
+    /// the hex-encoded SHA-256 hash which the hook's wasm must have
+    pub sha256: Option<String>,
+    /// the hosts the hook may make HTTP requests to, e.g. to file tickets. Hooks have no other
+    /// access to the network.
+    pub allowed_hosts: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Vulnerabilities {
+    /// the most severe known vulnerability allowed in the libraries found in the module. Checked
+    /// only by `validate_module` and the other functions which read the module from disk, since
+    /// it queries an advisory database.
+    pub max_severity: Option<Severity>,
+    /// the advisory database to query: `osv` (the default) or a path to a local OSV database
+    pub db: Option<String>,
 }
 
 #[allow(unused)]
@@ -114,7 +271,7 @@ pub enum ComplexityKind {
 
 impl Complexity {
     fn kind(&self) -> Result<ComplexityKind> {
-        match (self.max_risk.clone(), self.max_score) {
+        match (self.max_risk, self.max_score) {
             (None, None) => anyhow::bail!("No complexity check found."),
             (None, Some(_score)) => {
                 anyhow::bail!("Only `complexity.max_risk` is currently supported.")
@@ -128,7 +285,7 @@ impl Complexity {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
 pub enum NamespaceItem {
@@ -137,6 +294,11 @@ pub enum NamespaceItem {
         name: String,
         #[serde(default)]
         functions: Vec<FunctionItem>,
+        /// the most functions which may be imported from the namespace, under `include`
+        max_functions: Option<u32>,
+        /// under `include`, reject any function imported from the namespace which is not listed
+        /// in `functions`
+        forbid_unknown_functions: Option<bool>,
     },
 }
 
@@ -154,10 +316,27 @@ impl NamespaceItem {
             NamespaceItem::Item { functions, .. } => functions,
         }
     }
+
+    fn max_functions(&self) -> Option<u32> {
+        match self {
+            NamespaceItem::Name(_) => None,
+            NamespaceItem::Item { max_functions, .. } => *max_functions,
+        }
+    }
+
+    fn forbid_unknown_functions(&self) -> bool {
+        match self {
+            NamespaceItem::Name(_) => false,
+            NamespaceItem::Item {
+                forbid_unknown_functions,
+                ..
+            } => forbid_unknown_functions.unwrap_or(false),
+        }
+    }
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum ImportItem {
@@ -201,7 +380,7 @@ impl ImportItem {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum FunctionItem {
@@ -245,7 +424,7 @@ impl FunctionItem {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Namespace {
     pub include: Option<Vec<NamespaceItem>>,
@@ -253,16 +432,71 @@ pub struct Namespace {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Imports {
     pub include: Option<Vec<ImportItem>>,
     pub exclude: Option<Vec<ImportItem>>,
     pub namespace: Option<Namespace>,
+    /// how `include` is checked, `allow_only` if not set
+    pub mode: Option<IncludeMode>,
+    /// the most namespaces the module may import from
+    pub max_namespaces: Option<u32>,
+    /// the most functions the module may import from any one namespace
+    pub max_per_namespace: Option<u32>,
+}
+
+/// The meaning of `imports.include`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IncludeMode {
+    /// every function listed must be imported by the module, as with `exports.include`
+    RequireAll,
+    /// the module may import only the functions listed
+    #[default]
+    AllowOnly,
+    /// the module must import exactly the functions listed
+    Both,
+}
+
+impl IncludeMode {
+    pub fn requires(self) -> bool {
+        matches!(self, IncludeMode::RequireAll | IncludeMode::Both)
+    }
+
+    pub fn allows(self) -> bool {
+        matches!(self, IncludeMode::AllowOnly | IncludeMode::Both)
+    }
+}
+
+impl Display for IncludeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            IncludeMode::RequireAll => "require_all",
+            IncludeMode::AllowOnly => "allow_only",
+            IncludeMode::Both => "both",
+        })
+    }
+}
+
+impl std::str::FromStr for IncludeMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "require_all" => Ok(IncludeMode::RequireAll),
+            "allow_only" => Ok(IncludeMode::AllowOnly),
+            "both" => Ok(IncludeMode::Both),
+            _ => anyhow::bail!(
+                "unknown imports.include mode `{}`, expected `require_all`, `allow_only` or `both`",
+                s
+            ),
+        }
+    }
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Exports {
     pub include: Option<Vec<FunctionItem>>,
@@ -271,17 +505,68 @@ pub struct Exports {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Size {
-    pub max: Option<String>,
+    pub max: Option<SizeLimit>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// A size in a checkfile: an exact number of bytes (as written by `generate`), or a human-readable
+/// size such as `4MB` or `1.5MiB`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SizeLimit {
+    Bytes(u64),
+    Human(String),
+}
+
+impl SizeLimit {
+    /// The limit in bytes, failing if a human-readable size can't be parsed.
+    pub fn bytes(&self) -> Result<u64> {
+        match self {
+            SizeLimit::Bytes(bytes) => Ok(*bytes),
+            SizeLimit::Human(size) => {
+                parse_size(size).map_err(|e| anyhow::anyhow!("invalid size `{}`: {}", size, e))
+            }
+        }
+    }
+}
+
+impl Display for SizeLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SizeLimit::Bytes(bytes) => write!(f, "{} bytes", bytes),
+            SizeLimit::Human(size) => f.write_str(size),
+        }
+    }
+}
+
+impl From<u64> for SizeLimit {
+    fn from(bytes: u64) -> Self {
+        SizeLimit::Bytes(bytes)
+    }
+}
+
+impl From<&str> for SizeLimit {
+    fn from(size: &str) -> Self {
+        SizeLimit::Human(size.to_string())
+    }
+}
+
+impl From<String> for SizeLimit {
+    fn from(size: String) -> Self {
+        SizeLimit::Human(size)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub enum Classification {
     AbiCompatibilty,
     ResourceLimit,
     Security,
+    Licensing,
+    Integrity,
+    Identity,
 }
 
 impl Display for Classification {
@@ -290,6 +575,9 @@ impl Display for Classification {
             Classification::AbiCompatibilty => f.write_str("ABI Compatibility")?,
             Classification::ResourceLimit => f.write_str("Resource Limit")?,
             Classification::Security => f.write_str("Security")?,
+            Classification::Licensing => f.write_str("Licensing")?,
+            Classification::Integrity => f.write_str("Integrity")?,
+            Classification::Identity => f.write_str("Identity")?,
         }
         Ok(())
     }
@@ -307,14 +595,42 @@ pub struct FailureDetail {
 pub struct Report {
     /// k/v pair of the dot-separated path to validation field and expectation info
     pub fails: BTreeMap<String, FailureDetail>,
+    /// the module which was validated, kept out of the report itself and written in a
+    /// `ReportEnvelope`
+    #[serde(skip)]
+    pub module: Option<ModuleIdentity>,
+}
+
+/// The facts identifying a validated module.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ModuleIdentity {
+    /// the hex-encoded SHA-256 hash of the module
+    pub hash: String,
+    /// the name of the file the module was read from, if known
+    pub file_name: Option<String>,
+    /// size in bytes of the module
+    pub size: u64,
+    pub source_language: modsurfer_module::SourceLanguage,
+}
+
+impl ModuleIdentity {
+    pub fn new(module: &modsurfer_module::Module) -> Self {
+        let file_name = module.file_name();
+        ModuleIdentity {
+            hash: module.hash.clone(),
+            file_name: (!file_name.is_empty()).then_some(file_name),
+            size: module.size,
+            source_language: module.source_language.clone(),
+        }
+    }
 }
 
 impl Report {
+    /// The exit code for this report when any failure fails validation. Use a `Verdict` to apply
+    /// a severity threshold or a different failure policy.
     pub fn as_exit_code(&self) -> ExitCode {
-        match self.fails.len() {
-            0 => ExitCode::SUCCESS,
-            _ => ExitCode::FAILURE,
-        }
+        Verdict::new(self).exit_code()
     }
 
     pub fn has_failures(&self) -> bool {
@@ -329,10 +645,7 @@ impl Display for Report {
             return Ok(());
         }
 
-        let mut table = Table::new();
-        table.load_preset(UTF8_FULL);
-        table.apply_modifier(UTF8_SOLID_INNER_BORDERS);
-        table.set_header(vec![
+        let mut table = new_table(vec![
             "Status",
             "Property",
             "Expected",
@@ -367,14 +680,17 @@ impl Report {
     fn new() -> Self {
         Self {
             fails: Default::default(),
+            module: None,
         }
     }
 
+    // the property name and the expected and actual values are only formatted if the check
+    // fails, so that validating a module which passes allocates next to nothing
     fn validate_fn(
         &mut self,
-        name: &str,
-        expected: String,
-        actual: String,
+        name: impl Display,
+        expected: impl Display,
+        actual: impl Display,
         valid: bool,
         severity: usize,
         classification: Classification,
@@ -383,8 +699,8 @@ impl Report {
             self.fails.insert(
                 name.to_string(),
                 FailureDetail {
-                    actual,
-                    expected,
+                    actual: actual.to_string(),
+                    expected: expected.to_string(),
                     severity,
                     classification,
                 },
@@ -392,56 +708,41 @@ impl Report {
         }
     }
 
-    fn validate_fn_hash(&mut self, name: &str, expected: String, actual: Option<String>) {
-        if let Some(actual) = actual.clone() {
-            let test = expected == actual;
-            self.validate_fn(
-                name,
-                expected,
-                actual,
-                test,
-                7,
-                Classification::AbiCompatibilty,
-            );
-        } else {
-            self.fails.insert(
-                name.to_string(),
-                FailureDetail {
-                    actual: actual.unwrap_or_else(|| String::from("<NONE>")),
-                    expected,
-                    severity: 7,
-                    classification: Classification::AbiCompatibilty,
-                },
-            );
-        }
+    fn validate_fn_hash(&mut self, name: impl Display, expected: &str, actual: Option<&str>) {
+        self.validate_fn(
+            name,
+            expected,
+            actual.unwrap_or("<NONE>"),
+            actual == Some(expected),
+            7,
+            Classification::AbiCompatibilty,
+        );
     }
 
     fn validate_fn_type(
         &mut self,
-        name: &str,
+        name: impl Display,
         actual: &modsurfer_module::FunctionType,
         params: Option<&[modsurfer_module::ValType]>,
         results: Option<&[modsurfer_module::ValType]>,
     ) {
         if let Some(expected) = params {
-            let test_params = actual.params == expected;
             self.validate_fn(
-                &format!("{name}.params"),
-                format!("{:?}", expected),
-                format!("{:?}", actual.params),
-                test_params,
+                format_args!("{name}.params"),
+                ValTypes(expected),
+                ValTypes(&actual.params),
+                actual.params == expected,
                 8,
                 Classification::AbiCompatibilty,
             );
         };
 
         if let Some(expected) = results {
-            let test_results = actual.results == expected;
             self.validate_fn(
-                &format!("{name}.results"),
-                format!("{:?}", expected),
-                format!("{:?}", actual.results),
-                test_results,
+                format_args!("{name}.results"),
+                ValTypes(expected),
+                ValTypes(&actual.results),
+                actual.results == expected,
                 8,
                 Classification::AbiCompatibilty,
             );
@@ -449,6 +750,22 @@ impl Report {
     }
 }
 
+// a list of value types, displayed like `modsurfer_module::display_val_types`
+struct ValTypes<'a>(&'a [modsurfer_module::ValType]);
+
+impl Display for ValTypes<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(")?;
+        for (i, ty) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{ty}")?;
+        }
+        f.write_str(")")
+    }
+}
+
 struct Exist(bool);
 
 impl Display for Exist {
@@ -483,488 +800,355 @@ impl Module {
     // The source code to the WebAssembly binary is not open source.
     //
     // Importantly, this code has no side-effects, and uses no system resources. The `false`
-    // parameter provided to `Plugin::new` below, ensures that the module is run without functions
-    // provided by the WASI module namespace. Therefore it has no access to your running environment
-    // nor any system resources such as a filesystem or network.
+    // parameter provided to `Plugin::new` (see `Parser`), ensures that the module is run without
+    // functions provided by the WASI module namespace. Therefore it has no access to your running
+    // environment nor any system resources such as a filesystem or network.
     //
     // The function within the WebAssembly, "parse_module", only parses bytes provided to it from
     // the host context (the `wasm`), and collects parsed information into the `Module` which is
     // returned as a protobuf-encoded struct.
     pub fn parse(wasm: impl AsRef<[u8]>) -> Result<modsurfer_module::Module> {
-        let mut plugin: ModuleParser =
-            Plugin::new(modsurfer_plugins::MODSURFER_WASM, [], false)?.try_into()?;
-        let Protobuf(data) = plugin.parse_module(wasm.as_ref())?;
-        let metadata = if data.metadata.is_empty() {
-            None
-        } else {
-            Some(data.metadata)
-        };
-
-        let inserted_at: std::time::SystemTime = data
-            .inserted_at
-            .unwrap_or_else(|| protobuf::well_known_types::timestamp::Timestamp::new())
-            .into();
-
-        let module = modsurfer_module::Module {
-            hash: data.hash,
-            imports: from_api::imports(data.imports),
-            exports: from_api::exports(data.exports),
-            size: data.size as u64,
-            location: data.location,
-            source_language: from_api::source_language(
-                data.source_language.enum_value_or_default(),
-            ),
-            metadata,
-            inserted_at: inserted_at.into(),
-            strings: data.strings,
-            complexity: data.complexity,
-            graph: data.graph,
-            function_hashes: data.function_hashes,
-        };
+        Module::parse_with(wasm, FunctionHashes::default())
+    }
 
-        Ok(module)
+    /// Parse a module, computing the hashes of the functions selected by `function_hashes` if the
+    /// parser did not provide any. Plugins are reused across calls, see `Parser::shared`.
+    pub fn parse_with(
+        wasm: impl AsRef<[u8]>,
+        function_hashes: FunctionHashes,
+    ) -> Result<modsurfer_module::Module> {
+        Parser::shared().parse_with(wasm, function_hashes)
     }
-}
 
-fn namespace_prefix(import_item: &ImportItem, fn_name: &str) -> String {
-    match import_item.namespace() {
-        Some(ns) => format!("{}::{}", ns, fn_name),
-        None => fn_name.into(),
+    /// Read and parse the module at `path`, naming it in the `PluginError` of a failed parse.
+    pub fn parse_file(path: impl AsRef<Path>) -> Result<modsurfer_module::Module> {
+        let path = path.as_ref();
+        let wasm =
+            std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+        plugin::in_module(Module::parse(wasm), path)
     }
 }
 
-pub fn validate(validation: Validation, module: modsurfer_module::Module) -> Result<Report> {
-    let mut report = Report::new();
-
-    // WASI
-    if let Some(allowed) = validation.validate.allow_wasi {
-        let actual = module
-            .get_import_namespaces()
-            .contains(&"wasi_snapshot_preview1");
-        report.validate_fn(
-            "allow_wasi",
-            allowed.to_string(),
-            actual.to_string(),
-            !(allowed == false && actual),
-            10,
-            Classification::AbiCompatibilty,
-        );
+/// The context of an error from parsing a module, so that callers can tell invalid modules from
+/// other failures (e.g. with `anyhow::Error::is::<InvalidModule>()`).
+#[derive(Debug)]
+pub struct InvalidModule;
+
+impl Display for InvalidModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("failed to parse module")
     }
+}
 
-    // Imports
-    if let Some(imports) = validation.validate.imports {
-        let actual_import_module_func_types = module
-            .imports
-            .iter()
-            .map(|im| {
-                (
-                    (im.module_name.as_str(), im.func.name.as_str()),
-                    &im.func.ty,
-                )
-            })
-            .collect::<std::collections::BTreeMap<_, _>>();
-        let import_func_types = actual_import_module_func_types
-            .iter()
-            .map(|((_, k), ty)| (*k, ty.clone()))
-            .collect::<BTreeMap<_, _>>();
-
-        let import_module_names = module.get_import_namespaces();
-
-        // expect that all actual imports parsed from the module are within a subset of the import
-        // functions listed in the checkfile
-        if let Some(include) = imports.include {
-            actual_import_module_func_types.iter().for_each(
-                |((actual_namespace, actual_func_name), actual_func_ty)| {
-                    let actual_module_import = ImportItem::Item {
-                        namespace: Some(actual_namespace.to_string()),
-                        name: actual_func_name.to_string(),
-                        params: Some(actual_func_ty.params.clone()),
-                        results: Some(actual_func_ty.results.clone()),
-                    };
-
-                    // check that we have at minimum a match for name and namespace, use this module
-                    // to further check the params and results
-                    let found = include.iter().find(|checkfile_import| {
-                        checkfile_import.name() == actual_module_import.name()
-                            && checkfile_import.namespace() == actual_module_import.namespace()
-                    });
-                    if found.is_none() {
-                        report.validate_fn(
-                            &format!(
-                                "imports.include.{}",
-                                namespace_prefix(&actual_module_import, actual_func_name)
-                            ),
-                            Exist(false).to_string(),
-                            Exist(true).to_string(),
-                            false,
-                            10,
-                            Classification::AbiCompatibilty,
-                        );
-                    } else {
-                        // if an import _is_ contained in the checkfile, also validate that the
-                        // function type is equivalent to the expected type in the checkfile
-                        let checkfile_import = found.expect("module import must exist");
-                        report.validate_fn_type(
-                            &format!(
-                                "imports.include.{}",
-                                namespace_prefix(&actual_module_import, actual_func_name)
-                            ),
-                            &actual_func_ty,
-                            checkfile_import.params(),
-                            checkfile_import.results(),
-                        );
-                    }
-                },
-            );
-        }
+/// The error from parsing a module which took longer than the parser's timeout, see
+/// `Parser::set_timeout`.
+#[derive(Debug)]
+pub struct ParseTimeout(pub std::time::Duration);
 
-        if let Some(exclude) = imports.exclude {
-            exclude.iter().for_each(|imp| {
-                let name = imp.name();
-                let test = if let Some(ns) = imp.namespace() {
-                    actual_import_module_func_types.contains_key(&(ns, name))
-                } else {
-                    import_func_types.contains_key(&name.as_str())
-                };
-
-                let ty = if let Some(ns) = imp.namespace() {
-                    actual_import_module_func_types.get(&(ns, name))
-                } else {
-                    import_func_types.get(name.as_str())
-                };
-
-                if test {
-                    let ty = ty.unwrap();
-                    report.validate_fn_type(
-                        &format!("imports.exclude.{}", namespace_prefix(&imp, name)),
-                        *ty,
-                        imp.params(),
-                        imp.results(),
-                    );
-                };
-
-                report.validate_fn(
-                    &format!("imports.exclude.{}", namespace_prefix(&imp, name)),
-                    Exist(false).to_string(),
-                    Exist(test).to_string(),
-                    !test,
-                    5,
-                    Classification::AbiCompatibilty,
-                );
-            });
-        }
+impl Display for ParseTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parsing the module took longer than {:?}", self.0)
+    }
+}
 
-        if let Some(namespace) = imports.namespace {
-            if let Some(include) = namespace.include {
-                include.iter().for_each(|ns| {
-                    let name = ns.name();
-                    let functions = ns.functions();
-                    let test = import_module_names.contains(&name.as_str());
-                    report.validate_fn(
-                        &format!("imports.namespace.include.{}", name),
-                        Exist(true).to_string(),
-                        Exist(test).to_string(),
-                        test,
-                        8,
-                        Classification::AbiCompatibilty,
-                    );
-
-                    for f in functions.iter() {
-                        let test = actual_import_module_func_types
-                            .contains_key(&(name, f.name().as_str()));
-                        report.validate_fn(
-                            &format!("imports.namespace.include.{name}::{}", f.name()),
-                            Exist(true).to_string(),
-                            Exist(test).to_string(),
-                            test,
-                            8,
-                            Classification::AbiCompatibilty,
-                        );
-
-                        if test {
-                            let ty = actual_import_module_func_types
-                                .get(&(name, f.name().as_str()))
-                                .unwrap();
-                            report.validate_fn_type(
-                                &format!("imports.namespace.include.{name}::{}", f.name()),
-                                *ty,
-                                f.params(),
-                                f.results(),
-                            );
-                        }
-                    }
-                });
-            }
+impl std::error::Error for ParseTimeout {}
 
-            if let Some(exclude) = namespace.exclude {
-                exclude.iter().for_each(|ns| {
-                    let name = ns.name();
-                    let functions = ns.functions();
-                    let test = import_module_names.contains(&name.as_str());
-
-                    report.validate_fn(
-                        &format!("imports.namespace.exclude.{}", name),
-                        Exist(false).to_string(),
-                        Exist(test).to_string(),
-                        !test,
-                        10,
-                        Classification::AbiCompatibilty,
-                    );
-
-                    for f in functions.iter() {
-                        let test = actual_import_module_func_types
-                            .contains_key(&(name, f.name().as_str()));
-
-                        if test {
-                            let ty = actual_import_module_func_types
-                                .get(&(name, f.name().as_str()))
-                                .unwrap();
-
-                            report.validate_fn_type(
-                                &format!("imports.namespace.exclude.{name}::{}", f.name()),
-                                *ty,
-                                f.params(),
-                                f.results(),
-                            );
-                        };
-
-                        report.validate_fn(
-                            &format!("imports.namespace.exclude.{name}::{}", f.name()),
-                            Exist(false).to_string(),
-                            Exist(test).to_string(),
-                            !test,
-                            10,
-                            Classification::AbiCompatibilty,
-                        );
-                    }
-                });
-            }
-        }
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl modsurfer_module::ModuleParser for Module {
+    fn parse_bytes(&self, wasm: &[u8]) -> Result<modsurfer_module::Module> {
+        Module::parse(wasm)
     }
+}
 
-    // Exports
-    if let Some(exports) = validation.validate.exports {
-        let export_func_types = module
-            .exports
-            .iter()
-            .map(|im| (im.func.name.as_str(), &im.func.ty))
-            .collect::<std::collections::BTreeMap<_, _>>();
-
-        if let Some(max) = exports.max {
-            let num = export_func_types.len() as u32;
-            let overage = num.saturating_sub(max);
-            let max = if max == 0 { 1 } else { max };
-            let severity = ((overage as f32 / max as f32) * 10.0).ceil() as usize;
-            let test = num <= max;
-            report.validate_fn(
-                "exports.max",
-                format!("<= {max}"),
-                num.to_string(),
-                test,
-                severity,
-                Classification::Security,
-            );
-        }
+fn namespace_prefix<'a>(import_item: &'a ImportItem, fn_name: &'a str) -> NamespacePrefix<'a> {
+    NamespacePrefix(import_item.namespace(), fn_name)
+}
 
-        if let Some(include) = exports.include {
-            include.iter().for_each(|f| {
-                let name = f.name();
-                let test = export_func_types.contains_key(name.as_str());
-                report.validate_fn(
-                    &format!("exports.include.{}", name),
-                    Exist(true).to_string(),
-                    Exist(test).to_string(),
-                    test,
-                    10,
-                    Classification::AbiCompatibilty,
-                );
-
-                if test {
-                    let ty = export_func_types.get(name.as_str()).unwrap();
-                    report.validate_fn_type(
-                        &format!("exports.include.{}", name),
-                        *ty,
-                        f.params(),
-                        f.results(),
-                    );
-                }
-
-                if let Some(hash) = f.hash() {
-                    report.validate_fn_hash(
-                        &format!("exports.hash.{}", name),
-                        hash.to_string(),
-                        module.function_hashes.get(name).map(|x| x.clone()),
-                    );
-                }
-            });
-        }
+// a function name, prefixed by its namespace if it has one, e.g. `env::log`
+struct NamespacePrefix<'a>(Option<&'a str>, &'a str);
 
-        if let Some(exclude) = exports.exclude {
-            exclude.iter().for_each(|f| {
-                let name = f.name();
-
-                let ty = export_func_types.get(name.as_str());
-                let test = ty.is_some();
-                if test {
-                    let ty = ty.unwrap();
-                    report.validate_fn_type(
-                        &format!("exports.include.{}", name),
-                        *ty,
-                        f.params(),
-                        f.results(),
-                    );
-                }
-
-                report.validate_fn(
-                    &format!("exports.exclude.{}", name),
-                    Exist(false).to_string(),
-                    Exist(test).to_string(),
-                    !test,
-                    5,
-                    Classification::AbiCompatibilty,
-                );
-            });
+impl Display for NamespacePrefix<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(ns) => write!(f, "{}::{}", ns, self.1),
+            None => f.write_str(self.1),
         }
     }
+}
 
-    // Size
-    if let Some(size) = validation.validate.size {
-        if let Some(max) = size.max {
-            let parsed = parse_size(&max).unwrap();
-            let human_actual = human_bytes(module.size as f64);
-            let test = module.size <= parsed;
-            report.validate_fn(
-                "size.max",
-                format!("<= {max}"),
-                human_actual.to_string(),
-                test,
-                (module.size / parsed) as usize,
-                Classification::ResourceLimit,
-            );
-        }
-    }
+pub fn validate(validation: Validation, module: modsurfer_module::Module) -> Result<Report> {
+    validate_checks(validation, module, &mut Stopwatch::disabled())
+}
 
-    // Complexity
-    if let Some(complexity) = validation.validate.complexity {
-        let module_complexity = module.complexity.ok_or_else(|| anyhow::anyhow!("Could not determine module complexity, please remove the complexity parameter from your checkfile."))?;
-        match complexity.kind()? {
-            ComplexityKind::MaxRisk(risk) => {
-                report.validate_fn(
-                    "complexity.max_risk",
-                    format!("<= {}", risk),
-                    RiskLevel::from(module_complexity).to_string(),
-                    risk.max() >= module_complexity,
-                    (module_complexity / risk.max()) as usize,
-                    Classification::ResourceLimit,
-                );
-            }
-            _ => unreachable!(),
-        }
-    }
+/// Validate a module, recording the time taken by each category of checks.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub fn validate_timed(
+    validation: Validation,
+    module: modsurfer_module::Module,
+) -> Result<(Report, Vec<CheckTiming>)> {
+    let mut stopwatch = Stopwatch::start();
+    let report = validate_checks(validation, module, &mut stopwatch)?;
+
+    Ok((report, stopwatch.laps()))
+}
+
+fn validate_checks(
+    validation: Validation,
+    module: modsurfer_module::Module,
+    stopwatch: &mut Stopwatch,
+) -> Result<Report> {
+    let mut report =
+        RuleRegistry::current().validate_with(&validation.validate, &module, stopwatch)?;
+    report.module = Some(ModuleIdentity::new(&module));
 
     Ok(report)
 }
 
 #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-pub async fn validate_module(file: &PathBuf, check: &PathBuf) -> Result<Report> {
+pub async fn validate_module(file: &Path, check: &PathBuf) -> Result<Report> {
+    validate_module_with(file, check, &[]).await
+}
+
+/// Validate a module like `validate_module`, first overriding fields of the checkfile with each
+/// `(path, value)` pair. See `Validation::set`.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn validate_module_with(
+    file: &Path,
+    check: &PathBuf,
+    overrides: &[(String, String)],
+) -> Result<Report> {
+    let (validation, _) = resolve_checkfile(check, overrides).await?;
+
     // read the wasm file and parse a Module from it to later validate against the check file.
     // NOTE: the Module is produced by executing plugin code, linked and called from the
-    // `Module::parse` function.
-    let module_data = tokio::fs::read(file).await?;
-    let module = Module::parse(&module_data)?;
+    // `Module::parse` function, which takes and drops the wasm bytes once they are parsed.
+    let module = read_for_checkfile(file, &validation).await?;
 
-    let mut buf = tokio::fs::read(check).await?;
+    validate_parsed(validation, module).await
+}
 
-    let mut validation: Validation = serde_yaml::from_slice(&buf)?;
+/// Validate a module like `validate_module`, as if it were stored at `location` (a URL, or a path
+/// on disk), so that the checkfile's `module_name` and `location` checks apply. Used to validate a
+/// module before it is created.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn validate_module_at(file: &Path, check: &PathBuf, location: &str) -> Result<Report> {
+    let (validation, _) = resolve_checkfile(check, &[]).await?;
+    let mut module = read_for_checkfile(file, &validation).await?;
+    module.location = location.to_string();
 
-    if let Some(url) = validation.validate.url {
-        // fetch remote validation file
-        println!("Fetching validation schema from URL: {}", url);
+    validate_parsed(validation, module).await
+}
 
-        let resp = reqwest::get(&url).await?;
-        if !resp.status().is_success() {
-            anyhow::bail!(
-                "Failed to make request for remote validation schema: {}",
-                url
-            );
-        }
+// validate a parsed module, then look up vulnerabilities in the libraries found in it, and run the
+// checkfile's report hooks
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) async fn validate_parsed(
+    validation: Validation,
+    module: modsurfer_module::Module,
+) -> Result<Report> {
+    let vulnerabilities = validation.validate.vulnerabilities.clone();
+    let hooks = report_hooks(&validation);
+    let libraries = module.provenance.libraries.clone();
+
+    let mut report = validate(validation, module)?;
+    if let Some(Vulnerabilities {
+        max_severity: Some(max),
+        db,
+    }) = vulnerabilities
+    {
+        let db = db.as_deref().unwrap_or(OSV_DB);
+        vuln::check_vulnerabilities(&mut report, max, db, &libraries).await?;
+    }
 
-        buf.clear();
-        buf = resp.bytes().await?.into();
+    run_report_hooks(&hooks, report)
+}
 
-        // parse the file again & reassign `validation`
-        validation = serde_yaml::from_slice(&buf)?;
+// the checkfile's `hooks.post_report`, taken before the checkfile is consumed by `validate`
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn report_hooks(validation: &Validation) -> Vec<ReportHook> {
+    validation
+        .validate
+        .hooks
+        .as_ref()
+        .and_then(|hooks| hooks.post_report.clone())
+        .unwrap_or_default()
+}
+
+/// Validate a module like `validate_module`, from the contents of the module and checkfile rather
+/// than their paths, e.g. to embed validation in another program.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn validate_module_bytes(wasm: Vec<u8>, checkfile: &[u8]) -> Result<Report> {
+    let (validation, _) = resolve_checkfile_bytes(checkfile, &[]).await?;
+    let module = parse_for_checkfile(wasm, &validation)?;
+
+    validate_parsed(validation, module).await
+}
+
+/// Validate a module like `validate_module_with`, measuring how long each stage takes.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn validate_module_timed(
+    file: &Path,
+    check: &PathBuf,
+    overrides: &[(String, String)],
+) -> Result<(Report, Timings)> {
+    let start = std::time::Instant::now();
+    let (validation, url) = resolve_checkfile(check, overrides).await?;
+    let checkfile = start.elapsed();
+    let fetched = url.is_some();
+
+    let module = read_for_checkfile(file, &validation).await?;
+    let parse = start.elapsed() - checkfile;
+    let vulnerabilities = validation.validate.vulnerabilities.clone();
+    let hooks = report_hooks(&validation);
+    let libraries = module.provenance.libraries.clone();
+
+    let (mut report, mut checks) = validate_timed(validation, module)?;
+    if let Some(Vulnerabilities {
+        max_severity: Some(max),
+        db,
+    }) = vulnerabilities
+    {
+        let lookup = std::time::Instant::now();
+        let db = db.as_deref().unwrap_or(OSV_DB);
+        vuln::check_vulnerabilities(&mut report, max, db, &libraries).await?;
+        checks.push(CheckTiming {
+            category: "vulnerabilities",
+            duration: lookup.elapsed(),
+        });
+    }
+    if !hooks.is_empty() {
+        let hooked = std::time::Instant::now();
+        report = run_report_hooks(&hooks, report)?;
+        checks.push(CheckTiming {
+            category: "hooks",
+            duration: hooked.elapsed(),
+        });
     }
 
+    Ok((
+        report,
+        Timings {
+            parse,
+            checkfile,
+            fetched,
+            checks,
+            total: start.elapsed(),
+            peak_memory: peak_memory(),
+        },
+    ))
+}
+
+/// Validate a module against a snapshot of an approved build (as written by `modsurfer snapshot`),
+/// using the checkfile `generate_checkfile` produces for the approved build: the module must have
+/// the same imports, exports and exported function bodies, and stay within its size and
+/// complexity.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn validate_module_against(file: &Path, snapshot: &PathBuf) -> Result<Report> {
+    let approved = read_snapshot(snapshot).await?;
+    let validation = generate_checkfile(&approved)?;
+    let module = read_for_checkfile(file, &validation).await?;
+
     validate(validation, module)
 }
 
+// parse a module, skipping the facts which aren't needed to validate it against the checkfile.
+// Only exported functions are checked by their hash, so no others are hashed.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) fn parse_for_checkfile(
+    wasm: Vec<u8>,
+    validation: &Validation,
+) -> Result<modsurfer_module::Module> {
+    let parser = Parser::shared();
+    let options = ParseOptions::for_checkfile(validation).intersect(parser.options());
+    parser.parse_with_options(wasm, FunctionHashes::Exports, options)
+}
+
+// read and parse a module like `parse_for_checkfile`, naming it in any error from the parser
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) async fn read_for_checkfile(
+    file: &Path,
+    validation: &Validation,
+) -> Result<modsurfer_module::Module> {
+    let wasm = tokio::fs::read(file)
+        .await
+        .with_context(|| format!("failed to read {}", file.display()))?;
+    plugin::in_module(parse_for_checkfile(wasm, validation), file)
+}
+
+/// Read the facts parsed from a module, written by `modsurfer snapshot`.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn read_snapshot(snapshot: &PathBuf) -> Result<modsurfer_module::Module> {
+    let buf = tokio::fs::read(snapshot).await?;
+    serde_json::from_slice(&buf).with_context(|| format!("invalid snapshot {}", snapshot.display()))
+}
+
+/// Read a checkfile, replacing it with the remote checkfile at its `url` if it has one, and list
+/// the checks it makes.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn plan_checkfile(check: &PathBuf) -> Result<Plan> {
+    plan_checkfile_with(check, &[]).await
+}
+
+/// Plan a checkfile like `plan_checkfile`, then override its fields with each `(path, value)`
+/// pair. See `Validation::set`.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn plan_checkfile_with(check: &PathBuf, overrides: &[(String, String)]) -> Result<Plan> {
+    let (validation, url) = resolve_checkfile(check, overrides).await?;
+    let mut plan = Plan::new(check.display().to_string(), url, validation)?;
+    plan.overrides = overrides
+        .iter()
+        .map(|(path, value)| format!("{}={}", path, value))
+        .collect();
+
+    Ok(plan)
+}
+
+/// Read a checkfile, replacing it with the remote checkfile at its `url` if it has one, then
+/// override its fields with each `(path, value)` pair (see `Validation::set`). Returns the
+/// checkfile to validate against, and the URL it was fetched from, if any.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn resolve_checkfile(
+    check: &PathBuf,
+    overrides: &[(String, String)],
+) -> Result<(Validation, Option<String>)> {
+    resolve_checkfile_bytes(&tokio::fs::read(check).await?, overrides).await
+}
+
+/// Resolve a checkfile like `resolve_checkfile`, from its contents rather than its path.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn resolve_checkfile_bytes(
+    checkfile: &[u8],
+    overrides: &[(String, String)],
+) -> Result<(Validation, Option<String>)> {
+    if strict_checkfile() {
+        check_checkfile(checkfile)?;
+    }
+    let mut validation: Validation = serde_yaml::from_slice(checkfile)?;
+
+    let url = validation.validate.url.clone();
+    if let Some(url) = &url {
+        // fetch remote validation file, parse it & reassign `validation`
+        let buf = http::fetch_checkfile(url, validation.validate.url_sha256.as_deref()).await?;
+        if strict_checkfile() {
+            check_checkfile(&buf).with_context(|| format!("remote checkfile {}", url))?;
+        }
+        validation = serde_yaml::from_slice(&buf)?;
+    }
+
+    for (path, value) in overrides {
+        validation.set(path, value)?;
+    }
+
+    Ok((validation, url))
+}
+
+/// Generate a checkfile which the module passes. See `modsurfer_convert::to_checkfile`.
 pub fn generate_checkfile(module: &modsurfer_module::Module) -> Result<Validation> {
-    let mut validation = Validation::default();
-    let namespaces = module.get_import_namespaces();
-
-    // allow_wasi
-    if namespaces.contains(&"wasi_snapshot_preview1") {
-        validation.validate.allow_wasi = Some(true);
-    }
-
-    // imports (add all to include + namespace)
-    let mut imports = Imports::default();
-    let mut include_imports = vec![];
-    module.imports.iter().for_each(|imp| {
-        include_imports.push(ImportItem::Item {
-            namespace: Some(imp.module_name.clone()),
-            name: imp.func.name.clone(),
-            params: Some(imp.func.ty.params.clone()),
-            results: Some(imp.func.ty.results.clone()),
-        });
-    });
-    imports.include = Some(include_imports);
-
-    // imports.namespace (add all to imports)
-    let mut namespace = Namespace::default();
-    namespace.include = Some(
-        namespaces
-            .iter()
-            .map(|name| NamespaceItem::Name(name.to_string()))
-            .collect::<Vec<_>>(),
-    );
-    if !namespaces.is_empty() {
-        imports.namespace = Some(namespace);
-    }
-
-    // exports (add all exports)
-    let mut exports = Exports::default();
-    let mut include_exports = vec![];
-    module.exports.iter().for_each(|exp| {
-        include_exports.push(FunctionItem::Item {
-            name: exp.func.name.clone(),
-            params: Some(exp.func.ty.params.clone()),
-            results: Some(exp.func.ty.results.clone()),
-            hash: module.function_hashes.get(&exp.func.name).cloned(),
-        });
-    });
-    let export_count = include_exports.len();
-    exports.include = Some(include_exports);
-
-    // exports.max (match number of exports)
-    exports.max = Some(export_count as u32);
-
-    // size.max (use size from module)
-    let mut size = Size::default();
-    // add 10% padded size to max.size (ref: https://github.com/dylibso/modsurfer/issues/71)
-    let padded_size = module.size as f64 * 1.1;
-    size.max = Some(human_bytes(padded_size));
-
-    // complexity.max_risk (use complexity)
-    let mut complexity = Complexity::default();
-    complexity.max_risk = Some(RiskLevel::from(module.complexity.unwrap_or_default()));
-
-    validation.validate.url = None;
-    validation.validate.imports = Some(imports);
-    validation.validate.exports = Some(exports);
-    validation.validate.size = Some(size);
-    validation.validate.complexity = Some(complexity);
-
-    Ok(validation)
+    modsurfer_convert::to_checkfile(module).try_into()
+}
+
+/// Generate a checkfile which the module passes, with only namespace, size and complexity checks.
+/// See `modsurfer_convert::to_minimal_checkfile`.
+pub fn generate_minimal_checkfile(module: &modsurfer_module::Module) -> Result<Validation> {
+    modsurfer_convert::to_minimal_checkfile(module).try_into()
 }
@@ -0,0 +1,72 @@
+//! Link checks, which verify that every function a module imports is provided by its host (or by
+//! another module), with a matching signature, before the two are composed.
+use serde::{Deserialize, Serialize};
+
+use crate::{Classification, ImportItem, Report};
+
+/// The functions a host provides for modules to import, e.g. read from a `host.yaml`:
+///
+/// ```yaml
+/// provides:
+///   - namespace: env
+///     name: log
+///     params: [I32, I32]
+///     results: []
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Host {
+    /// functions provided, in the same form as `imports.include` in a checkfile. A function
+    /// without a namespace is provided in every namespace, and one without params or results is
+    /// provided with any signature.
+    pub provides: Vec<ImportItem>,
+}
+
+impl Host {
+    /// The functions exported by a module, provided in the namespace the importing module uses
+    /// for it, e.g. `env`.
+    pub fn from_module(module: &modsurfer_module::Module, namespace: &str) -> Self {
+        Host {
+            provides: module
+                .exports
+                .iter()
+                .map(|export| ImportItem::Item {
+                    namespace: Some(namespace.to_string()),
+                    name: export.func.name.clone(),
+                    params: Some(export.func.ty.params.clone()),
+                    results: Some(export.func.ty.results.clone()),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Check that every import of the module is provided by the host. Failures are reported under
+/// `link.<namespace>::<name>`.
+pub fn link_check(host: &Host, module: &modsurfer_module::Module) -> Report {
+    let mut report = Report::new();
+
+    module.imports.iter().for_each(|import| {
+        let name = &import.func.name;
+        let property = format!("link.{}::{}", import.module_name, name);
+
+        let provided = host.provides.iter().find(|item| {
+            item.name() == name && item.namespace().is_none_or(|ns| ns == import.module_name)
+        });
+        match provided {
+            Some(item) => {
+                report.validate_fn_type(&property, &import.func.ty, item.params(), item.results())
+            }
+            None => report.validate_fn(
+                &property,
+                "provided",
+                "not provided",
+                false,
+                10,
+                Classification::AbiCompatibilty,
+            ),
+        }
+    });
+
+    report
+}
@@ -0,0 +1,55 @@
+//! Overrides of individual checkfile fields, e.g. `size.max=6MB`, for a single validation run.
+use anyhow::{anyhow, Result};
+use serde_yaml::{Mapping, Value};
+
+use crate::Validation;
+
+impl Validation {
+    /// Override a single checkfile field, named by its path under `validate` (e.g. `size.max`),
+    /// with a YAML value (e.g. `6MB`, `high`, `false` or `[env, wasi_snapshot_preview1]`).
+    /// Fields which aren't in the checkfile are added.
+    pub fn set(&mut self, path: &str, value: &str) -> Result<()> {
+        let invalid = |reason: String| anyhow!("invalid override `{}={}`: {}", path, value, reason);
+
+        let keys = path
+            .strip_prefix("validate.")
+            .unwrap_or(path)
+            .split('.')
+            .collect::<Vec<_>>();
+        if keys.iter().any(|key| key.is_empty()) {
+            return Err(invalid("expected a path such as `size.max`".into()));
+        }
+        let value: Value = serde_yaml::from_str(value).map_err(|e| invalid(e.to_string()))?;
+
+        let mut doc = serde_yaml::to_value(&*self)?;
+        let mut node = doc
+            .get_mut("validate")
+            .ok_or_else(|| invalid("checkfile has no `validate` section".into()))?;
+        for key in &keys[..keys.len() - 1] {
+            if node.is_null() {
+                *node = Value::Mapping(Mapping::new());
+            }
+            node = node
+                .as_mapping_mut()
+                .ok_or_else(|| invalid(format!("`{}` is not a map", key)))?
+                .entry(Value::from(*key))
+                .or_insert(Value::Null);
+        }
+        if node.is_null() {
+            *node = Value::Mapping(Mapping::new());
+        }
+        let last = keys[keys.len() - 1];
+        node.as_mapping_mut()
+            .ok_or_else(|| {
+                invalid(format!(
+                    "cannot set `{}` on a value which is not a map",
+                    last
+                ))
+            })?
+            .insert(Value::from(last), value);
+
+        *self = serde_yaml::from_value(doc).map_err(|e| invalid(e.to_string()))?;
+
+        Ok(())
+    }
+}
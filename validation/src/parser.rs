@@ -0,0 +1,411 @@
+//! A pool of module parser plugins, so that parsing many modules (e.g. when auditing a directory
+//! or diffing) doesn't instantiate the parser plugin for each one.
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use extism_convert::Protobuf;
+use modsurfer_convert::from_api;
+use modsurfer_proto_v1::api::Module as ApiModule;
+
+use crate::registry::{RuleConfig, RuleRegistry};
+use crate::{FunctionHashes, InvalidModule, ModuleParser, ParseTimeout, PluginLimits, Validation};
+
+static SHARED: Parser = Parser::new();
+
+/// Which of the facts that are costly to collect (or to hold in memory) are kept when parsing a
+/// module. Facts which aren't kept are left empty.
+///
+/// The options are passed to the parser plugin as its config (see `ParseOptions::config`), so
+/// that a plugin which reads them can skip collecting the facts which aren't kept. Either way,
+/// the work done after the plugin (scanning strings for `provenance` and computing function
+/// hashes) is skipped, and the memory held by the facts which aren't kept is freed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// keep the module's strings, and scan them and its custom sections for the licenses and
+    /// libraries of its `provenance`
+    pub extract_strings: bool,
+    /// keep the module's call graph
+    pub build_graph: bool,
+    /// compute the hashes of the module's functions, if the parser did not provide any
+    pub compute_hashes: bool,
+}
+
+impl ParseOptions {
+    /// Keep every fact, as `Module::parse` does by default.
+    pub const ALL: ParseOptions = ParseOptions {
+        extract_strings: true,
+        build_graph: true,
+        compute_hashes: true,
+    };
+
+    /// Keep only the facts needed to validate a module against `validation`: strings for its
+    /// `vulnerabilities` and for the enabled rules which need them (see `Rule::needs_strings`),
+    /// and function hashes for `exports` listed with a `hash`.
+    pub fn for_checkfile(validation: &Validation) -> Self {
+        let validate = &validation.validate;
+        let config = RuleConfig::new(validate);
+        let strings = validate.vulnerabilities.is_some()
+            || RuleRegistry::current()
+                .rules()
+                .any(|rule| rule.needs_strings() && rule.enabled(&config));
+        let hashes = validate
+            .exports
+            .as_ref()
+            .and_then(|exports| exports.include.as_ref())
+            .is_some_and(|include| include.iter().any(|f| f.hash().is_some()));
+
+        ParseOptions {
+            extract_strings: strings,
+            build_graph: false,
+            compute_hashes: hashes,
+        }
+    }
+
+    /// The config of a parser plugin parsing with these options: `extract_strings`, `build_graph`
+    /// and `compute_hashes`, each set to `true` or `false`.
+    pub fn config(&self) -> BTreeMap<String, String> {
+        [
+            ("extract_strings", self.extract_strings),
+            ("build_graph", self.build_graph),
+            ("compute_hashes", self.compute_hashes),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+    }
+
+    /// The facts kept by both `self` and `other`.
+    pub fn intersect(self, other: ParseOptions) -> Self {
+        ParseOptions {
+            extract_strings: self.extract_strings && other.extract_strings,
+            build_graph: self.build_graph && other.build_graph,
+            compute_hashes: self.compute_hashes && other.compute_hashes,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::ALL
+    }
+}
+
+/// Parses modules with the parser plugin (see `Module::parse`), reusing idle plugins across
+/// calls. A `Parser` can be shared between threads: each call takes an idle plugin instantiated
+/// with the same options or, if there is none, instantiates another.
+///
+/// An idle plugin keeps the memory it grew to while parsing, which is as large as the largest
+/// module it has parsed, so a parser keeps at most `max_idle` plugins between calls.
+///
+/// With a timeout (see `set_timeout`), a plugin still parsing when it expires is cancelled, and
+/// the parse fails with a `ParseTimeout` error.
+pub struct Parser {
+    idle: Mutex<Vec<(ParseOptions, ModuleParser)>>,
+    /// the most idle plugins kept, defaulting to the number of CPUs
+    max_idle: Option<usize>,
+    options: RwLock<ParseOptions>,
+    limits: RwLock<PluginLimits>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Parser::new()
+    }
+}
+
+impl Parser {
+    /// A parser keeping an idle plugin for each CPU.
+    pub const fn new() -> Self {
+        Parser {
+            idle: Mutex::new(Vec::new()),
+            max_idle: None,
+            options: RwLock::new(ParseOptions::ALL),
+            limits: RwLock::new(PluginLimits {
+                memory_max: None,
+                timeout: None,
+            }),
+        }
+    }
+
+    /// A parser keeping at most `max_idle` idle plugins. With `0`, a plugin is instantiated for
+    /// every module parsed and dropped afterwards.
+    pub const fn with_max_idle(max_idle: usize) -> Self {
+        Parser {
+            idle: Mutex::new(Vec::new()),
+            max_idle: Some(max_idle),
+            options: RwLock::new(ParseOptions::ALL),
+            limits: RwLock::new(PluginLimits {
+                memory_max: None,
+                timeout: None,
+            }),
+        }
+    }
+
+    /// Limit the memory of each plugin to `bytes` (rounded down to whole WebAssembly pages), so
+    /// that parsing a module which needs more fails instead of growing the process without bound.
+    pub fn memory_max(mut self, bytes: u64) -> Self {
+        self.limits
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .memory_max = Some(bytes);
+        self
+    }
+
+    /// The parser used by `Module::parse`, shared by the whole process.
+    pub fn shared() -> &'static Parser {
+        &SHARED
+    }
+
+    /// The options used by `parse` and `parse_with`, `ParseOptions::ALL` unless set otherwise.
+    pub fn options(&self) -> ParseOptions {
+        *self.options.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Set the options used by `parse` and `parse_with` from here on, e.g. for every module parsed
+    /// by `Module::parse` when called on `Parser::shared`.
+    pub fn set_options(&self, options: ParseOptions) {
+        *self.options.write().unwrap_or_else(PoisonError::into_inner) = options;
+    }
+
+    /// The limits on the memory of each plugin and the time each module may take to parse.
+    pub fn limits(&self) -> PluginLimits {
+        *self.limits.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Set the limits used from here on. The timeout covers both the parser plugin, which is
+    /// cancelled when it expires, and the scans of the module made afterwards, which are checked
+    /// against it between each one.
+    pub fn set_limits(&self, limits: PluginLimits) {
+        *self.limits.write().unwrap_or_else(PoisonError::into_inner) = limits;
+        // the limits of a plugin are set when it is instantiated, so idle plugins are replaced
+        self.lock().clear();
+    }
+
+    /// Limit how long parsing a module may take from here on, or remove the limit with `None`.
+    /// See `set_limits`.
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        self.set_limits(PluginLimits {
+            timeout,
+            ..self.limits()
+        });
+    }
+
+    pub fn parse(&self, wasm: impl AsRef<[u8]>) -> Result<modsurfer_module::Module> {
+        self.parse_with(wasm, FunctionHashes::default())
+    }
+
+    /// Parse a module, computing the hashes of the functions selected by `function_hashes` if the
+    /// parser did not provide any.
+    pub fn parse_with(
+        &self,
+        wasm: impl AsRef<[u8]>,
+        function_hashes: FunctionHashes,
+    ) -> Result<modsurfer_module::Module> {
+        self.parse_with_options(wasm, function_hashes, self.options())
+    }
+
+    /// Parse a module like `parse_with`, keeping only the facts selected by `options` rather than
+    /// the parser's own options.
+    pub fn parse_with_options(
+        &self,
+        wasm: impl AsRef<[u8]>,
+        function_hashes: FunctionHashes,
+        options: ParseOptions,
+    ) -> Result<modsurfer_module::Module> {
+        let wasm = wasm.as_ref();
+        let limits = self.limits();
+        let deadline = Deadline::new(limits.timeout);
+        let data = self.call(wasm, options, &limits, &deadline)?;
+
+        let mut module = from_api::module(data)?;
+        if module.integrity.is_empty() {
+            deadline.check()?;
+            module.integrity = modsurfer_module::integrity_issues(wasm);
+        }
+        // parser plugins which predate these facts leave them empty
+        if module.custom_sections.is_empty() && module.function_metrics.is_empty() {
+            deadline.check()?;
+            modsurfer_module::ModuleFacts::read(wasm)
+                .context(InvalidModule)?
+                .fill(&mut module);
+        }
+        if !options.build_graph {
+            module.graph = None;
+        }
+        if !options.compute_hashes {
+            module.function_hashes.clear();
+        } else if module.function_hashes.is_empty() {
+            deadline.check()?;
+            module.function_hashes =
+                modsurfer_module::function_hashes(wasm, function_hashes).context(InvalidModule)?;
+        }
+        if !options.extract_strings {
+            module.strings = vec![];
+        } else if module.provenance.is_empty() {
+            deadline.check()?;
+            let custom = modsurfer_module::custom_section_strings(wasm).context(InvalidModule)?;
+            module.provenance = modsurfer_module::Provenance::scan(
+                module
+                    .strings
+                    .iter()
+                    .chain(custom.iter())
+                    .map(String::as_str),
+            );
+        }
+
+        deadline.check()?;
+
+        Ok(module)
+    }
+
+    // call the parser plugin, returning it to the pool before the module is scanned again by the
+    // caller, so that another thread can use it. The config of a plugin is set when it is
+    // instantiated, so only a plugin instantiated with the same options is reused.
+    fn call(
+        &self,
+        wasm: &[u8],
+        options: ParseOptions,
+        limits: &PluginLimits,
+        deadline: &Deadline,
+    ) -> Result<ApiModule> {
+        let idle = {
+            let mut idle = self.lock();
+            idle.iter()
+                .position(|(o, _)| *o == options)
+                .map(|i| idle.swap_remove(i).1)
+        };
+        let mut plugin: ModuleParser = match idle {
+            Some(plugin) => plugin,
+            None => limits
+                .instantiate(
+                    modsurfer_plugins::MODSURFER_WASM,
+                    [],
+                    &options.config(),
+                    &[],
+                )?
+                .try_into()?,
+        };
+
+        // a plugin which failed may have been left in a bad state, so it is dropped rather than
+        // reused. One cancelled by its timeout fails like any other, so the failure is told apart
+        // by the time taken. The error is copied out of the plugin before it is dropped.
+        let start = Instant::now();
+        let Protobuf(data) = match plugin.parse_module(wasm) {
+            Ok(data) => data,
+            Err(e) => {
+                deadline.check()?;
+                let e = limits.explain("parser", "parse_module", start, e);
+                return Err(anyhow::Error::from(e).context(InvalidModule));
+            }
+        };
+
+        let mut idle = self.lock();
+        if idle.len() < self.max_idle() {
+            idle.push((options, plugin));
+        }
+
+        Ok(data)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(ParseOptions, ModuleParser)>> {
+        // the pool is only pushed to and popped from, so it is valid even if a thread panicked
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn max_idle(&self) -> usize {
+        self.max_idle.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1)
+        })
+    }
+}
+
+// when a parse started, and how long it may take
+struct Deadline {
+    start: Instant,
+    timeout: Option<Duration>,
+}
+
+impl Deadline {
+    fn new(timeout: Option<Duration>) -> Self {
+        Deadline {
+            start: Instant::now(),
+            timeout,
+        }
+    }
+
+    fn check(&self) -> Result<()> {
+        match self.timeout {
+            Some(timeout) if self.start.elapsed() >= timeout => Err(ParseTimeout(timeout).into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl modsurfer_module::ModuleParser for Parser {
+    fn parse_bytes(&self, wasm: &[u8]) -> Result<modsurfer_module::Module> {
+        self.parse(wasm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_module_facts() {
+        let wasm = include_bytes!("../../vendors/fermyon/http/http.wasm");
+        let module = Parser::new()
+            .parse_with_options(wasm, FunctionHashes::None, ParseOptions::ALL)
+            .unwrap();
+
+        assert!(!module.memories.is_empty());
+        assert!(!module.tables.is_empty());
+        assert!(!module.globals.is_empty());
+        assert!(!module.custom_sections.is_empty());
+        assert!(!module.function_metrics.is_empty());
+        assert!(!module.toolchain.is_empty());
+    }
+
+    #[test]
+    fn options_are_passed_to_the_plugin() {
+        let options = ParseOptions {
+            extract_strings: false,
+            build_graph: true,
+            compute_hashes: false,
+        };
+        assert_eq!(
+            options.config(),
+            BTreeMap::from([
+                ("build_graph".to_string(), "true".to_string()),
+                ("compute_hashes".to_string(), "false".to_string()),
+                ("extract_strings".to_string(), "false".to_string()),
+            ])
+        );
+
+        // a plugin is only reused by a parse with the options it was instantiated with
+        let wasm = include_bytes!("../../vendors/fermyon/http/http.wasm");
+        let parser = Parser::with_max_idle(2);
+        let module = parser
+            .parse_with_options(wasm, FunctionHashes::None, options)
+            .unwrap();
+        assert!(module.strings.is_empty());
+        assert!(module.function_hashes.is_empty());
+
+        parser
+            .parse_with_options(wasm, FunctionHashes::None, options)
+            .unwrap();
+        assert_eq!(parser.lock().len(), 1);
+        parser
+            .parse_with_options(wasm, FunctionHashes::None, ParseOptions::ALL)
+            .unwrap();
+        let idle = parser.lock();
+        assert_eq!(
+            idle.iter().map(|(o, _)| *o).collect::<Vec<_>>(),
+            [options, ParseOptions::ALL]
+        );
+    }
+}
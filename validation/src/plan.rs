@@ -0,0 +1,340 @@
+//! The fully-resolved set of checks a checkfile makes, after fetching any remote checkfile it
+//! refers to, so that a policy can be inspected without validating a module.
+use anyhow::Result;
+use human_bytes::human_bytes;
+use serde::Serialize;
+
+use modsurfer_module::{display_val_types, ValType, MAX_RISK_SCORE};
+
+use crate::{
+    find_rule, namespace_prefix, ComplexityKind, FunctionItem, ImportItem, IncludeMode, Validation,
+};
+
+#[derive(Debug, Serialize)]
+pub struct Plan {
+    /// the checkfile on disk
+    pub checkfile: String,
+    /// the URL of the remote checkfile which replaced the one on disk, if any
+    pub url: Option<String>,
+    /// fields of the checkfile overridden for this run, e.g. `size.max=6MB`
+    pub overrides: Vec<String>,
+    /// the checkfile which will be validated against
+    pub validation: Validation,
+    pub checks: Vec<PlannedCheck>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedCheck {
+    /// the rule code, see `modsurfer explain`
+    pub rule: &'static str,
+    /// the report property path a failure of this check is reported under
+    pub property: String,
+    pub expected: String,
+}
+
+impl Plan {
+    pub fn new(checkfile: String, url: Option<String>, validation: Validation) -> Result<Self> {
+        let checks = validation.plan()?;
+
+        Ok(Plan {
+            checkfile,
+            url,
+            overrides: vec![],
+            validation,
+            checks,
+        })
+    }
+}
+
+impl Validation {
+    /// List every check this checkfile makes, in the order they are validated.
+    pub fn plan(&self) -> Result<Vec<PlannedCheck>> {
+        let mut checks = vec![];
+        let mut add = |property: String, expected: String| {
+            checks.push(PlannedCheck {
+                rule: find_rule(&property).map(|r| r.code).unwrap_or_default(),
+                property,
+                expected,
+            })
+        };
+        let check = &self.validate;
+
+        if let Some(allowed) = check.allow_wasi {
+            add(
+                "allow_wasi".into(),
+                if allowed {
+                    "WASI imports allowed".into()
+                } else {
+                    "no WASI imports".into()
+                },
+            );
+        }
+
+        if let Some(imports) = &check.imports {
+            if let Some(include) = &imports.include {
+                let n = include.len();
+                let (expected, each) = match imports.mode.unwrap_or_default() {
+                    IncludeMode::AllowOnly => (
+                        format!("no imports other than the {} listed", n),
+                        "may be imported",
+                    ),
+                    IncludeMode::RequireAll => {
+                        (format!("all {} listed imports", n), "must be imported")
+                    }
+                    IncludeMode::Both => (
+                        format!("exactly the {} listed imports", n),
+                        "must be imported",
+                    ),
+                };
+                add("imports.include".into(), expected);
+                include.iter().for_each(|imp| {
+                    add(
+                        format!("imports.include.{}", namespace_prefix(imp, imp.name())),
+                        expected_import(imp, each),
+                    )
+                });
+            }
+
+            if let Some(exclude) = &imports.exclude {
+                exclude.iter().for_each(|imp| {
+                    add(
+                        format!("imports.exclude.{}", namespace_prefix(imp, imp.name())),
+                        expected_import(imp, "not imported"),
+                    )
+                });
+            }
+
+            if let Some(max) = imports.max_namespaces {
+                add(
+                    "imports.max_namespaces".into(),
+                    format!("no more than {} namespaces imported from", max),
+                );
+            }
+
+            if let Some(max) = imports.max_per_namespace {
+                add(
+                    "imports.max_per_namespace".into(),
+                    format!(
+                        "no more than {} functions imported from each namespace",
+                        max
+                    ),
+                );
+            }
+
+            if let Some(namespace) = &imports.namespace {
+                if let Some(include) = &namespace.include {
+                    include.iter().for_each(|ns| {
+                        let name = ns.name();
+                        add(
+                            format!("imports.namespace.include.{}", name),
+                            "namespace imported".into(),
+                        );
+                        ns.functions().iter().for_each(|f| {
+                            add(
+                                format!("imports.namespace.include.{name}::{}", f.name()),
+                                expected_function(f, "imported"),
+                            )
+                        });
+                        if let Some(max) = ns.max_functions() {
+                            add(
+                                format!("imports.namespace.include.{name}.max_functions"),
+                                format!("no more than {} functions imported", max),
+                            );
+                        }
+                        if ns.forbid_unknown_functions() {
+                            add(
+                                format!(
+                                    "imports.namespace.include.{name}.forbid_unknown_functions"
+                                ),
+                                format!(
+                                    "no functions imported other than the {} listed",
+                                    ns.functions().len()
+                                ),
+                            );
+                        }
+                    });
+                }
+
+                if let Some(exclude) = &namespace.exclude {
+                    exclude.iter().for_each(|ns| {
+                        let name = ns.name();
+                        add(
+                            format!("imports.namespace.exclude.{}", name),
+                            "namespace not imported".into(),
+                        );
+                        ns.functions().iter().for_each(|f| {
+                            add(
+                                format!("imports.namespace.exclude.{name}::{}", f.name()),
+                                expected_function(f, "not imported"),
+                            )
+                        });
+                    });
+                }
+            }
+        }
+
+        if let Some(exports) = &check.exports {
+            if let Some(max) = exports.max {
+                add("exports.max".into(), format!("<= {max} exports"));
+            }
+
+            if let Some(include) = &exports.include {
+                include.iter().for_each(|f| {
+                    add(
+                        format!("exports.include.{}", f.name()),
+                        expected_function(f, "exported"),
+                    );
+                    if let Some(hash) = f.hash() {
+                        add(
+                            format!("exports.hash.{}", f.name()),
+                            format!("function body hash {hash}"),
+                        );
+                    }
+                });
+            }
+
+            if let Some(exclude) = &exports.exclude {
+                exclude.iter().for_each(|f| {
+                    add(
+                        format!("exports.exclude.{}", f.name()),
+                        expected_function(f, "not exported"),
+                    )
+                });
+            }
+        }
+
+        if let Some(max) = check.size.as_ref().and_then(|s| s.max.as_ref()) {
+            let bytes = max
+                .bytes()
+                .map_err(|e| anyhow::anyhow!("invalid size.max: {}", e))?;
+            add(
+                "size.max".into(),
+                format!("<= {max} ({})", human_bytes(bytes as f64)),
+            );
+        }
+
+        if let Some(complexity) = &check.complexity {
+            match complexity.kind()? {
+                ComplexityKind::MaxRisk(risk) => add(
+                    "complexity.max_risk".into(),
+                    format!("<= {} (cyclomatic complexity <= {})", risk, risk.max()),
+                ),
+                ComplexityKind::MaxScore(_) => unreachable!(),
+            }
+        }
+
+        if let Some(max) = check.risk.as_ref().and_then(|r| r.max_score) {
+            add(
+                "risk.max_score".into(),
+                format!("<= {max} (of {MAX_RISK_SCORE})"),
+            );
+        }
+
+        if let Some(deny) = check.capabilities.as_ref().and_then(|c| c.deny.as_ref()) {
+            deny.iter().for_each(|capability| {
+                add(
+                    format!("capabilities.deny.{capability}"),
+                    format!("no imports granting {capability}"),
+                )
+            });
+        }
+
+        if let Some(deny) = check.licenses.as_ref().and_then(|l| l.deny.as_ref()) {
+            deny.iter().for_each(|id| {
+                add(
+                    format!("licenses.deny.{id}"),
+                    format!("no {id} license text or SPDX identifier"),
+                )
+            });
+        }
+
+        if let Some(vulnerabilities) = &check.vulnerabilities {
+            if let Some(max) = vulnerabilities.max_severity {
+                add(
+                    "vulnerabilities.max_severity".into(),
+                    format!(
+                        "no known vulnerabilities more severe than {max} (from {})",
+                        vulnerabilities.db.as_deref().unwrap_or(crate::OSV_DB)
+                    ),
+                );
+            }
+        }
+
+        let integrity = check.integrity.clone().unwrap_or_default();
+        if integrity.allow_malformed != Some(true) {
+            add(
+                "integrity.malformed".into(),
+                "no structural problems in the binary".into(),
+            );
+        }
+        if let Some(integrity) = &check.integrity {
+            if integrity.allow_duplicate_imports == Some(false) {
+                add(
+                    "integrity.duplicate_imports".into(),
+                    "each function imported with one signature".into(),
+                );
+            }
+            if integrity.allow_duplicate_exports == Some(false) {
+                add(
+                    "integrity.duplicate_exports".into(),
+                    "each export name used once".into(),
+                );
+            }
+        }
+
+        if let Some(pattern) = check.module_name.as_ref().and_then(|n| n.pattern.as_ref()) {
+            add(
+                "module_name.pattern".into(),
+                format!("a file name matching {pattern}"),
+            );
+        }
+
+        if let Some(allowed) = check
+            .location
+            .as_ref()
+            .and_then(|l| l.allowed_schemes.as_ref())
+        {
+            add(
+                "location.allowed_schemes".into(),
+                format!("a URL with scheme {}", allowed.join(" or ")),
+            );
+        }
+
+        for plugin in check.plugins.iter().flatten() {
+            add(
+                format!("plugins.{}", plugin.name()),
+                format!("no failures reported by {}", plugin.path),
+            );
+        }
+
+        Ok(checks)
+    }
+}
+
+fn expected_import(imp: &ImportItem, expected: &str) -> String {
+    with_signature(expected, imp.params(), imp.results())
+}
+
+fn expected_function(f: &FunctionItem, expected: &str) -> String {
+    with_signature(expected, f.params(), f.results())
+}
+
+// describe the signature a function is checked against, if the checkfile gives one
+fn with_signature(
+    expected: &str,
+    params: Option<&[ValType]>,
+    results: Option<&[ValType]>,
+) -> String {
+    match (params, results) {
+        (None, None) => expected.to_string(),
+        _ => format!(
+            "{expected}, with type {} -> {}",
+            params
+                .map(display_val_types)
+                .unwrap_or_else(|| "(..)".into()),
+            results
+                .map(display_val_types)
+                .unwrap_or_else(|| "(..)".into()),
+        ),
+    }
+}
@@ -0,0 +1,272 @@
+//! Running plugins locally with Extism, within limits on the memory and time they may use, so that
+//! untrusted plugin code can't exhaust the host, even by accident. The parser plugin (see
+//! `Parser`) is limited the same way.
+//!
+//! Extism 1.0 has no fuel metering, so a plugin's computation is bounded by its timeout instead.
+//!
+//! The errors of plugins are copied into a `PluginError` as soon as a call returns, so that none
+//! refers to the plugin's memory once it is reused or dropped.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use extism::{Function, Manifest, Plugin, PluginBuilder, Wasm};
+use human_bytes::human_bytes;
+use sha2::{Digest, Sha256};
+
+/// The size of a page of WebAssembly memory, the unit of a plugin's memory limit.
+const WASM_PAGE_SIZE: u64 = 64 * 1024;
+
+/// Limits on the resources a plugin may use. Unset limits are not enforced.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PluginLimits {
+    /// the most memory the plugin may grow to, in bytes (rounded down to whole WebAssembly pages)
+    pub memory_max: Option<u64>,
+    /// the longest a single call to the plugin may run before it is cancelled
+    pub timeout: Option<Duration>,
+}
+
+impl PluginLimits {
+    /// Instantiate a plugin from `wasm` within these limits, without WASI, so that it has no
+    /// access to the host's filesystem, network or environment beyond the host `functions` given,
+    /// and HTTP requests to `allowed_hosts`. The plugin reads `config` with Extism's `config_get`.
+    pub(crate) fn instantiate(
+        &self,
+        wasm: &[u8],
+        functions: impl IntoIterator<Item = Function>,
+        config: &BTreeMap<String, String>,
+        allowed_hosts: &[String],
+    ) -> Result<Plugin> {
+        if *self == PluginLimits::default() && config.is_empty() && allowed_hosts.is_empty() {
+            return Plugin::new(wasm, functions, false);
+        }
+
+        let mut manifest = Manifest::new([Wasm::data(wasm)]);
+        for (key, value) in config {
+            manifest = manifest.with_config_key(key, value);
+        }
+        if !allowed_hosts.is_empty() {
+            manifest = manifest.with_allowed_hosts(allowed_hosts.iter().cloned());
+        }
+        if let Some(bytes) = self.memory_max {
+            let pages = u32::try_from(bytes / WASM_PAGE_SIZE).unwrap_or(u32::MAX);
+            manifest = manifest.with_memory_max(pages);
+        }
+        if let Some(timeout) = self.timeout {
+            manifest = manifest.with_timeout(timeout);
+        }
+
+        PluginBuilder::new(manifest)
+            .with_wasi(false)
+            .with_functions(functions)
+            .build()
+    }
+
+    /// Explain the failure of a call to `function` which started at `start`. A plugin stopped by
+    /// its timeout fails like any other, so the failure is told apart by the time taken.
+    pub(crate) fn explain(
+        &self,
+        plugin: &str,
+        function: &str,
+        start: Instant,
+        e: anyhow::Error,
+    ) -> PluginError {
+        if let Some(timeout) = self.timeout.filter(|t| start.elapsed() >= *t) {
+            return PluginError::Timeout {
+                plugin: plugin.to_string(),
+                timeout,
+            };
+        }
+
+        let message = format!("{:#}", e);
+        PluginError::Failed {
+            plugin: plugin.to_string(),
+            function: function.to_string(),
+            module: None,
+            offset: find_offset(&message),
+            memory_max: self.memory_max,
+            message,
+        }
+    }
+}
+
+/// Why a call to a plugin failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginError {
+    /// The plugin returned an error, or trapped.
+    Failed {
+        plugin: String,
+        function: String,
+        /// the error, copied out of the plugin
+        message: String,
+        /// the path of the module the plugin was given, when parsing one from a file
+        module: Option<String>,
+        /// the offset into the module the error refers to, if it gives one
+        offset: Option<u64>,
+        /// the plugin's memory limit, which a trap may have come from exceeding
+        memory_max: Option<u64>,
+    },
+    /// The plugin was cancelled for running longer than its timeout.
+    Timeout { plugin: String, timeout: Duration },
+}
+
+impl Display for PluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginError::Failed {
+                plugin,
+                function,
+                message,
+                module,
+                offset,
+                memory_max,
+            } => {
+                write!(f, "plugin {} failed in `{}`", plugin, function)?;
+                match (module, offset) {
+                    (Some(module), Some(offset)) => {
+                        write!(f, " on {} at offset {:#x}", module, offset)?
+                    }
+                    (Some(module), None) => write!(f, " on {}", module)?,
+                    (None, Some(offset)) => write!(f, " at offset {:#x}", offset)?,
+                    (None, None) => {}
+                }
+                write!(f, ": {}", message)?;
+                if let Some(bytes) = memory_max {
+                    write!(
+                        f,
+                        " (it may have exceeded its memory limit of {})",
+                        human_bytes(*bytes as f64)
+                    )?;
+                }
+                Ok(())
+            }
+            PluginError::Timeout { plugin, timeout } => write!(
+                f,
+                "plugin {} was cancelled after running longer than its timeout of {:?}",
+                plugin, timeout
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+/// Name the module which failed to parse in the `PluginError` of `result`, if it has one.
+pub(crate) fn in_module<T>(result: Result<T>, path: &Path) -> Result<T> {
+    result.map_err(|mut e| {
+        if let Some(PluginError::Failed { module, .. }) = e.downcast_mut::<PluginError>() {
+            *module = Some(path.display().to_string());
+        }
+        e
+    })
+}
+
+// the offset into a module an error refers to, as given by the parser's messages, e.g.
+// "unexpected end-of-file (at offset 0x1f)"
+fn find_offset(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("offset ")?;
+    let digits = rest.split(|c: char| !c.is_ascii_alphanumeric()).next()?;
+    match digits.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => digits.parse().ok(),
+    }
+}
+
+/// Read the wasm of a plugin listed in a checkfile, checking that it has the hex-encoded SHA-256
+/// `sha256`, if one is given.
+pub(crate) fn read_wasm(path: &str, sha256: Option<&str>) -> Result<Vec<u8>> {
+    let wasm = std::fs::read(path).with_context(|| format!("failed to read plugin {}", path))?;
+    if let Some(expected) = sha256 {
+        let actual = format!("{:x}", Sha256::digest(&wasm));
+        if !actual.eq_ignore_ascii_case(expected.trim()) {
+            anyhow::bail!(
+                "plugin {} has SHA-256 {}, but `sha256` expects {}",
+                path,
+                actual,
+                expected
+            );
+        }
+    }
+
+    Ok(wasm)
+}
+
+/// A plugin run on this machine, rather than by a Modsurfer server, within `PluginLimits`.
+pub struct LocalPlugin {
+    name: String,
+    plugin: Plugin,
+    limits: PluginLimits,
+}
+
+impl LocalPlugin {
+    /// Instantiate the plugin in `wasm`, naming it `name` in errors (e.g. the path it was read
+    /// from).
+    pub fn new(name: impl Into<String>, wasm: &[u8], limits: PluginLimits) -> Result<Self> {
+        LocalPlugin::build(name, wasm, limits, [], &BTreeMap::new(), &[])
+    }
+
+    /// Instantiate the plugin like `new`, with `config` for it to read, e.g. the keys declared in
+    /// its manifest.
+    pub fn with_config(
+        name: impl Into<String>,
+        wasm: &[u8],
+        limits: PluginLimits,
+        config: &BTreeMap<String, String>,
+    ) -> Result<Self> {
+        LocalPlugin::build(name, wasm, limits, [], config, &[])
+    }
+
+    // instantiate the plugin with host functions it may import, see `validator`
+    pub(crate) fn with_functions(
+        name: impl Into<String>,
+        wasm: &[u8],
+        limits: PluginLimits,
+        functions: impl IntoIterator<Item = Function>,
+    ) -> Result<Self> {
+        LocalPlugin::build(name, wasm, limits, functions, &BTreeMap::new(), &[])
+    }
+
+    // instantiate the plugin with HTTP requests to `allowed_hosts` allowed, see `hooks`
+    pub(crate) fn with_allowed_hosts(
+        name: impl Into<String>,
+        wasm: &[u8],
+        limits: PluginLimits,
+        allowed_hosts: &[String],
+    ) -> Result<Self> {
+        LocalPlugin::build(name, wasm, limits, [], &BTreeMap::new(), allowed_hosts)
+    }
+
+    fn build(
+        name: impl Into<String>,
+        wasm: &[u8],
+        limits: PluginLimits,
+        functions: impl IntoIterator<Item = Function>,
+        config: &BTreeMap<String, String>,
+        allowed_hosts: &[String],
+    ) -> Result<Self> {
+        let name = name.into();
+        let plugin = limits
+            .instantiate(wasm, functions, config, allowed_hosts)
+            .with_context(|| format!("failed to instantiate plugin {}", name))?;
+
+        Ok(LocalPlugin {
+            name,
+            plugin,
+            limits,
+        })
+    }
+
+    /// Call an exported function of the plugin with `input`, returning its output.
+    pub fn call(&mut self, function: &str, input: &[u8]) -> Result<Vec<u8>> {
+        if !self.plugin.function_exists(function) {
+            anyhow::bail!("plugin {} has no function `{}`", self.name, function);
+        }
+
+        let start = Instant::now();
+        self.plugin
+            .call::<&[u8], Vec<u8>>(function, input)
+            .map_err(|e| self.limits.explain(&self.name, function, start, e).into())
+    }
+}
@@ -0,0 +1,183 @@
+//! Tests for a checkfile: fixture modules which are known to pass or fail it, declared in a
+//! manifest, so that a change to a policy can be checked before it is rolled out.
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{new_table, read_for_checkfile, resolve_checkfile, validate_parsed, Report};
+
+/// The fixtures a checkfile is tested against, e.g.
+///
+/// ```yaml
+/// checkfile: mod.yaml
+/// fixtures:
+///   - module: good/plugin.wasm
+///     expect: pass
+///   - module: bad/uses-wasi.wasm
+///     expect: fail
+///     # the checks which must fail, if only some of them are expected to
+///     fails:
+///       - allow_wasi
+/// ```
+///
+/// Paths are relative to the manifest.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyTests {
+    pub checkfile: Option<PathBuf>,
+    pub fixtures: Vec<Fixture>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fixture {
+    pub module: PathBuf,
+    pub expect: Expectation,
+    /// report properties which must be among the failures, e.g. `allow_wasi`
+    #[serde(default)]
+    pub fails: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Expectation {
+    Pass,
+    Fail,
+}
+
+impl Display for Expectation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Expectation::Pass => "pass",
+            Expectation::Fail => "fail",
+        })
+    }
+}
+
+/// Whether a fixture met its expectation.
+#[derive(Debug, Serialize)]
+pub struct FixtureResult {
+    pub module: String,
+    pub expected: Expectation,
+    pub actual: Expectation,
+    /// the report properties which failed
+    pub failures: Vec<String>,
+    /// the properties listed in `fails` which didn't fail
+    pub missing: Vec<String>,
+}
+
+impl FixtureResult {
+    fn new(module: String, fixture: &Fixture, report: &Report) -> Self {
+        let failures = report.fails.keys().cloned().collect::<Vec<_>>();
+        FixtureResult {
+            module,
+            expected: fixture.expect,
+            actual: if failures.is_empty() {
+                Expectation::Pass
+            } else {
+                Expectation::Fail
+            },
+            missing: fixture
+                .fails
+                .iter()
+                .filter(|property| !report.fails.contains_key(*property))
+                .cloned()
+                .collect(),
+            failures,
+        }
+    }
+
+    pub fn holds(&self) -> bool {
+        self.expected == self.actual && self.missing.is_empty()
+    }
+}
+
+/// The result of testing a checkfile against each of its fixtures.
+#[derive(Debug, Serialize)]
+pub struct PolicyTestReport {
+    pub checkfile: String,
+    pub results: Vec<FixtureResult>,
+}
+
+impl PolicyTestReport {
+    /// Whether every fixture met its expectation.
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(FixtureResult::holds)
+    }
+}
+
+impl Display for PolicyTestReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = new_table(vec!["Status", "Module", "Expected", "Actual", "Details"]);
+        self.results.iter().for_each(|result| {
+            let details = if !result.missing.is_empty() {
+                format!("expected to fail: {}", result.missing.join(", "))
+            } else if result.expected == Expectation::Pass {
+                result.failures.join(", ")
+            } else {
+                String::new()
+            };
+            table.add_row(vec![
+                if result.holds() { "OK" } else { "FAIL" }.to_string(),
+                result.module.clone(),
+                result.expected.to_string(),
+                result.actual.to_string(),
+                details,
+            ]);
+        });
+
+        let held = self.results.iter().filter(|r| r.holds()).count();
+        writeln!(f, "{}", table)?;
+        write!(
+            f,
+            "{} of {} fixtures met their expectations for {}.",
+            held,
+            self.results.len(),
+            self.checkfile
+        )
+    }
+}
+
+/// Validate each fixture in a manifest against the checkfile it names, or against `checkfile` if
+/// given, and check that each passes or fails as expected.
+pub async fn test_policy(manifest: &Path, checkfile: Option<&Path>) -> Result<PolicyTestReport> {
+    let data = tokio::fs::read(manifest)
+        .await
+        .with_context(|| format!("failed to read {}", manifest.display()))?;
+    let tests: PolicyTests = serde_yaml::from_slice(&data)
+        .with_context(|| format!("invalid policy test manifest {}", manifest.display()))?;
+    let base = manifest.parent().unwrap_or_else(|| Path::new(""));
+
+    let checkfile = match (checkfile, &tests.checkfile) {
+        (Some(checkfile), _) => checkfile.to_path_buf(),
+        (None, Some(checkfile)) => base.join(checkfile),
+        (None, None) => anyhow::bail!(
+            "policy test manifest {} has no `checkfile`, and none was given",
+            manifest.display()
+        ),
+    };
+    let (validation, _) = resolve_checkfile(&checkfile, &[])
+        .await
+        .with_context(|| format!("invalid checkfile {}", checkfile.display()))?;
+
+    let mut results = Vec::with_capacity(tests.fixtures.len());
+    for fixture in &tests.fixtures {
+        let path = base.join(&fixture.module);
+        let module = read_for_checkfile(&path, &validation)
+            .await
+            .with_context(|| format!("failed to parse fixture {}", path.display()))?;
+        let report = validate_parsed(validation.clone(), module).await?;
+        results.push(FixtureResult::new(
+            fixture.module.display().to_string(),
+            fixture,
+            &report,
+        ));
+    }
+
+    Ok(PolicyTestReport {
+        checkfile: checkfile.display().to_string(),
+        results,
+    })
+}
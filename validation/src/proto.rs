@@ -0,0 +1,416 @@
+//! Conversions between a `Validation` and its protobuf representation, `api::Checkfile`, so that
+//! checkfiles can be sent to and returned by the server as typed messages.
+use anyhow::Result;
+use modsurfer_convert::{from_api, to_api};
+use modsurfer_module::{Capability, ValType};
+use modsurfer_proto_v1::api;
+use protobuf::{EnumOrUnknown, MessageField};
+
+use crate::{
+    Capabilities, Check, Complexity, Exports, FunctionItem, ImportItem, Imports, Integrity,
+    Licenses, Location, ModuleName, Namespace, NamespaceItem, Risk, Severity, Size, SizeLimit,
+    Validation, Vulnerabilities,
+};
+
+impl From<&Validation> for api::Checkfile {
+    fn from(validation: &Validation) -> Self {
+        api::Checkfile {
+            validate: MessageField::some(check(&validation.validate)),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<api::Checkfile> for Validation {
+    type Error = anyhow::Error;
+
+    fn try_from(checkfile: api::Checkfile) -> Result<Self> {
+        Ok(Validation {
+            validate: checkfile
+                .validate
+                .into_option()
+                .map(from_check)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+fn check(check: &Check) -> api::Check {
+    api::Check {
+        url: check.url.clone(),
+        url_sha256: check.url_sha256.clone(),
+        risk: check
+            .risk
+            .as_ref()
+            .map(|r| api::CheckRisk {
+                max_score: r.max_score,
+                ..Default::default()
+            })
+            .into(),
+        capabilities: check
+            .capabilities
+            .as_ref()
+            .map(|c| api::CheckCapabilities {
+                deny: c
+                    .deny
+                    .iter()
+                    .flatten()
+                    .map(|c| c.as_str().to_string())
+                    .collect(),
+                ..Default::default()
+            })
+            .into(),
+        licenses: check
+            .licenses
+            .as_ref()
+            .map(|l| api::CheckLicenses {
+                deny: l.deny.clone().unwrap_or_default(),
+                ..Default::default()
+            })
+            .into(),
+        vulnerabilities: check
+            .vulnerabilities
+            .as_ref()
+            .map(|v| api::CheckVulnerabilities {
+                max_severity: v.max_severity.map(|s| s.as_str().to_string()),
+                db: v.db.clone(),
+                ..Default::default()
+            })
+            .into(),
+        integrity: check
+            .integrity
+            .as_ref()
+            .map(|i| api::CheckIntegrity {
+                allow_duplicate_imports: i.allow_duplicate_imports,
+                allow_duplicate_exports: i.allow_duplicate_exports,
+                allow_malformed: i.allow_malformed,
+                ..Default::default()
+            })
+            .into(),
+        module_name: check
+            .module_name
+            .as_ref()
+            .map(|n| api::CheckModuleName {
+                pattern: n.pattern.clone(),
+                ..Default::default()
+            })
+            .into(),
+        location: check
+            .location
+            .as_ref()
+            .map(|l| api::CheckLocation {
+                allowed_schemes: l.allowed_schemes.clone().unwrap_or_default(),
+                ..Default::default()
+            })
+            .into(),
+        allow_wasi: check.allow_wasi,
+        imports: check.imports.as_ref().map(imports).into(),
+        exports: check.exports.as_ref().map(exports).into(),
+        size: check
+            .size
+            .as_ref()
+            .map(|s| api::CheckSize {
+                max: s.max.as_ref().map(|max| match max {
+                    SizeLimit::Bytes(bytes) => bytes.to_string(),
+                    SizeLimit::Human(size) => size.clone(),
+                }),
+                ..Default::default()
+            })
+            .into(),
+        complexity: check
+            .complexity
+            .as_ref()
+            .map(|c| api::CheckComplexity {
+                max_risk: c
+                    .max_risk
+                    .as_ref()
+                    .map(|r| EnumOrUnknown::new(to_api::risk_level(*r))),
+                max_score: c.max_score,
+                ..Default::default()
+            })
+            .into(),
+        ..Default::default()
+    }
+}
+
+fn from_check(check: api::Check) -> Result<Check> {
+    Ok(Check {
+        url: check.url,
+        url_sha256: check.url_sha256,
+        risk: check.risk.into_option().map(|r| Risk {
+            max_score: r.max_score,
+        }),
+        // capabilities unknown to this version are ignored
+        capabilities: check.capabilities.into_option().map(|c| Capabilities {
+            deny: Some(
+                c.deny
+                    .iter()
+                    .filter_map(|name| Capability::parse(name))
+                    .collect(),
+            ),
+        }),
+        licenses: check
+            .licenses
+            .into_option()
+            .map(|l| Licenses { deny: Some(l.deny) }),
+        vulnerabilities: check
+            .vulnerabilities
+            .into_option()
+            .map(|v| Vulnerabilities {
+                max_severity: v.max_severity.as_deref().and_then(Severity::parse),
+                db: v.db,
+            }),
+        integrity: check.integrity.into_option().map(|i| Integrity {
+            allow_duplicate_imports: i.allow_duplicate_imports,
+            allow_duplicate_exports: i.allow_duplicate_exports,
+            allow_malformed: i.allow_malformed,
+        }),
+        module_name: check
+            .module_name
+            .into_option()
+            .map(|n| ModuleName { pattern: n.pattern }),
+        location: check.location.into_option().map(|l| Location {
+            allowed_schemes: Some(l.allowed_schemes),
+        }),
+        allow_wasi: check.allow_wasi,
+        imports: check.imports.into_option().map(from_imports).transpose()?,
+        exports: check.exports.into_option().map(from_exports).transpose()?,
+        // sizes are strings in the API, so exact sizes are sent as a number of bytes
+        size: check.size.into_option().map(|s| Size {
+            max: s.max.map(|max| match max.parse::<u64>() {
+                Ok(bytes) => SizeLimit::Bytes(bytes),
+                Err(_) => SizeLimit::Human(max),
+            }),
+        }),
+        complexity: check.complexity.into_option().map(|c| Complexity {
+            max_risk: c
+                .max_risk
+                .map(|r| from_api::risk_level(r.enum_value_or_default())),
+            max_score: c.max_score,
+        }),
+        // the API has no options for validator plugins or report hooks, which only run on this
+        // machine, nor for registered rules
+        plugins: None,
+        hooks: None,
+        rules: None,
+    })
+}
+
+fn val_types(types: &Option<Vec<ValType>>) -> MessageField<api::ValTypes> {
+    types
+        .as_ref()
+        .map(|t| api::ValTypes {
+            types: to_api::val_types(t.clone()),
+            ..Default::default()
+        })
+        .into()
+}
+
+fn from_val_types(types: MessageField<api::ValTypes>) -> Result<Option<Vec<ValType>>> {
+    types
+        .into_option()
+        .map(|t| from_api::val_types(t.types))
+        .transpose()
+}
+
+fn imports(imports: &Imports) -> api::CheckImports {
+    let items = |items: &Vec<ImportItem>| api::CheckImportItems {
+        items: items.iter().map(import_item).collect(),
+        ..Default::default()
+    };
+
+    api::CheckImports {
+        include: imports.include.as_ref().map(items).into(),
+        exclude: imports.exclude.as_ref().map(items).into(),
+        namespace: imports.namespace.as_ref().map(namespace).into(),
+        mode: imports.mode.map(|mode| mode.to_string()),
+        max_namespaces: imports.max_namespaces,
+        max_per_namespace: imports.max_per_namespace,
+        ..Default::default()
+    }
+}
+
+fn from_imports(imports: api::CheckImports) -> Result<Imports> {
+    let items = |items: api::CheckImportItems| {
+        items
+            .items
+            .into_iter()
+            .map(from_import_item)
+            .collect::<Result<Vec<_>>>()
+    };
+
+    Ok(Imports {
+        include: imports.include.into_option().map(items).transpose()?,
+        exclude: imports.exclude.into_option().map(items).transpose()?,
+        namespace: imports
+            .namespace
+            .into_option()
+            .map(from_namespace)
+            .transpose()?,
+        mode: imports.mode.and_then(|mode| mode.parse().ok()),
+        max_namespaces: imports.max_namespaces,
+        max_per_namespace: imports.max_per_namespace,
+    })
+}
+
+fn import_item(item: &ImportItem) -> api::CheckImportItem {
+    match item {
+        ImportItem::Name(name) => api::CheckImportItem {
+            name: name.clone(),
+            ..Default::default()
+        },
+        ImportItem::Item {
+            namespace,
+            name,
+            params,
+            results,
+        } => api::CheckImportItem {
+            namespace: namespace.clone(),
+            name: name.clone(),
+            params: val_types(params),
+            results: val_types(results),
+            ..Default::default()
+        },
+    }
+}
+
+// items with only a name are converted to the short form, as they would be written in YAML
+fn from_import_item(item: api::CheckImportItem) -> Result<ImportItem> {
+    if item.namespace.is_none() && item.params.is_none() && item.results.is_none() {
+        return Ok(ImportItem::Name(item.name));
+    }
+
+    Ok(ImportItem::Item {
+        namespace: item.namespace,
+        name: item.name,
+        params: from_val_types(item.params)?,
+        results: from_val_types(item.results)?,
+    })
+}
+
+fn namespace(namespace: &Namespace) -> api::CheckNamespaces {
+    let items = |items: &Vec<NamespaceItem>| api::CheckNamespaceItems {
+        items: items
+            .iter()
+            .map(|item| api::CheckNamespaceItem {
+                name: item.name().clone(),
+                functions: item.functions().iter().map(function_item).collect(),
+                max_functions: item.max_functions(),
+                forbid_unknown_functions: match item {
+                    NamespaceItem::Name(_) => None,
+                    NamespaceItem::Item {
+                        forbid_unknown_functions,
+                        ..
+                    } => *forbid_unknown_functions,
+                },
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    api::CheckNamespaces {
+        include: namespace.include.as_ref().map(items).into(),
+        exclude: namespace.exclude.as_ref().map(items).into(),
+        ..Default::default()
+    }
+}
+
+fn from_namespace(namespace: api::CheckNamespaces) -> Result<Namespace> {
+    let items = |items: api::CheckNamespaceItems| {
+        items
+            .items
+            .into_iter()
+            .map(|item| {
+                if item.functions.is_empty()
+                    && item.max_functions.is_none()
+                    && item.forbid_unknown_functions.is_none()
+                {
+                    Ok(NamespaceItem::Name(item.name))
+                } else {
+                    Ok(NamespaceItem::Item {
+                        name: item.name,
+                        functions: item
+                            .functions
+                            .into_iter()
+                            .map(from_function_item)
+                            .collect::<Result<_>>()?,
+                        max_functions: item.max_functions,
+                        forbid_unknown_functions: item.forbid_unknown_functions,
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+
+    Ok(Namespace {
+        include: namespace.include.into_option().map(items).transpose()?,
+        exclude: namespace.exclude.into_option().map(items).transpose()?,
+    })
+}
+
+fn exports(exports: &Exports) -> api::CheckExports {
+    let items = |items: &Vec<FunctionItem>| api::CheckFunctionItems {
+        items: items.iter().map(function_item).collect(),
+        ..Default::default()
+    };
+
+    api::CheckExports {
+        include: exports.include.as_ref().map(items).into(),
+        exclude: exports.exclude.as_ref().map(items).into(),
+        max: exports.max,
+        ..Default::default()
+    }
+}
+
+fn from_exports(exports: api::CheckExports) -> Result<Exports> {
+    let items = |items: api::CheckFunctionItems| {
+        items
+            .items
+            .into_iter()
+            .map(from_function_item)
+            .collect::<Result<Vec<_>>>()
+    };
+
+    Ok(Exports {
+        include: exports.include.into_option().map(items).transpose()?,
+        exclude: exports.exclude.into_option().map(items).transpose()?,
+        max: exports.max,
+    })
+}
+
+fn function_item(item: &FunctionItem) -> api::CheckFunctionItem {
+    match item {
+        FunctionItem::Name(name) => api::CheckFunctionItem {
+            name: name.clone(),
+            ..Default::default()
+        },
+        FunctionItem::Item {
+            name,
+            params,
+            results,
+            hash,
+        } => api::CheckFunctionItem {
+            name: name.clone(),
+            params: val_types(params),
+            results: val_types(results),
+            hash: hash.clone(),
+            ..Default::default()
+        },
+    }
+}
+
+// items with only a name are converted to the short form, as they would be written in YAML
+fn from_function_item(item: api::CheckFunctionItem) -> Result<FunctionItem> {
+    if item.params.is_none() && item.results.is_none() && item.hash.is_none() {
+        return Ok(FunctionItem::Name(item.name));
+    }
+
+    Ok(FunctionItem::Item {
+        name: item.name,
+        params: from_val_types(item.params)?,
+        results: from_val_types(item.results)?,
+        hash: item.hash,
+    })
+}
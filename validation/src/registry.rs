@@ -0,0 +1,233 @@
+//! The rules which validate a module against a checkfile. Each check a checkfile can make (e.g.
+//! `imports` or `size`) is a `Rule`, and `validate` runs every rule in a `RuleRegistry`: the
+//! built-in rules, followed by any registered with `register_rule` by a program embedding this
+//! crate.
+//!
+//! A registered rule is configured in a checkfile under `rules`, by its name:
+//!
+//! ```yaml
+//! validate:
+//!   rules:
+//!     max_memory_pages:
+//!       max: 16
+//! ```
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+use modsurfer_module::Module;
+use serde::de::DeserializeOwned;
+
+use crate::timings::Stopwatch;
+use crate::{checks, Check, Classification, FailureDetail, Report};
+
+/// A check made against a module.
+pub trait Rule: Send + Sync {
+    /// A unique name for the rule, e.g. `size`, which is also the key of its options under
+    /// `rules` in a checkfile.
+    fn name(&self) -> &'static str;
+
+    /// The classification given to findings by `Finding::new`.
+    fn classification(&self) -> Classification;
+
+    /// The severity given to findings by `Finding::new`, from 1 to 10.
+    fn default_severity(&self) -> usize;
+
+    /// Whether the checkfile asks for this rule to run. By default, a rule runs if the checkfile
+    /// has options for it under `rules`.
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config.has_options(self.name())
+    }
+
+    /// Whether the rule reads the strings found in a module, or the `provenance` scanned from
+    /// them. Strings are only extracted when validating against a checkfile if an enabled rule
+    /// needs them.
+    fn needs_strings(&self) -> bool {
+        false
+    }
+
+    /// Check a module, returning a finding for each failure.
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>>;
+}
+
+/// The checkfile a module is validated against.
+pub struct RuleConfig<'a> {
+    pub check: &'a Check,
+}
+
+impl<'a> RuleConfig<'a> {
+    pub fn new(check: &'a Check) -> Self {
+        RuleConfig { check }
+    }
+
+    /// Whether the checkfile has options for the rule named `name` under `rules`.
+    pub fn has_options(&self, name: &str) -> bool {
+        self.check
+            .rules
+            .as_ref()
+            .is_some_and(|rules| rules.contains_key(name))
+    }
+
+    /// The options for the rule named `name` under `rules` in the checkfile, if any.
+    pub fn options<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+        match self.check.rules.as_ref().and_then(|rules| rules.get(name)) {
+            Some(options) => serde_yaml::from_value(options.clone())
+                .map(Some)
+                .map_err(|e| anyhow::anyhow!("invalid options for rule `{}`: {}", name, e)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A failure found by a rule, added to the `Report` under `property`.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    /// the dot-separated path of the failure in the report, conventionally prefixed with the
+    /// rule's name, e.g. `size.max`
+    pub property: String,
+    pub expected: String,
+    pub actual: String,
+    pub severity: usize,
+    pub classification: Classification,
+}
+
+impl Finding {
+    /// A finding with the classification and default severity of `rule`.
+    pub fn new<R: Rule + ?Sized>(
+        rule: &R,
+        property: impl Into<String>,
+        expected: impl Into<String>,
+        actual: impl Into<String>,
+    ) -> Self {
+        Finding {
+            property: property.into(),
+            expected: expected.into(),
+            actual: actual.into(),
+            severity: rule.default_severity(),
+            classification: rule.classification(),
+        }
+    }
+
+    pub fn severity(mut self, severity: usize) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn classification(mut self, classification: Classification) -> Self {
+        self.classification = classification;
+        self
+    }
+}
+
+impl Report {
+    // the findings of a built-in rule, which records failures in a report of its own
+    pub(crate) fn into_findings(self) -> Vec<Finding> {
+        self.fails
+            .into_iter()
+            .map(|(property, detail)| Finding {
+                property,
+                expected: detail.expected,
+                actual: detail.actual,
+                severity: detail.severity,
+                classification: detail.classification,
+            })
+            .collect()
+    }
+}
+
+/// An ordered set of rules, each with a unique name.
+#[derive(Clone)]
+pub struct RuleRegistry {
+    rules: Vec<Arc<dyn Rule>>,
+}
+
+impl RuleRegistry {
+    /// A registry of only the built-in rules, without those added by `register_rule`.
+    pub fn builtin() -> Self {
+        RuleRegistry {
+            rules: checks::builtin(),
+        }
+    }
+
+    /// The built-in rules, followed by those added by `register_rule`.
+    pub fn current() -> Self {
+        let mut registry = RuleRegistry::builtin();
+        registry
+            .rules
+            .extend(REGISTERED.read().unwrap_or_else(|e| e.into_inner()).clone());
+        registry
+    }
+
+    /// Add a rule, which runs after the rules already added. Fails if a rule has the same name.
+    pub fn register(&mut self, rule: impl Rule + 'static) -> Result<()> {
+        self.add(Arc::new(rule))
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    /// Run every enabled rule against a module.
+    pub fn validate(&self, check: &Check, module: &Module) -> Result<Report> {
+        self.validate_with(check, module, &mut Stopwatch::disabled())
+    }
+
+    pub(crate) fn validate_with(
+        &self,
+        check: &Check,
+        module: &Module,
+        stopwatch: &mut Stopwatch,
+    ) -> Result<Report> {
+        let config = RuleConfig::new(check);
+        let mut fails = BTreeMap::new();
+        for rule in self.rules.iter().filter(|rule| rule.enabled(&config)) {
+            for finding in rule.evaluate(module, &config)? {
+                fails.insert(
+                    finding.property,
+                    FailureDetail {
+                        actual: finding.actual,
+                        expected: finding.expected,
+                        severity: finding.severity,
+                        classification: finding.classification,
+                    },
+                );
+            }
+            stopwatch.lap(rule.name());
+        }
+
+        Ok(Report {
+            fails,
+            module: None,
+        })
+    }
+
+    fn add(&mut self, rule: Arc<dyn Rule>) -> Result<()> {
+        if self.rules.iter().any(|r| r.name() == rule.name()) {
+            anyhow::bail!("a rule named `{}` is already registered", rule.name());
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+}
+
+impl Default for RuleRegistry {
+    fn default() -> Self {
+        RuleRegistry::builtin()
+    }
+}
+
+static REGISTERED: RwLock<Vec<Arc<dyn Rule>>> = RwLock::new(vec![]);
+
+/// Run `rule` in every validation from here on, after the built-in rules. Fails if a built-in or
+/// registered rule has the same name.
+pub fn register_rule(rule: impl Rule + 'static) -> Result<()> {
+    let rule: Arc<dyn Rule> = Arc::new(rule);
+    let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+    let mut registry = RuleRegistry::builtin();
+    for r in registered.iter() {
+        registry.add(r.clone())?;
+    }
+    registry.add(rule.clone())?;
+    registered.push(rule);
+    Ok(())
+}
@@ -0,0 +1,347 @@
+//! Descriptions of each check a checkfile can make, identified by a stable rule code and by the
+//! property path used for its failures in a `Report`.
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::Classification;
+
+#[derive(Debug, Serialize)]
+pub struct Rule {
+    /// a stable identifier for the rule, e.g. `MS-SIZE-001`
+    pub code: &'static str,
+    /// the report property path (or prefix of it) for failures of this rule, e.g. `size.max`
+    pub property: &'static str,
+    pub summary: &'static str,
+    pub rationale: &'static str,
+    /// the severity given to failures, from 1 to 10
+    pub severity: &'static str,
+    pub classification: Classification,
+    /// a checkfile snippet which makes the check
+    pub example: &'static str,
+    /// how to fix or suppress a failure
+    pub remedy: &'static str,
+}
+
+pub const RULES: &[Rule] = &[
+    Rule {
+        code: "MS-WASI-001",
+        property: "allow_wasi",
+        summary: "Checks whether the module imports functions from WASI (`wasi_snapshot_preview1`).",
+        rationale: "WASI gives a module access to host resources such as files, clocks and randomness. Hosts which don't provide WASI can't instantiate a module that imports it.",
+        severity: "10",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  allow_wasi: false",
+        remedy: "Build the module for a target without WASI (e.g. `wasm32-unknown-unknown`), or set `allow_wasi: true` if the host provides it.",
+    },
+    Rule {
+        code: "MS-IMPORT-001",
+        property: "imports.include",
+        summary: "Checks that every function imported by the module is listed in `imports.include`, with a matching signature if one is given. With `mode: require_all`, checks instead that every function listed is imported, and with `mode: both`, that the module imports exactly the functions listed.",
+        rationale: "A module can only be instantiated if the host provides each of its imports, with the same parameter and result types.",
+        severity: "10",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  imports:\n    include:\n      - namespace: env\n        name: log\n        params: [i32, i32]\n        results: []",
+        remedy: "Remove the import from the module, or add it to `imports.include` if the host provides it. If `mode` requires the listed imports, add a missing import to the module, or remove it from `imports.include`. A failure ending in `.params` or `.results` means the signature in the checkfile differs from the module.",
+    },
+    Rule {
+        code: "MS-IMPORT-002",
+        property: "imports.exclude",
+        summary: "Checks that the module does not import any function listed in `imports.exclude`.",
+        rationale: "Some host functions are unsafe or unavailable for a given deployment, and modules importing them should be rejected.",
+        severity: "5",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  imports:\n    exclude:\n      - namespace: wasi_snapshot_preview1\n        name: fd_write",
+        remedy: "Remove the import from the module, or remove it from `imports.exclude` if it is allowed.",
+    },
+    Rule {
+        code: "MS-IMPORT-003",
+        property: "imports.max_namespaces",
+        summary: "Checks that the module imports from at most `imports.max_namespaces` namespaces.",
+        rationale: "Each namespace is a host API the module can use. A module which starts importing from new namespaces has grown its reach into the host, and should be reviewed, even if every function it imports is allowed.",
+        severity: "8",
+        classification: Classification::Security,
+        example: "validate:\n  imports:\n    max_namespaces: 2",
+        remedy: "Stop importing from the namespaces the module doesn't need (listed in the failure), or raise `imports.max_namespaces`.",
+    },
+    Rule {
+        code: "MS-IMPORT-004",
+        property: "imports.max_per_namespace",
+        summary: "Checks that the module imports at most `imports.max_per_namespace` functions from each namespace. Failures are reported for each namespace over the limit.",
+        rationale: "Bounds the host API surface a module touches without listing every function it may import. To limit a single namespace, use `max_functions` in `imports.namespace.include`.",
+        severity: "8",
+        classification: Classification::Security,
+        example: "validate:\n  imports:\n    max_per_namespace: 20",
+        remedy: "Stop importing functions the module doesn't need from the namespace, or raise `imports.max_per_namespace`.",
+    },
+    Rule {
+        code: "MS-NAMESPACE-001",
+        property: "imports.namespace.include",
+        summary: "Checks that the module imports from each namespace listed in `imports.namespace.include`, and each function listed for it. A namespace can also limit the number of functions imported from it with `max_functions`, or reject any function not listed with `forbid_unknown_functions: true`.",
+        rationale: "Modules written against a host SDK are expected to use it, so a missing namespace usually means the module was built for a different host.",
+        severity: "8",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  imports:\n    namespace:\n      include:\n        - name: env\n          functions:\n            - log",
+        remedy: "Build the module against the expected host SDK, or remove the namespace from `imports.namespace.include`. A failure ending in `.max_functions`, or for a function which is not listed, means the module imports more from the namespace than the host allows.",
+    },
+    Rule {
+        code: "MS-NAMESPACE-002",
+        property: "imports.namespace.exclude",
+        summary: "Checks that the module imports nothing from each namespace listed in `imports.namespace.exclude`, or none of the functions listed for it.",
+        rationale: "Excluding a whole namespace rejects modules built for an unsupported or disallowed host interface.",
+        severity: "10",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  imports:\n    namespace:\n      exclude:\n        - wasi_snapshot_preview1",
+        remedy: "Remove the imports of the namespace from the module, or remove it from `imports.namespace.exclude`.",
+    },
+    Rule {
+        code: "MS-EXPORT-001",
+        property: "exports.max",
+        summary: "Checks that the module exports at most `exports.max` functions.",
+        rationale: "Every export is an entry point the host can call. A large export surface is harder to audit and often means internal functions are exposed by mistake.",
+        severity: "scaled by how far the count exceeds the maximum, up to 10",
+        classification: Classification::Security,
+        example: "validate:\n  exports:\n    max: 10",
+        remedy: "Stop exporting internal functions (e.g. with linker flags or visibility attributes), or raise `exports.max`.",
+    },
+    Rule {
+        code: "MS-EXPORT-002",
+        property: "exports.include",
+        summary: "Checks that the module exports each function listed in `exports.include`, with a matching signature if one is given.",
+        rationale: "The host calls these exports, and fails at runtime if one is missing or has a different signature.",
+        severity: "10",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  exports:\n    include:\n      - name: _start\n        params: []\n        results: []",
+        remedy: "Export the function from the module with the expected signature, or remove it from `exports.include`. A failure ending in `.params` or `.results` means the signature in the checkfile differs from the module.",
+    },
+    Rule {
+        code: "MS-EXPORT-003",
+        property: "exports.exclude",
+        summary: "Checks that the module does not export any function listed in `exports.exclude`.",
+        rationale: "Some exports, such as memory allocators or debugging hooks, should not be reachable by the host.",
+        severity: "5",
+        classification: Classification::AbiCompatibilty,
+        example: "validate:\n  exports:\n    exclude:\n      - debug_dump",
+        remedy: "Stop exporting the function from the module, or remove it from `exports.exclude`.",
+    },
+    Rule {
+        code: "MS-EXPORT-004",
+        property: "exports.hash",
+        summary: "Checks that the body of an exported function has the SHA-256 hash given for it in `exports.include`.",
+        rationale: "Pinning the hash of a function detects when its implementation changes, even if its name and signature don't.",
+        severity: "10",
+        classification: Classification::Security,
+        example: "validate:\n  exports:\n    include:\n      - name: verify\n        hash: 3f5c...",
+        remedy: "Confirm the change to the function is expected and update the hash (e.g. with `modsurfer generate`), or remove `hash` to stop pinning it.",
+    },
+    Rule {
+        code: "MS-SIZE-001",
+        property: "size.max",
+        summary: "Checks that the module is no larger than `size.max`, e.g. `4MiB`.",
+        rationale: "Large modules take longer to download, compile and instantiate, and use more memory on every host that runs them.",
+        severity: "scaled by how many times larger than the maximum the module is",
+        classification: Classification::ResourceLimit,
+        example: "validate:\n  size:\n    max: 4MiB",
+        remedy: "Build in release mode, strip debug info and custom sections, and optimize the module (e.g. with `wasm-opt -Oz`), or raise `size.max`.",
+    },
+    Rule {
+        code: "MS-COMPLEXITY-001",
+        property: "complexity.max_risk",
+        summary: "Checks that the cyclomatic complexity of the module is within the `complexity.max_risk` level (`low`, `medium` or `high`).",
+        rationale: "Complex modules are harder to review and more likely to take unbounded time to run.",
+        severity: "scaled by how far the complexity exceeds the maximum for the risk level",
+        classification: Classification::ResourceLimit,
+        example: "validate:\n  complexity:\n    max_risk: medium",
+        remedy: "Simplify or split the module, or raise `complexity.max_risk`.",
+    },
+    Rule {
+        code: "MS-RISK-001",
+        property: "risk.max_score",
+        summary: "Checks that the composite risk score of the module (0 to 100) is at most `risk.max_score`. The score adds points for complexity, filesystem, network and clock imports, size, strings containing URLs or credentials, and a missing signature.",
+        rationale: "A single signal such as complexity is too crude to triage modules by; the score combines what a module can reach with how hard it is to review.",
+        severity: "scaled by how far the score exceeds the maximum",
+        classification: Classification::Security,
+        example: "validate:\n  risk:\n    max_score: 40",
+        remedy: "Reduce the signals listed with the score (e.g. remove network imports or sign the module), or raise `risk.max_score`.",
+    },
+    Rule {
+        code: "MS-CAPABILITY-001",
+        property: "capabilities.deny",
+        summary: "Checks that none of the module's imports grant a capability listed in `capabilities.deny`: `filesystem_read`, `filesystem_write`, `network`, `clock`, `randomness`, `environment` or `process_exit`. See `modsurfer capabilities` for what a module is granted.",
+        rationale: "Denying what a module may do on the host is easier to review than listing the individual WASI functions which allow it, and covers both WASI preview 1 and preview 2.",
+        severity: "10",
+        classification: Classification::Security,
+        example: "validate:\n  capabilities:\n    deny:\n      - network\n      - filesystem_write",
+        remedy: "Remove the imports listed in the failure from the module, or remove the capability from `capabilities.deny`.",
+    },
+    Rule {
+        code: "MS-LICENSE-001",
+        property: "licenses.deny",
+        summary: "Checks that no license listed in `licenses.deny` is found in the module's strings or custom sections, from an `SPDX-License-Identifier` tag or the text of a common license. A denied identifier also matches its variants, e.g. `GPL-3.0` matches `GPL-3.0-or-later`.",
+        rationale: "Statically linked libraries carry their license into the module, and some licenses place obligations on distributing it.",
+        severity: "10",
+        classification: Classification::Licensing,
+        example: "validate:\n  licenses:\n    deny:\n      - GPL-3.0\n      - AGPL-3.0",
+        remedy: "Replace the library the license was found with, or remove the license from `licenses.deny` if its obligations are met.",
+    },
+    Rule {
+        code: "MS-VULN-001",
+        property: "vulnerabilities",
+        summary: "Checks that no library found in the module (see `modsurfer vuln`) has a known vulnerability more severe than `vulnerabilities.max_severity` (`low`, `medium`, `high` or `critical`), according to OSV or a local OSV database given by `vulnerabilities.db`. Advisories without a severity are treated as critical.",
+        rationale: "Libraries statically linked into a module carry their vulnerabilities with them, and aren't updated by patching the host.",
+        severity: "3 for low, 5 for medium, 8 for high and 10 for critical vulnerabilities",
+        classification: Classification::Security,
+        example: "validate:\n  vulnerabilities:\n    max_severity: medium",
+        remedy: "Rebuild the module with a fixed version of the library, or add the advisory to a baseline if it doesn't apply to how the library is used.",
+    },
+    Rule {
+        code: "MS-INTEGRITY-001",
+        property: "integrity.duplicate_imports",
+        summary: "Checks, unless `integrity.allow_duplicate_imports` is true, that no function is imported more than once from the same namespace with different signatures.",
+        rationale: "A host can provide only one function for each namespace and name, so an import with a second signature cannot be satisfied; it usually means the module was linked against two versions of a host API.",
+        severity: "10",
+        classification: Classification::Integrity,
+        example: "validate:\n  integrity:\n    allow_duplicate_imports: false",
+        remedy: "Rebuild the module against a single version of the host API, so that every import of the function has the same signature.",
+    },
+    Rule {
+        code: "MS-INTEGRITY-002",
+        property: "integrity.duplicate_exports",
+        summary: "Checks, unless `integrity.allow_duplicate_exports` is true, that no function name is exported more than once.",
+        rationale: "Export names must be unique for a module to be valid, but the module can still be parsed, so it would otherwise only be rejected when it is instantiated.",
+        severity: "10",
+        classification: Classification::Integrity,
+        example: "validate:\n  integrity:\n    allow_duplicate_exports: false",
+        remedy: "Rename or remove the duplicate exports, which are usually left by a post-processing tool which merged or renamed them.",
+    },
+    Rule {
+        code: "MS-INTEGRITY-003",
+        property: "integrity.malformed",
+        summary: "Checks that the module's binary has no structural problems: function or type indices out of bounds, a different number of functions in the function and code sections, sections out of order or repeated, unknown sections, truncated sections or custom section names, and integers encoded with more LEB128 bytes than their type allows. Made for every checkfile, unless `integrity.allow_malformed` is true.",
+        rationale: "A parser can read past these problems, so a malformed or hand-fuzzed binary would otherwise pass validation and only fail (or behave differently between runtimes) when it is loaded.",
+        severity: "10",
+        classification: Classification::Integrity,
+        example: "validate:\n  integrity:\n    allow_malformed: false",
+        remedy: "Rebuild the module with a standard toolchain, or check whichever tool last rewrote it. The property of the failure names the kind of problem and its offset in the binary.",
+    },
+    Rule {
+        code: "MS-NAME-001",
+        property: "module_name.pattern",
+        summary: "Checks that the file name of the module's location matches the regular expression `module_name.pattern`, as a whole. Only made when the location is known: by `modsurfer create`, before the module is stored, and when auditing stored modules.",
+        rationale: "Modules named after their hash, or a build's temporary file, can't be told apart in the registry, nor traced back to the project which built them.",
+        severity: "5",
+        classification: Classification::Identity,
+        example: "validate:\n  module_name:\n    pattern: '[a-z][a-z0-9-]*\\.wasm'",
+        remedy: "Rename the module (or pass a `--location` naming it) to match the pattern, or loosen `module_name.pattern`.",
+    },
+    Rule {
+        code: "MS-LOCATION-001",
+        property: "location.allowed_schemes",
+        summary: "Checks that the module's location is a URL with one of the schemes in `location.allowed_schemes`, e.g. `s3` or `oci`. Only made when the location is known: by `modsurfer create`, before the module is stored, and when auditing stored modules. A plain path on disk is never allowed.",
+        rationale: "Modules kept outside of approved storage may be changed or deleted without review, leaving the registry pointing at artifacts no one controls.",
+        severity: "7",
+        classification: Classification::Identity,
+        example: "validate:\n  location:\n    allowed_schemes: [s3, oci]",
+        remedy: "Upload the module to approved storage and pass its URL with `--location`, or add the scheme to `location.allowed_schemes`.",
+    },
+    Rule {
+        code: "MS-PLUGIN-001",
+        property: "plugins",
+        summary: "Runs each validator plugin listed under `plugins`, which checks the module with code of its own and reports failures under `plugins.<name>`. Plugins run on this machine without WASI, and ask the host for the module's imports, exports and strings.",
+        rationale: "Policies specific to an organization or a host (e.g. naming conventions for exports, or strings which must not ship) can be enforced without forking Modsurfer.",
+        severity: "5, unless the plugin gives another",
+        classification: Classification::Security,
+        example: "validate:\n  plugins:\n    - path: checks/no-debug-exports.wasm\n      sha256: 3a7bd3e2360a3d29eea436fcfb7e44c735d117c42d1c1835420b6b9942dd4f1b",
+        remedy: "Fix the failure the plugin describes, or remove the plugin from `plugins`. A plugin which fails to run fails validation with its error.",
+    },
+    Rule {
+        code: "MS-LINK-001",
+        property: "link",
+        summary: "Checks, with `modsurfer link-check`, that every function imported by the module is provided by a host description or exported by another module, with a matching signature.",
+        rationale: "Composing a module with a host or another module fails at instantiation if any import is missing or has different parameter or result types.",
+        severity: "10 for a missing import, 8 for a mismatched signature",
+        classification: Classification::AbiCompatibilty,
+        example: "provides:\n  - namespace: env\n    name: log\n    params: [I32, I32]\n    results: []",
+        remedy: "Remove the import from the module, or provide it from the host (or export it from the other module) with the same signature. A failure ending in `.params` or `.results` means the signatures differ.",
+    },
+    Rule {
+        code: "MS-UPGRADE-001",
+        property: "upgrade.exports",
+        summary: "Checks, with `modsurfer validate --compare-registry`, that the module still exports every function exported by its latest version in Modsurfer, with the same signature.",
+        rationale: "A host built against the previous version calls its exports, and fails to instantiate (or call) a new version which removed or changed one.",
+        severity: "10 for a removed export, 8 for a changed signature",
+        classification: Classification::AbiCompatibilty,
+        example: "modsurfer validate -p my.wasm -c mod.yaml --compare-registry name=my-plugin",
+        remedy: "Restore the export with its previous signature, or release the module as a new major version along with a host which doesn't need it. A failure ending in `.params` or `.results` means the signature changed.",
+    },
+    Rule {
+        code: "MS-UPGRADE-002",
+        property: "upgrade.imports",
+        summary: "Checks, with `modsurfer validate --compare-registry`, that the module imports no function which its latest version in Modsurfer didn't, and none with a different signature.",
+        rationale: "A host built against the previous version only provides the functions it imported, and can't instantiate a new version which imports anything else.",
+        severity: "10 for an added import, 8 for a changed signature",
+        classification: Classification::AbiCompatibilty,
+        example: "modsurfer validate -p my.wasm -c mod.yaml --compare-registry name=my-plugin",
+        remedy: "Remove the new import from the module, or provide it from the host before releasing the new version. A failure ending in `.params` or `.results` means the signature changed.",
+    },
+    Rule {
+        code: "MS-WIT-001",
+        property: "world.imports",
+        summary: "Checks, with `modsurfer validate --wit`, that every interface and function imported by the component is imported by the WIT world, with a matching signature.",
+        rationale: "A component can only run in a host implementing its world if the world provides everything the component imports.",
+        severity: "10 for an import the world doesn't provide, 8 for a mismatched signature",
+        classification: Classification::AbiCompatibilty,
+        example: "world app {\n  import wasi:cli/environment@0.2.0;\n  import log: func(msg: string);\n}",
+        remedy: "Remove the import from the component, or target a world which provides it.",
+    },
+    Rule {
+        code: "MS-WIT-002",
+        property: "world.exports",
+        summary: "Checks, with `modsurfer validate --wit`, that the component exports every interface and function exported by the WIT world, with a matching signature, and nothing the world doesn't declare.",
+        rationale: "A host implementing the world calls each of its exports, and fails to instantiate a component which is missing one.",
+        severity: "10 for a missing export, 8 for a mismatched signature, 5 for an extra interface or function",
+        classification: Classification::AbiCompatibilty,
+        example: "world app {\n  export run: func() -> u32;\n}",
+        remedy: "Implement the missing export in the component, or remove exports the world doesn't declare.",
+    },
+];
+
+/// Find the rule for a rule code (case-insensitive) or a report property path, e.g.
+/// `imports.include.env::log.params` finds the rule for `imports.include`.
+pub fn find_rule(query: &str) -> Option<&'static Rule> {
+    RULES
+        .iter()
+        .find(|rule| rule.code.eq_ignore_ascii_case(query))
+        .or_else(|| {
+            RULES
+                .iter()
+                .filter(|rule| {
+                    query == rule.property
+                        || query
+                            .strip_prefix(rule.property)
+                            .is_some_and(|rest| rest.starts_with('.'))
+                })
+                .max_by_key(|rule| rule.property.len())
+        })
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} ({})", self.code, self.property)?;
+        writeln!(f)?;
+        writeln!(f, "{}", self.summary)?;
+        writeln!(f)?;
+        writeln!(f, "Why it matters: {}", self.rationale)?;
+        writeln!(f)?;
+        writeln!(f, "Classification: {}", self.classification)?;
+        writeln!(f, "Severity: {}", self.severity)?;
+        writeln!(f)?;
+        writeln!(f, "Example checkfile:")?;
+        writeln!(f)?;
+        self.example
+            .lines()
+            .try_for_each(|line| writeln!(f, "    {}", line))?;
+        writeln!(f)?;
+        write!(f, "To fix or suppress: {}", self.remedy)
+    }
+}
@@ -0,0 +1,86 @@
+//! Conversion of a validation `Report` into a SARIF 2.1.0 log (ref:
+//! https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html), so that failures can be
+//! consumed by code scanning tools.
+use serde_json::{json, Value};
+
+use crate::{find_rule, FailureDetail, Report};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+impl Report {
+    /// Convert the report into a SARIF log with a single run, where each failure is a result
+    /// located in the module at `artifact_uri`.
+    pub fn to_sarif(&self, artifact_uri: &str) -> Value {
+        let rules = self
+            .fails
+            .iter()
+            .map(|(property, detail)| {
+                let mut rule = json!({
+                    "id": property,
+                    "properties": {
+                        "classification": detail.classification.to_string(),
+                    },
+                });
+                if let Some(known) = find_rule(property) {
+                    rule["name"] = json!(known.code);
+                    rule["fullDescription"] = json!({ "text": known.summary });
+                    rule["help"] = json!({ "text": known.remedy });
+                }
+                rule
+            })
+            .collect::<Vec<_>>();
+
+        let results = self
+            .fails
+            .iter()
+            .map(|(property, detail)| {
+                json!({
+                    "ruleId": property,
+                    "level": level(detail),
+                    "message": {
+                        "text": format!(
+                            "{}: expected {}, found {}",
+                            property, detail.expected, detail.actual
+                        ),
+                    },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": artifact_uri },
+                        },
+                    }],
+                    "properties": {
+                        "expected": detail.expected,
+                        "actual": detail.actual,
+                        "severity": detail.severity,
+                        "classification": detail.classification.to_string(),
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "modsurfer",
+                        "informationUri": "https://github.com/dylibso/modsurfer",
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        })
+    }
+}
+
+// map the 1-10 failure severity onto the SARIF result levels
+fn level(detail: &FailureDetail) -> &'static str {
+    match detail.severity {
+        7.. => "error",
+        4..=6 => "warning",
+        _ => "note",
+    }
+}
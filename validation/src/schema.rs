@@ -0,0 +1,242 @@
+//! The JSON Schema of a checkfile, generated from the types it is read into.
+//!
+//! With `set_strict_checkfile`, checkfiles are checked against the schema before they are read,
+//! so that every structural error (an unknown field, a value of the wrong type) is reported at
+//! once with its line, rather than only the first error found by serde.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use schemars::gen::SchemaSettings;
+use schemars::schema::RootSchema;
+
+use crate::Validation;
+
+/// The JSON Schema of a checkfile.
+pub fn checkfile_schema() -> RootSchema {
+    // checks which aren't made are left out rather than set to `null`, so that an error in a
+    // check is reported within it, rather than as the check matching neither its schema nor `null`
+    SchemaSettings::draft07()
+        .with(|settings| settings.option_add_null_type = false)
+        .into_generator()
+        .into_root_schema_for::<Validation>()
+}
+
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// Check every checkfile read from here on against the schema before reading it, failing with all
+/// of the errors found.
+pub fn set_strict_checkfile(strict: bool) {
+    STRICT.store(strict, Ordering::Relaxed);
+}
+
+/// Whether checkfiles are checked against the schema before they are read.
+pub fn strict_checkfile() -> bool {
+    STRICT.load(Ordering::Relaxed)
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use strict::{check_checkfile, schema_errors, SchemaError};
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod strict {
+    use std::collections::HashMap;
+    use std::fmt::Display;
+
+    use anyhow::Result;
+    use jsonschema::{error::ValidationErrorKind, JSONSchema};
+    use serde::Serialize;
+    use yaml_rust::parser::{Event, MarkedEventReceiver, Parser};
+    use yaml_rust::scanner::Marker;
+
+    use super::checkfile_schema;
+
+    /// A part of a checkfile which doesn't match the schema.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct SchemaError {
+        /// the line the error was found on, counting from 1
+        pub line: Option<usize>,
+        /// a JSON pointer to the value in error, e.g. `/validate/size/max`
+        pub path: String,
+        pub message: String,
+    }
+
+    impl Display for SchemaError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if let Some(line) = self.line {
+                write!(f, "line {}: ", line)?;
+            }
+            if !self.path.is_empty() {
+                write!(f, "{}: ", self.path)?;
+            }
+            f.write_str(&self.message)
+        }
+    }
+
+    /// Check a checkfile against the schema, returning every error found, in line order. Fails
+    /// only if the checkfile isn't valid YAML.
+    pub fn schema_errors(checkfile: &[u8]) -> Result<Vec<SchemaError>> {
+        let mut value: serde_json::Value = serde_yaml::from_slice(checkfile)?;
+        remove_nulls(&mut value);
+        let schema = serde_json::to_value(checkfile_schema())?;
+        let schema = JSONSchema::compile(&schema)
+            .map_err(|e| anyhow::anyhow!("invalid checkfile schema: {}", e))?;
+        let lines = yaml_lines(&String::from_utf8_lossy(checkfile));
+
+        let mut errors = vec![];
+        if let Err(found) = schema.validate(&value) {
+            for e in found {
+                let path = e.instance_path.to_string();
+                match &e.kind {
+                    // report each unknown field on its own line
+                    ValidationErrorKind::AdditionalProperties { unexpected } => {
+                        errors.extend(unexpected.iter().map(|field| {
+                            let path = format!("{}/{}", path, escape(field));
+                            SchemaError {
+                                line: line_of(&lines, &path),
+                                path,
+                                message: format!("unknown field `{}`", field),
+                            }
+                        }))
+                    }
+                    _ => errors.push(SchemaError {
+                        line: line_of(&lines, &path),
+                        path,
+                        message: e.to_string(),
+                    }),
+                }
+            }
+        }
+        errors.sort_by(|a, b| (a.line, &a.path).cmp(&(b.line, &b.path)));
+
+        Ok(errors)
+    }
+
+    /// Fail with every error found if a checkfile doesn't match the schema.
+    pub fn check_checkfile(checkfile: &[u8]) -> Result<()> {
+        let errors = schema_errors(checkfile)?;
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        let mut message = format!(
+            "checkfile doesn't match the schema ({} error{}):",
+            errors.len(),
+            if errors.len() == 1 { "" } else { "s" }
+        );
+        for error in errors {
+            message.push_str("\n  ");
+            message.push_str(&error.to_string());
+        }
+        anyhow::bail!(message)
+    }
+
+    // a field set to `null` is read as unset, as if it were left out
+    fn remove_nulls(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(fields) => {
+                fields.retain(|_, v| !v.is_null());
+                fields.values_mut().for_each(remove_nulls);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(remove_nulls),
+            _ => {}
+        }
+    }
+
+    // the line of the value at `path`, or of the nearest value containing it
+    fn line_of(lines: &HashMap<String, usize>, mut path: &str) -> Option<usize> {
+        loop {
+            if let Some(line) = lines.get(path) {
+                return Some(*line);
+            }
+            path = &path[..path.rfind('/')?];
+        }
+    }
+
+    // the line of each value in a YAML document, keyed by its JSON pointer. A value in a mapping
+    // is given the line of its key.
+    fn yaml_lines(yaml: &str) -> HashMap<String, usize> {
+        let mut lines = YamlLines::default();
+        // an invalid document has already failed to deserialize
+        let _ = Parser::new(yaml.chars()).load(&mut lines, false);
+        lines.lines
+    }
+
+    enum Frame {
+        Mapping { path: String, key: Option<String> },
+        Sequence { path: String, index: usize },
+    }
+
+    #[derive(Default)]
+    struct YamlLines {
+        stack: Vec<Frame>,
+        lines: HashMap<String, usize>,
+    }
+
+    impl YamlLines {
+        // the path of the value starting at the current event
+        fn value_path(&self) -> String {
+            match self.stack.last() {
+                None => String::new(),
+                Some(Frame::Mapping {
+                    path,
+                    key: Some(key),
+                }) => format!("{}/{}", path, escape(key)),
+                Some(Frame::Mapping { path, key: None }) => path.clone(),
+                Some(Frame::Sequence { path, index }) => format!("{}/{}", path, index),
+            }
+        }
+
+        fn end_value(&mut self) {
+            match self.stack.last_mut() {
+                Some(Frame::Mapping { key, .. }) => *key = None,
+                Some(Frame::Sequence { index, .. }) => *index += 1,
+                None => {}
+            }
+        }
+
+        fn start_value(&mut self, mark: Marker) -> String {
+            let path = self.value_path();
+            self.lines.entry(path.clone()).or_insert(mark.line());
+            path
+        }
+    }
+
+    impl MarkedEventReceiver for YamlLines {
+        fn on_event(&mut self, ev: Event, mark: Marker) {
+            match ev {
+                Event::Scalar(value, ..) => match self.stack.last_mut() {
+                    Some(Frame::Mapping { path, key }) if key.is_none() => {
+                        let path = format!("{}/{}", path, escape(&value));
+                        self.lines.entry(path).or_insert(mark.line());
+                        *key = Some(value);
+                    }
+                    _ => {
+                        self.start_value(mark);
+                        self.end_value();
+                    }
+                },
+                Event::Alias(_) => {
+                    self.start_value(mark);
+                    self.end_value();
+                }
+                Event::MappingStart(_) => {
+                    let path = self.start_value(mark);
+                    self.stack.push(Frame::Mapping { path, key: None });
+                }
+                Event::SequenceStart(_) => {
+                    let path = self.start_value(mark);
+                    self.stack.push(Frame::Sequence { path, index: 0 });
+                }
+                Event::MappingEnd | Event::SequenceEnd => {
+                    self.stack.pop();
+                    self.end_value();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // escape a key as a JSON pointer token
+    fn escape(key: &str) -> String {
+        key.replace('~', "~0").replace('/', "~1")
+    }
+}
@@ -0,0 +1,63 @@
+//! Shared construction of the tables used to display reports, so that their borders and width can
+//! be configured once (e.g. from CLI flags) for every table printed by the process.
+use std::sync::RwLock;
+
+use comfy_table::{
+    modifiers::UTF8_SOLID_INNER_BORDERS,
+    presets::{ASCII_FULL, UTF8_FULL},
+    Cell, ColumnConstraint, ContentArrangement, Table, Width,
+};
+
+static TABLE_STYLE: RwLock<TableStyle> = RwLock::new(TableStyle {
+    ascii: false,
+    max_width: None,
+    max_column_width: None,
+});
+
+/// How tables are rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TableStyle {
+    /// draw borders with ASCII characters only, instead of UTF-8 box drawing characters
+    pub ascii: bool,
+    /// the total width of a table, wrapping cell content to fit
+    pub max_width: Option<u16>,
+    /// the width of any single column, wrapping cell content to fit
+    pub max_column_width: Option<u16>,
+}
+
+/// Set the style of every table created by `new_table` from here on.
+pub fn set_table_style(style: TableStyle) {
+    *TABLE_STYLE.write().unwrap_or_else(|e| e.into_inner()) = style;
+}
+
+/// The style currently applied by `new_table`.
+pub fn table_style() -> TableStyle {
+    *TABLE_STYLE.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Create a table with the given header, styled with the current `TableStyle`.
+pub fn new_table<T: Into<Cell>>(header: Vec<T>) -> Table {
+    let style = table_style();
+    let mut table = Table::new();
+
+    if style.ascii {
+        table.load_preset(ASCII_FULL);
+    } else {
+        table.load_preset(UTF8_FULL);
+        table.apply_modifier(UTF8_SOLID_INNER_BORDERS);
+    }
+    table.set_header(header);
+
+    if let Some(width) = style.max_width {
+        table.set_content_arrangement(ContentArrangement::Dynamic);
+        table.set_width(width);
+    }
+    if let Some(width) = style.max_column_width {
+        table.set_content_arrangement(ContentArrangement::Dynamic);
+        table.column_iter_mut().for_each(|column| {
+            column.set_constraint(ColumnConstraint::UpperBoundary(Width::Fixed(width)));
+        });
+    }
+
+    table
+}
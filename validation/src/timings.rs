@@ -0,0 +1,146 @@
+//! Measurements of where time goes when validating a module, for profiling checkfiles and
+//! pipelines.
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+/// How long each stage of validating a module took, and the peak memory used by the process.
+#[derive(Debug, Default, Serialize)]
+pub struct Timings {
+    /// reading and parsing the module
+    #[serde(serialize_with = "millis")]
+    pub parse: Duration,
+    /// reading the checkfile, and fetching the remote checkfile it refers to, if any
+    #[serde(serialize_with = "millis")]
+    pub checkfile: Duration,
+    /// whether the checkfile was fetched from a URL
+    pub fetched: bool,
+    /// each category of checks which ran, e.g. `imports`, in the order they ran
+    pub checks: Vec<CheckTiming>,
+    #[serde(serialize_with = "millis")]
+    pub total: Duration,
+    /// the peak resident memory of the process in bytes, where the platform reports it
+    pub peak_memory: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckTiming {
+    pub category: &'static str,
+    #[serde(serialize_with = "millis")]
+    pub duration: Duration,
+}
+
+fn millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+/// Records the time taken by each check category. A disabled stopwatch never reads the clock,
+/// which isn't available on every target (e.g. `wasm32-unknown-unknown`).
+pub(crate) struct Stopwatch {
+    last: Option<Instant>,
+    laps: Vec<CheckTiming>,
+}
+
+impl Stopwatch {
+    pub(crate) fn disabled() -> Self {
+        Stopwatch {
+            last: None,
+            laps: vec![],
+        }
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    pub(crate) fn start() -> Self {
+        Stopwatch {
+            last: Some(Instant::now()),
+            laps: vec![],
+        }
+    }
+
+    /// Record the time since the previous lap (or the start) against `category`.
+    pub(crate) fn lap(&mut self, category: &'static str) {
+        if let Some(last) = self.last {
+            let now = Instant::now();
+            self.laps.push(CheckTiming {
+                category,
+                duration: now - last,
+            });
+            self.last = Some(now);
+        }
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    pub(crate) fn laps(self) -> Vec<CheckTiming> {
+        self.laps
+    }
+}
+
+/// The peak resident set size of this process, from `VmHWM` in `/proc/self/status`.
+#[cfg(target_os = "linux")]
+pub fn peak_memory() -> Option<u64> {
+    status_bytes("VmHWM:")
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn peak_memory() -> Option<u64> {
+    None
+}
+
+/// The current resident set size of this process, from `VmRSS` in `/proc/self/status`.
+#[cfg(target_os = "linux")]
+pub(crate) fn resident_memory() -> Option<u64> {
+    status_bytes("VmRSS:")
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn resident_memory() -> Option<u64> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn status_bytes(field: &str) -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix(field))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kib * 1024)
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl std::fmt::Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ms = |d: &Duration| format!("{:.2} ms", d.as_secs_f64() * 1000.0);
+        let mut table = crate::new_table(vec!["Stage", "Time"]);
+
+        table.add_row(vec!["parse".to_string(), ms(&self.parse)]);
+        table.add_row(vec![
+            if self.fetched {
+                "checkfile (fetched)".to_string()
+            } else {
+                "checkfile".to_string()
+            },
+            ms(&self.checkfile),
+        ]);
+        self.checks.iter().for_each(|check| {
+            table.add_row(vec![
+                format!("check: {}", check.category),
+                ms(&check.duration),
+            ]);
+        });
+        table.add_row(vec!["total".to_string(), ms(&self.total)]);
+        if let Some(peak) = self.peak_memory {
+            table.add_row(vec![
+                "peak memory".to_string(),
+                human_bytes::human_bytes(peak as f64),
+            ]);
+        }
+
+        f.write_str(table.to_string().as_str())
+    }
+}
@@ -0,0 +1,59 @@
+//! Upgrade checks, which compare a module with its previous version and report the changes which
+//! would break a host built against the previous version.
+use modsurfer_module::Module;
+
+use crate::{Classification, Report};
+
+/// Check that `module` can replace `previous` without changes to the host: every function
+/// `previous` exports is still exported with the same signature, and `module` imports nothing
+/// which `previous` didn't, nor with a different signature. Failures are reported under
+/// `upgrade.exports.<name>` and `upgrade.imports.<namespace>::<name>`.
+///
+/// Removing an import or adding an export doesn't break the host, so neither is reported.
+pub fn upgrade_check(previous: &Module, module: &Module) -> Report {
+    let mut report = Report::new();
+
+    previous.exports.iter().for_each(|export| {
+        let name = &export.func.name;
+        let property = format!("upgrade.exports.{}", name);
+        match module.find_export(name) {
+            Some(current) => report.validate_fn_type(
+                &property,
+                &current.func.ty,
+                Some(&export.func.ty.params),
+                Some(&export.func.ty.results),
+            ),
+            None => report.validate_fn(
+                &property,
+                "exported",
+                "removed",
+                false,
+                10,
+                Classification::AbiCompatibilty,
+            ),
+        }
+    });
+
+    module.imports.iter().for_each(|import| {
+        let name = &import.func.name;
+        let property = format!("upgrade.imports.{}::{}", import.module_name, name);
+        match previous.find_import(&import.module_name, name) {
+            Some(before) => report.validate_fn_type(
+                &property,
+                &import.func.ty,
+                Some(&before.func.ty.params),
+                Some(&before.func.ty.results),
+            ),
+            None => report.validate_fn(
+                &property,
+                "not imported",
+                "added",
+                false,
+                10,
+                Classification::AbiCompatibilty,
+            ),
+        }
+    });
+
+    report
+}
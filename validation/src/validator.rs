@@ -0,0 +1,229 @@
+//! Validator plugins, listed under `plugins` in a checkfile, which check a module with code of
+//! their own:
+//!
+//! ```yaml
+//! validate:
+//!   plugins:
+//!     - path: checks/no-debug-exports.wasm
+//! ```
+//!
+//! Each plugin exports a `validate` function, called once for every module validated. Rather than
+//! decode the whole module, a plugin asks the host for the facts it needs, and reports each failure
+//! as soon as it finds it, with these host functions (all values are JSON):
+//!
+//! - `get_import_list() -> [{"namespace", "name", "params", "results"}]`: the imported functions
+//! - `get_export_list() -> [{"name", "params", "results"}]`: the exported functions
+//! - `get_strings(filter) -> [string]`: the strings found in the module which contain `filter`
+//! - `emit_finding({"property", "expected", "actual", "severity", "classification"})`: report a
+//!   failure, added to the report as `plugins.<name>.<property>`. `severity` (from 1 to 10) and
+//!   `classification` (e.g. `Security`) may be left out.
+//!
+//! Plugins run within the same `PluginLimits` as the parser, set by `Parser::set_limits`.
+use std::sync::PoisonError;
+
+use anyhow::Result;
+use extism::{CurrentPlugin, Function, UserData, Val, PTR};
+use modsurfer_module::{Export, Import, Module, ValType};
+use serde::{Deserialize, Serialize};
+
+use crate::plugin::{read_wasm, LocalPlugin};
+use crate::registry::{Finding, Rule, RuleConfig};
+use crate::{Classification, Parser, ValidatorPlugin};
+
+/// The function each validator plugin must export.
+const VALIDATE_FUNCTION: &str = "validate";
+
+pub(crate) struct Plugins;
+
+impl Rule for Plugins {
+    fn name(&self) -> &'static str {
+        "plugins"
+    }
+
+    fn classification(&self) -> Classification {
+        Classification::Security
+    }
+
+    fn default_severity(&self) -> usize {
+        5
+    }
+
+    fn enabled(&self, config: &RuleConfig) -> bool {
+        config
+            .check
+            .plugins
+            .as_ref()
+            .is_some_and(|plugins| !plugins.is_empty())
+    }
+
+    // plugins may read the module's strings with `get_strings`
+    fn needs_strings(&self) -> bool {
+        true
+    }
+
+    fn evaluate(&self, module: &Module, config: &RuleConfig) -> Result<Vec<Finding>> {
+        let mut findings = vec![];
+        for plugin in config.check.plugins.iter().flatten() {
+            let name = plugin.name();
+            for finding in run(plugin, &name, module)? {
+                findings.push(Finding {
+                    property: format!("plugins.{}.{}", name, finding.property),
+                    expected: finding.expected,
+                    actual: finding.actual,
+                    severity: finding.severity.unwrap_or_else(|| self.default_severity()),
+                    classification: finding
+                        .classification
+                        .unwrap_or_else(|| self.classification()),
+                });
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+// a failure reported by a plugin with `emit_finding`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PluginFinding {
+    property: String,
+    expected: String,
+    actual: String,
+    severity: Option<usize>,
+    classification: Option<Classification>,
+}
+
+// the facts of the module a plugin may ask for, and the findings it has reported so far
+struct HostState {
+    imports: Vec<Import>,
+    exports: Vec<Export>,
+    strings: Vec<String>,
+    findings: Vec<PluginFinding>,
+}
+
+#[derive(Serialize)]
+struct HostImport<'a> {
+    namespace: &'a str,
+    name: &'a str,
+    params: &'a [ValType],
+    results: &'a [ValType],
+}
+
+#[derive(Serialize)]
+struct HostExport<'a> {
+    name: &'a str,
+    params: &'a [ValType],
+    results: &'a [ValType],
+}
+
+// call a plugin's `validate` function on a module, returning the findings it reported
+fn run(plugin: &ValidatorPlugin, name: &str, module: &Module) -> Result<Vec<PluginFinding>> {
+    let wasm = read_wasm(&plugin.path, plugin.sha256.as_deref())?;
+
+    let state = UserData::new(HostState {
+        imports: module.imports.clone(),
+        exports: module.exports.clone(),
+        strings: module.strings.clone(),
+        findings: vec![],
+    });
+    let mut local = LocalPlugin::with_functions(
+        name,
+        &wasm,
+        Parser::shared().limits(),
+        host_functions(&state),
+    )?;
+    local.call(VALIDATE_FUNCTION, &[])?;
+
+    with_state(&state, |state| std::mem::take(&mut state.findings))
+}
+
+fn host_functions(state: &UserData<HostState>) -> Vec<Function> {
+    vec![
+        Function::new("get_import_list", [], [PTR], state.clone(), get_import_list),
+        Function::new("get_export_list", [], [PTR], state.clone(), get_export_list),
+        Function::new("get_strings", [PTR], [PTR], state.clone(), get_strings),
+        Function::new("emit_finding", [PTR], [], state.clone(), emit_finding),
+    ]
+}
+
+fn get_import_list(
+    plugin: &mut CurrentPlugin,
+    _inputs: &[Val],
+    outputs: &mut [Val],
+    state: UserData<HostState>,
+) -> Result<()> {
+    let json = with_state(&state, |state| {
+        let imports = state
+            .imports
+            .iter()
+            .map(|import| HostImport {
+                namespace: &import.module_name,
+                name: &import.func.name,
+                params: &import.func.ty.params,
+                results: &import.func.ty.results,
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string(&imports)
+    })??;
+    plugin.memory_set_val(&mut outputs[0], json)
+}
+
+fn get_export_list(
+    plugin: &mut CurrentPlugin,
+    _inputs: &[Val],
+    outputs: &mut [Val],
+    state: UserData<HostState>,
+) -> Result<()> {
+    let json = with_state(&state, |state| {
+        let exports = state
+            .exports
+            .iter()
+            .map(|export| HostExport {
+                name: &export.func.name,
+                params: &export.func.ty.params,
+                results: &export.func.ty.results,
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string(&exports)
+    })??;
+    plugin.memory_set_val(&mut outputs[0], json)
+}
+
+fn get_strings(
+    plugin: &mut CurrentPlugin,
+    inputs: &[Val],
+    outputs: &mut [Val],
+    state: UserData<HostState>,
+) -> Result<()> {
+    let filter: String = plugin.memory_get_val(&inputs[0])?;
+    let json = with_state(&state, |state| {
+        let strings = state
+            .strings
+            .iter()
+            .filter(|s| s.contains(filter.as_str()))
+            .collect::<Vec<_>>();
+        serde_json::to_string(&strings)
+    })??;
+    plugin.memory_set_val(&mut outputs[0], json)
+}
+
+fn emit_finding(
+    plugin: &mut CurrentPlugin,
+    inputs: &[Val],
+    _outputs: &mut [Val],
+    state: UserData<HostState>,
+) -> Result<()> {
+    let json: String = plugin.memory_get_val(&inputs[0])?;
+    let finding: PluginFinding =
+        serde_json::from_str(&json).map_err(|e| anyhow::anyhow!("invalid finding: {}", e))?;
+    if let Some(severity) = finding.severity.filter(|s| !(1..=10).contains(s)) {
+        anyhow::bail!("invalid finding: severity {} is not from 1 to 10", severity);
+    }
+    with_state(&state, |state| state.findings.push(finding))
+}
+
+fn with_state<R>(state: &UserData<HostState>, f: impl FnOnce(&mut HostState) -> R) -> Result<R> {
+    let state = state.get()?;
+    let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+    Ok(f(&mut state))
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+
+use serde::Serialize;
+
+use crate::{Classification, FailureDetail, Report};
+
+/// How the failures in a report which meet the severity threshold affect the outcome.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub enum FailurePolicy {
+    /// any failure at or above the threshold fails validation
+    #[default]
+    Fail,
+    /// failures are reported, but never fail validation
+    Warn,
+}
+
+/// The number and highest severity of failures in a classification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ClassificationSummary {
+    pub failures: usize,
+    pub max_severity: usize,
+}
+
+/// The decision made from a validation report, shared by every consumer of reports so that they
+/// agree on whether a module passed and which exit code to use.
+#[derive(Clone, Copy, Debug)]
+pub struct Verdict<'a> {
+    report: &'a Report,
+    min_severity: usize,
+    policy: FailurePolicy,
+}
+
+impl<'a> Verdict<'a> {
+    /// A verdict which fails on any failure in the report.
+    pub fn new(report: &'a Report) -> Self {
+        Verdict {
+            report,
+            min_severity: 0,
+            policy: FailurePolicy::default(),
+        }
+    }
+
+    /// Ignore failures with a severity lower than `min_severity`.
+    pub fn min_severity(mut self, min_severity: usize) -> Self {
+        self.min_severity = min_severity;
+        self
+    }
+
+    pub fn policy(mut self, policy: FailurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn report(&self) -> &'a Report {
+        self.report
+    }
+
+    /// The failures at or above the severity threshold.
+    pub fn failures(&self) -> impl Iterator<Item = (&'a String, &'a FailureDetail)> {
+        let min_severity = self.min_severity;
+        self.report
+            .fails
+            .iter()
+            .filter(move |(_, detail)| detail.severity >= min_severity)
+    }
+
+    pub fn passed(&self) -> bool {
+        match self.policy {
+            FailurePolicy::Fail => self.failures().next().is_none(),
+            FailurePolicy::Warn => true,
+        }
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        if self.passed() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+
+    /// Count the failures at or above the severity threshold by their classification.
+    pub fn summary(&self) -> BTreeMap<Classification, ClassificationSummary> {
+        self.failures()
+            .fold(BTreeMap::new(), |mut acc, (_, detail)| {
+                let summary: &mut ClassificationSummary =
+                    acc.entry(detail.classification).or_default();
+                summary.failures += 1;
+                summary.max_severity = summary.max_severity.max(detail.severity);
+                acc
+            })
+    }
+}
@@ -0,0 +1,412 @@
+//! Known vulnerabilities of the libraries found in a module (see `Module::provenance`), looked up
+//! in [OSV](https://osv.dev) or in a local database of advisories in the OSV format.
+//!
+//! Libraries are looked up as `OSS-Fuzz` packages, the OSV ecosystem which covers C and C++
+//! libraries such as zlib and OpenSSL.
+
+// advisories can only be looked up where there is a network and filesystem
+#![cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), allow(dead_code))]
+
+use std::fmt::Display;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::path::Path;
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use anyhow::{Context, Result};
+use modsurfer_module::Library;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use crate::{Classification, Report};
+
+/// The advisory database used unless a path to a local database is given.
+pub const OSV_DB: &str = "osv";
+
+const OSV_QUERY_URL: &str = "https://api.osv.dev/v1/query";
+const OSV_ECOSYSTEM: &str = "OSS-Fuzz";
+
+// the OSV package name of each library found by `Provenance::scan`, where it differs
+const OSV_PACKAGES: &[(&str, &str)] = &[
+    ("OpenSSL", "openssl"),
+    ("Mbed TLS", "mbedtls"),
+    ("SQLite", "sqlite3"),
+    ("libcurl", "curl"),
+    ("Lua", "lua"),
+];
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// Parse a severity as written by OSV databases, e.g. `HIGH` or `MODERATE`.
+    pub fn parse(s: &str) -> Option<Severity> {
+        match s.to_ascii_lowercase().as_str() {
+            "low" => Some(Severity::Low),
+            "medium" | "moderate" => Some(Severity::Medium),
+            "high" => Some(Severity::High),
+            "critical" => Some(Severity::Critical),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+
+    // the severity of a report failure, from 1 to 10
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    fn points(&self) -> usize {
+        match self {
+            Severity::Low => 3,
+            Severity::Medium => 5,
+            Severity::High => 8,
+            Severity::Critical => 10,
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An advisory affecting a library found in a module.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    pub library: Library,
+    /// the advisory ID, e.g. `OSV-2022-394`
+    pub id: String,
+    /// other IDs of the advisory, e.g. a CVE
+    pub aliases: Vec<String>,
+    pub summary: Option<String>,
+    /// the severity given by the advisory database, if any
+    pub severity: Option<Severity>,
+}
+
+impl Finding {
+    /// The severity used to gate on findings. Advisories without a severity are assumed to be
+    /// critical.
+    pub fn effective_severity(&self) -> Severity {
+        self.severity.unwrap_or(Severity::Critical)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Findings {
+    pub findings: Vec<Finding>,
+}
+
+// the subset of the OSV schema used to match and describe advisories, see
+// https://ossf.github.io/osv-schema/
+#[derive(Debug, Deserialize)]
+struct OsvVulnerability {
+    id: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    summary: Option<String>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+    database_specific: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAffected {
+    package: Option<OsvPackage>,
+    #[serde(default)]
+    versions: Vec<String>,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+    ecosystem_specific: Option<serde_json::Value>,
+    database_specific: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvPackage {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    events: Vec<OsvEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvEvent {
+    introduced: Option<String>,
+    fixed: Option<String>,
+    last_affected: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OsvRecords {
+    One(OsvVulnerability),
+    Many(Vec<OsvVulnerability>),
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvQueryResponse {
+    #[serde(default)]
+    vulns: Vec<OsvVulnerability>,
+}
+
+impl OsvVulnerability {
+    // OSV databases put the severity in `database_specific` (e.g. GitHub) or in the
+    // `ecosystem_specific` fields of the affected packages (e.g. OSS-Fuzz)
+    fn severity(&self) -> Option<Severity> {
+        let severity = |v: &Option<serde_json::Value>| {
+            v.as_ref()
+                .and_then(|v| v.get("severity"))
+                .and_then(|s| s.as_str())
+                .and_then(Severity::parse)
+        };
+        severity(&self.database_specific).or_else(|| {
+            self.affected
+                .iter()
+                .find_map(|a| severity(&a.ecosystem_specific).or(severity(&a.database_specific)))
+        })
+    }
+
+    fn affects(&self, package: &str, version: &str) -> bool {
+        self.affected.iter().any(|affected| {
+            affected
+                .package
+                .as_ref()
+                .is_some_and(|p| p.name.eq_ignore_ascii_case(package))
+                && (affected.versions.iter().any(|v| v == version)
+                    || affected.ranges.iter().any(|r| r.contains(version)))
+        })
+    }
+
+    fn finding(&self, library: &Library) -> Finding {
+        Finding {
+            library: library.clone(),
+            id: self.id.clone(),
+            aliases: self.aliases.clone(),
+            summary: self.summary.clone(),
+            severity: self.severity(),
+        }
+    }
+}
+
+impl OsvRange {
+    // whether `version` falls in any interval of the range's events, which are in version order.
+    // Ranges of git commits can't be compared with a version string.
+    fn contains(&self, version: &str) -> bool {
+        if self.kind == "GIT" {
+            return false;
+        }
+
+        let mut affected = false;
+        for event in &self.events {
+            if let Some(introduced) = &event.introduced {
+                if introduced == "0" || compare_versions(version, introduced).is_ge() {
+                    affected = true;
+                }
+            }
+            if let Some(fixed) = &event.fixed {
+                if compare_versions(version, fixed).is_ge() {
+                    affected = false;
+                }
+            }
+            if let Some(last) = &event.last_affected {
+                if compare_versions(version, last).is_gt() {
+                    affected = false;
+                }
+            }
+        }
+        affected
+    }
+}
+
+// compare dotted versions part by part, numerically where a part starts with digits, so that
+// `1.2.11` > `1.2.9` and `1.1.1k` > `1.1.1j`
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let parts = |v: &str| {
+        v.trim_start_matches('v')
+            .split(['.', '-', '_'])
+            .map(|part| {
+                let digits =
+                    part.len() - part.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                (
+                    part[..digits].parse::<u64>().unwrap_or_default(),
+                    part[digits..].to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (parts(a), parts(b));
+    let len = a.len().max(b.len());
+    let zero = (0, String::new());
+
+    (0..len)
+        .map(|i| a.get(i).unwrap_or(&zero).cmp(b.get(i).unwrap_or(&zero)))
+        .find(|order| order.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
+fn osv_package(library: &Library) -> String {
+    OSV_PACKAGES
+        .iter()
+        .find(|(name, _)| *name == library.name)
+        .map(|(_, package)| package.to_string())
+        .unwrap_or_else(|| library.name.to_lowercase())
+}
+
+/// Find the advisories affecting `libraries` in `db`, which is either `OSV_DB` to query the OSV
+/// API, or a path to a local OSV database: a JSON file of one or more advisories, or a directory
+/// of them (e.g. an extracted OSV export).
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub async fn find_vulnerabilities(libraries: &[Library], db: &str) -> Result<Findings> {
+    let mut findings = vec![];
+
+    if db == OSV_DB {
+        let client = crate::http::http_client()?;
+        for library in libraries {
+            let query = serde_json::json!({
+                "version": library.version,
+                "package": { "name": osv_package(library), "ecosystem": OSV_ECOSYSTEM },
+            });
+            let resp = client
+                .post(OSV_QUERY_URL)
+                .timeout(crate::http::fetch_timeout()?)
+                .body(query.to_string())
+                .send()
+                .await
+                .and_then(|resp| resp.error_for_status())
+                .with_context(|| format!("Failed to query OSV for {}", library))?;
+            let resp: OsvQueryResponse = serde_json::from_slice(&resp.bytes().await?)?;
+            findings.extend(resp.vulns.iter().map(|v| v.finding(library)));
+        }
+    } else {
+        let advisories = read_local_db(Path::new(db)).await?;
+        for library in libraries {
+            let package = osv_package(library);
+            findings.extend(
+                advisories
+                    .iter()
+                    .filter(|v| v.affects(&package, &library.version))
+                    .map(|v| v.finding(library)),
+            );
+        }
+    }
+
+    findings.sort_by(|a, b| {
+        b.effective_severity()
+            .cmp(&a.effective_severity())
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(Findings { findings })
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+async fn read_local_db(path: &Path) -> Result<Vec<OsvVulnerability>> {
+    let read = |buf: Vec<u8>, path: &Path| -> Result<Vec<OsvVulnerability>> {
+        match serde_json::from_slice(&buf)
+            .with_context(|| format!("invalid OSV advisory {}", path.display()))?
+        {
+            OsvRecords::One(v) => Ok(vec![v]),
+            OsvRecords::Many(vs) => Ok(vs),
+        }
+    };
+
+    if !tokio::fs::metadata(path)
+        .await
+        .with_context(|| format!("advisory database {} not found", path.display()))?
+        .is_dir()
+    {
+        return read(tokio::fs::read(path).await?, path);
+    }
+
+    let mut advisories = vec![];
+    let mut entries = tokio::fs::read_dir(path).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            advisories.extend(read(tokio::fs::read(&path).await?, &path)?);
+        }
+    }
+
+    Ok(advisories)
+}
+
+/// Fail the report for each advisory affecting `libraries` which is more severe than
+/// `max_severity`, under `vulnerabilities.<advisory id>`.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) async fn check_vulnerabilities(
+    report: &mut Report,
+    max_severity: Severity,
+    db: &str,
+    libraries: &[Library],
+) -> Result<()> {
+    let findings = find_vulnerabilities(libraries, db).await?;
+    findings.findings.iter().for_each(|finding| {
+        let severity = finding.effective_severity();
+        report.validate_fn(
+            format_args!("vulnerabilities.{}", finding.id),
+            format!("<= {max_severity}"),
+            format!(
+                "{severity} in {}{}",
+                finding.library,
+                finding
+                    .summary
+                    .as_ref()
+                    .map(|s| format!(": {s}"))
+                    .unwrap_or_default()
+            ),
+            severity <= max_severity,
+            severity.points(),
+            Classification::Security,
+        );
+    });
+
+    Ok(())
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl Display for Findings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.findings.is_empty() {
+            return f.write_str("No known vulnerabilities found.");
+        }
+
+        let mut table = crate::new_table(vec!["Library", "Advisory", "Severity", "Summary"]);
+        self.findings.iter().for_each(|finding| {
+            table.add_row(vec![
+                finding.library.to_string(),
+                std::iter::once(finding.id.as_str())
+                    .chain(finding.aliases.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                finding
+                    .severity
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "unknown".into()),
+                finding.summary.clone().unwrap_or_default(),
+            ]);
+        });
+
+        f.write_str(table.to_string().as_str())
+    }
+}
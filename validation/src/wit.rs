@@ -0,0 +1,487 @@
+//! Conformance of a component to a WIT world: every interface and function the component imports
+//! must be imported by the world, and everything the world exports must be exported by the
+//! component, with matching signatures.
+//!
+//! Worlds are read with `wit-parser`, so `use`, `include`, resources and inline interfaces are
+//! understood, and interfaces from other packages (e.g. `import wasi:cli/environment@0.2.0;`) are
+//! read from the `deps` directory of a WIT package. The world a component targets is decoded
+//! from its binary, and the two are compared by the names of their imports and exports:
+//! interfaces by their qualified name (e.g. `my:app/host@1.0.0`), and functions and inline
+//! interfaces by their own. Type aliases are compared by the type they refer to, while records,
+//! variants, enums, flags and resources are compared by name.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use wit_parser::decoding::{decode, DecodedWasm};
+use wit_parser::{
+    Function, Handle, Resolve, Results, Type, TypeDefKind, UnresolvedPackage, WorldId, WorldItem,
+};
+
+use crate::{Classification, Report};
+
+/// A world, read from WIT or decoded from a component, along with the packages it refers to.
+#[derive(Debug, Clone)]
+pub struct World {
+    resolve: Resolve,
+    id: WorldId,
+}
+
+// an import or export of a world
+#[derive(Debug, PartialEq)]
+enum Item {
+    Interface(BTreeMap<String, Signature>),
+    Function(Signature),
+}
+
+#[derive(Debug, PartialEq)]
+struct Signature {
+    params: Vec<String>,
+    results: Vec<String>,
+}
+
+impl World {
+    /// Read the world named `name` from a WIT file, or from a directory containing a WIT package
+    /// and the packages it uses in `deps`, or its only world if no name is given.
+    pub fn load(path: impl AsRef<Path>, name: Option<&str>) -> Result<World> {
+        let path = path.as_ref();
+        let mut resolve = Resolve::new();
+        let (package, _) = resolve
+            .push_path(path)
+            .with_context(|| format!("failed to read WIT from {}", path.display()))?;
+
+        World::select(resolve, package, name)
+    }
+
+    /// Parse the world named `name` from a WIT document, or its only world if no name is given.
+    /// The document can't use packages other than its own.
+    pub fn parse(wit: &str, name: Option<&str>) -> Result<World> {
+        let mut resolve = Resolve::new();
+        let package = resolve.push(UnresolvedPackage::parse(Path::new("world.wit"), wit)?)?;
+
+        World::select(resolve, package, name)
+    }
+
+    /// The world a component targets, decoded from its binary.
+    pub fn of_component(wasm: &[u8]) -> Result<World> {
+        match decode(wasm)? {
+            DecodedWasm::Component(resolve, id) => Ok(World { resolve, id }),
+            DecodedWasm::WitPackage(..) => bail!("expected a component, found a WIT package"),
+        }
+    }
+
+    fn select(
+        resolve: Resolve,
+        package: wit_parser::PackageId,
+        name: Option<&str>,
+    ) -> Result<World> {
+        let id = resolve.select_world(package, name)?;
+        Ok(World { resolve, id })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.resolve.worlds[self.id].name
+    }
+
+    // the functions imported or exported by the world, by the name of the interface they belong
+    // to or their own; types imported by the world directly are left out
+    fn items(&self, exports: bool) -> BTreeMap<String, Item> {
+        let world = &self.resolve.worlds[self.id];
+        let items = if exports {
+            &world.exports
+        } else {
+            &world.imports
+        };
+
+        items
+            .iter()
+            .filter_map(|(key, item)| {
+                let item = match item {
+                    WorldItem::Interface(id) => Item::Interface(
+                        self.resolve.interfaces[*id]
+                            .functions
+                            .iter()
+                            .map(|(name, f)| (name.clone(), self.signature(f)))
+                            .collect(),
+                    ),
+                    WorldItem::Function(f) => Item::Function(self.signature(f)),
+                    WorldItem::Type(_) => return None,
+                };
+                Some((self.resolve.name_world_key(key), item))
+            })
+            .collect()
+    }
+
+    fn signature(&self, f: &Function) -> Signature {
+        Signature {
+            params: f.params.iter().map(|(_, ty)| self.type_name(ty)).collect(),
+            results: match &f.results {
+                Results::Named(results) => {
+                    results.iter().map(|(_, ty)| self.type_name(ty)).collect()
+                }
+                Results::Anon(ty) => vec![self.type_name(ty)],
+            },
+        }
+    }
+
+    fn type_name(&self, ty: &Type) -> String {
+        let id = match ty {
+            Type::Bool => return "bool".into(),
+            Type::U8 => return "u8".into(),
+            Type::U16 => return "u16".into(),
+            Type::U32 => return "u32".into(),
+            Type::U64 => return "u64".into(),
+            Type::S8 => return "s8".into(),
+            Type::S16 => return "s16".into(),
+            Type::S32 => return "s32".into(),
+            Type::S64 => return "s64".into(),
+            Type::Float32 => return "float32".into(),
+            Type::Float64 => return "float64".into(),
+            Type::Char => return "char".into(),
+            Type::String => return "string".into(),
+            Type::Id(id) => *id,
+        };
+
+        let ty = &self.resolve.types[id];
+        let optional = |ty: &Option<Type>| match ty {
+            Some(ty) => self.type_name(ty),
+            None => "_".into(),
+        };
+        match &ty.kind {
+            TypeDefKind::Type(ty) => self.type_name(ty),
+            TypeDefKind::List(ty) => format!("list<{}>", self.type_name(ty)),
+            TypeDefKind::Option(ty) => format!("option<{}>", self.type_name(ty)),
+            TypeDefKind::Result(r) if r.ok.is_none() && r.err.is_none() => "result".into(),
+            TypeDefKind::Result(r) => format!("result<{}, {}>", optional(&r.ok), optional(&r.err)),
+            TypeDefKind::Tuple(t) => format!(
+                "tuple<{}>",
+                t.types
+                    .iter()
+                    .map(|ty| self.type_name(ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TypeDefKind::Handle(Handle::Own(id)) => self.type_name(&Type::Id(*id)),
+            TypeDefKind::Handle(Handle::Borrow(id)) => {
+                format!("borrow<{}>", self.type_name(&Type::Id(*id)))
+            }
+            TypeDefKind::Future(ty) => format!("future<{}>", optional(ty)),
+            TypeDefKind::Stream(s) => {
+                format!("stream<{}, {}>", optional(&s.element), optional(&s.end))
+            }
+            _ => ty.name.clone().unwrap_or_else(|| "<unknown>".into()),
+        }
+    }
+}
+
+/// Check that the component conforms to the world. Failures are reported under
+/// `world.imports.<interface>[::<function>]` and `world.exports.<interface>[#<function>]`, or
+/// `world.imports.<function>` and `world.exports.<function>` for functions of the world itself.
+pub fn validate_world(world: &World, component: &World) -> Report {
+    let mut report = Report::new();
+
+    // everything imported by the component must be provided by the world
+    let provided = world.items(false);
+    for (name, item) in component.items(false) {
+        let property = format!("world.imports.{name}");
+        match (provided.get(&name), &item) {
+            (None, _) => not_imported(&mut report, &property),
+            (Some(Item::Function(expected)), Item::Function(actual)) => {
+                validate_signature(&mut report, &property, expected, actual)
+            }
+            (Some(Item::Interface(expected)), Item::Interface(functions)) => {
+                for (f, actual) in functions {
+                    let property = format!("{property}::{f}");
+                    match expected.get(f) {
+                        Some(expected) => {
+                            validate_signature(&mut report, &property, expected, actual)
+                        }
+                        None => not_imported(&mut report, &property),
+                    }
+                }
+            }
+            (Some(expected), actual) => mismatched_kind(&mut report, &property, expected, actual),
+        }
+    }
+
+    // everything exported by the world must be exported by the component
+    let mut exports = component.items(true);
+    for (name, item) in world.items(true) {
+        let property = format!("world.exports.{name}");
+        match (&item, exports.remove(&name)) {
+            (_, None) => missing_export(&mut report, &property),
+            (Item::Function(expected), Some(Item::Function(actual))) => {
+                validate_signature(&mut report, &property, expected, &actual)
+            }
+            (Item::Interface(functions), Some(Item::Interface(mut actual))) => {
+                for (f, expected) in functions {
+                    let property = format!("{property}#{f}");
+                    match actual.remove(f) {
+                        Some(actual) => {
+                            validate_signature(&mut report, &property, expected, &actual)
+                        }
+                        None => missing_export(&mut report, &property),
+                    }
+                }
+                for f in actual.keys() {
+                    extra_export(&mut report, &format!("{property}#{f}"));
+                }
+            }
+            (expected, Some(actual)) => mismatched_kind(&mut report, &property, expected, &actual),
+        }
+    }
+
+    // and the component must not export anything the world doesn't declare
+    for name in exports.keys() {
+        extra_export(&mut report, &format!("world.exports.{name}"));
+    }
+
+    report
+}
+
+fn not_imported(report: &mut Report, property: &str) {
+    report.validate_fn(
+        property,
+        "not imported",
+        "imported",
+        false,
+        10,
+        Classification::AbiCompatibilty,
+    );
+}
+
+fn missing_export(report: &mut Report, property: &str) {
+    report.validate_fn(
+        property,
+        "exported",
+        "not exported",
+        false,
+        10,
+        Classification::AbiCompatibilty,
+    );
+}
+
+fn extra_export(report: &mut Report, property: &str) {
+    report.validate_fn(
+        property,
+        "not exported",
+        "exported",
+        false,
+        5,
+        Classification::AbiCompatibilty,
+    );
+}
+
+fn mismatched_kind(report: &mut Report, property: &str, expected: &Item, actual: &Item) {
+    let kind = |item: &Item| match item {
+        Item::Interface(_) => "interface",
+        Item::Function(_) => "function",
+    };
+    report.validate_fn(
+        property,
+        kind(expected),
+        kind(actual),
+        false,
+        10,
+        Classification::AbiCompatibilty,
+    );
+}
+
+fn validate_signature(
+    report: &mut Report,
+    property: &str,
+    expected: &Signature,
+    actual: &Signature,
+) {
+    for (part, expected, actual) in [
+        ("params", &expected.params, &actual.params),
+        ("results", &expected.results, &actual.results),
+    ] {
+        report.validate_fn(
+            format_args!("{property}.{part}"),
+            Types(expected),
+            Types(actual),
+            expected == actual,
+            8,
+            Classification::AbiCompatibilty,
+        );
+    }
+}
+
+struct Types<'a>(&'a [String]);
+
+impl Display for Types<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.0.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `test/wit/app.wasm` is a component targeting the `app` world, built with
+    // `wasm-tools component embed --dummy --world app test/wit | wasm-tools component new`
+    fn component() -> World {
+        World::of_component(include_bytes!("../../test/wit/app.wasm")).unwrap()
+    }
+
+    fn fails(report: &Report) -> Vec<&str> {
+        report.fails.keys().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn component_conforms_to_its_world() {
+        let world = World::load("../test/wit", Some("app")).unwrap();
+        assert_eq!(world.name(), "app");
+
+        let report = validate_world(&world, &component());
+        assert!(report.fails.is_empty(), "{:?}", fails(&report));
+    }
+
+    #[test]
+    fn world_is_decoded_from_component() {
+        let component = component();
+        let imports = component.items(false);
+        let exports = component.items(true);
+
+        // `types` is imported along with `host`, which uses its `point`
+        assert_eq!(
+            imports.keys().collect::<Vec<_>>(),
+            [
+                "example:app/host@1.0.0",
+                "example:app/types@1.0.0",
+                "notify",
+                "settings",
+                "wasi:clocks/monotonic-clock@0.2.0",
+            ]
+        );
+        assert_eq!(
+            exports.keys().collect::<Vec<_>>(),
+            ["example:app/types@1.0.0", "handler", "run"]
+        );
+
+        // types used from other interfaces and aliases are compared by what they refer to
+        let Some(Item::Interface(host)) = imports.get("example:app/host@1.0.0") else {
+            panic!("host is not an interface");
+        };
+        assert_eq!(
+            host["distance"],
+            Signature {
+                params: vec!["point".into(), "point".into()],
+                results: vec!["float64".into()],
+            }
+        );
+        let Some(Item::Interface(clock)) = imports.get("wasi:clocks/monotonic-clock@0.2.0") else {
+            panic!("monotonic-clock is not an interface");
+        };
+        assert_eq!(clock["now"].results, ["u64"]);
+
+        // resources are exported as their constructor and methods
+        let Some(Item::Interface(types)) = exports.get("example:app/types@1.0.0") else {
+            panic!("types is not an interface");
+        };
+        assert_eq!(
+            types.keys().collect::<Vec<_>>(),
+            [
+                "[constructor]counter",
+                "[method]counter.increment",
+                "[method]counter.value"
+            ]
+        );
+        assert_eq!(types["[constructor]counter"].results, ["counter"]);
+        assert_eq!(
+            types["[method]counter.increment"].params,
+            ["borrow<counter>", "u32"]
+        );
+
+        let Some(Item::Interface(settings)) = imports.get("settings") else {
+            panic!("settings is not an interface");
+        };
+        assert_eq!(settings["get"].results, ["option<string>"]);
+    }
+
+    #[test]
+    fn included_world_does_not_provide_everything() {
+        let world = World::load("../test/wit", Some("base")).unwrap();
+        let report = validate_world(&world, &component());
+
+        assert_eq!(
+            fails(&report),
+            [
+                "world.exports.example:app/types@1.0.0",
+                "world.exports.handler",
+                "world.exports.run",
+                "world.imports.notify",
+                "world.imports.settings",
+            ]
+        );
+        assert_eq!(report.fails["world.exports.run"].severity, 5);
+        assert_eq!(report.fails["world.imports.notify"].severity, 10);
+    }
+
+    #[test]
+    fn foreign_packages_must_be_resolved() {
+        let wit = std::fs::read_to_string("../test/wit/world.wit").unwrap();
+        assert!(World::parse(&wit, Some("app")).is_err());
+    }
+
+    #[test]
+    fn mismatches_are_reported() {
+        let world = World::parse(
+            r#"
+            package example:app@1.0.0;
+
+            interface types {
+              resource counter {
+                constructor(start: u32);
+                increment: func(by: u64) -> u32;
+              }
+            }
+
+            interface host {
+              log: func(message: string);
+            }
+
+            world app {
+              import host;
+              import settings: func(key: string) -> option<string>;
+              import notify: func(code: u32);
+
+              export types;
+              export run: func(args: list<string>) -> s32;
+              export handler: interface {
+                handle: func(id: u64) -> bool;
+                reset: func();
+              }
+              export stop: func();
+            }
+            "#,
+            None,
+        )
+        .unwrap();
+        let report = validate_world(&world, &component());
+
+        assert_eq!(
+            fails(&report),
+            [
+                "world.exports.example:app/types@1.0.0#[method]counter.increment.params",
+                "world.exports.example:app/types@1.0.0#[method]counter.value",
+                "world.exports.handler#reset",
+                "world.exports.stop",
+                "world.imports.example:app/host@1.0.0::distance",
+                "world.imports.example:app/host@1.0.0::random",
+                "world.imports.example:app/types@1.0.0",
+                "world.imports.settings",
+                "world.imports.wasi:clocks/monotonic-clock@0.2.0",
+            ]
+        );
+        let params =
+            &report.fails["world.exports.example:app/types@1.0.0#[method]counter.increment.params"];
+        assert_eq!(params.expected, "(borrow<counter>, u64)");
+        assert_eq!(params.actual, "(borrow<counter>, u32)");
+        assert_eq!(params.severity, 8);
+        assert_eq!(report.fails["world.imports.settings"].expected, "function");
+    }
+}